@@ -0,0 +1,199 @@
+// src/app/config_service.rs
+// Loading and saving of the docka configuration file
+// docka設定ファイルの読み込みと保存
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{DockaError, DockaResult};
+use crate::utils::secret_mask::SecretMask;
+use crate::utils::time_formatter::{DisplayTimezone, TimeDisplay};
+
+/// Name of the configuration file inside the configuration directory
+/// 設定ディレクトリ内の設定ファイル名
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// User settings read from `config.json`
+/// `config.json`から読み込むユーザー設定
+///
+/// Every field is optional in the file; missing ones keep their default.
+/// ファイル内の全フィールドは省略可能で、省略されたものはデフォルト値になります。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::app::config_service::AppConfig;
+/// use docka::utils::time_formatter::{DisplayTimezone, TimeDisplay};
+///
+/// let config: AppConfig =
+///     serde_json::from_str(r#"{ "timezone": "+09:00", "time_display": "absolute" }"#).unwrap();
+/// assert_eq!(config.time_display, TimeDisplay::Absolute);
+/// assert_eq!(config.timezone, DisplayTimezone::parse("+09:00").unwrap());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    /// Timezone of absolute timestamps (`local`, `UTC` or an offset such as `+09:00`)
+    /// 日時表示のタイムゾーン（`local`、`UTC`、または`+09:00`のようなオフセット）
+    pub timezone: DisplayTimezone,
+
+    /// Whether timestamps start out relative (`Up 3h`) or absolute
+    /// タイムスタンプを相対表示（`Up 3h`）と日時表示のどちらで開始するか
+    pub time_display: TimeDisplay,
+
+    /// Key patterns whose values are masked, e.g. `["*PASSWORD*", "*_KEY"]`
+    /// 値をマスクするキーのパターン（例: `["*PASSWORD*", "*_KEY"]`）
+    pub secret_patterns: SecretMask,
+
+    /// Named filter expressions, e.g. `{ "broken": "status:exited exit:!=0" }`
+    /// 名前付きのフィルタ式（例: `{ "broken": "status:exited exit:!=0" }`）
+    pub saved_filters: BTreeMap<String, String>,
+}
+
+/// Reads and writes the configuration file
+/// 設定ファイルを読み書きする
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigService {
+    /// Location of `config.json`
+    /// `config.json`の場所
+    path: PathBuf,
+}
+
+impl ConfigService {
+    /// Create a service for the file at `path`
+    /// `path`のファイルを扱うサービスを作成
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Locate the file from the current process environment
+    /// 現在のプロセス環境からファイルの場所を決定
+    ///
+    /// `DOCKA_CONFIG` names the file directly; otherwise it is
+    /// `$XDG_CONFIG_HOME/docka/config.json` or `~/.config/docka/config.json`.
+    ///
+    /// `DOCKA_CONFIG`はファイルを直接指定します。指定がない場合は
+    /// `$XDG_CONFIG_HOME/docka/config.json`または`~/.config/docka/config.json`です。
+    #[must_use]
+    pub fn from_env() -> Self {
+        let non_empty = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+
+        if let Some(path) = non_empty("DOCKA_CONFIG") {
+            return Self::new(path);
+        }
+        let config_dir = non_empty("XDG_CONFIG_HOME").map_or_else(
+            || {
+                non_empty("HOME").map_or_else(
+                    || PathBuf::from(".config"),
+                    |home| PathBuf::from(home).join(".config"),
+                )
+            },
+            PathBuf::from,
+        );
+        Self::new(config_dir.join("docka").join(CONFIG_FILE_NAME))
+    }
+
+    /// Location of the configuration file
+    /// 設定ファイルの場所
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the configuration, using the defaults when the file does not exist
+    /// 設定を読み込む（ファイルが存在しない場合はデフォルト値を使用）
+    ///
+    /// # Errors
+    /// * `DockaError::Configuration` - When the file cannot be read or is not valid
+    pub fn load(&self) -> DockaResult<AppConfig> {
+        let text = match std::fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(AppConfig::default());
+            }
+            Err(error) => {
+                return Err(DockaError::configuration(format!(
+                    "Cannot read {}: {error}",
+                    self.path.display()
+                )));
+            }
+        };
+        serde_json::from_str(&text).map_err(|error| {
+            DockaError::configuration(format!("Invalid {}: {error}", self.path.display()))
+        })
+    }
+
+    /// Write the configuration, creating its directory when needed
+    /// 設定を書き込む（必要に応じてディレクトリを作成）
+    ///
+    /// # Errors
+    /// * `DockaError::Configuration` - When the file or its directory cannot be written
+    pub fn save(&self, config: &AppConfig) -> DockaResult<()> {
+        let cannot_write = |error: std::io::Error| {
+            DockaError::configuration(format!("Cannot write {}: {error}", self.path.display()))
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(cannot_write)?;
+        }
+        let mut text = serde_json::to_string_pretty(config)?;
+        text.push('\n');
+        std::fs::write(&self.path, text).map_err(cannot_write)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_config_file() {
+        // Test that a missing file gives the defaults and an invalid one is reported
+        // ファイルがない場合はデフォルト値になり、不正な場合は報告されることをテスト
+        let dir = tempfile::tempdir().unwrap();
+        let service = ConfigService::new(dir.path().join(CONFIG_FILE_NAME));
+        assert_eq!(service.load().unwrap(), AppConfig::default());
+
+        std::fs::write(service.path(), r#"{ "timezone": "UTC" }"#).unwrap();
+        let config = service.load().unwrap();
+        assert_eq!(config.timezone, DisplayTimezone::Utc);
+        assert_eq!(config.time_display, TimeDisplay::Relative);
+        assert_eq!(config.secret_patterns, SecretMask::default());
+
+        std::fs::write(service.path(), r#"{ "secret_patterns": ["*DSN"] }"#).unwrap();
+        let config = service.load().unwrap();
+        assert!(config.secret_patterns.is_secret("SENTRY_DSN"));
+        assert!(!config.secret_patterns.is_secret("DB_PASSWORD"));
+
+        std::fs::write(service.path(), r#"{ "timezone": "Asia/Tokyo" }"#).unwrap();
+        assert!(matches!(
+            service.load(),
+            Err(DockaError::Configuration { .. })
+        ));
+        std::fs::write(service.path(), r#"{ "timzone": "UTC" }"#).unwrap();
+        assert!(matches!(
+            service.load(),
+            Err(DockaError::Configuration { .. })
+        ));
+    }
+
+    #[test]
+    fn test_save_config_file() {
+        // Test that a saved configuration with named filters loads back unchanged
+        // 名前付きフィルタを含む保存した設定がそのまま読み込まれることをテスト
+        let dir = tempfile::tempdir().unwrap();
+        let service = ConfigService::new(dir.path().join("docka").join(CONFIG_FILE_NAME));
+        let mut config = AppConfig {
+            timezone: DisplayTimezone::Utc,
+            ..AppConfig::default()
+        };
+        config
+            .saved_filters
+            .insert("broken".to_string(), "status:exited exit:!=0".to_string());
+
+        service.save(&config).unwrap();
+        assert_eq!(service.load().unwrap(), config);
+    }
+}
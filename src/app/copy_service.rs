@@ -0,0 +1,1079 @@
+// src/app/copy_service.rs
+// Copying files between containers and the local filesystem
+// コンテナとローカルファイルシステム間のファイルコピー
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures_util::StreamExt;
+use tokio::fs::{self, File};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::{mpsc, watch};
+use tracing::{info, warn};
+
+use crate::domain::entities::PathKind;
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::ContainerId;
+use crate::error::{DockaError, DockaResult};
+use crate::utils::tar::{self, END_OF_ARCHIVE, TarEvent, TarHeader, TarReader};
+
+/// Size of the chunks read from local files during an upload
+/// アップロード中にローカルファイルから読み込むチャンクのサイズ
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Chunks queued between reading local files and sending them to the engine
+/// ローカルファイルの読み込みとエンジンへの送信の間でキューに入るチャンク数
+///
+/// Together with `CHUNK_SIZE` this bounds an upload to a few hundred KiB of
+/// buffered data however large the copied directory is.
+///
+/// `CHUNK_SIZE`と合わせて、コピーするディレクトリの大きさにかかわらず
+/// アップロードのバッファを数百`KiB`に制限します。
+const UPLOAD_QUEUE_CHUNKS: usize = 4;
+
+/// Direction of a copy
+/// コピーの方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyDirection {
+    /// From the container to the local filesystem
+    /// コンテナからローカルファイルシステムへ
+    FromContainer,
+
+    /// From the local filesystem into the container
+    /// ローカルファイルシステムからコンテナへ
+    ToContainer,
+}
+
+impl CopyDirection {
+    /// Display label
+    /// 表示ラベル
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::FromContainer => "Container → local",
+            Self::ToContainer => "Local → container",
+        }
+    }
+
+    /// The other direction
+    /// 逆方向
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::FromContainer => Self::ToContainer,
+            Self::ToContainer => Self::FromContainer,
+        }
+    }
+}
+
+/// What to copy, following `docker cp` semantics
+/// コピー内容（`docker cp`の意味に従う）
+///
+/// A destination that is an existing directory receives the source under its
+/// own name; any other destination names the copy.
+///
+/// 既存ディレクトリの宛先にはソースが元の名前で置かれ、それ以外の宛先は
+/// コピーの名前になります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyRequest {
+    /// Container to copy from or into
+    /// コピー元またはコピー先のコンテナ
+    pub container_id: ContainerId,
+
+    /// Direction of the copy
+    /// コピーの方向
+    pub direction: CopyDirection,
+
+    /// Absolute path inside the container
+    /// コンテナ内の絶対パス
+    pub container_path: String,
+
+    /// Path on the local filesystem
+    /// ローカルファイルシステム上のパス
+    pub local_path: PathBuf,
+}
+
+/// Resolved copy, ready to run
+/// 解決済みで実行可能なコピー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyPlan {
+    /// Original request
+    /// 元のリクエスト
+    pub request: CopyRequest,
+
+    /// Directory the copied entry is written into
+    /// コピーしたエントリを書き込むディレクトリ
+    pub target_dir: PathBuf,
+
+    /// Name of the copied entry inside `target_dir`
+    /// `target_dir`内でのコピーしたエントリの名前
+    pub target_name: String,
+
+    /// Whether something already exists at the destination
+    /// 宛先に既に何かが存在するか
+    pub overwrites: bool,
+}
+
+impl CopyPlan {
+    /// Full destination path, for confirmation prompts and reports
+    /// 確認プロンプトと結果表示用の完全な宛先パス
+    #[must_use]
+    pub fn destination(&self) -> String {
+        self.target_dir
+            .join(&self.target_name)
+            .display()
+            .to_string()
+    }
+}
+
+/// Progress of a running copy
+/// 実行中のコピーの進捗
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CopyProgress {
+    /// Entries (files, directories, links) started so far
+    /// これまでに開始したエントリ（ファイル、ディレクトリ、リンク）数
+    pub entries: usize,
+
+    /// File content bytes transferred so far
+    /// これまでに転送したファイル内容のバイト数
+    pub bytes: u64,
+
+    /// Total file content bytes, when known (uploads only)
+    /// ファイル内容の合計バイト数（判明している場合、アップロードのみ）
+    pub total_bytes: Option<u64>,
+
+    /// Archive path of the entry being copied
+    /// コピー中のエントリのアーカイブパス
+    pub current: String,
+}
+
+impl CopyProgress {
+    /// Completed fraction between 0 and 1, when the total is known
+    /// 完了した割合（0から1、合計が判明している場合）
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Display only / 表示専用
+    pub fn fraction(&self) -> Option<f64> {
+        self.total_bytes.map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                (self.bytes as f64 / total as f64).min(1.0)
+            }
+        })
+    }
+}
+
+/// Outcome of a completed copy
+/// 完了したコピーの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyReport {
+    /// Entries copied
+    /// コピーしたエントリ数
+    pub entries: usize,
+
+    /// File content bytes copied
+    /// コピーしたファイル内容のバイト数
+    pub bytes: u64,
+
+    /// Where the copy was written
+    /// コピーの書き込み先
+    pub destination: String,
+}
+
+/// Local file or directory to pack into an upload archive
+/// アップロードアーカイブに詰めるローカルファイルまたはディレクトリ
+#[derive(Debug, Clone)]
+struct LocalEntry {
+    source: PathBuf,
+    header: TarHeader,
+}
+
+/// Service streaming tar archives between containers and local paths
+/// コンテナとローカルパスの間でtarアーカイブをストリーミングするサービス
+///
+/// Archives are processed chunk by chunk in both directions and never held
+/// in memory as a whole, so large directories stay within the memory
+/// budget.
+///
+/// アーカイブは両方向ともチャンク単位で処理され、全体をメモリに保持しないため、
+/// 大きなディレクトリでもメモリ予算内に収まります。
+#[derive(Clone)]
+pub struct CopyService {
+    repository: Arc<dyn DockerRepository>,
+}
+
+impl CopyService {
+    /// Create a service using `repository`
+    /// `repository`を使用するサービスを作成
+    #[must_use]
+    pub fn new(repository: Arc<dyn DockerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Resolve the destination of a copy and check whether it exists
+    /// コピーの宛先を解決し、存在するかチェック
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - A path is empty, relative, or does not exist
+    /// * Any error returned by the repository
+    pub async fn plan(&self, request: CopyRequest) -> DockaResult<CopyPlan> {
+        let container_path = request.container_path.trim();
+        if !container_path.starts_with('/') {
+            return Err(DockaError::invalid_input(
+                "Container path must be absolute (e.g. /var/log)",
+            ));
+        }
+        let container_path = normalize_container_path(container_path);
+        if container_path == "/" {
+            return Err(DockaError::invalid_input(
+                "Choose a file or directory inside the container, not /",
+            ));
+        }
+        if request.local_path.as_os_str().is_empty() {
+            return Err(DockaError::invalid_input("Local path is required"));
+        }
+        let request = CopyRequest {
+            container_path,
+            ..request
+        };
+
+        match request.direction {
+            CopyDirection::FromContainer => self.plan_download(request).await,
+            CopyDirection::ToContainer => self.plan_upload(request).await,
+        }
+    }
+
+    /// Resolve a copy out of the container
+    /// コンテナからのコピーを解決
+    async fn plan_download(&self, request: CopyRequest) -> DockaResult<CopyPlan> {
+        let id = &request.container_id;
+        if self
+            .repository
+            .container_path_kind(id, &request.container_path)
+            .await?
+            .is_none()
+        {
+            return Err(DockaError::invalid_input(format!(
+                "No such path in container: {}",
+                request.container_path
+            )));
+        }
+
+        let local = &request.local_path;
+        let (target_dir, target_name) = if fs::metadata(local)
+            .await
+            .is_ok_and(|metadata| metadata.is_dir())
+        {
+            (
+                local.clone(),
+                base_name(&request.container_path).to_string(),
+            )
+        } else {
+            let parent = local
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+            if !fs::metadata(&parent)
+                .await
+                .is_ok_and(|metadata| metadata.is_dir())
+            {
+                return Err(DockaError::invalid_input(format!(
+                    "No such local directory: {}",
+                    parent.display()
+                )));
+            }
+            let name = local
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .ok_or_else(|| DockaError::invalid_input("Local path has no file name"))?;
+            (parent, name)
+        };
+
+        let overwrites = fs::symlink_metadata(target_dir.join(&target_name))
+            .await
+            .is_ok();
+        Ok(CopyPlan {
+            request,
+            target_dir,
+            target_name,
+            overwrites,
+        })
+    }
+
+    /// Resolve a copy into the container
+    /// コンテナへのコピーを解決
+    async fn plan_upload(&self, request: CopyRequest) -> DockaResult<CopyPlan> {
+        let local = fs::canonicalize(&request.local_path).await.map_err(|_| {
+            DockaError::invalid_input(format!(
+                "No such local path: {}",
+                request.local_path.display()
+            ))
+        })?;
+        let source_name = local
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| DockaError::invalid_input("Cannot copy the local root directory"))?;
+
+        let id = &request.container_id;
+        let destination = request.container_path.clone();
+        let (target_dir, target_name, overwrites) = match self
+            .repository
+            .container_path_kind(id, &destination)
+            .await?
+        {
+            Some(PathKind::Directory) => {
+                let target = format!("{}/{source_name}", destination.trim_end_matches('/'));
+                let overwrites = self
+                    .repository
+                    .container_path_kind(id, &target)
+                    .await?
+                    .is_some();
+                (destination, source_name, overwrites)
+            }
+            kind => {
+                let parent = parent_dir(&destination);
+                if !self
+                    .repository
+                    .container_path_kind(id, &parent)
+                    .await?
+                    .is_some_and(PathKind::is_directory)
+                {
+                    return Err(DockaError::invalid_input(format!(
+                        "No such directory in container: {parent}"
+                    )));
+                }
+                let name = base_name(&destination).to_string();
+                (parent, name, kind.is_some())
+            }
+        };
+
+        Ok(CopyPlan {
+            request: CopyRequest {
+                local_path: local,
+                ..request
+            },
+            target_dir: PathBuf::from(target_dir),
+            target_name,
+            overwrites,
+        })
+    }
+
+    /// Run a planned copy, publishing progress to `progress`
+    /// 計画したコピーを実行し、進捗を`progress`に公開
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - The archive is corrupt or escapes the destination
+    /// * `DockaError::Io` - Reading or writing local files failed
+    /// * Any error returned by the repository
+    pub async fn execute(
+        &self,
+        plan: &CopyPlan,
+        progress: &watch::Sender<CopyProgress>,
+    ) -> DockaResult<CopyReport> {
+        info!(
+            "Copying {} ({}) to {}",
+            plan.request.container_path,
+            plan.request.direction.label(),
+            plan.destination()
+        );
+        match plan.request.direction {
+            CopyDirection::FromContainer => self.download(plan, progress).await?,
+            CopyDirection::ToContainer => self.upload(plan, progress).await?,
+        }
+
+        let done = progress.borrow();
+        Ok(CopyReport {
+            entries: done.entries,
+            bytes: done.bytes,
+            destination: plan.destination(),
+        })
+    }
+
+    /// Extract the container's archive under the local destination
+    /// コンテナのアーカイブをローカルの宛先に展開
+    async fn download(
+        &self,
+        plan: &CopyPlan,
+        progress: &watch::Sender<CopyProgress>,
+    ) -> DockaResult<()> {
+        let mut stream = self
+            .repository
+            .download_archive(&plan.request.container_id, &plan.request.container_path)
+            .await?;
+        let mut reader = TarReader::new();
+        let mut file: Option<File> = None;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            let mut input = chunk.as_ref();
+            while let Some(event) = reader.next_event(&mut input)? {
+                match event {
+                    TarEvent::Entry(header) => {
+                        progress.send_modify(|progress| {
+                            progress.entries += 1;
+                            progress.current.clone_from(&header.path);
+                        });
+                        file = extract_entry(plan, &header).await?;
+                    }
+                    TarEvent::Data(data) => {
+                        if let Some(file) = file.as_mut() {
+                            file.write_all(data).await?;
+                            progress.send_modify(|progress| progress.bytes += data.len() as u64);
+                        }
+                    }
+                    TarEvent::EntryEnd => {
+                        if let Some(mut file) = file.take() {
+                            file.flush().await?;
+                        }
+                    }
+                }
+            }
+        }
+
+        // A connection dropped mid-transfer ends the stream without an error
+        // 転送中に切断された接続はエラーなしでストリームを終える
+        if !reader.is_finished() {
+            return Err(DockaError::invalid_input(format!(
+                "The archive of '{}' ended early; the copy is incomplete",
+                plan.request.container_path
+            )));
+        }
+        Ok(())
+    }
+
+    /// Pack the local source and stream it into the container
+    /// ローカルのソースを詰めてコンテナにストリーミング
+    async fn upload(
+        &self,
+        plan: &CopyPlan,
+        progress: &watch::Sender<CopyProgress>,
+    ) -> DockaResult<()> {
+        let entries = collect_local_entries(&plan.request.local_path, &plan.target_name).await?;
+        let total = entries
+            .iter()
+            .filter(|entry| entry.header.kind == PathKind::File)
+            .map(|entry| entry.header.size)
+            .sum();
+        progress.send_modify(|progress| progress.total_bytes = Some(total));
+
+        // Local files are read by a separate task feeding a bounded queue, so
+        // at most UPLOAD_QUEUE_CHUNKS chunks wait for the engine at any time
+        // ローカルファイルは別タスクが上限付きキューに読み込むため、エンジンを
+        // 待つチャンクは常にUPLOAD_QUEUE_CHUNKS個以下になる
+        let (sender, receiver) = mpsc::channel(UPLOAD_QUEUE_CHUNKS);
+        let producer_progress = progress.clone();
+        let producer = tokio::spawn(async move {
+            let result = write_archive(entries, &sender, &producer_progress).await;
+            if result.is_err() {
+                // Abort the request body; the real error is returned below
+                // リクエスト本文を中断する（実際のエラーは下で返す）
+                let _ = sender
+                    .send(Err(DockaError::internal("Reading local files failed")))
+                    .await;
+            }
+            result
+        });
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|chunk| (chunk, receiver))
+        });
+
+        let uploaded = self
+            .repository
+            .upload_archive(
+                &plan.request.container_id,
+                &plan.target_dir.to_string_lossy(),
+                Box::pin(stream),
+            )
+            .await;
+        producer.await??;
+        uploaded
+    }
+}
+
+/// Create the local counterpart of an archive entry
+/// アーカイブエントリに対応するローカルのエントリを作成
+///
+/// Returns the open file for regular files so their data can be written.
+/// Entries below a symlink inside the destination are rejected, whether the
+/// link was created by the same archive or already existed, so a malicious
+/// archive cannot write outside the destination through a link.
+///
+/// 通常ファイルではデータを書き込めるよう開いたファイルを返します。
+/// 宛先内のシンボリックリンク配下のエントリは、同じアーカイブが作成したリンクか
+/// 既存のリンクかにかかわらず拒否するため、悪意のあるアーカイブがリンク経由で
+/// 宛先の外に書き込むことはできません。
+async fn extract_entry(plan: &CopyPlan, header: &TarHeader) -> DockaResult<Option<File>> {
+    let local = local_entry_path(plan, &header.path)?;
+    ensure_no_symlink_ancestor(plan, &local, &header.path).await?;
+
+    match header.kind {
+        PathKind::Directory => {
+            fs::create_dir_all(&local).await?;
+            Ok(None)
+        }
+        PathKind::File => {
+            remove_link(&local).await?;
+            let file = File::create(&local).await?;
+            set_mode(&file, header.mode).await?;
+            Ok(Some(file))
+        }
+        PathKind::Symlink => {
+            let target = header.link_target.clone().unwrap_or_default();
+            remove_link(&local).await?;
+            create_symlink(&target, &local).await?;
+            Ok(None)
+        }
+        PathKind::HardLink => {
+            let target = header.link_target.as_deref().unwrap_or_default();
+            let original = local_entry_path(plan, target)?;
+            ensure_no_symlink_ancestor(plan, &original, target).await?;
+            remove_link(&local).await?;
+            fs::hard_link(&original, &local).await?;
+            Ok(None)
+        }
+        PathKind::Other => {
+            warn!("Skipping special file {}", header.path);
+            Ok(None)
+        }
+    }
+}
+
+/// Refuse a local path whose directories inside the destination include a symlink
+/// 宛先内のディレクトリにシンボリックリンクを含むローカルパスを拒否
+///
+/// Every existing ancestor below the target directory is checked with
+/// `symlink_metadata`; the target directory itself was chosen by the user and
+/// may be a link.
+/// 対象ディレクトリより下の既存の祖先を`symlink_metadata`で確認します。
+/// 対象ディレクトリ自体はユーザーが選んだものでありリンクでも構いません。
+async fn ensure_no_symlink_ancestor(
+    plan: &CopyPlan,
+    local: &Path,
+    archive_path: &str,
+) -> DockaResult<()> {
+    for ancestor in local
+        .ancestors()
+        .skip(1)
+        .take_while(|ancestor| *ancestor != plan.target_dir)
+    {
+        if fs::symlink_metadata(ancestor)
+            .await
+            .is_ok_and(|metadata| metadata.file_type().is_symlink())
+        {
+            return Err(DockaError::invalid_input(format!(
+                "Archive entry '{archive_path}' points through a symlink"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Map an archive path to the local destination, renaming the top entry
+/// アーカイブパスをローカルの宛先に対応付け、トップエントリの名前を変更
+fn local_entry_path(plan: &CopyPlan, archive_path: &str) -> DockaResult<PathBuf> {
+    let mut components = Path::new(archive_path)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir));
+    let escapes = || {
+        DockaError::invalid_input(format!(
+            "Archive entry '{archive_path}' escapes the destination"
+        ))
+    };
+
+    match components.next() {
+        Some(Component::Normal(_)) => {}
+        _ => return Err(escapes()),
+    }
+    let mut path = plan.target_dir.join(&plan.target_name);
+    for component in components {
+        match component {
+            Component::Normal(part) => path.push(part),
+            _ => return Err(escapes()),
+        }
+    }
+    Ok(path)
+}
+
+/// Remove a symlink or file at `path` so it can be replaced, if one exists
+/// 置き換えられるよう`path`にあるシンボリックリンクまたはファイルを削除（存在する場合）
+///
+/// Writing through an existing symlink would modify its target instead.
+/// 既存のシンボリックリンク経由で書き込むとリンク先が変更されてしまうため。
+async fn remove_link(path: &Path) -> DockaResult<()> {
+    match fs::symlink_metadata(path).await {
+        Ok(metadata) if metadata.file_type().is_symlink() || metadata.is_file() => {
+            fs::remove_file(path).await?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Apply the archive's permission bits to an extracted file
+/// 展開したファイルにアーカイブのパーミッションビットを適用
+#[cfg(unix)]
+async fn set_mode(file: &File, mode: u32) -> DockaResult<()> {
+    use std::os::unix::fs::PermissionsExt;
+    file.set_permissions(std::fs::Permissions::from_mode(mode & 0o7777))
+        .await?;
+    Ok(())
+}
+
+/// Apply the archive's permission bits to an extracted file (no-op off Unix)
+/// 展開したファイルにアーカイブのパーミッションビットを適用（Unix以外では何もしない）
+#[cfg(not(unix))]
+async fn set_mode(_file: &File, _mode: u32) -> DockaResult<()> {
+    Ok(())
+}
+
+/// Create a symbolic link
+/// シンボリックリンクを作成
+#[cfg(unix)]
+async fn create_symlink(target: &str, link: &Path) -> DockaResult<()> {
+    fs::symlink(target, link).await?;
+    Ok(())
+}
+
+/// Create a symbolic link (skipped off Unix, where it needs extra privileges)
+/// シンボリックリンクを作成（追加の権限が必要なUnix以外ではスキップ）
+#[cfg(not(unix))]
+async fn create_symlink(_target: &str, link: &Path) -> DockaResult<()> {
+    warn!("Skipping symlink {}", link.display());
+    Ok(())
+}
+
+/// Walk the local source and build the archive headers, depth first
+/// ローカルのソースを深さ優先でたどり、アーカイブヘッダーを構築
+///
+/// Only paths and metadata are collected; file contents are read later
+/// while streaming.
+/// パスとメタデータのみを収集し、ファイル内容はストリーミング中に読み込みます。
+async fn collect_local_entries(source: &Path, name: &str) -> DockaResult<Vec<LocalEntry>> {
+    let mut entries = Vec::new();
+    let mut pending = vec![(source.to_path_buf(), name.to_string())];
+
+    while let Some((path, archive_path)) = pending.pop() {
+        let metadata = fs::symlink_metadata(&path).await?;
+        let file_type = metadata.file_type();
+        let mut header = if file_type.is_dir() {
+            let mut children = Vec::new();
+            let mut directory = fs::read_dir(&path).await?;
+            while let Some(child) = directory.next_entry().await? {
+                children.push(child.file_name());
+            }
+            // Reverse order so the stack yields children alphabetically
+            // スタックから子がアルファベット順に出るよう逆順にする
+            children.sort_unstable_by(|a, b| b.cmp(a));
+            pending.extend(children.into_iter().map(|child| {
+                let archive_child = format!("{archive_path}/{}", child.to_string_lossy());
+                (path.join(child), archive_child)
+            }));
+            TarHeader::new(archive_path, PathKind::Directory, 0)
+        } else if file_type.is_symlink() {
+            let mut header = TarHeader::new(archive_path, PathKind::Symlink, 0);
+            header.link_target = Some(fs::read_link(&path).await?.to_string_lossy().into_owned());
+            header
+        } else if file_type.is_file() {
+            TarHeader::new(archive_path, PathKind::File, metadata.len())
+        } else {
+            warn!("Skipping special file {}", path.display());
+            continue;
+        };
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            header.mode = metadata.permissions().mode() & 0o7777;
+        }
+        header.mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(0, |elapsed| elapsed.as_secs());
+        entries.push(LocalEntry {
+            source: path,
+            header,
+        });
+    }
+    Ok(entries)
+}
+
+/// Stream headers and file contents into `sender` as a tar archive
+/// ヘッダーとファイル内容をtarアーカイブとして`sender`にストリーミング
+///
+/// Stops quietly when the receiving side has gone away (the upload failed).
+/// 受信側がなくなった場合（アップロード失敗）は静かに停止します。
+async fn write_archive(
+    entries: Vec<LocalEntry>,
+    sender: &mpsc::Sender<DockaResult<Bytes>>,
+    progress: &watch::Sender<CopyProgress>,
+) -> DockaResult<()> {
+    for entry in entries {
+        progress.send_modify(|progress| {
+            progress.entries += 1;
+            progress.current.clone_from(&entry.header.path);
+        });
+        if sender
+            .send(Ok(Bytes::from(entry.header.encode()?)))
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+        if entry.header.kind != PathKind::File {
+            continue;
+        }
+
+        let file = File::open(&entry.source).await?;
+        let mut reader = file.take(entry.header.size);
+        let mut remaining = entry.header.size;
+        while remaining > 0 {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let read = reader.read(&mut chunk).await?;
+            if read == 0 {
+                // The file shrank after it was measured; keep the archive valid
+                // 計測後にファイルが縮んだ場合もアーカイブを有効に保つ
+                warn!("{} shrank while copying", entry.source.display());
+                chunk.truncate(
+                    usize::try_from(remaining)
+                        .unwrap_or(CHUNK_SIZE)
+                        .min(CHUNK_SIZE),
+                );
+            } else {
+                chunk.truncate(read);
+            }
+            let sent = chunk.len() as u64;
+            remaining -= sent;
+            if sender.send(Ok(Bytes::from(chunk))).await.is_err() {
+                return Ok(());
+            }
+            progress.send_modify(|progress| progress.bytes += sent);
+        }
+
+        let padding = tar::padding(entry.header.size);
+        if padding > 0
+            && sender
+                .send(Ok(Bytes::from(vec![0; padding])))
+                .await
+                .is_err()
+        {
+            return Ok(());
+        }
+    }
+
+    let _ = sender.send(Ok(Bytes::from_static(&END_OF_ARCHIVE))).await;
+    Ok(())
+}
+
+/// Collapse repeated and trailing slashes of a container path
+/// コンテナパスの連続する`/`と末尾の`/`をまとめる
+pub(crate) fn normalize_container_path(path: &str) -> String {
+    let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+    format!("/{}", parts.join("/"))
+}
+
+/// Last component of a normalized container path
+/// 正規化したコンテナパスの最後の要素
+pub(crate) fn base_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Parent directory of a normalized container path
+/// 正規化したコンテナパスの親ディレクトリ
+pub(crate) fn parent_dir(path: &str) -> String {
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => path[..index].to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use crate::utils::tar::BLOCK_SIZE;
+
+    fn archive_of(entries: &[(TarHeader, &[u8])]) -> Vec<u8> {
+        let mut archive = Vec::new();
+        for (header, data) in entries {
+            archive.extend(header.encode().unwrap());
+            archive.extend(*data);
+            archive.resize(archive.len() + tar::padding(data.len() as u64), 0);
+        }
+        archive.extend(END_OF_ARCHIVE);
+        archive
+    }
+
+    async fn create_service() -> (CopyService, Arc<MockDockerRepository>) {
+        let repository = Arc::new(MockDockerRepository::new());
+        repository
+            .add_container(
+                Container::builder()
+                    .id("web-1")
+                    .name("web")
+                    .image("nginx:latest")
+                    .status(ContainerStatus::Running)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        (
+            CopyService::new(Arc::clone(&repository) as Arc<dyn DockerRepository>),
+            repository,
+        )
+    }
+
+    fn request(direction: CopyDirection, container_path: &str, local_path: &Path) -> CopyRequest {
+        CopyRequest {
+            container_id: ContainerId::new("web-1").unwrap(),
+            direction,
+            container_path: container_path.to_string(),
+            local_path: local_path.to_path_buf(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_renames_and_reports_progress() {
+        // Test extracting a directory under a new name, overwriting an existing file
+        // 既存ファイルを上書きしつつディレクトリを新しい名前で展開することをテスト
+        let (service, repository) = create_service().await;
+        let content = vec![b'x'; 70_000];
+        let mut link = TarHeader::new("log/latest", PathKind::Symlink, 0);
+        link.link_target = Some("access.log".to_string());
+        repository
+            .set_archive(
+                "web-1",
+                "/var/log",
+                archive_of(&[
+                    (TarHeader::new("log", PathKind::Directory, 0), &[]),
+                    (
+                        TarHeader::new("log/access.log", PathKind::File, 70_000),
+                        &content,
+                    ),
+                    (link, &[]),
+                ]),
+            )
+            .await;
+
+        let local = tempfile::tempdir().unwrap();
+        let destination = local.path().join("logs");
+        std::fs::create_dir(&destination).unwrap();
+        std::fs::write(destination.join("access.log"), b"old").unwrap();
+
+        let plan = service
+            .plan(request(
+                CopyDirection::FromContainer,
+                "/var//log/",
+                &destination,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(plan.target_name, "log");
+        assert!(!plan.overwrites);
+
+        let plan = service
+            .plan(request(
+                CopyDirection::FromContainer,
+                "/var/log",
+                &local.path().join("copy"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(plan.target_name, "copy");
+
+        let (sender, receiver) = watch::channel(CopyProgress::default());
+        let report = service.execute(&plan, &sender).await.unwrap();
+        assert_eq!(report.entries, 3);
+        assert_eq!(report.bytes, 70_000);
+        assert_eq!(receiver.borrow().current, "log/latest");
+
+        let copied = local.path().join("copy");
+        assert_eq!(std::fs::read(copied.join("access.log")).unwrap(), content);
+        #[cfg(unix)]
+        assert_eq!(
+            std::fs::read_link(copied.join("latest")).unwrap(),
+            Path::new("access.log")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_rejects_escaping_entries() {
+        // Test that entries leaving the destination, directly or via a symlink, are refused
+        // 直接またはシンボリックリンク経由で宛先を出るエントリが拒否されることをテスト
+        let (service, repository) = create_service().await;
+        let mut link = TarHeader::new("etc/escape", PathKind::Symlink, 0);
+        link.link_target = Some("/".to_string());
+        repository
+            .set_archive(
+                "web-1",
+                "/etc",
+                archive_of(&[
+                    (TarHeader::new("etc", PathKind::Directory, 0), &[]),
+                    (link, &[]),
+                    (TarHeader::new("etc/escape/owned", PathKind::File, 1), b"!"),
+                ]),
+            )
+            .await;
+        repository
+            .set_archive(
+                "web-1",
+                "/evil",
+                archive_of(&[(TarHeader::new("../evil", PathKind::File, 1), b"!")]),
+            )
+            .await;
+
+        let local = tempfile::tempdir().unwrap();
+        let (sender, _receiver) = watch::channel(CopyProgress::default());
+        for path in ["/etc", "/evil"] {
+            let plan = service
+                .plan(request(CopyDirection::FromContainer, path, local.path()))
+                .await
+                .unwrap();
+            assert!(matches!(
+                service.execute(&plan, &sender).await,
+                Err(DockaError::InvalidInput { .. })
+            ));
+        }
+        assert!(!local.path().join("evil").exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_download_rejects_entries_below_existing_symlinks() {
+        // Test that a symlink already in the destination cannot redirect extracted files
+        // 宛先に既に存在するシンボリックリンクで展開先をずらせないことをテスト
+        let (service, repository) = create_service().await;
+        repository
+            .set_archive(
+                "web-1",
+                "/etc",
+                archive_of(&[
+                    (TarHeader::new("etc", PathKind::Directory, 0), &[]),
+                    (TarHeader::new("etc/escape/owned", PathKind::File, 1), b"!"),
+                ]),
+            )
+            .await;
+
+        let outside = tempfile::tempdir().unwrap();
+        let local = tempfile::tempdir().unwrap();
+        std::fs::create_dir(local.path().join("etc")).unwrap();
+        std::os::unix::fs::symlink(outside.path(), local.path().join("etc/escape")).unwrap();
+
+        let plan = service
+            .plan(request(CopyDirection::FromContainer, "/etc", local.path()))
+            .await
+            .unwrap();
+        let (sender, _receiver) = watch::channel(CopyProgress::default());
+        assert!(matches!(
+            service.execute(&plan, &sender).await,
+            Err(DockaError::InvalidInput { .. })
+        ));
+        assert!(!outside.path().join("owned").exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_reports_truncated_archive() {
+        // Test that an archive ending before its end marker is an error, not a success
+        // 終端マーカーの前に終わるアーカイブが成功ではなくエラーになることをテスト
+        let (service, repository) = create_service().await;
+        let mut archive = archive_of(&[(
+            TarHeader::new("app.log", PathKind::File, 2000),
+            &[b'x'; 2000],
+        )]);
+        archive.truncate(BLOCK_SIZE + 1000);
+        repository.set_archive("web-1", "/app.log", archive).await;
+
+        let local = tempfile::tempdir().unwrap();
+        let plan = service
+            .plan(request(
+                CopyDirection::FromContainer,
+                "/app.log",
+                local.path(),
+            ))
+            .await
+            .unwrap();
+        let (sender, _receiver) = watch::channel(CopyProgress::default());
+        let error = service.execute(&plan, &sender).await.unwrap_err();
+        assert!(error.to_string().contains("ended early"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_streams_directory_into_container() {
+        // Test packing a local directory and detecting an existing destination
+        // ローカルディレクトリを詰め、既存の宛先を検出することをテスト
+        let (service, repository) = create_service().await;
+        repository
+            .set_archive(
+                "web-1",
+                "/srv",
+                archive_of(&[(TarHeader::new("srv", PathKind::Directory, 0), &[])]),
+            )
+            .await;
+        repository
+            .set_archive(
+                "web-1",
+                "/srv/site",
+                archive_of(&[(TarHeader::new("site", PathKind::Directory, 0), &[])]),
+            )
+            .await;
+
+        let local = tempfile::tempdir().unwrap();
+        let site = local.path().join("site");
+        std::fs::create_dir_all(site.join("css")).unwrap();
+        std::fs::write(site.join("index.html"), vec![b'h'; 100_000]).unwrap();
+        std::fs::write(site.join("css/app.css"), b"body{}").unwrap();
+
+        let plan = service
+            .plan(request(CopyDirection::ToContainer, "/srv", &site))
+            .await
+            .unwrap();
+        assert!(plan.overwrites);
+        assert_eq!(plan.destination(), "/srv/site");
+
+        let (sender, receiver) = watch::channel(CopyProgress::default());
+        let report = service.execute(&plan, &sender).await.unwrap();
+        assert_eq!(report.bytes, 100_006);
+        assert_eq!(receiver.borrow().fraction(), Some(1.0));
+
+        let archive = repository.uploaded_archive("web-1", "/srv").await.unwrap();
+        let mut reader = TarReader::new();
+        let mut input = archive.as_slice();
+        let mut paths = Vec::new();
+        while let Some(event) = reader.next_event(&mut input).unwrap() {
+            if let TarEvent::Entry(header) = event {
+                paths.push(header.path);
+            }
+        }
+        assert!(reader.is_finished());
+        assert_eq!(
+            paths,
+            ["site", "site/css", "site/css/app.css", "site/index.html"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_validates_paths() {
+        // Test that relative, root and missing paths are reported before copying
+        // 相対パス、ルート、存在しないパスがコピー前に報告されることをテスト
+        let (service, _repository) = create_service().await;
+        let local = tempfile::tempdir().unwrap();
+
+        for (direction, container_path) in [
+            (CopyDirection::FromContainer, "var/log"),
+            (CopyDirection::FromContainer, "//"),
+            (CopyDirection::FromContainer, "/missing"),
+            (CopyDirection::ToContainer, "/missing/dir"),
+        ] {
+            let result = service
+                .plan(request(direction, container_path, local.path()))
+                .await;
+            assert!(
+                matches!(result, Err(DockaError::InvalidInput { .. })),
+                "{container_path}"
+            );
+        }
+    }
+}
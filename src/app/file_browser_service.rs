@@ -0,0 +1,477 @@
+// src/app/file_browser_service.rs
+// Read-only browsing of a container's filesystem
+// コンテナのファイルシステムの読み取り専用ブラウズ
+
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+
+use crate::app::copy_service::{base_name, normalize_container_path, parent_dir};
+use crate::domain::entities::{FileEntry, PathKind};
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::ContainerId;
+use crate::error::{DockaError, DockaResult};
+use crate::utils::tar::{TarEvent, TarHeader, TarReader};
+
+/// Archive entries read before a directory listing stops
+/// ディレクトリ一覧が読み込みを止めるまでのアーカイブエントリ数
+///
+/// The engine archives a directory with everything below it, so listing `/`
+/// would otherwise walk the whole filesystem.
+///
+/// エンジンはディレクトリを配下すべてと共にアーカイブするため、制限がないと
+/// `/`の一覧でファイルシステム全体をたどることになります。
+pub const MAX_SCANNED_ENTRIES: usize = 10_000;
+
+/// Bytes of a file read for its preview
+/// プレビュー用に読み込むファイルのバイト数
+pub const PREVIEW_LIMIT: usize = 256 * 1024;
+
+/// Symbolic links followed before giving up
+/// 諦めるまでにたどるシンボリックリンクの数
+const MAX_LINK_HOPS: usize = 8;
+
+/// Direct children of a container directory
+/// コンテナのディレクトリの直下の子
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryListing {
+    /// Absolute path of the directory, with symbolic links resolved
+    /// ディレクトリの絶対パス（シンボリックリンクは解決済み）
+    pub path: String,
+
+    /// Children, directories first, then by name
+    /// 子（ディレクトリが先、その後は名前順）
+    pub entries: Vec<FileEntry>,
+
+    /// Whether scanning stopped at `MAX_SCANNED_ENTRIES`, so children may be missing
+    /// 走査が`MAX_SCANNED_ENTRIES`で止まり、子が欠けている可能性があるか
+    pub truncated: bool,
+}
+
+/// Beginning of a container file
+/// コンテナ内のファイルの先頭部分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePreview {
+    /// Previewed file, with symbolic links resolved
+    /// プレビューしたファイル（シンボリックリンクは解決済み）
+    pub entry: FileEntry,
+
+    /// Text of the first `PREVIEW_LIMIT` bytes (`None` for binary files)
+    /// 最初の`PREVIEW_LIMIT`バイトのテキスト（バイナリファイルは`None`）
+    pub text: Option<String>,
+
+    /// Whether the file is longer than the preview
+    /// ファイルがプレビューより長いか
+    pub truncated: bool,
+}
+
+/// What a container path turned out to be
+/// コンテナのパスが何であったか
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrowserContent {
+    /// A directory and its children
+    /// ディレクトリとその子
+    Directory(DirectoryListing),
+
+    /// A file and the beginning of its content
+    /// ファイルとその内容の先頭部分
+    File(FilePreview),
+}
+
+/// Service opening container paths for the file browser
+/// ファイルブラウザ用にコンテナのパスを開くサービス
+///
+/// Everything is read through the engine's archive endpoint, so it works
+/// for stopped containers and images without a shell. Nothing is ever
+/// written to the container.
+///
+/// すべてエンジンのアーカイブエンドポイント経由で読み込むため、停止中の
+/// コンテナやシェルのないイメージでも動作します。コンテナへは何も書き込みません。
+#[derive(Clone)]
+pub struct FileBrowserService {
+    repository: Arc<dyn DockerRepository>,
+}
+
+impl FileBrowserService {
+    /// Create a service using `repository`
+    /// `repository`を使用するサービスを作成
+    #[must_use]
+    pub fn new(repository: Arc<dyn DockerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// List a directory or preview a file, following symbolic links
+    /// ディレクトリを一覧表示またはファイルをプレビュー（シンボリックリンクをたどる）
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - The path is relative, is a special file,
+    ///   or has too many levels of symbolic links
+    /// * Any error returned by the repository, e.g. for a missing path
+    pub async fn open(&self, id: &ContainerId, path: &str) -> DockaResult<BrowserContent> {
+        let path = path.trim();
+        if !path.starts_with('/') {
+            return Err(DockaError::invalid_input(
+                "Container path must be absolute (e.g. /etc)",
+            ));
+        }
+
+        let mut path = normalize_container_path(path);
+        for _ in 0..=MAX_LINK_HOPS {
+            match self.read(id, &path).await? {
+                Resolved::Content(content) => return Ok(content),
+                Resolved::Link(target) => path = resolve_link(&path, &target),
+            }
+        }
+        Err(DockaError::invalid_input(format!(
+            "Too many levels of symbolic links: {path}"
+        )))
+    }
+
+    /// Read the archive of one path, stopping as soon as enough was seen
+    /// 1つのパスのアーカイブを読み込み、十分に見た時点で止める
+    async fn read(&self, id: &ContainerId, path: &str) -> DockaResult<Resolved> {
+        let mut stream = self.repository.download_archive(id, path).await?;
+        let mut reader = TarReader::new();
+        let mut state: Option<ReadState> = None;
+
+        // Dropping the stream once done cancels the rest of the download
+        // 完了後にストリームを破棄すると残りのダウンロードは取り消される
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            let mut input = chunk.as_ref();
+            while let Some(event) = reader.next_event(&mut input)? {
+                let done = match (state.as_mut(), event) {
+                    (None, TarEvent::Entry(header)) => match header.kind {
+                        PathKind::Directory => {
+                            let root = archive_name(&header.path).to_string();
+                            state = Some(ReadState::Listing {
+                                root,
+                                listing: DirectoryListing {
+                                    path: path.to_string(),
+                                    entries: Vec::new(),
+                                    truncated: false,
+                                },
+                                scanned: 1,
+                            });
+                            false
+                        }
+                        PathKind::File | PathKind::HardLink => {
+                            let entry = file_entry(path, &header);
+                            let capacity = usize::try_from(header.size)
+                                .unwrap_or(PREVIEW_LIMIT)
+                                .min(PREVIEW_LIMIT);
+                            let empty = header.size == 0;
+                            state = Some(ReadState::Preview {
+                                entry,
+                                data: Vec::with_capacity(capacity),
+                            });
+                            empty
+                        }
+                        PathKind::Symlink => {
+                            return Ok(Resolved::Link(header.link_target.unwrap_or_default()));
+                        }
+                        PathKind::Other => {
+                            return Err(DockaError::invalid_input(format!(
+                                "Cannot preview special file: {path}"
+                            )));
+                        }
+                    },
+                    (
+                        Some(ReadState::Listing {
+                            root,
+                            listing,
+                            scanned,
+                        }),
+                        TarEvent::Entry(header),
+                    ) => {
+                        if let Some(name) = child_name(root, &header.path) {
+                            let child_path = join_path(path, name);
+                            listing.entries.push(file_entry(&child_path, &header));
+                        }
+                        *scanned += 1;
+                        listing.truncated = *scanned >= MAX_SCANNED_ENTRIES;
+                        listing.truncated
+                    }
+                    (Some(ReadState::Preview { data, .. }), TarEvent::Data(bytes)) => {
+                        let take = bytes.len().min(PREVIEW_LIMIT - data.len());
+                        data.extend_from_slice(&bytes[..take]);
+                        data.len() >= PREVIEW_LIMIT
+                    }
+                    (Some(ReadState::Preview { .. }), TarEvent::EntryEnd) => true,
+                    _ => false,
+                };
+                if done && let Some(state) = state.take() {
+                    return Ok(Resolved::Content(finish(state)));
+                }
+            }
+        }
+
+        state
+            .map(|state| Resolved::Content(finish(state)))
+            .ok_or_else(|| DockaError::invalid_input(format!("No such path in container: {path}")))
+    }
+}
+
+/// Outcome of reading one path
+/// 1つのパスを読み込んだ結果
+enum Resolved {
+    Content(BrowserContent),
+    Link(String),
+}
+
+/// Partial result while an archive is being read
+/// アーカイブ読み込み中の途中結果
+enum ReadState {
+    Listing {
+        root: String,
+        listing: DirectoryListing,
+        scanned: usize,
+    },
+    Preview {
+        entry: FileEntry,
+        data: Vec<u8>,
+    },
+}
+
+/// Turn the collected state into browser content
+/// 集めた状態をブラウザの内容に変換
+fn finish(state: ReadState) -> BrowserContent {
+    match state {
+        ReadState::Listing { mut listing, .. } => {
+            listing.entries.sort_by(|a, b| {
+                b.kind
+                    .is_directory()
+                    .cmp(&a.kind.is_directory())
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+            BrowserContent::Directory(listing)
+        }
+        ReadState::Preview { entry, data } => {
+            let truncated = entry.size > data.len() as u64;
+            // A NUL byte is the usual sign of a binary file
+            // NULバイトはバイナリファイルの一般的な目印
+            let text = (!data.contains(&0)).then(|| String::from_utf8_lossy(&data).into_owned());
+            BrowserContent::File(FilePreview {
+                entry,
+                text,
+                truncated,
+            })
+        }
+    }
+}
+
+/// Build an entry for `path` from its archive header
+/// アーカイブヘッダーから`path`のエントリを構築
+fn file_entry(path: &str, header: &TarHeader) -> FileEntry {
+    FileEntry {
+        name: base_name(path).to_string(),
+        path: path.to_string(),
+        kind: header.kind,
+        size: header.size,
+        mode: header.mode,
+        mtime: header.mtime,
+        link_target: header.link_target.clone(),
+    }
+}
+
+/// Archive path without a leading `./` or trailing `/`
+/// 先頭の`./`と末尾の`/`を除いたアーカイブパス
+fn archive_name(path: &str) -> &str {
+    let path = path.trim_end_matches('/');
+    let path = path.strip_prefix("./").unwrap_or(path);
+    if path == "." { "" } else { path }
+}
+
+/// Name of a direct child of `root`, or `None` for deeper entries
+/// `root`の直下の子の名前（より深いエントリは`None`）
+fn child_name<'a>(root: &str, path: &'a str) -> Option<&'a str> {
+    let path = archive_name(path);
+    let rest = if root.is_empty() {
+        path
+    } else {
+        path.strip_prefix(root)?.strip_prefix('/')?
+    };
+    (!rest.is_empty() && !rest.contains('/')).then_some(rest)
+}
+
+/// Join a normalized directory and a child name
+/// 正規化したディレクトリと子の名前を結合
+fn join_path(dir: &str, name: &str) -> String {
+    if dir == "/" {
+        format!("/{name}")
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+/// Resolve the target of the symbolic link at `link` to an absolute path
+/// `link`にあるシンボリックリンクのリンク先を絶対パスに解決
+fn resolve_link(link: &str, target: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    let base = if target.starts_with('/') {
+        String::new()
+    } else {
+        parent_dir(link)
+    };
+    for part in base.split('/').chain(target.split('/')) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use crate::utils::tar::{self, END_OF_ARCHIVE};
+
+    fn archive_of(entries: &[(TarHeader, &[u8])]) -> Vec<u8> {
+        let mut archive = Vec::new();
+        for (header, data) in entries {
+            archive.extend(header.encode().unwrap());
+            archive.extend(*data);
+            archive.resize(archive.len() + tar::padding(data.len() as u64), 0);
+        }
+        archive.extend(END_OF_ARCHIVE);
+        archive
+    }
+
+    fn link(path: &str, target: &str) -> TarHeader {
+        let mut header = TarHeader::new(path, PathKind::Symlink, 0);
+        header.link_target = Some(target.to_string());
+        header
+    }
+
+    async fn create_service() -> (FileBrowserService, Arc<MockDockerRepository>, ContainerId) {
+        let repository = Arc::new(MockDockerRepository::new());
+        repository
+            .add_container(
+                Container::builder()
+                    .id("web-1")
+                    .name("web")
+                    .image("nginx:latest")
+                    .status(ContainerStatus::Running)
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+        (
+            FileBrowserService::new(Arc::clone(&repository) as Arc<dyn DockerRepository>),
+            repository,
+            ContainerId::new("web-1").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_open_lists_direct_children_through_links() {
+        // Test that only direct children are listed, directories first, via a link
+        // リンク経由で直下の子のみがディレクトリ優先で一覧表示されることをテスト
+        let (service, repository, id) = create_service().await;
+        repository
+            .set_archive(
+                "web-1",
+                "/conf",
+                archive_of(&[(link("conf", "etc/nginx"), &[])]),
+            )
+            .await;
+        repository
+            .set_archive(
+                "web-1",
+                "/etc/nginx",
+                archive_of(&[
+                    (TarHeader::new("nginx/", PathKind::Directory, 0), &[]),
+                    (TarHeader::new("nginx/nginx.conf", PathKind::File, 2), b"ok"),
+                    (TarHeader::new("nginx/conf.d/", PathKind::Directory, 0), &[]),
+                    (
+                        TarHeader::new("nginx/conf.d/default.conf", PathKind::File, 0),
+                        &[],
+                    ),
+                    (link("nginx/mime", "../mime.types"), &[]),
+                ]),
+            )
+            .await;
+
+        let Ok(BrowserContent::Directory(listing)) = service.open(&id, "/conf/").await else {
+            panic!("expected a directory listing");
+        };
+        assert_eq!(listing.path, "/etc/nginx");
+        assert!(!listing.truncated);
+        let names: Vec<(&str, &str)> = listing
+            .entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.path.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("conf.d", "/etc/nginx/conf.d"),
+                ("mime", "/etc/nginx/mime"),
+                ("nginx.conf", "/etc/nginx/nginx.conf"),
+            ]
+        );
+        assert_eq!(
+            resolve_link("/etc/nginx/mime", "../mime.types"),
+            "/etc/mime.types"
+        );
+
+        assert!(service.open(&id, "etc").await.is_err());
+        assert!(service.open(&id, "/missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_open_previews_text_and_binary_files() {
+        // Test that previews are capped and binary content is not shown as text
+        // プレビューが上限で切られ、バイナリ内容がテキストとして表示されないことをテスト
+        let (service, repository, id) = create_service().await;
+        let large = vec![b'a'; PREVIEW_LIMIT + 10];
+        repository
+            .set_archive(
+                "web-1",
+                "/var/log/big.log",
+                archive_of(&[(
+                    TarHeader::new("big.log", PathKind::File, large.len() as u64),
+                    &large,
+                )]),
+            )
+            .await;
+        repository
+            .set_archive(
+                "web-1",
+                "/bin/app",
+                archive_of(&[(TarHeader::new("app", PathKind::File, 4), b"\x7fE\0F")]),
+            )
+            .await;
+
+        let Ok(BrowserContent::File(preview)) = service.open(&id, "/var/log/big.log").await else {
+            panic!("expected a file preview");
+        };
+        assert_eq!(preview.entry.name, "big.log");
+        assert!(preview.truncated);
+        assert_eq!(preview.text.map(|text| text.len()), Some(PREVIEW_LIMIT));
+
+        let Ok(BrowserContent::File(preview)) = service.open(&id, "/bin/app").await else {
+            panic!("expected a file preview");
+        };
+        assert!(!preview.truncated);
+        assert_eq!(preview.text, None);
+    }
+
+    #[test]
+    fn test_child_name_handles_root_archives() {
+        // Test direct-child detection for named and root archives
+        // 名前付きアーカイブとルートアーカイブでの直下の子の判定をテスト
+        assert_eq!(child_name("nginx", "nginx/conf.d/"), Some("conf.d"));
+        assert_eq!(child_name("nginx", "nginx/conf.d/a.conf"), None);
+        assert_eq!(child_name("nginx", "nginx2/a"), None);
+        assert_eq!(child_name(archive_name("./"), "./etc/"), Some("etc"));
+        assert_eq!(child_name(archive_name("/"), "etc"), Some("etc"));
+    }
+}
@@ -0,0 +1,36 @@
+// src/app/mod.rs
+// Application layer module
+// アプリケーション層モジュール
+
+//! Application services that combine repository calls into use cases.
+//!
+//! Services here hold no UI state; the TUI calls them and renders the
+//! plain data they return.
+//!
+//! リポジトリ呼び出しをユースケースにまとめるアプリケーションサービス。
+//! ここのサービスは`UI`の状態を持たず、`TUI`がそれらを呼び出し、
+//! 返されたデータを描画します。
+
+/// Guided cleanup of unused Docker objects
+/// 未使用`Docker`オブジェクトのガイド付きクリーンアップ
+pub mod prune_service;
+
+/// Streaming copies between containers and the local filesystem
+/// コンテナとローカルファイルシステム間のストリーミングコピー
+pub mod copy_service;
+
+/// Read-only browsing of a container's filesystem
+/// コンテナのファイルシステムの読み取り専用ブラウズ
+pub mod file_browser_service;
+
+/// Loading of the docka configuration file
+/// docka設定ファイルの読み込み
+pub mod config_service;
+
+/// Local TCP port forwarding to container ports
+/// コンテナポートへのローカルTCPポートフォワーディング
+pub mod port_forward_service;
+
+// /// Container use cases (Phase 2)
+// /// コンテナのユースケース（Phase 2）
+// pub mod container_service;
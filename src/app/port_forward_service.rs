@@ -0,0 +1,302 @@
+// src/app/port_forward_service.rs
+// Local TCP port forwarding to container ports
+// コンテナポートへのローカルTCPポートフォワーディング
+
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::io::copy_bidirectional;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::sleep;
+use tracing::{debug, info, warn};
+
+use crate::error::{DockaError, DockaResult};
+
+/// Pause after a failed accept before listening again
+/// 受け付けに失敗した後、再び待ち受けるまでの待機時間
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// A running forward from a local port to a container endpoint
+/// ローカルポートからコンテナのエンドポイントへの実行中のフォワード
+///
+/// Dropping the forward closes the listener and every open connection.
+/// フォワードを破棄するとリスナーと全ての接続が閉じられます。
+#[derive(Debug)]
+pub struct PortForward {
+    /// Identifier used to stop the forward
+    /// フォワードの停止に使用する識別子
+    pub id: u64,
+
+    /// Local address the listener accepts connections on
+    /// リスナーが接続を受け付けるローカルアドレス
+    pub local_addr: SocketAddr,
+
+    /// Container endpoint connections are proxied to
+    /// 接続の転送先のコンテナのエンドポイント
+    pub target: SocketAddr,
+
+    /// What is forwarded, e.g. `db 9229/tcp`
+    /// 転送対象（例: `db 9229/tcp`）
+    pub label: String,
+
+    /// Connections currently proxied
+    /// 現在転送中の接続
+    open_connections: Arc<AtomicUsize>,
+
+    /// Task accepting connections
+    /// 接続を受け付けるタスク
+    task: JoinHandle<()>,
+}
+
+impl PortForward {
+    /// Number of connections currently proxied
+    /// 現在転送中の接続の数
+    #[must_use]
+    pub fn open_connections(&self) -> usize {
+        self.open_connections.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for PortForward {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Forwards local TCP ports to container ports, like `kubectl port-forward`
+/// `kubectl port-forward`のようにローカルのTCPポートをコンテナのポートに転送
+///
+/// Listeners bind to `127.0.0.1` only, so a forward never exposes a
+/// container port beyond the local machine.
+///
+/// リスナーは`127.0.0.1`にのみバインドするため、フォワードがコンテナのポートを
+/// ローカルマシンの外に公開することはありません。
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use docka::app::port_forward_service::PortForwardService;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut forwards = PortForwardService::new();
+/// let forward = forwards
+///     .start(9229, "172.17.0.3:9229".parse()?, "api 9229/tcp")
+///     .await?;
+/// println!("{} → {}", forward.local_addr, forward.target);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct PortForwardService {
+    /// Running forwards, oldest first
+    /// 実行中のフォワード（古い順）
+    forwards: Vec<PortForward>,
+
+    /// Identifier of the next forward
+    /// 次のフォワードの識別子
+    next_id: u64,
+}
+
+impl PortForwardService {
+    /// Create a service without forwards
+    /// フォワードのないサービスを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Listen on `local_port` and proxy every connection to `target`
+    /// `local_port`で待ち受け、全ての接続を`target`に転送
+    ///
+    /// A taken `local_port` is an error rather than silently replaced, so the
+    /// forward is never found on an unexpected port; `0` picks a free port.
+    /// The port in use is in the returned `local_addr`.
+    /// 使用中の`local_port`は暗黙に置き換えずエラーとするため、フォワードが
+    /// 予期しないポートで待ち受けることはありません。`0`は空いているポートを選びます。
+    /// 使用するポートは返される`local_addr`にあります。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When `local_port` is already in use
+    /// * `DockaError::Io` - When the port cannot be bound for another reason
+    pub async fn start(
+        &mut self,
+        local_port: u16,
+        target: SocketAddr,
+        label: impl Into<String>,
+    ) -> DockaResult<&PortForward> {
+        let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, local_port)).await {
+            Ok(listener) => listener,
+            Err(error) if error.kind() == ErrorKind::AddrInUse => {
+                return Err(DockaError::invalid_input(format!(
+                    "Local port {local_port} is already in use"
+                )));
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let local_addr = listener.local_addr()?;
+        let label = label.into();
+        info!("Forwarding {local_addr} to {target} ({label})");
+
+        let open_connections = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn(Self::accept_loop(
+            listener,
+            target,
+            Arc::clone(&open_connections),
+        ));
+
+        self.next_id += 1;
+        self.forwards.push(PortForward {
+            id: self.next_id,
+            local_addr,
+            target,
+            label,
+            open_connections,
+            task,
+        });
+        Ok(&self.forwards[self.forwards.len() - 1])
+    }
+
+    /// Stop a forward, closing its listener and connections
+    /// フォワードを停止し、リスナーと接続を閉じる
+    ///
+    /// Returns `false` when no forward has that identifier.
+    /// その識別子のフォワードがない場合は`false`を返します。
+    pub fn stop(&mut self, id: u64) -> bool {
+        let count = self.forwards.len();
+        self.forwards.retain(|forward| forward.id != id);
+        self.forwards.len() != count
+    }
+
+    /// Running forwards, oldest first
+    /// 実行中のフォワード（古い順）
+    #[must_use]
+    pub fn forwards(&self) -> &[PortForward] {
+        &self.forwards
+    }
+
+    /// Accept connections until the forward is stopped
+    /// フォワードが停止されるまで接続を受け付ける
+    ///
+    /// Connections run in a `JoinSet` owned by this task, so aborting the
+    /// task closes them as well.
+    /// 接続はこのタスクが所有する`JoinSet`で実行されるため、タスクを中断すると
+    /// それらも閉じられます。
+    async fn accept_loop(
+        listener: TcpListener,
+        target: SocketAddr,
+        open_connections: Arc<AtomicUsize>,
+    ) {
+        let mut connections = JoinSet::new();
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (mut inbound, peer) = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(error) => {
+                            // Back off so a persistent error (e.g. EMFILE) does not spin
+                            // 永続的なエラー（例: EMFILE）で空回りしないよう待機する
+                            warn!("Cannot accept connection for {target}: {error}");
+                            sleep(ACCEPT_RETRY_DELAY).await;
+                            continue;
+                        }
+                    };
+                    let open_connections = Arc::clone(&open_connections);
+                    open_connections.fetch_add(1, Ordering::Relaxed);
+                    connections.spawn(async move {
+                        match TcpStream::connect(target).await {
+                            Ok(mut outbound) => {
+                                if let Err(error) =
+                                    copy_bidirectional(&mut inbound, &mut outbound).await
+                                {
+                                    debug!("Forwarded connection from {peer} ended: {error}");
+                                }
+                            }
+                            Err(error) => debug!("Cannot reach {target} for {peer}: {error}"),
+                        }
+                        open_connections.fetch_sub(1, Ordering::Relaxed);
+                    });
+                }
+                // Reap finished connections so the set does not grow
+                // セットが大きくならないよう終了した接続を回収する
+                Some(_) = connections.join_next(), if !connections.is_empty() => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Start a TCP echo server standing in for the container endpoint
+    /// コンテナのエンドポイントの代わりとなるTCPエコーサーバーを起動
+    async fn start_echo_server() -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let (mut reader, mut writer) = stream.split();
+                    let _ = tokio::io::copy(&mut reader, &mut writer).await;
+                });
+            }
+        });
+        addr
+    }
+
+    async fn echo_through(addr: SocketAddr, message: &[u8]) -> std::io::Result<Vec<u8>> {
+        let mut stream = TcpStream::connect(addr).await?;
+        stream.write_all(message).await?;
+        let mut reply = vec![0; message.len()];
+        stream.read_exact(&mut reply).await?;
+        Ok(reply)
+    }
+
+    #[tokio::test]
+    async fn test_forward_proxies_until_stopped() {
+        // Test that connections reach the echo server until the forward is stopped
+        // フォワードが停止されるまで接続がエコーサーバーに届くことをテスト
+        let echo = start_echo_server().await;
+        let mut service = PortForwardService::new();
+        let forward = service.start(0, echo, "echo 7/tcp").await.unwrap();
+        let (id, local_addr) = (forward.id, forward.local_addr);
+        assert!(local_addr.ip().is_loopback());
+        assert_eq!(forward.target, echo);
+
+        assert_eq!(echo_through(local_addr, b"ping").await.unwrap(), b"ping");
+        assert_eq!(echo_through(local_addr, b"pong").await.unwrap(), b"pong");
+        assert_eq!(service.forwards().len(), 1);
+
+        assert!(service.stop(id));
+        assert!(!service.stop(id));
+        assert!(service.forwards().is_empty());
+        tokio::task::yield_now().await;
+        assert!(echo_through(local_addr, b"ping").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_taken_local_port_is_reported() {
+        // Test that a taken local port is reported instead of replaced by a free one
+        // 使用中のローカルポートが空いているポートに置き換えられず報告されることをテスト
+        let echo = start_echo_server().await;
+        let taken = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let taken_port = taken.local_addr().unwrap().port();
+
+        let mut service = PortForwardService::new();
+        let error = service.start(taken_port, echo, "echo").await.unwrap_err();
+        assert!(matches!(error, DockaError::InvalidInput { .. }));
+        assert!(error.to_string().contains(&taken_port.to_string()));
+        assert!(service.forwards().is_empty());
+
+        let forward = service.start(0, echo, "echo").await.unwrap();
+        assert_ne!(forward.local_addr.port(), taken_port);
+        let local_addr = forward.local_addr;
+        assert_eq!(echo_through(local_addr, b"hi").await.unwrap(), b"hi");
+    }
+}
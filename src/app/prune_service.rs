@@ -0,0 +1,817 @@
+// src/app/prune_service.rs
+// Guided cleanup of unused Docker objects
+// 未使用Dockerオブジェクトのガイド付きクリーンアップ
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::{info, warn};
+
+use crate::domain::entities::{Container, ContainerFilter};
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::ContainerId;
+use crate::error::{DockaError, DockaResult};
+
+/// Kind of objects a prune can target
+/// 削除の対象にできるオブジェクトの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PruneTarget {
+    /// Stopped, exited, created or dead containers
+    /// 停止・終了・作成済み・異常終了のコンテナ
+    StoppedContainers,
+
+    /// Untagged images not used by any container
+    /// どのコンテナにも使われていないタグなしイメージ
+    DanglingImages,
+
+    /// Every image not used by any container, tagged or not
+    /// タグの有無にかかわらず、どのコンテナにも使われていない全イメージ
+    UnusedImages,
+
+    /// Unreferenced volumes created without a name
+    /// 名前なしで作成された参照されていないボリューム
+    AnonymousVolumes,
+
+    /// Every unreferenced volume, including named ones
+    /// 名前付きを含む参照されていない全ボリューム
+    UnusedVolumes,
+
+    /// Custom networks without attached containers
+    /// コンテナが接続していないカスタムネットワーク
+    UnusedNetworks,
+
+    /// Build cache records not used by a running build
+    /// 実行中のビルドで使われていないビルドキャッシュレコード
+    BuildCache,
+}
+
+impl PruneTarget {
+    /// Every target in the order they are listed and removed
+    /// 一覧表示と削除の順序での全対象
+    pub const ALL: [Self; 7] = [
+        Self::StoppedContainers,
+        Self::DanglingImages,
+        Self::UnusedImages,
+        Self::AnonymousVolumes,
+        Self::UnusedVolumes,
+        Self::UnusedNetworks,
+        Self::BuildCache,
+    ];
+
+    /// Display label
+    /// 表示ラベル
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::StoppedContainers => "Stopped containers",
+            Self::DanglingImages => "Dangling images",
+            Self::UnusedImages => "Unused images (including tagged)",
+            Self::AnonymousVolumes => "Unused anonymous volumes",
+            Self::UnusedVolumes => "Unused volumes (including named)",
+            Self::UnusedNetworks => "Unused networks",
+            Self::BuildCache => "Build cache",
+        }
+    }
+
+    /// Targets selected by default, the same as `docker system prune`
+    /// デフォルトで選択される対象（`docker system prune`と同じ）
+    ///
+    /// Volumes and tagged images may hold data that is expensive to recreate,
+    /// so they have to be opted into.
+    ///
+    /// ボリュームやタグ付きイメージは再作成のコストが高いデータを持つ可能性が
+    /// あるため、明示的に選択する必要があります。
+    #[must_use]
+    pub fn defaults() -> BTreeSet<Self> {
+        BTreeSet::from([
+            Self::StoppedContainers,
+            Self::DanglingImages,
+            Self::UnusedNetworks,
+            Self::BuildCache,
+        ])
+    }
+}
+
+/// What to prune and which objects to keep
+/// 何を削除し、どのオブジェクトを残すか
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneOptions {
+    /// Selected object kinds
+    /// 選択されたオブジェクトの種類
+    pub targets: BTreeSet<PruneTarget>,
+
+    /// Only prune objects older than this (last use for build cache)
+    /// これより古いオブジェクトのみ削除（ビルドキャッシュは最終使用日時）
+    pub older_than: Option<Duration>,
+
+    /// Only prune objects carrying all of these labels
+    /// これら全てのラベルを持つオブジェクトのみ削除
+    pub labels: HashMap<String, String>,
+}
+
+impl Default for PruneOptions {
+    fn default() -> Self {
+        Self {
+            targets: PruneTarget::defaults(),
+            older_than: None,
+            labels: HashMap::new(),
+        }
+    }
+}
+
+impl PruneOptions {
+    /// Parse a label filter such as `env=dev,team=web`
+    /// `env=dev,team=web`のようなラベルフィルタを解析
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When an entry is not `key=value`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docka::app::prune_service::PruneOptions;
+    ///
+    /// let labels = PruneOptions::parse_labels("env=dev, team=web").unwrap();
+    /// assert_eq!(labels["team"], "web");
+    /// assert!(PruneOptions::parse_labels("env").is_err());
+    /// ```
+    pub fn parse_labels(text: &str) -> DockaResult<HashMap<String, String>> {
+        text.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    Ok((key.trim().to_string(), value.trim().to_string()))
+                }
+                _ => Err(DockaError::invalid_input(format!(
+                    "Invalid label filter '{entry}' (expected key=value)"
+                ))),
+            })
+            .collect()
+    }
+
+    /// Latest creation time an object may have to be pruned
+    /// 削除対象となるオブジェクトの作成日時の上限
+    #[must_use]
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.older_than.map(|age| now - age)
+    }
+
+    /// Check the age and label filters against an object
+    /// オブジェクトに対して経過時間とラベルのフィルタをチェック
+    ///
+    /// Objects of unknown age never match an age filter.
+    /// 経過時間が不明なオブジェクトは経過時間フィルタに一致しません。
+    fn matches(
+        &self,
+        cutoff: Option<DateTime<Utc>>,
+        created_at: Option<DateTime<Utc>>,
+        labels: &HashMap<String, String>,
+    ) -> bool {
+        let old_enough = match (cutoff, created_at) {
+            (None, _) => true,
+            (Some(cutoff), Some(created_at)) => created_at < cutoff,
+            (Some(_), None) => false,
+        };
+        old_enough
+            && self
+                .labels
+                .iter()
+                .all(|(key, value)| labels.get(key) == Some(value))
+    }
+
+    fn includes(&self, target: PruneTarget) -> bool {
+        self.targets.contains(&target)
+    }
+}
+
+/// Kind of a prune candidate
+/// 削除候補の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PruneKind {
+    /// Container
+    /// コンテナ
+    Container,
+
+    /// Image
+    /// イメージ
+    Image,
+
+    /// Volume
+    /// ボリューム
+    Volume,
+
+    /// Network
+    /// ネットワーク
+    Network,
+
+    /// Build cache record
+    /// ビルドキャッシュレコード
+    BuildCache,
+}
+
+impl PruneKind {
+    /// Display label
+    /// 表示ラベル
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Container => "container",
+            Self::Image => "image",
+            Self::Volume => "volume",
+            Self::Network => "network",
+            Self::BuildCache => "build cache",
+        }
+    }
+}
+
+/// An object that the prune would remove
+/// 削除で除去されるオブジェクト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneCandidate {
+    /// Object kind
+    /// オブジェクトの種類
+    pub kind: PruneKind,
+
+    /// Identifier used for removal (name for volumes)
+    /// 削除に使用する識別子（ボリュームは名前）
+    pub id: String,
+
+    /// Display name
+    /// 表示名
+    pub name: String,
+
+    /// Bytes freed by removing it, `None` when unknown
+    /// 削除で解放されるバイト数（不明な場合は`None`）
+    pub size: Option<u64>,
+
+    /// Creation time (last use for build cache), if known
+    /// 作成日時（ビルドキャッシュは最終使用日時、判明している場合）
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Container> for PruneCandidate {
+    fn from(container: &Container) -> Self {
+        Self {
+            kind: PruneKind::Container,
+            id: container.id.as_str().to_string(),
+            name: container.display_name().to_string(),
+            // Writable layer sizes are not listed by default
+            // 書き込み可能レイヤーのサイズはデフォルトでは一覧に含まれない
+            size: None,
+            created_at: Some(container.created_at),
+        }
+    }
+}
+
+/// Objects a prune would remove, shown before anything is deleted
+/// 削除前に表示する、削除で除去されるオブジェクト
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrunePreview {
+    /// Candidates in removal order
+    /// 削除順の候補
+    pub candidates: Vec<PruneCandidate>,
+}
+
+impl PrunePreview {
+    /// Check whether there is nothing to remove
+    /// 削除するものがないかチェック
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    /// Bytes freed by removing every candidate of known size
+    /// サイズが判明している全候補の削除で解放されるバイト数
+    #[must_use]
+    pub fn total_reclaimable(&self) -> u64 {
+        self.candidates.iter().filter_map(|c| c.size).sum()
+    }
+
+    /// Number of candidates whose size the engine did not report
+    /// エンジンがサイズを報告しなかった候補の数
+    #[must_use]
+    pub fn unknown_size_count(&self) -> usize {
+        self.candidates.iter().filter(|c| c.size.is_none()).count()
+    }
+
+    /// Number of candidates of a kind
+    /// 種類ごとの候補数
+    #[must_use]
+    pub fn count(&self, kind: PruneKind) -> usize {
+        self.candidates.iter().filter(|c| c.kind == kind).count()
+    }
+}
+
+/// Result of removing one candidate
+/// 1つの候補の削除結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneItemResult {
+    /// The candidate
+    /// 候補
+    pub candidate: PruneCandidate,
+
+    /// Why removal failed (`None` on success)
+    /// 削除に失敗した理由（成功時は`None`）
+    pub error: Option<String>,
+}
+
+impl PruneItemResult {
+    /// Check whether the candidate was removed
+    /// 候補が削除されたかチェック
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Per-item outcome of an executed prune
+/// 実行された削除の項目ごとの結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// Results in removal order
+    /// 削除順の結果
+    pub results: Vec<PruneItemResult>,
+}
+
+impl PruneReport {
+    /// Number of removed objects
+    /// 削除されたオブジェクト数
+    #[must_use]
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|r| r.is_success()).count()
+    }
+
+    /// Number of objects that could not be removed
+    /// 削除できなかったオブジェクト数
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+
+    /// Bytes freed by the removed objects of known size
+    /// サイズが判明している削除済みオブジェクトで解放されたバイト数
+    #[must_use]
+    pub fn reclaimed(&self) -> u64 {
+        self.results
+            .iter()
+            .filter(|r| r.is_success())
+            .filter_map(|r| r.candidate.size)
+            .sum()
+    }
+}
+
+/// Plans and runs cleanups of unused Docker objects
+/// 未使用`Docker`オブジェクトのクリーンアップを計画・実行
+///
+/// `preview` only reads from the engine; nothing is removed until the
+/// previewed candidates are passed to `execute`, which removes them one by
+/// one and keeps going when a single removal fails.
+///
+/// `preview`はエンジンから読み取るのみで、プレビューした候補が`execute`に
+/// 渡されるまで何も削除されません。`execute`は候補を1つずつ削除し、
+/// 個別の削除が失敗しても処理を続けます。
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use docka::app::prune_service::{PruneOptions, PruneService};
+/// use docka::BollardDockerRepository;
+///
+/// # async fn example() -> docka::DockaResult<()> {
+/// let service = PruneService::new(Arc::new(BollardDockerRepository::new().await?));
+/// let preview = service.preview(&PruneOptions::default()).await?;
+/// println!("{} objects, {} bytes", preview.candidates.len(), preview.total_reclaimable());
+///
+/// let report = service.execute(&preview).await;
+/// println!("{} removed, {} failed", report.succeeded(), report.failed());
+/// # Ok(())
+/// # }
+/// ```
+pub struct PruneService {
+    repository: Arc<dyn DockerRepository>,
+}
+
+impl PruneService {
+    /// Create a service operating on the given repository
+    /// 指定したリポジトリを操作するサービスを作成
+    #[must_use]
+    pub fn new(repository: Arc<dyn DockerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Stopped containers matching the label and age filters
+    /// ラベルと経過時間フィルタに一致する停止中のコンテナ
+    async fn container_candidates(
+        &self,
+        options: &PruneOptions,
+        cutoff: Option<DateTime<Utc>>,
+    ) -> DockaResult<Vec<PruneCandidate>> {
+        let filter = ContainerFilter {
+            labels: options.labels.clone(),
+            created_before: cutoff,
+            ..ContainerFilter::default()
+        };
+        let containers = self.repository.list_containers_filtered(&filter).await?;
+
+        // The same states `docker container prune` removes
+        // `docker container prune`が削除するのと同じ状態
+        Ok(containers
+            .iter()
+            .filter(|c| c.can_start())
+            .map(PruneCandidate::from)
+            .collect())
+    }
+
+    /// Containers that are not running and match `filter`, longest exited first
+    /// 実行中でなく`filter`に一致するコンテナ（終了が古い順）
+    ///
+    /// Containers with an unknown exit time follow, oldest created first.
+    /// 終了日時が不明なコンテナは後に続き、作成が古い順に並びます。
+    ///
+    /// # Errors
+    /// * Any error returned while listing containers
+    pub async fn stale_containers(&self, filter: &ContainerFilter) -> DockaResult<Vec<Container>> {
+        let mut containers: Vec<Container> = self
+            .repository
+            .list_containers_filtered(filter)
+            .await?
+            .into_iter()
+            .filter(Container::can_start)
+            .collect();
+        containers.sort_by_key(|c| (c.finished_at.is_none(), c.finished_at, c.created_at));
+        Ok(containers)
+    }
+
+    /// List what a prune with these options would remove
+    /// これらのオプションでの削除が除去するものを一覧表示
+    ///
+    /// # Errors
+    /// * Any error returned while listing containers, images, volumes,
+    ///   networks or build cache
+    pub async fn preview(&self, options: &PruneOptions) -> DockaResult<PrunePreview> {
+        let cutoff = options.cutoff(Utc::now());
+        let mut candidates = Vec::new();
+
+        if options.includes(PruneTarget::StoppedContainers) {
+            candidates.extend(self.container_candidates(options, cutoff).await?);
+        }
+
+        if options.includes(PruneTarget::DanglingImages)
+            || options.includes(PruneTarget::UnusedImages)
+        {
+            let all = options.includes(PruneTarget::UnusedImages);
+            let images = self.repository.list_images().await?;
+            candidates.extend(
+                images
+                    .iter()
+                    .filter(|i| !i.in_use && (all || i.is_dangling()))
+                    .filter(|i| options.matches(cutoff, Some(i.created_at), &i.labels))
+                    .map(|i| PruneCandidate {
+                        kind: PruneKind::Image,
+                        id: i.id.clone(),
+                        name: if i.is_dangling() {
+                            format!("<none> ({})", i.short_id())
+                        } else {
+                            i.full_name_explicit()
+                        },
+                        size: Some(i.size),
+                        created_at: Some(i.created_at),
+                    }),
+            );
+        }
+
+        if options.includes(PruneTarget::AnonymousVolumes)
+            || options.includes(PruneTarget::UnusedVolumes)
+        {
+            let all = options.includes(PruneTarget::UnusedVolumes);
+            let volumes = self.repository.list_volumes().await?;
+            candidates.extend(
+                volumes
+                    .iter()
+                    .filter(|v| !v.is_in_use() && (all || v.is_anonymous()))
+                    .filter(|v| options.matches(cutoff, v.created_at, &v.labels))
+                    .map(|v| PruneCandidate {
+                        kind: PruneKind::Volume,
+                        id: v.name.clone(),
+                        name: v.name.clone(),
+                        size: v.size,
+                        created_at: v.created_at,
+                    }),
+            );
+        }
+
+        if options.includes(PruneTarget::UnusedNetworks) {
+            let networks = self.repository.list_networks().await?;
+            candidates.extend(
+                networks
+                    .iter()
+                    .filter(|n| !n.is_in_use() && !n.is_predefined())
+                    .filter(|n| options.matches(cutoff, n.created_at, &n.labels))
+                    .map(|n| PruneCandidate {
+                        kind: PruneKind::Network,
+                        id: n.id.clone(),
+                        name: n.name.clone(),
+                        // Networks hold no disk space
+                        // ネットワークはディスク容量を持たない
+                        size: Some(0),
+                        created_at: n.created_at,
+                    }),
+            );
+        }
+
+        if options.includes(PruneTarget::BuildCache) {
+            let records = self.repository.list_build_cache().await?;
+            let no_labels = HashMap::new();
+            candidates.extend(
+                records
+                    .iter()
+                    .filter(|r| !r.in_use)
+                    .filter(|r| options.matches(cutoff, r.last_activity(), &no_labels))
+                    .map(|r| PruneCandidate {
+                        kind: PruneKind::BuildCache,
+                        id: r.id.clone(),
+                        name: if r.description.is_empty() {
+                            r.short_id().to_string()
+                        } else {
+                            r.description.clone()
+                        },
+                        // Shared records are freed with the records owning them
+                        // 共有レコードは所有するレコードとともに解放される
+                        size: Some(if r.shared { 0 } else { r.size }),
+                        created_at: r.last_activity(),
+                    }),
+            );
+        }
+
+        Ok(PrunePreview { candidates })
+    }
+
+    /// Remove every previewed candidate and report each outcome
+    /// プレビューした全候補を削除し、それぞれの結果を報告
+    ///
+    /// Containers go first so that the images, volumes and networks they
+    /// held can be removed afterwards.
+    ///
+    /// コンテナを最初に削除し、それらが保持していたイメージ、ボリューム、
+    /// ネットワークを後から削除できるようにします。
+    pub async fn execute(&self, preview: &PrunePreview) -> PruneReport {
+        let mut results = Vec::with_capacity(preview.candidates.len());
+        for candidate in &preview.candidates {
+            let error = self.remove(candidate).await.err().map(|e| {
+                warn!(
+                    "Failed to remove {} {}: {}",
+                    candidate.kind.label(),
+                    candidate.name,
+                    e
+                );
+                e.to_string()
+            });
+            results.push(PruneItemResult {
+                candidate: candidate.clone(),
+                error,
+            });
+        }
+
+        let report = PruneReport { results };
+        info!(
+            "Prune finished: {} removed, {} failed",
+            report.succeeded(),
+            report.failed()
+        );
+        report
+    }
+
+    async fn remove(&self, candidate: &PruneCandidate) -> DockaResult<()> {
+        match candidate.kind {
+            PruneKind::Container => {
+                let id = ContainerId::new(candidate.id.clone())?;
+                self.repository.remove_container(&id, false).await
+            }
+            PruneKind::Image => self.repository.remove_image(&candidate.id, false).await,
+            PruneKind::Volume => self.repository.remove_volume(&candidate.id).await,
+            PruneKind::Network => self.repository.remove_network(&candidate.id).await,
+            PruneKind::BuildCache => self.repository.remove_build_cache(&candidate.id).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::{BuildCacheEntry, Image, Network, Volume};
+    use crate::domain::value_objects::ContainerStatus;
+
+    async fn create_repository() -> Arc<MockDockerRepository> {
+        let repo = Arc::new(MockDockerRepository::new());
+        let days_ago = |days| Utc::now() - Duration::days(days);
+        let container = |id: &str, status, days| {
+            Container::builder()
+                .id(id)
+                .name(id)
+                .image("nginx:latest")
+                .status(status)
+                .created_at(days_ago(days))
+                .label("env", "dev")
+                .build()
+                .unwrap()
+        };
+        repo.add_container(container("old-exited", ContainerStatus::Stopped, 10))
+            .await;
+        repo.add_container(container("new-exited", ContainerStatus::Stopped, 0))
+            .await;
+        repo.add_container(container("running", ContainerStatus::Running, 10))
+            .await;
+
+        let image = |id: &str, repository: &str, in_use| {
+            Image::builder()
+                .id(id)
+                .repository(repository)
+                .size(1_000)
+                .created_at(days_ago(10))
+                .in_use(in_use)
+                .build()
+                .unwrap()
+        };
+        repo.add_image(image("sha256:dangling", "<none>", false))
+            .await;
+        repo.add_image(image("sha256:tagged", "redis", false)).await;
+        repo.add_image(image("sha256:used", "nginx", true)).await;
+
+        repo.add_volume(
+            Volume::builder()
+                .name("a".repeat(64))
+                .size(500)
+                .ref_count(0)
+                .build()
+                .unwrap(),
+        )
+        .await;
+        repo.add_volume(
+            Volume::builder()
+                .name("named")
+                .size(700)
+                .ref_count(0)
+                .build()
+                .unwrap(),
+        )
+        .await;
+
+        repo.add_network(Network::builder().id("n1").name("bridge").build().unwrap())
+            .await;
+        repo.add_network(Network::builder().id("n2").name("old-app").build().unwrap())
+            .await;
+
+        repo.add_build_cache(BuildCacheEntry {
+            id: "cache1".to_string(),
+            size: 2_000,
+            last_used_at: Some(days_ago(20)),
+            ..BuildCacheEntry::default()
+        })
+        .await;
+
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_preview_default_targets() {
+        // Test that the defaults match `docker system prune`
+        // デフォルトが`docker system prune`と一致することをテスト
+        let repo = create_repository().await;
+        let service = PruneService::new(repo);
+
+        let preview = service.preview(&PruneOptions::default()).await.unwrap();
+        let names: Vec<&str> = preview.candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "old-exited",
+                "new-exited",
+                "<none> (dangling)",
+                "old-app",
+                "cache1"
+            ]
+        );
+        assert_eq!(preview.count(PruneKind::Container), 2);
+        assert_eq!(preview.total_reclaimable(), 3_000);
+        assert_eq!(preview.unknown_size_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_preview_age_and_label_filters() {
+        // Test that age and label filters narrow the candidates
+        // 経過時間とラベルのフィルタが候補を絞り込むことをテスト
+        let repo = create_repository().await;
+        let service = PruneService::new(repo);
+
+        let options = PruneOptions {
+            targets: PruneTarget::ALL.into_iter().collect(),
+            older_than: Some(Duration::days(5)),
+            labels: HashMap::new(),
+        };
+        let preview = service.preview(&options).await.unwrap();
+        let names: Vec<&str> = preview.candidates.iter().map(|c| c.name.as_str()).collect();
+        // Volumes and networks without a creation time are kept
+        // 作成日時のないボリュームとネットワークは残される
+        assert_eq!(
+            names,
+            vec!["old-exited", "<none> (dangling)", "redis:latest", "cache1"]
+        );
+
+        let options = PruneOptions {
+            labels: PruneOptions::parse_labels("env=dev").unwrap(),
+            ..options
+        };
+        let preview = service.preview(&options).await.unwrap();
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].kind, PruneKind::Container);
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_each_item() {
+        // Test that every candidate gets a result and failures do not stop the run
+        // 全候補に結果があり、失敗で処理が止まらないことをテスト
+        let repo = create_repository().await;
+        let service = PruneService::new(Arc::clone(&repo) as Arc<dyn DockerRepository>);
+
+        let mut preview = service.preview(&PruneOptions::default()).await.unwrap();
+        preview.candidates.insert(
+            0,
+            PruneCandidate {
+                kind: PruneKind::Volume,
+                id: "missing".to_string(),
+                name: "missing".to_string(),
+                size: Some(100),
+                created_at: None,
+            },
+        );
+
+        let report = service.execute(&preview).await;
+        assert_eq!(report.results.len(), 6);
+        assert_eq!(report.failed(), 1);
+        assert_eq!(report.succeeded(), 5);
+        assert_eq!(report.reclaimed(), 3_000);
+        assert!(report.results[0].error.is_some());
+        assert_eq!(repo.container_count().await, 1);
+        assert!(repo.build_cache.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stale_containers_sorted_by_exit_time() {
+        // Test that running containers are skipped and the longest exited come first
+        // 実行中のコンテナが除外され、終了が最も古いものが先頭になることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        let exited = |id: &str, days: Option<i64>| {
+            let builder = Container::builder()
+                .id(id)
+                .image("runner:latest")
+                .status(ContainerStatus::Exited { exit_code: 1 })
+                .label("ci", "true");
+            match days {
+                Some(days) => builder.finished_at(Utc::now() - Duration::days(days)),
+                None => builder,
+            }
+            .build()
+            .unwrap()
+        };
+        repo.add_container(exited("recent", Some(1))).await;
+        repo.add_container(exited("unknown", None)).await;
+        repo.add_container(exited("oldest", Some(30))).await;
+        repo.add_container(
+            Container::builder()
+                .id("live")
+                .image("runner:latest")
+                .status(ContainerStatus::Running)
+                .label("ci", "true")
+                .build()
+                .unwrap(),
+        )
+        .await;
+        let service = PruneService::new(repo);
+
+        let filter = ContainerFilter {
+            labels: PruneOptions::parse_labels("ci=true").unwrap(),
+            ..ContainerFilter::default()
+        };
+        let ids: Vec<String> = service
+            .stale_containers(&filter)
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.id.as_str().to_string())
+            .collect();
+        assert_eq!(ids, vec!["oldest", "recent", "unknown"]);
+    }
+
+    #[test]
+    fn test_parse_labels_rejects_missing_key() {
+        // Test label filter parsing
+        // ラベルフィルタの解析をテスト
+        assert!(PruneOptions::parse_labels("").unwrap().is_empty());
+        assert!(PruneOptions::parse_labels("=dev").is_err());
+        assert_eq!(PruneOptions::parse_labels("a=1,b=").unwrap()["b"], "");
+    }
+}
@@ -0,0 +1,129 @@
+// src/domain/entities/archive.rs
+// Tar archives exchanged with a container's filesystem
+// コンテナのファイルシステムとやり取りするtarアーカイブ
+
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::error::DockaResult;
+
+/// Tar archive as a stream of chunks, as sent and received by the engine
+/// エンジンが送受信するチャンクのストリームとしてのtarアーカイブ
+///
+/// Archives are never collected in memory; each chunk is handed on as soon
+/// as it arrives so that copying large directories stays within the memory
+/// budget.
+///
+/// アーカイブはメモリ上に集めず、各チャンクを受け取り次第渡すため、
+/// 大きなディレクトリのコピーでもメモリ予算内に収まります。
+pub type ArchiveStream = Pin<Box<dyn Stream<Item = DockaResult<Bytes>> + Send>>;
+
+/// Kind of a filesystem entry inside a container or an archive
+/// コンテナ内またはアーカイブ内のファイルシステムエントリの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PathKind {
+    /// Regular file
+    /// 通常ファイル
+    File,
+
+    /// Directory
+    /// ディレクトリ
+    Directory,
+
+    /// Symbolic link
+    /// シンボリックリンク
+    Symlink,
+
+    /// Hard link to an earlier entry of the same archive
+    /// 同じアーカイブ内の前のエントリへのハードリンク
+    HardLink,
+
+    /// Device, FIFO or any other special file
+    /// デバイス、FIFO、その他の特殊ファイル
+    Other,
+}
+
+impl PathKind {
+    /// Check whether the entry is a directory
+    /// エントリがディレクトリかチェック
+    #[must_use]
+    pub const fn is_directory(self) -> bool {
+        matches!(self, Self::Directory)
+    }
+}
+
+/// File or directory inside a container, as described by its archive header
+/// アーカイブヘッダーが示すコンテナ内のファイルまたはディレクトリ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileEntry {
+    /// Last component of the path
+    /// パスの最後の要素
+    pub name: String,
+
+    /// Absolute path inside the container
+    /// コンテナ内の絶対パス
+    pub path: String,
+
+    /// Kind of entry
+    /// エントリの種類
+    pub kind: PathKind,
+
+    /// Size in bytes (0 for directories and links)
+    /// バイト単位のサイズ（ディレクトリとリンクは0）
+    pub size: u64,
+
+    /// Permission bits
+    /// パーミッションビット
+    pub mode: u32,
+
+    /// Modification time in seconds since the Unix epoch
+    /// `Unix`エポックからの秒数での更新日時
+    pub mtime: u64,
+
+    /// Target of a symbolic or hard link
+    /// シンボリックリンクまたはハードリンクのリンク先
+    pub link_target: Option<String>,
+}
+
+impl FileEntry {
+    /// Format the kind and permission bits the way `ls -l` does
+    /// 種類とパーミッションビットを`ls -l`と同じ形式でフォーマット
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docka::domain::entities::{FileEntry, PathKind};
+    ///
+    /// let entry = FileEntry {
+    ///     name: "nginx".to_string(),
+    ///     path: "/etc/nginx".to_string(),
+    ///     kind: PathKind::Directory,
+    ///     size: 0,
+    ///     mode: 0o755,
+    ///     mtime: 0,
+    ///     link_target: None,
+    /// };
+    /// assert_eq!(entry.permissions(), "drwxr-xr-x");
+    /// ```
+    #[must_use]
+    pub fn permissions(&self) -> String {
+        let kind = match self.kind {
+            PathKind::Directory => 'd',
+            PathKind::Symlink => 'l',
+            PathKind::File | PathKind::HardLink => '-',
+            PathKind::Other => '?',
+        };
+        let mut text = String::with_capacity(10);
+        text.push(kind);
+        for shift in [6, 3, 0] {
+            let bits = (self.mode >> shift) & 0o7;
+            text.push(if bits & 0o4 == 0 { '-' } else { 'r' });
+            text.push(if bits & 0o2 == 0 { '-' } else { 'w' });
+            text.push(if bits & 0o1 == 0 { '-' } else { 'x' });
+        }
+        text
+    }
+}
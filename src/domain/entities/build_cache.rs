@@ -0,0 +1,86 @@
+// src/domain/entities/build_cache.rs
+// BuildKit build cache records
+// BuildKitビルドキャッシュレコード
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A `BuildKit` build cache record, as reported by `/system/df`
+/// `/system/df`が報告する`BuildKit`ビルドキャッシュレコード
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildCacheEntry {
+    /// Cache record identifier
+    /// キャッシュレコード識別子
+    pub id: String,
+
+    /// Record type (e.g., "regular", "source.local", "exec.cachemount")
+    /// レコード種別（例："regular"、"source.local"、"exec.cachemount"）
+    pub cache_type: String,
+
+    /// Description of the build step that produced the record
+    /// レコードを生成したビルドステップの説明
+    pub description: String,
+
+    /// Disk space used in bytes
+    /// 使用しているディスク容量（バイト）
+    pub size: u64,
+
+    /// Whether a running build currently uses the record
+    /// 実行中のビルドが現在レコードを使用しているか
+    pub in_use: bool,
+
+    /// Whether the record is shared with other records
+    /// レコードが他のレコードと共有されているか
+    pub shared: bool,
+
+    /// Creation timestamp
+    /// 作成タイムスタンプ
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// Last time a build used the record
+    /// ビルドが最後にレコードを使用した日時
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl BuildCacheEntry {
+    /// Get short record ID (first 12 characters)
+    /// 短縮レコードIDを取得（最初の12文字）
+    #[must_use]
+    pub fn short_id(&self) -> &str {
+        let len = self.id.len().min(12);
+        &self.id[..len]
+    }
+
+    /// Last time the record was created or used
+    /// レコードが作成または使用された最後の日時
+    ///
+    /// Age filters use this, like `docker builder prune --filter until=...`.
+    /// `docker builder prune --filter until=...`と同様に経過時間フィルタで使用します。
+    #[must_use]
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at.or(self.created_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_activity_prefers_last_use() {
+        // Test that the last use wins over the creation time
+        // 最終使用日時が作成日時より優先されることをテスト
+        let created = Utc::now() - chrono::Duration::days(10);
+        let used = Utc::now() - chrono::Duration::days(1);
+        let mut entry = BuildCacheEntry {
+            id: "abcdef0123456789".to_string(),
+            created_at: Some(created),
+            ..BuildCacheEntry::default()
+        };
+        assert_eq!(entry.last_activity(), Some(created));
+        assert_eq!(entry.short_id(), "abcdef012345");
+
+        entry.last_used_at = Some(used);
+        assert_eq!(entry.last_activity(), Some(used));
+    }
+}
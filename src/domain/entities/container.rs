@@ -2,7 +2,8 @@
 // Container domain entity with business logic
 // ビジネスロジックを持つコンテナドメインエンティティ
 
-use crate::domain::value_objects::{ContainerId, ContainerStatus};
+use crate::domain::entities::filter_expression::FilterCondition;
+use crate::domain::value_objects::{ContainerId, ContainerStatus, HealthStatus, PortMapping};
 use crate::error::{DockaError, DockaResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -55,21 +56,52 @@ pub struct Container {
     /// 現在のコンテナステータス
     pub status: ContainerStatus,
 
+    /// Result of the container's health check (`None` without a check)
+    /// コンテナのヘルスチェックの結果（チェックがない場合は`None`）
+    #[serde(default)]
+    pub health: HealthStatus,
+
     /// Container creation timestamp
     /// コンテナ作成タイムスタンプ
     pub created_at: DateTime<Utc>,
 
+    /// When the container was last started, if it has been
+    /// コンテナが最後に起動した日時（起動したことがある場合）
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// When the container last exited, if it has
+    /// コンテナが最後に終了した日時（終了している場合）
+    pub finished_at: Option<DateTime<Utc>>,
+
     /// Container labels (metadata)
     /// コンテナラベル（メタデータ）
     pub labels: HashMap<String, String>,
 
     /// Container command
     /// コンテナコマンド
+    ///
+    /// May hold secrets; mask it with `SecretMask` before showing it.
+    /// シークレットを含む場合があるため、表示前に`SecretMask`でマスクしてください。
     pub command: Option<String>,
 
     /// Container working directory
     /// コンテナ作業ディレクトリ
     pub working_dir: Option<String>,
+
+    /// Exposed and published ports, ordered by container port
+    /// 公開・`expose`されたポート（コンテナポート順）
+    ///
+    /// The list API reports host bindings only while the container runs.
+    /// 一覧APIはコンテナの実行中のみホストのバインドを報告します。
+    #[serde(default)]
+    pub ports: Vec<PortMapping>,
+
+    /// Name of the Docker endpoint this container lives on
+    /// このコンテナが存在する`Docker`エンドポイント名
+    ///
+    /// Only set when containers from several hosts are merged into one list.
+    /// 複数ホストのコンテナを1つの一覧にまとめる場合のみ設定されます。
+    pub host: Option<String>,
 }
 
 impl Container {
@@ -130,6 +162,34 @@ impl Container {
         self.status.can_stop()
     }
 
+    /// Check if container can receive a signal
+    /// コンテナがシグナルを受け取れるかチェック
+    #[must_use]
+    pub const fn can_kill(&self) -> bool {
+        self.status.can_kill()
+    }
+
+    /// Check if container's limits and restart policy can be updated
+    /// コンテナの制限と再起動ポリシーを更新できるかチェック
+    #[must_use]
+    pub const fn can_update(&self) -> bool {
+        self.status.can_update()
+    }
+
+    /// Check if container can be renamed
+    /// コンテナの名前を変更できるかチェック
+    #[must_use]
+    pub const fn can_rename(&self) -> bool {
+        self.status.can_rename()
+    }
+
+    /// Check if the container's health check is failing
+    /// コンテナのヘルスチェックが失敗しているかチェック
+    #[must_use]
+    pub const fn is_unhealthy(&self) -> bool {
+        matches!(self.health, HealthStatus::Unhealthy)
+    }
+
     /// Check if container can be paused
     /// コンテナが一時停止可能かチェック
     #[must_use]
@@ -189,6 +249,30 @@ impl Container {
         }
     }
 
+    /// Time since the container started, `None` if unknown or not running
+    /// コンテナ起動からの経過時間（不明または実行中でない場合は`None`）
+    #[must_use]
+    pub fn uptime(&self) -> Option<chrono::Duration> {
+        self.started_at
+            .filter(|_| self.is_running())
+            .map(|started_at| Utc::now().signed_duration_since(started_at))
+    }
+
+    /// Time since the container exited, `None` if unknown or still running
+    /// コンテナ終了からの経過時間（不明または実行中の場合は`None`）
+    #[must_use]
+    pub fn exited_for(&self) -> Option<chrono::Duration> {
+        self.finished_at
+            .filter(|_| self.is_stopped())
+            .map(|finished_at| Utc::now().signed_duration_since(finished_at))
+    }
+
+    /// Ports published on the host
+    /// ホストで公開されているポート
+    pub fn published_ports(&self) -> impl Iterator<Item = &PortMapping> {
+        self.ports.iter().filter(|port| port.is_published())
+    }
+
     /// Get label value by key
     /// キーによるラベル値の取得
     #[must_use]
@@ -223,6 +307,38 @@ impl Container {
         Ok(())
     }
 
+    /// Validate a container name against the engine's naming rules
+    /// エンジンの命名規則に対してコンテナ名を検証
+    ///
+    /// Names start with a letter or digit followed by letters, digits, `_`,
+    /// `.` or `-`, as in `[a-zA-Z0-9][a-zA-Z0-9_.-]*`. A leading `/` as
+    /// reported by the API is accepted.
+    /// 名前は英数字で始まり、英数字、`_`、`.`、`-`が続きます
+    /// （`[a-zA-Z0-9][a-zA-Z0-9_.-]*`）。`API`が報告する先頭の`/`は許可されます。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the name is empty, too long or has invalid characters
+    pub fn validate_name(name: &str) -> DockaResult<()> {
+        let name = name.strip_prefix('/').unwrap_or(name);
+        if name.is_empty() {
+            return Err(DockaError::invalid_input("Container name cannot be empty"));
+        }
+        if name.len() > 255 {
+            return Err(DockaError::invalid_input(
+                "Container name too long (max 255 characters)",
+            ));
+        }
+        let mut chars = name.chars();
+        let valid_first = chars.next().is_some_and(|c| c.is_ascii_alphanumeric());
+        let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !valid_first || !valid_rest {
+            return Err(DockaError::invalid_input(format!(
+                "Invalid container name '{name}': use letters, digits, '_', '.' or '-', starting with a letter or digit"
+            )));
+        }
+        Ok(())
+    }
+
     /// Validate container entity integrity
     /// コンテナエンティティの整合性を検証
     ///
@@ -232,12 +348,10 @@ impl Container {
     /// # Errors
     /// * `DockaError::InvalidInput` - When validation fails
     pub fn validate(&self) -> DockaResult<()> {
-        // Validate name format
-        // 名前フォーマットの検証
-        if self.name.len() > 255 {
-            return Err(DockaError::invalid_input(
-                "Container name too long (max 255 characters)",
-            ));
+        // Validate name format (unnamed containers fall back to the short ID)
+        // 名前フォーマットの検証（名前なしのコンテナは短縮IDで表示）
+        if !self.name.is_empty() {
+            Self::validate_name(&self.name)?;
         }
 
         // Validate image format (basic check)
@@ -293,10 +407,15 @@ pub struct ContainerBuilder {
     name: Option<String>,
     image: Option<String>,
     status: Option<ContainerStatus>,
+    health: HealthStatus,
     created_at: Option<DateTime<Utc>>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
     labels: HashMap<String, String>,
     command: Option<String>,
     working_dir: Option<String>,
+    ports: Vec<PortMapping>,
+    host: Option<String>,
 }
 
 impl ContainerBuilder {
@@ -339,6 +458,14 @@ impl ContainerBuilder {
         self
     }
 
+    /// Set the health check result
+    /// ヘルスチェックの結果を設定
+    #[must_use]
+    pub const fn health(mut self, health: HealthStatus) -> Self {
+        self.health = health;
+        self
+    }
+
     /// Set creation timestamp
     /// 作成タイムスタンプを設定
     #[must_use]
@@ -347,6 +474,22 @@ impl ContainerBuilder {
         self
     }
 
+    /// Set the time the container was last started
+    /// コンテナが最後に起動した日時を設定
+    #[must_use]
+    pub const fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Set the time the container last exited
+    /// コンテナが最後に終了した日時を設定
+    #[must_use]
+    pub const fn finished_at(mut self, finished_at: DateTime<Utc>) -> Self {
+        self.finished_at = Some(finished_at);
+        self
+    }
+
     /// Add a label
     /// ラベルを追加
     #[must_use]
@@ -379,6 +522,22 @@ impl ContainerBuilder {
         self
     }
 
+    /// Add a port mapping
+    /// ポートマッピングを追加
+    #[must_use]
+    pub fn port(mut self, port: PortMapping) -> Self {
+        self.ports.push(port);
+        self
+    }
+
+    /// Set the Docker endpoint the container belongs to
+    /// コンテナが属する`Docker`エンドポイントを設定
+    #[must_use]
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
     /// Build the container with validation
     /// 検証付きでコンテナを構築
     ///
@@ -414,10 +573,15 @@ impl ContainerBuilder {
             name,
             image,
             status,
+            health: self.health,
             created_at,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
             labels: self.labels,
             command: self.command,
             working_dir: self.working_dir,
+            ports: self.ports,
+            host: self.host,
         };
 
         // Validate the complete container
@@ -454,6 +618,40 @@ pub struct ContainerFilter {
     /// Include only running containers
     /// 実行中のコンテナのみ含める
     pub only_running: bool,
+
+    /// Include only containers created before this time
+    /// この日時より前に作成されたコンテナのみ含める
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Include only containers created after this time
+    /// この日時より後に作成されたコンテナのみ含める
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Include only containers that exited before this time
+    /// この日時より前に終了したコンテナのみ含める
+    ///
+    /// Set it to `now - duration` for "exited for longer than `duration`";
+    /// containers with an unknown exit time never match.
+    /// 「`duration`より長く終了している」には`now - duration`を設定します。
+    /// 終了日時が不明なコンテナはマッチしません。
+    pub finished_before: Option<DateTime<Utc>>,
+
+    /// Include only containers that exited with this code
+    /// この終了コードで終了したコンテナのみ含める
+    pub exit_code: Option<i32>,
+
+    /// Include only containers that exited with an error (see `ContainerStatus::is_failed_exit`)
+    /// エラーで終了したコンテナのみ含める（`ContainerStatus::is_failed_exit`を参照）
+    pub only_failed_exits: bool,
+
+    /// Include only containers with this health check result
+    /// このヘルスチェック結果のコンテナのみ含める
+    pub health: Option<HealthStatus>,
+
+    /// Conditions of a filter expression, all of which must hold
+    /// (see `parse_filter_expression`)
+    /// フィルタ式の条件（全てが成り立つ必要がある、`parse_filter_expression`を参照）
+    pub conditions: Vec<FilterCondition>,
 }
 
 impl ContainerFilter {
@@ -480,10 +678,18 @@ impl ContainerFilter {
     pub fn matches(&self, container: &Container) -> bool {
         // Check status filter
         // ステータスフィルタのチェック
-        if let Some(ref status) = self.status {
-            if container.status != *status {
-                return false;
-            }
+        if let Some(ref status) = self.status
+            && container.status != *status
+        {
+            return false;
+        }
+
+        // Check health filter
+        // ヘルスフィルタのチェック
+        if let Some(health) = self.health
+            && container.health != health
+        {
+            return false;
         }
 
         // Check running-only filter
@@ -494,18 +700,53 @@ impl ContainerFilter {
 
         // Check name pattern
         // 名前パターンのチェック
-        if let Some(ref pattern) = self.name_pattern {
-            if !container.name.contains(pattern) && !container.id.matches(pattern) {
-                return false;
-            }
+        if let Some(ref pattern) = self.name_pattern
+            && !container.name.contains(pattern)
+            && !container.id.matches(pattern)
+        {
+            return false;
         }
 
         // Check image pattern
         // イメージパターンのチェック
-        if let Some(ref pattern) = self.image_pattern {
-            if !container.image.contains(pattern) {
-                return false;
-            }
+        if let Some(ref pattern) = self.image_pattern
+            && !container.image.contains(pattern)
+        {
+            return false;
+        }
+
+        // Check creation time
+        // 作成日時のチェック
+        if let Some(cutoff) = self.created_before
+            && container.created_at >= cutoff
+        {
+            return false;
+        }
+
+        if let Some(cutoff) = self.created_after
+            && container.created_at <= cutoff
+        {
+            return false;
+        }
+
+        // Check exit time and code
+        // 終了日時と終了コードのチェック
+        if let Some(cutoff) = self.finished_before
+            && !container
+                .finished_at
+                .is_some_and(|finished_at| finished_at < cutoff && container.is_stopped())
+        {
+            return false;
+        }
+
+        if let Some(code) = self.exit_code
+            && container.status != (ContainerStatus::Exited { exit_code: code })
+        {
+            return false;
+        }
+
+        if self.only_failed_exits && !container.status.is_failed_exit() {
+            return false;
         }
 
         // Check label filters
@@ -517,7 +758,12 @@ impl ContainerFilter {
             }
         }
 
-        true
+        // Check expression conditions
+        // 式の条件のチェック
+        let now = Utc::now();
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(container, now))
     }
 }
 
@@ -706,6 +952,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_name_rules() {
+        // Test the naming rules shared by the builder and renames
+        // ビルダーと名前変更で共有する命名規則のテスト
+        for name in ["web", "web-1", "/web_app.v2", "1st"] {
+            assert!(Container::validate_name(name).is_ok(), "{name}");
+        }
+        for name in ["", "/", "-web", ".web", "web app", "web/1", "wéb"] {
+            assert!(Container::validate_name(name).is_err(), "{name}");
+        }
+        let result = Container::builder()
+            .id("test-123")
+            .name("bad name")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_container_filter() {
         // Test container filtering
@@ -771,6 +1036,126 @@ mod tests {
         assert_eq!(prod_containers.len(), 2);
     }
 
+    #[test]
+    fn test_container_filter_by_creation_time() {
+        // Test filtering by creation time
+        // 作成日時によるフィルタリングのテスト
+        let container = create_test_container();
+        let age_filter = ContainerFilter {
+            created_before: Some(Utc::now() - chrono::Duration::hours(1)),
+            ..ContainerFilter::default()
+        };
+        assert!(!age_filter.matches(&container));
+
+        let recent_filter = ContainerFilter {
+            created_after: Some(Utc::now() - chrono::Duration::hours(1)),
+            ..ContainerFilter::default()
+        };
+        assert!(recent_filter.matches(&container));
+    }
+
+    #[test]
+    fn test_container_filter_exit_criteria() {
+        // Test exited-for-longer-than and exit code criteria
+        // 終了経過時間と終了コードの基準をテスト
+        let now = Utc::now();
+        let exited = |id: &str, exit_code: i32, days: i64| {
+            Container::builder()
+                .id(id)
+                .image("ci-runner:latest")
+                .status(ContainerStatus::Exited { exit_code })
+                .finished_at(now - chrono::Duration::days(days))
+                .build()
+                .unwrap()
+        };
+        let old_failure = exited("old-fail", 1, 10);
+        let old_success = exited("old-ok", 0, 10);
+        let recent = exited("recent", 1, 1);
+        let unknown = Container::builder()
+            .id("unknown")
+            .image("ci-runner:latest")
+            .status(ContainerStatus::Exited { exit_code: 1 })
+            .build()
+            .unwrap();
+
+        let filter = ContainerFilter {
+            finished_before: Some(now - chrono::Duration::days(7)),
+            ..ContainerFilter::default()
+        };
+        assert!(filter.matches(&old_failure));
+        assert!(filter.matches(&old_success));
+        assert!(!filter.matches(&recent));
+        assert!(!filter.matches(&unknown));
+        assert!(old_failure.exited_for().unwrap() >= chrono::Duration::days(10));
+
+        let filter = ContainerFilter {
+            exit_code: Some(1),
+            ..filter
+        };
+        assert!(filter.matches(&old_failure));
+        assert!(!filter.matches(&old_success));
+    }
+
+    #[test]
+    fn test_container_filter_health() {
+        // Test that the health filter tells unhealthy containers apart from unchecked ones
+        // ヘルスフィルタが異常なコンテナとチェックのないコンテナを区別することをテスト
+        let container = |id: &str, health: HealthStatus| {
+            Container::builder()
+                .id(id)
+                .image("nginx:latest")
+                .status(ContainerStatus::Running)
+                .health(health)
+                .build()
+                .unwrap()
+        };
+        let unhealthy = container("api", HealthStatus::Unhealthy);
+        let healthy = container("web", HealthStatus::Healthy);
+        let unchecked = container("cache", HealthStatus::None);
+        assert!(unhealthy.is_unhealthy());
+        assert!(!unchecked.is_unhealthy());
+
+        let filter = ContainerFilter {
+            health: Some(HealthStatus::Unhealthy),
+            ..ContainerFilter::default()
+        };
+        assert!(filter.matches(&unhealthy));
+        assert!(!filter.matches(&healthy));
+        assert!(!filter.matches(&unchecked));
+    }
+
+    #[test]
+    fn test_container_filter_failed_exits() {
+        // Test that the failed exits filter skips clean stops and running containers
+        // 失敗した終了のフィルタが正常な停止と実行中のコンテナを除外することをテスト
+        let container = |id: &str, status: ContainerStatus| {
+            Container::builder()
+                .id(id)
+                .image("nginx:latest")
+                .status(status)
+                .build()
+                .unwrap()
+        };
+        let filter = ContainerFilter {
+            only_failed_exits: true,
+            ..ContainerFilter::default()
+        };
+        assert!(filter.matches(&container(
+            "oom",
+            ContainerStatus::Exited { exit_code: 137 }
+        )));
+        assert!(filter.matches(&container(
+            "crash",
+            ContainerStatus::Exited { exit_code: 1 }
+        )));
+        assert!(!filter.matches(&container("done", ContainerStatus::Exited { exit_code: 0 })));
+        assert!(!filter.matches(&container(
+            "stopped",
+            ContainerStatus::Exited { exit_code: 143 }
+        )));
+        assert!(!filter.matches(&container("web", ContainerStatus::Running)));
+    }
+
     #[test]
     fn test_container_age_formatting() {
         // Test age formatting
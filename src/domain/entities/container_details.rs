@@ -0,0 +1,170 @@
+// src/domain/entities/container_details.rs
+// Details of a single container read from inspect
+// inspectから読み取った単一コンテナの詳細
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+use crate::domain::value_objects::{HealthStatus, PortMapping};
+
+/// Details of a container that the list endpoint does not report
+/// 一覧エンドポイントが報告しないコンテナの詳細
+///
+/// Filled from `docker inspect` when the details view is opened, so the
+/// list refresh stays a single request.
+///
+/// 詳細ビューを開いたときに`docker inspect`から取得するため、
+/// 一覧の更新は1回のリクエストのままです。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerDetails {
+    /// When the container was last started (`State.StartedAt`), `None` if never
+    /// コンテナが最後に起動した日時（`State.StartedAt`、起動したことがない場合は`None`）
+    pub started_at: Option<DateTime<Utc>>,
+
+    /// When the container last exited (`State.FinishedAt`), `None` if never
+    /// コンテナが最後に終了した日時（`State.FinishedAt`、終了したことがない場合は`None`）
+    pub finished_at: Option<DateTime<Utc>>,
+
+    /// Health check state, `None` when no health check is configured
+    /// ヘルスチェックの状態（ヘルスチェックが未設定の場合は`None`）
+    pub health: Option<HealthReport>,
+
+    /// Restarts done by the restart policy since the container was started (`RestartCount`)
+    /// コンテナの起動以降に再起動ポリシーが行った再起動の回数（`RestartCount`）
+    pub restart_count: u64,
+
+    /// Whether the last exit was a kill by the kernel OOM killer (`State.OOMKilled`)
+    /// 最後の終了がカーネルのOOMキラーによる強制終了だったか（`State.OOMKilled`）
+    pub oom_killed: bool,
+
+    /// Host ports requested in `HostConfig.PortBindings`, ordered by container port
+    /// `HostConfig.PortBindings`で要求されたホストポート（コンテナポート順）
+    ///
+    /// Known even while the container is stopped, unlike the list's ports.
+    /// 一覧のポートと異なり、コンテナの停止中でも分かります。
+    pub port_bindings: Vec<PortMapping>,
+
+    /// Addresses of the container on its networks, ordered by network name
+    /// コンテナのネットワーク上のアドレス（ネットワーク名順）
+    ///
+    /// Reachable from the engine's host, which is what port forwarding uses.
+    /// エンジンのホストから到達可能で、ポートフォワーディングが使用します。
+    pub network_addresses: Vec<NetworkAddress>,
+
+    /// Environment of the container as `KEY=VALUE` entries (`Config.Env`)
+    /// `KEY=VALUE`形式のコンテナの環境変数（`Config.Env`）
+    ///
+    /// May hold secrets; mask them with `SecretMask` before showing them.
+    /// シークレットを含む場合があるため、表示前に`SecretMask`でマスクしてください。
+    pub env: Vec<String>,
+}
+
+/// Address of a container on one of its networks
+/// コンテナのネットワークの1つにおけるアドレス
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkAddress {
+    /// Network name, e.g. `bridge` or `myapp_default`
+    /// ネットワーク名（例: `bridge`、`myapp_default`）
+    pub network: String,
+
+    /// Address of the container on the network
+    /// ネットワーク上のコンテナのアドレス
+    pub ip: IpAddr,
+}
+
+/// State of a container's health check (`State.Health`)
+/// コンテナのヘルスチェックの状態（`State.Health`）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Current health status
+    /// 現在のヘルスステータス
+    pub status: HealthStatus,
+
+    /// Number of consecutive failed probes
+    /// 連続して失敗したプローブの数
+    pub failing_streak: u32,
+
+    /// Most recent probes, oldest first (the engine keeps the last five)
+    /// 直近のプローブ（古い順、エンジンは最後の5件を保持）
+    pub probes: Vec<HealthProbe>,
+}
+
+impl HealthReport {
+    /// Most recent probes, newest first
+    /// 直近のプローブ（新しい順）
+    pub fn latest_probes(&self) -> impl Iterator<Item = &HealthProbe> {
+        self.probes.iter().rev()
+    }
+}
+
+/// A single run of a container's health check command
+/// コンテナのヘルスチェックコマンドの1回の実行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthProbe {
+    /// When the probe started
+    /// プローブの開始時刻
+    pub start: DateTime<Utc>,
+
+    /// When the probe finished, `None` while it is running
+    /// プローブの終了時刻（実行中は`None`）
+    pub end: Option<DateTime<Utc>>,
+
+    /// Exit code: `0` healthy, `1` unhealthy, other values are errors
+    /// 終了コード: `0`は正常、`1`は異常、その他の値はエラー
+    pub exit_code: i64,
+
+    /// Output of the check command, truncated by the engine to `4 KiB`
+    /// チェックコマンドの出力（エンジンにより`4 KiB`に切り詰め）
+    pub output: String,
+}
+
+impl HealthProbe {
+    /// Check whether the probe reported the container as healthy
+    /// プローブがコンテナを正常と報告したかチェック
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.exit_code == 0
+    }
+
+    /// How long the probe took, `None` while it is running
+    /// プローブの所要時間（実行中は`None`）
+    #[must_use]
+    pub fn duration(&self) -> Option<Duration> {
+        self.end.map(|end| end - self.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_outcome_and_order() {
+        // Test that probes report success and duration and are listed newest first
+        // プローブが成否と所要時間を報告し、新しい順に列挙されることをテスト
+        let start = DateTime::parse_from_rfc3339("2024-05-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let probe = |seconds: i64, exit_code: i64| HealthProbe {
+            start: start + Duration::seconds(seconds),
+            end: Some(start + Duration::seconds(seconds) + Duration::milliseconds(250)),
+            exit_code,
+            output: String::new(),
+        };
+        let report = HealthReport {
+            status: HealthStatus::Unhealthy,
+            failing_streak: 1,
+            probes: vec![probe(0, 0), probe(30, 1)],
+        };
+
+        let latest: Vec<i64> = report.latest_probes().map(|p| p.exit_code).collect();
+        assert_eq!(latest, [1, 0]);
+        assert!(report.probes[0].is_success());
+        assert!(!report.probes[1].is_success());
+        assert_eq!(
+            report.probes[0].duration(),
+            Some(Duration::milliseconds(250))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// src/domain/entities/container_exit.rs
+// Container exits (die events) and crash-loop detection
+// コンテナの終了（dieイベント）とクラッシュループの検出
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::ContainerId;
+
+/// Exits kept per container; older ones are dropped first
+/// コンテナごとに保持する終了の数（古いものから破棄）
+pub const EXIT_HISTORY_LIMIT: usize = 20;
+
+/// A container's main process exiting, as reported by a `die` event
+/// `die`イベントが報告するコンテナのメインプロセスの終了
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerExit {
+    /// Container that exited
+    /// 終了したコンテナ
+    pub container_id: ContainerId,
+
+    /// When the process exited
+    /// プロセスが終了した時刻
+    pub at: DateTime<Utc>,
+
+    /// Exit code, `None` when the event did not carry one
+    /// 終了コード（イベントに含まれない場合は`None`）
+    pub exit_code: Option<i64>,
+}
+
+/// How many exits within how long count as a crash loop
+/// 何回の終了がどれだけの期間内に起きるとクラッシュループとみなすか
+///
+/// A container is crash-looping when it exited more than `max_restarts`
+/// times within `window`. Manual stops are `die` events too, so repeatedly
+/// stopping a container by hand also trips the flag.
+///
+/// コンテナが`window`内に`max_restarts`回を超えて終了した場合にクラッシュループと
+/// みなします。手動の停止も`die`イベントになるため、手動で何度も停止した場合も
+/// フラグが立ちます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrashLoopPolicy {
+    /// Exits tolerated within the window
+    /// 期間内に許容する終了回数
+    pub max_restarts: usize,
+
+    /// Length of the window
+    /// 期間の長さ
+    pub window: Duration,
+}
+
+impl Default for CrashLoopPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 3,
+            window: Duration::minutes(10),
+        }
+    }
+}
+
+/// Recent exits of every container, newest last
+/// 全コンテナの最近の終了（新しいものが最後）
+///
+/// Fed from polled `die` events so that restarts are noticed even when the
+/// `restarting` status is too short-lived to be seen in a refresh.
+///
+/// ポーリングした`die`イベントから記録するため、`restarting`ステータスが
+/// 短すぎて更新で見えない場合でも再起動に気付けます。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExitHistory {
+    /// Exits by container, oldest first
+    /// コンテナごとの終了（古い順）
+    exits: HashMap<ContainerId, Vec<ContainerExit>>,
+}
+
+impl ExitHistory {
+    /// Create an empty history
+    /// 空の履歴を作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record exits, ignoring ones already recorded
+    /// 終了を記録（記録済みのものは無視）
+    ///
+    /// Polls overlap by design, so the same event can be reported twice.
+    /// ポーリングは意図的に重なるため、同じイベントが2回報告される場合があります。
+    pub fn record(&mut self, exits: impl IntoIterator<Item = ContainerExit>) {
+        for exit in exits {
+            let entries = self.exits.entry(exit.container_id.clone()).or_default();
+            if entries.contains(&exit) {
+                continue;
+            }
+            let position = entries.partition_point(|entry| entry.at <= exit.at);
+            entries.insert(position, exit);
+            if entries.len() > EXIT_HISTORY_LIMIT {
+                entries.remove(0);
+            }
+        }
+    }
+
+    /// Recorded exits of a container, oldest first
+    /// コンテナの記録済みの終了（古い順）
+    #[must_use]
+    pub fn exits_for(&self, id: &ContainerId) -> &[ContainerExit] {
+        self.exits.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Number of exits of a container within the policy window ending at `now`
+    /// `now`で終わるポリシー期間内のコンテナの終了回数
+    #[must_use]
+    pub fn recent_exits(
+        &self,
+        id: &ContainerId,
+        policy: CrashLoopPolicy,
+        now: DateTime<Utc>,
+    ) -> usize {
+        let since = now - policy.window;
+        self.exits_for(id)
+            .iter()
+            .filter(|exit| exit.at > since && exit.at <= now)
+            .count()
+    }
+
+    /// Check whether a container exited more often than the policy allows
+    /// コンテナがポリシーの許容回数を超えて終了したかチェック
+    #[must_use]
+    pub fn is_crash_looping(
+        &self,
+        id: &ContainerId,
+        policy: CrashLoopPolicy,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.recent_exits(id, policy, now) > policy.max_restarts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exit(id: &ContainerId, at: DateTime<Utc>, exit_code: i64) -> ContainerExit {
+        ContainerExit {
+            container_id: id.clone(),
+            at,
+            exit_code: Some(exit_code),
+        }
+    }
+
+    #[test]
+    fn test_crash_loop_within_window() {
+        // Test that only exits inside the window count and duplicates are ignored
+        // 期間内の終了のみが数えられ、重複が無視されることをテスト
+        let id = ContainerId::new("web-1").unwrap();
+        let now = Utc::now();
+        let policy = CrashLoopPolicy {
+            max_restarts: 2,
+            window: Duration::minutes(5),
+        };
+        let mut history = ExitHistory::new();
+        history.record([
+            exit(&id, now - Duration::minutes(1), 1),
+            exit(&id, now - Duration::minutes(20), 1),
+            exit(&id, now - Duration::minutes(2), 1),
+        ]);
+        history.record([exit(&id, now - Duration::minutes(1), 1)]);
+        assert_eq!(history.exits_for(&id).len(), 3);
+        assert_eq!(history.exits_for(&id)[0].at, now - Duration::minutes(20));
+        assert!(!history.is_crash_looping(&id, policy, now));
+
+        history.record([exit(&id, now - Duration::seconds(10), 137)]);
+        assert_eq!(history.recent_exits(&id, policy, now), 3);
+        assert!(history.is_crash_looping(&id, policy, now));
+        assert!(!history.is_crash_looping(&id, policy, now + Duration::minutes(10)));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        // Test that only the newest exits are kept per container
+        // コンテナごとに最新の終了のみが保持されることをテスト
+        let id = ContainerId::new("web-1").unwrap();
+        let start = Utc::now();
+        let mut history = ExitHistory::new();
+        history.record((0..30).map(|i| exit(&id, start + Duration::seconds(i), i)));
+
+        let exits = history.exits_for(&id);
+        assert_eq!(exits.len(), EXIT_HISTORY_LIMIT);
+        assert_eq!(exits[0].exit_code, Some(10));
+        assert!(
+            history
+                .exits_for(&ContainerId::new("db").unwrap())
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+// src/domain/entities/container_stats.rs
+// Resource usage of a running container
+// 実行中のコンテナのリソース使用量
+
+use serde::{Deserialize, Serialize};
+
+/// CPU and memory usage of a container, as reported by `docker stats`
+/// `docker stats`が報告するコンテナのCPUとメモリの使用量
+///
+/// Figures are `None` when the engine leaves them out, e.g. on the first
+/// sample of a container that just started.
+///
+/// エンジンが省略した場合（例: 起動直後のコンテナの最初のサンプル）、
+/// 値は`None`になります。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStats {
+    /// CPU usage in percent of one core (may exceed 100 on several cores)
+    /// 1コアに対するCPU使用率（複数コアでは100を超える場合あり）
+    pub cpu_percent: Option<f64>,
+
+    /// Memory in use in bytes, without the page cache
+    /// ページキャッシュを除いた使用中のメモリ（バイト）
+    pub memory_bytes: Option<u64>,
+}
+
+impl ContainerStats {
+    /// Compute the CPU percentage the way `docker stats` does
+    /// `docker stats`と同じ方法でCPU使用率を計算
+    ///
+    /// The container's CPU time delta is divided by the host's over the same
+    /// interval and scaled by the number of online CPUs. Returns `None` when
+    /// the host delta is zero (no previous sample).
+    ///
+    /// 同じ期間のホストのCPU時間の差分でコンテナのCPU時間の差分を割り、
+    /// オンラインのCPU数を掛けます。ホストの差分が0の場合（前回のサンプルなし）は
+    /// `None`を返します。
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Ratio of CPU times / CPU時間の比率
+    pub fn cpu_percent_from(
+        container_delta: u64,
+        system_delta: u64,
+        online_cpus: u32,
+    ) -> Option<f64> {
+        (system_delta > 0).then(|| {
+            container_delta as f64 / system_delta as f64 * f64::from(online_cpus.max(1)) * 100.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_percent_scales_by_online_cpus() {
+        // Test that the CPU share is scaled by the number of CPUs and unknown without a delta
+        // CPUの割合がCPU数で拡大され、差分がない場合は不明になることをテスト
+        assert_eq!(ContainerStats::cpu_percent_from(50, 200, 4), Some(100.0));
+        assert_eq!(ContainerStats::cpu_percent_from(50, 200, 0), Some(25.0));
+        assert_eq!(ContainerStats::cpu_percent_from(50, 0, 4), None);
+    }
+}
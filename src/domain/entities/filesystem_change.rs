@@ -0,0 +1,146 @@
+// src/domain/entities/filesystem_change.rs
+// Changes in a container's writable layer
+// コンテナの書き込み可能レイヤーの変更
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// How a path differs from the container's image
+/// パスがコンテナのイメージとどう異なるか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangeKind {
+    /// Path was added
+    /// パスが追加された
+    Added,
+
+    /// Path was modified (directories are reported as modified when their content changes)
+    /// パスが変更された（内容が変わったディレクトリも変更として報告される）
+    Modified,
+
+    /// Path was deleted
+    /// パスが削除された
+    Deleted,
+}
+
+impl ChangeKind {
+    /// One-letter marker used by `docker diff`
+    /// `docker diff`が使用する1文字のマーカー
+    #[must_use]
+    pub const fn marker(self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Modified => 'C',
+            Self::Deleted => 'D',
+        }
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added => write!(f, "added"),
+            Self::Modified => write!(f, "changed"),
+            Self::Deleted => write!(f, "deleted"),
+        }
+    }
+}
+
+/// A path that differs from the container's image, as reported by `docker diff`
+/// `docker diff`が報告するコンテナのイメージと異なるパス
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilesystemChange {
+    /// Absolute path inside the container
+    /// コンテナ内の絶対パス
+    pub path: String,
+
+    /// Kind of change
+    /// 変更の種類
+    pub kind: ChangeKind,
+}
+
+impl FilesystemChange {
+    /// Create a change
+    /// 変更を作成
+    #[must_use]
+    pub fn new(path: impl Into<String>, kind: ChangeKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+/// Number of changes of each kind
+/// 種類ごとの変更数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeCounts {
+    /// Added paths
+    /// 追加されたパス
+    pub added: usize,
+
+    /// Modified paths
+    /// 変更されたパス
+    pub modified: usize,
+
+    /// Deleted paths
+    /// 削除されたパス
+    pub deleted: usize,
+}
+
+impl ChangeCounts {
+    /// Count one change
+    /// 変更を1つ数える
+    pub const fn record(&mut self, kind: ChangeKind) {
+        match kind {
+            ChangeKind::Added => self.added += 1,
+            ChangeKind::Modified => self.modified += 1,
+            ChangeKind::Deleted => self.deleted += 1,
+        }
+    }
+
+    /// Add the counts of another set of changes
+    /// 別の変更の集合の数を加算
+    pub const fn merge(&mut self, other: Self) {
+        self.added += other.added;
+        self.modified += other.modified;
+        self.deleted += other.deleted;
+    }
+
+    /// Total number of changes
+    /// 変更の総数
+    #[must_use]
+    pub const fn total(self) -> usize {
+        self.added + self.modified + self.deleted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_counts() {
+        // Test that changes are counted by kind and merged
+        // 変更が種類ごとに数えられ、合算されることをテスト
+        let mut counts = ChangeCounts::default();
+        counts.record(ChangeKind::Added);
+        counts.record(ChangeKind::Added);
+        counts.record(ChangeKind::Deleted);
+
+        let mut total = ChangeCounts {
+            modified: 1,
+            ..ChangeCounts::default()
+        };
+        total.merge(counts);
+        assert_eq!(
+            total,
+            ChangeCounts {
+                added: 2,
+                modified: 1,
+                deleted: 1
+            }
+        );
+        assert_eq!(total.total(), 4);
+        assert_eq!(ChangeKind::Modified.marker(), 'C');
+    }
+}
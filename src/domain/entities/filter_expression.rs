@@ -0,0 +1,594 @@
+// src/domain/entities/filter_expression.rs
+// Filter expressions such as `status:running image:postgres* -name:test`
+// `status:running image:postgres* -name:test`のようなフィルタ式
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Duration, Utc};
+use regex::Regex;
+
+use crate::domain::entities::{Container, ContainerFilter};
+use crate::domain::value_objects::{ContainerStatus, HealthStatus};
+use crate::error::{DockaError, DockaResult};
+use crate::utils::time_formatter::parse_duration;
+
+/// Fields accepted in an expression, listed in error messages
+/// 式で使用できるフィールド（エラーメッセージに表示）
+const FIELDS: &str = "status, health, name, image, id, label, exit or age";
+
+/// Text matched by a `name:`, `image:` or `label:` term
+/// `name:`、`image:`、`label:`の項で照合するテキスト
+///
+/// Plain text is found anywhere in the value. Text with `*` or `?` is a
+/// glob matching the whole value, and text between slashes (`/^api-\d+$/`)
+/// is a regular expression found anywhere in the value. Matching is case
+/// sensitive; a regular expression can start with `(?i)` to ignore case.
+///
+/// 通常のテキストは値の任意の位置で一致します。`*`や`?`を含むテキストは
+/// 値全体に一致するグロブ、スラッシュで囲んだテキスト（`/^api-\d+$/`）は
+/// 値の任意の位置で一致する正規表現です。照合は大文字小文字を区別します。
+/// 正規表現は`(?i)`で始めると大文字小文字を無視します。
+#[derive(Debug, Clone)]
+pub enum TextPattern {
+    /// Plain text found anywhere in the value
+    /// 値の任意の位置で一致する通常のテキスト
+    Contains(String),
+
+    /// Glob with `*` and `?`, compiled to an anchored regular expression
+    /// `*`と`?`を使うグロブ（先頭と末尾を固定した正規表現にコンパイル済み）
+    Glob(Regex),
+
+    /// Regular expression written between slashes
+    /// スラッシュで囲んで記述した正規表現
+    Regex(Regex),
+}
+
+impl TextPattern {
+    /// Parse the text of a term, reporting errors at `position`
+    /// 項のテキストを解析（エラーは`position`の位置で報告）
+    ///
+    /// # Errors
+    /// * `DockaError::FilterParse` - When a regular expression does not compile
+    pub fn parse(text: &str, position: usize) -> DockaResult<Self> {
+        if let Some(source) = text
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+        {
+            return compile(source, position + 1).map(Self::Regex);
+        }
+        if text.contains(['*', '?']) {
+            let source: String = text
+                .chars()
+                .map(|c| match c {
+                    '*' => ".*".to_string(),
+                    '?' => ".".to_string(),
+                    c => regex::escape(c.encode_utf8(&mut [0; 4])),
+                })
+                .collect();
+            return compile(&format!("^{source}$"), position).map(Self::Glob);
+        }
+        Ok(Self::Contains(text.to_string()))
+    }
+
+    /// Check whether a value matches the pattern
+    /// 値がパターンに一致するかチェック
+    #[must_use]
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Contains(text) => value.contains(text.as_str()),
+            Self::Glob(regex) | Self::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+/// Comparison operator of an `exit:` or `age:` term
+/// `exit:`や`age:`の項の比較演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `=` or no operator
+    /// `=`または演算子なし
+    Eq,
+
+    /// `!=`
+    Ne,
+
+    /// `>`
+    Gt,
+
+    /// `>=`
+    Ge,
+
+    /// `<`
+    Lt,
+
+    /// `<=`
+    Le,
+}
+
+impl CompareOp {
+    /// Operators by their spelling, longest first so `>=` is not read as `>`
+    /// 表記ごとの演算子（`>=`を`>`と読まないよう長いものから）
+    const SPELLINGS: [(&'static str, Self); 6] = [
+        ("!=", Self::Ne),
+        (">=", Self::Ge),
+        ("<=", Self::Le),
+        (">", Self::Gt),
+        ("<", Self::Lt),
+        ("=", Self::Eq),
+    ];
+
+    /// Split a leading operator off `text`, `=` when there is none
+    /// `text`の先頭の演算子を分離（ない場合は`=`）
+    fn split(text: &str) -> (Self, &str) {
+        Self::SPELLINGS
+            .iter()
+            .find_map(|(spelling, op)| text.strip_prefix(spelling).map(|rest| (*op, rest)))
+            .unwrap_or((Self::Eq, text))
+    }
+
+    /// Check whether `actual` compares to `expected` as this operator requires
+    /// `actual`と`expected`がこの演算子の条件を満たすかチェック
+    fn holds<T: PartialOrd>(self, actual: &T, expected: &T) -> bool {
+        let Some(ordering) = actual.partial_cmp(expected) else {
+            return false;
+        };
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::Ne => ordering != Ordering::Equal,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::Ge => ordering != Ordering::Less,
+            Self::Lt => ordering == Ordering::Less,
+            Self::Le => ordering != Ordering::Greater,
+        }
+    }
+}
+
+/// Single test on a container, one per term of an expression
+/// コンテナに対する1つの判定（式の項ごとに1つ）
+#[derive(Debug, Clone)]
+pub enum FilterPredicate {
+    /// `status:exited`: same state, whatever the exit code
+    /// `status:exited`: 終了コードにかかわらず同じ状態
+    Status(ContainerStatus),
+
+    /// `health:unhealthy`
+    Health(HealthStatus),
+
+    /// `name:api*`, or a bare term, which also matches an ID prefix
+    /// `name:api*`、または IDの先頭にも一致する単独の項
+    Name {
+        /// Pattern of the name
+        /// 名前のパターン
+        pattern: TextPattern,
+
+        /// Whether an ID starting with the text matches too (bare terms)
+        /// テキストで始まるIDも一致するか（単独の項）
+        or_id: bool,
+    },
+
+    /// `image:postgres*`
+    Image(TextPattern),
+
+    /// `id:3f4e`: the ID starts with the text
+    /// `id:3f4e`: IDがテキストで始まる
+    Id(String),
+
+    /// `label:env=prod`, or `label:env` for any value
+    /// `label:env=prod`、または任意の値の場合は`label:env`
+    Label {
+        /// Label key
+        /// ラベルのキー
+        key: String,
+
+        /// Pattern of the value, `None` when only the key must be present
+        /// 値のパターン（キーの存在のみを求める場合は`None`）
+        value: Option<TextPattern>,
+    },
+
+    /// `exit:!=0`: the container exited with a matching code
+    /// `exit:!=0`: コンテナが一致するコードで終了した
+    ExitCode(CompareOp, i32),
+
+    /// `age:>2d`: time since the container was created
+    /// `age:>2d`: コンテナ作成からの経過時間
+    Age(CompareOp, Duration),
+}
+
+impl FilterPredicate {
+    /// Check whether a container passes the test at `now`
+    /// `now`の時点でコンテナが判定を満たすかチェック
+    #[must_use]
+    pub fn matches(&self, container: &Container, now: DateTime<Utc>) -> bool {
+        match self {
+            Self::Status(status) => {
+                std::mem::discriminant(status) == std::mem::discriminant(&container.status)
+            }
+            Self::Health(health) => container.health == *health,
+            Self::Name { pattern, or_id } => {
+                pattern.matches(&container.name)
+                    || (*or_id
+                        && matches!(pattern, TextPattern::Contains(text) if container.id.matches(text)))
+            }
+            Self::Image(pattern) => pattern.matches(&container.image),
+            Self::Id(prefix) => container.id.matches(prefix),
+            Self::Label { key, value } => container
+                .get_label(key)
+                .is_some_and(|actual| value.as_ref().is_none_or(|pattern| pattern.matches(actual))),
+            Self::ExitCode(op, code) => match container.status {
+                ContainerStatus::Exited { exit_code } => op.holds(&exit_code, code),
+                _ => false,
+            },
+            Self::Age(op, age) => op.holds(&(now - container.created_at), age),
+        }
+    }
+}
+
+/// Predicate of one term, possibly negated with a leading `-`
+/// 1つの項の判定（先頭の`-`で否定される場合がある）
+#[derive(Debug, Clone)]
+pub struct FilterCondition {
+    /// Whether the term started with `-`
+    /// 項が`-`で始まっていたか
+    pub negated: bool,
+
+    /// Test of the term
+    /// 項の判定
+    pub predicate: FilterPredicate,
+}
+
+impl FilterCondition {
+    /// Check whether a container satisfies the condition at `now`
+    /// `now`の時点でコンテナが条件を満たすかチェック
+    #[must_use]
+    pub fn matches(&self, container: &Container, now: DateTime<Utc>) -> bool {
+        self.predicate.matches(container, now) != self.negated
+    }
+}
+
+/// Parse an expression such as `status:running image:postgres* -name:test`
+/// `status:running image:postgres* -name:test`のような式を解析
+///
+/// Terms are separated by spaces and must all hold. A term is `field:value`,
+/// negated by a leading `-`; a bare word matches the name or an ID prefix.
+/// Values with spaces are written in double quotes (`name:"my app"`).
+/// The conditions are returned in `ContainerFilter::conditions`.
+///
+/// 項は空白で区切り、全てが成り立つ必要があります。項は`field:value`の形式で、
+/// 先頭の`-`で否定されます。単独の語は名前またはIDの先頭に一致します。
+/// 空白を含む値は二重引用符で囲みます（`name:"my app"`）。
+/// 条件は`ContainerFilter::conditions`に格納されます。
+///
+/// # Errors
+/// * `DockaError::FilterParse` - With the character offset of the faulty term
+///   (see `DockaError::input_position`)
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::entities::filter_expression::parse_filter_expression;
+///
+/// let filter = parse_filter_expression("status:running -name:test exit:!=0 age:>2d").unwrap();
+/// assert_eq!(filter.conditions.len(), 4);
+///
+/// let error = parse_filter_expression("status:running colour:red").unwrap_err();
+/// assert_eq!(error.input_position(), Some(15));
+/// ```
+pub fn parse_filter_expression(expression: &str) -> DockaResult<ContainerFilter> {
+    let conditions = tokenize(expression)?
+        .into_iter()
+        .map(|term| parse_term(&term.text, 0).map_err(|error| term.relocate(error)))
+        .collect::<DockaResult<Vec<_>>>()?;
+    Ok(ContainerFilter {
+        conditions,
+        ..ContainerFilter::default()
+    })
+}
+
+/// Term of an expression with quotes removed
+/// 引用符を除去した式の項
+#[derive(Debug)]
+struct Term {
+    /// Text of the term without quotes
+    /// 引用符を除いた項のテキスト
+    text: String,
+
+    /// Character offset in the expression of each character of `text`
+    /// `text`の各文字の式内での文字位置
+    offsets: Vec<usize>,
+
+    /// Character offset in the expression where the term starts
+    /// 式内で項が始まる文字位置
+    start: usize,
+}
+
+impl Term {
+    /// Start an empty term at character offset `start`
+    /// 文字位置`start`で空の項を開始
+    const fn new(start: usize) -> Self {
+        Self {
+            text: String::new(),
+            offsets: Vec::new(),
+            start,
+        }
+    }
+
+    /// Character offset in the expression of character `index` of `text`
+    /// `text`の`index`番目の文字の式内での文字位置
+    ///
+    /// An index past the end points just after the last character.
+    /// 末尾を超える位置は最後の文字の直後を指します。
+    fn position(&self, index: usize) -> usize {
+        self.offsets
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| self.offsets.last().map_or(self.start, |&offset| offset + 1))
+    }
+
+    /// Move the position of an error from `text` into the expression
+    /// エラーの位置を`text`内から式内へ移す
+    fn relocate(&self, error: DockaError) -> DockaError {
+        match error {
+            DockaError::FilterParse {
+                message,
+                position: index,
+            } => DockaError::filter_parse(message, self.position(index)),
+            error => error,
+        }
+    }
+}
+
+/// Split an expression into terms, removing quotes
+/// 式を項に分割（引用符は除去）
+fn tokenize(expression: &str) -> DockaResult<Vec<Term>> {
+    let mut terms = Vec::new();
+    let mut current: Option<Term> = None;
+    let mut quote_start = None;
+    for (index, c) in expression.chars().enumerate() {
+        if c == '"' {
+            quote_start = match quote_start {
+                Some(_) => None,
+                None => Some(index),
+            };
+            current.get_or_insert_with(|| Term::new(index));
+        } else if c.is_whitespace() && quote_start.is_none() {
+            terms.extend(current.take());
+        } else {
+            let term = current.get_or_insert_with(|| Term::new(index));
+            term.text.push(c);
+            term.offsets.push(index);
+        }
+    }
+    if let Some(index) = quote_start {
+        return Err(DockaError::filter_parse("Unclosed quote", index));
+    }
+    terms.extend(current);
+    Ok(terms)
+}
+
+/// Parse one term, reporting errors at character offsets from `start`
+/// 1つの項を解析（エラーは`start`からの文字位置で報告）
+fn parse_term(term: &str, start: usize) -> DockaResult<FilterCondition> {
+    let (negated, body, start) = term
+        .strip_prefix('-')
+        .map_or((false, term, start), |body| (true, body, start + 1));
+    if body.is_empty() {
+        return Err(DockaError::filter_parse("Expected a term after '-'", start));
+    }
+    let Some((field, value)) = body.split_once(':') else {
+        return Ok(FilterCondition {
+            negated,
+            predicate: FilterPredicate::Name {
+                pattern: TextPattern::parse(body, start)?,
+                or_id: true,
+            },
+        });
+    };
+    let at = start + field.chars().count() + 1;
+    if value.is_empty() {
+        return Err(DockaError::filter_parse(
+            format!("Expected a value after '{field}:'"),
+            at,
+        ));
+    }
+    let predicate = match field.to_ascii_lowercase().as_str() {
+        "status" | "state" => FilterPredicate::Status(parse_status(value, at)?),
+        "health" => FilterPredicate::Health(parse_health(value, at)?),
+        "name" => FilterPredicate::Name {
+            pattern: TextPattern::parse(value, at)?,
+            or_id: false,
+        },
+        "image" => FilterPredicate::Image(TextPattern::parse(value, at)?),
+        "id" => FilterPredicate::Id(value.to_string()),
+        "label" => parse_label(value, at)?,
+        "exit" => {
+            let (op, code) = CompareOp::split(value);
+            let code = code
+                .parse()
+                .map_err(|_| DockaError::filter_parse(format!("Invalid exit code '{code}'"), at))?;
+            FilterPredicate::ExitCode(op, code)
+        }
+        "age" => {
+            let (op, age) = CompareOp::split(value);
+            let age = parse_duration(age).map_err(|error| match error {
+                DockaError::InvalidInput { message } => DockaError::filter_parse(message, at),
+                other => other,
+            })?;
+            FilterPredicate::Age(op, age)
+        }
+        _ => {
+            return Err(DockaError::filter_parse(
+                format!("Unknown field '{field}' (use {FIELDS})"),
+                start,
+            ));
+        }
+    };
+    Ok(FilterCondition { negated, predicate })
+}
+
+/// Parse the value of a `label:` term, `key=pattern` or `key`
+/// `label:`の項の値（`key=pattern`または`key`）を解析
+fn parse_label(value: &str, at: usize) -> DockaResult<FilterPredicate> {
+    let (key, pattern) = match value.split_once('=') {
+        Some((key, pattern)) => (key, Some(pattern)),
+        None => (value, None),
+    };
+    if key.is_empty() {
+        return Err(DockaError::filter_parse(
+            "Expected a label key before '='",
+            at,
+        ));
+    }
+    let pattern_at = at + key.chars().count() + 1;
+    Ok(FilterPredicate::Label {
+        key: key.to_string(),
+        value: pattern
+            .map(|pattern| TextPattern::parse(pattern, pattern_at))
+            .transpose()?,
+    })
+}
+
+/// Parse a container state name such as `running` or `exited`
+/// `running`や`exited`のようなコンテナの状態名を解析
+fn parse_status(value: &str, at: usize) -> DockaResult<ContainerStatus> {
+    Ok(match value.to_ascii_lowercase().as_str() {
+        "running" | "up" => ContainerStatus::Running,
+        "stopped" => ContainerStatus::Stopped,
+        "starting" => ContainerStatus::Starting,
+        "stopping" => ContainerStatus::Stopping,
+        "exited" => ContainerStatus::Exited { exit_code: 0 },
+        "paused" => ContainerStatus::Paused,
+        "restarting" => ContainerStatus::Restarting,
+        "removing" => ContainerStatus::Removing,
+        "dead" => ContainerStatus::Dead,
+        "created" => ContainerStatus::Created,
+        _ => {
+            return Err(DockaError::filter_parse(
+                format!(
+                    "Unknown status '{value}' (use running, exited, paused, restarting, created, dead, ...)"
+                ),
+                at,
+            ));
+        }
+    })
+}
+
+/// Parse a health check result such as `unhealthy`
+/// `unhealthy`のようなヘルスチェック結果を解析
+fn parse_health(value: &str, at: usize) -> DockaResult<HealthStatus> {
+    HealthStatus::ALL
+        .into_iter()
+        .find(|health| health.label().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            DockaError::filter_parse(
+                format!("Unknown health '{value}' (use healthy, unhealthy, starting or none)"),
+                at,
+            )
+        })
+}
+
+/// Compile a regular expression, reporting errors at `position`
+/// 正規表現をコンパイル（エラーは`position`の位置で報告）
+fn compile(source: &str, position: usize) -> DockaResult<Regex> {
+    Regex::new(source).map_err(|error| {
+        // The last line of a syntax error names the problem; earlier ones draw the pattern
+        // 構文エラーの最後の行が問題を示し、それより前の行はパターンを描画する
+        let error = error.to_string();
+        let reason = error
+            .lines()
+            .last()
+            .unwrap_or_default()
+            .trim_start_matches("error: ");
+        DockaError::filter_parse(format!("Invalid regular expression: {reason}"), position)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(name: &str, image: &str, status: ContainerStatus, age_days: i64) -> Container {
+        Container::builder()
+            .id(format!("{name}0123456789"))
+            .name(name)
+            .image(image)
+            .status(status)
+            .created_at(Utc::now() - Duration::days(age_days))
+            .label("env", "prod")
+            .build()
+            .unwrap()
+    }
+
+    fn matching(expression: &str, containers: &[Container]) -> Vec<String> {
+        let filter = parse_filter_expression(expression).unwrap();
+        containers
+            .iter()
+            .filter(|container| filter.matches(container))
+            .map(|container| container.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_and_match_expression() {
+        // Test negation, globs, regexes, labels, exit codes and ages together
+        // 否定、グロブ、正規表現、ラベル、終了コード、経過時間を組み合わせてテスト
+        let containers = [
+            container("db", "postgres:16", ContainerStatus::Running, 5),
+            container("db-test", "postgres:15", ContainerStatus::Running, 5),
+            container(
+                "api-1",
+                "api:latest",
+                ContainerStatus::Exited { exit_code: 1 },
+                3,
+            ),
+            container(
+                "api-2",
+                "api:latest",
+                ContainerStatus::Exited { exit_code: 0 },
+                1,
+            ),
+        ];
+
+        assert_eq!(
+            matching(
+                "status:running image:postgres* label:env=prod -name:test age:>2d",
+                &containers
+            ),
+            ["db"]
+        );
+        assert_eq!(matching("status:exited exit:!=0", &containers), ["api-1"]);
+        assert_eq!(matching("-exit:0", &containers).len(), 3);
+        assert_eq!(matching(r"name:/^api-\d$/ age:<2d", &containers), ["api-2"]);
+        assert_eq!(matching("label:env -label:team", &containers).len(), 4);
+        assert_eq!(matching("image:*:15", &containers), ["db-test"]);
+        assert_eq!(matching("api-20123", &containers), ["api-2"]);
+        assert_eq!(matching(r#"name:"db" "#, &containers), ["db", "db-test"]);
+        assert!(matching("", &containers).len() == 4);
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_faulty_term() {
+        // Test that errors are FilterParse with the offset of the faulty term
+        // エラーが問題の項の位置を持つFilterParseであることをテスト
+        for (expression, position) in [
+            ("status:running colour:red", 15),
+            ("status:sleeping", 7),
+            ("name:api exit:>=x", 14),
+            ("age:>2y", 4),
+            ("-", 1),
+            ("image:", 6),
+            ("name:/(/", 6),
+            ("label:=prod", 6),
+            ("name:\"my app", 5),
+            ("\"status\":sleeping", 9),
+            ("label:\"my team\"=/(/", 17),
+            ("name:\"a b\" label:\"x\"=/(/ age:>2y", 22),
+        ] {
+            let error = parse_filter_expression(expression).unwrap_err();
+            assert!(
+                matches!(error, DockaError::FilterParse { .. }),
+                "{expression}"
+            );
+            assert_eq!(error.input_position(), Some(position), "{expression}");
+        }
+    }
+}
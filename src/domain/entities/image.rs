@@ -7,6 +7,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Repository and tag the engine reports for untagged images
+/// エンジンがタグなしイメージに報告するリポジトリとタグ
+pub const UNTAGGED: &str = "<none>";
+
 /// Docker image domain entity
 /// Dockerイメージドメインエンティティ
 ///
@@ -164,6 +168,16 @@ impl Image {
         !self.in_use
     }
 
+    /// Check if the image has no repository or tag (`<none>:<none>`)
+    /// イメージにリポジトリやタグがないかチェック（`<none>:<none>`）
+    ///
+    /// Dangling images are usually left behind when a tag moves to a newer build.
+    /// ダングリングイメージは通常、タグが新しいビルドに移った際に残されます。
+    #[must_use]
+    pub fn is_dangling(&self) -> bool {
+        self.repository == UNTAGGED
+    }
+
     /// Get image age in human-readable format
     /// 人間が読める形式でイメージの経過時間を取得
     #[must_use]
@@ -573,4 +587,18 @@ mod tests {
 
         assert_eq!(original, deserialized);
     }
+
+    #[test]
+    fn test_image_is_dangling() {
+        // Test that untagged images are recognized as dangling
+        // タグなしイメージがダングリングとして認識されることをテスト
+        let dangling = Image::builder()
+            .id("sha256:abc")
+            .repository(UNTAGGED)
+            .tag(UNTAGGED)
+            .build()
+            .unwrap();
+        assert!(dangling.is_dangling());
+        assert!(!create_test_image().is_dangling());
+    }
 }
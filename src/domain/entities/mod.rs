@@ -17,8 +17,15 @@
 //!
 //! - ✅ Container entity with comprehensive business logic
 //! - ✅ Image entity with basic functionality
-//! - 🚧 Volume entity (Phase 2 planned)
-//! - 🚧 Network entity (Phase 2 planned)
+//! - ✅ System overview and disk usage
+//! - ✅ Volume, network and build cache entities for cleanup
+//! - ✅ Container process table
+//! - ✅ Container CPU and memory usage
+//! - ✅ Container filesystem changes
+//! - ✅ Tar archives for copying and browsing files
+//! - ✅ Container details from inspect (health log)
+//! - ✅ Container exits and crash-loop detection
+//! - ✅ Filter expressions (`status:running -name:test age:>2d`)
 
 /// Container entity with business logic
 /// ビジネスロジックを持つコンテナエンティティ
@@ -28,16 +35,49 @@ pub mod container;
 /// メタデータ管理を持つイメージエンティティ
 pub mod image;
 
-// Phase 2 で追加予定のエンティティ (コメントアウト)
-// Entities to be added in Phase 2 (commented out)
+/// Docker host overview and disk usage
+/// `Docker`ホストの概要とディスク使用量
+pub mod system;
 
-// /// Volume entity for storage management
-// /// ストレージ管理用ボリュームエンティティ
-// pub mod volume;
+/// Volume entity for storage management
+/// ストレージ管理用ボリュームエンティティ
+pub mod volume;
 
-// /// Network entity for container networking
-// /// コンテナネットワーキング用ネットワークエンティティ
-// pub mod network;
+/// Network entity for container networking
+/// コンテナネットワーキング用ネットワークエンティティ
+pub mod network;
+
+/// `BuildKit` build cache records
+/// `BuildKit`ビルドキャッシュレコード
+pub mod build_cache;
+
+/// Processes running inside a container
+/// コンテナ内で実行中のプロセス
+pub mod process;
+
+/// CPU and memory usage of a container
+/// コンテナのCPUとメモリの使用量
+pub mod container_stats;
+
+/// Changes in a container's writable layer
+/// コンテナの書き込み可能レイヤーの変更
+pub mod filesystem_change;
+
+/// Tar archives exchanged with a container's filesystem
+/// コンテナのファイルシステムとやり取りするtarアーカイブ
+pub mod archive;
+
+/// Details of a single container read from inspect
+/// inspectから読み取った単一コンテナの詳細
+pub mod container_details;
+
+/// Container exits (die events) and crash-loop detection
+/// コンテナの終了（dieイベント）とクラッシュループの検出
+pub mod container_exit;
+
+/// Filter expressions parsed into container filters
+/// コンテナフィルタに解析されるフィルタ式
+pub mod filter_expression;
 
 // Re-export for convenient access
 // 便利なアクセスのため再エクスポート
@@ -50,13 +90,48 @@ pub use container::{Container, ContainerBuilder, ContainerFilter};
 /// Dockerイメージドメインエンティティ
 pub use image::{Image, ImageBuilder};
 
-// Phase 2 で追加予定の再エクスポート (コメントアウト)
-// Re-exports to be added in Phase 2 (commented out)
+/// Docker host overview and disk usage
+/// `Docker`ホストの概要とディスク使用量
+pub use system::{ContainerCounts, DiskUsage, DiskUsageCategory, SystemOverview};
+
+/// Docker volume entity
+/// Dockerボリュームエンティティ
+pub use volume::{Volume, VolumeBuilder};
+
+/// Docker network entity
+/// Dockerネットワークエンティティ
+pub use network::{Network, NetworkBuilder};
+
+/// `BuildKit` build cache record
+/// `BuildKit`ビルドキャッシュレコード
+pub use build_cache::BuildCacheEntry;
+
+/// Process running inside a container
+/// コンテナ内で実行中のプロセス
+pub use process::ContainerProcess;
+
+/// CPU and memory usage of a container
+/// コンテナのCPUとメモリの使用量
+pub use container_stats::ContainerStats;
+
+/// Change in a container's writable layer
+/// コンテナの書き込み可能レイヤーの変更
+pub use filesystem_change::{ChangeCounts, ChangeKind, FilesystemChange};
+
+/// Tar archive stream and container file entries
+/// tarアーカイブのストリームとコンテナ内のファイルエントリ
+pub use archive::{ArchiveStream, FileEntry, PathKind};
+
+/// Container details from inspect
+/// inspectから取得したコンテナの詳細
+pub use container_details::{ContainerDetails, HealthProbe, HealthReport, NetworkAddress};
 
-// /// Docker volume entity (Phase 2)
-// /// Dockerボリュームエンティティ（Phase 2）
-// pub use volume::{Volume, VolumeBuilder};
+/// Container exits and crash-loop detection
+/// コンテナの終了とクラッシュループの検出
+pub use container_exit::{ContainerExit, CrashLoopPolicy, ExitHistory};
 
-// /// Docker network entity (Phase 2)
-// /// Dockerネットワークエンティティ（Phase 2）
-// pub use network::{Network, NetworkBuilder};
+/// Filter expression parser and its conditions
+/// フィルタ式のパーサーとその条件
+pub use filter_expression::{
+    CompareOp, FilterCondition, FilterPredicate, TextPattern, parse_filter_expression,
+};
@@ -0,0 +1,228 @@
+// src/domain/entities/network.rs
+// Network domain entity for container networking
+// コンテナネットワーキング用ネットワークドメインエンティティ
+
+use crate::error::{DockaError, DockaResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Networks every engine creates and that can never be removed
+/// 全エンジンが作成し削除できないネットワーク
+const PREDEFINED_NETWORKS: [&str; 3] = ["bridge", "host", "none"];
+
+/// Docker network domain entity
+/// Dockerネットワークドメインエンティティ
+///
+/// # Examples
+///
+/// ```rust
+/// # use docka::domain::entities::Network;
+/// let network = Network::builder()
+///     .id("f2de39df4171")
+///     .name("backend")
+///     .build()
+///     .expect("Valid network");
+///
+/// assert!(!network.is_in_use());
+/// assert!(!network.is_predefined());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Network {
+    /// Network identifier
+    /// ネットワーク識別子
+    pub id: String,
+
+    /// Network name
+    /// ネットワーク名
+    pub name: String,
+
+    /// Network driver (e.g., "bridge", "overlay")
+    /// ネットワークドライバ（例："bridge"、"overlay"）
+    pub driver: String,
+
+    /// Network creation timestamp, if reported by the engine
+    /// ネットワーク作成タイムスタンプ（エンジンが報告した場合）
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// Network labels (metadata)
+    /// ネットワークラベル（メタデータ）
+    pub labels: HashMap<String, String>,
+
+    /// Number of containers attached to the network
+    /// ネットワークに接続しているコンテナ数
+    pub containers: usize,
+}
+
+impl Network {
+    /// Create a new network builder
+    /// 新しいネットワークビルダーを作成
+    #[must_use]
+    pub fn builder() -> NetworkBuilder {
+        NetworkBuilder::new()
+    }
+
+    /// Get short network ID (first 12 characters)
+    /// 短縮ネットワークIDを取得（最初の12文字）
+    #[must_use]
+    pub fn short_id(&self) -> &str {
+        let len = self.id.len().min(12);
+        &self.id[..len]
+    }
+
+    /// Check whether any container is attached
+    /// いずれかのコンテナが接続しているかチェック
+    #[must_use]
+    pub const fn is_in_use(&self) -> bool {
+        self.containers > 0
+    }
+
+    /// Check whether this is one of the engine's built-in networks
+    /// エンジン組み込みのネットワークかチェック
+    #[must_use]
+    pub fn is_predefined(&self) -> bool {
+        PREDEFINED_NETWORKS.contains(&self.name.as_str())
+    }
+
+    /// Get label value by key
+    /// キーによるラベル値の取得
+    #[must_use]
+    pub fn get_label(&self, key: &str) -> Option<&String> {
+        self.labels.get(key)
+    }
+}
+
+/// Builder for creating Network instances with validation
+/// 検証付きでNetworkインスタンスを作成するビルダー
+#[derive(Debug, Default)]
+pub struct NetworkBuilder {
+    id: Option<String>,
+    name: Option<String>,
+    driver: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    labels: HashMap<String, String>,
+    containers: usize,
+}
+
+impl NetworkBuilder {
+    /// Create a new network builder
+    /// 新しいネットワークビルダーを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set network ID
+    /// ネットワークIDを設定
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set network name
+    /// ネットワーク名を設定
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set network driver
+    /// ネットワークドライバを設定
+    #[must_use]
+    pub fn driver(mut self, driver: impl Into<String>) -> Self {
+        self.driver = Some(driver.into());
+        self
+    }
+
+    /// Set creation timestamp
+    /// 作成タイムスタンプを設定
+    #[must_use]
+    pub const fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    /// Add a label
+    /// ラベルを追加
+    #[must_use]
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set multiple labels
+    /// 複数のラベルを設定
+    #[must_use]
+    pub fn labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set the number of attached containers
+    /// 接続しているコンテナ数を設定
+    #[must_use]
+    pub const fn containers(mut self, containers: usize) -> Self {
+        self.containers = containers;
+        self
+    }
+
+    /// Build the network with validation
+    /// 検証付きでネットワークを構築
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the ID or name is missing or empty
+    pub fn build(self) -> DockaResult<Network> {
+        let id = self
+            .id
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| DockaError::invalid_input("Network ID is required"))?;
+        let name = self
+            .name
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| DockaError::invalid_input("Network name is required"))?;
+
+        Ok(Network {
+            id,
+            name,
+            driver: self.driver.unwrap_or_else(|| "bridge".to_string()),
+            created_at: self.created_at,
+            labels: self.labels,
+            containers: self.containers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_builder_validation() {
+        // Test that ID and name are required
+        // IDと名前が必須であることをテスト
+        assert!(Network::builder().name("backend").build().is_err());
+        assert!(Network::builder().id("abc").build().is_err());
+
+        let network = Network::builder()
+            .id("f2de39df4171b0dc801e8002d1d999b77256983dfc63041c0f34030aa3977566")
+            .name("backend")
+            .containers(2)
+            .build()
+            .unwrap();
+        assert_eq!(network.short_id(), "f2de39df4171");
+        assert_eq!(network.driver, "bridge");
+        assert!(network.is_in_use());
+    }
+
+    #[test]
+    fn test_predefined_networks() {
+        // Test that built-in networks are recognized
+        // 組み込みネットワークが認識されることをテスト
+        for name in ["bridge", "host", "none"] {
+            let network = Network::builder().id("1").name(name).build().unwrap();
+            assert!(network.is_predefined());
+        }
+    }
+}
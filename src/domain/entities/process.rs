@@ -0,0 +1,81 @@
+// src/domain/entities/process.rs
+// Processes running inside a container
+// コンテナ内で実行中のプロセス
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// A process running inside a container, as reported by `docker top`
+/// `docker top`が報告するコンテナ内で実行中のプロセス
+///
+/// Usage columns are `None` when the engine does not report them
+/// (e.g. Windows containers, which ignore `ps` arguments).
+///
+/// エンジンが報告しない場合（例: `ps`引数を無視する`Windows`コンテナ）、
+/// 使用率の列は`None`になります。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContainerProcess {
+    /// Process ID as seen from the host
+    /// ホストから見たプロセスID
+    pub pid: u32,
+
+    /// User running the process
+    /// プロセスを実行しているユーザー
+    pub user: String,
+
+    /// CPU usage in percent
+    /// CPU使用率（パーセント）
+    pub cpu_percent: Option<f64>,
+
+    /// Memory usage in percent of the host memory
+    /// ホストメモリに対するメモリ使用率（パーセント）
+    pub memory_percent: Option<f64>,
+
+    /// Command line
+    /// コマンドライン
+    pub command: String,
+}
+
+impl ContainerProcess {
+    /// Order processes like `top`: busiest first, then by PID
+    /// `top`と同様に並べる: 負荷の高い順、次にPID順
+    ///
+    /// Processes without a CPU figure sort after those with one.
+    /// CPU値のないプロセスはCPU値のあるプロセスの後に並びます。
+    #[must_use]
+    pub fn cmp_by_cpu(&self, other: &Self) -> Ordering {
+        let cpu = |process: &Self| process.cpu_percent.unwrap_or(-1.0);
+        cpu(other)
+            .total_cmp(&cpu(self))
+            .then(self.pid.cmp(&other.pid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, cpu_percent: Option<f64>) -> ContainerProcess {
+        ContainerProcess {
+            pid,
+            cpu_percent,
+            ..ContainerProcess::default()
+        }
+    }
+
+    #[test]
+    fn test_cmp_by_cpu_puts_busiest_first() {
+        // Test that busy processes come first and unknown usage comes last
+        // 負荷の高いプロセスが先に、使用率不明のプロセスが最後に並ぶことをテスト
+        let mut processes = [
+            process(30, None),
+            process(20, Some(0.5)),
+            process(10, Some(12.0)),
+            process(5, Some(0.5)),
+        ];
+        processes.sort_by(ContainerProcess::cmp_by_cpu);
+
+        let pids: Vec<u32> = processes.iter().map(|process| process.pid).collect();
+        assert_eq!(pids, [10, 5, 20, 30]);
+    }
+}
@@ -0,0 +1,216 @@
+// src/domain/entities/system.rs
+// Docker host overview and disk usage
+// Dockerホストの概要とディスク使用量
+
+use serde::{Deserialize, Serialize};
+
+/// Number of containers on the host by state
+/// 状態別のホスト上のコンテナ数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerCounts {
+    /// Running containers
+    /// 実行中のコンテナ
+    pub running: u64,
+
+    /// Paused containers
+    /// 一時停止中のコンテナ
+    pub paused: u64,
+
+    /// Stopped (created, exited or dead) containers
+    /// 停止中（作成済み、終了、異常終了）のコンテナ
+    pub stopped: u64,
+}
+
+impl ContainerCounts {
+    /// Total number of containers
+    /// コンテナの総数
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.running + self.paused + self.stopped
+    }
+}
+
+/// Disk usage of one kind of Docker object
+/// 1種類の`Docker`オブジェクトのディスク使用量
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::entities::DiskUsageCategory;
+///
+/// let images = DiskUsageCategory {
+///     total_count: 12,
+///     active_count: 4,
+///     size: 4_000_000_000,
+///     reclaimable: 3_000_000_000,
+/// };
+/// assert_eq!(images.reclaimable_percent(), 75);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiskUsageCategory {
+    /// Number of objects
+    /// オブジェクト数
+    pub total_count: u64,
+
+    /// Objects currently in use (by a container, or running)
+    /// 現在使用中のオブジェクト（コンテナが使用中、または実行中）
+    pub active_count: u64,
+
+    /// Disk space used in bytes
+    /// 使用しているディスク容量（バイト）
+    pub size: u64,
+
+    /// Bytes that pruning unused objects would free
+    /// 未使用オブジェクトの削除で解放されるバイト数
+    pub reclaimable: u64,
+}
+
+impl DiskUsageCategory {
+    /// Share of the size that can be reclaimed, in percent
+    /// 回収可能な容量の割合（パーセント）
+    #[must_use]
+    pub fn reclaimable_percent(&self) -> u64 {
+        crate::utils::size_formatter::percentage(self.reclaimable, self.size)
+    }
+}
+
+/// Disk usage split by object kind, as reported by `docker system df`
+/// `docker system df`が報告するオブジェクト種別ごとのディスク使用量
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiskUsage {
+    /// Images (shared layers counted once)
+    /// イメージ（共有レイヤーは一度だけ計上）
+    pub images: DiskUsageCategory,
+
+    /// Writable layers of containers
+    /// コンテナの書き込み可能レイヤー
+    pub containers: DiskUsageCategory,
+
+    /// Local volumes
+    /// ローカルボリューム
+    pub volumes: DiskUsageCategory,
+
+    /// `BuildKit` build cache
+    /// `BuildKit`ビルドキャッシュ
+    pub build_cache: DiskUsageCategory,
+}
+
+impl DiskUsage {
+    /// Categories with their display names, in `docker system df` order
+    /// `docker system df`順の表示名付きカテゴリ
+    #[must_use]
+    pub const fn categories(&self) -> [(&'static str, &DiskUsageCategory); 4] {
+        [
+            ("Images", &self.images),
+            ("Containers", &self.containers),
+            ("Local Volumes", &self.volumes),
+            ("Build Cache", &self.build_cache),
+        ]
+    }
+
+    /// Total disk space used by all categories
+    /// 全カテゴリが使用するディスク容量の合計
+    #[must_use]
+    pub fn total_size(&self) -> u64 {
+        self.categories()
+            .iter()
+            .map(|(_, category)| category.size)
+            .sum()
+    }
+
+    /// Total space that pruning could free
+    /// 削除で解放できる容量の合計
+    #[must_use]
+    pub fn total_reclaimable(&self) -> u64 {
+        self.categories()
+            .iter()
+            .map(|(_, category)| category.reclaimable)
+            .sum()
+    }
+}
+
+/// Overview of a Docker host combining `/info`, `/version` and `/system/df`
+/// `/info`、`/version`、`/system/df`を組み合わせた`Docker`ホストの概要
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemOverview {
+    /// Engine version
+    /// エンジンバージョン
+    pub engine_version: String,
+
+    /// Engine API version
+    /// エンジンの`API`バージョン
+    pub api_version: String,
+
+    /// Host operating system
+    /// ホストのオペレーティングシステム
+    pub os: String,
+
+    /// Host kernel version
+    /// ホストのカーネルバージョン
+    pub kernel_version: String,
+
+    /// Storage driver (e.g. `overlay2`)
+    /// ストレージドライバ（例: `overlay2`）
+    pub storage_driver: String,
+
+    /// Number of CPUs available to the engine
+    /// エンジンが利用できる`CPU`数
+    pub cpus: u64,
+
+    /// Total memory available to the engine in bytes
+    /// エンジンが利用できるメモリの合計（バイト）
+    pub memory: u64,
+
+    /// Containers by state
+    /// 状態別のコンテナ数
+    pub containers: ContainerCounts,
+
+    /// Number of images
+    /// イメージ数
+    pub images: u64,
+
+    /// Disk usage, `None` when the engine cannot report it
+    /// ディスク使用量（エンジンが報告できない場合は`None`）
+    pub disk_usage: Option<DiskUsage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_container_counts_total() {
+        // Test that the total adds every state
+        // 合計が全状態を加算することをテスト
+        let counts = ContainerCounts {
+            running: 3,
+            paused: 1,
+            stopped: 5,
+        };
+        assert_eq!(counts.total(), 9);
+    }
+
+    #[test]
+    fn test_disk_usage_totals() {
+        // Test that totals sum every category
+        // 合計が全カテゴリを加算することをテスト
+        let category = |size, reclaimable| DiskUsageCategory {
+            total_count: 1,
+            active_count: 0,
+            size,
+            reclaimable,
+        };
+        let usage = DiskUsage {
+            images: category(1_000, 400),
+            containers: category(200, 200),
+            volumes: category(300, 0),
+            build_cache: category(500, 100),
+        };
+
+        assert_eq!(usage.total_size(), 2_000);
+        assert_eq!(usage.total_reclaimable(), 700);
+        assert_eq!(usage.categories()[2].0, "Local Volumes");
+        assert_eq!(usage.images.reclaimable_percent(), 40);
+        assert_eq!(DiskUsageCategory::default().reclaimable_percent(), 0);
+    }
+}
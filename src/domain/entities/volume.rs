@@ -0,0 +1,226 @@
+// src/domain/entities/volume.rs
+// Volume domain entity for Docker storage management
+// Dockerストレージ管理用ボリュームドメインエンティティ
+
+use crate::error::{DockaError, DockaResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Docker volume domain entity
+/// Dockerボリュームドメインエンティティ
+///
+/// Usage data (size and reference count) is only known when the engine
+/// computed it; a volume with an unknown reference count is treated as
+/// in use so that it is never offered for removal by mistake.
+///
+/// 使用量データ（サイズと参照数）はエンジンが計算した場合のみ判明します。
+/// 参照数が不明なボリュームは誤って削除対象にならないよう使用中として扱います。
+///
+/// # Examples
+///
+/// ```rust
+/// # use docka::domain::entities::Volume;
+/// let volume = Volume::builder()
+///     .name("postgres-data")
+///     .size(1_000_000)
+///     .ref_count(0)
+///     .build()
+///     .expect("Valid volume");
+///
+/// assert!(!volume.is_in_use());
+/// assert!(!volume.is_anonymous());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Volume {
+    /// Volume name
+    /// ボリューム名
+    pub name: String,
+
+    /// Volume driver (e.g., "local")
+    /// ボリュームドライバ（例："local"）
+    pub driver: String,
+
+    /// Volume creation timestamp, if reported by the engine
+    /// ボリューム作成タイムスタンプ（エンジンが報告した場合）
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// Volume labels (metadata)
+    /// ボリュームラベル（メタデータ）
+    pub labels: HashMap<String, String>,
+
+    /// Disk space used in bytes, if computed
+    /// 使用しているディスク容量（バイト、計算された場合）
+    pub size: Option<u64>,
+
+    /// Number of containers referencing the volume, if computed
+    /// ボリュームを参照しているコンテナ数（計算された場合）
+    pub ref_count: Option<u64>,
+}
+
+impl Volume {
+    /// Create a new volume builder
+    /// 新しいボリュームビルダーを作成
+    #[must_use]
+    pub fn builder() -> VolumeBuilder {
+        VolumeBuilder::new()
+    }
+
+    /// Check whether any container references the volume
+    /// いずれかのコンテナがボリュームを参照しているかチェック
+    ///
+    /// Unknown reference counts count as in use.
+    /// 不明な参照数は使用中として扱います。
+    #[must_use]
+    pub const fn is_in_use(&self) -> bool {
+        !matches!(self.ref_count, Some(0))
+    }
+
+    /// Check whether the volume was created without a name
+    /// ボリュームが名前なしで作成されたかチェック
+    ///
+    /// Anonymous volumes get a 64 character hexadecimal name from the engine.
+    /// 匿名ボリュームはエンジンから64文字の16進数の名前が付けられます。
+    #[must_use]
+    pub fn is_anonymous(&self) -> bool {
+        self.name.len() == 64 && self.name.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+
+    /// Get label value by key
+    /// キーによるラベル値の取得
+    #[must_use]
+    pub fn get_label(&self, key: &str) -> Option<&String> {
+        self.labels.get(key)
+    }
+}
+
+/// Builder for creating Volume instances with validation
+/// 検証付きでVolumeインスタンスを作成するビルダー
+#[derive(Debug, Default)]
+pub struct VolumeBuilder {
+    name: Option<String>,
+    driver: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    labels: HashMap<String, String>,
+    size: Option<u64>,
+    ref_count: Option<u64>,
+}
+
+impl VolumeBuilder {
+    /// Create a new volume builder
+    /// 新しいボリュームビルダーを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set volume name
+    /// ボリューム名を設定
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set volume driver
+    /// ボリュームドライバを設定
+    #[must_use]
+    pub fn driver(mut self, driver: impl Into<String>) -> Self {
+        self.driver = Some(driver.into());
+        self
+    }
+
+    /// Set creation timestamp
+    /// 作成タイムスタンプを設定
+    #[must_use]
+    pub const fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    /// Add a label
+    /// ラベルを追加
+    #[must_use]
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set multiple labels
+    /// 複数のラベルを設定
+    #[must_use]
+    pub fn labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set disk usage in bytes
+    /// ディスク使用量をバイトで設定
+    #[must_use]
+    pub const fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the number of referencing containers
+    /// 参照しているコンテナ数を設定
+    #[must_use]
+    pub const fn ref_count(mut self, ref_count: u64) -> Self {
+        self.ref_count = Some(ref_count);
+        self
+    }
+
+    /// Build the volume with validation
+    /// 検証付きでボリュームを構築
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the name is missing or empty
+    pub fn build(self) -> DockaResult<Volume> {
+        let name = self
+            .name
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| DockaError::invalid_input("Volume name is required"))?;
+
+        Ok(Volume {
+            name,
+            driver: self.driver.unwrap_or_else(|| "local".to_string()),
+            created_at: self.created_at,
+            labels: self.labels,
+            size: self.size,
+            ref_count: self.ref_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_builder_requires_name() {
+        // Test that a volume needs a non-empty name
+        // ボリュームに空でない名前が必要なことをテスト
+        assert!(Volume::builder().build().is_err());
+        assert!(Volume::builder().name("").build().is_err());
+
+        let volume = Volume::builder().name("data").build().unwrap();
+        assert_eq!(volume.driver, "local");
+        assert_eq!(volume.size, None);
+    }
+
+    #[test]
+    fn test_volume_usage_and_anonymity() {
+        // Test in-use detection with unknown counts and anonymous names
+        // 不明な参照数での使用中判定と匿名名の判定をテスト
+        let unknown = Volume::builder().name("data").build().unwrap();
+        assert!(unknown.is_in_use());
+
+        let anonymous = Volume::builder()
+            .name("0123456789abcdef".repeat(4))
+            .ref_count(0)
+            .build()
+            .unwrap();
+        assert!(!anonymous.is_in_use());
+        assert!(anonymous.is_anonymous());
+    }
+}
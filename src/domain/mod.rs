@@ -60,16 +60,16 @@ pub use value_objects::{ContainerId, ContainerStatus};
 /// データアクセス抽象化用リポジトリtrait
 pub use repositories::DockerRepository;
 
-// Phase 2/3 で追加予定の再エクスポート (コメントアウト)
-// Re-exports to be added in Phase 2/3 (commented out)
+/// Volume domain entity
+/// ボリュームドメインエンティティ
+pub use entities::Volume;
 
-// /// Volume domain entity (Phase 2)
-// /// ボリュームドメインエンティティ（Phase 2）
-// pub use entities::Volume;
+/// Network domain entity
+/// ネットワークドメインエンティティ
+pub use entities::Network;
 
-// /// Network domain entity (Phase 2)
-// /// ネットワークドメインエンティティ（Phase 2）
-// pub use entities::Network;
+// Phase 2/3 で追加予定の再エクスポート (コメントアウト)
+// Re-exports to be added in Phase 2/3 (commented out)
 
 // /// Cache repository trait (Phase 1.3)
 // /// キャッシュリポジトリtrait（Phase 1.3）
@@ -2,13 +2,20 @@
 // Docker API operations repository trait
 // Docker API操作リポジトリtrait
 
-use crate::domain::entities::{Container, ContainerFilter};
-use crate::domain::value_objects::ContainerId;
+use std::sync::Arc;
+
+use crate::domain::entities::{
+    ArchiveStream, BuildCacheEntry, Container, ContainerDetails, ContainerExit, ContainerFilter,
+    ContainerProcess, ContainerStats, FilesystemChange, Image, Network, PathKind, SystemOverview,
+    Volume,
+};
+use crate::domain::value_objects::{ContainerId, EngineCapabilities, ResourceLimits, Signal};
 use crate::error::DockaResult;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// Repository trait for Docker API operations
-/// Docker API操作用リポジトリtrait
+/// Docker API操作用リポジトリ`trait`
 ///
 /// This trait abstracts Docker API operations and provides a clean interface
 /// for the application layer. It follows the Repository pattern to decouple
@@ -21,8 +28,8 @@ use async_trait::async_trait;
 /// # Design Principles
 ///
 /// - **Async by Design**: All operations are async for non-blocking UI
-/// - **Error Handling**: Uses DockaResult for consistent error management
-/// - **Type Safety**: Leverages strong typing with ContainerId and Container
+/// - **Error Handling**: Uses `DockaResult` for consistent error management
+/// - **Type Safety**: Leverages strong typing with `ContainerId` and `Container`
 /// - **Testability**: Enables dependency injection and mocking
 ///
 /// # Phase Implementation Status
@@ -181,6 +188,25 @@ pub trait DockerRepository: Send + Sync {
         timeout_seconds: u32,
     ) -> DockaResult<()>;
 
+    /// Send a signal to a container's main process
+    /// コンテナのメインプロセスにシグナルを送信
+    ///
+    /// Unlike stopping, the container keeps running unless the process
+    /// terminates on the signal, so `SIGHUP` can reload a daemon in place.
+    ///
+    /// 停止とは異なり、プロセスがシグナルで終了しない限りコンテナは実行を
+    /// 続けるため、`SIGHUP`でデーモンをその場で再読み込みできます。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `signal` - Signal to send
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::InvalidInput` - When container is not running
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn kill_container(&self, id: &ContainerId, signal: Signal) -> DockaResult<()>;
+
     /// Remove a container
     /// コンテナを削除
     ///
@@ -255,6 +281,326 @@ pub trait DockerRepository: Send + Sync {
     /// * `DockaError::DockerApi` - On Docker API communication errors
     async fn unpause_container(&self, id: &ContainerId) -> DockaResult<()>;
 
+    // =========================================================================
+    // Live configuration changes
+    // 実行中の設定変更
+    // =========================================================================
+
+    /// Current resource limits and restart policy of a container (`docker inspect`)
+    /// コンテナの現在のリソース制限と再起動ポリシー（`docker inspect`）
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn container_resources(&self, id: &ContainerId) -> DockaResult<ResourceLimits>;
+
+    /// Change resource limits and restart policy without recreating the container
+    /// コンテナを再作成せずにリソース制限と再起動ポリシーを変更
+    ///
+    /// Limits apply immediately to running containers. When the memory limit
+    /// changes, the memory plus swap limit is moved along with it so the swap
+    /// allowance stays the same.
+    ///
+    /// 制限は実行中のコンテナに即座に適用されます。メモリ制限が変わると、
+    /// スワップ枠が変わらないようメモリとスワップの合計制限も合わせて変更します。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `limits` - Complete set of limits to apply
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::InvalidInput` - When `ResourceLimits::validate_update` rejects the change
+    /// * `DockaError::DockerApi` - When the engine refuses (e.g. no memory cgroup)
+    async fn update_container(&self, id: &ContainerId, limits: &ResourceLimits) -> DockaResult<()>;
+
+    /// Give a container a new name (`docker rename`)
+    /// コンテナに新しい名前を付ける（`docker rename`）
+    ///
+    /// The ID is unchanged, so the container stays selected after a refresh.
+    /// `ID`は変わらないため、更新後もコンテナは選択されたままです。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `new_name` - New name, checked with `Container::validate_name`
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::InvalidInput` - When the name is invalid or already in use
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn rename_container(&self, id: &ContainerId, new_name: &str) -> DockaResult<()>;
+
+    // =========================================================================
+    // Inspection of running containers
+    // 実行中のコンテナの調査
+    // =========================================================================
+
+    /// List the processes running inside a container
+    /// コンテナ内で実行中のプロセスを一覧表示
+    ///
+    /// Processes are returned in the order the engine reports them.
+    /// プロセスはエンジンが報告した順で返されます。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::InvalidInput` - When the mock container is not running
+    /// * `DockaError::DockerApi` - When the engine refuses (e.g. container not running)
+    async fn top_processes(&self, id: &ContainerId) -> DockaResult<Vec<ContainerProcess>>;
+
+    /// Sample the CPU and memory usage of a running container (`docker stats`)
+    /// 実行中のコンテナのCPUとメモリの使用量を取得（`docker stats`）
+    ///
+    /// Takes a single sample; the CPU figure needs the engine's previous one
+    /// and is `None` right after the container started.
+    /// 1回だけ取得します。CPU値はエンジンの前回のサンプルが必要なため、
+    /// コンテナの起動直後は`None`になります。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::InvalidInput` - When the mock container is not running
+    /// * `DockaError::DockerApi` - When the engine cannot report the usage
+    async fn container_stats(&self, id: &ContainerId) -> DockaResult<ContainerStats>;
+
+    /// List the paths that differ from the container's image (`docker diff`)
+    /// コンテナのイメージと異なるパスを一覧表示（`docker diff`）
+    ///
+    /// Works for stopped containers too, since their writable layer is kept.
+    /// 書き込み可能レイヤーは保持されるため、停止中のコンテナでも動作します。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn container_changes(&self, id: &ContainerId) -> DockaResult<Vec<FilesystemChange>>;
+
+    /// Details the container list does not carry, such as the health log (`docker inspect`)
+    /// ヘルスログなど、コンテナ一覧に含まれない詳細（`docker inspect`）
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn container_details(&self, id: &ContainerId) -> DockaResult<ContainerDetails>;
+
+    /// Container exits (`die` events) reported between `since` and now
+    /// `since`から現在までに報告されたコンテナの終了（`die`イベント）
+    ///
+    /// The engine only keeps a limited event backlog, so older exits may be missing.
+    /// エンジンが保持するイベント履歴には限りがあるため、古い終了は欠ける場合があります。
+    ///
+    /// # Arguments
+    /// * `since` - Earliest exit time to report
+    ///
+    /// # Errors
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn container_exits(&self, since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>>;
+
+    // =========================================================================
+    // Copying files
+    // ファイルのコピー
+    // =========================================================================
+
+    /// Download a file or directory of a container as a tar stream
+    /// コンテナのファイルまたはディレクトリをtarストリームとしてダウンロード
+    ///
+    /// The archive holds one top-level entry named after the last component
+    /// of `path`. Chunks are yielded as the engine sends them.
+    ///
+    /// アーカイブには`path`の最後の要素の名前を持つトップレベルエントリが
+    /// 1つ含まれます。チャンクはエンジンが送信した順に返されます。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `path` - Absolute path inside the container
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - When the path does not exist or the engine refuses
+    async fn download_archive(&self, id: &ContainerId, path: &str) -> DockaResult<ArchiveStream>;
+
+    /// Extract a tar stream into a directory of a container
+    /// tarストリームをコンテナのディレクトリに展開
+    ///
+    /// Existing files are overwritten; callers ask for confirmation first.
+    /// 既存のファイルは上書きされるため、呼び出し側が事前に確認します。
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `path` - Existing directory inside the container
+    /// * `archive` - Tar stream to extract
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - When the directory does not exist or the engine refuses
+    async fn upload_archive(
+        &self,
+        id: &ContainerId,
+        path: &str,
+        archive: ArchiveStream,
+    ) -> DockaResult<()>;
+
+    /// Kind of the entry at a container path, `None` when nothing is there
+    /// コンテナパスにあるエントリの種類（何もない場合は`None`）
+    ///
+    /// # Arguments
+    /// * `id` - Container identifier
+    /// * `path` - Absolute path inside the container
+    ///
+    /// # Errors
+    /// * `DockaError::ContainerNotFound` - When container doesn't exist
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn container_path_kind(
+        &self,
+        id: &ContainerId,
+        path: &str,
+    ) -> DockaResult<Option<PathKind>>;
+
+    /// Capabilities of the connected engine
+    /// 接続中のエンジンの機能情報
+    ///
+    /// Reports the engine version, OS, negotiated API version and the features
+    /// the engine cannot provide, so that callers can hide or disable actions
+    /// instead of failing with a generic API error. Returns
+    /// `EngineCapabilities::unknown()` when the engine cannot be inspected.
+    ///
+    /// エンジンバージョン、OS、ネゴシエート済み`API`バージョン、およびエンジンが
+    /// 提供できない機能を報告し、呼び出し側が汎用的な`API`エラーで失敗する代わりに
+    /// アクションを非表示または無効化できるようにします。エンジンを調査できない場合は
+    /// `EngineCapabilities::unknown()`を返します。
+    async fn capabilities(&self) -> EngineCapabilities;
+
+    /// Get an overview of the Docker host
+    /// `Docker`ホストの概要を取得
+    ///
+    /// Combines engine information, container counts and disk usage split by
+    /// images, containers, volumes and build cache. Disk usage is `None` when
+    /// the engine does not support `/system/df`.
+    ///
+    /// エンジン情報、コンテナ数、およびイメージ・コンテナ・ボリューム・
+    /// ビルドキャッシュ別のディスク使用量を組み合わせます。エンジンが
+    /// `/system/df`をサポートしない場合、ディスク使用量は`None`です。
+    ///
+    /// # Errors
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn system_overview(&self) -> DockaResult<SystemOverview>;
+
+    // =========================================================================
+    // Cleanup: images, volumes, networks and build cache
+    // クリーンアップ: イメージ、ボリューム、ネットワーク、ビルドキャッシュ
+    // =========================================================================
+
+    /// List all images, marking those used by any container
+    /// 全イメージを一覧表示（いずれかのコンテナが使用中のものをマーク）
+    ///
+    /// Untagged images are reported with `<none>` as repository and tag.
+    /// タグなしイメージはリポジトリとタグが`<none>`として報告されます。
+    ///
+    /// # Errors
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn list_images(&self) -> DockaResult<Vec<Image>>;
+
+    /// Remove an image by ID
+    /// IDでイメージを削除
+    ///
+    /// # Errors
+    /// * `DockaError::ImageNotFound` - When the image does not exist
+    /// * `DockaError::DockerApi` - When the engine refuses (e.g. image in use)
+    async fn remove_image(&self, id: &str, force: bool) -> DockaResult<()>;
+
+    /// List all volumes with their reference counts
+    /// 参照数付きで全ボリュームを一覧表示
+    ///
+    /// Sizes are filled in only when the engine supports `/system/df`.
+    /// サイズはエンジンが`/system/df`をサポートする場合のみ設定されます。
+    ///
+    /// # Errors
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>>;
+
+    /// Remove a volume by name
+    /// 名前でボリュームを削除
+    ///
+    /// # Errors
+    /// * `DockaError::DockerApi` - When the volume is missing or still in use
+    async fn remove_volume(&self, name: &str) -> DockaResult<()>;
+
+    /// List all networks with the number of attached containers
+    /// 接続コンテナ数付きで全ネットワークを一覧表示
+    ///
+    /// # Errors
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn list_networks(&self) -> DockaResult<Vec<Network>>;
+
+    /// Remove a network by ID
+    /// IDでネットワークを削除
+    ///
+    /// # Errors
+    /// * `DockaError::DockerApi` - When the network is missing or still in use
+    async fn remove_network(&self, id: &str) -> DockaResult<()>;
+
+    /// List `BuildKit` build cache records
+    /// `BuildKit`ビルドキャッシュレコードを一覧表示
+    ///
+    /// Returns an empty list when the engine cannot report disk usage.
+    /// エンジンがディスク使用量を報告できない場合は空の一覧を返します。
+    ///
+    /// # Errors
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>>;
+
+    /// Remove a build cache record by ID
+    /// IDでビルドキャッシュレコードを削除
+    ///
+    /// # Errors
+    /// * `DockaError::DockerApi` - On Docker API communication errors
+    async fn remove_build_cache(&self, id: &str) -> DockaResult<()>;
+
+    /// Names of hosts whose containers come from a stale snapshot
+    /// コンテナ情報が古いスナップショットになっているホスト名
+    ///
+    /// Only repositories that merge several Docker hosts report anything here:
+    /// when one host fails, its last known rows are kept and the host is listed.
+    /// Single-endpoint repositories report failures as errors instead.
+    ///
+    /// 複数の`Docker`ホストを統合するリポジトリのみが値を返します。
+    /// ホストが失敗した場合は最後に取得した行を保持し、そのホストを列挙します。
+    /// 単一エンドポイントのリポジトリは失敗をエラーとして返します。
+    async fn stale_hosts(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Repository serving a single host of an aggregated view
+    /// 統合ビューの単一ホストを扱うリポジトリ
+    ///
+    /// Lets callers route an action by the `host` a container row was listed
+    /// with instead of by its ID, which may collide across hosts.
+    /// Single-endpoint repositories have no named hosts and return `None`.
+    ///
+    /// コンテナ行に付与された`host`で操作を振り分けるために使用します
+    /// （IDはホスト間で衝突しうるため）。
+    /// 単一エンドポイントのリポジトリは名前付きホストを持たず`None`を返します。
+    fn host_repository(&self, _host: &str) -> Option<Arc<dyn DockerRepository>> {
+        None
+    }
+
     // =========================================================================
     // Phase 2 Implementation Plans (Commented for future development)
     // Phase 2実装計画（将来の開発のためコメントアウト）
@@ -267,10 +613,6 @@ pub trait DockerRepository: Send + Sync {
     // - Get container logs with optional line limit
     // - オプションの行制限付きでコンテナログを取得
     //
-    // async fn get_container_stats(&self, id: &ContainerId) -> DockaResult<ContainerStats>;
-    // - Get real-time resource usage statistics
-    // - リアルタイムリソース使用統計を取得
-    //
     // async fn exec_in_container(&self, id: &ContainerId, command: &[String]) -> DockaResult<ExecResult>;
     // - Execute command inside container
     // - コンテナ内でコマンドを実行
@@ -297,6 +639,12 @@ pub trait DockerRepository: Send + Sync {
     // - 設定から新しいコンテナを作成
 }
 
+/// Tar archives held by the mock, by container ID and path
+/// モックが保持するtarアーカイブ（コンテナIDとパスごと）
+#[cfg(test)]
+pub type MockArchives =
+    std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<(String, String), Vec<u8>>>>;
+
 /// Mock implementation for testing
 /// テスト用Mock実装
 ///
@@ -349,6 +697,73 @@ pub struct MockDockerRepository {
     /// In-memory container storage
     /// インメモリコンテナストレージ
     pub containers: std::sync::Arc<tokio::sync::RwLock<Vec<Container>>>,
+
+    /// Simulates an unreachable daemon when set
+    /// 設定時に到達不能なデーモンをシミュレート
+    pub unavailable: std::sync::Arc<std::sync::atomic::AtomicBool>,
+
+    /// Engine capabilities reported by the mock
+    /// モックが報告するエンジン機能情報
+    pub capabilities: std::sync::Arc<tokio::sync::RwLock<EngineCapabilities>>,
+
+    /// In-memory image storage
+    /// インメモリイメージストレージ
+    pub images: std::sync::Arc<tokio::sync::RwLock<Vec<Image>>>,
+
+    /// In-memory volume storage
+    /// インメモリボリュームストレージ
+    pub volumes: std::sync::Arc<tokio::sync::RwLock<Vec<Volume>>>,
+
+    /// In-memory network storage
+    /// インメモリネットワークストレージ
+    pub networks: std::sync::Arc<tokio::sync::RwLock<Vec<Network>>>,
+
+    /// In-memory build cache storage
+    /// インメモリビルドキャッシュストレージ
+    pub build_cache: std::sync::Arc<tokio::sync::RwLock<Vec<BuildCacheEntry>>>,
+
+    /// Process tables by container ID
+    /// コンテナIDごとのプロセステーブル
+    pub processes: std::sync::Arc<
+        tokio::sync::RwLock<std::collections::HashMap<String, Vec<ContainerProcess>>>,
+    >,
+
+    /// Resource usage by container ID (no figures when absent)
+    /// コンテナIDごとのリソース使用量（ない場合は値なし）
+    pub stats:
+        std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, ContainerStats>>>,
+
+    /// Filesystem changes by container ID
+    /// コンテナIDごとのファイルシステム変更
+    pub changes: std::sync::Arc<
+        tokio::sync::RwLock<std::collections::HashMap<String, Vec<FilesystemChange>>>,
+    >,
+
+    /// Tar archives served for downloads, by container ID and path
+    /// ダウンロード用に提供するtarアーカイブ（コンテナIDとパスごと）
+    pub archives: MockArchives,
+
+    /// Tar archives received by uploads, by container ID and directory
+    /// アップロードで受け取ったtarアーカイブ（コンテナIDとディレクトリごと）
+    pub uploads: MockArchives,
+
+    /// Resource limits by container ID (default limits when absent)
+    /// コンテナIDごとのリソース制限（ない場合はデフォルトの制限）
+    pub resources:
+        std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, ResourceLimits>>>,
+
+    /// Signals sent by `kill_container`, by container ID in sending order
+    /// `kill_container`で送信されたシグナル（コンテナIDごと、送信順）
+    pub signals: std::sync::Arc<tokio::sync::RwLock<Vec<(String, Signal)>>>,
+
+    /// Exits reported by `container_exits`, in any order
+    /// `container_exits`が報告する終了（順不同）
+    pub exits: std::sync::Arc<tokio::sync::RwLock<Vec<ContainerExit>>>,
+
+    /// Inspect details by container ID (empty details when absent)
+    /// コンテナIDごとのinspect詳細（ない場合は空の詳細）
+    pub details:
+        std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, ContainerDetails>>>,
 }
 
 #[cfg(test)]
@@ -380,12 +795,124 @@ impl MockDockerRepository {
         let containers = self.containers.read().await;
         containers.len()
     }
+
+    /// Make every subsequent operation fail as if the daemon were down
+    /// 以降の全操作をデーモン停止時のように失敗させる
+    pub fn set_unavailable(&self, unavailable: bool) {
+        self.unavailable
+            .store(unavailable, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Replace the engine capabilities reported by the mock
+    /// モックが報告するエンジン機能情報を置き換え
+    pub async fn set_capabilities(&self, capabilities: EngineCapabilities) {
+        *self.capabilities.write().await = capabilities;
+    }
+
+    /// Add an image to the mock repository
+    /// モックリポジトリにイメージを追加
+    pub async fn add_image(&self, image: Image) {
+        self.images.write().await.push(image);
+    }
+
+    /// Add a volume to the mock repository
+    /// モックリポジトリにボリュームを追加
+    pub async fn add_volume(&self, volume: Volume) {
+        self.volumes.write().await.push(volume);
+    }
+
+    /// Add a network to the mock repository
+    /// モックリポジトリにネットワークを追加
+    pub async fn add_network(&self, network: Network) {
+        self.networks.write().await.push(network);
+    }
+
+    /// Add a build cache record to the mock repository
+    /// モックリポジトリにビルドキャッシュレコードを追加
+    pub async fn add_build_cache(&self, entry: BuildCacheEntry) {
+        self.build_cache.write().await.push(entry);
+    }
+
+    /// Set the processes reported for a container
+    /// コンテナについて報告するプロセスを設定
+    pub async fn set_processes(&self, id: &str, processes: Vec<ContainerProcess>) {
+        self.processes
+            .write()
+            .await
+            .insert(id.to_string(), processes);
+    }
+
+    /// Set the resource usage reported for a container
+    /// コンテナについて報告するリソース使用量を設定
+    pub async fn set_stats(&self, id: &str, stats: ContainerStats) {
+        self.stats.write().await.insert(id.to_string(), stats);
+    }
+
+    /// Set the resource limits reported for a container
+    /// コンテナについて報告するリソース制限を設定
+    pub async fn set_resources(&self, id: &str, limits: ResourceLimits) {
+        self.resources.write().await.insert(id.to_string(), limits);
+    }
+
+    /// Set the inspect details reported for a container
+    /// コンテナについて報告するinspect詳細を設定
+    pub async fn set_details(&self, id: &str, details: ContainerDetails) {
+        self.details.write().await.insert(id.to_string(), details);
+    }
+
+    /// Add an exit reported by `container_exits`
+    /// `container_exits`が報告する終了を追加
+    pub async fn add_exit(&self, exit: ContainerExit) {
+        self.exits.write().await.push(exit);
+    }
+
+    /// Signals sent so far, with the receiving container ID
+    /// これまでに送信されたシグナルと受信したコンテナID
+    pub async fn sent_signals(&self) -> Vec<(String, Signal)> {
+        self.signals.read().await.clone()
+    }
+
+    /// Set the filesystem changes reported for a container
+    /// コンテナについて報告するファイルシステム変更を設定
+    pub async fn set_changes(&self, id: &str, changes: Vec<FilesystemChange>) {
+        self.changes.write().await.insert(id.to_string(), changes);
+    }
+
+    /// Set the tar archive downloaded for a container path
+    /// コンテナパスについてダウンロードされるtarアーカイブを設定
+    pub async fn set_archive(&self, id: &str, path: &str, archive: Vec<u8>) {
+        self.archives
+            .write()
+            .await
+            .insert((id.to_string(), path.to_string()), archive);
+    }
+
+    /// Tar archive uploaded into a container directory, if any
+    /// コンテナのディレクトリにアップロードされたtarアーカイブ（ある場合）
+    pub async fn uploaded_archive(&self, id: &str, path: &str) -> Option<Vec<u8>> {
+        self.uploads
+            .read()
+            .await
+            .get(&(id.to_string(), path.to_string()))
+            .cloned()
+    }
+
+    /// Return an error when the mock simulates an unreachable daemon
+    /// モックが到達不能なデーモンをシミュレートしている場合にエラーを返す
+    fn ensure_available(&self) -> DockaResult<()> {
+        if self.unavailable.load(std::sync::atomic::Ordering::SeqCst) {
+            Err(crate::error::DockaError::DockerDaemonNotRunning)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
 #[async_trait]
 impl DockerRepository for MockDockerRepository {
     async fn list_containers(&self) -> DockaResult<Vec<Container>> {
+        self.ensure_available()?;
         let containers = self.containers.read().await;
         Ok(containers.clone())
     }
@@ -394,6 +921,7 @@ impl DockerRepository for MockDockerRepository {
         &self,
         filter: &ContainerFilter,
     ) -> DockaResult<Vec<Container>> {
+        self.ensure_available()?;
         let containers = self.containers.read().await;
         Ok(containers
             .iter()
@@ -403,6 +931,7 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn get_container(&self, id: &ContainerId) -> DockaResult<Container> {
+        self.ensure_available()?;
         let containers = self.containers.read().await;
         containers
             .iter()
@@ -414,6 +943,7 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn start_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.ensure_available()?;
         let mut containers = self.containers.write().await;
         if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
             if !container.can_start() {
@@ -432,6 +962,7 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn stop_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.ensure_available()?;
         self.stop_container_with_timeout(id, 10).await
     }
 
@@ -440,6 +971,7 @@ impl DockerRepository for MockDockerRepository {
         id: &ContainerId,
         _timeout_seconds: u32,
     ) -> DockaResult<()> {
+        self.ensure_available()?;
         let mut containers = self.containers.write().await;
         if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
             if !container.can_stop() {
@@ -457,7 +989,33 @@ impl DockerRepository for MockDockerRepository {
         }
     }
 
+    async fn kill_container(&self, id: &ContainerId, signal: Signal) -> DockaResult<()> {
+        self.ensure_available()?;
+        let mut containers = self.containers.write().await;
+        let Some(container) = containers.iter_mut().find(|c| c.id == *id) else {
+            return Err(crate::error::DockaError::ContainerNotFound {
+                name: id.to_string(),
+            });
+        };
+        if !container.can_kill() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Container {} cannot receive {} in status {}",
+                id, signal, container.status
+            )));
+        }
+        // Only SIGKILL is certain to end PID 1; other signals need a handler
+        // PID 1を確実に終了させるのはSIGKILLのみで、他のシグナルはハンドラが必要
+        if signal == Signal::Kill {
+            container.status =
+                crate::domain::value_objects::ContainerStatus::Exited { exit_code: 137 };
+        }
+        drop(containers);
+        self.signals.write().await.push((id.to_string(), signal));
+        Ok(())
+    }
+
     async fn remove_container(&self, id: &ContainerId, force: bool) -> DockaResult<()> {
+        self.ensure_available()?;
         let mut containers = self.containers.write().await;
         if let Some(pos) = containers.iter().position(|c| c.id == *id) {
             let container = &containers[pos];
@@ -468,6 +1026,7 @@ impl DockerRepository for MockDockerRepository {
                 )));
             }
             containers.remove(pos);
+            drop(containers);
             Ok(())
         } else {
             Err(crate::error::DockaError::ContainerNotFound {
@@ -477,6 +1036,7 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn restart_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.ensure_available()?;
         let mut containers = self.containers.write().await;
         if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
             if !container.can_restart() {
@@ -495,6 +1055,11 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn pause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.ensure_available()?;
+        self.capabilities
+            .read()
+            .await
+            .ensure(crate::domain::value_objects::EngineFeature::Pause)?;
         let mut containers = self.containers.write().await;
         if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
             if !container.can_pause() {
@@ -513,6 +1078,11 @@ impl DockerRepository for MockDockerRepository {
     }
 
     async fn unpause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.ensure_available()?;
+        self.capabilities
+            .read()
+            .await
+            .ensure(crate::domain::value_objects::EngineFeature::Pause)?;
         let mut containers = self.containers.write().await;
         if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
             if !container.can_unpause() {
@@ -529,6 +1099,291 @@ impl DockerRepository for MockDockerRepository {
             })
         }
     }
+
+    async fn container_resources(&self, id: &ContainerId) -> DockaResult<ResourceLimits> {
+        self.get_container(id).await?;
+        Ok(self
+            .resources
+            .read()
+            .await
+            .get(id.as_str())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn update_container(&self, id: &ContainerId, limits: &ResourceLimits) -> DockaResult<()> {
+        let current = self.container_resources(id).await?;
+        limits.validate_update(&current)?;
+        let mut updated = limits.clone();
+        updated.memory_swap = limits.memory_swap_after(&current).or(current.memory_swap);
+        self.resources.write().await.insert(id.to_string(), updated);
+        Ok(())
+    }
+
+    async fn rename_container(&self, id: &ContainerId, new_name: &str) -> DockaResult<()> {
+        self.ensure_available()?;
+        crate::domain::entities::Container::validate_name(new_name)?;
+        let mut containers = self.containers.write().await;
+        if containers.iter().any(|c| c.name == new_name && c.id != *id) {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Container name '{new_name}' is already in use"
+            )));
+        }
+        if let Some(container) = containers.iter_mut().find(|c| c.id == *id) {
+            container.name = new_name.to_string();
+            Ok(())
+        } else {
+            Err(crate::error::DockaError::ContainerNotFound {
+                name: id.to_string(),
+            })
+        }
+    }
+
+    async fn top_processes(&self, id: &ContainerId) -> DockaResult<Vec<ContainerProcess>> {
+        let container = self.get_container(id).await?;
+        if !container.is_running() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Container {id} is not running"
+            )));
+        }
+        Ok(self
+            .processes
+            .read()
+            .await
+            .get(id.as_str())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn container_stats(&self, id: &ContainerId) -> DockaResult<ContainerStats> {
+        let container = self.get_container(id).await?;
+        if !container.is_running() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Container {id} is not running"
+            )));
+        }
+        Ok(self
+            .stats
+            .read()
+            .await
+            .get(id.as_str())
+            .copied()
+            .unwrap_or_default())
+    }
+
+    async fn container_changes(&self, id: &ContainerId) -> DockaResult<Vec<FilesystemChange>> {
+        self.get_container(id).await?;
+        Ok(self
+            .changes
+            .read()
+            .await
+            .get(id.as_str())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn container_details(&self, id: &ContainerId) -> DockaResult<ContainerDetails> {
+        self.get_container(id).await?;
+        Ok(self
+            .details
+            .read()
+            .await
+            .get(id.as_str())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn container_exits(&self, since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>> {
+        self.ensure_available()?;
+        Ok(self
+            .exits
+            .read()
+            .await
+            .iter()
+            .filter(|exit| exit.at >= since)
+            .cloned()
+            .collect())
+    }
+
+    async fn download_archive(&self, id: &ContainerId, path: &str) -> DockaResult<ArchiveStream> {
+        self.get_container(id).await?;
+        let archive = self
+            .archives
+            .read()
+            .await
+            .get(&(id.to_string(), path.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                crate::error::DockaError::invalid_input(format!("No such path: {path}"))
+            })?;
+        // Small chunks exercise the streaming decoder
+        // 小さなチャンクでストリーミングデコーダーを検証する
+        let chunks: Vec<DockaResult<bytes::Bytes>> = archive
+            .chunks(300)
+            .map(|chunk| Ok(bytes::Bytes::copy_from_slice(chunk)))
+            .collect();
+        Ok(Box::pin(futures_util::stream::iter(chunks)))
+    }
+
+    async fn upload_archive(
+        &self,
+        id: &ContainerId,
+        path: &str,
+        mut archive: ArchiveStream,
+    ) -> DockaResult<()> {
+        use futures_util::StreamExt;
+
+        self.get_container(id).await?;
+        let mut received = Vec::new();
+        while let Some(chunk) = archive.next().await {
+            received.extend_from_slice(&chunk?);
+        }
+        self.uploads
+            .write()
+            .await
+            .insert((id.to_string(), path.to_string()), received);
+        Ok(())
+    }
+
+    async fn container_path_kind(
+        &self,
+        id: &ContainerId,
+        path: &str,
+    ) -> DockaResult<Option<PathKind>> {
+        self.get_container(id).await?;
+        let Some(archive) = self
+            .archives
+            .read()
+            .await
+            .get(&(id.to_string(), path.to_string()))
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        let mut input = archive.as_slice();
+        match crate::utils::tar::TarReader::new().next_event(&mut input)? {
+            Some(crate::utils::tar::TarEvent::Entry(header)) => Ok(Some(header.kind)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn capabilities(&self) -> EngineCapabilities {
+        self.capabilities.read().await.clone()
+    }
+
+    async fn system_overview(&self) -> DockaResult<SystemOverview> {
+        use crate::domain::entities::{ContainerCounts, DiskUsage};
+        use crate::domain::value_objects::{ContainerStatus, EngineFeature};
+
+        self.ensure_available()?;
+        let containers = self.containers.read().await;
+        let mut counts = ContainerCounts::default();
+        for container in containers.iter() {
+            match container.status {
+                ContainerStatus::Running => counts.running += 1,
+                ContainerStatus::Paused => counts.paused += 1,
+                _ => counts.stopped += 1,
+            }
+        }
+        drop(containers);
+
+        let capabilities = self.capabilities.read().await;
+        Ok(SystemOverview {
+            engine_version: capabilities.engine_version.clone(),
+            os: capabilities.os.clone(),
+            containers: counts,
+            disk_usage: capabilities
+                .supports(EngineFeature::DiskUsage)
+                .then(DiskUsage::default),
+            ..SystemOverview::default()
+        })
+    }
+
+    async fn list_images(&self) -> DockaResult<Vec<Image>> {
+        self.ensure_available()?;
+        Ok(self.images.read().await.clone())
+    }
+
+    async fn remove_image(&self, id: &str, force: bool) -> DockaResult<()> {
+        self.ensure_available()?;
+        let mut images = self.images.write().await;
+        let pos = images
+            .iter()
+            .position(|image| image.id == id)
+            .ok_or_else(|| crate::error::DockaError::ImageNotFound {
+                name: id.to_string(),
+            })?;
+        if !force && !images[pos].can_remove() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Image {id} is used by a container"
+            )));
+        }
+        images.remove(pos);
+        drop(images);
+        Ok(())
+    }
+
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>> {
+        self.ensure_available()?;
+        Ok(self.volumes.read().await.clone())
+    }
+
+    async fn remove_volume(&self, name: &str) -> DockaResult<()> {
+        self.ensure_available()?;
+        let mut volumes = self.volumes.write().await;
+        let pos = volumes
+            .iter()
+            .position(|volume| volume.name == name)
+            .ok_or_else(|| {
+                crate::error::DockaError::invalid_input(format!("No such volume: {name}"))
+            })?;
+        if volumes[pos].is_in_use() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Volume {name} is in use"
+            )));
+        }
+        volumes.remove(pos);
+        drop(volumes);
+        Ok(())
+    }
+
+    async fn list_networks(&self) -> DockaResult<Vec<Network>> {
+        self.ensure_available()?;
+        Ok(self.networks.read().await.clone())
+    }
+
+    async fn remove_network(&self, id: &str) -> DockaResult<()> {
+        self.ensure_available()?;
+        let mut networks = self.networks.write().await;
+        let pos = networks
+            .iter()
+            .position(|network| network.id == id)
+            .ok_or_else(|| {
+                crate::error::DockaError::invalid_input(format!("No such network: {id}"))
+            })?;
+        if networks[pos].is_in_use() || networks[pos].is_predefined() {
+            return Err(crate::error::DockaError::invalid_input(format!(
+                "Network {id} cannot be removed"
+            )));
+        }
+        networks.remove(pos);
+        drop(networks);
+        Ok(())
+    }
+
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>> {
+        self.ensure_available()?;
+        Ok(self.build_cache.read().await.clone())
+    }
+
+    async fn remove_build_cache(&self, id: &str) -> DockaResult<()> {
+        self.ensure_available()?;
+        self.build_cache
+            .write()
+            .await
+            .retain(|entry| entry.id != id || entry.in_use);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -836,6 +1691,36 @@ mod tests {
         assert_eq!(restarted.status, ContainerStatus::Running);
     }
 
+    #[tokio::test]
+    async fn test_mock_repository_top_processes() {
+        // Test that processes are only listed for running containers
+        // プロセスが実行中のコンテナについてのみ一覧表示されることをテスト
+        let repo = MockDockerRepository::new();
+        repo.add_container(create_test_container("web", ContainerStatus::Running).await)
+            .await;
+        repo.add_container(create_test_container("job", ContainerStatus::Stopped).await)
+            .await;
+        repo.set_processes(
+            "web",
+            vec![ContainerProcess {
+                pid: 1,
+                command: "nginx".to_string(),
+                ..ContainerProcess::default()
+            }],
+        )
+        .await;
+
+        let web = ContainerId::new("web").unwrap();
+        let processes = repo.top_processes(&web).await.unwrap();
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].command, "nginx");
+
+        let job = ContainerId::new("job").unwrap();
+        assert!(repo.top_processes(&job).await.is_err());
+        let missing = ContainerId::new("missing").unwrap();
+        assert!(repo.top_processes(&missing).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_mock_repository_error_cases() {
         // Test various error scenarios
@@ -44,6 +44,10 @@
 /// Docker `API操作リポジトリtrait`
 pub mod docker_repository;
 
+/// Repository aggregating several Docker hosts
+/// 複数の`Docker`ホストを統合するリポジトリ
+pub mod multi_host_repository;
+
 // Phase 1.3 で実装予定
 // To be implemented in Phase 1.3
 
@@ -71,6 +75,10 @@ pub mod docker_repository;
 /// モック実装を通じた依存性注入とテストを可能にします。
 pub use docker_repository::DockerRepository;
 
+/// Aggregated multi-host Docker repository
+/// 複数ホスト統合`Docker`リポジトリ
+pub use multi_host_repository::MultiHostDockerRepository;
+
 // Phase 1.3 で有効化予定
 // To be enabled in Phase 1.3
 
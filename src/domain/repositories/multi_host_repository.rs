@@ -0,0 +1,967 @@
+// src/domain/repositories/multi_host_repository.rs
+// Repository that merges containers from several Docker hosts
+// 複数のDockerホストのコンテナを統合するリポジトリ
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use tokio::task::JoinSet;
+use tokio::time::timeout;
+use tracing::warn;
+
+use crate::domain::entities::{
+    ArchiveStream, BuildCacheEntry, Container, ContainerDetails, ContainerExit, ContainerFilter,
+    ContainerProcess, ContainerStats, DiskUsage, FilesystemChange, Image, Network, PathKind,
+    SystemOverview, Volume,
+};
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::{
+    ContainerId, EngineCapabilities, EngineFeature, ResourceLimits, Signal,
+};
+use crate::error::{DockaError, DockaResult};
+
+/// Maximum time a single host may take to answer a listing
+/// 単一ホストが一覧取得に応答するまでの最大時間
+pub const HOST_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A named Docker host participating in the aggregated view
+/// 統合ビューに参加する名前付き`Docker`ホスト
+struct HostRepository {
+    name: String,
+    repository: Arc<dyn DockerRepository>,
+}
+
+/// Last known state of a single host
+/// 単一ホストの最後に取得した状態
+#[derive(Debug, Default, Clone)]
+struct HostSnapshot {
+    containers: Vec<Container>,
+    stale: bool,
+}
+
+/// Docker repository that aggregates several hosts into one list
+/// 複数ホストを1つの一覧に統合する`Docker`リポジトリ
+///
+/// Listings query every host concurrently and tag each container with the
+/// host name. Lifecycle operations are routed to the host that reported the
+/// container. When a host fails, its last known containers are still returned
+/// and the host is reported by `stale_hosts` until it answers again.
+///
+/// 一覧取得は全ホストに並行して問い合わせ、各コンテナにホスト名を付与します。
+/// ライフサイクル操作はコンテナを報告したホストへ振り分けられます。
+/// ホストが失敗した場合でも最後に取得したコンテナは返され、再び応答するまで
+/// `stale_hosts`でそのホストが報告されます。
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use docka::domain::repositories::{DockerRepository, MultiHostDockerRepository};
+///
+/// # async fn example(
+/// #     local: Arc<dyn DockerRepository>,
+/// #     remote: Arc<dyn DockerRepository>,
+/// # ) -> docka::DockaResult<()> {
+/// let repo = MultiHostDockerRepository::new(vec![
+///     ("default".to_string(), local),
+///     ("build-01".to_string(), remote),
+/// ])?;
+///
+/// for container in repo.list_containers().await? {
+///     println!("{:?} {}", container.host, container.display_name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultiHostDockerRepository {
+    hosts: Vec<HostRepository>,
+    snapshots: RwLock<Vec<HostSnapshot>>,
+}
+
+impl MultiHostDockerRepository {
+    /// Create an aggregated repository from named host repositories
+    /// 名前付きホストリポジトリから統合リポジトリを作成
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When no host is given or a name is duplicated
+    pub fn new(hosts: Vec<(String, Arc<dyn DockerRepository>)>) -> DockaResult<Self> {
+        if hosts.is_empty() {
+            return Err(DockaError::invalid_input(
+                "At least one Docker host is required",
+            ));
+        }
+
+        for (index, (name, _)) in hosts.iter().enumerate() {
+            if hosts[..index].iter().any(|(other, _)| other == name) {
+                return Err(DockaError::invalid_input(format!(
+                    "Docker host '{name}' is configured more than once"
+                )));
+            }
+        }
+
+        let snapshots = RwLock::new(vec![HostSnapshot::default(); hosts.len()]);
+        let hosts = hosts
+            .into_iter()
+            .map(|(name, repository)| HostRepository { name, repository })
+            .collect();
+
+        Ok(Self { hosts, snapshots })
+    }
+
+    /// Names of all aggregated hosts in configuration order
+    /// 設定順の全統合ホスト名
+    #[must_use]
+    pub fn host_names(&self) -> Vec<&str> {
+        self.hosts.iter().map(|host| host.name.as_str()).collect()
+    }
+
+    /// Query every host concurrently and update the snapshots
+    /// 全ホストに並行して問い合わせスナップショットを更新
+    ///
+    /// All hosts are awaited before the snapshots are locked, so a slow host
+    /// never blocks readers such as `stale_hosts` or action routing.
+    /// Returns the last error when every host failed.
+    ///
+    /// スナップショットをロックする前に全ホストの応答を待つため、遅いホストが
+    /// `stale_hosts`や操作の振り分けなどの読み取りを妨げることはありません。
+    /// 全ホストが失敗した場合は最後のエラーを返します。
+    async fn refresh_snapshots(&self) -> DockaResult<Vec<Container>> {
+        let mut tasks = JoinSet::new();
+        for (index, host) in self.hosts.iter().enumerate() {
+            let repository = Arc::clone(&host.repository);
+            let name = host.name.clone();
+            tasks.spawn(async move {
+                let result = timeout(HOST_QUERY_TIMEOUT, repository.list_containers())
+                    .await
+                    .unwrap_or_else(|_| Err(host_timed_out(&name)));
+                (index, result)
+            });
+        }
+
+        let mut results = Vec::with_capacity(self.hosts.len());
+        while let Some(joined) = tasks.join_next().await {
+            results.push(joined?);
+        }
+
+        let mut snapshots = self.snapshots.write().await;
+        let mut last_error = None;
+        for (index, result) in results {
+            let host_name = &self.hosts[index].name;
+            match result {
+                Ok(mut containers) => {
+                    for container in &mut containers {
+                        container.host = Some(host_name.clone());
+                    }
+                    snapshots[index] = HostSnapshot {
+                        containers,
+                        stale: false,
+                    };
+                }
+                Err(error) => {
+                    warn!("Docker host '{}' is unavailable: {}", host_name, error);
+                    snapshots[index].stale = true;
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        if let Some(error) = last_error
+            && snapshots.iter().all(|snapshot| snapshot.stale)
+        {
+            return Err(error);
+        }
+
+        Ok(snapshots
+            .iter()
+            .flat_map(|snapshot| snapshot.containers.iter().cloned())
+            .collect())
+    }
+
+    /// Repository of the host with the given name
+    /// 指定した名前のホストのリポジトリ
+    fn named_host(&self, name: &str) -> Option<&HostRepository> {
+        self.hosts.iter().find(|host| host.name == name)
+    }
+
+    /// Find the host that last reported the container
+    /// コンテナを最後に報告したホストを検索
+    ///
+    /// Full IDs are matched exactly. A short ID is accepted only when it
+    /// identifies a single container across all hosts, since the same prefix
+    /// may exist on several hosts.
+    ///
+    /// 完全なIDは完全一致で照合します。同じ接頭辞が複数のホストに存在しうるため、
+    /// 短縮IDは全ホストで1つのコンテナを特定できる場合のみ受け付けます。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When a short ID matches several containers
+    async fn cached_host_for(&self, id: &ContainerId) -> DockaResult<Option<&HostRepository>> {
+        let owner = owning_snapshot(&self.snapshots.read().await, id)?;
+        Ok(owner.map(|index| &self.hosts[index]))
+    }
+
+    /// Resolve the host owning a container, refreshing once if unknown
+    /// コンテナを所有するホストを解決（不明な場合は一度更新）
+    async fn host_for(&self, id: &ContainerId) -> DockaResult<&HostRepository> {
+        if let Some(host) = self.cached_host_for(id).await? {
+            return Ok(host);
+        }
+
+        self.refresh_snapshots().await?;
+        self.cached_host_for(id)
+            .await?
+            .ok_or_else(|| DockaError::ContainerNotFound {
+                name: id.to_string(),
+            })
+    }
+}
+
+#[async_trait]
+impl DockerRepository for MultiHostDockerRepository {
+    async fn list_containers(&self) -> DockaResult<Vec<Container>> {
+        self.refresh_snapshots().await
+    }
+
+    async fn list_containers_filtered(
+        &self,
+        filter: &ContainerFilter,
+    ) -> DockaResult<Vec<Container>> {
+        let containers = self.refresh_snapshots().await?;
+        Ok(containers
+            .into_iter()
+            .filter(|container| filter.matches(container))
+            .collect())
+    }
+
+    async fn get_container(&self, id: &ContainerId) -> DockaResult<Container> {
+        let host = self.host_for(id).await?;
+        let mut container = host.repository.get_container(id).await?;
+        container.host = Some(host.name.clone());
+        Ok(container)
+    }
+
+    async fn start_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .start_container(id)
+            .await
+    }
+
+    async fn stop_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.host_for(id).await?.repository.stop_container(id).await
+    }
+
+    async fn stop_container_with_timeout(
+        &self,
+        id: &ContainerId,
+        timeout_seconds: u32,
+    ) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .stop_container_with_timeout(id, timeout_seconds)
+            .await
+    }
+
+    async fn kill_container(&self, id: &ContainerId, signal: Signal) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .kill_container(id, signal)
+            .await
+    }
+
+    async fn remove_container(&self, id: &ContainerId, force: bool) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .remove_container(id, force)
+            .await
+    }
+
+    async fn restart_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .restart_container(id)
+            .await
+    }
+
+    async fn pause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .pause_container(id)
+            .await
+    }
+
+    async fn unpause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .unpause_container(id)
+            .await
+    }
+
+    async fn container_resources(&self, id: &ContainerId) -> DockaResult<ResourceLimits> {
+        self.host_for(id)
+            .await?
+            .repository
+            .container_resources(id)
+            .await
+    }
+
+    async fn update_container(&self, id: &ContainerId, limits: &ResourceLimits) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .update_container(id, limits)
+            .await
+    }
+
+    async fn rename_container(&self, id: &ContainerId, new_name: &str) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .rename_container(id, new_name)
+            .await
+    }
+
+    async fn top_processes(&self, id: &ContainerId) -> DockaResult<Vec<ContainerProcess>> {
+        self.host_for(id).await?.repository.top_processes(id).await
+    }
+
+    async fn container_stats(&self, id: &ContainerId) -> DockaResult<ContainerStats> {
+        self.host_for(id)
+            .await?
+            .repository
+            .container_stats(id)
+            .await
+    }
+
+    async fn container_changes(&self, id: &ContainerId) -> DockaResult<Vec<FilesystemChange>> {
+        self.host_for(id)
+            .await?
+            .repository
+            .container_changes(id)
+            .await
+    }
+
+    async fn container_details(&self, id: &ContainerId) -> DockaResult<ContainerDetails> {
+        self.host_for(id)
+            .await?
+            .repository
+            .container_details(id)
+            .await
+    }
+
+    async fn container_exits(&self, since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>> {
+        let mut tasks = JoinSet::new();
+        for (index, host) in self.hosts.iter().enumerate() {
+            let repository = Arc::clone(&host.repository);
+            let name = host.name.clone();
+            tasks.spawn(async move {
+                let result = timeout(HOST_QUERY_TIMEOUT, repository.container_exits(since))
+                    .await
+                    .unwrap_or_else(|_| Err(host_timed_out(&name)));
+                (index, result)
+            });
+        }
+
+        // Exits of reachable hosts are still worth showing
+        // 到達可能なホストの終了は表示する価値がある
+        let mut exits = Vec::new();
+        let mut answered = false;
+        let mut last_error = None;
+        while let Some(joined) = tasks.join_next().await {
+            match joined? {
+                (_, Ok(host_exits)) => {
+                    answered = true;
+                    exits.extend(host_exits);
+                }
+                (index, Err(error)) => {
+                    warn!(
+                        "Docker host '{}' did not report container exits: {}",
+                        self.hosts[index].name, error
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        if answered {
+            Ok(exits)
+        } else {
+            Err(last_error.unwrap_or(DockaError::DockerDaemonNotRunning))
+        }
+    }
+
+    async fn download_archive(&self, id: &ContainerId, path: &str) -> DockaResult<ArchiveStream> {
+        self.host_for(id)
+            .await?
+            .repository
+            .download_archive(id, path)
+            .await
+    }
+
+    async fn upload_archive(
+        &self,
+        id: &ContainerId,
+        path: &str,
+        archive: ArchiveStream,
+    ) -> DockaResult<()> {
+        self.host_for(id)
+            .await?
+            .repository
+            .upload_archive(id, path, archive)
+            .await
+    }
+
+    async fn container_path_kind(
+        &self,
+        id: &ContainerId,
+        path: &str,
+    ) -> DockaResult<Option<PathKind>> {
+        self.host_for(id)
+            .await?
+            .repository
+            .container_path_kind(id, path)
+            .await
+    }
+
+    async fn capabilities(&self) -> EngineCapabilities {
+        let mut tasks = JoinSet::new();
+        for (index, host) in self.hosts.iter().enumerate() {
+            let repository = Arc::clone(&host.repository);
+            tasks.spawn(async move {
+                let capabilities = timeout(HOST_QUERY_TIMEOUT, repository.capabilities())
+                    .await
+                    .unwrap_or_default();
+                (index, capabilities)
+            });
+        }
+
+        let mut per_host = vec![EngineCapabilities::unknown(); self.hosts.len()];
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((index, capabilities)) = joined {
+                per_host[index] = capabilities;
+            }
+        }
+
+        merge_capabilities(&self.hosts, &per_host)
+    }
+
+    async fn system_overview(&self) -> DockaResult<SystemOverview> {
+        let mut tasks = JoinSet::new();
+        for (index, host) in self.hosts.iter().enumerate() {
+            let repository = Arc::clone(&host.repository);
+            let name = host.name.clone();
+            tasks.spawn(async move {
+                let result = timeout(HOST_QUERY_TIMEOUT, repository.system_overview())
+                    .await
+                    .unwrap_or_else(|_| Err(host_timed_out(&name)));
+                (index, result)
+            });
+        }
+
+        let mut overviews = Vec::new();
+        let mut last_error = None;
+        while let Some(joined) = tasks.join_next().await {
+            match joined? {
+                (index, Ok(overview)) => overviews.push((index, overview)),
+                (index, Err(error)) => {
+                    tracing::warn!(
+                        "Docker host '{}' did not report its overview: {}",
+                        self.hosts[index].name,
+                        error
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        if overviews.is_empty() {
+            return Err(last_error.unwrap_or(DockaError::DockerDaemonNotRunning));
+        }
+        overviews.sort_by_key(|(index, _)| *index);
+        let overviews: Vec<SystemOverview> = overviews.into_iter().map(|(_, o)| o).collect();
+        Ok(merge_overviews(&overviews))
+    }
+
+    async fn list_images(&self) -> DockaResult<Vec<Image>> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn remove_image(&self, _id: &str, _force: bool) -> DockaResult<()> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn remove_volume(&self, _name: &str) -> DockaResult<()> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn list_networks(&self) -> DockaResult<Vec<Network>> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn remove_network(&self, _id: &str) -> DockaResult<()> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn remove_build_cache(&self, _id: &str) -> DockaResult<()> {
+        Err(cleanup_requires_single_host())
+    }
+
+    async fn stale_hosts(&self) -> Vec<String> {
+        let snapshots = self.snapshots.read().await;
+        snapshots
+            .iter()
+            .zip(&self.hosts)
+            .filter(|(snapshot, _)| snapshot.stale)
+            .map(|(_, host)| host.name.clone())
+            .collect()
+    }
+
+    fn host_repository(&self, host: &str) -> Option<Arc<dyn DockerRepository>> {
+        self.named_host(host)
+            .map(|host| Arc::clone(&host.repository))
+    }
+}
+
+/// Index of the snapshot holding the container, see `cached_host_for`
+/// コンテナを保持するスナップショットの位置（`cached_host_for`を参照）
+fn owning_snapshot(snapshots: &[HostSnapshot], id: &ContainerId) -> DockaResult<Option<usize>> {
+    if let Some(index) = snapshots.iter().position(|snapshot| {
+        snapshot
+            .containers
+            .iter()
+            .any(|container| container.id == *id)
+    }) {
+        return Ok(Some(index));
+    }
+
+    let mut owners = snapshots.iter().enumerate().flat_map(|(index, snapshot)| {
+        snapshot
+            .containers
+            .iter()
+            .filter(|container| container.id.matches(id.as_str()))
+            .map(move |_| index)
+    });
+    match (owners.next(), owners.next()) {
+        (Some(index), None) => Ok(Some(index)),
+        (Some(_), Some(_)) => Err(DockaError::invalid_input(format!(
+            "Container ID '{id}' matches several containers; use the full ID"
+        ))),
+        (None, _) => Ok(None),
+    }
+}
+
+/// Error returned when a host does not answer within `HOST_QUERY_TIMEOUT`
+/// ホストが`HOST_QUERY_TIMEOUT`以内に応答しない場合に返すエラー
+fn host_timed_out(name: &str) -> DockaError {
+    DockaError::timeout(format!("Docker host '{name}'"), HOST_QUERY_TIMEOUT)
+}
+
+/// Error returned for image, volume, network and build cache operations
+/// イメージ、ボリューム、ネットワーク、ビルドキャッシュ操作で返すエラー
+///
+/// Those objects carry no host, so removals could not be routed safely;
+/// cleanup is done one host at a time instead.
+///
+/// これらのオブジェクトはホスト情報を持たず削除を安全に振り分けられないため、
+/// クリーンアップはホストごとに行います。
+fn cleanup_requires_single_host() -> DockaError {
+    DockaError::unsupported(
+        "Cleanup",
+        "switch to a single Docker host to clean up images, volumes and networks",
+    )
+}
+
+/// Combine per-host capabilities into the set every host supports
+/// ホストごとの機能情報を全ホストがサポートする機能の集合に統合
+///
+/// The lowest negotiated API version wins and a feature missing on any host is
+/// reported as unsupported, prefixed with the host name.
+///
+/// ネゴシエート済みの最も低い`API`バージョンが採用され、いずれかのホストで
+/// 欠けている機能はホスト名を付けて非サポートとして報告されます。
+fn merge_capabilities(
+    hosts: &[HostRepository],
+    per_host: &[EngineCapabilities],
+) -> EngineCapabilities {
+    let known: Vec<&EngineCapabilities> = per_host.iter().filter(|c| c.is_known()).collect();
+    let distinct = |field: fn(&EngineCapabilities) -> &str| {
+        let mut values: Vec<&str> = known.iter().map(|c| field(c)).collect();
+        values.sort_unstable();
+        values.dedup();
+        values.join(", ")
+    };
+
+    let mut merged = known
+        .iter()
+        .filter_map(|c| c.api_version)
+        .min()
+        .map_or_else(EngineCapabilities::unknown, |api_version| {
+            EngineCapabilities::new(
+                distinct(|c| c.engine_version.as_str()),
+                distinct(|c| c.os.as_str()),
+                api_version,
+            )
+        });
+
+    for feature in EngineFeature::ALL {
+        if let Some((host, reason)) = hosts
+            .iter()
+            .zip(per_host)
+            .find_map(|(host, c)| c.unsupported_reason(feature).map(|r| (host, r)))
+        {
+            merged = merged.with_unsupported(feature, format!("{}: {reason}", host.name));
+        }
+    }
+
+    merged
+}
+
+/// Add up the overviews of every host that answered
+/// 応答した全ホストの概要を合算
+///
+/// Counts and sizes are summed; versions and drivers that differ are listed.
+/// 数とサイズは合計され、異なるバージョンやドライバは列挙されます。
+fn merge_overviews(overviews: &[SystemOverview]) -> SystemOverview {
+    let distinct = |field: fn(&SystemOverview) -> &str| {
+        let mut values: Vec<&str> = overviews
+            .iter()
+            .map(field)
+            .filter(|value| !value.is_empty())
+            .collect();
+        values.sort_unstable();
+        values.dedup();
+        values.join(", ")
+    };
+
+    let mut merged = SystemOverview {
+        engine_version: distinct(|o| o.engine_version.as_str()),
+        api_version: distinct(|o| o.api_version.as_str()),
+        os: distinct(|o| o.os.as_str()),
+        kernel_version: distinct(|o| o.kernel_version.as_str()),
+        storage_driver: distinct(|o| o.storage_driver.as_str()),
+        ..SystemOverview::default()
+    };
+
+    for overview in overviews {
+        merged.cpus += overview.cpus;
+        merged.memory += overview.memory;
+        merged.images += overview.images;
+        merged.containers.running += overview.containers.running;
+        merged.containers.paused += overview.containers.paused;
+        merged.containers.stopped += overview.containers.stopped;
+
+        if let Some(usage) = &overview.disk_usage {
+            let total = merged.disk_usage.get_or_insert_with(DiskUsage::default);
+            for (sum, part) in [
+                (&mut total.images, &usage.images),
+                (&mut total.containers, &usage.containers),
+                (&mut total.volumes, &usage.volumes),
+                (&mut total.build_cache, &usage.build_cache),
+            ] {
+                sum.total_count += part.total_count;
+                sum.active_count += part.active_count;
+                sum.size += part.size;
+                sum.reclaimable += part.reclaimable;
+            }
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::MockDockerRepository;
+    use crate::domain::value_objects::ContainerStatus;
+
+    fn create_test_container(id: &str, status: ContainerStatus) -> Container {
+        Container::builder()
+            .id(id)
+            .name(format!("test-{id}"))
+            .image("nginx:latest")
+            .status(status)
+            .build()
+            .expect("Valid test container")
+    }
+
+    async fn create_two_hosts() -> (
+        Arc<MockDockerRepository>,
+        Arc<MockDockerRepository>,
+        MultiHostDockerRepository,
+    ) {
+        let local = Arc::new(MockDockerRepository::new());
+        let remote = Arc::new(MockDockerRepository::new());
+        local
+            .add_container(create_test_container("local-web", ContainerStatus::Running))
+            .await;
+        remote
+            .add_container(create_test_container("remote-db", ContainerStatus::Stopped))
+            .await;
+
+        let repo = MultiHostDockerRepository::new(vec![
+            (
+                "default".to_string(),
+                Arc::clone(&local) as Arc<dyn DockerRepository>,
+            ),
+            (
+                "build-01".to_string(),
+                Arc::clone(&remote) as Arc<dyn DockerRepository>,
+            ),
+        ])
+        .expect("Valid hosts");
+
+        (local, remote, repo)
+    }
+
+    #[test]
+    fn test_new_rejects_empty_and_duplicate_hosts() {
+        // Test that host lists are validated
+        // ホスト一覧が検証されることをテスト
+        assert!(MultiHostDockerRepository::new(Vec::new()).is_err());
+
+        let repo: Arc<dyn DockerRepository> = Arc::new(MockDockerRepository::new());
+        let result = MultiHostDockerRepository::new(vec![
+            ("default".to_string(), Arc::clone(&repo)),
+            ("default".to_string(), repo),
+        ]);
+        assert!(matches!(result, Err(DockaError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_list_merges_hosts_and_tags_containers() {
+        // Test that containers from every host are merged and tagged
+        // 全ホストのコンテナが統合されホスト名が付与されることをテスト
+        let (_, _, repo) = create_two_hosts().await;
+
+        let containers = repo.list_containers().await.unwrap();
+        assert_eq!(containers.len(), 2);
+
+        let local = containers
+            .iter()
+            .find(|c| c.id.as_str() == "local-web")
+            .unwrap();
+        let remote = containers
+            .iter()
+            .find(|c| c.id.as_str() == "remote-db")
+            .unwrap();
+        assert_eq!(local.host.as_deref(), Some("default"));
+        assert_eq!(remote.host.as_deref(), Some("build-01"));
+        assert!(repo.stale_hosts().await.is_empty());
+        assert_eq!(repo.host_names(), vec!["default", "build-01"]);
+    }
+
+    #[tokio::test]
+    async fn test_actions_are_routed_to_owning_host() {
+        // Test that lifecycle operations reach the host owning the container
+        // ライフサイクル操作がコンテナを所有するホストに届くことをテスト
+        let (local, remote, repo) = create_two_hosts().await;
+        let remote_id = ContainerId::new("remote-db").unwrap();
+        let local_id = ContainerId::new("local-web").unwrap();
+
+        // Routing works even before the first listing
+        // 最初の一覧取得前でも振り分けが機能する
+        repo.start_container(&remote_id).await.unwrap();
+        repo.stop_container(&local_id).await.unwrap();
+
+        let remote_container = remote.get_container(&remote_id).await.unwrap();
+        let local_container = local.get_container(&local_id).await.unwrap();
+        assert_eq!(remote_container.status, ContainerStatus::Running);
+        assert_eq!(local_container.status, ContainerStatus::Stopped);
+
+        let fetched = repo.get_container(&remote_id).await.unwrap();
+        assert_eq!(fetched.host.as_deref(), Some("build-01"));
+
+        let unknown = ContainerId::new("missing").unwrap();
+        assert!(matches!(
+            repo.restart_container(&unknown).await,
+            Err(DockaError::ContainerNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_short_ids_must_be_unique_across_hosts() {
+        // Test that full IDs route exactly and ambiguous short IDs are refused
+        // 完全なIDは正確に振り分けられ、曖昧な短縮IDは拒否されることをテスト
+        let local = Arc::new(MockDockerRepository::new());
+        let remote = Arc::new(MockDockerRepository::new());
+        local
+            .add_container(create_test_container("abc", ContainerStatus::Stopped))
+            .await;
+        remote
+            .add_container(create_test_container("abcdef", ContainerStatus::Stopped))
+            .await;
+        let repo = MultiHostDockerRepository::new(vec![
+            (
+                "default".to_string(),
+                Arc::clone(&local) as Arc<dyn DockerRepository>,
+            ),
+            (
+                "build-01".to_string(),
+                Arc::clone(&remote) as Arc<dyn DockerRepository>,
+            ),
+        ])
+        .unwrap();
+
+        // "abc" is a prefix of the remote ID but the exact ID of the local one
+        // "abc"はリモートIDの接頭辞だがローカルの完全なID
+        let exact = ContainerId::new("abc").unwrap();
+        repo.start_container(&exact).await.unwrap();
+        let started = local.get_container(&exact).await.unwrap();
+        assert_eq!(started.status, ContainerStatus::Running);
+        let untouched = remote
+            .get_container(&ContainerId::new("abcdef").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(untouched.status, ContainerStatus::Stopped);
+
+        let ambiguous = ContainerId::new("ab").unwrap();
+        assert!(matches!(
+            repo.start_container(&ambiguous).await,
+            Err(DockaError::InvalidInput { .. })
+        ));
+
+        assert!(repo.host_repository("build-01").is_some());
+        assert!(repo.host_repository("missing").is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_unresponsive_host_reports_timeout() {
+        // Test that a host that never answers is reported as timed out, not as down
+        // 応答しないホストが停止ではなくタイムアウトとして報告されることをテスト
+        let (local, remote, repo) = create_two_hosts().await;
+        repo.list_containers().await.unwrap();
+
+        let _blocked = remote.containers.write().await;
+        let containers = repo.list_containers().await.unwrap();
+        assert_eq!(containers.len(), 2);
+        assert_eq!(repo.stale_hosts().await, vec!["build-01".to_string()]);
+
+        let _also_blocked = local.containers.write().await;
+        assert!(matches!(
+            repo.list_containers().await,
+            Err(DockaError::Timeout { seconds: 5, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failing_host_keeps_stale_rows() {
+        // Test that a failing host keeps its last rows and is reported as stale
+        // 失敗したホストが最後の行を保持し古いと報告されることをテスト
+        let (_, remote, repo) = create_two_hosts().await;
+        repo.list_containers().await.unwrap();
+
+        remote.set_unavailable(true);
+        let containers = repo.list_containers().await.unwrap();
+        assert_eq!(containers.len(), 2);
+        assert_eq!(repo.stale_hosts().await, vec!["build-01".to_string()]);
+
+        remote.set_unavailable(false);
+        repo.list_containers().await.unwrap();
+        assert!(repo.stale_hosts().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_all_hosts_failing_returns_error() {
+        // Test that an error is returned only when every host fails
+        // 全ホストが失敗した場合のみエラーが返されることをテスト
+        let (local, remote, repo) = create_two_hosts().await;
+        local.set_unavailable(true);
+        remote.set_unavailable(true);
+
+        assert!(matches!(
+            repo.list_containers().await,
+            Err(DockaError::DockerDaemonNotRunning)
+        ));
+        assert_eq!(repo.stale_hosts().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_are_the_common_subset() {
+        // Test that merged capabilities use the lowest API and any host's limitation
+        // 統合された機能情報が最低APIといずれかのホストの制限を使うことをテスト
+        let (local, remote, repo) = create_two_hosts().await;
+        local
+            .set_capabilities(EngineCapabilities::new(
+                "24.0.7",
+                "linux",
+                crate::domain::value_objects::ApiVersion::new(1, 43),
+            ))
+            .await;
+        remote
+            .set_capabilities(
+                EngineCapabilities::new(
+                    "1.13.1",
+                    "linux",
+                    crate::domain::value_objects::ApiVersion::new(1, 24),
+                )
+                .with_unsupported(EngineFeature::Pause, "no cgroup driver"),
+            )
+            .await;
+
+        let merged = repo.capabilities().await;
+        assert_eq!(
+            merged.api_version,
+            Some(crate::domain::value_objects::ApiVersion::new(1, 24))
+        );
+        assert_eq!(merged.engine_version, "1.13.1, 24.0.7");
+        assert_eq!(
+            merged.unsupported_reason(EngineFeature::Pause).as_deref(),
+            Some("build-01: no cgroup driver")
+        );
+        assert!(!merged.supports(EngineFeature::DiskUsage));
+    }
+
+    #[tokio::test]
+    async fn test_system_overview_sums_hosts() {
+        // Test that the overview adds up every host and skips failing ones
+        // 概要が全ホストを合算し失敗したホストを除外することをテスト
+        let (local, remote, repo) = create_two_hosts().await;
+
+        let overview = repo.system_overview().await.unwrap();
+        assert_eq!(overview.containers.running, 1);
+        assert_eq!(overview.containers.stopped, 1);
+        assert!(overview.disk_usage.is_some());
+
+        remote.set_unavailable(true);
+        let overview = repo.system_overview().await.unwrap();
+        assert_eq!(overview.containers.total(), 1);
+
+        local.set_unavailable(true);
+        assert!(repo.system_overview().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_requires_single_host() {
+        // Test that cleanup listings are refused in the aggregated view
+        // 統合ビューでクリーンアップ用の一覧取得が拒否されることをテスト
+        let (_, _, repo) = create_two_hosts().await;
+
+        assert!(matches!(
+            repo.list_images().await,
+            Err(DockaError::Unsupported { .. })
+        ));
+        assert!(matches!(
+            repo.remove_volume("data").await,
+            Err(DockaError::Unsupported { .. })
+        ));
+    }
+}
@@ -103,6 +103,16 @@ impl ContainerStatus {
         matches!(self, Self::Running | Self::Paused | Self::Restarting)
     }
 
+    /// Check if the container's main process can receive a signal
+    /// コンテナのメインプロセスがシグナルを受け取れるかチェック
+    ///
+    /// The engine refuses to signal paused containers.
+    /// エンジンは一時停止中のコンテナへのシグナル送信を拒否します。
+    #[must_use]
+    pub const fn can_kill(&self) -> bool {
+        matches!(self, Self::Running | Self::Restarting)
+    }
+
     /// Check if the container can be paused
     /// コンテナが一時停止可能かチェック
     #[must_use]
@@ -127,6 +137,23 @@ impl ContainerStatus {
         )
     }
 
+    /// Check if the container's limits and restart policy can be updated
+    /// コンテナの制限と再起動ポリシーを更新できるかチェック
+    ///
+    /// Stopped containers accept updates too; limits apply on the next start.
+    /// 停止中のコンテナも更新を受け付け、制限は次回の開始時に適用されます。
+    #[must_use]
+    pub const fn can_update(&self) -> bool {
+        !matches!(self, Self::Removing | Self::Dead)
+    }
+
+    /// Check if the container can be renamed
+    /// コンテナの名前を変更できるかチェック
+    #[must_use]
+    pub const fn can_rename(&self) -> bool {
+        !matches!(self, Self::Removing)
+    }
+
     /// Check if the container can be restarted
     /// コンテナが再起動可能かチェック
     #[must_use]
@@ -196,6 +223,45 @@ impl ContainerStatus {
         }
     }
 
+    /// Check if the container exited with an error rather than a clean stop
+    /// コンテナが正常な停止ではなくエラーで終了したかチェック
+    ///
+    /// Exit code `0` and `143` (`SIGTERM`, sent by `docker stop`) count as
+    /// clean stops. `137` counts as a failure even though it is also what a
+    /// `docker stop` that hit its timeout leaves behind.
+    ///
+    /// 終了コード`0`と`143`（`docker stop`が送る`SIGTERM`）は正常な停止とみなします。
+    /// `137`はタイムアウトした`docker stop`でも残りますが、失敗とみなします。
+    #[must_use]
+    pub const fn is_failed_exit(&self) -> bool {
+        matches!(self, Self::Exited { exit_code } if *exit_code != 0 && *exit_code != 143)
+    }
+
+    /// Explain what a common exit code means (`None` for other codes)
+    /// よくある終了コードの意味を説明（その他のコードは`None`）
+    ///
+    /// Codes above 128 are `128 + signal number`.
+    /// 128を超えるコードは`128 + シグナル番号`です。
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docka::domain::value_objects::ContainerStatus;
+    /// assert_eq!(ContainerStatus::exit_code_meaning(137), Some("SIGKILL or OOM"));
+    /// assert_eq!(ContainerStatus::exit_code_meaning(1), None);
+    /// ```
+    #[must_use]
+    pub const fn exit_code_meaning(exit_code: i64) -> Option<&'static str> {
+        match exit_code {
+            126 => Some("command not executable"),
+            127 => Some("command not found"),
+            137 => Some("SIGKILL or OOM"),
+            139 => Some("segfault"),
+            143 => Some("SIGTERM"),
+            _ => None,
+        }
+    }
+
     /// Get the display color for UI rendering
     /// UI描画用の表示色を取得
     ///
@@ -218,7 +284,11 @@ impl ContainerStatus {
     /// Docker API文字列からステータスを解析
     ///
     /// Converts Docker API status strings to `ContainerStatus` enum.
+    /// Human-readable list statuses such as `Up 3 minutes (healthy)` are
+    /// accepted too; their health suffix is parsed by `HealthStatus`.
     /// Docker `APIステータス文字列をContainerStatus列挙型に変換します`。
+    /// `Up 3 minutes (healthy)`のような人間向けの一覧ステータスも受け付け、
+    /// そのヘルス接尾辞は`HealthStatus`が解析します。
     #[must_use]
     pub fn from_docker_string(status: &str) -> Self {
         match status.to_lowercase().as_str() {
@@ -231,13 +301,16 @@ impl ContainerStatus {
             "removing" => Self::Removing,
             "dead" => Self::Dead,
             "created" => Self::Created,
+            s if s.starts_with("up ") && s.contains("(paused)") => Self::Paused,
+            s if s == "up" || s.starts_with("up ") => Self::Running,
+            s if s.starts_with("restarting") => Self::Restarting,
             s if s.starts_with("exited") => {
-                // Parse exit code from string like "exited (0)" or "exited (1)"
-                // "exited (0)"や"exited (1)"のような文字列から終了コードを解析
+                // Parse exit code from string like "exited (0)" or "exited (1) 3 days ago"
+                // "exited (0)"や"exited (1) 3 days ago"のような文字列から終了コードを解析
                 let exit_code = s
                     .strip_prefix("exited (")
-                    .and_then(|s| s.strip_suffix(')'))
-                    .and_then(|s| s.parse().ok())
+                    .and_then(|s| s.split_once(')'))
+                    .and_then(|(code, _)| code.parse().ok())
                     .unwrap_or(-1);
                 Self::Exited { exit_code }
             }
@@ -357,6 +430,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_docker_list_status_parsing() {
+        // Test that human-readable list statuses keep the state despite suffixes
+        // 人間向けの一覧ステータスが接尾辞があっても状態を保つことをテスト
+        assert_eq!(
+            ContainerStatus::from_docker_string("Exited (137) 2 hours ago"),
+            ContainerStatus::Exited { exit_code: 137 }
+        );
+        assert_eq!(
+            ContainerStatus::from_docker_string("Up 3 minutes (healthy)"),
+            ContainerStatus::Running
+        );
+        assert_eq!(
+            ContainerStatus::from_docker_string("Up 2 hours (Paused)"),
+            ContainerStatus::Paused
+        );
+        assert_eq!(
+            ContainerStatus::from_docker_string("Restarting (1) 5 seconds ago"),
+            ContainerStatus::Restarting
+        );
+    }
+
     #[test]
     fn test_display_properties() {
         // Test display-related properties
@@ -387,6 +482,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_exit_code_interpretation() {
+        // Test that common exit codes are explained and clean stops are not failures
+        // よくある終了コードが説明され、正常な停止が失敗とみなされないことをテスト
+        assert_eq!(
+            ContainerStatus::exit_code_meaning(127),
+            Some("command not found")
+        );
+        assert_eq!(ContainerStatus::exit_code_meaning(139), Some("segfault"));
+        assert_eq!(ContainerStatus::exit_code_meaning(2), None);
+
+        assert!(ContainerStatus::Exited { exit_code: 137 }.is_failed_exit());
+        assert!(ContainerStatus::Exited { exit_code: 1 }.is_failed_exit());
+        assert!(!ContainerStatus::Exited { exit_code: 0 }.is_failed_exit());
+        assert!(!ContainerStatus::Exited { exit_code: 143 }.is_failed_exit());
+        assert!(!ContainerStatus::Dead.is_failed_exit());
+    }
+
     #[test]
     fn test_serialization() {
         // Test serde serialization/deserialization
@@ -0,0 +1,322 @@
+// src/domain/value_objects/engine_capabilities.rs
+// Docker engine capabilities negotiated at connect time
+// 接続時にネゴシエートされたDockerエンジンの機能
+
+use crate::error::{DockaError, DockaResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Docker Engine API version (`major.minor`)
+/// `Docker` `Engine` `API`バージョン（`major.minor`）
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::ApiVersion;
+///
+/// let version = ApiVersion::parse("1.41").unwrap();
+/// assert!(version >= ApiVersion::new(1, 25));
+/// assert_eq!(version.to_string(), "1.41");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ApiVersion {
+    /// Major version number
+    /// メジャーバージョン番号
+    pub major: u32,
+
+    /// Minor version number
+    /// マイナーバージョン番号
+    pub minor: u32,
+}
+
+impl ApiVersion {
+    /// Create a version from its components
+    /// 構成要素からバージョンを作成
+    #[must_use]
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parse a version string such as `1.43`
+    /// `1.43`のようなバージョン文字列を解析
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the string is not `major.minor`
+    pub fn parse(version: &str) -> DockaResult<Self> {
+        let invalid = || DockaError::invalid_input(format!("Invalid API version '{version}'"));
+
+        let (major, minor) = version.trim().split_once('.').ok_or_else(invalid)?;
+        Ok(Self {
+            major: major.parse().map_err(|_| invalid())?,
+            minor: minor.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Optional engine feature used by docka
+/// `docka`が使用するオプションのエンジン機能
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EngineFeature {
+    /// Pausing and unpausing containers (needs the cgroup freezer)
+    /// コンテナの一時停止と再開（`cgroup` `freezer`が必要）
+    Pause,
+
+    /// Disk usage summary from `/system/df` (API 1.25+)
+    /// `/system/df`によるディスク使用量の概要（`API` 1.25以降）
+    DiskUsage,
+}
+
+impl EngineFeature {
+    /// Every optional feature, for checks that need to enumerate them
+    /// 列挙が必要なチェック用の全オプション機能
+    pub const ALL: [Self; 2] = [Self::Pause, Self::DiskUsage];
+
+    /// Human readable feature name
+    /// 人間が読める機能名
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Pause => "pause",
+            Self::DiskUsage => "disk usage",
+        }
+    }
+
+    /// Oldest Engine API version that provides the feature
+    /// 機能を提供する最も古い`Engine` `API`バージョン
+    #[must_use]
+    pub const fn min_api_version(self) -> Option<ApiVersion> {
+        match self {
+            Self::Pause => None,
+            Self::DiskUsage => Some(ApiVersion::new(1, 25)),
+        }
+    }
+}
+
+impl Display for EngineFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// What the connected Docker engine is and which optional features it supports
+/// 接続中の`Docker`エンジンの情報とサポートするオプション機能
+///
+/// Built from `/version` and `/info` when connecting, after the client API
+/// version has been negotiated down to what the engine accepts.
+/// The UI consults it to hide or disable actions instead of letting them fail.
+///
+/// 接続時に、クライアントの`API`バージョンをエンジンが受け付ける値まで
+/// ネゴシエートした後、`/version`と`/info`から構築されます。
+/// `UI`はこれを参照し、失敗させる代わりにアクションを非表示または無効化します。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::{ApiVersion, EngineCapabilities, EngineFeature};
+///
+/// let capabilities = EngineCapabilities::new("24.0.7", "linux", ApiVersion::new(1, 43))
+///     .with_unsupported(EngineFeature::Pause, "rootless engine on cgroup v1");
+///
+/// assert!(!capabilities.supports(EngineFeature::Pause));
+/// assert!(capabilities.ensure(EngineFeature::Pause).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineCapabilities {
+    /// Engine version (e.g. `24.0.7`, `4.9.3` for Podman)
+    /// エンジンバージョン（例: `24.0.7`、`Podman`では`4.9.3`）
+    pub engine_version: String,
+
+    /// Engine operating system (e.g. `linux`, `Ubuntu 22.04.4 LTS`)
+    /// エンジンのオペレーティングシステム（例: `linux`、`Ubuntu 22.04.4 LTS`）
+    pub os: String,
+
+    /// Negotiated Engine API version (`None` until the engine has been inspected)
+    /// ネゴシエート済みの`Engine` `API`バージョン（エンジン調査前は`None`）
+    pub api_version: Option<ApiVersion>,
+
+    /// Features the engine reported as unavailable, with the reason
+    /// エンジンが利用不可と報告した機能とその理由
+    unsupported: Vec<(EngineFeature, String)>,
+}
+
+impl EngineCapabilities {
+    /// Create capabilities with every feature available for the API version
+    /// `API`バージョンで利用可能な全機能を持つ機能情報を作成
+    #[must_use]
+    pub fn new(
+        engine_version: impl Into<String>,
+        os: impl Into<String>,
+        api_version: ApiVersion,
+    ) -> Self {
+        Self {
+            engine_version: engine_version.into(),
+            os: os.into(),
+            api_version: Some(api_version),
+            unsupported: Vec::new(),
+        }
+    }
+
+    /// Capabilities of an engine that has not been inspected yet
+    /// まだ調査されていないエンジンの機能情報
+    ///
+    /// Nothing is restricted so that operations are attempted normally.
+    /// 操作が通常通り試行されるよう何も制限しません。
+    #[must_use]
+    pub const fn unknown() -> Self {
+        Self {
+            engine_version: String::new(),
+            os: String::new(),
+            api_version: None,
+            unsupported: Vec::new(),
+        }
+    }
+
+    /// Check whether the engine has been inspected
+    /// エンジンが調査済みかチェック
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        self.api_version.is_some()
+    }
+
+    /// Mark a feature as unsupported by the engine
+    /// 機能をエンジンがサポートしないものとして記録
+    #[must_use]
+    pub fn with_unsupported(mut self, feature: EngineFeature, reason: impl Into<String>) -> Self {
+        self.unsupported
+            .retain(|(existing, _)| *existing != feature);
+        self.unsupported.push((feature, reason.into()));
+        self
+    }
+
+    /// Why a feature is unavailable, or `None` when it is supported
+    /// 機能が利用できない理由（サポートされている場合は`None`）
+    #[must_use]
+    pub fn unsupported_reason(&self, feature: EngineFeature) -> Option<String> {
+        if let Some((_, reason)) = self
+            .unsupported
+            .iter()
+            .find(|(existing, _)| *existing == feature)
+        {
+            return Some(reason.clone());
+        }
+
+        let api_version = self.api_version?;
+        feature
+            .min_api_version()
+            .filter(|required| api_version < *required)
+            .map(|required| format!("requires API {required}, engine speaks {api_version}"))
+    }
+
+    /// Check whether a feature can be used
+    /// 機能が使用可能かチェック
+    #[must_use]
+    pub fn supports(&self, feature: EngineFeature) -> bool {
+        self.unsupported_reason(feature).is_none()
+    }
+
+    /// Fail with `DockaError::Unsupported` when a feature cannot be used
+    /// 機能が使用できない場合に`DockaError::Unsupported`で失敗
+    ///
+    /// # Errors
+    /// * `DockaError::Unsupported` - When the engine does not provide the feature
+    pub fn ensure(&self, feature: EngineFeature) -> DockaResult<()> {
+        self.unsupported_reason(feature).map_or(Ok(()), |reason| {
+            Err(DockaError::unsupported(feature.label(), reason))
+        })
+    }
+
+    /// Short description for the status bar (e.g. `24.0.7 · API 1.43 · linux`)
+    /// ステータスバー用の短い説明（例: `24.0.7 · API 1.43 · linux`）
+    ///
+    /// Returns `None` until the engine has been inspected.
+    /// エンジン調査前は`None`を返します。
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        self.api_version
+            .map(|api_version| format!("{} · API {api_version} · {}", self.engine_version, self.os))
+    }
+}
+
+impl Default for EngineCapabilities {
+    fn default() -> Self {
+        Self::unknown()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_version_parse_and_order() {
+        // Test parsing and ordering of API versions
+        // APIバージョンの解析と順序のテスト
+        let version = ApiVersion::parse("1.41").unwrap();
+        assert_eq!(version, ApiVersion::new(1, 41));
+        assert!(ApiVersion::new(1, 9) < ApiVersion::new(1, 24));
+        assert!(ApiVersion::new(2, 0) > ApiVersion::new(1, 99));
+
+        for invalid in ["", "1", "1.x", "v1.41"] {
+            assert!(ApiVersion::parse(invalid).is_err(), "{invalid} should fail");
+        }
+    }
+
+    #[test]
+    fn test_unknown_capabilities_support_everything() {
+        // Test that uninspected engines restrict nothing
+        // 未調査のエンジンが何も制限しないことをテスト
+        let capabilities = EngineCapabilities::unknown();
+        assert!(!capabilities.is_known());
+        assert!(capabilities.summary().is_none());
+        assert!(capabilities.supports(EngineFeature::Pause));
+        assert!(capabilities.supports(EngineFeature::DiskUsage));
+        assert!(capabilities.ensure(EngineFeature::Pause).is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_feature_reports_reason() {
+        // Test that engine-reported limitations surface as Unsupported errors
+        // エンジンが報告した制限がUnsupportedエラーとして現れることをテスト
+        let capabilities = EngineCapabilities::new("20.10.7", "linux", ApiVersion::new(1, 41))
+            .with_unsupported(EngineFeature::Pause, "no cgroup driver");
+
+        assert!(!capabilities.supports(EngineFeature::Pause));
+        assert_eq!(
+            capabilities
+                .unsupported_reason(EngineFeature::Pause)
+                .as_deref(),
+            Some("no cgroup driver")
+        );
+        assert!(matches!(
+            capabilities.ensure(EngineFeature::Pause),
+            Err(DockaError::Unsupported { .. })
+        ));
+        assert_eq!(
+            capabilities.summary().as_deref(),
+            Some("20.10.7 · API 1.41 · linux")
+        );
+    }
+
+    #[test]
+    fn test_features_gated_by_api_version() {
+        // Test that old engines lose features introduced by newer API versions
+        // 古いエンジンで新しいAPIバージョンの機能が無効になることをテスト
+        let old = EngineCapabilities::new("1.12.6", "linux", ApiVersion::new(1, 24));
+        assert!(!old.supports(EngineFeature::DiskUsage));
+        assert!(
+            old.unsupported_reason(EngineFeature::DiskUsage)
+                .unwrap()
+                .contains("1.25")
+        );
+        assert!(old.supports(EngineFeature::Pause));
+
+        let current = EngineCapabilities::new("24.0.7", "linux", ApiVersion::new(1, 43));
+        assert!(current.supports(EngineFeature::DiskUsage));
+    }
+}
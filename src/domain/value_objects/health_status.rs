@@ -0,0 +1,133 @@
+// src/domain/value_objects/health_status.rs
+// Health check status reported alongside the container state
+// コンテナの状態と並んで報告されるヘルスチェックステータス
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Result of a container's health check, independent of its run state
+/// 実行状態とは独立したコンテナのヘルスチェックの結果
+///
+/// A running container can be unhealthy, so health is kept next to
+/// `ContainerStatus` instead of being folded into it.
+/// 実行中のコンテナが異常な場合もあるため、ヘルスは`ContainerStatus`に
+/// 含めずその隣に保持します。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::HealthStatus;
+///
+/// assert_eq!(HealthStatus::from_docker_string("Up 3 minutes (unhealthy)"), HealthStatus::Unhealthy);
+/// assert_eq!(HealthStatus::from_docker_string("Up 5 seconds (health: starting)"), HealthStatus::Starting);
+/// assert_eq!(HealthStatus::from_docker_string("Up 2 hours"), HealthStatus::None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum HealthStatus {
+    /// No health check is configured, or the container is not running
+    /// ヘルスチェックが未設定、またはコンテナが実行されていない
+    #[default]
+    None,
+
+    /// Within the start period, before the first conclusive probe
+    /// 最初の確定的なプローブより前の開始期間中
+    Starting,
+
+    /// The last probes succeeded
+    /// 直近のプローブが成功した
+    Healthy,
+
+    /// As many probes in a row failed as the check's retry count
+    /// チェックの再試行回数と同じ数のプローブが連続で失敗した
+    Unhealthy,
+}
+
+impl HealthStatus {
+    /// Every status, in filter order
+    /// フィルタ順の全ステータス
+    pub const ALL: [Self; 4] = [Self::Unhealthy, Self::Starting, Self::Healthy, Self::None];
+
+    /// Parse the health suffix of a list status such as `Up 3 minutes (healthy)`
+    /// `Up 3 minutes (healthy)`のような一覧ステータスのヘルス接尾辞を解析
+    #[must_use]
+    pub fn from_docker_string(status: &str) -> Self {
+        let status = status.to_lowercase();
+        if status.contains("(unhealthy)") {
+            Self::Unhealthy
+        } else if status.contains("(healthy)") {
+            Self::Healthy
+        } else if status.contains("(health: starting)") {
+            Self::Starting
+        } else {
+            Self::None
+        }
+    }
+
+    /// Parse the health state reported by inspect (`State.Health.Status`)
+    /// inspectが報告するヘルス状態（`State.Health.Status`）を解析
+    #[must_use]
+    pub fn parse(state: &str) -> Self {
+        match state.to_lowercase().as_str() {
+            "starting" => Self::Starting,
+            "healthy" => Self::Healthy,
+            "unhealthy" => Self::Unhealthy,
+            _ => Self::None,
+        }
+    }
+
+    /// Short lowercase label, e.g. `unhealthy`
+    /// 短い小文字のラベル（例: `unhealthy`）
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Starting => "starting",
+            Self::Healthy => "healthy",
+            Self::Unhealthy => "unhealthy",
+        }
+    }
+
+    /// Check whether the container has a health check reporting a result
+    /// コンテナに結果を報告するヘルスチェックがあるかチェック
+    #[must_use]
+    pub const fn is_checked(self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+impl Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list_and_inspect_forms() {
+        // Test that list suffixes and inspect states map to the same statuses
+        // 一覧の接尾辞とinspectの状態が同じステータスに対応することをテスト
+        let cases = [
+            ("Up 3 minutes (healthy)", "healthy", HealthStatus::Healthy),
+            (
+                "Up 1 hour (Unhealthy)",
+                "unhealthy",
+                HealthStatus::Unhealthy,
+            ),
+            (
+                "Up 4 seconds (health: starting)",
+                "starting",
+                HealthStatus::Starting,
+            ),
+            ("Exited (0) 2 days ago", "none", HealthStatus::None),
+        ];
+        for (list, inspect, expected) in cases {
+            assert_eq!(HealthStatus::from_docker_string(list), expected, "{list}");
+            assert_eq!(HealthStatus::parse(inspect), expected, "{inspect}");
+            assert_eq!(HealthStatus::parse(expected.label()), expected);
+        }
+        assert!(!HealthStatus::None.is_checked());
+    }
+}
@@ -21,6 +21,30 @@ pub mod container_id;
 /// 状態遷移を持つコンテナステータス列挙型
 pub mod container_status;
 
+/// Health check status kept next to the container status
+/// コンテナステータスと並べて保持するヘルスチェックステータス
+pub mod health_status;
+
+/// Docker engine capabilities and API version
+/// `Docker`エンジンの機能と`API`バージョン
+pub mod engine_capabilities;
+
+/// Signals sent to a container's main process
+/// コンテナのメインプロセスに送信するシグナル
+pub mod signal;
+
+/// Restart policy of a container
+/// コンテナの再起動ポリシー
+pub mod restart_policy;
+
+/// Resource limits changeable on a live container
+/// 実行中のコンテナで変更できるリソース制限
+pub mod resource_limits;
+
+/// Container ports and the host endpoints they are published on
+/// コンテナのポートとそれが公開されるホストのエンドポイント
+pub mod port_mapping;
+
 // Re-export for convenient access
 // 便利なアクセスのため再エクスポート
 
@@ -31,3 +55,27 @@ pub use container_id::ContainerId;
 /// Docker container status enumeration
 /// Dockerコンテナステータス列挙型
 pub use container_status::ContainerStatus;
+
+/// Health check result of a container
+/// コンテナのヘルスチェックの結果
+pub use health_status::HealthStatus;
+
+/// Engine capabilities negotiated at connect time
+/// 接続時にネゴシエートされたエンジン機能
+pub use engine_capabilities::{ApiVersion, EngineCapabilities, EngineFeature};
+
+/// Signal sent by `docker kill`
+/// `docker kill`で送信するシグナル
+pub use signal::Signal;
+
+/// Restart policy as in `docker run --restart`
+/// `docker run --restart`と同じ再起動ポリシー
+pub use restart_policy::RestartPolicy;
+
+/// Limits changed by `docker update`
+/// `docker update`で変更する制限
+pub use resource_limits::ResourceLimits;
+
+/// Port mapping as in `docker port`
+/// `docker port`と同じポートマッピング
+pub use port_mapping::{PortMapping, PortProtocol};
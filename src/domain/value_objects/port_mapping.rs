@@ -0,0 +1,202 @@
+// src/domain/value_objects/port_mapping.rs
+// Container ports and the host endpoints they are published on
+// コンテナのポートとそれが公開されるホストのエンドポイント
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Transport protocol of a container port
+/// コンテナポートのトランスポートプロトコル
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum PortProtocol {
+    /// Transmission Control Protocol
+    /// `TCP`
+    #[default]
+    Tcp,
+
+    /// User Datagram Protocol
+    /// `UDP`
+    Udp,
+
+    /// Stream Control Transmission Protocol
+    /// `SCTP`
+    Sctp,
+}
+
+impl PortProtocol {
+    /// Parse the protocol as written by the engine (`tcp`, `udp`, `sctp`)
+    /// エンジンの表記（`tcp`、`udp`、`sctp`）からプロトコルを解析
+    ///
+    /// The engine omits the protocol for TCP, so unknown text means TCP.
+    /// エンジンは`TCP`のプロトコルを省略するため、不明なテキストは`TCP`とみなします。
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        match text.trim().to_lowercase().as_str() {
+            "udp" => Self::Udp,
+            "sctp" => Self::Sctp,
+            _ => Self::Tcp,
+        }
+    }
+
+    /// Lower-case name as used by the Docker CLI
+    /// `Docker` `CLI`と同じ小文字の名前
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+            Self::Sctp => "sctp",
+        }
+    }
+}
+
+impl Display for PortProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A container port, and the host endpoint it is published on if any
+/// コンテナのポートと、公開されている場合はそのホストのエンドポイント
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::{PortMapping, PortProtocol};
+///
+/// let mapping = PortMapping::new(5432, PortProtocol::Tcp).published(Some("0.0.0.0"), 15432);
+/// assert_eq!(mapping.to_string(), "0.0.0.0:15432 → 5432/tcp");
+/// assert_eq!(PortMapping::new(53, PortProtocol::Udp).to_string(), "53/udp");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PortMapping {
+    /// Port inside the container
+    /// コンテナ内のポート
+    pub container_port: u16,
+
+    /// Transport protocol
+    /// トランスポートプロトコル
+    pub protocol: PortProtocol,
+
+    /// Host address the port is bound to (`None` or `0.0.0.0` for every address)
+    /// ポートがバインドされるホストのアドレス（`None`または`0.0.0.0`は全アドレス）
+    pub host_ip: Option<String>,
+
+    /// Host port (`None` when the port is only exposed, not published)
+    /// ホストのポート（公開されず`expose`のみの場合は`None`）
+    pub host_port: Option<u16>,
+}
+
+impl PortMapping {
+    /// Create an unpublished container port
+    /// 公開されていないコンテナポートを作成
+    #[must_use]
+    pub const fn new(container_port: u16, protocol: PortProtocol) -> Self {
+        Self {
+            container_port,
+            protocol,
+            host_ip: None,
+            host_port: None,
+        }
+    }
+
+    /// Publish the port on a host address and port
+    /// ポートをホストのアドレスとポートで公開
+    ///
+    /// An empty address is treated as every address.
+    /// 空のアドレスは全アドレスとして扱います。
+    #[must_use]
+    pub fn published(mut self, host_ip: Option<&str>, host_port: u16) -> Self {
+        self.host_ip = host_ip
+            .map(str::trim)
+            .filter(|ip| !ip.is_empty())
+            .map(str::to_string);
+        self.host_port = Some(host_port);
+        self
+    }
+
+    /// Check whether the port is reachable from the host
+    /// ポートがホストから到達可能かチェック
+    #[must_use]
+    pub const fn is_published(&self) -> bool {
+        self.host_port.is_some()
+    }
+
+    /// Host endpoint as `ip:port` (`[ip]:port` for IPv6), `None` when unpublished
+    /// `ip:port`形式（`IPv6`は`[ip]:port`）のホストエンドポイント（公開されていない場合は`None`）
+    #[must_use]
+    pub fn host_endpoint(&self) -> Option<String> {
+        let port = self.host_port?;
+        let ip = self.host_ip.as_deref().unwrap_or("0.0.0.0");
+        Some(if ip.contains(':') {
+            format!("[{ip}]:{port}")
+        } else {
+            format!("{ip}:{port}")
+        })
+    }
+
+    /// Check whether both mappings claim the same host port
+    /// 両方のマッピングが同じホストポートを使用するかチェック
+    ///
+    /// Addresses overlap when they are equal or either one binds every address.
+    /// アドレスは等しい場合、またはどちらかが全アドレスにバインドする場合に重なります。
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        let binds_all = |ip: Option<&str>| matches!(ip, None | Some("0.0.0.0" | "::"));
+        self.host_port.is_some()
+            && self.host_port == other.host_port
+            && self.protocol == other.protocol
+            && (binds_all(self.host_ip.as_deref())
+                || binds_all(other.host_ip.as_deref())
+                || self.host_ip == other.host_ip)
+    }
+}
+
+impl Display for PortMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(endpoint) = self.host_endpoint() {
+            write!(f, "{endpoint} → ")?;
+        }
+        write!(f, "{}/{}", self.container_port, self.protocol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_mapping_display() {
+        // Test published, unpublished and IPv6 mappings
+        // 公開、非公開、IPv6のマッピングをテスト
+        let published = PortMapping::new(80, PortProtocol::Tcp).published(None, 8080);
+        assert_eq!(published.to_string(), "0.0.0.0:8080 → 80/tcp");
+        assert!(published.is_published());
+
+        let ipv6 = PortMapping::new(53, PortProtocol::parse("udp")).published(Some("::1"), 5353);
+        assert_eq!(ipv6.host_endpoint().as_deref(), Some("[::1]:5353"));
+        assert_eq!(ipv6.to_string(), "[::1]:5353 → 53/udp");
+
+        let exposed = PortMapping::new(9229, PortProtocol::parse(""));
+        assert_eq!(exposed.to_string(), "9229/tcp");
+        assert!(!exposed.is_published());
+    }
+
+    #[test]
+    fn test_port_mapping_conflicts() {
+        // Test that the same host port conflicts unless addresses or protocols differ
+        // アドレスまたはプロトコルが異なる場合を除き、同じホストポートが競合することをテスト
+        let postgres =
+            |ip: Option<&str>| PortMapping::new(5432, PortProtocol::Tcp).published(ip, 5432);
+        assert!(postgres(None).conflicts_with(&postgres(Some("127.0.0.1"))));
+        assert!(postgres(Some("127.0.0.1")).conflicts_with(&postgres(Some("127.0.0.1"))));
+        assert!(!postgres(Some("127.0.0.1")).conflicts_with(&postgres(Some("10.0.0.5"))));
+
+        let udp = PortMapping::new(5432, PortProtocol::Udp).published(None, 5432);
+        assert!(!postgres(None).conflicts_with(&udp));
+        let exposed = PortMapping::new(5432, PortProtocol::Tcp);
+        assert!(!exposed.conflicts_with(&exposed));
+    }
+}
@@ -0,0 +1,253 @@
+// src/domain/value_objects/resource_limits.rs
+// Resource limits and restart policy that can be changed on a live container
+// 実行中のコンテナで変更できるリソース制限と再起動ポリシー
+
+use crate::domain::value_objects::RestartPolicy;
+use crate::error::{DockaError, DockaResult};
+use crate::utils::size_formatter::format_memory_size;
+use serde::{Deserialize, Serialize};
+
+/// Smallest memory limit accepted by the engine (6 MiB)
+/// エンジンが受け付ける最小のメモリ制限（6 MiB）
+pub const MIN_MEMORY: u64 = 6 * 1024 * 1024;
+
+/// Smallest CPU quota accepted by the engine, in microseconds
+/// エンジンが受け付ける最小の`CPU`クォータ（マイクロ秒）
+pub const MIN_CPU_QUOTA: u64 = 1_000;
+
+/// CPU period the engine uses when none is configured, in microseconds
+/// 未設定時にエンジンが使用する`CPU`期間（マイクロ秒）
+pub const DEFAULT_CPU_PERIOD: u64 = 100_000;
+
+/// Limits of a container that `docker update` can change without recreating it
+/// `docker update`で再作成せずに変更できるコンテナの制限
+///
+/// `None` means no limit. The engine cannot remove a memory limit, memory
+/// reservation or CPU share once set, so `validate_update` rejects that
+/// instead of letting the change be silently ignored.
+///
+/// `None`は制限なしを意味します。エンジンは一度設定したメモリ制限、
+/// メモリ予約、`CPU`シェアを削除できないため、変更が黙って無視される
+/// 代わりに`validate_update`がそれを拒否します。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::ResourceLimits;
+///
+/// let limits = ResourceLimits {
+///     memory: Some(512 * 1024 * 1024),
+///     cpu_quota: Some(50_000),
+///     ..ResourceLimits::default()
+/// };
+/// assert!(limits.validate().is_ok());
+/// assert_eq!(limits.cpus(), Some(0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// Memory limit in bytes
+    /// メモリ制限（バイト）
+    pub memory: Option<u64>,
+
+    /// Soft memory limit in bytes
+    /// ソフトメモリ制限（バイト）
+    pub memory_reservation: Option<u64>,
+
+    /// Memory plus swap limit reported by the engine (`-1` for unlimited swap)
+    /// エンジンが報告するメモリとスワップの合計制限（`-1`はスワップ無制限）
+    ///
+    /// Not edited directly; `memory_swap_after` keeps the swap allowance
+    /// when the memory limit changes.
+    /// 直接は編集せず、メモリ制限の変更時に`memory_swap_after`がスワップ枠を維持します。
+    pub memory_swap: Option<i64>,
+
+    /// Relative CPU weight (default 1024)
+    /// 相対的な`CPU`の重み（デフォルト1024）
+    pub cpu_shares: Option<u64>,
+
+    /// CPU time per period in microseconds
+    /// 期間あたりの`CPU`時間（マイクロ秒）
+    pub cpu_quota: Option<u64>,
+
+    /// Length of a CPU period in microseconds
+    /// `CPU`期間の長さ（マイクロ秒）
+    pub cpu_period: Option<u64>,
+
+    /// Maximum number of processes
+    /// 最大プロセス数
+    pub pids_limit: Option<u64>,
+
+    /// Restart policy
+    /// 再起動ポリシー
+    pub restart_policy: RestartPolicy,
+}
+
+impl ResourceLimits {
+    /// Number of CPUs the quota amounts to
+    /// クォータに相当する`CPU`数
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Display only / 表示専用
+    pub fn cpus(&self) -> Option<f64> {
+        let period = self.cpu_period.unwrap_or(DEFAULT_CPU_PERIOD);
+        self.cpu_quota
+            .map(|quota| quota as f64 / period.max(1) as f64)
+    }
+
+    /// Check the limits against the engine's minimums
+    /// エンジンの最小値に対して制限をチェック
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When a limit is below its minimum or the
+    ///   reservation exceeds the memory limit
+    pub fn validate(&self) -> DockaResult<()> {
+        if let Some(memory) = self.memory
+            && memory < MIN_MEMORY
+        {
+            return Err(DockaError::invalid_input(format!(
+                "Memory limit must be at least {}",
+                format_memory_size(MIN_MEMORY)
+            )));
+        }
+        if let (Some(reservation), Some(memory)) = (self.memory_reservation, self.memory)
+            && reservation > memory
+        {
+            return Err(DockaError::invalid_input(
+                "Memory reservation must not exceed the memory limit",
+            ));
+        }
+        if self.cpu_shares.is_some_and(|shares| shares < 2) {
+            return Err(DockaError::invalid_input("CPU shares must be at least 2"));
+        }
+        if self.cpu_quota.is_some_and(|quota| quota < MIN_CPU_QUOTA) {
+            return Err(DockaError::invalid_input(format!(
+                "CPU quota must be at least {MIN_CPU_QUOTA}µs"
+            )));
+        }
+        if self.pids_limit == Some(0) {
+            return Err(DockaError::invalid_input(
+                "PIDs limit must be at least 1 (leave empty for no limit)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that the engine can move a container from `current` to these limits
+    /// エンジンがコンテナを`current`からこの制限に変更できるかチェック
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When `validate` fails or a limit the engine
+    ///   cannot remove would be cleared
+    pub fn validate_update(&self, current: &Self) -> DockaResult<()> {
+        self.validate()?;
+        let cleared = [
+            ("memory limit", current.memory, self.memory),
+            (
+                "memory reservation",
+                current.memory_reservation,
+                self.memory_reservation,
+            ),
+            ("CPU shares", current.cpu_shares, self.cpu_shares),
+        ]
+        .into_iter()
+        .find(|(_, before, after)| before.is_some() && after.is_none());
+        if let Some((name, _, _)) = cleared {
+            return Err(DockaError::invalid_input(format!(
+                "The {name} cannot be removed by a live update; recreate the container instead"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Memory plus swap limit to send so the swap allowance survives a memory change
+    /// メモリ変更後もスワップ枠が維持されるよう送信するメモリとスワップの合計制限
+    ///
+    /// The engine rejects a memory limit above the current memory plus swap
+    /// limit, so raising memory alone would fail for most limited containers.
+    /// エンジンは現在のメモリとスワップの合計制限を超えるメモリ制限を拒否するため、
+    /// メモリだけを引き上げると制限付きのコンテナの多くで失敗します。
+    #[must_use]
+    pub fn memory_swap_after(&self, current: &Self) -> Option<i64> {
+        if self.memory == current.memory {
+            return None;
+        }
+        let (Some(memory), Some(before), Some(swap)) =
+            (self.memory, current.memory, current.memory_swap)
+        else {
+            return None;
+        };
+        if swap <= 0 {
+            return None;
+        }
+        let allowance = swap.saturating_sub(i64::try_from(before).ok()?);
+        i64::try_from(memory).ok()?.checked_add(allowance.max(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: u64 = 1024 * 1024;
+
+    fn limited() -> ResourceLimits {
+        ResourceLimits {
+            memory: Some(512 * MIB),
+            memory_reservation: Some(256 * MIB),
+            memory_swap: Some(1_024 * 1_024 * 1_024),
+            cpu_shares: Some(512),
+            ..ResourceLimits::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_limits() {
+        // Test the engine minimums and the reservation bound
+        // エンジンの最小値と予約の上限をテスト
+        assert!(limited().validate().is_ok());
+        let cases = [
+            ResourceLimits {
+                memory: Some(MIN_MEMORY - 1),
+                ..ResourceLimits::default()
+            },
+            ResourceLimits {
+                memory_reservation: Some(600 * MIB),
+                ..limited()
+            },
+            ResourceLimits {
+                cpu_quota: Some(999),
+                ..ResourceLimits::default()
+            },
+            ResourceLimits {
+                pids_limit: Some(0),
+                ..ResourceLimits::default()
+            },
+        ];
+        for limits in cases {
+            assert!(limits.validate().is_err(), "{limits:?}");
+        }
+    }
+
+    #[test]
+    fn test_update_keeps_swap_and_refuses_removal() {
+        // Test that raising memory keeps the swap allowance and limits cannot be removed
+        // メモリの引き上げでスワップ枠が維持され、制限を削除できないことをテスト
+        let current = limited();
+        let raised = ResourceLimits {
+            memory: Some(2_048 * MIB),
+            ..current
+        };
+        assert!(raised.validate_update(&current).is_ok());
+        assert_eq!(
+            raised.memory_swap_after(&current),
+            Some(i64::try_from(2_560 * MIB).unwrap())
+        );
+        assert_eq!(current.memory_swap_after(&current), None);
+
+        let cleared = ResourceLimits {
+            cpu_shares: None,
+            ..current
+        };
+        let error = cleared.validate_update(&current).unwrap_err();
+        assert!(error.to_string().contains("CPU shares"));
+    }
+}
@@ -0,0 +1,134 @@
+// src/domain/value_objects/restart_policy.rs
+// Restart policy applied by the engine when a container exits
+// コンテナ終了時にエンジンが適用する再起動ポリシー
+
+use crate::error::{DockaError, DockaResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Restart policy of a container, as in `docker run --restart`
+/// `docker run --restart`と同じコンテナの再起動ポリシー
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::RestartPolicy;
+///
+/// let policy = RestartPolicy::parse("on-failure:3").unwrap();
+/// assert_eq!(policy, RestartPolicy::OnFailure { max_retries: 3 });
+/// assert_eq!(policy.to_string(), "on-failure:3");
+/// assert_eq!(RestartPolicy::default().to_string(), "no");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RestartPolicy {
+    /// Never restart automatically
+    /// 自動的に再起動しない
+    #[default]
+    No,
+
+    /// Always restart, including after a daemon restart
+    /// デーモン再起動後も含め常に再起動
+    Always,
+
+    /// Restart unless the container was stopped by the user
+    /// ユーザーが停止した場合を除き再起動
+    UnlessStopped,
+
+    /// Restart after a non-zero exit
+    /// 0以外の終了コードの後に再起動
+    OnFailure {
+        /// Attempts before giving up (`0` retries forever)
+        /// 諦めるまでの試行回数（`0`は無制限）
+        max_retries: u32,
+    },
+}
+
+impl RestartPolicy {
+    /// Policy name used by the engine
+    /// エンジンが使用するポリシー名
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::No => "no",
+            Self::Always => "always",
+            Self::UnlessStopped => "unless-stopped",
+            Self::OnFailure { .. } => "on-failure",
+        }
+    }
+
+    /// Next policy in the order `no`, `always`, `unless-stopped`, `on-failure`
+    /// `no`、`always`、`unless-stopped`、`on-failure`の順で次のポリシー
+    #[must_use]
+    pub const fn cycled(self) -> Self {
+        match self {
+            Self::No => Self::Always,
+            Self::Always => Self::UnlessStopped,
+            Self::UnlessStopped => Self::OnFailure { max_retries: 0 },
+            Self::OnFailure { .. } => Self::No,
+        }
+    }
+
+    /// Parse a policy written as for `docker run --restart`
+    /// `docker run --restart`の書式のポリシーを解析
+    ///
+    /// An empty string means `no`, as reported by the engine for containers
+    /// created without a policy.
+    /// 空文字列は`no`を意味します（ポリシーなしで作成したコンテナでエンジンが報告する値）。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the name is unknown or the retry count is invalid
+    pub fn parse(text: &str) -> DockaResult<Self> {
+        let invalid = || {
+            DockaError::invalid_input(format!(
+                "Invalid restart policy '{text}' (use no, always, unless-stopped or on-failure[:N])"
+            ))
+        };
+
+        let (name, retries) = text.trim().split_once(':').map_or_else(
+            || (text.trim(), None),
+            |(name, retries)| (name, Some(retries)),
+        );
+        match (name, retries) {
+            ("" | "no", None) => Ok(Self::No),
+            ("always", None) => Ok(Self::Always),
+            ("unless-stopped", None) => Ok(Self::UnlessStopped),
+            ("on-failure", None) => Ok(Self::OnFailure { max_retries: 0 }),
+            ("on-failure", Some(retries)) => Ok(Self::OnFailure {
+                max_retries: retries.trim().parse().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OnFailure { max_retries } if *max_retries > 0 => {
+                write!(f, "on-failure:{max_retries}")
+            }
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trips_display() {
+        // Test that every policy parses back from its display form
+        // 全てのポリシーが表示形式から解析し直せることをテスト
+        let mut policy = RestartPolicy::No;
+        for _ in 0..4 {
+            assert_eq!(RestartPolicy::parse(&policy.to_string()).unwrap(), policy);
+            policy = policy.cycled();
+        }
+        assert_eq!(policy, RestartPolicy::No);
+        assert_eq!(RestartPolicy::parse("").unwrap(), RestartPolicy::No);
+        assert!(RestartPolicy::parse("always:3").is_err());
+        assert!(RestartPolicy::parse("on-failure:-1").is_err());
+        assert!(RestartPolicy::parse("sometimes").is_err());
+    }
+}
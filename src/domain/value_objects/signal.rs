@@ -0,0 +1,160 @@
+// src/domain/value_objects/signal.rs
+// Signals that can be sent to a container's main process
+// コンテナのメインプロセスに送信できるシグナル
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Unix signal sent to a container's main process by `docker kill`
+/// `docker kill`でコンテナのメインプロセスに送信する`Unix`シグナル
+///
+/// Only the signals commonly handled by daemons are offered: `SIGHUP`
+/// reloads the configuration of nginx, `HAProxy` and similar servers without
+/// a restart, and `SIGUSR1`/`SIGUSR2` typically reopen logs or dump state.
+///
+/// デーモンが一般的に処理するシグナルのみを提供します。`SIGHUP`は再起動なしで
+/// nginxや`HAProxy`などのサーバーの設定を再読み込みし、`SIGUSR1`/`SIGUSR2`は
+/// 通常ログの再オープンや状態のダンプを行います。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::value_objects::Signal;
+///
+/// assert_eq!(Signal::Hup.name(), "SIGHUP");
+/// assert_eq!(Signal::Kill.number(), 9);
+/// assert!(Signal::Term.is_terminating());
+/// assert!(!Signal::Usr1.is_terminating());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Signal {
+    /// Polite request to terminate (`SIGTERM`)
+    /// 終了の丁寧な要求 (`SIGTERM`)
+    Term,
+
+    /// Immediate termination that cannot be handled (`SIGKILL`)
+    /// 処理できない即時終了 (`SIGKILL`)
+    Kill,
+
+    /// Hang-up, conventionally "reload configuration" (`SIGHUP`)
+    /// ハングアップ、慣習的に「設定の再読み込み」 (`SIGHUP`)
+    Hup,
+
+    /// Interrupt, as sent by `Ctrl+C` (`SIGINT`)
+    /// `Ctrl+C`で送られる割り込み (`SIGINT`)
+    Int,
+
+    /// Quit, often with a core dump or graceful shutdown (`SIGQUIT`)
+    /// 終了、コアダンプやグレースフルシャットダウンを伴うことが多い (`SIGQUIT`)
+    Quit,
+
+    /// First user-defined signal (`SIGUSR1`)
+    /// 1つ目のユーザー定義シグナル (`SIGUSR1`)
+    Usr1,
+
+    /// Second user-defined signal (`SIGUSR2`)
+    /// 2つ目のユーザー定義シグナル (`SIGUSR2`)
+    Usr2,
+
+    /// Window size change, a graceful stop for nginx workers (`SIGWINCH`)
+    /// ウィンドウサイズ変更、nginxワーカーのグレースフル停止 (`SIGWINCH`)
+    Winch,
+}
+
+impl Signal {
+    /// Every signal in picker order
+    /// ピッカー順の全シグナル
+    pub const ALL: [Self; 8] = [
+        Self::Term,
+        Self::Kill,
+        Self::Hup,
+        Self::Int,
+        Self::Quit,
+        Self::Usr1,
+        Self::Usr2,
+        Self::Winch,
+    ];
+
+    /// Name understood by the engine, e.g. `SIGHUP`
+    /// エンジンが理解する名前（例: `SIGHUP`）
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Term => "SIGTERM",
+            Self::Kill => "SIGKILL",
+            Self::Hup => "SIGHUP",
+            Self::Int => "SIGINT",
+            Self::Quit => "SIGQUIT",
+            Self::Usr1 => "SIGUSR1",
+            Self::Usr2 => "SIGUSR2",
+            Self::Winch => "SIGWINCH",
+        }
+    }
+
+    /// Signal number on Linux
+    /// `Linux`でのシグナル番号
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::Hup => 1,
+            Self::Int => 2,
+            Self::Quit => 3,
+            Self::Kill => 9,
+            Self::Usr1 => 10,
+            Self::Usr2 => 12,
+            Self::Term => 15,
+            Self::Winch => 28,
+        }
+    }
+
+    /// Short description of the usual effect
+    /// 通常の効果の短い説明
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Term => "terminate gracefully",
+            Self::Kill => "terminate immediately",
+            Self::Hup => "reload configuration",
+            Self::Int => "interrupt",
+            Self::Quit => "quit",
+            Self::Usr1 => "user-defined (often reopen logs)",
+            Self::Usr2 => "user-defined",
+            Self::Winch => "window change (nginx: stop workers)",
+        }
+    }
+
+    /// Check whether the signal ends a process that does not handle it
+    /// 処理しないプロセスをシグナルが終了させるかチェック
+    ///
+    /// A container's main process runs as PID 1, which ignores unhandled
+    /// signals, so only `SIGKILL` is certain to stop the container.
+    /// コンテナのメインプロセスは`PID 1`として実行され、処理しないシグナルを
+    /// 無視するため、コンテナを確実に停止させるのは`SIGKILL`のみです。
+    #[must_use]
+    pub const fn is_terminating(self) -> bool {
+        matches!(self, Self::Term | Self::Kill | Self::Int | Self::Quit)
+    }
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_signals_are_distinct() {
+        // Test that names and numbers identify each signal
+        // 名前と番号が各シグナルを識別することをテスト
+        let names: HashSet<_> = Signal::ALL.iter().map(|signal| signal.name()).collect();
+        let numbers: HashSet<_> = Signal::ALL.iter().map(|signal| signal.number()).collect();
+        assert_eq!(names.len(), Signal::ALL.len());
+        assert_eq!(numbers.len(), Signal::ALL.len());
+        assert_eq!(Signal::Winch.to_string(), "SIGWINCH");
+    }
+}
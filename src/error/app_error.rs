@@ -72,6 +72,25 @@ pub enum DockaError {
         message: String,
     },
 
+    /// Filter expression that cannot be parsed.
+    ///
+    /// This error occurs when a term of a container filter expression is
+    /// malformed; the position lets the filter bar point at the faulty term.
+    ///
+    /// 解析できないフィルタ式。
+    /// コンテナのフィルタ式の項が不正な場合に発生します。位置により
+    /// フィルタバーが問題の項を指し示せます。
+    #[error("Invalid filter expression: {message}")]
+    FilterParse {
+        /// Description of what is wrong with the term.
+        /// 項の何が問題かの説明。
+        message: String,
+
+        /// Character offset of the faulty term within the expression.
+        /// 式内の問題の項の文字位置。
+        position: usize,
+    },
+
     /// Operation not permitted due to insufficient privileges.
     ///
     /// This error occurs when attempting to perform operations
@@ -191,6 +210,45 @@ pub enum DockaError {
         feature: String,
     },
 
+    /// Operation not supported by the connected Docker engine.
+    ///
+    /// This error occurs when the engine reports that a feature is unavailable,
+    /// for example pausing containers on a rootless engine without cgroups.
+    ///
+    /// 接続中の`Docker`エンジンが操作をサポートしていない。
+    /// 例えば`cgroups`のないルートレスエンジンでのコンテナ一時停止など、
+    /// エンジンが機能を利用不可と報告した場合に発生します。
+    #[error("'{feature}' is not supported by this Docker engine: {reason}")]
+    Unsupported {
+        /// The operation or feature that is unavailable.
+        /// 利用できない操作または機能。
+        feature: String,
+
+        /// Why the engine cannot provide it.
+        /// エンジンが提供できない理由。
+        reason: String,
+    },
+
+    /// Docker operation did not answer in time.
+    ///
+    /// This error occurs when a Docker host does not respond within the
+    /// allotted time, for example an unreachable remote host that neither
+    /// answers nor refuses the connection.
+    ///
+    /// `Docker`操作が時間内に応答しなかった。
+    /// 例えば接続を拒否も応答もしない到達不能なリモートホストなど、
+    /// `Docker`ホストが制限時間内に応答しない場合に発生します。
+    #[error("{operation} timed out after {seconds} seconds")]
+    Timeout {
+        /// The operation that did not complete in time.
+        /// 時間内に完了しなかった操作。
+        operation: String,
+
+        /// How long the operation was allowed to take, in seconds.
+        /// 操作に許された時間（秒）。
+        seconds: u64,
+    },
+
     /// Internal application error.
     ///
     /// This error represents unexpected conditions that should not
@@ -220,6 +278,25 @@ impl DockaError {
         }
     }
 
+    /// Create a new `FilterParse` error pointing at a character offset of the expression
+    /// `式の文字位置を指す新しいFilterParseエラーを作成`
+    pub fn filter_parse(message: impl Into<String>, position: usize) -> Self {
+        Self::FilterParse {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Character offset of a `FilterParse` error within the expression
+    /// `FilterParseエラーの式内の文字位置`
+    #[must_use]
+    pub const fn input_position(&self) -> Option<usize> {
+        match self {
+            Self::FilterParse { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+
     /// Create a new `PermissionDenied` error
     /// `新しいPermissionDeniedエラーを作成`
     pub fn permission_denied(operation: impl Into<String>) -> Self {
@@ -260,6 +337,24 @@ impl DockaError {
         }
     }
 
+    /// Create a new `Unsupported` error
+    /// `新しいUnsupportedエラーを作成`
+    pub fn unsupported(feature: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Unsupported {
+            feature: feature.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a new `Timeout` error
+    /// `新しいTimeoutエラーを作成`
+    pub fn timeout(operation: impl Into<String>, limit: std::time::Duration) -> Self {
+        Self::Timeout {
+            operation: operation.into(),
+            seconds: limit.as_secs(),
+        }
+    }
+
     /// Create a new Internal error
     /// 新しいInternalエラーを作成
     pub fn internal(message: impl Into<String>) -> Self {
@@ -280,8 +375,11 @@ impl DockaError {
             | Self::Configuration { .. }
             | Self::PermissionDenied { .. }
             | Self::InvalidInput { .. }
+            | Self::FilterParse { .. }
             | Self::ContainerNotFound { .. }
-            | Self::ImageNotFound { .. } => true,
+            | Self::ImageNotFound { .. }
+            | Self::Unsupported { .. }
+            | Self::Timeout { .. } => true,
 
             // Non-recoverable errors - these indicate system-level failures
             // 回復不可能なエラー - システムレベルの失敗を示す
@@ -312,6 +410,9 @@ impl DockaError {
             Self::InvalidInput { .. } => {
                 "Invalid input. Please check your command and try again.".to_string()
             }
+            Self::FilterParse { .. } => {
+                "Invalid filter expression. Please check it and try again.".to_string()
+            }
             Self::PermissionDenied { .. } => {
                 "Permission denied. Please check your Docker permissions.".to_string()
             }
@@ -323,6 +424,12 @@ impl DockaError {
             Self::NotImplemented { feature } => {
                 format!("Feature '{feature}' is not yet available.")
             }
+            Self::Unsupported { feature, reason } => {
+                format!("'{feature}' is not supported by the connected Docker engine ({reason}).")
+            }
+            Self::Timeout { operation, seconds } => {
+                format!("{operation} did not answer within {seconds} seconds. Please try again.")
+            }
             _ => "An unexpected error occurred. Please try again.".to_string(),
         }
     }
@@ -379,6 +486,21 @@ mod tests {
         assert!(matches!(docka_error, DockaError::Serialization(_)));
     }
 
+    #[test]
+    fn test_filter_parse_position() {
+        // Test that only FilterParse errors report a position
+        // FilterParseエラーのみが位置を報告することをテスト
+        let error = DockaError::filter_parse("Unknown field 'colour'", 15);
+        assert_eq!(error.input_position(), Some(15));
+        assert_eq!(
+            error.to_string(),
+            "Invalid filter expression: Unknown field 'colour'"
+        );
+        assert!(error.is_recoverable());
+        assert_eq!(DockaError::invalid_input("bad").input_position(), None);
+        assert_eq!(DockaError::internal("bad").input_position(), None);
+    }
+
     #[test]
     fn test_error_recoverability() {
         // Test error recovery classification
@@ -439,6 +561,26 @@ mod tests {
         assert!(user_msg.contains("not found"));
     }
 
+    #[test]
+    fn test_unsupported_and_timeout_messages() {
+        // Test the messages of unsupported operations and timeouts
+        // 未対応の操作とタイムアウトのメッセージのテスト
+        let unsupported_error = DockaError::unsupported("pause", "no cgroups");
+        assert!(unsupported_error.is_recoverable());
+        let user_msg = unsupported_error.user_message();
+        assert!(user_msg.contains("pause"));
+        assert!(user_msg.contains("no cgroups"));
+
+        let timeout_error =
+            DockaError::timeout("Docker host 'build-01'", std::time::Duration::from_secs(5));
+        assert!(timeout_error.is_recoverable());
+        assert_eq!(
+            timeout_error.to_string(),
+            "Docker host 'build-01' timed out after 5 seconds"
+        );
+        assert!(timeout_error.user_message().contains("within 5 seconds"));
+    }
+
     #[test]
     fn test_docka_result_type() {
         // Test DockaResult type alias
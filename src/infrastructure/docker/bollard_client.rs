@@ -2,23 +2,62 @@
 // Fixed version addressing deprecated API warnings
 // 非推奨API警告に対応した修正版
 
-use crate::domain::entities::{Container, ContainerFilter};
+use crate::domain::entities::image::UNTAGGED;
+use crate::domain::entities::{
+    ArchiveStream, BuildCacheEntry, ChangeKind, Container, ContainerCounts, ContainerDetails,
+    ContainerExit, ContainerFilter, ContainerProcess, ContainerStats, DiskUsage, DiskUsageCategory,
+    FilesystemChange, HealthProbe, HealthReport, Image, Network, NetworkAddress, PathKind,
+    SystemOverview, Volume,
+};
 use crate::domain::repositories::DockerRepository;
-use crate::domain::value_objects::{ContainerId, ContainerStatus};
+use crate::domain::value_objects::{
+    ApiVersion, ContainerId, ContainerStatus, EngineCapabilities, EngineFeature, HealthStatus,
+    PortMapping, PortProtocol, ResourceLimits, RestartPolicy, Signal,
+};
 use crate::error::{DockaError, DockaResult};
+use crate::infrastructure::docker::docker_context::{
+    DEFAULT_CONTEXT_NAME, DockerEndpoint, DockerEnvironment, DockerTransport, EndpointCatalog,
+};
+use crate::utils::tar::{TarEvent, TarReader};
 use async_trait::async_trait;
 // Fixed: Use new OpenAPI generated types for all container operations
 // 修正: 全てのコンテナ操作で新しいOpenAPI生成型を使用
-use bollard::Docker;
+use bollard::models::{
+    ChangeType, ContainerInspectResponse, ContainerStatsResponse, ContainerSummary,
+    ContainerSummaryStateEnum, ContainerTopResponse, ContainerUpdateBody, EventMessage, HostConfig,
+    ImageSummary, NetworkSettings, Port, PortMap, RestartPolicyNameEnum, SystemDataUsageResponse,
+    SystemInfo, SystemInfoCgroupDriverEnum, SystemInfoCgroupVersionEnum, SystemVersion,
+};
 use bollard::query_parameters::{
-    ListContainersOptions, ListContainersOptionsBuilder, RemoveContainerOptions,
-    RemoveContainerOptionsBuilder, RestartContainerOptions, RestartContainerOptionsBuilder,
-    StartContainerOptions, StartContainerOptionsBuilder, StopContainerOptions,
-    StopContainerOptionsBuilder,
+    DataUsageOptions, DataUsageOptionsBuilder, DownloadFromContainerOptionsBuilder,
+    EventsOptionsBuilder, InspectContainerOptions, KillContainerOptionsBuilder,
+    ListContainersOptions, ListContainersOptionsBuilder, ListImagesOptions, ListNetworksOptions,
+    ListVolumesOptions, PruneBuildOptionsBuilder, RemoveContainerOptions,
+    RemoveContainerOptionsBuilder, RemoveImageOptionsBuilder, RenameContainerOptionsBuilder,
+    RestartContainerOptions, RestartContainerOptionsBuilder, StartContainerOptions,
+    StartContainerOptionsBuilder, StatsOptionsBuilder, StopContainerOptions,
+    StopContainerOptionsBuilder, TopOptionsBuilder, UploadToContainerOptionsBuilder,
 };
+use bollard::{API_DEFAULT_VERSION, Docker, body_try_stream};
+use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::sync::{Mutex, OnceCell};
 use tracing::{debug, error, info, warn};
 
+/// Request timeout in seconds used for endpoint connections (bollard default)
+/// エンドポイント接続で使用するリクエストタイムアウト秒数（bollardのデフォルト）
+const CONNECTION_TIMEOUT_SECS: u64 = 120;
+
+/// `ps` arguments for `docker top`, chosen to include CPU and memory usage
+/// CPUとメモリ使用率を含めるために選んだ`docker top`用の`ps`引数
+const TOP_PS_ARGS: &str = "aux";
+
+/// Containers inspected at once when reading start and exit times for the list
+/// 一覧用に起動・終了日時を読み取る際に同時にinspectするコンテナ数
+const INSPECT_CONCURRENCY: usize = 8;
+
 /// Bollard-based implementation of `DockerRepository`
 /// `DockerRepository`の`Bollard`ベース実装
 ///
@@ -62,33 +101,154 @@ pub struct BollardDockerRepository {
     /// Shared Docker client instance
     /// 共有Dockerクライアントインスタンス
     client: Arc<Docker>,
+
+    /// Name of the endpoint (Docker context) this client is connected to
+    /// このクライアントが接続しているエンドポイント（Dockerコンテキスト）名
+    endpoint_name: String,
+
+    /// Engine capabilities, probed once after API version negotiation
+    /// `API`バージョンのネゴシエート後に一度だけ調査されるエンジン機能情報
+    capabilities: Arc<OnceCell<EngineCapabilities>>,
+
+    /// Start and exit times read from inspect, by container ID
+    /// inspectから読み取った起動・終了日時（コンテナIDごと）
+    state_times: Arc<Mutex<HashMap<String, StateTimes>>>,
+}
+
+/// Start and exit times of a container with the list status they were read at
+/// 読み取った時点の一覧のステータスを伴うコンテナの起動・終了日時
+///
+/// The list API only reports these times as rounded text ("Up 3 hours"), so
+/// they are inspected once and reused until the status text changes, which
+/// it does on every start, stop and restart.
+/// 一覧APIはこれらの日時を丸めたテキスト（"Up 3 hours"）でしか報告しないため、
+/// 一度inspectし、ステータステキストが変わるまで（起動・停止・再起動のたびに変わる）
+/// 再利用します。
+#[derive(Debug, Clone)]
+struct StateTimes {
+    /// Human-readable status of the listing the times belong to
+    /// 日時が対応する一覧の人間向けステータス
+    status: String,
+
+    /// `State.StartedAt`, `None` if never started
+    /// `State.StartedAt`（起動したことがない場合は`None`）
+    started_at: Option<DateTime<Utc>>,
+
+    /// `State.FinishedAt`, `None` if never exited
+    /// `State.FinishedAt`（終了したことがない場合は`None`）
+    finished_at: Option<DateTime<Utc>>,
 }
 
 impl BollardDockerRepository {
-    /// Create a new `BollardDockerRepository` with default connection
-    /// `デフォルト接続で新しいBollardDockerRepositoryを作成`
+    /// Create a new `BollardDockerRepository` for the active Docker endpoint
+    /// アクティブな`Docker`エンドポイント用の新しい`BollardDockerRepository`を作成
     ///
-    /// This method attempts to connect to Docker daemon using default settings:
-    /// - Unix socket on Linux/macOS
-    /// - Named pipe on Windows
-    /// - Environment variables (`DOCKER_HOST`, etc.) if available
+    /// The endpoint is resolved like the Docker CLI does:
+    /// - `DOCKER_HOST` (with `DOCKER_TLS_VERIFY` / `DOCKER_CERT_PATH`)
+    /// - `DOCKER_CONTEXT`
+    /// - `currentContext` from `~/.docker/config.json`
+    /// - the local default socket
     ///
-    /// このメソッドはデフォルト設定でDocker daemonへの接続を試行します：
-    /// - Linux/macOSではUnixソケット
-    /// - `WindowsではNamed` pipe
-    /// - `利用可能な場合は環境変数（DOCKER_HOST等`）
+    /// エンドポイントは`Docker` `CLI`と同じ順序で解決されます：
+    /// - `DOCKER_HOST`（`DOCKER_TLS_VERIFY` / `DOCKER_CERT_PATH`付き）
+    /// - `DOCKER_CONTEXT`
+    /// - `~/.docker/config.json`の`currentContext`
+    /// - ローカルのデフォルトソケット
     ///
     /// # Errors
     /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
     /// * `DockaError::PermissionDenied` - When lacking Docker permissions
-    /// * `DockaError::DockerApi` - On other connection failures
+    /// * `DockaError::InvalidInput` - When the configured endpoint is malformed
     pub async fn new() -> DockaResult<Self> {
         info!("Initializing Bollard Docker client");
 
-        // Attempt to connect using default settings
-        // デフォルト設定での接続を試行
-        let docker = Docker::connect_with_defaults().map_err(|e| {
-            error!("Failed to connect to Docker daemon: {}", e);
+        let catalog = EndpointCatalog::load(&DockerEnvironment::from_env())?;
+        Self::connect(catalog.active()).await
+    }
+
+    /// Connect to a specific Docker endpoint
+    /// 特定の`Docker`エンドポイントに接続
+    ///
+    /// Supports unix sockets, plain TCP and TCP with TLS client certificates.
+    /// `unix`ソケット、プレーン`TCP`、`TLS`クライアント証明書付き`TCP`をサポートします。
+    ///
+    /// # Arguments
+    /// * `endpoint` - Endpoint resolved from the environment or context store
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the endpoint asks for an unsupported setting (`SkipTLSVerify`)
+    /// * `DockaError::DockerDaemonNotRunning` - When Docker daemon is not accessible
+    /// * `DockaError::PermissionDenied` - When lacking Docker permissions
+    pub async fn connect(endpoint: &DockerEndpoint) -> DockaResult<Self> {
+        let repo = Self::connect_lazy(endpoint)?;
+
+        // Verify connection with a ping
+        // pingで接続を確認
+        Self::verify_connection(&repo.client).await?;
+
+        // Negotiate the API version so that old engines accept our requests
+        // 古いエンジンがリクエストを受け付けるようAPIバージョンをネゴシエート
+        let capabilities = repo.capabilities().await;
+        for feature in EngineFeature::ALL {
+            if let Some(reason) = capabilities.unsupported_reason(feature) {
+                warn!(
+                    "Docker endpoint '{}' does not support {}: {}",
+                    endpoint.name, feature, reason
+                );
+            }
+        }
+
+        info!(
+            "Successfully connected to Docker endpoint '{}' ({})",
+            endpoint.name,
+            capabilities.summary().unwrap_or_default()
+        );
+        Ok(repo)
+    }
+
+    /// Create a client for an endpoint without contacting the daemon
+    /// デーモンに接続せずにエンドポイント用クライアントを作成
+    ///
+    /// Used by the multi-host view, where an unreachable host must not prevent
+    /// the others from being listed; failures surface on the first request.
+    ///
+    /// 到達不能なホストが他のホストの一覧表示を妨げてはならない複数ホストビューで
+    /// 使用されます。失敗は最初のリクエスト時に表面化します。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the endpoint asks for an unsupported setting (`SkipTLSVerify`)
+    /// * `DockaError::DockerDaemonNotRunning` - When the client cannot be configured
+    /// * `DockaError::PermissionDenied` - When lacking Docker permissions
+    pub fn connect_lazy(endpoint: &DockerEndpoint) -> DockaResult<Self> {
+        endpoint.ensure_supported()?;
+        info!(
+            "Connecting to Docker endpoint '{}' ({})",
+            endpoint.name, endpoint.host
+        );
+
+        let docker = match &endpoint.transport {
+            DockerTransport::Unix { path } => Docker::connect_with_unix(
+                &path.to_string_lossy(),
+                CONNECTION_TIMEOUT_SECS,
+                API_DEFAULT_VERSION,
+            ),
+            DockerTransport::Tcp { address } => {
+                Docker::connect_with_http(address, CONNECTION_TIMEOUT_SECS, API_DEFAULT_VERSION)
+            }
+            DockerTransport::Tls { address, cert_path } => Docker::connect_with_ssl(
+                address,
+                &cert_path.join("key.pem"),
+                &cert_path.join("cert.pem"),
+                &cert_path.join("ca.pem"),
+                CONNECTION_TIMEOUT_SECS,
+                API_DEFAULT_VERSION,
+            ),
+        }
+        .map_err(|e| {
+            error!(
+                "Failed to connect to Docker endpoint '{}': {}",
+                endpoint.name, e
+            );
             match e {
                 bollard::errors::Error::DockerResponseServerError {
                     status_code: 403, ..
@@ -97,16 +257,12 @@ impl BollardDockerRepository {
             }
         })?;
 
-        // Verify connection with a ping
-        // pingで接続を確認
-        Self::verify_connection(&docker).await?;
-
-        let repo = Self {
+        Ok(Self {
             client: Arc::new(docker),
-        };
-
-        info!("Successfully connected to Docker daemon");
-        Ok(repo)
+            endpoint_name: endpoint.name.clone(),
+            capabilities: Arc::new(OnceCell::new()),
+            state_times: Arc::default(),
+        })
     }
 
     /// Create `BollardDockerRepository` with custom Docker client
@@ -121,9 +277,115 @@ impl BollardDockerRepository {
     pub fn with_client(docker: Docker) -> Self {
         Self {
             client: Arc::new(docker),
+            endpoint_name: DEFAULT_CONTEXT_NAME.to_string(),
+            capabilities: Arc::new(OnceCell::new()),
+            state_times: Arc::default(),
         }
     }
 
+    /// Name of the endpoint this repository is connected to
+    /// このリポジトリが接続しているエンドポイント名
+    #[must_use]
+    pub fn endpoint_name(&self) -> &str {
+        &self.endpoint_name
+    }
+
+    /// Negotiate the API version and inspect the engine
+    /// `API`バージョンをネゴシエートしエンジンを調査
+    ///
+    /// The shared client is downgraded to the engine's API version, then
+    /// `/version` and `/info` describe the engine. A failing `/info` is not
+    /// fatal; cgroup-based operations are then attempted normally.
+    ///
+    /// 共有クライアントをエンジンの`API`バージョンまで下げ、`/version`と`/info`で
+    /// エンジンを記述します。`/info`の失敗は致命的ではなく、その場合`cgroup`ベースの
+    /// 操作は通常通り試行されます。
+    ///
+    /// # Errors
+    /// * `DockaError::DockerApi` - When `/version` cannot be read
+    async fn probe_capabilities(docker: &Docker) -> DockaResult<EngineCapabilities> {
+        // Clones share the version cell, so negotiating on a clone updates every user
+        // クローンはバージョンを共有するため、クローンでのネゴシエートが全利用者に反映される
+        if let Err(e) = docker.clone().negotiate_version().await {
+            warn!("API version negotiation failed, keeping defaults: {}", e);
+        }
+
+        let version = docker.version().await.map_err(DockaError::DockerApi)?;
+        let client_version = docker.client_version();
+        let api_version = ApiVersion::new(
+            u32::try_from(client_version.major_version).unwrap_or(u32::MAX),
+            u32::try_from(client_version.minor_version).unwrap_or(u32::MAX),
+        );
+
+        let info = match docker.info().await {
+            Ok(info) => Some(info),
+            Err(e) => {
+                debug!("Could not read engine info: {}", e);
+                None
+            }
+        };
+
+        let os = info
+            .as_ref()
+            .and_then(|info| info.operating_system.clone())
+            .or_else(|| version.os.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        let mut capabilities =
+            EngineCapabilities::new(Self::engine_version(&version), os, api_version);
+
+        // Rootless engines may lack the cgroup support pausing needs
+        // ルートレスエンジンは一時停止に必要なcgroupサポートを欠く場合がある
+        if let Some(reason) = info.as_ref().and_then(Self::cgroup_limitation) {
+            capabilities = capabilities.with_unsupported(EngineFeature::Pause, reason);
+        }
+
+        Ok(capabilities)
+    }
+
+    /// Engine version label, naming Podman when it provides the API
+    /// エンジンバージョンのラベル（`Podman`が`API`を提供している場合はその名前を付与）
+    fn engine_version(version: &SystemVersion) -> String {
+        let number = version
+            .version
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+        let is_podman = version
+            .platform
+            .as_ref()
+            .is_some_and(|platform| platform.name.contains("Podman"));
+
+        if is_podman {
+            format!("Podman {number}")
+        } else {
+            number
+        }
+    }
+
+    /// Derive the cgroup limitation reported by `/info`
+    /// `/info`が報告する`cgroup`の制限を導出
+    ///
+    /// Pausing needs the cgroup freezer, which is missing when the engine runs
+    /// without a cgroup driver or rootless on cgroup v1.
+    ///
+    /// 一時停止には`cgroup` `freezer`が必要ですが、エンジンが`cgroup`ドライバなしで
+    /// 動作している場合や`cgroup` v1上のルートレスでは利用できません。
+    fn cgroup_limitation(info: &SystemInfo) -> Option<String> {
+        if info.cgroup_driver == Some(SystemInfoCgroupDriverEnum::NONE) {
+            return Some("the engine runs without a cgroup driver".to_string());
+        }
+
+        let rootless = info.security_options.as_ref().is_some_and(|options| {
+            options
+                .iter()
+                .any(|option| option.contains("name=rootless"))
+        });
+        if rootless && info.cgroup_version == Some(SystemInfoCgroupVersionEnum::_1) {
+            return Some("rootless engines need cgroup v2 to freeze containers".to_string());
+        }
+
+        None
+    }
+
     /// Verify Docker daemon connection and permissions
     /// Docker daemon接続と権限を確認
     ///
@@ -277,9 +539,14 @@ impl DockerRepository for BollardDockerRepository {
         // Convert bollard containers to domain entities
         // bollardコンテナをドメインエンティティに変換
         let mut domain_containers = Vec::with_capacity(containers.len());
+        let mut status_texts = Vec::with_capacity(containers.len());
         for container in containers {
+            let status_text = container.status.clone().unwrap_or_default();
             match Self::convert_container(container) {
-                Ok(domain_container) => domain_containers.push(domain_container),
+                Ok(domain_container) => {
+                    domain_containers.push(domain_container);
+                    status_texts.push(status_text);
+                }
                 Err(e) => {
                     warn!("Skipping invalid container: {}", e);
                     // Continue processing other containers instead of failing entirely
@@ -288,6 +555,9 @@ impl DockerRepository for BollardDockerRepository {
             }
         }
 
+        self.fill_state_times(&mut domain_containers, status_texts)
+            .await;
+
         info!(
             "Successfully converted {} containers",
             domain_containers.len()
@@ -401,6 +671,35 @@ impl DockerRepository for BollardDockerRepository {
         Ok(())
     }
 
+    async fn kill_container(&self, id: &ContainerId, signal: Signal) -> DockaResult<()> {
+        info!("Sending {} to container: {}", signal, id);
+
+        // Verify container exists and can receive signals
+        // コンテナが存在し、シグナルを受け取れることを確認
+        let container = self.get_container(id).await?;
+        if !container.can_kill() {
+            return Err(DockaError::invalid_input(format!(
+                "Container {} cannot receive {} in status {}",
+                id, signal, container.status
+            )));
+        }
+
+        let options = KillContainerOptionsBuilder::default()
+            .signal(signal.name())
+            .build();
+
+        self.client
+            .kill_container(id.as_str(), Some(options))
+            .await
+            .map_err(|e| {
+                error!("Failed to send {} to container {}: {}", signal, id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        info!("Successfully sent {} to container: {}", signal, id);
+        Ok(())
+    }
+
     async fn remove_container(&self, id: &ContainerId, force: bool) -> DockaResult<()> {
         info!("Removing container: {} (force: {})", id, force);
 
@@ -428,88 +727,1264 @@ impl DockerRepository for BollardDockerRepository {
                 DockaError::DockerApi(e)
             })?;
 
-        info!("Successfully removed container: {}", id);
-        Ok(())
+        info!("Successfully removed container: {}", id);
+        Ok(())
+    }
+
+    async fn restart_container(&self, id: &ContainerId) -> DockaResult<()> {
+        info!("Restarting container: {}", id);
+
+        // Verify container exists and can be restarted
+        // コンテナが存在し、再起動可能であることを確認
+        let container = self.get_container(id).await?;
+        if !container.can_restart() {
+            return Err(DockaError::invalid_input(format!(
+                "Container {} cannot be restarted from status {}",
+                id, container.status
+            )));
+        }
+
+        let options = Some(Self::create_restart_options(Some(10))); // Default 10 second timeout
+        self.client
+            .restart_container(id.as_str(), options)
+            .await
+            .map_err(|e| {
+                error!("Failed to restart container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        info!("Successfully restarted container: {}", id);
+        Ok(())
+    }
+
+    async fn pause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        info!("Pausing container: {}", id);
+        self.capabilities().await.ensure(EngineFeature::Pause)?;
+
+        // Verify container exists and can be paused
+        // コンテナが存在し、一時停止可能であることを確認
+        let container = self.get_container(id).await?;
+        if !container.can_pause() {
+            return Err(DockaError::invalid_input(format!(
+                "Container {} cannot be paused from status {}",
+                id, container.status
+            )));
+        }
+
+        self.client
+            .pause_container(id.as_str())
+            .await
+            .map_err(|e| {
+                error!("Failed to pause container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        info!("Successfully paused container: {}", id);
+        Ok(())
+    }
+
+    async fn unpause_container(&self, id: &ContainerId) -> DockaResult<()> {
+        info!("Unpausing container: {}", id);
+        self.capabilities().await.ensure(EngineFeature::Pause)?;
+
+        // Verify container exists and can be unpaused
+        // コンテナが存在し、一時停止解除可能であることを確認
+        let container = self.get_container(id).await?;
+        if !container.can_unpause() {
+            return Err(DockaError::invalid_input(format!(
+                "Container {} cannot be unpaused from status {}",
+                id, container.status
+            )));
+        }
+
+        self.client
+            .unpause_container(id.as_str())
+            .await
+            .map_err(|e| {
+                error!("Failed to unpause container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        info!("Successfully unpaused container: {}", id);
+        Ok(())
+    }
+
+    async fn container_resources(&self, id: &ContainerId) -> DockaResult<ResourceLimits> {
+        debug!("Inspecting resources of container: {}", id);
+        let response = self.inspect(id).await?;
+        Ok(Self::convert_resources(
+            response.host_config.unwrap_or_default(),
+        ))
+    }
+
+    async fn update_container(&self, id: &ContainerId, limits: &ResourceLimits) -> DockaResult<()> {
+        info!("Updating resources of container: {}", id);
+
+        let current = self.container_resources(id).await?;
+        limits.validate_update(&current)?;
+        let body = Self::create_update_body(limits, &current)?;
+
+        self.client
+            .update_container(id.as_str(), body)
+            .await
+            .map_err(|e| {
+                error!("Failed to update container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        info!("Successfully updated container: {}", id);
+        Ok(())
+    }
+
+    async fn rename_container(&self, id: &ContainerId, new_name: &str) -> DockaResult<()> {
+        info!("Renaming container {} to {}", id, new_name);
+        Container::validate_name(new_name)?;
+
+        let options = RenameContainerOptionsBuilder::new().name(new_name).build();
+        self.client
+            .rename_container(id.as_str(), options)
+            .await
+            .map_err(|e| match e {
+                bollard::errors::Error::DockerResponseServerError {
+                    status_code: 404, ..
+                } => DockaError::ContainerNotFound {
+                    name: id.to_string(),
+                },
+                bollard::errors::Error::DockerResponseServerError {
+                    status_code: 409, ..
+                } => DockaError::invalid_input(format!(
+                    "Container name '{new_name}' is already in use"
+                )),
+                e => {
+                    error!("Failed to rename container {}: {}", id, e);
+                    DockaError::DockerApi(e)
+                }
+            })?;
+
+        info!("Successfully renamed container: {}", id);
+        Ok(())
+    }
+
+    async fn top_processes(&self, id: &ContainerId) -> DockaResult<Vec<ContainerProcess>> {
+        debug!("Listing processes of container: {}", id);
+
+        // Windows engines reject `ps` arguments and report their own columns
+        // Windowsエンジンは`ps`引数を拒否し、独自の列を報告する
+        let options = (self.capabilities().await.os != "windows")
+            .then(|| TopOptionsBuilder::new().ps_args(TOP_PS_ARGS).build());
+
+        let response = self
+            .client
+            .top_processes(id.as_str(), options)
+            .await
+            .map_err(|e| {
+                error!("Failed to list processes of container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        Ok(Self::convert_processes(response))
+    }
+
+    async fn container_stats(&self, id: &ContainerId) -> DockaResult<ContainerStats> {
+        debug!("Sampling resource usage of container: {}", id);
+        let options = StatsOptionsBuilder::new().stream(false).build();
+
+        let response = self
+            .client
+            .stats(id.as_str(), Some(options))
+            .next()
+            .await
+            .transpose()
+            .map_err(|e| {
+                error!("Failed to sample usage of container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        Ok(response.map(Self::convert_stats).unwrap_or_default())
+    }
+
+    async fn container_changes(&self, id: &ContainerId) -> DockaResult<Vec<FilesystemChange>> {
+        debug!("Listing filesystem changes of container: {}", id);
+        let changes = self
+            .client
+            .container_changes(id.as_str())
+            .await
+            .map_err(|e| {
+                error!("Failed to list changes of container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+
+        Ok(changes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|change| {
+                FilesystemChange::new(change.path, Self::convert_change_kind(change.kind))
+            })
+            .collect())
+    }
+
+    async fn container_details(&self, id: &ContainerId) -> DockaResult<ContainerDetails> {
+        debug!("Inspecting details of container: {}", id);
+        let response = self.inspect(id).await?;
+        Ok(Self::convert_details(response))
+    }
+
+    async fn container_exits(&self, since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>> {
+        debug!("Listing container exits since {}", since);
+
+        // An `until` bound makes the engine close the stream after the backlog
+        // `until`を指定するとエンジンは履歴の送信後にストリームを閉じる
+        let filters = HashMap::from([("type", vec!["container"]), ("event", vec!["die"])]);
+        let options = EventsOptionsBuilder::new()
+            .since(&since.timestamp().to_string())
+            .until(&Utc::now().timestamp().to_string())
+            .filters(&filters)
+            .build();
+
+        let events: Vec<EventMessage> = self
+            .client
+            .events(Some(options))
+            .try_collect()
+            .await
+            .map_err(|e| {
+                error!("Failed to read container events: {}", e);
+                DockaError::DockerApi(e)
+            })?;
+
+        Ok(events
+            .into_iter()
+            .filter_map(Self::convert_exit)
+            .filter(|exit| exit.at >= since)
+            .collect())
+    }
+
+    async fn download_archive(&self, id: &ContainerId, path: &str) -> DockaResult<ArchiveStream> {
+        debug!("Downloading {} from container: {}", path, id);
+        let options = DownloadFromContainerOptionsBuilder::new()
+            .path(path)
+            .build();
+        let container = id.to_string();
+        let stream = self
+            .client
+            .download_from_container(id.as_str(), Some(options))
+            .map_err(move |e| {
+                error!("Failed to download from container {}: {}", container, e);
+                DockaError::DockerApi(e)
+            });
+        Ok(Box::pin(stream))
+    }
+
+    async fn upload_archive(
+        &self,
+        id: &ContainerId,
+        path: &str,
+        archive: ArchiveStream,
+    ) -> DockaResult<()> {
+        debug!("Uploading archive to {} in container: {}", path, id);
+        let options = UploadToContainerOptionsBuilder::new().path(path).build();
+        // Errors reading local files abort the request body
+        // ローカルファイルの読み込みエラーはリクエスト本文を中断する
+        let body = body_try_stream(archive.map_err(std::io::Error::other));
+        self.client
+            .upload_to_container(id.as_str(), Some(options), body)
+            .await
+            .map_err(|e| {
+                error!("Failed to upload to container {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })
+    }
+
+    async fn container_path_kind(
+        &self,
+        id: &ContainerId,
+        path: &str,
+    ) -> DockaResult<Option<PathKind>> {
+        // There is no stat endpoint in bollard, so read the first header of
+        // the archive and drop the rest of the stream
+        // bollardにはstatエンドポイントがないため、アーカイブの最初のヘッダーを
+        // 読み、残りのストリームは破棄する
+        let options = DownloadFromContainerOptionsBuilder::new()
+            .path(path)
+            .build();
+        let mut stream = self
+            .client
+            .download_from_container(id.as_str(), Some(options));
+        let mut reader = TarReader::new();
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    let mut input = chunk.as_ref();
+                    if let Some(TarEvent::Entry(header)) = reader.next_event(&mut input)? {
+                        return Ok(Some(header.kind));
+                    }
+                }
+                Err(bollard::errors::Error::DockerResponseServerError {
+                    status_code: 404,
+                    message,
+                }) if message.starts_with("No such container") => {
+                    return Err(DockaError::ContainerNotFound {
+                        name: id.to_string(),
+                    });
+                }
+                Err(bollard::errors::Error::DockerResponseServerError {
+                    status_code: 404, ..
+                }) => return Ok(None),
+                Err(e) => return Err(DockaError::DockerApi(e)),
+            }
+        }
+        Ok(None)
+    }
+
+    async fn capabilities(&self) -> EngineCapabilities {
+        // Failed probes are not cached so that a later call can retry
+        // 失敗した調査はキャッシュせず、後の呼び出しで再試行できるようにする
+        self.capabilities
+            .get_or_try_init(|| Self::probe_capabilities(&self.client))
+            .await
+            .cloned()
+            .unwrap_or_else(|e| {
+                debug!("Could not inspect Docker engine: {}", e);
+                EngineCapabilities::unknown()
+            })
+    }
+
+    async fn system_overview(&self) -> DockaResult<SystemOverview> {
+        debug!("Collecting system overview");
+        let capabilities = self.capabilities().await;
+
+        let info = self.client.info().await.map_err(|e| {
+            error!("Failed to read engine info: {}", e);
+            DockaError::DockerApi(e)
+        })?;
+        let version = self.client.version().await.map_err(|e| {
+            error!("Failed to read engine version: {}", e);
+            DockaError::DockerApi(e)
+        })?;
+
+        // Old engines have no /system/df; show the rest of the overview anyway
+        // 古いエンジンには/system/dfがないため、概要の残りはそのまま表示する
+        let disk_usage = if capabilities.supports(EngineFeature::DiskUsage) {
+            let df = self
+                .client
+                .df(None::<DataUsageOptions>)
+                .await
+                .map_err(|e| {
+                    error!("Failed to read disk usage: {}", e);
+                    DockaError::DockerApi(e)
+                })?;
+            Some(Self::disk_usage_from_df(&df))
+        } else {
+            None
+        };
+
+        let count = |value: Option<i64>| value.and_then(|v| u64::try_from(v).ok()).unwrap_or(0);
+        Ok(SystemOverview {
+            engine_version: Self::engine_version(&version),
+            api_version: version.api_version.clone().unwrap_or_default(),
+            os: info
+                .operating_system
+                .clone()
+                .or_else(|| version.os.clone())
+                .unwrap_or_default(),
+            kernel_version: info
+                .kernel_version
+                .clone()
+                .or_else(|| version.kernel_version.clone())
+                .unwrap_or_default(),
+            storage_driver: info.driver.clone().unwrap_or_default(),
+            cpus: count(info.ncpu),
+            memory: count(info.mem_total),
+            containers: ContainerCounts {
+                running: count(info.containers_running),
+                paused: count(info.containers_paused),
+                stopped: count(info.containers_stopped),
+            },
+            images: count(info.images),
+            disk_usage,
+        })
+    }
+
+    async fn list_images(&self) -> DockaResult<Vec<Image>> {
+        debug!("Listing images");
+        let images = self
+            .client
+            .list_images(None::<ListImagesOptions>)
+            .await
+            .map_err(|e| {
+                error!("Failed to list images: {}", e);
+                DockaError::DockerApi(e)
+            })?;
+
+        // /images/json does not count containers, so derive usage from them
+        // /images/jsonはコンテナ数を数えないため、コンテナから使用状況を導出
+        let used: HashSet<String> = self
+            .list_container_summaries()
+            .await?
+            .into_iter()
+            .filter_map(|container| container.image_id)
+            .collect();
+
+        let mut domain_images = Vec::with_capacity(images.len());
+        for image in images {
+            match Self::convert_image(image, &used) {
+                Ok(domain_image) => domain_images.push(domain_image),
+                Err(e) => warn!("Skipping invalid image: {}", e),
+            }
+        }
+        Ok(domain_images)
+    }
+
+    async fn remove_image(&self, id: &str, force: bool) -> DockaResult<()> {
+        info!("Removing image: {} (force: {})", id, force);
+        let options = RemoveImageOptionsBuilder::new().force(force).build();
+        self.client
+            .remove_image(id, Some(options), None)
+            .await
+            .map_err(|e| {
+                error!("Failed to remove image {}: {}", id, e);
+                DockaError::DockerApi(e)
+            })?;
+        Ok(())
+    }
+
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>> {
+        debug!("Listing volumes");
+
+        // Only /system/df reports sizes and reference counts
+        // サイズと参照数を報告するのは/system/dfのみ
+        if self.capabilities().await.supports(EngineFeature::DiskUsage) {
+            let volumes = self.data_usage("volume").await?.volumes.unwrap_or_default();
+            return Ok(volumes
+                .into_iter()
+                .filter_map(Self::convert_volume)
+                .collect());
+        }
+
+        let volumes = self
+            .client
+            .list_volumes(None::<ListVolumesOptions>)
+            .await
+            .map_err(|e| {
+                error!("Failed to list volumes: {}", e);
+                DockaError::DockerApi(e)
+            })?
+            .volumes
+            .unwrap_or_default();
+
+        let mut references: HashMap<String, u64> = HashMap::new();
+        for container in self.list_container_summaries().await? {
+            for name in container
+                .mounts
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|m| m.name)
+            {
+                *references.entry(name).or_default() += 1;
+            }
+        }
+
+        Ok(volumes
+            .into_iter()
+            .filter_map(Self::convert_volume)
+            .map(|mut volume| {
+                volume.ref_count = Some(references.get(&volume.name).copied().unwrap_or(0));
+                volume
+            })
+            .collect())
+    }
+
+    // bollard 0.19 still takes the deprecated options type here
+    // bollard 0.19はここでまだ非推奨のオプション型を受け取る
+    #[allow(deprecated)]
+    async fn remove_volume(&self, name: &str) -> DockaResult<()> {
+        info!("Removing volume: {}", name);
+        self.client
+            .remove_volume(name, None::<bollard::volume::RemoveVolumeOptions>)
+            .await
+            .map_err(|e| {
+                error!("Failed to remove volume {}: {}", name, e);
+                DockaError::DockerApi(e)
+            })
+    }
+
+    async fn list_networks(&self) -> DockaResult<Vec<Network>> {
+        debug!("Listing networks");
+        let networks = self
+            .client
+            .list_networks(None::<ListNetworksOptions>)
+            .await
+            .map_err(|e| {
+                error!("Failed to list networks: {}", e);
+                DockaError::DockerApi(e)
+            })?;
+
+        // The network list does not include endpoints; count them per container
+        // ネットワーク一覧はエンドポイントを含まないため、コンテナごとに数える
+        let mut attached: HashMap<String, usize> = HashMap::new();
+        for container in self.list_container_summaries().await? {
+            let networks = container
+                .network_settings
+                .and_then(|settings| settings.networks)
+                .unwrap_or_default();
+            for name in networks.into_keys() {
+                *attached.entry(name).or_default() += 1;
+            }
+        }
+
+        Ok(networks
+            .into_iter()
+            .filter_map(|network| Self::convert_network(network, &attached))
+            .collect())
+    }
+
+    async fn remove_network(&self, id: &str) -> DockaResult<()> {
+        info!("Removing network: {}", id);
+        self.client.remove_network(id).await.map_err(|e| {
+            error!("Failed to remove network {}: {}", id, e);
+            DockaError::DockerApi(e)
+        })
+    }
+
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>> {
+        debug!("Listing build cache");
+        if !self.capabilities().await.supports(EngineFeature::DiskUsage) {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .data_usage("build-cache")
+            .await?
+            .build_cache
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(Self::convert_build_cache)
+            .collect())
+    }
+
+    async fn remove_build_cache(&self, id: &str) -> DockaResult<()> {
+        info!("Removing build cache record: {}", id);
+        let filters = HashMap::from([("id", vec![id])]);
+        let options = PruneBuildOptionsBuilder::new()
+            .all(true)
+            .filters(&filters)
+            .build();
+        self.client.prune_build(Some(options)).await.map_err(|e| {
+            error!("Failed to remove build cache record {}: {}", id, e);
+            DockaError::DockerApi(e)
+        })?;
+        Ok(())
+    }
+}
+
+impl BollardDockerRepository {
+    /// List raw container summaries, including stopped containers
+    /// 停止中を含む生のコンテナサマリーを一覧表示
+    async fn list_container_summaries(&self) -> DockaResult<Vec<ContainerSummary>> {
+        self.client
+            .list_containers(Some(Self::create_list_options(true)))
+            .await
+            .map_err(|e| {
+                error!("Failed to list containers: {}", e);
+                DockaError::DockerApi(e)
+            })
+    }
+
+    /// Inspect a container, mapping a missing container to `ContainerNotFound`
+    /// コンテナを調査（存在しないコンテナは`ContainerNotFound`に変換）
+    async fn inspect(&self, id: &ContainerId) -> DockaResult<ContainerInspectResponse> {
+        self.client
+            .inspect_container(id.as_str(), None::<InspectContainerOptions>)
+            .await
+            .map_err(|e| match e {
+                bollard::errors::Error::DockerResponseServerError {
+                    status_code: 404, ..
+                } => DockaError::ContainerNotFound {
+                    name: id.to_string(),
+                },
+                e => {
+                    error!("Failed to inspect container {}: {}", id, e);
+                    DockaError::DockerApi(e)
+                }
+            })
+    }
+
+    /// Query `/system/df` for a single object type
+    /// 単一のオブジェクト種別について`/system/df`を問い合わせ
+    ///
+    /// Engines older than API 1.42 ignore the type and report everything.
+    /// `API` 1.42より古いエンジンは種別を無視して全てを報告します。
+    async fn data_usage(&self, object_type: &str) -> DockaResult<SystemDataUsageResponse> {
+        let options = DataUsageOptionsBuilder::new()
+            ._type(vec![object_type.to_string()])
+            .build();
+        self.client.df(Some(options)).await.map_err(|e| {
+            error!("Failed to read disk usage: {}", e);
+            DockaError::DockerApi(e)
+        })
+    }
+    /// Summarize `/system/df` the way `docker system df` does
+    /// `docker system df`と同じ方法で`/system/df`を集計
+    ///
+    /// Reclaimable space is what pruning unused objects would free:
+    /// image layers not used by any container, writable layers of containers
+    /// that are not running, unreferenced volumes and build cache not in use.
+    ///
+    /// 回収可能な容量は未使用オブジェクトの削除で解放される容量です。
+    /// どのコンテナにも使われていないイメージレイヤー、実行中でないコンテナの
+    /// 書き込み可能レイヤー、参照されていないボリューム、使用中でないビルドキャッシュが対象です。
+    fn disk_usage_from_df(df: &SystemDataUsageResponse) -> DiskUsage {
+        let bytes = |value: i64| u64::try_from(value).unwrap_or(0);
+        let mut usage = DiskUsage::default();
+
+        if let Some(images) = &df.images {
+            let size = df
+                .layers_size
+                .map_or_else(|| images.iter().map(|i| bytes(i.size)).sum(), bytes);
+            let active: Vec<_> = images.iter().filter(|i| i.containers > 0).collect();
+            // Shared layers of images in use cannot be reclaimed
+            // 使用中イメージの共有レイヤーは回収できない
+            let used: u64 = active
+                .iter()
+                .filter(|i| i.shared_size >= 0)
+                .map(|i| bytes(i.size - i.shared_size))
+                .sum();
+            usage.images = DiskUsageCategory {
+                total_count: images.len() as u64,
+                active_count: active.len() as u64,
+                size,
+                reclaimable: size.saturating_sub(used),
+            };
+        }
+
+        if let Some(containers) = &df.containers {
+            let mut category = DiskUsageCategory {
+                total_count: containers.len() as u64,
+                ..DiskUsageCategory::default()
+            };
+            for container in containers {
+                let size = container.size_rw.map_or(0, bytes);
+                category.size += size;
+                if matches!(
+                    container.state,
+                    Some(
+                        ContainerSummaryStateEnum::RUNNING
+                            | ContainerSummaryStateEnum::PAUSED
+                            | ContainerSummaryStateEnum::RESTARTING
+                    )
+                ) {
+                    category.active_count += 1;
+                } else {
+                    category.reclaimable += size;
+                }
+            }
+            usage.containers = category;
+        }
+
+        if let Some(volumes) = &df.volumes {
+            let mut category = DiskUsageCategory {
+                total_count: volumes.len() as u64,
+                ..DiskUsageCategory::default()
+            };
+            for usage_data in volumes.iter().filter_map(|v| v.usage_data.as_ref()) {
+                let size = bytes(usage_data.size);
+                category.size += size;
+                if usage_data.ref_count > 0 {
+                    category.active_count += 1;
+                } else {
+                    category.reclaimable += size;
+                }
+            }
+            usage.volumes = category;
+        }
+
+        if let Some(build_cache) = &df.build_cache {
+            let mut category = DiskUsageCategory {
+                total_count: build_cache.len() as u64,
+                ..DiskUsageCategory::default()
+            };
+            // Shared records are counted by the record owning them
+            // 共有レコードは所有するレコードで計上される
+            for record in build_cache.iter().filter(|r| r.shared != Some(true)) {
+                let size = record.size.map_or(0, bytes);
+                category.size += size;
+                if record.in_use == Some(true) {
+                    category.active_count += 1;
+                } else {
+                    category.reclaimable += size;
+                }
+            }
+            usage.build_cache = category;
+        }
+
+        usage
+    }
+
+    /// Convert a bollard image summary to a domain Image entity
+    /// bollardイメージサマリーをドメインImageエンティティに変換
+    ///
+    /// The first tag names the image; untagged images use `<none>`.
+    /// 最初のタグをイメージ名とし、タグなしイメージは`<none>`を使用します。
+    #[allow(clippy::missing_errors_doc)]
+    pub fn convert_image(summary: ImageSummary, used: &HashSet<String>) -> DockaResult<Image> {
+        let (repository, tag) = summary
+            .repo_tags
+            .iter()
+            .find(|reference| reference.as_str() != "<none>:<none>")
+            .map_or_else(
+                || (UNTAGGED.to_string(), UNTAGGED.to_string()),
+                |reference| Self::split_reference(reference),
+            );
+
+        // Clock skew must not make the image invalid
+        // 時刻のずれでイメージが無効にならないようにする
+        let created_at = DateTime::from_timestamp(summary.created, 0)
+            .map_or_else(Utc::now, |created| created.min(Utc::now()));
+
+        Image::builder()
+            .in_use(used.contains(&summary.id))
+            .id(summary.id)
+            .repository(repository)
+            .tag(tag)
+            .size(u64::try_from(summary.size).unwrap_or(0))
+            .created_at(created_at)
+            .labels(summary.labels)
+            .build()
+    }
+
+    /// Split `repository:tag`, keeping registry ports in the repository
+    /// レジストリのポートをリポジトリに残して`repository:tag`を分割
+    fn split_reference(reference: &str) -> (String, String) {
+        match reference.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => {
+                (repository.to_string(), tag.to_string())
+            }
+            _ => (reference.to_string(), "latest".to_string()),
+        }
+    }
+
+    /// Fill the start and exit times of listed containers from inspect
+    /// 一覧のコンテナの起動・終了日時をinspectから設定
+    ///
+    /// Only containers whose status text changed since the last listing are
+    /// inspected, `INSPECT_CONCURRENCY` at a time. A failed inspect leaves
+    /// the times unset, so the list falls back to the status text.
+    /// 前回の一覧からステータステキストが変わったコンテナのみを
+    /// `INSPECT_CONCURRENCY`個ずつinspectします。inspectに失敗した場合は日時を
+    /// 設定しないため、一覧はステータステキストの表示に戻ります。
+    async fn fill_state_times(&self, containers: &mut [Container], status_texts: Vec<String>) {
+        let changed: Vec<(ContainerId, String)> = {
+            let cache = self.state_times.lock().await;
+            containers
+                .iter()
+                .zip(status_texts)
+                .filter(|(container, status)| {
+                    cache
+                        .get(container.id.as_str())
+                        .is_none_or(|times| times.status != *status)
+                })
+                .map(|(container, status)| (container.id.clone(), status))
+                .collect()
+        };
+        let inspected: Vec<_> = futures_util::stream::iter(changed)
+            .map(|(id, status)| async move {
+                let result = self.inspect(&id).await;
+                (id, status, result)
+            })
+            .buffer_unordered(INSPECT_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut cache = self.state_times.lock().await;
+        for (id, status, result) in inspected {
+            match result {
+                Ok(response) => {
+                    let state = response.state.unwrap_or_default();
+                    cache.insert(
+                        id.as_str().to_string(),
+                        StateTimes {
+                            status,
+                            started_at: Self::parse_occurred(state.started_at.as_deref()),
+                            finished_at: Self::parse_occurred(state.finished_at.as_deref()),
+                        },
+                    );
+                }
+                Err(e) => debug!("Cannot inspect {} for its start and exit times: {}", id, e),
+            }
+        }
+        let listed: HashSet<&str> = containers
+            .iter()
+            .map(|container| container.id.as_str())
+            .collect();
+        cache.retain(|id, _| listed.contains(id.as_str()));
+
+        for container in containers {
+            let times = cache.get(container.id.as_str());
+            container.started_at = times.and_then(|times| times.started_at);
+            container.finished_at = times
+                .and_then(|times| times.finished_at)
+                .filter(|_| matches!(container.status, ContainerStatus::Exited { .. }));
+        }
+        drop(cache);
+    }
+
+    /// Parse an RFC 3339 timestamp reported by the engine
+    /// エンジンが報告する`RFC 3339`タイムスタンプを解析
+    fn parse_timestamp(value: Option<&str>) -> Option<DateTime<Utc>> {
+        value
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+    }
+
+    /// Parse the time of a state change, `None` for one that never happened
+    /// 状態変化の日時を解析（起きていない場合は`None`）
+    ///
+    /// The engine reports the zero time (`0001-01-01T00:00:00Z`) for events
+    /// that never happened.
+    /// エンジンは起きていないイベントにゼロ時刻（`0001-01-01T00:00:00Z`）を報告します。
+    fn parse_occurred(value: Option<&str>) -> Option<DateTime<Utc>> {
+        Self::parse_timestamp(value).filter(|timestamp| timestamp.timestamp() > 0)
+    }
+
+    /// Convert a bollard volume to a domain Volume entity
+    /// bollardボリュームをドメインVolumeエンティティに変換
+    ///
+    /// The API reports `-1` for usage data it did not compute.
+    /// `API`は計算していない使用量データに`-1`を報告します。
+    fn convert_volume(volume: bollard::models::Volume) -> Option<Volume> {
+        let mut builder = Volume::builder()
+            .name(volume.name)
+            .driver(volume.driver)
+            .labels(volume.labels);
+        if let Some(created_at) = Self::parse_timestamp(volume.created_at.as_deref()) {
+            builder = builder.created_at(created_at);
+        }
+        if let Some(usage) = volume.usage_data {
+            if let Ok(size) = u64::try_from(usage.size) {
+                builder = builder.size(size);
+            }
+            if let Ok(ref_count) = u64::try_from(usage.ref_count) {
+                builder = builder.ref_count(ref_count);
+            }
+        }
+
+        builder
+            .build()
+            .inspect_err(|e| warn!("Skipping invalid volume: {}", e))
+            .ok()
+    }
+
+    /// Convert a bollard network to a domain Network entity
+    /// bollardネットワークをドメインNetworkエンティティに変換
+    fn convert_network(
+        network: bollard::models::Network,
+        attached: &HashMap<String, usize>,
+    ) -> Option<Network> {
+        let name = network.name.unwrap_or_default();
+        let mut builder = Network::builder()
+            .id(network.id.unwrap_or_default())
+            .containers(attached.get(&name).copied().unwrap_or(0))
+            .name(name)
+            .driver(network.driver.unwrap_or_default())
+            .labels(network.labels.unwrap_or_default());
+        if let Some(created_at) = Self::parse_timestamp(network.created.as_deref()) {
+            builder = builder.created_at(created_at);
+        }
+
+        builder
+            .build()
+            .inspect_err(|e| warn!("Skipping invalid network: {}", e))
+            .ok()
     }
 
-    async fn restart_container(&self, id: &ContainerId) -> DockaResult<()> {
-        info!("Restarting container: {}", id);
+    /// Convert a bollard build cache record, skipping records without an ID
+    /// bollardビルドキャッシュレコードを変換（IDのないレコードはスキップ）
+    fn convert_build_cache(record: bollard::models::BuildCache) -> Option<BuildCacheEntry> {
+        Some(BuildCacheEntry {
+            id: record.id?,
+            cache_type: record.typ.map(|typ| typ.to_string()).unwrap_or_default(),
+            description: record.description.unwrap_or_default(),
+            size: record
+                .size
+                .and_then(|size| u64::try_from(size).ok())
+                .unwrap_or(0),
+            in_use: record.in_use.unwrap_or(false),
+            shared: record.shared.unwrap_or(false),
+            created_at: Self::parse_timestamp(record.created_at.as_deref()),
+            last_used_at: Self::parse_timestamp(record.last_used_at.as_deref()),
+        })
+    }
 
-        // Verify container exists and can be restarted
-        // コンテナが存在し、再起動可能であることを確認
-        let container = self.get_container(id).await?;
-        if !container.can_restart() {
-            return Err(DockaError::invalid_input(format!(
-                "Container {} cannot be restarted from status {}",
-                id, container.status
-            )));
-        }
+    /// Convert a stats sample the way `docker stats` reports it
+    /// `docker stats`と同じ方法で統計サンプルを変換
+    ///
+    /// Memory excludes the inactive page cache (`inactive_file` on cgroup v2,
+    /// `total_inactive_file` on v1), which the kernel can reclaim at will.
+    /// メモリはカーネルがいつでも回収できる非アクティブなページキャッシュ
+    /// （cgroup v2では`inactive_file`、v1では`total_inactive_file`）を除きます。
+    fn convert_stats(response: ContainerStatsResponse) -> ContainerStats {
+        let total = |stats: &Option<bollard::models::ContainerCpuStats>| {
+            stats.as_ref().map(|cpu| {
+                (
+                    cpu.cpu_usage
+                        .as_ref()
+                        .and_then(|usage| usage.total_usage)
+                        .unwrap_or(0),
+                    cpu.system_cpu_usage.unwrap_or(0),
+                )
+            })
+        };
+        let cpu_percent = total(&response.cpu_stats)
+            .zip(total(&response.precpu_stats))
+            .and_then(|((container, system), (pre_container, pre_system))| {
+                let online_cpus = response
+                    .cpu_stats
+                    .as_ref()
+                    .and_then(|cpu| cpu.online_cpus)
+                    .unwrap_or(1);
+                ContainerStats::cpu_percent_from(
+                    container.saturating_sub(pre_container),
+                    system.saturating_sub(pre_system),
+                    online_cpus,
+                )
+            });
 
-        let options = Some(Self::create_restart_options(Some(10))); // Default 10 second timeout
-        self.client
-            .restart_container(id.as_str(), options)
-            .await
-            .map_err(|e| {
-                error!("Failed to restart container {}: {}", id, e);
-                DockaError::DockerApi(e)
-            })?;
+        let memory_bytes = response.memory_stats.and_then(|memory| {
+            let cache = memory
+                .stats
+                .as_ref()
+                .and_then(|stats| {
+                    stats
+                        .get("inactive_file")
+                        .or_else(|| stats.get("total_inactive_file"))
+                })
+                .copied()
+                .unwrap_or(0);
+            memory.usage.map(|usage| usage.saturating_sub(cache))
+        });
+
+        ContainerStats {
+            cpu_percent,
+            memory_bytes,
+        }
+    }
 
-        info!("Successfully restarted container: {}", id);
-        Ok(())
+    /// Convert a `docker top` table, locating columns by their titles
+    /// `docker top`のテーブルを変換（列はタイトルで特定）
+    ///
+    /// Linux engines report `ps aux` columns and Windows engines report
+    /// `Name`/`PID` only; rows without a numeric PID are skipped.
+    ///
+    /// `Linux`エンジンは`ps aux`の列を、`Windows`エンジンは`Name`/`PID`のみを
+    /// 報告します。数値のPIDがない行はスキップされます。
+    fn convert_processes(response: ContainerTopResponse) -> Vec<ContainerProcess> {
+        let titles = response.titles.unwrap_or_default();
+        let column = |names: &[&str]| {
+            titles
+                .iter()
+                .position(|title| names.contains(&title.as_str()))
+        };
+        let pid = column(&["PID"]);
+        let user = column(&["USER", "UID"]);
+        let cpu = column(&["%CPU"]);
+        let memory = column(&["%MEM"]);
+        let command = column(&["COMMAND", "CMD", "Name"]);
+
+        response
+            .processes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|row| {
+                let field = |index: Option<usize>| index.and_then(|i| row.get(i));
+                let percent = |index| field(index).and_then(|value| value.parse::<f64>().ok());
+                Some(ContainerProcess {
+                    pid: field(pid)?.parse().ok()?,
+                    user: field(user).cloned().unwrap_or_default(),
+                    cpu_percent: percent(cpu),
+                    memory_percent: percent(memory),
+                    command: field(command).cloned().unwrap_or_default(),
+                })
+            })
+            .collect()
     }
 
-    async fn pause_container(&self, id: &ContainerId) -> DockaResult<()> {
-        info!("Pausing container: {}", id);
+    /// Read the limits and restart policy from an inspected host config
+    /// 調査したホスト設定から制限と再起動ポリシーを読み取る
+    ///
+    /// The engine reports `0` (and `-1` for the PIDs limit) when no limit is set.
+    /// エンジンは制限が未設定の場合に`0`（`PIDs`制限では`-1`）を報告します。
+    fn convert_resources(host_config: HostConfig) -> ResourceLimits {
+        let limit = |value: Option<i64>| {
+            value
+                .and_then(|value| u64::try_from(value).ok())
+                .filter(|value| *value > 0)
+        };
+        let restart_policy = host_config
+            .restart_policy
+            .map_or(RestartPolicy::No, |policy| match policy.name {
+                Some(RestartPolicyNameEnum::ALWAYS) => RestartPolicy::Always,
+                Some(RestartPolicyNameEnum::UNLESS_STOPPED) => RestartPolicy::UnlessStopped,
+                Some(RestartPolicyNameEnum::ON_FAILURE) => RestartPolicy::OnFailure {
+                    max_retries: policy
+                        .maximum_retry_count
+                        .and_then(|count| u32::try_from(count).ok())
+                        .unwrap_or(0),
+                },
+                Some(RestartPolicyNameEnum::NO | RestartPolicyNameEnum::EMPTY) | None => {
+                    RestartPolicy::No
+                }
+            });
 
-        // Verify container exists and can be paused
-        // コンテナが存在し、一時停止可能であることを確認
-        let container = self.get_container(id).await?;
-        if !container.can_pause() {
-            return Err(DockaError::invalid_input(format!(
-                "Container {} cannot be paused from status {}",
-                id, container.status
-            )));
+        ResourceLimits {
+            memory: limit(host_config.memory),
+            memory_reservation: limit(host_config.memory_reservation),
+            memory_swap: host_config.memory_swap.filter(|swap| *swap != 0),
+            cpu_shares: limit(host_config.cpu_shares),
+            cpu_quota: limit(host_config.cpu_quota),
+            cpu_period: limit(host_config.cpu_period),
+            pids_limit: limit(host_config.pids_limit),
+            restart_policy,
         }
+    }
 
-        self.client
-            .pause_container(id.as_str())
-            .await
-            .map_err(|e| {
-                error!("Failed to pause container {}: {}", id, e);
-                DockaError::DockerApi(e)
-            })?;
+    /// Read the details shown in the details view from an inspect response
+    /// inspectレスポンスから詳細ビューに表示する詳細を読み取る
+    ///
+    /// Probes without a parseable start time are dropped.
+    /// 解析できる開始時刻のないプローブは除外します。
+    fn convert_details(response: ContainerInspectResponse) -> ContainerDetails {
+        let state = response.state.unwrap_or_default();
+        let health = state
+            .health
+            .map(|health| HealthReport {
+                status: health.status.map_or(HealthStatus::None, |status| {
+                    HealthStatus::parse(status.as_ref())
+                }),
+                failing_streak: health
+                    .failing_streak
+                    .and_then(|streak| u32::try_from(streak).ok())
+                    .unwrap_or(0),
+                probes: health
+                    .log
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|result| {
+                        Some(HealthProbe {
+                            start: Self::parse_timestamp(result.start.as_deref())?,
+                            end: Self::parse_timestamp(result.end.as_deref()),
+                            exit_code: result.exit_code.unwrap_or(-1),
+                            output: result.output.unwrap_or_default(),
+                        })
+                    })
+                    .collect(),
+            })
+            .filter(|report| report.status.is_checked() || !report.probes.is_empty());
+
+        ContainerDetails {
+            started_at: Self::parse_occurred(state.started_at.as_deref()),
+            finished_at: Self::parse_occurred(state.finished_at.as_deref()),
+            health,
+            restart_count: response
+                .restart_count
+                .and_then(|count| u64::try_from(count).ok())
+                .unwrap_or(0),
+            oom_killed: state.oom_killed.unwrap_or(false),
+            env: response
+                .config
+                .and_then(|config| config.env)
+                .unwrap_or_default(),
+            network_addresses: Self::network_addresses(response.network_settings),
+            port_bindings: response
+                .host_config
+                .and_then(|host_config| host_config.port_bindings)
+                .map(Self::convert_port_bindings)
+                .unwrap_or_default(),
+        }
+    }
 
-        info!("Successfully paused container: {}", id);
-        Ok(())
+    /// Addresses of a container on its networks, ordered by network name
+    /// コンテナのネットワーク上のアドレス（ネットワーク名順）
+    fn network_addresses(settings: Option<NetworkSettings>) -> Vec<NetworkAddress> {
+        let mut addresses: Vec<NetworkAddress> = settings
+            .and_then(|settings| settings.networks)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(network, endpoint)| {
+                let ip = endpoint.ip_address?.parse().ok()?;
+                Some(NetworkAddress { network, ip })
+            })
+            .collect();
+        addresses.sort_by(|a, b| a.network.cmp(&b.network));
+        addresses
     }
 
-    async fn unpause_container(&self, id: &ContainerId) -> DockaResult<()> {
-        info!("Unpausing container: {}", id);
+    /// Convert the ports of a container summary
+    /// コンテナサマリーのポートを変換
+    fn convert_ports(ports: Vec<Port>) -> Vec<PortMapping> {
+        Self::normalize_ports(
+            ports
+                .into_iter()
+                .map(|port| {
+                    let mapping = PortMapping::new(
+                        port.private_port,
+                        port.typ
+                            .map_or(PortProtocol::Tcp, |typ| PortProtocol::parse(typ.as_ref())),
+                    );
+                    match port.public_port {
+                        Some(public_port) => mapping.published(port.ip.as_deref(), public_port),
+                        None => mapping,
+                    }
+                })
+                .collect(),
+        )
+    }
 
-        // Verify container exists and can be unpaused
-        // コンテナが存在し、一時停止解除可能であることを確認
-        let container = self.get_container(id).await?;
-        if !container.can_unpause() {
-            return Err(DockaError::invalid_input(format!(
-                "Container {} cannot be unpaused from status {}",
-                id, container.status
-            )));
+    /// Convert `HostConfig.PortBindings`, keyed by `<port>/<protocol>`
+    /// `<port>/<protocol>`をキーとする`HostConfig.PortBindings`を変換
+    ///
+    /// Bindings with an empty host port get a random port at start, so they
+    /// are kept as unpublished.
+    /// ホストポートが空のバインドは起動時にランダムなポートになるため、
+    /// 公開されていないものとして保持します。
+    fn convert_port_bindings(bindings: PortMap) -> Vec<PortMapping> {
+        let mut ports = Vec::new();
+        for (key, host_bindings) in bindings {
+            let (port, protocol) = key.split_once('/').unwrap_or((key.as_str(), "tcp"));
+            let Ok(container_port) = port.parse() else {
+                continue;
+            };
+            let mapping = PortMapping::new(container_port, PortProtocol::parse(protocol));
+            let host_bindings = host_bindings.unwrap_or_default();
+            if host_bindings.is_empty() {
+                ports.push(mapping.clone());
+            }
+            for binding in host_bindings {
+                let host_port = binding.host_port.as_deref().and_then(|p| p.parse().ok());
+                ports.push(host_port.map_or_else(
+                    || mapping.clone(),
+                    |host_port| {
+                        mapping
+                            .clone()
+                            .published(binding.host_ip.as_deref(), host_port)
+                    },
+                ));
+            }
         }
+        Self::normalize_ports(ports)
+    }
 
-        self.client
-            .unpause_container(id.as_str())
-            .await
-            .map_err(|e| {
-                error!("Failed to unpause container {}: {}", id, e);
-                DockaError::DockerApi(e)
-            })?;
+    /// Sort ports by container port and drop the IPv6 twin of IPv4 bindings
+    /// ポートをコンテナポート順に並べ、`IPv4`バインドと対になる`IPv6`バインドを除外
+    ///
+    /// The engine lists a port published on every address once for `0.0.0.0`
+    /// and once for `::`.
+    /// エンジンは全アドレスで公開されたポートを`0.0.0.0`と`::`で1回ずつ列挙します。
+    fn normalize_ports(mut ports: Vec<PortMapping>) -> Vec<PortMapping> {
+        ports.sort_by(|a, b| {
+            (a.container_port, a.protocol, a.host_port, &a.host_ip).cmp(&(
+                b.container_port,
+                b.protocol,
+                b.host_port,
+                &b.host_ip,
+            ))
+        });
+        ports.dedup_by(|later, earlier| {
+            later.container_port == earlier.container_port
+                && later.protocol == earlier.protocol
+                && later.host_port == earlier.host_port
+                && (later.host_ip == earlier.host_ip
+                    || (later.host_ip.as_deref() == Some("::")
+                        && matches!(earlier.host_ip.as_deref(), None | Some("0.0.0.0"))))
+        });
+        ports
+    }
 
-        info!("Successfully unpaused container: {}", id);
-        Ok(())
+    /// Convert a `die` event to a container exit
+    /// `die`イベントをコンテナの終了に変換
+    ///
+    /// Events without a container ID or time are dropped.
+    /// コンテナIDまたは時刻のないイベントは除外します。
+    fn convert_exit(event: EventMessage) -> Option<ContainerExit> {
+        let actor = event.actor?;
+        let container_id = ContainerId::new(actor.id?).ok()?;
+        let at = match (event.time_nano, event.time) {
+            (Some(nanos), _) => DateTime::from_timestamp_nanos(nanos),
+            (None, Some(seconds)) => DateTime::from_timestamp(seconds, 0)?,
+            (None, None) => return None,
+        };
+        let exit_code = actor
+            .attributes
+            .and_then(|attributes| attributes.get("exitCode")?.parse().ok());
+
+        Some(ContainerExit {
+            container_id,
+            at,
+            exit_code,
+        })
+    }
+
+    /// Build the `/containers/{id}/update` body moving `current` to `limits`
+    /// `current`を`limits`に変更する`/containers/{id}/update`の本文を構築
+    ///
+    /// The engine ignores zero values, so removed CPU quota and PIDs limits
+    /// are sent as `-1`, which it accepts as "unlimited".
+    /// エンジンは0の値を無視するため、削除したCPUクォータとPIDs制限は
+    /// 「無制限」として受け付けられる`-1`として送信します。
+    fn create_update_body(
+        limits: &ResourceLimits,
+        current: &ResourceLimits,
+    ) -> DockaResult<ContainerUpdateBody> {
+        let engine = |value: Option<u64>| {
+            value
+                .map(|value| {
+                    i64::try_from(value).map_err(|_| {
+                        DockaError::invalid_input(format!("Limit {value} is too large"))
+                    })
+                })
+                .transpose()
+        };
+        let removable = |value: Option<u64>, before: Option<u64>| {
+            if value.is_none() && before.is_some() {
+                Ok(Some(-1))
+            } else {
+                engine(value)
+            }
+        };
+        let (name, maximum_retry_count) = match limits.restart_policy {
+            RestartPolicy::No => (RestartPolicyNameEnum::NO, None),
+            RestartPolicy::Always => (RestartPolicyNameEnum::ALWAYS, None),
+            RestartPolicy::UnlessStopped => (RestartPolicyNameEnum::UNLESS_STOPPED, None),
+            RestartPolicy::OnFailure { max_retries } => (
+                RestartPolicyNameEnum::ON_FAILURE,
+                Some(i64::from(max_retries)),
+            ),
+        };
+
+        Ok(ContainerUpdateBody {
+            memory: engine(limits.memory)?,
+            memory_reservation: engine(limits.memory_reservation)?,
+            memory_swap: limits.memory_swap_after(current),
+            cpu_shares: engine(limits.cpu_shares)?,
+            cpu_quota: removable(limits.cpu_quota, current.cpu_quota)?,
+            cpu_period: engine(limits.cpu_period)?,
+            pids_limit: removable(limits.pids_limit, current.pids_limit)?,
+            restart_policy: Some(bollard::models::RestartPolicy {
+                name: Some(name),
+                maximum_retry_count,
+            }),
+            ..ContainerUpdateBody::default()
+        })
+    }
+
+    /// Convert the numeric change kind of `/containers/{id}/changes`
+    /// `/containers/{id}/changes`の数値の変更種別を変換
+    const fn convert_change_kind(kind: ChangeType) -> ChangeKind {
+        match kind {
+            ChangeType::_0 => ChangeKind::Modified,
+            ChangeType::_1 => ChangeKind::Added,
+            ChangeType::_2 => ChangeKind::Deleted,
+        }
     }
-}
 
-impl BollardDockerRepository {
     /// Convert bollard container to domain Container entity
     /// bollardコンテナをドメインContainerエンティティに変換
     ///
@@ -546,12 +2021,26 @@ impl BollardDockerRepository {
             .image
             .unwrap_or_else(|| "unknown".to_string());
 
-        // Convert status
-        // ステータスを変換
-        let status = bollard_container
-            .status
-            .as_deref()
-            .map_or(ContainerStatus::Dead, ContainerStatus::from_docker_string);
+        // Convert status: `state` is machine-readable, while the human-readable
+        // `status` ("Exited (137) 3 days ago") carries the exit code
+        // ステータスを変換: `state`は機械可読、人間向けの`status`
+        // （"Exited (137) 3 days ago"）は終了コードを持つ
+        let status_text = bollard_container.status.as_deref();
+        let status = match bollard_container.state {
+            Some(ContainerSummaryStateEnum::EXITED) => {
+                match status_text.map(ContainerStatus::from_docker_string) {
+                    Some(status @ ContainerStatus::Exited { .. }) => status,
+                    _ => ContainerStatus::Exited { exit_code: -1 },
+                }
+            }
+            Some(state) if state != ContainerSummaryStateEnum::EMPTY => {
+                ContainerStatus::from_docker_string(state.as_ref())
+            }
+            _ => status_text.map_or(ContainerStatus::Dead, ContainerStatus::from_docker_string),
+        };
+        // Health only appears in the human-readable text, e.g. "Up 3 minutes (healthy)"
+        // ヘルスは人間向けのテキストにのみ現れる（例: "Up 3 minutes (healthy)"）
+        let health = status_text.map_or(HealthStatus::None, HealthStatus::from_docker_string);
 
         // Convert creation timestamp
         // 作成タイムスタンプを変換
@@ -568,6 +2057,7 @@ impl BollardDockerRepository {
         // Extract command (bollard now provides command as String, not Vec<String>)
         // コマンドを抽出（bollardは現在commandをVec<String>ではなくStringで提供）
         let command = bollard_container.command.filter(|cmd| !cmd.is_empty());
+        let ports = Self::convert_ports(bollard_container.ports.unwrap_or_default());
 
         // Build the domain container
         // ドメインコンテナを構築
@@ -576,6 +2066,7 @@ impl BollardDockerRepository {
             .name(name)
             .image(image)
             .status(status)
+            .health(health)
             .created_at(created_at)
             .labels(labels);
 
@@ -584,6 +2075,9 @@ impl BollardDockerRepository {
         if let Some(cmd) = command {
             builder = builder.command(cmd);
         }
+        for port in ports {
+            builder = builder.port(port);
+        }
 
         builder.build()
     }
@@ -789,6 +2283,377 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_container_uses_state_and_exit_code() {
+        // Test that the machine-readable state wins, the exit code comes from the
+        // human-readable status, and times are left to inspect
+        // 機械可読の状態が優先され、終了コードは人間向けステータスから得られ、
+        // 日時はinspectに任されることをテスト
+        let mut summary =
+            create_test_container_summary("ci-job", "ci-job", "runner:latest", "Up 3 hours");
+        summary.state = Some(ContainerSummaryStateEnum::RUNNING);
+        let container = BollardDockerRepository::convert_container(summary).unwrap();
+        assert_eq!(container.status, ContainerStatus::Running);
+        assert!(container.started_at.is_none());
+        assert_eq!(container.health, HealthStatus::None);
+
+        let mut summary =
+            create_test_container_summary("api", "api", "api:latest", "Up 10 minutes (unhealthy)");
+        summary.state = Some(ContainerSummaryStateEnum::RUNNING);
+        let container = BollardDockerRepository::convert_container(summary).unwrap();
+        assert_eq!(container.status, ContainerStatus::Running);
+        assert_eq!(container.health, HealthStatus::Unhealthy);
+
+        let mut summary = create_test_container_summary(
+            "ci-job",
+            "ci-job",
+            "runner:latest",
+            "Exited (137) 3 days ago",
+        );
+        summary.state = Some(ContainerSummaryStateEnum::EXITED);
+        let container = BollardDockerRepository::convert_container(summary).unwrap();
+        assert_eq!(container.status, ContainerStatus::Exited { exit_code: 137 });
+        assert!(container.finished_at.is_none());
+    }
+
+    #[test]
+    fn test_resources_round_trip_through_update_body() {
+        // Test reading limits from inspect and sending removals as -1
+        // inspectから制限を読み取り、削除を-1として送信することをテスト
+        let current = BollardDockerRepository::convert_resources(HostConfig {
+            memory: Some(512 << 20),
+            memory_swap: Some(1 << 30),
+            cpu_quota: Some(50_000),
+            pids_limit: Some(-1),
+            restart_policy: Some(bollard::models::RestartPolicy {
+                name: Some(RestartPolicyNameEnum::ON_FAILURE),
+                maximum_retry_count: Some(5),
+            }),
+            ..HostConfig::default()
+        });
+        assert_eq!(current.memory, Some(512 << 20));
+        assert_eq!(current.pids_limit, None);
+        assert_eq!(
+            current.restart_policy,
+            RestartPolicy::OnFailure { max_retries: 5 }
+        );
+
+        let limits = ResourceLimits {
+            memory: Some(1 << 30),
+            cpu_quota: None,
+            restart_policy: RestartPolicy::UnlessStopped,
+            ..current
+        };
+        let body = BollardDockerRepository::create_update_body(&limits, &current).unwrap();
+        assert_eq!(body.memory, Some(1 << 30));
+        assert_eq!(body.memory_swap, Some(1_536 << 20));
+        assert_eq!(body.cpu_quota, Some(-1));
+        assert_eq!(body.pids_limit, None);
+        assert_eq!(
+            body.restart_policy.and_then(|policy| policy.name),
+            Some(RestartPolicyNameEnum::UNLESS_STOPPED)
+        );
+    }
+
+    #[test]
+    fn test_convert_details_reads_health_log() {
+        // Test that the health log is read and containers without a check have no report
+        // ヘルスログが読み取られ、チェックのないコンテナにはレポートがないことをテスト
+        let response = ContainerInspectResponse {
+            state: Some(bollard::models::ContainerState {
+                health: Some(bollard::models::Health {
+                    status: Some(bollard::models::HealthStatusEnum::UNHEALTHY),
+                    failing_streak: Some(3),
+                    log: Some(vec![
+                        bollard::models::HealthcheckResult {
+                            start: Some("2024-05-01T10:00:00.123456789Z".to_string()),
+                            end: Some("2024-05-01T10:00:00.5Z".to_string()),
+                            exit_code: Some(1),
+                            output: Some("curl: (7) Failed to connect".to_string()),
+                        },
+                        bollard::models::HealthcheckResult {
+                            start: None,
+                            ..bollard::models::HealthcheckResult::default()
+                        },
+                    ]),
+                }),
+                ..bollard::models::ContainerState::default()
+            }),
+            ..ContainerInspectResponse::default()
+        };
+        let report = BollardDockerRepository::convert_details(response)
+            .health
+            .unwrap();
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert_eq!(report.failing_streak, 3);
+        assert_eq!(report.probes.len(), 1);
+        assert_eq!(report.probes[0].exit_code, 1);
+        assert!(report.probes[0].output.contains("Failed to connect"));
+        assert!(report.probes[0].end.is_some());
+
+        let unchecked = ContainerInspectResponse {
+            state: Some(bollard::models::ContainerState {
+                health: Some(bollard::models::Health {
+                    status: Some(bollard::models::HealthStatusEnum::NONE),
+                    ..bollard::models::Health::default()
+                }),
+                ..bollard::models::ContainerState::default()
+            }),
+            ..ContainerInspectResponse::default()
+        };
+        assert_eq!(
+            BollardDockerRepository::convert_details(unchecked),
+            ContainerDetails::default()
+        );
+    }
+
+    #[test]
+    fn test_convert_exit_from_die_event() {
+        // Test that die events keep their exit code and nanosecond time
+        // dieイベントの終了コードとナノ秒の時刻が保持されることをテスト
+        let event = |id: Option<&str>, exit_code: &str| EventMessage {
+            actor: Some(bollard::models::EventActor {
+                id: id.map(str::to_string),
+                attributes: Some(HashMap::from([(
+                    "exitCode".to_string(),
+                    exit_code.to_string(),
+                )])),
+            }),
+            time: Some(1_714_557_600),
+            time_nano: Some(1_714_557_600_250_000_000),
+            ..EventMessage::default()
+        };
+
+        let exit = BollardDockerRepository::convert_exit(event(Some("abc123"), "137")).unwrap();
+        assert_eq!(exit.container_id.as_str(), "abc123");
+        assert_eq!(exit.exit_code, Some(137));
+        assert_eq!(exit.at.timestamp_subsec_millis(), 250);
+        assert_eq!(
+            BollardDockerRepository::convert_exit(event(Some("abc123"), "?"))
+                .unwrap()
+                .exit_code,
+            None
+        );
+        assert!(BollardDockerRepository::convert_exit(event(None, "1")).is_none());
+
+        let details = BollardDockerRepository::convert_details(ContainerInspectResponse {
+            restart_count: Some(7),
+            ..ContainerInspectResponse::default()
+        });
+        assert_eq!(details.restart_count, 7);
+        assert!(details.health.is_none());
+        assert!(!details.oom_killed);
+    }
+
+    #[test]
+    fn test_convert_details_reads_oom_kill_and_times() {
+        // Test that an OOM kill and start time are kept and the zero finish time is dropped
+        // OOMによる強制終了と起動時刻が保持され、ゼロの終了時刻が除外されることをテスト
+        let details = BollardDockerRepository::convert_details(ContainerInspectResponse {
+            state: Some(bollard::models::ContainerState {
+                exit_code: Some(137),
+                oom_killed: Some(true),
+                started_at: Some("2024-05-01T10:00:00.5Z".to_string()),
+                finished_at: Some("0001-01-01T00:00:00Z".to_string()),
+                ..bollard::models::ContainerState::default()
+            }),
+            ..ContainerInspectResponse::default()
+        });
+        assert!(details.oom_killed);
+        assert_eq!(
+            details.started_at.unwrap().to_rfc3339(),
+            "2024-05-01T10:00:00.500+00:00"
+        );
+        assert!(details.finished_at.is_none());
+    }
+
+    #[test]
+    fn test_convert_ports_from_summary_and_bindings() {
+        // Test that ports are sorted, deduplicated across IPv4/IPv6 and read from bindings,
+        // and that network addresses are read from inspect
+        // ポートが並べ替えられ、IPv4/IPv6間で重複排除され、バインドから読み取られ、
+        // ネットワークアドレスがinspectから読み取られることをテスト
+        let port = |ip: Option<&str>, private_port, public_port, typ| Port {
+            ip: ip.map(str::to_string),
+            private_port,
+            public_port,
+            typ: Some(typ),
+        };
+        let mut summary = create_test_container_summary("abc123", "db", "postgres", "Up 1 hour");
+        summary.ports = Some(vec![
+            port(
+                Some("::"),
+                5432,
+                Some(15432),
+                bollard::models::PortTypeEnum::TCP,
+            ),
+            port(None, 9229, None, bollard::models::PortTypeEnum::TCP),
+            port(
+                Some("0.0.0.0"),
+                5432,
+                Some(15432),
+                bollard::models::PortTypeEnum::TCP,
+            ),
+            port(
+                Some("127.0.0.1"),
+                53,
+                Some(5353),
+                bollard::models::PortTypeEnum::UDP,
+            ),
+        ]);
+        let container = BollardDockerRepository::convert_container(summary).unwrap();
+        let ports: Vec<String> = container.ports.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            ports,
+            [
+                "127.0.0.1:5353 → 53/udp",
+                "0.0.0.0:15432 → 5432/tcp",
+                "9229/tcp"
+            ]
+        );
+        assert_eq!(container.published_ports().count(), 2);
+
+        let binding = |host_port: &str| bollard::models::PortBinding {
+            host_ip: Some(String::new()),
+            host_port: Some(host_port.to_string()),
+        };
+        let details = BollardDockerRepository::convert_details(ContainerInspectResponse {
+            config: Some(bollard::models::ContainerConfig {
+                env: Some(vec!["POSTGRES_PASSWORD=hunter2".to_string()]),
+                ..bollard::models::ContainerConfig::default()
+            }),
+            host_config: Some(HostConfig {
+                port_bindings: Some(HashMap::from([
+                    ("80/tcp".to_string(), Some(vec![binding("8080")])),
+                    ("443/tcp".to_string(), Some(vec![binding("")])),
+                ])),
+                ..HostConfig::default()
+            }),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([
+                    (
+                        "bridge".to_string(),
+                        bollard::models::EndpointSettings {
+                            ip_address: Some("172.17.0.3".to_string()),
+                            ..bollard::models::EndpointSettings::default()
+                        },
+                    ),
+                    (
+                        "none".to_string(),
+                        bollard::models::EndpointSettings {
+                            ip_address: Some(String::new()),
+                            ..bollard::models::EndpointSettings::default()
+                        },
+                    ),
+                ])),
+                ..NetworkSettings::default()
+            }),
+            ..ContainerInspectResponse::default()
+        });
+        assert_eq!(
+            details.network_addresses,
+            [NetworkAddress {
+                network: "bridge".to_string(),
+                ip: "172.17.0.3".parse().unwrap(),
+            }]
+        );
+        let bindings: Vec<String> = details
+            .port_bindings
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(bindings, ["0.0.0.0:8080 → 80/tcp", "443/tcp"]);
+        assert_eq!(details.env, ["POSTGRES_PASSWORD=hunter2"]);
+    }
+
+    #[test]
+    fn test_convert_stats_like_docker_stats() {
+        // Test that CPU comes from the deltas between samples and memory excludes the page cache
+        // CPUがサンプル間の差分から計算され、メモリがページキャッシュを除くことをテスト
+        use bollard::models::{ContainerCpuStats, ContainerCpuUsage, ContainerMemoryStats};
+
+        let cpu = |total_usage, system_cpu_usage| ContainerCpuStats {
+            cpu_usage: Some(ContainerCpuUsage {
+                total_usage: Some(total_usage),
+                ..Default::default()
+            }),
+            system_cpu_usage: Some(system_cpu_usage),
+            online_cpus: Some(2),
+            ..Default::default()
+        };
+        let response = ContainerStatsResponse {
+            cpu_stats: Some(cpu(1_500, 10_000)),
+            precpu_stats: Some(cpu(1_000, 8_000)),
+            memory_stats: Some(ContainerMemoryStats {
+                usage: Some(100 * 1024 * 1024),
+                stats: Some(HashMap::from([(
+                    "inactive_file".to_string(),
+                    20 * 1024 * 1024,
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let stats = BollardDockerRepository::convert_stats(response);
+        assert_eq!(stats.cpu_percent, Some(50.0));
+        assert_eq!(stats.memory_bytes, Some(80 * 1024 * 1024));
+
+        let stats = BollardDockerRepository::convert_stats(ContainerStatsResponse::default());
+        assert_eq!(stats, ContainerStats::default());
+    }
+
+    #[test]
+    fn test_convert_processes_by_column_title() {
+        // Test that ps aux and Windows tables are mapped by their titles
+        // ps auxとWindowsのテーブルがタイトルで変換されることをテスト
+        let titles = |names: &[&str]| Some(names.iter().map(ToString::to_string).collect());
+        let row = |values: &[&str]| values.iter().map(ToString::to_string).collect();
+
+        let linux = ContainerTopResponse {
+            titles: titles(&[
+                "USER", "PID", "%CPU", "%MEM", "VSZ", "RSS", "TTY", "STAT", "START", "TIME",
+                "COMMAND",
+            ]),
+            processes: Some(vec![
+                row(&[
+                    "root",
+                    "4242",
+                    "12.5",
+                    "0.3",
+                    "10",
+                    "20",
+                    "?",
+                    "Ss",
+                    "10:00",
+                    "0:01",
+                    "nginx: master process nginx -g daemon off;",
+                ]),
+                row(&["root", "garbage"]),
+            ]),
+        };
+        let processes = BollardDockerRepository::convert_processes(linux);
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].pid, 4242);
+        assert_eq!(processes[0].user, "root");
+        assert_eq!(processes[0].cpu_percent, Some(12.5));
+        assert_eq!(processes[0].memory_percent, Some(0.3));
+        assert_eq!(
+            processes[0].command,
+            "nginx: master process nginx -g daemon off;"
+        );
+
+        let windows = ContainerTopResponse {
+            titles: titles(&["Name", "PID", "CPU", "Private Working Set"]),
+            processes: Some(vec![row(&["smss.exe", "96", "00:00:00.031", "241.7kB"])]),
+        };
+        let processes = BollardDockerRepository::convert_processes(windows);
+        assert_eq!(processes[0].pid, 96);
+        assert_eq!(processes[0].command, "smss.exe");
+        assert_eq!(processes[0].cpu_percent, None);
+        assert!(processes[0].user.is_empty());
+    }
+
     #[test]
     fn test_convert_container_name_processing() {
         // Test container name processing (removing leading slash)
@@ -1070,4 +2935,195 @@ mod tests {
             assert_eq!(count, 10, "Each thread should process 10 containers");
         }
     }
+
+    #[test]
+    fn test_cgroup_limitation_from_info() {
+        // Test that engines without usable cgroups are detected from /info
+        // 使用可能なcgroupがないエンジンが/infoから検出されることをテスト
+        let full = SystemInfo {
+            cgroup_driver: Some(SystemInfoCgroupDriverEnum::SYSTEMD),
+            cgroup_version: Some(SystemInfoCgroupVersionEnum::_2),
+            security_options: Some(vec!["name=rootless".to_string()]),
+            ..Default::default()
+        };
+        assert!(BollardDockerRepository::cgroup_limitation(&full).is_none());
+
+        let no_driver = SystemInfo {
+            cgroup_driver: Some(SystemInfoCgroupDriverEnum::NONE),
+            ..Default::default()
+        };
+        assert!(BollardDockerRepository::cgroup_limitation(&no_driver).is_some());
+
+        let rootless_v1 = SystemInfo {
+            cgroup_driver: Some(SystemInfoCgroupDriverEnum::CGROUPFS),
+            cgroup_version: Some(SystemInfoCgroupVersionEnum::_1),
+            security_options: Some(vec![
+                "name=seccomp,profile=default".to_string(),
+                "name=rootless".to_string(),
+            ]),
+            ..Default::default()
+        };
+        assert!(BollardDockerRepository::cgroup_limitation(&rootless_v1).is_some());
+    }
+
+    #[test]
+    fn test_engine_version_names_podman() {
+        // Test that Podman engines are labelled as such
+        // Podmanエンジンがそのようにラベル付けされることをテスト
+        let docker = SystemVersion {
+            version: Some("24.0.7".to_string()),
+            platform: Some(bollard::models::SystemVersionPlatform {
+                name: "Docker Engine - Community".to_string(),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(BollardDockerRepository::engine_version(&docker), "24.0.7");
+
+        let podman = SystemVersion {
+            version: Some("4.9.3".to_string()),
+            platform: Some(bollard::models::SystemVersionPlatform {
+                name: "Podman Engine".to_string(),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            BollardDockerRepository::engine_version(&podman),
+            "Podman 4.9.3"
+        );
+    }
+
+    #[test]
+    fn test_disk_usage_from_df() {
+        // Test that df results are summarized like `docker system df`
+        // dfの結果が`docker system df`と同様に集計されることをテスト
+        use bollard::models::{BuildCache, ImageSummary, Volume, VolumeUsageData};
+
+        let image = |size, shared_size, containers| ImageSummary {
+            size,
+            shared_size,
+            containers,
+            ..Default::default()
+        };
+        let container = |state, size_rw| ContainerSummary {
+            state: Some(state),
+            size_rw: Some(size_rw),
+            ..Default::default()
+        };
+        let volume = |size, ref_count| Volume {
+            usage_data: Some(VolumeUsageData { size, ref_count }),
+            ..Default::default()
+        };
+        let cache = |size, in_use, shared| BuildCache {
+            size: Some(size),
+            in_use: Some(in_use),
+            shared: Some(shared),
+            ..Default::default()
+        };
+
+        let df = SystemDataUsageResponse {
+            layers_size: Some(1_000),
+            images: Some(vec![image(600, 200, 1), image(400, 200, 0)]),
+            containers: Some(vec![
+                container(ContainerSummaryStateEnum::RUNNING, 50),
+                container(ContainerSummaryStateEnum::EXITED, 30),
+            ]),
+            volumes: Some(vec![volume(100, 1), volume(70, 0), volume(-1, 0)]),
+            build_cache: Some(vec![
+                cache(500, false, false),
+                cache(200, true, false),
+                cache(900, false, true),
+            ]),
+        };
+
+        let usage = BollardDockerRepository::disk_usage_from_df(&df);
+        assert_eq!(usage.images.total_count, 2);
+        assert_eq!(usage.images.active_count, 1);
+        assert_eq!(usage.images.size, 1_000);
+        assert_eq!(usage.images.reclaimable, 600);
+        assert_eq!(usage.containers.size, 80);
+        assert_eq!(usage.containers.active_count, 1);
+        assert_eq!(usage.containers.reclaimable, 30);
+        assert_eq!(usage.volumes.total_count, 3);
+        assert_eq!(usage.volumes.size, 170);
+        assert_eq!(usage.volumes.reclaimable, 70);
+        assert_eq!(usage.build_cache.size, 700);
+        assert_eq!(usage.build_cache.reclaimable, 500);
+        assert_eq!(usage.total_reclaimable(), 1_200);
+    }
+
+    #[test]
+    fn test_convert_image_tags_and_usage() {
+        // Test tag splitting, dangling images and usage marking
+        // タグの分割、ダングリングイメージ、使用中マークをテスト
+        let summary = |id: &str, tags: &[&str]| ImageSummary {
+            id: id.to_string(),
+            repo_tags: tags.iter().map(ToString::to_string).collect(),
+            created: 1_700_000_000,
+            size: 1_000,
+            ..ImageSummary::default()
+        };
+        let used = HashSet::from(["sha256:a".to_string()]);
+
+        let image =
+            BollardDockerRepository::convert_image(summary("sha256:a", &["nginx:1.25"]), &used)
+                .unwrap();
+        assert_eq!(
+            (image.repository.as_str(), image.tag.as_str()),
+            ("nginx", "1.25")
+        );
+        assert!(image.in_use);
+
+        let image = BollardDockerRepository::convert_image(
+            summary("sha256:b", &["localhost:5000/app"]),
+            &used,
+        )
+        .unwrap();
+        assert_eq!(image.repository, "localhost:5000/app");
+        assert_eq!(image.tag, "latest");
+        assert!(!image.in_use);
+
+        let image =
+            BollardDockerRepository::convert_image(summary("sha256:c", &["<none>:<none>"]), &used)
+                .unwrap();
+        assert!(image.is_dangling());
+    }
+
+    #[test]
+    fn test_convert_volume_and_build_cache() {
+        // Test that uncomputed usage stays unknown and timestamps are parsed
+        // 未計算の使用量が不明のままでタイムスタンプが解析されることをテスト
+        let volume = BollardDockerRepository::convert_volume(bollard::models::Volume {
+            name: "data".to_string(),
+            driver: "local".to_string(),
+            created_at: Some("2024-01-02T03:04:05Z".to_string()),
+            usage_data: Some(bollard::models::VolumeUsageData {
+                size: -1,
+                ref_count: 0,
+            }),
+            ..bollard::models::Volume::default()
+        })
+        .unwrap();
+        assert_eq!(volume.size, None);
+        assert_eq!(volume.ref_count, Some(0));
+        assert_eq!(
+            volume.created_at.unwrap().to_rfc3339(),
+            "2024-01-02T03:04:05+00:00"
+        );
+
+        let entry = BollardDockerRepository::convert_build_cache(bollard::models::BuildCache {
+            id: Some("abc".to_string()),
+            typ: Some(bollard::models::BuildCacheTypeEnum::REGULAR),
+            size: Some(42),
+            last_used_at: Some("not a date".to_string()),
+            ..bollard::models::BuildCache::default()
+        })
+        .unwrap();
+        assert_eq!(entry.cache_type, "regular");
+        assert_eq!(entry.size, 42);
+        assert_eq!(entry.last_used_at, None);
+        assert!(
+            BollardDockerRepository::convert_build_cache(bollard::models::BuildCache::default())
+                .is_none()
+        );
+    }
 }
@@ -0,0 +1,808 @@
+// src/infrastructure/docker/docker_context.rs
+// Docker context and endpoint resolution
+// Dockerコンテキストとエンドポイントの解決
+
+use crate::error::{DockaError, DockaResult};
+use crate::infrastructure::docker::socket_probe::{EngineKind, SocketProbe};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Name used by the Docker CLI for the implicit local endpoint
+/// `Docker` `CLI`が暗黙のローカルエンドポイントに使用する名前
+pub const DEFAULT_CONTEXT_NAME: &str = "default";
+
+/// Socket path fragments of engines whose containers live in a VM or a user namespace
+/// コンテナが仮想マシンまたはユーザー名前空間内にあるエンジンのソケットパスの断片
+const UNROUTABLE_SOCKET_MARKERS: &[&str] = &[
+    "/run/user/",
+    ".docker/desktop/",
+    ".docker/run/",
+    ".colima/",
+    ".lima/",
+    ".orbstack/",
+    ".rd/",
+    "podman/machine/",
+];
+
+/// Default Docker daemon socket on Linux and macOS
+/// `Linux`と`macOS`でのデフォルト`Docker` `daemon`ソケット
+pub const DEFAULT_DOCKER_HOST: &str = "unix:///var/run/docker.sock";
+
+/// Transport used to reach a Docker daemon
+/// `Docker` `daemon`に到達するためのトランスポート
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DockerTransport {
+    /// Local unix domain socket
+    /// ローカル`unix`ドメインソケット
+    Unix {
+        /// Socket path
+        /// ソケットパス
+        path: PathBuf,
+    },
+
+    /// Plain TCP connection without TLS
+    /// `TLS`なしのプレーン`TCP`接続
+    Tcp {
+        /// `host:port` address
+        /// `host:port`アドレス
+        address: String,
+    },
+
+    /// TCP connection secured with TLS client certificates
+    /// `TLS`クライアント証明書で保護された`TCP`接続
+    Tls {
+        /// `host:port` address
+        /// `host:port`アドレス
+        address: String,
+
+        /// Directory containing `ca.pem`, `cert.pem` and `key.pem`
+        /// `ca.pem`、`cert.pem`、`key.pem`を含むディレクトリ
+        cert_path: PathBuf,
+    },
+}
+
+impl DockerTransport {
+    /// Parse a Docker host URL such as `unix:///var/run/docker.sock` or `tcp://10.0.0.5:2376`
+    /// `unix:///var/run/docker.sock`や`tcp://10.0.0.5:2376`のような`Docker`ホストURLを解析
+    ///
+    /// # Arguments
+    /// * `host` - Host URL in Docker CLI format
+    /// * `tls_cert_path` - Certificate directory when TLS is requested
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the scheme is missing or unsupported (e.g. `ssh://`, `npipe://`)
+    pub fn parse(host: &str, tls_cert_path: Option<&Path>) -> DockaResult<Self> {
+        let host = host.trim();
+
+        if let Some(path) = host.strip_prefix("unix://") {
+            if path.is_empty() {
+                return Err(DockaError::invalid_input(format!(
+                    "Docker host '{host}' has an empty socket path"
+                )));
+            }
+            return Ok(Self::Unix {
+                path: PathBuf::from(path),
+            });
+        }
+
+        let (address, force_tls) = if let Some(address) = host.strip_prefix("tcp://") {
+            (address, false)
+        } else if let Some(address) = host.strip_prefix("http://") {
+            (address, false)
+        } else if let Some(address) = host.strip_prefix("https://") {
+            (address, true)
+        } else {
+            return Err(DockaError::invalid_input(format!(
+                "Unsupported Docker host '{host}' (expected unix://, tcp://, http:// or https://)"
+            )));
+        };
+
+        let address = address.trim_end_matches('/');
+        if address.is_empty() {
+            return Err(DockaError::invalid_input(format!(
+                "Docker host '{host}' has an empty address"
+            )));
+        }
+
+        match tls_cert_path {
+            Some(cert_path) => Ok(Self::Tls {
+                address: address.to_string(),
+                cert_path: cert_path.to_path_buf(),
+            }),
+            None if force_tls => Err(DockaError::invalid_input(format!(
+                "Docker host '{host}' requires TLS but no certificate path is configured"
+            ))),
+            None => Ok(Self::Tcp {
+                address: address.to_string(),
+            }),
+        }
+    }
+
+    /// Short scheme label for display purposes
+    /// 表示用の短いスキームラベル
+    #[must_use]
+    pub const fn scheme(&self) -> &'static str {
+        match self {
+            Self::Unix { .. } => "unix",
+            Self::Tcp { .. } => "tcp",
+            Self::Tls { .. } => "tls",
+        }
+    }
+}
+
+/// A named Docker endpoint that docka can connect to
+/// `docka`が接続可能な名前付き`Docker`エンドポイント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerEndpoint {
+    /// Context name (e.g. `default`, `build-01`)
+    /// コンテキスト名（例: `default`、`build-01`）
+    pub name: String,
+
+    /// Optional human readable description from context metadata
+    /// コンテキストメタデータからの任意の説明
+    pub description: Option<String>,
+
+    /// Original host URL
+    /// 元のホストURL
+    pub host: String,
+
+    /// Parsed transport
+    /// 解析済みトランスポート
+    pub transport: DockerTransport,
+
+    /// Engine behind an auto-detected socket (`None` when configured explicitly)
+    /// 自動検出されたソケットの背後のエンジン（明示的に設定された場合は`None`）
+    pub detected_engine: Option<EngineKind>,
+
+    /// Whether the context asks to skip verifying the daemon certificate
+    /// コンテキストがデーモン証明書の検証のスキップを求めているか
+    pub skip_tls_verify: bool,
+}
+
+impl DockerEndpoint {
+    /// Create an endpoint from a host URL
+    /// ホストURLからエンドポイントを作成
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the host URL cannot be parsed
+    pub fn new(
+        name: impl Into<String>,
+        host: impl Into<String>,
+        tls_cert_path: Option<&Path>,
+    ) -> DockaResult<Self> {
+        let host = host.into();
+        let transport = DockerTransport::parse(&host, tls_cert_path)?;
+        Ok(Self {
+            name: name.into(),
+            description: None,
+            host,
+            transport,
+            detected_engine: None,
+            skip_tls_verify: false,
+        })
+    }
+
+    /// Check that docka can connect to this endpoint as configured
+    /// 設定どおりにdockaがこのエンドポイントへ接続できるかチェック
+    ///
+    /// docka always verifies the daemon certificate, so a TLS context with
+    /// `SkipTLSVerify` is refused instead of silently connecting with
+    /// verification on.
+    /// dockaは常にデーモン証明書を検証するため、`SkipTLSVerify`付きの`TLS`
+    /// コンテキストは検証を有効にして黙って接続する代わりに拒否されます。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the context sets `SkipTLSVerify` on a TLS host
+    pub fn ensure_supported(&self) -> DockaResult<()> {
+        if self.skip_tls_verify && matches!(self.transport, DockerTransport::Tls { .. }) {
+            return Err(DockaError::invalid_input(format!(
+                "Docker context '{}' sets SkipTLSVerify, which docka does not support; \
+                 install the daemon's CA certificate in the context instead",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether container addresses are reachable from this machine
+    /// コンテナのアドレスにこのマシンから到達可能か
+    ///
+    /// Only a rootful engine behind a local unix socket on Linux shares the
+    /// host's network. Remote hosts, Docker Desktop, Colima, Podman machines
+    /// and rootless engines keep container addresses out of reach.
+    /// `Linux`のローカル`unix`ソケットの背後にあるルート権限のエンジンのみが
+    /// ホストのネットワークを共有します。リモートホスト、`Docker Desktop`、`Colima`、
+    /// `Podman`マシン、ルートレスエンジンではコンテナのアドレスに到達できません。
+    #[must_use]
+    pub fn reaches_container_addresses(&self) -> bool {
+        let DockerTransport::Unix { path } = &self.transport else {
+            return false;
+        };
+        let path = path.to_string_lossy();
+        cfg!(target_os = "linux")
+            && self
+                .detected_engine
+                .is_none_or(|engine| engine == EngineKind::Podman)
+            && !UNROUTABLE_SOCKET_MARKERS
+                .iter()
+                .any(|marker| path.contains(marker))
+    }
+}
+
+/// Docker related environment settings
+/// `Docker`関連の環境設定
+///
+/// Captured once so that resolution can be tested without touching the process environment.
+/// プロセス環境に触れずに解決ロジックをテストできるよう一度だけ取得します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DockerEnvironment {
+    /// `DOCKER_HOST`
+    pub docker_host: Option<String>,
+
+    /// `DOCKER_CONTEXT`
+    pub docker_context: Option<String>,
+
+    /// `DOCKER_TLS_VERIFY` is set to a non-empty value
+    /// `DOCKER_TLS_VERIFY`が空でない値に設定されている
+    pub tls_verify: bool,
+
+    /// `DOCKER_CERT_PATH`
+    pub cert_path: Option<PathBuf>,
+
+    /// Docker CLI configuration directory (`DOCKER_CONFIG` or `~/.docker`)
+    /// `Docker` `CLI`設定ディレクトリ（`DOCKER_CONFIG`または`~/.docker`）
+    pub config_dir: PathBuf,
+
+    /// Alternative sockets probed when the default socket is missing
+    /// デフォルトソケットが存在しない場合に検査する代替ソケット
+    pub socket_probe: SocketProbe,
+}
+
+impl DockerEnvironment {
+    /// Read settings from the current process environment
+    /// 現在のプロセス環境から設定を読み込み
+    #[must_use]
+    pub fn from_env() -> Self {
+        let non_empty = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+
+        let config_dir = non_empty("DOCKER_CONFIG").map_or_else(
+            || {
+                non_empty("HOME").map_or_else(
+                    || PathBuf::from(".docker"),
+                    |home| PathBuf::from(home).join(".docker"),
+                )
+            },
+            PathBuf::from,
+        );
+
+        let home_dir = non_empty("HOME").map(PathBuf::from);
+        let runtime_dir = non_empty("XDG_RUNTIME_DIR").map(PathBuf::from);
+
+        Self {
+            docker_host: non_empty("DOCKER_HOST"),
+            docker_context: non_empty("DOCKER_CONTEXT"),
+            tls_verify: non_empty("DOCKER_TLS_VERIFY").is_some(),
+            cert_path: non_empty("DOCKER_CERT_PATH").map(PathBuf::from),
+            config_dir,
+            socket_probe: SocketProbe::well_known(home_dir.as_deref(), runtime_dir.as_deref()),
+        }
+    }
+
+    /// Certificate directory used for the default endpoint when TLS is enabled
+    /// `TLS`有効時にデフォルトエンドポイントで使用する証明書ディレクトリ
+    #[must_use]
+    pub fn effective_cert_path(&self) -> PathBuf {
+        self.cert_path
+            .clone()
+            .unwrap_or_else(|| self.config_dir.clone())
+    }
+}
+
+/// `meta.json` layout written by `docker context create`
+/// `docker context create`が書き込む`meta.json`のレイアウト
+#[derive(Debug, Deserialize)]
+struct ContextMeta {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Metadata", default)]
+    metadata: Option<ContextMetadata>,
+    #[serde(rename = "Endpoints", default)]
+    endpoints: HashMap<String, ContextEndpointMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextMetadata {
+    #[serde(rename = "Description", default)]
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextEndpointMeta {
+    #[serde(rename = "Host", default)]
+    host: Option<String>,
+    #[serde(rename = "SkipTLSVerify", default)]
+    skip_tls_verify: bool,
+}
+
+/// Reader for the Docker CLI context store (`~/.docker/contexts`)
+/// `Docker` `CLI`コンテキストストア（`~/.docker/contexts`）のリーダー
+#[derive(Debug, Clone)]
+pub struct DockerContextStore {
+    /// Docker CLI configuration directory
+    /// `Docker` `CLI`設定ディレクトリ
+    config_dir: PathBuf,
+}
+
+impl DockerContextStore {
+    /// Create a store rooted at the given Docker configuration directory
+    /// 指定された`Docker`設定ディレクトリをルートとするストアを作成
+    #[must_use]
+    pub fn new(config_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            config_dir: config_dir.into(),
+        }
+    }
+
+    /// Name of the context selected with `docker context use`, if any
+    /// `docker context use`で選択されたコンテキスト名（存在する場合）
+    #[must_use]
+    pub fn current_context(&self) -> Option<String> {
+        let content = std::fs::read_to_string(self.config_dir.join("config.json")).ok()?;
+        let config: serde_json::Value = serde_json::from_str(&content).ok()?;
+        config
+            .get("currentContext")
+            .and_then(serde_json::Value::as_str)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Load all contexts that define a usable Docker endpoint
+    /// 使用可能な`Docker`エンドポイントを定義する全コンテキストを読み込み
+    ///
+    /// Contexts with unsupported transports (such as `ssh://`) or unreadable
+    /// metadata are skipped with a warning rather than failing the whole load.
+    ///
+    /// 未対応のトランスポート（`ssh://`等）や読めないメタデータを持つコンテキストは
+    /// 全体を失敗させずに警告付きでスキップされます。
+    ///
+    /// # Errors
+    /// * `DockaError::Io` - When the metadata directory exists but cannot be listed
+    pub fn list_endpoints(&self) -> DockaResult<Vec<DockerEndpoint>> {
+        let meta_dir = self.config_dir.join("contexts").join("meta");
+        if !meta_dir.is_dir() {
+            debug!("No Docker context store at {}", meta_dir.display());
+            return Ok(Vec::new());
+        }
+
+        let mut endpoints = Vec::new();
+        for entry in std::fs::read_dir(&meta_dir)? {
+            let entry = entry?;
+            let meta_file = entry.path().join("meta.json");
+            let Ok(content) = std::fs::read_to_string(&meta_file) else {
+                continue;
+            };
+
+            let meta: ContextMeta = match serde_json::from_str(&content) {
+                Ok(meta) => meta,
+                Err(e) => {
+                    warn!("Skipping unreadable context {}: {}", meta_file.display(), e);
+                    continue;
+                }
+            };
+
+            let Some(docker) = meta.endpoints.get("docker") else {
+                continue;
+            };
+            let Some(host) = docker.host.as_deref() else {
+                continue;
+            };
+
+            // TLS material lives next to the metadata under contexts/tls/<id>/docker
+            // TLS証明書はcontexts/tls/<id>/docker配下に置かれる
+            let tls_dir = self
+                .config_dir
+                .join("contexts")
+                .join("tls")
+                .join(entry.file_name())
+                .join("docker");
+            let tls_cert_path = tls_dir.is_dir().then_some(tls_dir.as_path());
+
+            match DockerEndpoint::new(meta.name.clone(), host, tls_cert_path) {
+                Ok(mut endpoint) => {
+                    // Kept in the list so that selecting it explains the refusal
+                    // 選択時に拒否の理由を説明できるよう一覧には残す
+                    endpoint.skip_tls_verify = docker.skip_tls_verify;
+                    endpoint.description = meta
+                        .metadata
+                        .and_then(|metadata| metadata.description)
+                        .filter(|description| !description.is_empty());
+                    endpoints.push(endpoint);
+                }
+                Err(e) => warn!("Skipping context '{}': {}", meta.name, e),
+            }
+        }
+
+        endpoints.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(endpoints)
+    }
+}
+
+/// All known endpoints together with the one that should be used at startup
+/// 起動時に使用するエンドポイントを含む既知の全エンドポイント
+///
+/// Resolution follows the Docker CLI precedence:
+/// `DOCKER_HOST` > `DOCKER_CONTEXT` > `currentContext` in `config.json` > `default`.
+///
+/// 解決順序は`Docker` `CLI`と同じです:
+/// `DOCKER_HOST` > `DOCKER_CONTEXT` > `config.json`の`currentContext` > `default`。
+#[derive(Debug, Clone)]
+pub struct EndpointCatalog {
+    /// Known endpoints, `default` first
+    /// 既知のエンドポイント（`default`が先頭）
+    endpoints: Vec<DockerEndpoint>,
+
+    /// Index of the active endpoint
+    /// アクティブエンドポイントのインデックス
+    active: usize,
+}
+
+impl EndpointCatalog {
+    /// Build the catalog from environment settings and the context store
+    /// 環境設定とコンテキストストアからカタログを構築
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When `DOCKER_HOST` is malformed or `DOCKER_CONTEXT` names an unknown context
+    /// * `DockaError::Io` - When the context store cannot be read
+    pub fn load(env: &DockerEnvironment) -> DockaResult<Self> {
+        let store = DockerContextStore::new(&env.config_dir);
+
+        // The implicit default endpoint honors DOCKER_HOST and the TLS variables,
+        // falling back to a detected rootless/Podman/VM socket when the default is missing
+        // 暗黙のデフォルトエンドポイントはDOCKER_HOSTとTLS変数を反映し、
+        // デフォルトが存在しない場合は検出したルートレス/Podman/VMソケットを使用する
+        let detected = env
+            .docker_host
+            .is_none()
+            .then(|| env.socket_probe.detect())
+            .flatten();
+        let default_host = match (&env.docker_host, detected) {
+            (Some(host), _) => host.clone(),
+            (None, Some(candidate)) => candidate.host(),
+            (None, None) => DEFAULT_DOCKER_HOST.to_string(),
+        };
+        let cert_path = env.tls_verify.then(|| env.effective_cert_path());
+        let mut default_endpoint =
+            DockerEndpoint::new(DEFAULT_CONTEXT_NAME, default_host, cert_path.as_deref())?;
+        if let Some(candidate) = detected {
+            debug!(
+                "Default Docker socket missing, using {} at {}",
+                candidate.kind,
+                candidate.path.display()
+            );
+            default_endpoint.detected_engine = Some(candidate.kind);
+            default_endpoint.description = Some(format!("Auto-detected {} socket", candidate.kind));
+        }
+
+        let mut endpoints = vec![default_endpoint];
+        endpoints.extend(
+            store
+                .list_endpoints()?
+                .into_iter()
+                .filter(|endpoint| endpoint.name != DEFAULT_CONTEXT_NAME),
+        );
+
+        let active_name = if env.docker_host.is_some() {
+            None
+        } else if let Some(name) = env.docker_context.clone() {
+            Some(name)
+        } else {
+            store.current_context()
+        };
+
+        let active = match active_name {
+            None => 0,
+            Some(name) => endpoints
+                .iter()
+                .position(|endpoint| endpoint.name == name)
+                .ok_or_else(|| {
+                    DockaError::invalid_input(format!("Docker context '{name}' does not exist"))
+                })?,
+        };
+
+        Ok(Self { endpoints, active })
+    }
+
+    /// All known endpoints
+    /// 既知の全エンドポイント
+    #[must_use]
+    pub fn endpoints(&self) -> &[DockerEndpoint] {
+        &self.endpoints
+    }
+
+    /// Endpoint selected at startup
+    /// 起動時に選択されたエンドポイント
+    #[must_use]
+    pub fn active(&self) -> &DockerEndpoint {
+        &self.endpoints[self.active]
+    }
+
+    /// Find an endpoint by context name
+    /// コンテキスト名でエンドポイントを検索
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&DockerEndpoint> {
+        self.endpoints.iter().find(|endpoint| endpoint.name == name)
+    }
+
+    /// Names of all endpoints in display order
+    /// 表示順の全エンドポイント名
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::docker::socket_probe::SocketCandidate;
+    use tempfile::TempDir;
+
+    fn write_context(config_dir: &Path, id: &str, name: &str, host: &str, with_tls: bool) {
+        write_context_with(config_dir, id, name, host, with_tls, false);
+    }
+
+    fn write_context_with(
+        config_dir: &Path,
+        id: &str,
+        name: &str,
+        host: &str,
+        with_tls: bool,
+        skip_tls_verify: bool,
+    ) {
+        let meta_dir = config_dir.join("contexts").join("meta").join(id);
+        std::fs::create_dir_all(&meta_dir).unwrap();
+        let meta = serde_json::json!({
+            "Name": name,
+            "Metadata": { "Description": format!("{name} host") },
+            "Endpoints": { "docker": { "Host": host, "SkipTLSVerify": skip_tls_verify } }
+        });
+        std::fs::write(meta_dir.join("meta.json"), meta.to_string()).unwrap();
+
+        if with_tls {
+            let tls_dir = config_dir
+                .join("contexts")
+                .join("tls")
+                .join(id)
+                .join("docker");
+            std::fs::create_dir_all(tls_dir).unwrap();
+        }
+    }
+
+    fn env_for(config_dir: &Path) -> DockerEnvironment {
+        DockerEnvironment {
+            config_dir: config_dir.to_path_buf(),
+            ..DockerEnvironment::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_transports() {
+        // Test parsing of supported host URL schemes
+        // サポートされるホストURLスキームの解析をテスト
+        assert_eq!(
+            DockerTransport::parse("unix:///var/run/docker.sock", None).unwrap(),
+            DockerTransport::Unix {
+                path: PathBuf::from("/var/run/docker.sock")
+            }
+        );
+        assert_eq!(
+            DockerTransport::parse("tcp://10.0.0.5:2375", None).unwrap(),
+            DockerTransport::Tcp {
+                address: "10.0.0.5:2375".to_string()
+            }
+        );
+        assert_eq!(
+            DockerTransport::parse("tcp://10.0.0.5:2376", Some(Path::new("/certs"))).unwrap(),
+            DockerTransport::Tls {
+                address: "10.0.0.5:2376".to_string(),
+                cert_path: PathBuf::from("/certs")
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported_hosts() {
+        // Test that unsupported or malformed hosts are rejected
+        // 未対応または不正なホストが拒否されることをテスト
+        for host in [
+            "ssh://user@host",
+            "npipe:////./pipe/docker_engine",
+            "unix://",
+            "tcp://",
+            "localhost:2375",
+        ] {
+            assert!(
+                matches!(
+                    DockerTransport::parse(host, None),
+                    Err(DockaError::InvalidInput { .. })
+                ),
+                "{host} should be rejected"
+            );
+        }
+
+        // https without certificates cannot be honored
+        // 証明書なしのhttpsは扱えない
+        assert!(DockerTransport::parse("https://host:2376", None).is_err());
+    }
+
+    #[test]
+    fn test_reaches_container_addresses_only_on_local_rootful_sockets() {
+        // Test that only local rootful sockets reach container addresses
+        // ローカルのルート権限のソケットのみがコンテナのアドレスに到達することをテスト
+        let endpoint = |host: &str| DockerEndpoint::new("test", host, None).unwrap();
+        assert_eq!(
+            endpoint(DEFAULT_DOCKER_HOST).reaches_container_addresses(),
+            cfg!(target_os = "linux")
+        );
+        for host in [
+            "tcp://10.0.0.5:2375",
+            "unix:///run/user/1000/docker.sock",
+            "unix:///home/dev/.docker/desktop/docker.sock",
+            "unix:///Users/dev/.colima/default/docker.sock",
+            "unix:///home/dev/.local/share/containers/podman/machine/qemu/podman.sock",
+        ] {
+            assert!(!endpoint(host).reaches_container_addresses(), "{host}");
+        }
+
+        let mut detected = endpoint("unix:///var/run/docker.sock");
+        detected.detected_engine = Some(EngineKind::Colima);
+        assert!(!detected.reaches_container_addresses());
+    }
+
+    #[test]
+    fn test_catalog_defaults_without_contexts() {
+        // Test that an empty configuration yields the local default endpoint
+        // 空の設定でローカルのデフォルトエンドポイントになることをテスト
+        let dir = TempDir::new().unwrap();
+        let catalog = EndpointCatalog::load(&env_for(dir.path())).unwrap();
+
+        assert_eq!(catalog.endpoints().len(), 1);
+        assert_eq!(catalog.active().name, DEFAULT_CONTEXT_NAME);
+        assert_eq!(catalog.active().host, DEFAULT_DOCKER_HOST);
+    }
+
+    #[test]
+    fn test_catalog_reads_context_store() {
+        // Test that contexts are loaded and currentContext selects the active one
+        // コンテキストが読み込まれcurrentContextでアクティブが選択されることをテスト
+        let dir = TempDir::new().unwrap();
+        write_context(dir.path(), "aaa", "build-01", "tcp://build-01:2376", true);
+        write_context(dir.path(), "bbb", "build-02", "tcp://build-02:2375", false);
+        write_context(dir.path(), "ccc", "remote-ssh", "ssh://me@remote", false);
+        std::fs::write(
+            dir.path().join("config.json"),
+            r#"{"currentContext":"build-02"}"#,
+        )
+        .unwrap();
+
+        let catalog = EndpointCatalog::load(&env_for(dir.path())).unwrap();
+
+        assert_eq!(catalog.names(), vec!["default", "build-01", "build-02"]);
+        assert_eq!(catalog.active().name, "build-02");
+
+        let build_01 = catalog.find("build-01").unwrap();
+        assert_eq!(build_01.transport.scheme(), "tls");
+        assert_eq!(build_01.description.as_deref(), Some("build-01 host"));
+        assert_eq!(catalog.find("build-02").unwrap().transport.scheme(), "tcp");
+    }
+
+    #[test]
+    fn test_skip_tls_verify_context_is_refused() {
+        // Test that a TLS context with SkipTLSVerify is listed but refused with a clear error
+        // SkipTLSVerify付きのTLSコンテキストが一覧に残り、明確なエラーで拒否されることをテスト
+        let dir = TempDir::new().unwrap();
+        write_context_with(dir.path(), "aaa", "lab", "tcp://lab:2376", true, true);
+        write_context_with(dir.path(), "bbb", "plain", "tcp://plain:2375", false, true);
+
+        let catalog = EndpointCatalog::load(&env_for(dir.path())).unwrap();
+        let error = catalog.find("lab").unwrap().ensure_supported().unwrap_err();
+        assert!(error.to_string().contains("SkipTLSVerify"));
+
+        // Without TLS there is no certificate to skip
+        // TLSがない場合はスキップする証明書がない
+        assert!(catalog.find("plain").unwrap().ensure_supported().is_ok());
+    }
+
+    #[test]
+    fn test_catalog_environment_precedence() {
+        // Test DOCKER_HOST > DOCKER_CONTEXT > currentContext precedence
+        // DOCKER_HOST > DOCKER_CONTEXT > currentContextの優先順位をテスト
+        let dir = TempDir::new().unwrap();
+        write_context(dir.path(), "aaa", "build-01", "tcp://build-01:2375", false);
+        std::fs::write(
+            dir.path().join("config.json"),
+            r#"{"currentContext":"missing"}"#,
+        )
+        .unwrap();
+
+        let mut env = env_for(dir.path());
+        env.docker_context = Some("build-01".to_string());
+        assert_eq!(
+            EndpointCatalog::load(&env).unwrap().active().name,
+            "build-01"
+        );
+
+        env.docker_host = Some("tcp://127.0.0.1:2376".to_string());
+        env.tls_verify = true;
+        env.cert_path = Some(PathBuf::from("/etc/docker/certs"));
+        let catalog = EndpointCatalog::load(&env).unwrap();
+        assert_eq!(catalog.active().name, DEFAULT_CONTEXT_NAME);
+        assert_eq!(
+            catalog.active().transport,
+            DockerTransport::Tls {
+                address: "127.0.0.1:2376".to_string(),
+                cert_path: PathBuf::from("/etc/docker/certs")
+            }
+        );
+    }
+
+    #[test]
+    fn test_catalog_unknown_context_is_error() {
+        // Test that DOCKER_CONTEXT naming an unknown context is reported
+        // 存在しないコンテキストを指すDOCKER_CONTEXTが報告されることをテスト
+        let dir = TempDir::new().unwrap();
+        let mut env = env_for(dir.path());
+        env.docker_context = Some("nope".to_string());
+
+        assert!(matches!(
+            EndpointCatalog::load(&env),
+            Err(DockaError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn test_catalog_uses_detected_socket_when_default_missing() {
+        // Test that a detected socket replaces the missing default and DOCKER_HOST still wins
+        // 検出したソケットが存在しないデフォルトを置き換え、DOCKER_HOSTが優先されることをテスト
+        let dir = TempDir::new().unwrap();
+        let podman_socket = dir.path().join("run").join("podman").join("podman.sock");
+        std::fs::create_dir_all(podman_socket.parent().unwrap()).unwrap();
+        std::fs::write(&podman_socket, b"").unwrap();
+
+        let mut env = env_for(dir.path());
+        env.socket_probe = SocketProbe {
+            default_socket: dir.path().join("missing.sock"),
+            candidates: vec![SocketCandidate {
+                kind: EngineKind::RootlessPodman,
+                path: podman_socket.clone(),
+            }],
+        };
+
+        let catalog = EndpointCatalog::load(&env).unwrap();
+        let active = catalog.active();
+        assert_eq!(active.detected_engine, Some(EngineKind::RootlessPodman));
+        assert_eq!(
+            active.transport,
+            DockerTransport::Unix {
+                path: podman_socket
+            }
+        );
+        assert!(
+            active
+                .description
+                .as_deref()
+                .unwrap()
+                .contains("rootless Podman")
+        );
+
+        env.docker_host = Some("tcp://10.0.0.5:2375".to_string());
+        let catalog = EndpointCatalog::load(&env).unwrap();
+        assert_eq!(catalog.active().detected_engine, None);
+        assert_eq!(catalog.active().host, "tcp://10.0.0.5:2375");
+    }
+}
@@ -17,6 +17,8 @@
 //! ```text
 //! Infrastructure/Docker Layer
 //! ├── bollard_client.rs    # Main Docker API client implementation
+//! ├── docker_context.rs    # Docker context / endpoint resolution
+//! ├── socket_probe.rs      # Rootless / Podman / VM socket autodetection
 //! ├── api_mapper.rs        # API response to domain entity mapping
 //! └── error_handler.rs     # Docker-specific error handling
 //! ```
@@ -60,6 +62,36 @@ pub mod bollard_client;
 /// 完全な非同期サポートと包括的なエラーハンドリングを提供します。
 pub use bollard_client::BollardDockerRepository;
 
+/// Docker context and endpoint resolution
+/// `Docker`コンテキストとエンドポイントの解決
+///
+/// Reads `~/.docker/contexts` and the `DOCKER_*` environment variables to build
+/// the list of endpoints docka can connect to.
+///
+/// `~/.docker/contexts`と`DOCKER_*`環境変数を読み込み、
+/// `docka`が接続可能なエンドポイント一覧を構築します。
+pub mod docker_context;
+
+/// Endpoint resolution types re-export
+/// エンドポイント解決型の再エクスポート
+pub use docker_context::{
+    DockerContextStore, DockerEndpoint, DockerEnvironment, DockerTransport, EndpointCatalog,
+};
+
+/// Autodetection of alternative local sockets
+/// 代替ローカルソケットの自動検出
+///
+/// Probes rootless Docker, Podman, Colima, Rancher Desktop and `OrbStack` sockets
+/// when `/var/run/docker.sock` is missing.
+///
+/// `/var/run/docker.sock`が存在しない場合に、ルートレス`Docker`、`Podman`、
+/// `Colima`、`Rancher Desktop`、`OrbStack`のソケットを検査します。
+pub mod socket_probe;
+
+/// Socket autodetection types re-export
+/// ソケット自動検出型の再エクスポート
+pub use socket_probe::{EngineKind, SocketCandidate, SocketProbe};
+
 // Phase 1.2 で実装予定
 // To be implemented in Phase 1.2
 
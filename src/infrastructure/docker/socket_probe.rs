@@ -0,0 +1,264 @@
+// src/infrastructure/docker/socket_probe.rs
+// Autodetection of alternative local Docker-compatible sockets
+// 代替ローカルDocker互換ソケットの自動検出
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Socket path used by a rootful Docker engine
+/// ルート権限の`Docker`エンジンが使用するソケットパス
+pub const DEFAULT_DOCKER_SOCKET: &str = "/var/run/docker.sock";
+
+/// Socket path used by a rootful Podman API service
+/// ルート権限の`Podman` `API`サービスが使用するソケットパス
+pub const PODMAN_ROOTFUL_SOCKET: &str = "/run/podman/podman.sock";
+
+/// Engine (or distribution) that provides a local Docker-compatible socket
+/// ローカル`Docker`互換ソケットを提供するエンジン（またはディストリビューション）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EngineKind {
+    /// Docker running in rootless mode
+    /// ルートレスモードで動作する`Docker`
+    RootlessDocker,
+
+    /// Podman API service running as the current user
+    /// 現在のユーザーで動作する`Podman` `API`サービス
+    RootlessPodman,
+
+    /// Podman API service running as root
+    /// ルートで動作する`Podman` `API`サービス
+    Podman,
+
+    /// Colima virtual machine
+    /// `Colima`仮想マシン
+    Colima,
+
+    /// Rancher Desktop (moby backend)
+    /// `Rancher Desktop`（`moby`バックエンド）
+    RancherDesktop,
+
+    /// `OrbStack` desktop runtime
+    /// `OrbStack`デスクトップランタイム
+    OrbStack,
+}
+
+impl EngineKind {
+    /// Human readable name for status messages
+    /// ステータスメッセージ用の人間が読める名前
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::RootlessDocker => "rootless Docker",
+            Self::RootlessPodman => "rootless Podman",
+            Self::Podman => "Podman",
+            Self::Colima => "Colima",
+            Self::RancherDesktop => "Rancher Desktop",
+            Self::OrbStack => "OrbStack",
+        }
+    }
+
+    /// Whether the engine runs without root privileges on the host
+    /// エンジンがホスト上でルート権限なしで動作するか
+    #[must_use]
+    pub const fn is_rootless(self) -> bool {
+        matches!(self, Self::RootlessDocker | Self::RootlessPodman)
+    }
+}
+
+impl fmt::Display for EngineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// A well-known socket location to probe
+/// 検査対象の既知のソケット位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocketCandidate {
+    /// Engine expected behind the socket
+    /// ソケットの背後にあると想定されるエンジン
+    pub kind: EngineKind,
+
+    /// Socket path
+    /// ソケットパス
+    pub path: PathBuf,
+}
+
+impl SocketCandidate {
+    /// Docker host URL for this socket
+    /// このソケットの`Docker`ホストURL
+    #[must_use]
+    pub fn host(&self) -> String {
+        format!("unix://{}", self.path.display())
+    }
+}
+
+/// Probe for local sockets used when the default Docker socket is missing
+/// デフォルトの`Docker`ソケットが存在しない場合に使用するローカルソケットの検査
+///
+/// Candidates are checked in order and the first existing path wins:
+/// rootless Docker, rootless Podman, rootful Podman, Colima, Rancher Desktop, `OrbStack`.
+///
+/// 候補は順番に検査され、最初に存在したパスが採用されます:
+/// ルートレス`Docker`、ルートレス`Podman`、ルート`Podman`、`Colima`、`Rancher Desktop`、`OrbStack`。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocketProbe {
+    /// Socket that makes probing unnecessary when it exists
+    /// 存在する場合は検査を不要にするソケット
+    pub default_socket: PathBuf,
+
+    /// Alternatives in priority order
+    /// 優先順の代替候補
+    pub candidates: Vec<SocketCandidate>,
+}
+
+impl Default for SocketProbe {
+    /// A probe without alternatives (the default socket is always used)
+    /// 代替候補のない検査（常にデフォルトソケットを使用）
+    fn default() -> Self {
+        Self {
+            default_socket: PathBuf::from(DEFAULT_DOCKER_SOCKET),
+            candidates: Vec::new(),
+        }
+    }
+}
+
+impl SocketProbe {
+    /// Build the well-known candidate list from the user's directories
+    /// ユーザーのディレクトリから既知の候補一覧を構築
+    ///
+    /// # Arguments
+    /// * `home_dir` - `$HOME`
+    /// * `runtime_dir` - `$XDG_RUNTIME_DIR`
+    #[must_use]
+    pub fn well_known(home_dir: Option<&Path>, runtime_dir: Option<&Path>) -> Self {
+        let mut candidates = Vec::new();
+        let mut push = |kind, path: PathBuf| candidates.push(SocketCandidate { kind, path });
+
+        if let Some(runtime_dir) = runtime_dir {
+            push(EngineKind::RootlessDocker, runtime_dir.join("docker.sock"));
+            push(
+                EngineKind::RootlessPodman,
+                runtime_dir.join("podman").join("podman.sock"),
+            );
+        }
+
+        push(EngineKind::Podman, PathBuf::from(PODMAN_ROOTFUL_SOCKET));
+
+        if let Some(home_dir) = home_dir {
+            push(
+                EngineKind::Colima,
+                home_dir.join(".colima").join("default").join("docker.sock"),
+            );
+            push(
+                EngineKind::Colima,
+                home_dir.join(".colima").join("docker.sock"),
+            );
+            push(
+                EngineKind::RancherDesktop,
+                home_dir.join(".rd").join("docker.sock"),
+            );
+            push(
+                EngineKind::OrbStack,
+                home_dir.join(".orbstack").join("run").join("docker.sock"),
+            );
+        }
+
+        Self {
+            default_socket: PathBuf::from(DEFAULT_DOCKER_SOCKET),
+            candidates,
+        }
+    }
+
+    /// Pick an alternative socket if the default one is missing
+    /// デフォルトソケットが存在しない場合に代替ソケットを選択
+    ///
+    /// Returns `None` when the default socket exists or no candidate exists.
+    /// デフォルトソケットが存在する場合、または候補が存在しない場合は`None`を返します。
+    #[must_use]
+    pub fn detect(&self) -> Option<&SocketCandidate> {
+        if self.default_socket.exists() {
+            return None;
+        }
+
+        self.candidates
+            .iter()
+            .find(|candidate| candidate.path.exists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn touch(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, b"").unwrap();
+    }
+
+    fn probe_in(temp: &TempDir) -> SocketProbe {
+        let mut probe = SocketProbe::well_known(
+            Some(&temp.path().join("home")),
+            Some(&temp.path().join("run")),
+        );
+        probe.default_socket = temp.path().join("docker.sock");
+        // Keep the rootful Podman path inside the sandbox
+        // ルートPodmanのパスをサンドボックス内に保つ
+        for candidate in &mut probe.candidates {
+            if candidate.kind == EngineKind::Podman {
+                candidate.path = temp.path().join("podman").join("podman.sock");
+            }
+        }
+        probe
+    }
+
+    #[test]
+    fn test_detect_prefers_default_socket() {
+        // Test that nothing is detected while the default socket exists
+        // デフォルトソケットが存在する間は何も検出されないことをテスト
+        let temp = TempDir::new().unwrap();
+        let probe = probe_in(&temp);
+        touch(&probe.default_socket);
+        touch(&temp.path().join("run").join("docker.sock"));
+
+        assert!(probe.detect().is_none());
+    }
+
+    #[test]
+    fn test_detect_rootless_podman() {
+        // Test that the rootless Podman socket is picked when it is the only one
+        // ルートレスPodmanソケットのみ存在する場合に選択されることをテスト
+        let temp = TempDir::new().unwrap();
+        let probe = probe_in(&temp);
+        touch(&temp.path().join("run").join("podman").join("podman.sock"));
+
+        let detected = probe.detect().unwrap();
+        assert_eq!(detected.kind, EngineKind::RootlessPodman);
+        assert!(detected.host().starts_with("unix://"));
+        assert!(detected.kind.is_rootless());
+    }
+
+    #[test]
+    fn test_detect_follows_priority_order() {
+        // Test that rootless Docker wins over desktop distributions
+        // ルートレスDockerがデスクトップディストリビューションより優先されることをテスト
+        let temp = TempDir::new().unwrap();
+        let probe = probe_in(&temp);
+        touch(&temp.path().join("home").join(".orbstack/run/docker.sock"));
+        touch(&temp.path().join("home").join(".colima/default/docker.sock"));
+        assert_eq!(probe.detect().unwrap().kind, EngineKind::Colima);
+
+        touch(&temp.path().join("run").join("docker.sock"));
+        assert_eq!(probe.detect().unwrap().kind, EngineKind::RootlessDocker);
+    }
+
+    #[test]
+    fn test_default_probe_has_no_candidates() {
+        // Test that the default probe never picks an alternative
+        // デフォルトの検査が代替を選択しないことをテスト
+        let probe = SocketProbe::default();
+        assert!(probe.candidates.is_empty());
+        assert_eq!(probe.default_socket, PathBuf::from(DEFAULT_DOCKER_SOCKET));
+    }
+}
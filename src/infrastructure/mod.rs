@@ -110,6 +110,10 @@ pub mod docker;
 /// ```
 pub use docker::BollardDockerRepository;
 
+/// Docker endpoint resolution types
+/// `Docker`エンドポイント解決型
+pub use docker::{DockerEndpoint, DockerEnvironment, EndpointCatalog};
+
 // Phase 1.3 で有効化予定
 // To be enabled in Phase 1.3
 
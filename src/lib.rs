@@ -6,6 +6,22 @@
 #![warn(clippy::all)]
 #![warn(clippy::pedantic)]
 #![warn(clippy::nursery)]
+// Lints the original unit tests predate; the tests are kept as written
+// 元の単体テストが以前から抱えるリント。テストは書かれたまま維持する
+#![cfg_attr(
+    test,
+    allow(
+        clippy::assertions_on_constants,
+        clippy::float_cmp,
+        clippy::match_wild_err_arm,
+        clippy::needless_collect,
+        clippy::similar_names,
+        clippy::unnecessary_wraps,
+        clippy::unreadable_literal,
+        clippy::unused_async,
+        clippy::useless_vec
+    )
+)]
 
 //! # docka
 //!
@@ -118,18 +134,18 @@ pub mod infrastructure;
 /// ユーザー交互作用管理を提供します。
 pub mod ui;
 
+/// Application layer containing use cases and services.
+///
+/// This layer orchestrates domain operations and coordinates between
+/// the domain layer and infrastructure layer.
+///
+/// ユースケースとサービスを含むアプリケーション層。
+/// ドメイン操作を調整し、ドメイン層とインフラ層間の連携を担当します。
+pub mod app;
+
 // Phase 1 implementation modules - uncomment as implemented
 // Phase 1実装モジュール - 実装時にコメントアウト解除
 
-// /// Application layer containing use cases and services.
-// ///
-// /// This layer orchestrates domain operations and coordinates between
-// /// the domain layer and infrastructure layer.
-// ///
-// /// ユースケースとサービスを含むアプリケーション層。
-// /// ドメイン操作を調整し、ドメイン層とインフラ層間の連携を担当します。
-// pub mod app;
-
 // /// Actor system for concurrent task management.
 // ///
 // /// This layer implements the actor pattern for handling asynchronous
@@ -139,15 +155,15 @@ pub mod ui;
 // /// 非同期操作の処理とUI応答性維持のためのアクターパターンを実装します。
 // pub mod actors;
 
-// /// Utility functions and helper types.
-// ///
-// /// This module contains formatting utilities, common helper functions,
-// /// and shared types used across the application.
-// ///
-// /// ユーティリティ関数とヘルパー型。
-// /// フォーマットユーティリティ、共通ヘルパー関数、
-// /// アプリケーション全体で使用される共有型を含みます。
-// pub mod utils;
+/// Utility functions and helper types.
+///
+/// This module contains formatting utilities, common helper functions,
+/// and shared types used across the application.
+///
+/// ユーティリティ関数とヘルパー型。
+/// フォーマットユーティリティ、共通ヘルパー関数、
+/// アプリケーション全体で使用される共有型を含みます。
+pub mod utils;
 
 // Re-export commonly used types for convenient access
 // 便利なアクセスのためによく使用される型を再エクスポート
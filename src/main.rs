@@ -2,6 +2,7 @@
 // Main entry point and TUI event loop for docka application
 // dockaアプリケーションのメインエントリーポイントとTUIイベントループ
 
+use chrono::Utc;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
@@ -10,6 +11,7 @@ use crossterm::{
 use ratatui::{
     Terminal,
     backend::{Backend, CrosstermBackend},
+    layout::{Constraint, Layout},
 };
 use std::{
     io::{self, Stdout},
@@ -20,15 +22,23 @@ use tokio::time::timeout;
 
 // Import docka modules
 use docka::{
+    app::config_service::ConfigService,
+    domain::repositories::{DockerRepository, MultiHostDockerRepository},
     error::{DockaError, DockaResult},
-    infrastructure::BollardDockerRepository,
+    infrastructure::{BollardDockerRepository, DockerEnvironment, EndpointCatalog},
     ui::{
-        app::{App, NavigationDirection},
+        app::{ALL_HOSTS_ENDPOINT, App, NavigationDirection},
         events::{AppEvent, EventStats, handle_key_event, process_app_event},
         layouts::SimpleLayout,
         styles::Theme,
         validate_key_input,
-        widgets::{ContainerListWidget, StatusBar}, // ContainerListWidget, StatusBar を追加
+        widgets::{
+            ActionMenuWidget, CleanupAssistantWidget, ContainerListWidget, ContextSwitcherWidget,
+            CopyDialogWidget, DetailsViewWidget, DiffViewWidget, FileBrowserWidget,
+            LabelBrowserWidget, PortsViewWidget, ProcessPaneWidget, PruneDialogWidget,
+            RenameDialogWidget, ResourceDialogWidget, RestartPolicyPickerWidget,
+            SignalPickerWidget, StatusBar, SystemDashboardWidget,
+        },
     },
 };
 
@@ -53,19 +63,44 @@ const STARTUP_TIMEOUT: Duration = Duration::from_secs(3);
 /// * `Err(Box<dyn std::error::Error>)` - Application failed with error
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing for debugging in development
-    // 開発時のデバッグ用トレース初期化
+    // Read user settings (timezone, time display, secret patterns) first,
+    // so log output is masked from the start
+    // ログ出力を最初からマスクするため、ユーザー設定（タイムゾーン、時刻表示、
+    // シークレットのパターン）を先に読み込む
+    let config_service = ConfigService::from_env();
+    let config = config_service.load()?;
+
+    // Initialize tracing for debugging in development; release builds log
+    // nothing, so this masking writer is the only log output path
+    // 開発時のデバッグ用トレース初期化。リリースビルドはログを出力しないため、
+    // このマスク付きライターが唯一のログ出力経路
     #[cfg(debug_assertions)]
-    init_tracing()?;
+    init_tracing(config.secret_patterns.clone())?;
 
     // Display startup banner
     // 起動バナーを表示
     println!("{} v{} - TUI Docker Management Tool", APP_NAME, VERSION);
     println!("Initializing Docker connection...");
 
+    // Resolve Docker endpoints from DOCKER_* variables and ~/.docker/contexts
+    // DOCKER_*変数と~/.docker/contextsからDockerエンドポイントを解決
+    let catalog = EndpointCatalog::load(&DockerEnvironment::from_env())?;
+    let endpoint = catalog.active();
+    println!(
+        "Using Docker endpoint '{}' ({})",
+        endpoint.name, endpoint.host
+    );
+    if let Some(engine) = endpoint.detected_engine {
+        println!("Default Docker socket not found, auto-detected {engine} socket");
+    }
+
+    // Settings docka refuses are reported as such, not as a daemon that is down
+    // dockaが拒否する設定はデーモン停止ではなくそのまま報告する
+    endpoint.ensure_supported()?;
+
     // Initialize Docker repository with timeout
     // タイムアウト付きでDockerリポジトリを初期化
-    let docker_repo = timeout(STARTUP_TIMEOUT, BollardDockerRepository::new())
+    let docker_repo = timeout(STARTUP_TIMEOUT, BollardDockerRepository::connect(endpoint))
         .await
         .map_err(|_| DockaError::Internal {
             message: "Docker initialization timed out after 3 seconds".to_string(),
@@ -87,6 +122,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create application state
     // アプリケーション状態を作成
     let mut app = App::new(docker_repo);
+    app.apply_config(&config);
+    app.config_service = Some(config_service);
+    app.set_available_endpoints(endpoint_choices(&catalog), endpoint.name.clone());
+    for endpoint in catalog.endpoints() {
+        if let Some(engine) = endpoint.detected_engine {
+            app.remember_detected_engine(endpoint.name.clone(), engine.label());
+        }
+        if endpoint.reaches_container_addresses() {
+            app.remember_routable_endpoint(endpoint.name.clone());
+        }
+    }
 
     // Initial container load
     // 初期コンテナロード
@@ -99,7 +145,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Run the application
     // アプリケーション実行
-    let result = run_app(&mut terminal, &mut app).await;
+    let result = run_app(&mut terminal, &mut app, &catalog).await;
 
     // Cleanup terminal
     // ターミナルクリーンアップ
@@ -224,11 +270,16 @@ where
 /// # Arguments
 /// * `terminal` - Terminal instance for rendering
 /// * `app` - Application state to manage
+/// * `catalog` - Docker endpoints available to the context switcher
 ///
 /// # Returns
 /// * `Ok(EventStats)` - Application exited successfully with statistics
 /// * `Err(DockaError)` - Event loop failed
-async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> DockaResult<EventStats> {
+async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    catalog: &EndpointCatalog,
+) -> DockaResult<EventStats> {
     let mut event_stats = EventStats::new();
     let mut last_render = Instant::now();
     const TARGET_FPS: Duration = Duration::from_millis(16); // ~60 FPS
@@ -245,15 +296,60 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Docka
     while app.is_running() {
         // Handle events with timeout
         // タイムアウト付きイベント処理
-        if event::poll(EVENT_POLL_INTERVAL).map_err(DockaError::Io)? {
-            if let Event::Key(key_event) = event::read().map_err(DockaError::Io)? {
+        // Note: Other events (resize, mouse, etc.) are implicitly ignored
+        // 注意: その他のイベント（リサイズ、マウス等）は暗黙的に無視される
+        if event::poll(EVENT_POLL_INTERVAL).map_err(DockaError::Io)?
+            && let Event::Key(key_event) = event::read().map_err(DockaError::Io)?
+        {
+            // Text fields receive raw keys so bound keys can be typed
+            // バインド済みキーも入力できるようテキストフィールドは生のキーを受け取る
+            if app.is_text_input_active() {
+                app.handle_text_input(key_event).await;
+            } else if validate_key_input(key_event) {
                 // Validate and process key input
                 // キー入力を検証して処理
-                if validate_key_input(key_event) {
-                    let app_event = handle_key_event(key_event);
+                let app_event = handle_key_event(key_event);
 
-                    // 統合されたイベント処理
-                    let event_result = match app_event {
+                // 統合されたイベント処理
+                let event_result = if app.is_context_switcher_open() {
+                    // The context switcher popup captures navigation keys
+                    // コンテキストスイッチャーはナビゲーションキーを占有する
+                    handle_context_switcher_event(app, &app_event, catalog, &mut container_widget)
+                        .await
+                } else if app.is_action_menu_open() {
+                    // The action menu captures navigation keys as well
+                    // アクションメニューもナビゲーションキーを占有する
+                    handle_action_menu_event(app, &app_event, &mut container_widget).await
+                } else if app.is_signal_picker_open() {
+                    // The signal picker is opened from the action menu's Kill entry
+                    // シグナルピッカーはアクションメニューのKill項目から開かれる
+                    handle_signal_picker_event(app, &app_event, &mut container_widget).await
+                } else if app.is_restart_policy_picker_open() {
+                    handle_restart_policy_picker_event(app, &app_event, &mut container_widget).await
+                } else if app.is_prune_dialog_open() {
+                    // The prune dialog may be opened over the dashboard too
+                    // 削除ダイアログはダッシュボード上でも開かれる
+                    handle_prune_dialog_event(app, &app_event, &mut container_widget).await
+                } else if app.is_resource_dialog_open() {
+                    handle_resource_dialog_event(app, &app_event, &mut container_widget).await
+                } else if app.is_copy_dialog_open() {
+                    handle_copy_dialog_event(app, &app_event).await
+                } else if app.is_cleanup_assistant_open() {
+                    handle_cleanup_assistant_event(app, &app_event, &mut container_widget).await
+                } else if app.is_diff_view_open() {
+                    handle_diff_view_event(app, &app_event).await
+                } else if app.is_file_browser_open() {
+                    handle_file_browser_event(app, &app_event).await
+                } else if app.is_details_view_open() {
+                    handle_details_view_event(app, &app_event).await
+                } else if app.is_ports_view_open() {
+                    handle_ports_view_event(app, &app_event).await
+                } else if app.is_label_browser_open() {
+                    handle_label_browser_event(app, &app_event).await
+                } else if app.is_system_overview_open() {
+                    handle_system_overview_event(app, &app_event).await
+                } else {
+                    match app_event {
                         AppEvent::SelectNext => {
                             app.handle_container_navigation(
                                 &mut container_widget,
@@ -283,26 +379,40 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Docka
                         }
                         // 他のイベントは既存のprocess_app_event関数を使用
                         _ => process_app_event(app, app_event.clone()).await,
-                    };
-
-                    // Record event statistics
-                    // イベント統計を記録
-                    event_stats.record_event(&app_event, &event_result);
-
-                    // Handle processing errors
-                    // 処理エラーを処理
-                    if let Err(ref error) = event_result {
-                        // Log error but continue running
-                        // エラーをログするが実行を継続
-                        #[cfg(debug_assertions)]
-                        eprintln!("Event processing error: {}", error);
                     }
+                };
+
+                // Record event statistics
+                // イベント統計を記録
+                event_stats.record_event(&app_event, &event_result);
+
+                // Handle processing errors
+                // 処理エラーを処理
+                if let Err(ref error) = event_result {
+                    // Log error but continue running
+                    // エラーをログするが実行を継続
+                    #[cfg(debug_assertions)]
+                    eprintln!("Event processing error: {}", error);
                 }
             }
-            // Note: Other events (resize, mouse, etc.) are implicitly ignored
-            // 注意: その他のイベント（リサイズ、マウス等）は暗黙的に無視される
         }
 
+        // Collect process listings and reload the pane in the background when stale
+        // プロセス一覧を回収し、古くなったペインをバックグラウンドで再読み込み
+        app.refresh_process_pane_if_due(Instant::now()).await;
+
+        // Poll container exits in the background so crash loops are flagged
+        // クラッシュループを示せるようバックグラウンドでコンテナの終了をポーリング
+        app.poll_container_exits_if_due(Utc::now()).await;
+
+        // Pick up the result of a finished background copy
+        // 終了したバックグラウンドコピーの結果を回収
+        app.poll_copy_dialog().await;
+
+        // Re-sort the list once usage samples for the CPU/memory sort arrive
+        // CPU/メモリの並べ替え用の使用量を取得したら一覧を並べ替え直す
+        app.poll_container_stats().await;
+
         // === 統合レンダリング（修正箇所） ===
         // Render UI with frame rate limiting
         // フレームレート制限付きでUIをレンダリング
@@ -321,6 +431,505 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Docka
     Ok(event_stats)
 }
 
+/// Handle key events while the context switcher popup is open
+/// コンテキストスイッチャーポップアップ表示中のキーイベントを処理
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `catalog` - Known Docker endpoints
+/// * `container_widget` - Container list widget to resynchronize after switching
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Switching to the chosen endpoint failed
+async fn handle_context_switcher_event(
+    app: &mut App,
+    event: &AppEvent,
+    catalog: &EndpointCatalog,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_context_switcher(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_context_switcher(NavigationDirection::Previous),
+        AppEvent::Enter => return switch_endpoint(app, catalog, container_widget).await,
+        AppEvent::Quit | AppEvent::SwitchContext => app.close_context_switcher(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the action menu popup is open
+/// アクションメニューポップアップ表示中のキーイベントを処理
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after the action
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - The chosen action is unsupported or failed
+async fn handle_action_menu_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_action_menu(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_action_menu(NavigationDirection::Previous),
+        AppEvent::Enter => {
+            let result = app.run_highlighted_action().await;
+            app.sync_widget_state(container_widget);
+            return result;
+        }
+        AppEvent::Quit => app.close_action_menu(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the signal picker popup is open
+/// シグナルピッカーポップアップ表示中のキーイベントを処理
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after sending
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Sending the signal failed
+async fn handle_signal_picker_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_signal_picker(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_signal_picker(NavigationDirection::Previous),
+        AppEvent::Enter => {
+            let result = app.send_highlighted_signal().await;
+            app.sync_widget_state(container_widget);
+            return result;
+        }
+        AppEvent::Quit => app.close_signal_picker(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the restart policy picker popup is open
+/// 再起動ポリシーピッカーポップアップ表示中のキーイベントを処理
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after applying
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Updating the container failed
+async fn handle_restart_policy_picker_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_restart_policy_picker(NavigationDirection::Next),
+        AppEvent::SelectPrevious => {
+            app.navigate_restart_policy_picker(NavigationDirection::Previous);
+        }
+        AppEvent::Enter => {
+            let result = app.apply_restart_policy().await;
+            app.sync_widget_state(container_widget);
+            return result;
+        }
+        AppEvent::Quit => app.close_restart_policy_picker(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the prune dialog is open
+/// 削除ダイアログ表示中のキーイベントを処理
+///
+/// `q`/`Esc` step back one stage; the dialog closes from its first step.
+/// `q`/`Esc`は1つ前の手順に戻り、最初の手順からはダイアログを閉じます。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after removal
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Reloading containers after removal failed
+async fn handle_prune_dialog_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_prune_dialog(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_prune_dialog(NavigationDirection::Previous),
+        AppEvent::Enter => {
+            let result = app.prune_dialog_enter().await;
+            app.sync_widget_state(container_widget);
+            return result;
+        }
+        AppEvent::Quit => app.prune_dialog_back(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the copy files dialog is open
+/// ファイルコピーダイアログ表示中のキーイベントを処理
+///
+/// `q`/`Esc` step back one stage and cancel a running copy; the dialog
+/// closes from its first step.
+/// `q`/`Esc`は1つ前の手順に戻り実行中のコピーを取り消し、最初の手順からは
+/// ダイアログを閉じます。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the dialog
+async fn handle_copy_dialog_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_copy_dialog(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_copy_dialog(NavigationDirection::Previous),
+        AppEvent::Enter => app.copy_dialog_enter().await,
+        AppEvent::Quit => app.copy_dialog_back(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the resource limits dialog is open
+/// リソース制限ダイアログ表示中のキーイベントを処理
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after applying
+///
+/// # Returns
+/// * `Ok(())` - Event handled; update errors are shown inside the dialog
+/// * `Err(DockaError)` - Refreshing the list after the update failed
+async fn handle_resource_dialog_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_resource_dialog(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_resource_dialog(NavigationDirection::Previous),
+        AppEvent::Enter => {
+            let result = app.resource_dialog_enter().await;
+            app.sync_widget_state(container_widget);
+            return result;
+        }
+        AppEvent::Quit => app.close_resource_dialog(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the system dashboard is shown
+/// システムダッシュボード表示中のキーイベントを処理
+///
+/// `q`/`Esc` return to the container list instead of quitting.
+/// `q`/`Esc`は終了せずにコンテナリストに戻ります。
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Reloading the overview failed
+async fn handle_system_overview_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::Refresh => return app.show_system_overview().await,
+        AppEvent::Quit | AppEvent::ShowSystem => app.close_system_overview(),
+        AppEvent::ForceQuit => app.force_quit(),
+        AppEvent::Prune => app.open_prune_dialog(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the cleanup assistant is shown
+/// クリーンアップアシスタント表示中のキーイベントを処理
+///
+/// `q`/`Esc` cancel a pending removal, then return to the container list.
+/// `q`/`Esc`は保留中の削除を取り消し、その後コンテナリストに戻ります。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+/// * `container_widget` - Container list widget to resynchronize after removal
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the assistant
+async fn handle_cleanup_assistant_event(
+    app: &mut App,
+    event: &AppEvent,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_cleanup_assistant(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_cleanup_assistant(NavigationDirection::Previous),
+        AppEvent::Enter => {
+            app.cleanup_assistant_enter().await;
+            app.sync_widget_state(container_widget);
+        }
+        AppEvent::Refresh => app.reload_cleanup_candidates().await,
+        AppEvent::Quit | AppEvent::CleanupAssistant => app.cleanup_assistant_back(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the filesystem changes view is shown
+/// ファイルシステム変更ビュー表示中のキーイベントを処理
+///
+/// `q`/`Esc` return to the container list.
+/// `q`/`Esc`はコンテナリストに戻ります。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the view
+async fn handle_diff_view_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_diff_view(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_diff_view(NavigationDirection::Previous),
+        AppEvent::Enter => app.toggle_diff_node(),
+        AppEvent::Refresh => app.reload_diff_view().await,
+        AppEvent::Quit | AppEvent::ShowChanges => app.close_diff_view(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the file browser is shown
+/// ファイルブラウザ表示中のキーイベントを処理
+///
+/// `q`/`Esc` close an open preview, then return to the container list.
+/// `q`/`Esc`は開いているプレビューを閉じ、その後コンテナリストに戻ります。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the browser
+async fn handle_file_browser_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_file_browser(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_file_browser(NavigationDirection::Previous),
+        AppEvent::Enter => app.file_browser_enter().await,
+        AppEvent::Refresh => app.reload_file_browser().await,
+        AppEvent::Quit => app.file_browser_back(),
+        AppEvent::BrowseFiles => app.close_file_browser(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the container details view is shown
+/// コンテナ詳細ビュー表示中のキーイベントを処理
+///
+/// `q`/`Esc` return to the container list and `u` switches between
+/// relative and absolute times.
+/// `q`/`Esc`はコンテナリストに戻り、`u`は相対時刻と日時を切り替えます。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the view
+async fn handle_details_view_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_details_view(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_details_view(NavigationDirection::Previous),
+        AppEvent::Refresh => app.reload_details_view().await,
+        AppEvent::Quit | AppEvent::ShowDetails => app.close_details_view(),
+        AppEvent::ToggleTimeDisplay => app.toggle_time_display(),
+        AppEvent::ToggleSecrets => app.toggle_reveal_secrets(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the ports view is shown
+/// ポートビュー表示時のキーイベントを処理
+///
+/// `Enter` forwards the highlighted port or stops the highlighted forward,
+/// and `f` opens a field for forwarding any port, published or not;
+/// `q`/`Esc` and `o` return to the container list, leaving forwards running.
+/// `Enter`は選択中のポートを転送するか選択中のフォワードを停止し、`f`は公開の
+/// 有無を問わず任意のポートを転送する入力欄を開きます。
+/// `q`/`Esc`と`o`はフォワードを実行したままコンテナリストに戻ります。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled; errors are shown inside the view
+async fn handle_ports_view_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_ports_view(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_ports_view(NavigationDirection::Previous),
+        AppEvent::Refresh => app.reload_ports_view().await,
+        AppEvent::Enter => app.toggle_highlighted_forward().await,
+        AppEvent::CopyFiles => app.open_forward_input(),
+        AppEvent::Quit | AppEvent::ShowPorts => app.close_ports_view(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle key events while the label browser is shown
+/// ラベルブラウザ表示時のキーイベントを処理
+///
+/// `Enter` filters the list by the highlighted label value and `g` groups
+/// it by the highlighted key; both return to the container list.
+/// `Enter`は選択中のラベル値で一覧を絞り込み、`g`は選択中のキーで
+/// グループ化します。どちらもコンテナリストに戻ります。
+///
+/// # Arguments
+/// * `app` - Application state
+/// * `event` - Event produced by the key bindings
+///
+/// # Returns
+/// * `Ok(())` - Event handled
+/// * `Err(DockaError)` - Refreshing the filtered list failed
+async fn handle_label_browser_event(app: &mut App, event: &AppEvent) -> DockaResult<()> {
+    match event {
+        AppEvent::SelectNext => app.navigate_label_browser(NavigationDirection::Next),
+        AppEvent::SelectPrevious => app.navigate_label_browser(NavigationDirection::Previous),
+        AppEvent::Refresh => app.reload_label_browser().await,
+        AppEvent::Enter => return app.toggle_highlighted_label_filter().await,
+        AppEvent::GroupByLabel => app.group_by_highlighted_label(),
+        AppEvent::Quit | AppEvent::BrowseLabels => app.close_label_browser(),
+        AppEvent::ForceQuit => app.force_quit(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Reconnect to the endpoint highlighted in the context switcher
+/// コンテキストスイッチャーで選択されたエンドポイントに再接続
+///
+/// The current repository is kept if the new endpoint cannot be reached.
+/// 新しいエンドポイントに到達できない場合は現在のリポジトリを維持します。
+///
+/// # Returns
+/// * `Ok(())` - Switched (or the active endpoint was chosen again)
+/// * `Err(DockaError)` - Connection to the chosen endpoint failed
+async fn switch_endpoint(
+    app: &mut App,
+    catalog: &EndpointCatalog,
+    container_widget: &mut ContainerListWidget,
+) -> DockaResult<()> {
+    let Some(name) = app.highlighted_endpoint().map(str::to_string) else {
+        return Ok(());
+    };
+    app.close_context_switcher();
+
+    if name == app.endpoint_name {
+        return Ok(());
+    }
+
+    let connection = if name == ALL_HOSTS_ENDPOINT {
+        connect_all_hosts(catalog)
+    } else {
+        let endpoint = catalog
+            .find(&name)
+            .ok_or_else(|| DockaError::invalid_input(format!("Unknown Docker context '{name}'")))?;
+
+        timeout(STARTUP_TIMEOUT, BollardDockerRepository::connect(endpoint))
+            .await
+            .unwrap_or_else(|_| {
+                Err(DockaError::internal(format!(
+                    "Connecting to '{name}' timed out after {} seconds",
+                    STARTUP_TIMEOUT.as_secs()
+                )))
+            })
+            .map(|repo| Arc::new(repo) as Arc<dyn DockerRepository>)
+    };
+
+    match connection {
+        Ok(repo) => {
+            app.switch_repository(repo, name);
+            app.refresh_containers().await?;
+            app.sync_widget_state(container_widget);
+            Ok(())
+        }
+        Err(e) => {
+            app.set_error_state(format!("Cannot switch to '{name}': {e}"));
+            Err(e)
+        }
+    }
+}
+
+/// Entries offered by the context switcher
+/// コンテキストスイッチャーに表示する項目
+///
+/// The aggregated "all hosts" entry is only offered when there is more than
+/// one endpoint to merge.
+/// 統合用の"all hosts"項目は統合するエンドポイントが複数ある場合のみ表示されます。
+fn endpoint_choices(catalog: &EndpointCatalog) -> Vec<String> {
+    let mut names = catalog.names();
+    if names.len() > 1 {
+        names.push(ALL_HOSTS_ENDPOINT.to_string());
+    }
+    names
+}
+
+/// Build a repository that merges every endpoint of the catalog
+/// カタログの全エンドポイントを統合するリポジトリを構築
+///
+/// Clients are created without pinging so that an unreachable host only
+/// marks its own rows as stale instead of blocking the whole view.
+/// 到達不能なホストがビュー全体を妨げず自身の行だけを古いと示すよう、
+/// クライアントはpingせずに作成されます。
+///
+/// # Returns
+/// * `Ok(Arc<dyn DockerRepository>)` - Aggregated repository
+/// * `Err(DockaError)` - An endpoint could not be configured
+fn connect_all_hosts(catalog: &EndpointCatalog) -> DockaResult<Arc<dyn DockerRepository>> {
+    let hosts = catalog
+        .endpoints()
+        .iter()
+        .map(|endpoint| {
+            let repo: Arc<dyn DockerRepository> =
+                Arc::new(BollardDockerRepository::connect_lazy(endpoint)?);
+            Ok((endpoint.name.clone(), repo))
+        })
+        .collect::<DockaResult<Vec<_>>>()?;
+
+    Ok(Arc::new(MultiHostDockerRepository::new(hosts)?))
+}
+
 // /// Render UI with full widget integration
 // /// 完全なウィジェット統合でUIをレンダリング
 // fn render_ui_integrated(
@@ -359,7 +968,23 @@ fn render_help_area(f: &mut ratatui::Frame, area: ratatui::layout::Rect, theme:
         Span::styled("r", theme.styles.success_style()),
         Span::styled(": refresh | ", theme.styles.muted_style()),
         Span::styled("Enter", theme.styles.success_style()),
-        Span::styled(": select | ", theme.styles.muted_style()),
+        Span::styled(": actions | ", theme.styles.muted_style()),
+        Span::styled("c", theme.styles.success_style()),
+        Span::styled(": context | ", theme.styles.muted_style()),
+        Span::styled("i", theme.styles.success_style()),
+        Span::styled(": system | ", theme.styles.muted_style()),
+        Span::styled("p", theme.styles.success_style()),
+        Span::styled(": prune | ", theme.styles.muted_style()),
+        Span::styled("a", theme.styles.success_style()),
+        Span::styled(": cleanup | ", theme.styles.muted_style()),
+        Span::styled("t", theme.styles.success_style()),
+        Span::styled(": processes | ", theme.styles.muted_style()),
+        Span::styled("d", theme.styles.success_style()),
+        Span::styled(": diff | ", theme.styles.muted_style()),
+        Span::styled("f", theme.styles.success_style()),
+        Span::styled(": copy | ", theme.styles.muted_style()),
+        Span::styled("b", theme.styles.success_style()),
+        Span::styled(": browse | ", theme.styles.muted_style()),
         Span::styled("q", theme.styles.error_style()),
         Span::styled(": quit", theme.styles.muted_style()),
     ];
@@ -417,8 +1042,30 @@ fn render_ui<B: Backend>(
             // レスポンシブレイアウトを計算
             let layout = SimpleLayout::calculate_responsive(f.area());
 
-            // メインエリア: ContainerListWidget
-            ContainerListWidget::render(container_widget, f, app, layout.main, theme);
+            // メインエリア: システムダッシュボードまたはContainerListWidget
+            if app.is_system_overview_open() {
+                SystemDashboardWidget::render(f, app, layout.main, theme);
+            } else if app.is_cleanup_assistant_open() {
+                CleanupAssistantWidget::render(f, app, layout.main, theme);
+            } else if app.is_diff_view_open() {
+                DiffViewWidget::render(f, app, layout.main, theme);
+            } else if app.is_file_browser_open() {
+                FileBrowserWidget::render(f, app, layout.main, theme);
+            } else if app.is_details_view_open() {
+                DetailsViewWidget::render(f, app, layout.main, theme);
+            } else if app.is_ports_view_open() {
+                PortsViewWidget::render(f, app, layout.main, theme);
+            } else if app.is_label_browser_open() {
+                LabelBrowserWidget::render(f, app, layout.main, theme);
+            } else if app.is_process_pane_open() {
+                let [list_area, pane_area] =
+                    Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)])
+                        .areas(layout.main);
+                ContainerListWidget::render(container_widget, f, app, list_area, theme);
+                ProcessPaneWidget::render(f, app, pane_area, theme);
+            } else {
+                ContainerListWidget::render(container_widget, f, app, layout.main, theme);
+            }
 
             // ステータスバーエリア: StatusBar
             StatusBar::render(f, app, layout.status);
@@ -427,6 +1074,16 @@ fn render_ui<B: Backend>(
             if layout.help.height > 0 && layout.help.width > 0 {
                 render_help_area(f, layout.help, theme);
             }
+
+            // ポップアップ（開いている場合のみ描画）
+            ActionMenuWidget::render(f, app, f.area(), theme);
+            SignalPickerWidget::render(f, app, f.area(), theme);
+            ContextSwitcherWidget::render(f, app, f.area(), theme);
+            PruneDialogWidget::render(f, app, f.area(), theme);
+            CopyDialogWidget::render(f, app, f.area(), theme);
+            ResourceDialogWidget::render(f, app, f.area(), theme);
+            RestartPolicyPickerWidget::render(f, app, f.area(), theme);
+            RenameDialogWidget::render(f, app, f.area(), theme);
         })
         // === 修正: CompletedFrame を () に変換 ===
         .map(|_| ()) // CompletedFrame<'_> を () に変換
@@ -437,13 +1094,20 @@ fn render_ui<B: Backend>(
 
 /// Initialize tracing for development debugging
 /// 開発デバッグ用トレース初期化
+///
+/// Secrets matching `mask` are masked in every log line.
+/// `mask`に一致するシークレットは全てのログ行でマスクされます。
 #[cfg(debug_assertions)]
-fn init_tracing() -> Result<(), Box<dyn std::error::Error>> {
+fn init_tracing(
+    mask: docka::utils::secret_mask::SecretMask,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use docka::utils::secret_mask::MaskingWriter;
     use tracing_subscriber;
 
     // Simple tracing setup without env-filter
     // env-filterなしのシンプルなtracing設定
     tracing_subscriber::fmt()
+        .with_writer(move || MaskingWriter::new(mask.clone(), std::io::stdout()))
         .with_target(false)
         .with_thread_ids(true)
         .with_file(true)
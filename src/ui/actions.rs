@@ -0,0 +1,275 @@
+// src/ui/actions.rs
+// Container actions offered by the action menu
+// アクションメニューで提供するコンテナアクション
+
+use crate::domain::entities::Container;
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::{ContainerId, EngineCapabilities, EngineFeature, Signal};
+use crate::error::{DockaError, DockaResult};
+
+/// Lifecycle action that can be run on the selected container
+/// 選択中のコンテナに実行できるライフサイクルアクション
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainerAction {
+    /// Start a stopped container
+    /// 停止中のコンテナを開始
+    Start,
+
+    /// Stop a running container
+    /// 実行中のコンテナを停止
+    Stop,
+
+    /// Restart a container
+    /// コンテナを再起動
+    Restart,
+
+    /// Pause a running container
+    /// 実行中のコンテナを一時停止
+    Pause,
+
+    /// Resume a paused container
+    /// 一時停止中のコンテナを再開
+    Unpause,
+
+    /// Send a signal to a running container
+    /// 実行中のコンテナにシグナルを送信
+    ///
+    /// The menu opens the signal picker for this action; executing it
+    /// directly sends `SIGKILL`.
+    /// メニューはこのアクションでシグナルピッカーを開き、直接実行した場合は
+    /// `SIGKILL`を送信します。
+    Kill,
+
+    /// Change resource limits and restart policy
+    /// リソース制限と再起動ポリシーを変更
+    ///
+    /// The menu opens the resource dialog for this action, since it needs
+    /// the new limits.
+    /// 新しい制限が必要なため、メニューはこのアクションでリソースダイアログを開きます。
+    EditResources,
+
+    /// Give the container a new name
+    /// コンテナに新しい名前を付ける
+    ///
+    /// The menu opens the rename dialog for this action.
+    /// メニューはこのアクションで名前変更ダイアログを開きます。
+    Rename,
+
+    /// Change only the restart policy
+    /// 再起動ポリシーのみを変更
+    ///
+    /// The menu opens the restart policy picker, which shows the current
+    /// policy, for this action.
+    /// メニューはこのアクションで現在のポリシーを表示する再起動ポリシー
+    /// ピッカーを開きます。
+    RestartPolicy,
+}
+
+impl ContainerAction {
+    /// Every action in menu order
+    /// メニュー順の全アクション
+    pub const ALL: [Self; 9] = [
+        Self::Start,
+        Self::Stop,
+        Self::Restart,
+        Self::Pause,
+        Self::Unpause,
+        Self::Kill,
+        Self::EditResources,
+        Self::Rename,
+        Self::RestartPolicy,
+    ];
+
+    /// Menu label
+    /// メニューラベル
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Start => "Start",
+            Self::Stop => "Stop",
+            Self::Restart => "Restart",
+            Self::Pause => "Pause",
+            Self::Unpause => "Unpause",
+            Self::Kill => "Kill…",
+            Self::EditResources => "Resources…",
+            Self::Rename => "Rename…",
+            Self::RestartPolicy => "Restart policy…",
+        }
+    }
+
+    /// Optional engine feature the action depends on
+    /// アクションが依存するオプションのエンジン機能
+    #[must_use]
+    pub const fn required_feature(self) -> Option<EngineFeature> {
+        match self {
+            Self::Pause | Self::Unpause => Some(EngineFeature::Pause),
+            Self::Start
+            | Self::Stop
+            | Self::Restart
+            | Self::Kill
+            | Self::EditResources
+            | Self::Rename
+            | Self::RestartPolicy => None,
+        }
+    }
+
+    /// Check whether the action makes sense for the container's current state
+    /// コンテナの現在の状態でアクションが意味を持つかチェック
+    #[must_use]
+    pub const fn is_applicable(self, container: &Container) -> bool {
+        match self {
+            Self::Start => container.can_start(),
+            Self::Stop => container.can_stop(),
+            Self::Restart => container.can_restart(),
+            Self::Pause => container.can_pause(),
+            Self::Unpause => container.can_unpause(),
+            Self::Kill => container.can_kill(),
+            Self::EditResources | Self::RestartPolicy => container.can_update(),
+            Self::Rename => container.can_rename(),
+        }
+    }
+
+    /// Run the action through the repository
+    /// リポジトリを通じてアクションを実行
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - For `EditResources`, `Rename` and
+    ///   `RestartPolicy`, which need input from their popup
+    /// * Any error returned by the corresponding repository operation
+    pub async fn execute(
+        self,
+        repository: &dyn DockerRepository,
+        id: &ContainerId,
+    ) -> DockaResult<()> {
+        match self {
+            Self::Start => repository.start_container(id).await,
+            Self::Stop => repository.stop_container(id).await,
+            Self::Restart => repository.restart_container(id).await,
+            Self::Pause => repository.pause_container(id).await,
+            Self::Unpause => repository.unpause_container(id).await,
+            Self::Kill => repository.kill_container(id, Signal::Kill).await,
+            Self::EditResources | Self::Rename | Self::RestartPolicy => Err(
+                DockaError::invalid_input(format!("{} needs input from its popup", self.label())),
+            ),
+        }
+    }
+}
+
+/// Entry of the action menu
+/// アクションメニューの項目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionMenuEntry {
+    /// Action run when the entry is chosen
+    /// 項目選択時に実行されるアクション
+    pub action: ContainerAction,
+
+    /// Why the engine cannot run the action (`None` when enabled)
+    /// エンジンがアクションを実行できない理由（有効な場合は`None`）
+    pub disabled_reason: Option<String>,
+}
+
+impl ActionMenuEntry {
+    /// Check whether the entry can be run
+    /// 項目が実行可能かチェック
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.disabled_reason.is_none()
+    }
+}
+
+/// Build the action menu for a container
+/// コンテナ用のアクションメニューを構築
+///
+/// Actions that do not apply to the container's state are hidden, while
+/// actions the engine cannot run are kept but disabled with the reason,
+/// so the user learns why instead of getting a generic API error.
+///
+/// コンテナの状態に当てはまらないアクションは非表示になり、エンジンが
+/// 実行できないアクションは理由付きで無効化して残します。これにより
+/// ユーザーは汎用的な`API`エラーではなく理由を知ることができます。
+#[must_use]
+pub fn menu_entries(
+    container: &Container,
+    capabilities: &EngineCapabilities,
+) -> Vec<ActionMenuEntry> {
+    ContainerAction::ALL
+        .into_iter()
+        .filter(|action| action.is_applicable(container))
+        .map(|action| ActionMenuEntry {
+            action,
+            disabled_reason: action
+                .required_feature()
+                .and_then(|feature| capabilities.unsupported_reason(feature)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{ApiVersion, ContainerStatus};
+
+    fn create_container(status: ContainerStatus) -> Container {
+        Container::builder()
+            .id("abc123")
+            .name("web")
+            .image("nginx:latest")
+            .status(status)
+            .build()
+            .expect("Valid test container")
+    }
+
+    #[test]
+    fn test_menu_hides_actions_invalid_for_state() {
+        // Test that only actions valid for the container state are offered
+        // コンテナ状態に有効なアクションのみ提供されることをテスト
+        let capabilities = EngineCapabilities::unknown();
+
+        let running = menu_entries(&create_container(ContainerStatus::Running), &capabilities);
+        let actions: Vec<_> = running.iter().map(|entry| entry.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                ContainerAction::Stop,
+                ContainerAction::Restart,
+                ContainerAction::Pause,
+                ContainerAction::Kill,
+                ContainerAction::EditResources,
+                ContainerAction::Rename,
+                ContainerAction::RestartPolicy
+            ]
+        );
+
+        let stopped = menu_entries(&create_container(ContainerStatus::Stopped), &capabilities);
+        assert!(
+            stopped
+                .iter()
+                .any(|entry| entry.action == ContainerAction::Start)
+        );
+        assert!(stopped.iter().all(|entry| {
+            entry.action != ContainerAction::Pause && entry.action != ContainerAction::Kill
+        }));
+    }
+
+    #[test]
+    fn test_menu_disables_unsupported_actions() {
+        // Test that engine limitations disable actions with the reason
+        // エンジンの制限がアクションを理由付きで無効化することをテスト
+        let capabilities = EngineCapabilities::new("20.10.7", "linux", ApiVersion::new(1, 41))
+            .with_unsupported(EngineFeature::Pause, "no cgroup driver");
+
+        let entries = menu_entries(&create_container(ContainerStatus::Running), &capabilities);
+        let pause = entries
+            .iter()
+            .find(|entry| entry.action == ContainerAction::Pause)
+            .unwrap();
+        assert!(!pause.is_enabled());
+        assert_eq!(pause.disabled_reason.as_deref(), Some("no cgroup driver"));
+        assert!(
+            entries
+                .iter()
+                .filter(|entry| entry.action != ContainerAction::Pause)
+                .all(ActionMenuEntry::is_enabled)
+        );
+    }
+}
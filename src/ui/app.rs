@@ -2,11 +2,52 @@
 // Basic App structure implementation for TUI application
 // TUIアプリケーション用基本App構造実装
 
-use crate::domain::entities::Container;
+use crate::app::config_service::{AppConfig, ConfigService};
+use crate::app::file_browser_service::FileBrowserService;
+use crate::domain::entities::{
+    Container, ContainerExit, ContainerProcess, CrashLoopPolicy, ExitHistory, SystemOverview,
+};
 use crate::domain::repositories::DockerRepository;
-use crate::error::DockaResult;
+use crate::domain::value_objects::{ContainerId, EngineCapabilities, PortMapping, Signal};
+use crate::error::{DockaError, DockaResult};
+use crate::ui::actions::{self, ActionMenuEntry, ContainerAction};
+use crate::ui::copy_dialog::CopyDialog;
+use crate::ui::details_view::DetailsView;
+use crate::ui::diff_view::DiffView;
+use crate::ui::file_browser::FileBrowser;
+use crate::ui::filter_bar::FilterBarKey;
+use crate::ui::label_browser::LabelBrowser;
+use crate::ui::ports_view::ForwardKey;
+use crate::ui::process_pane::ProcessPane;
+use crate::ui::rename_dialog::{RenameDialog, RenameKey};
+use crate::ui::resource_dialog::ResourceDialog;
+use crate::ui::restart_policy_picker::RestartPolicyPicker;
+use crate::ui::search_bar::SearchKey;
+use crate::utils::secret_mask::SecretMask;
+use crate::utils::time_formatter::{DisplayTimezone, TimeDisplay};
+use chrono::{DateTime, Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
+use tokio::task::JoinHandle;
+
+mod cleanup;
+mod copy;
+mod ports;
+mod query;
+
+pub use cleanup::Cleanup;
+pub use ports::PortForwarding;
+pub use query::ListQuery;
+
+/// Context switcher entry that merges every known endpoint into one list
+/// 既知の全エンドポイントを1つの一覧に統合するコンテキストスイッチャー項目
+pub const ALL_HOSTS_ENDPOINT: &str = "all hosts";
+
+/// How often container exits (`die` events) are polled for crash-loop detection
+/// クラッシュループ検出のためにコンテナの終了（`die`イベント）をポーリングする間隔
+pub const EXIT_POLL_INTERVAL: Duration = Duration::seconds(5);
 
 /// View state enum representing current application UI state
 /// `現在のアプリケーションUI状態を表すViewState列挙型`
@@ -21,6 +62,27 @@ pub enum ViewState {
     /// Error state with error message
     /// エラーメッセージ付きエラー状態
     Error(String),
+    /// Host information and disk usage dashboard
+    /// ホスト情報とディスク使用量のダッシュボード
+    SystemOverview,
+    /// Stale container cleanup assistant
+    /// 古いコンテナのクリーンアップアシスタント
+    CleanupAssistant,
+    /// Filesystem changes of the selected container
+    /// 選択中のコンテナのファイルシステム変更
+    FilesystemChanges,
+    /// Read-only file browser of the selected container
+    /// 選択中のコンテナの読み取り専用ファイルブラウザ
+    FileBrowser,
+    /// Inspect details (health log) of the selected container
+    /// 選択中のコンテナのinspect詳細（ヘルスログ）
+    ContainerDetails,
+    /// Ports of all containers and the host endpoints they are published on
+    /// 全コンテナのポートとその公開先のホストエンドポイント
+    Ports,
+    /// Label keys and values in use, to filter or group the list by
+    /// 一覧の絞り込みやグループ化に使う、使用中のラベルのキーと値
+    Labels,
 }
 
 /// Navigation direction for container selection
@@ -76,6 +138,30 @@ pub struct App {
     /// 現在のコンテナリスト
     pub containers: Vec<Container>,
 
+    /// Containers of the last listing, before filtering and searching
+    /// フィルタと検索の前の、最後に一覧取得したコンテナ
+    pub listed_containers: Vec<Container>,
+
+    /// Filter, search, sort and grouping of the list
+    /// 一覧のフィルタ、検索、並び順、グループ化
+    pub query: ListQuery,
+
+    /// Configuration file saved filters are written to (`None` in tests)
+    /// 保存したフィルタを書き込む設定ファイル（テストでは`None`）
+    pub config_service: Option<ConfigService>,
+
+    /// Whether uptimes and exit times are shown relative or absolute
+    /// 稼働時間と終了時刻を相対表示するか日時表示するか
+    pub time_display: TimeDisplay,
+
+    /// Timezone of absolute timestamps
+    /// 日時表示のタイムゾーン
+    pub timezone: DisplayTimezone,
+
+    /// Key patterns whose values are masked on screen
+    /// 画面上で値をマスクするキーのパターン
+    pub secret_mask: SecretMask,
+
     /// Currently selected container index
     /// 現在選択されているコンテナのインデックス
     pub selected_index: usize,
@@ -92,6 +178,10 @@ pub struct App {
     /// 表示用の最後のエラーメッセージ
     pub last_error: Option<String>,
 
+    /// Warning about the last action, which ran anyway (cleared on refresh)
+    /// 最後のアクションについての警告（アクションは実行済み、更新時に消去）
+    pub warning: Option<String>,
+
     /// Last activity timestamp for rendering optimization
     /// レンダリング最適化用最後のアクティビティタイムスタンプ
     pub last_activity: Instant,
@@ -146,6 +236,118 @@ pub struct App {
     /// # }
     /// ```
     pub show_help: bool,
+
+    /// Name of the Docker endpoint (context) the repository is connected to
+    /// リポジトリが接続している`Docker`エンドポイント（コンテキスト）名
+    pub endpoint_name: String,
+
+    /// Engine behind an auto-detected socket, shown next to the endpoint name
+    /// 自動検出されたソケットの背後のエンジン（エンドポイント名の横に表示）
+    pub detected_engine: Option<String>,
+
+    /// Engines detected behind each endpoint's socket, by endpoint name
+    /// エンドポイント名ごとの、ソケットの背後で検出されたエンジン
+    detected_engines: HashMap<String, String>,
+
+    /// Endpoints whose container addresses are reachable from this machine
+    /// コンテナのアドレスにこのマシンから到達可能なエンドポイント
+    routable_endpoints: HashSet<String>,
+
+    /// Endpoint names offered by the context switcher
+    /// コンテキストスイッチャーに表示するエンドポイント名
+    pub available_endpoints: Vec<String>,
+
+    /// Highlighted entry while the context switcher is open (`None` when closed)
+    /// コンテキストスイッチャー表示中の選択項目（閉じている場合は`None`）
+    pub context_switcher_index: Option<usize>,
+
+    /// Hosts whose rows are a stale snapshot (multi-host view only)
+    /// 行が古いスナップショットになっているホスト（複数ホストビューのみ）
+    pub stale_hosts: Vec<String>,
+
+    /// Capabilities of the connected engine, used to gate actions
+    /// アクションの可否判定に使用する接続中エンジンの機能情報
+    pub capabilities: EngineCapabilities,
+
+    /// Highlighted entry while the action menu is open (`None` when closed)
+    /// アクションメニュー表示中の選択項目（閉じている場合は`None`）
+    pub action_menu_index: Option<usize>,
+
+    /// Highlighted signal while the signal picker is open (`None` when closed)
+    /// シグナルピッカー表示中の選択シグナル（閉じている場合は`None`）
+    pub signal_picker_index: Option<usize>,
+
+    /// Last host overview loaded for the system dashboard
+    /// システムダッシュボード用に最後に読み込んだホスト概要
+    pub system_overview: Option<SystemOverview>,
+
+    /// Guided prune dialog and stale container cleanup assistant
+    /// ガイド付き削除ダイアログと古いコンテナのクリーンアップアシスタント
+    pub cleanup: Cleanup,
+
+    /// Process pane below the container list (`None` when hidden)
+    /// コンテナリストの下のプロセスペイン（非表示の場合は`None`）
+    pub process_pane: Option<ProcessPane>,
+
+    /// Background process listing for the pane, with the container it lists
+    /// ペイン用のバックグラウンドのプロセス一覧取得と対象のコンテナ
+    process_task: Option<(Container, JoinHandle<DockaResult<Vec<ContainerProcess>>>)>,
+
+    /// Filesystem changes view (`None` when closed)
+    /// ファイルシステム変更ビュー（閉じている場合は`None`）
+    pub diff_view: Option<DiffView>,
+
+    /// Copy files dialog (`None` when closed)
+    /// ファイルコピーダイアログ（閉じている場合は`None`）
+    pub copy_dialog: Option<CopyDialog>,
+
+    /// In-container file browser (`None` when closed)
+    /// コンテナ内ファイルブラウザ（閉じている場合は`None`）
+    pub file_browser: Option<FileBrowser>,
+
+    /// Container details view (`None` when closed)
+    /// コンテナ詳細ビュー（閉じている場合は`None`）
+    pub details_view: Option<DetailsView>,
+
+    /// Published ports view and the local port forwards
+    /// 公開ポートビューとローカルポートフォワード
+    pub ports: PortForwarding,
+
+    /// Label browser (`None` when closed)
+    /// ラベルブラウザ（閉じている場合は`None`）
+    pub label_browser: Option<LabelBrowser>,
+
+    /// Resource limits dialog (`None` when closed)
+    /// リソース制限ダイアログ（閉じている場合は`None`）
+    pub resource_dialog: Option<ResourceDialog>,
+
+    /// Rename container dialog (`None` when closed)
+    /// コンテナ名変更ダイアログ（閉じている場合は`None`）
+    pub rename_dialog: Option<RenameDialog>,
+
+    /// Restart policy picker (`None` when closed)
+    /// 再起動ポリシーピッカー（閉じている場合は`None`）
+    pub restart_policy_picker: Option<RestartPolicyPicker>,
+
+    /// Recent container exits, used to flag crash loops
+    /// クラッシュループの判定に使う最近のコンテナの終了
+    pub exit_history: ExitHistory,
+
+    /// Exits within which window count as a crash loop
+    /// どの期間内の何回の終了をクラッシュループとみなすか
+    pub crash_loop_policy: CrashLoopPolicy,
+
+    /// Time of the last successful exit poll (`None` before the first one)
+    /// 最後に成功した終了のポーリング時刻（初回前は`None`）
+    pub exits_polled_at: Option<DateTime<Utc>>,
+
+    /// Background exit poll (`None` when idle)
+    /// バックグラウンドの終了のポーリング（待機中は`None`）
+    exit_poll: Option<JoinHandle<DockaResult<Vec<ContainerExit>>>>,
+
+    /// Start time of the last exit poll, successful or not
+    /// 成否を問わず最後に終了のポーリングを開始した時刻
+    exits_attempted_at: Option<DateTime<Utc>>,
 }
 
 impl App {
@@ -175,12 +377,47 @@ impl App {
             running: true,
             should_quit: false,
             containers: Vec::new(),
+            listed_containers: Vec::new(),
+            query: ListQuery::default(),
+            config_service: None,
+            time_display: TimeDisplay::default(),
+            timezone: DisplayTimezone::default(),
+            secret_mask: SecretMask::default(),
             selected_index: 0,
             view_state: ViewState::Loading,
             docker_repository,
             last_error: None,
+            warning: None,
             last_activity: Instant::now(), // 初期化を追加
             show_help: false,              // <- 新規追加
+            endpoint_name: "default".to_string(),
+            detected_engine: None,
+            detected_engines: HashMap::new(),
+            routable_endpoints: HashSet::new(),
+            available_endpoints: Vec::new(),
+            context_switcher_index: None,
+            stale_hosts: Vec::new(),
+            capabilities: EngineCapabilities::unknown(),
+            action_menu_index: None,
+            signal_picker_index: None,
+            system_overview: None,
+            cleanup: Cleanup::default(),
+            process_pane: None,
+            process_task: None,
+            diff_view: None,
+            copy_dialog: None,
+            file_browser: None,
+            details_view: None,
+            ports: PortForwarding::default(),
+            label_browser: None,
+            resource_dialog: None,
+            rename_dialog: None,
+            restart_policy_picker: None,
+            exit_history: ExitHistory::new(),
+            crash_loop_policy: CrashLoopPolicy::default(),
+            exits_polled_at: None,
+            exit_poll: None,
+            exits_attempted_at: None,
         }
     }
 
@@ -233,17 +470,18 @@ impl App {
         // Set loading state
         self.view_state = ViewState::Loading;
         self.last_error = None;
+        self.warning = None;
         self.last_activity = Instant::now(); // アクティビティ更新を追加
 
-        match self.docker_repository.list_containers().await {
+        let result = self.docker_repository.list_containers().await;
+        self.stale_hosts = self.docker_repository.stale_hosts().await;
+        self.capabilities = self.docker_repository.capabilities().await;
+
+        match result {
             Ok(containers) => {
-                self.containers = containers;
-                // Reset selected index if out of bounds
-                if self.selected_index >= self.containers.len() && !self.containers.is_empty() {
-                    self.selected_index = self.containers.len() - 1;
-                } else if self.containers.is_empty() {
-                    self.selected_index = 0;
-                }
+                self.listed_containers = containers;
+                self.request_container_stats();
+                self.apply_filters();
                 self.view_state = ViewState::ContainerList;
                 self.last_error = None; // Clear previous error
                 Ok(())
@@ -393,6 +631,30 @@ impl App {
         self.containers.get(self.selected_index)
     }
 
+    /// Repository for actions on a listed container
+    /// 一覧のコンテナに対する操作用のリポジトリ
+    ///
+    /// The container row is found by exact ID, preferring the selected row,
+    /// and routed by its `host` field, so equal IDs or ID prefixes on several
+    /// hosts cannot send an action to the wrong one. Rows without a host use
+    /// the active repository.
+    ///
+    /// コンテナ行は選択中の行を優先して完全なIDで検索し、`host`フィールドで
+    /// 振り分けるため、複数ホストでIDや接頭辞が同じでも誤ったホストに操作が
+    /// 送られることはありません。ホストのない行はアクティブなリポジトリを使用します。
+    fn repository_for(&self, id: &ContainerId) -> Arc<dyn DockerRepository> {
+        self.selected_container()
+            .filter(|container| container.id == *id)
+            .or_else(|| {
+                self.listed_containers
+                    .iter()
+                    .find(|container| container.id == *id)
+            })
+            .and_then(|container| container.host.as_deref())
+            .and_then(|host| self.docker_repository.host_repository(host))
+            .unwrap_or_else(|| Arc::clone(&self.docker_repository))
+    }
+
     /// Check if the application should continue running
     /// アプリケーションが実行を継続すべきかチェック
     ///
@@ -637,7 +899,7 @@ impl App {
     ///
     /// * `bool` - True if no containers are loaded
     #[must_use]
-    pub fn is_container_list_empty(&self) -> bool {
+    pub const fn is_container_list_empty(&self) -> bool {
         self.containers.is_empty()
     }
 
@@ -648,7 +910,7 @@ impl App {
     ///
     /// * `bool` - True if `selected_index` is valid for current container list
     #[must_use]
-    pub fn is_selected_index_valid(&self) -> bool {
+    pub const fn is_selected_index_valid(&self) -> bool {
         !self.containers.is_empty() && self.selected_index < self.containers.len()
     }
 
@@ -752,168 +1014,1530 @@ impl App {
         self.view_state = ViewState::ContainerList;
         self.last_activity = std::time::Instant::now();
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::MockDockerRepository;
-    use crate::domain::{ContainerBuilder, ContainerId, ContainerStatus};
-    use std::sync::Arc;
 
-    fn create_test_app() -> App {
-        let mock_repo = Arc::new(MockDockerRepository::new());
-        App::new(mock_repo)
+    /// Set the endpoints offered by the context switcher and the active one
+    /// コンテキストスイッチャーに表示するエンドポイントとアクティブなエンドポイントを設定
+    ///
+    /// # Arguments
+    /// * `endpoints` - Endpoint (context) names in display order
+    /// * `active` - Name of the endpoint currently connected
+    pub fn set_available_endpoints(&mut self, endpoints: Vec<String>, active: impl Into<String>) {
+        self.available_endpoints = endpoints;
+        self.endpoint_name = active.into();
     }
 
-    fn create_test_container(id: &str, name: &str) -> Container {
-        ContainerBuilder::new()
-            .id(ContainerId::new(id).unwrap())
-            .name(name.to_string())
-            .image("test:latest".to_string())
-            .status(ContainerStatus::Running)
-            .build()
-            .unwrap()
+    /// Remember the engine detected behind an endpoint's socket
+    /// エンドポイントのソケットの背後で検出されたエンジンを記憶
+    ///
+    /// The engine is shown whenever the endpoint is active, including after
+    /// switching away and back.
+    /// エンジンは、切り替えて戻った後も含め、そのエンドポイントがアクティブな間表示されます。
+    pub fn remember_detected_engine(
+        &mut self,
+        endpoint: impl Into<String>,
+        engine: impl Into<String>,
+    ) {
+        let endpoint = endpoint.into();
+        let engine = engine.into();
+        if endpoint == self.endpoint_name {
+            self.detected_engine = Some(engine.clone());
+        }
+        self.detected_engines.insert(endpoint, engine);
     }
 
-    #[test]
-    fn test_app_new() {
-        let app = create_test_app();
-        assert!(app.running);
-        assert!(!app.should_quit);
-        assert_eq!(app.containers.len(), 0);
-        assert_eq!(app.selected_index, 0);
-        assert_eq!(app.view_state, ViewState::Loading);
-        assert!(app.last_error.is_none());
-        assert!(app.last_activity.elapsed().as_secs() < 1);
+    /// Remember that an endpoint's container addresses are reachable from this machine
+    /// エンドポイントのコンテナのアドレスにこのマシンから到達可能であることを記憶
+    ///
+    /// Only containers of such endpoints can have their ports forwarded.
+    /// そのようなエンドポイントのコンテナのみポートを転送できます。
+    pub fn remember_routable_endpoint(&mut self, endpoint: impl Into<String>) {
+        self.routable_endpoints.insert(endpoint.into());
     }
 
-    #[test]
-    fn test_needs_redraw() {
-        let mut app = create_test_app();
+    /// Open the context switcher with the active endpoint highlighted
+    /// アクティブなエンドポイントを選択した状態でコンテキストスイッチャーを開く
+    pub fn open_context_switcher(&mut self) {
+        if self.available_endpoints.is_empty() {
+            return;
+        }
 
-        // 初期状態では再描画不要（100ms経過済み）
-        std::thread::sleep(std::time::Duration::from_millis(110));
-        assert!(!app.needs_redraw());
+        let index = self
+            .available_endpoints
+            .iter()
+            .position(|name| *name == self.endpoint_name)
+            .unwrap_or(0);
+        self.context_switcher_index = Some(index);
+        self.last_activity = Instant::now();
+    }
 
-        // アクティビティ更新後は再描画必要
-        app.last_activity = Instant::now();
-        assert!(app.needs_redraw());
+    /// Close the context switcher without switching
+    /// 切り替えずにコンテキストスイッチャーを閉じる
+    pub fn close_context_switcher(&mut self) {
+        self.context_switcher_index = None;
+        self.last_activity = Instant::now();
     }
 
-    #[test]
-    fn test_navigation_updates_activity() {
-        let mut app = create_test_app();
-        app.containers = vec![create_test_container("1", "test1")];
+    /// Check whether the context switcher is open
+    /// コンテキストスイッチャーが開いているかチェック
+    #[must_use]
+    pub const fn is_context_switcher_open(&self) -> bool {
+        self.context_switcher_index.is_some()
+    }
 
-        let initial_activity = app.last_activity;
+    /// Move the context switcher highlight (circular)
+    /// コンテキストスイッチャーの選択を移動（循環）
+    pub fn navigate_context_switcher(&mut self, direction: NavigationDirection) {
+        let count = self.available_endpoints.len();
+        let Some(index) = self.context_switcher_index else {
+            return;
+        };
+        if count == 0 {
+            return;
+        }
 
-        // 小さな遅延で確実にタイムスタンプが変わることを保証
-        std::thread::sleep(std::time::Duration::from_millis(10));
+        let next = match direction {
+            NavigationDirection::Next => (index + 1) % count,
+            NavigationDirection::Previous => (index + count - 1) % count,
+        };
+        self.context_switcher_index = Some(next);
+        self.last_activity = Instant::now();
+    }
 
-        app.select_next();
+    /// Endpoint name highlighted in the context switcher
+    /// コンテキストスイッチャーで選択中のエンドポイント名
+    #[must_use]
+    pub fn highlighted_endpoint(&self) -> Option<&str> {
+        self.context_switcher_index
+            .and_then(|index| self.available_endpoints.get(index))
+            .map(String::as_str)
+    }
 
-        // アクティビティタイムスタンプが更新されているべき
-        assert!(app.last_activity > initial_activity);
+    /// Entries of the action menu for the selected container
+    /// 選択中のコンテナ用アクションメニューの項目
+    #[must_use]
+    pub fn action_menu_entries(&self) -> Vec<ActionMenuEntry> {
+        self.selected_container()
+            .map(|container| actions::menu_entries(container, &self.capabilities))
+            .unwrap_or_default()
     }
 
-    #[test]
-    fn test_navigation_empty_list() {
-        let mut app = create_test_app();
+    /// Open the action menu for the selected container
+    /// 選択中のコンテナのアクションメニューを開く
+    ///
+    /// The first enabled entry is highlighted; nothing happens when no
+    /// container is selected.
+    /// 最初の有効な項目が選択されます。コンテナが未選択の場合は何もしません。
+    pub fn open_action_menu(&mut self) {
+        let entries = self.action_menu_entries();
+        if entries.is_empty() {
+            return;
+        }
 
-        // Should not panic with empty list
-        app.select_next();
-        assert_eq!(app.selected_index, 0);
+        let index = entries
+            .iter()
+            .position(ActionMenuEntry::is_enabled)
+            .unwrap_or(0);
+        self.action_menu_index = Some(index);
+        self.last_activity = Instant::now();
+    }
 
-        app.select_previous();
-        assert_eq!(app.selected_index, 0);
+    /// Close the action menu without running anything
+    /// 何も実行せずにアクションメニューを閉じる
+    pub fn close_action_menu(&mut self) {
+        self.action_menu_index = None;
+        self.last_activity = Instant::now();
     }
 
-    #[test]
-    fn test_navigation_single_item() {
-        let mut app = create_test_app();
-        app.containers = vec![create_test_container("1", "test1")];
-        app.selected_index = 0;
+    /// Check whether the action menu is open
+    /// アクションメニューが開いているかチェック
+    #[must_use]
+    pub const fn is_action_menu_open(&self) -> bool {
+        self.action_menu_index.is_some()
+    }
 
-        // Should stay at 0 for single item
-        app.select_next();
-        assert_eq!(app.selected_index, 0);
+    /// Move the action menu highlight (circular)
+    /// アクションメニューの選択を移動（循環）
+    pub fn navigate_action_menu(&mut self, direction: NavigationDirection) {
+        let count = self.action_menu_entries().len();
+        let Some(index) = self.action_menu_index else {
+            return;
+        };
+        if count == 0 {
+            return;
+        }
 
-        app.select_previous();
-        assert_eq!(app.selected_index, 0);
+        let next = match direction {
+            NavigationDirection::Next => (index + 1) % count,
+            NavigationDirection::Previous => (index + count - 1) % count,
+        };
+        self.action_menu_index = Some(next);
+        self.last_activity = Instant::now();
     }
 
-    #[test]
-    fn test_navigation_multiple_items() {
-        let mut app = create_test_app();
-        app.containers = vec![
-            create_test_container("1", "test1"),
-            create_test_container("2", "test2"),
-            create_test_container("3", "test3"),
-        ];
-        app.selected_index = 0;
-
-        // Test forward navigation
-        app.select_next();
-        assert_eq!(app.selected_index, 1);
+    /// Run the highlighted action on the selected container
+    /// 選択中のコンテナに対して選択されたアクションを実行
+    ///
+    /// The menu is closed and the list refreshed after a successful action.
+    /// Disabled entries are not sent to the engine; their reason is shown instead.
+    /// `Kill` and `Resources…` open the signal picker and the resource dialog
+    /// rather than running immediately.
+    ///
+    /// アクション成功後はメニューを閉じて一覧を更新します。
+    /// 無効な項目はエンジンに送られず、代わりに理由が表示されます。
+    /// `Kill`と`Resources…`はすぐに実行せず、シグナルピッカーと
+    /// リソースダイアログを開きます。
+    ///
+    /// # Errors
+    /// * `DockaError::Unsupported` - When the highlighted action is disabled
+    /// * Any error returned by the repository operation or the refresh
+    pub async fn run_highlighted_action(&mut self) -> DockaResult<()> {
+        let Some(entry) = self
+            .action_menu_index
+            .and_then(|index| self.action_menu_entries().into_iter().nth(index))
+        else {
+            self.close_action_menu();
+            return Ok(());
+        };
+        let Some(id) = self
+            .selected_container()
+            .map(|container| container.id.clone())
+        else {
+            self.close_action_menu();
+            return Ok(());
+        };
+        self.close_action_menu();
+
+        if let Some(reason) = entry.disabled_reason {
+            let error = DockaError::unsupported(entry.action.label(), reason);
+            self.set_error_state(error.user_message());
+            return Err(error);
+        }
 
-        app.select_next();
-        assert_eq!(app.selected_index, 2);
+        match entry.action {
+            ContainerAction::Kill => {
+                self.open_signal_picker();
+                return Ok(());
+            }
+            ContainerAction::EditResources => return self.open_resource_dialog().await,
+            ContainerAction::Rename => {
+                self.open_rename_dialog();
+                return Ok(());
+            }
+            ContainerAction::RestartPolicy => return self.open_restart_policy_picker().await,
+            _ => {}
+        }
 
-        // Test wrap around forward
-        app.select_next();
-        assert_eq!(app.selected_index, 0);
+        let warning = if entry.action == ContainerAction::Start {
+            self.host_port_conflicts(&id).await
+        } else {
+            None
+        };
+
+        if let Err(error) = entry
+            .action
+            .execute(self.repository_for(&id).as_ref(), &id)
+            .await
+        {
+            let message = warning.map_or_else(
+                || error.user_message(),
+                |warning| format!("{} ({warning})", error.user_message()),
+            );
+            self.set_error_state(message);
+            return Err(error);
+        }
 
-        // Test backward navigation
-        app.select_previous();
-        assert_eq!(app.selected_index, 2);
+        self.refresh_containers().await?;
+        self.warning = warning;
+        Ok(())
+    }
 
-        app.select_previous();
-        assert_eq!(app.selected_index, 1);
+    /// Describe the host ports of a container that running containers already use
+    /// コンテナのホストポートのうち、実行中のコンテナが既に使用しているものを説明
+    ///
+    /// The requested bindings are read from inspect, because the list only
+    /// reports ports of running containers. Returns `None` when nothing
+    /// conflicts or the engine cannot be asked.
+    /// 一覧は実行中のコンテナのポートしか報告しないため、要求されたバインドは
+    /// inspectから読み取ります。競合がない場合やエンジンに問い合わせられない場合は
+    /// `None`を返します。
+    async fn host_port_conflicts(&self, id: &ContainerId) -> Option<String> {
+        let bindings = self
+            .repository_for(id)
+            .container_details(id)
+            .await
+            .ok()?
+            .port_bindings;
+        if !bindings.iter().any(PortMapping::is_published) {
+            return None;
+        }
+        let host = self
+            .containers
+            .iter()
+            .find(|container| &container.id == id)
+            .and_then(|container| container.host.as_ref());
+        let others = self.docker_repository.list_containers().await.ok()?;
+
+        let conflicts: Vec<String> = bindings
+            .iter()
+            .flat_map(|binding| {
+                others
+                    .iter()
+                    .filter(|other| {
+                        &other.id != id && other.is_running() && other.host.as_ref() == host
+                    })
+                    .filter(|other| other.published_ports().any(|p| p.conflicts_with(binding)))
+                    .map(move |other| {
+                        format!(
+                            "host port {}/{} is used by {}",
+                            binding.host_port.unwrap_or_default(),
+                            binding.protocol,
+                            other.display_name()
+                        )
+                    })
+            })
+            .collect();
+        (!conflicts.is_empty()).then(|| conflicts.join(", "))
+    }
 
-        app.select_previous();
-        assert_eq!(app.selected_index, 0);
+    /// Open the signal picker for the selected container
+    /// 選択中のコンテナのシグナルピッカーを開く
+    ///
+    /// `SIGTERM` is highlighted first; nothing happens when no container is
+    /// selected.
+    /// 最初に`SIGTERM`が選択されます。コンテナが未選択の場合は何もしません。
+    pub fn open_signal_picker(&mut self) {
+        if self.selected_container().is_none() {
+            return;
+        }
+        self.signal_picker_index = Some(0);
+        self.last_activity = Instant::now();
     }
 
-    #[test]
-    fn test_selected_container() {
-        let mut app = create_test_app();
+    /// Close the signal picker without sending anything
+    /// 何も送信せずにシグナルピッカーを閉じる
+    pub fn close_signal_picker(&mut self) {
+        self.signal_picker_index = None;
+        self.last_activity = Instant::now();
+    }
 
-        // Empty list
-        assert!(app.selected_container().is_none());
+    /// Check whether the signal picker is open
+    /// シグナルピッカーが開いているかチェック
+    #[must_use]
+    pub const fn is_signal_picker_open(&self) -> bool {
+        self.signal_picker_index.is_some()
+    }
 
-        // With containers
-        let container1 = create_test_container("1", "test1");
-        let container2 = create_test_container("2", "test2");
-        app.containers = vec![container1.clone(), container2.clone()];
+    /// Signal under the picker highlight
+    /// ピッカーで選択中のシグナル
+    #[must_use]
+    pub fn highlighted_signal(&self) -> Option<Signal> {
+        self.signal_picker_index
+            .and_then(|index| Signal::ALL.get(index).copied())
+    }
 
-        app.selected_index = 0;
-        assert_eq!(app.selected_container().unwrap().id, container1.id);
+    /// Move the signal picker highlight (circular)
+    /// シグナルピッカーの選択を移動（循環）
+    pub fn navigate_signal_picker(&mut self, direction: NavigationDirection) {
+        let Some(index) = self.signal_picker_index else {
+            return;
+        };
+        let count = Signal::ALL.len();
+        let next = match direction {
+            NavigationDirection::Next => (index + 1) % count,
+            NavigationDirection::Previous => (index + count - 1) % count,
+        };
+        self.signal_picker_index = Some(next);
+        self.last_activity = Instant::now();
+    }
 
-        app.selected_index = 1;
-        assert_eq!(app.selected_container().unwrap().id, container2.id);
+    /// Send the highlighted signal to the selected container
+    /// 選択中のシグナルを選択中のコンテナに送信
+    ///
+    /// The picker is closed and the list refreshed, since terminating signals
+    /// change the container's state.
+    /// 終了系のシグナルはコンテナの状態を変えるため、ピッカーを閉じて一覧を
+    /// 更新します。
+    ///
+    /// # Errors
+    /// * Any error returned by `DockerRepository::kill_container` or the refresh
+    pub async fn send_highlighted_signal(&mut self) -> DockaResult<()> {
+        let signal = self.highlighted_signal();
+        let id = self
+            .selected_container()
+            .map(|container| container.id.clone());
+        self.close_signal_picker();
+        let (Some(signal), Some(id)) = (signal, id) else {
+            return Ok(());
+        };
+
+        if let Err(error) = self.repository_for(&id).kill_container(&id, signal).await {
+            self.set_error_state(error.user_message());
+            return Err(error);
+        }
 
-        // Out of bounds
-        app.selected_index = 999;
-        assert!(app.selected_container().is_none());
+        self.refresh_containers().await
     }
 
-    #[test]
-    fn test_app_lifecycle() {
-        let mut app = create_test_app();
+    /// Load the host overview and switch to the system dashboard
+    /// ホスト概要を読み込みシステムダッシュボードに切り替え
+    ///
+    /// Also used to refresh the dashboard while it is shown.
+    /// ダッシュボード表示中の更新にも使用されます。
+    ///
+    /// # Errors
+    /// * Any error returned by `DockerRepository::system_overview`
+    pub async fn show_system_overview(&mut self) -> DockaResult<()> {
+        self.view_state = ViewState::Loading;
+        self.last_activity = Instant::now();
 
-        assert!(app.is_running());
+        match self.docker_repository.system_overview().await {
+            Ok(overview) => {
+                self.system_overview = Some(overview);
+                self.view_state = ViewState::SystemOverview;
+                self.last_error = None;
+                Ok(())
+            }
+            Err(error) => {
+                let error_message = error.to_string();
+                self.view_state = ViewState::Error(error_message.clone());
+                self.last_error = Some(error_message);
+                Err(error)
+            }
+        }
+    }
 
-        app.quit();
-        assert!(!app.is_running());
+    /// Leave the system dashboard and return to the container list
+    /// システムダッシュボードを閉じてコンテナリストに戻る
+    pub fn close_system_overview(&mut self) {
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
 
-        // Reset for force quit test
-        app.should_quit = false;
-        assert!(app.is_running());
+    /// Check whether the system dashboard is shown
+    /// システムダッシュボードが表示されているかチェック
+    #[must_use]
+    pub const fn is_system_overview_open(&self) -> bool {
+        matches!(self.view_state, ViewState::SystemOverview)
+    }
 
-        app.force_quit();
-        assert!(!app.is_running());
-        assert!(!app.running);
+    /// Check whether a text field currently receives raw keys
+    /// テキストフィールドが現在生のキーを受け取っているかチェック
+    ///
+    /// While this is `true`, key events bypass `handle_key_event` so bound
+    /// keys such as `q` can be typed.
+    /// `true`の間、キーイベントは`handle_key_event`を通らないため、
+    /// `q`などのバインド済みキーを入力できます。
+    #[must_use]
+    pub fn is_text_input_active(&self) -> bool {
+        self.cleanup
+            .prune_dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.editing)
+            || self
+                .copy_dialog
+                .as_ref()
+                .is_some_and(|dialog| dialog.editing)
+            || self
+                .cleanup
+                .assistant
+                .as_ref()
+                .is_some_and(|assistant| assistant.editing)
+            || self
+                .resource_dialog
+                .as_ref()
+                .is_some_and(|dialog| dialog.editing)
+            || self.rename_dialog.is_some()
+            || self
+                .ports
+                .view
+                .as_ref()
+                .is_some_and(|view| view.forward_input.is_some())
+            || self.query.search.editing
+            || self.query.filter_bar.is_some()
+    }
+
+    /// Send a raw key to the active text field
+    /// アクティブなテキストフィールドに生のキーを送る
+    ///
+    /// `Ctrl+C` still quits. Typing a search query filters the list live and
+    /// selects the first match; `Enter` in the filter bar applies or saves the
+    /// expression. Confirming a cleanup criterion with `Enter`
+    /// reloads the assistant's candidates, `Enter` in the rename dialog
+    /// renames the container, and `Enter` in the ports view's forward field
+    /// starts the forward.
+    /// `Ctrl+C`は引き続き終了します。検索クエリの入力は一覧をその場で絞り込み、
+    /// 最初の一致を選択します。フィルタバーの`Enter`は式を適用または保存します。
+    /// クリーンアップ基準を`Enter`で確定すると
+    /// アシスタントの候補を再読み込みし、名前変更ダイアログの`Enter`は
+    /// コンテナの名前を変更し、ポートビューのフォワード入力欄の`Enter`は
+    /// フォワードを開始します。
+    pub async fn handle_text_input(&mut self, key_event: KeyEvent) {
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.force_quit();
+            return;
+        }
+        self.last_activity = Instant::now();
+
+        if let Some(bar) = self.query.filter_bar.as_mut() {
+            match bar.handle_key(key_event, &self.query.saved_filters) {
+                FilterBarKey::Edited => {}
+                FilterBarKey::Apply => self.apply_filter_expression(),
+                FilterBarKey::Save => self.save_filter_expression(),
+                FilterBarKey::Cancel => self.query.filter_bar = None,
+            }
+        } else if self.query.search.editing {
+            let outcome = self.query.search.handle_key(key_event);
+            self.apply_filters();
+            if outcome == SearchKey::Edited {
+                self.selected_index = 0;
+            }
+        } else if let Some(dialog) = self.cleanup.prune_dialog.as_mut() {
+            dialog.handle_text_key(key_event);
+        } else if let Some(dialog) = self.copy_dialog.as_mut() {
+            dialog.handle_text_key(key_event);
+        } else if let Some(dialog) = self.resource_dialog.as_mut() {
+            dialog.handle_text_key(key_event);
+        } else if let Some(dialog) = self.rename_dialog.as_mut() {
+            match dialog.handle_key(key_event) {
+                RenameKey::Edited => {}
+                RenameKey::Cancel => self.close_rename_dialog(),
+                RenameKey::Submit => {
+                    // A failed refresh is already shown as the error view
+                    // 更新の失敗は既にエラービューとして表示されている
+                    let _ = self.rename_dialog_enter().await;
+                }
+            }
+        } else if let Some(view) = self.ports.view.as_mut()
+            && let Some(input) = view.forward_input.as_mut()
+        {
+            match input.handle_key(key_event) {
+                ForwardKey::Edited => {}
+                ForwardKey::Cancel => view.forward_input = None,
+                ForwardKey::Submit => self.submit_forward_input().await,
+            }
+        } else if let Some(assistant) = self.cleanup.assistant.as_mut()
+            && assistant.handle_text_key(key_event)
+        {
+            self.reload_cleanup_candidates().await;
+        }
+    }
+
+    /// Show or hide the process pane of the selected container
+    /// 選択中のコンテナのプロセスペインを表示または非表示
+    ///
+    /// The processes are loaded by the next `refresh_process_pane_if_due`.
+    /// プロセスは次の`refresh_process_pane_if_due`で読み込まれます。
+    pub fn toggle_process_pane(&mut self) {
+        self.cancel_process_refresh();
+        self.process_pane = match self.process_pane {
+            Some(_) => None,
+            None => Some(ProcessPane::new()),
+        };
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the process pane is shown
+    /// プロセスペインが表示されているかチェック
+    #[must_use]
+    pub const fn is_process_pane_open(&self) -> bool {
+        self.process_pane.is_some()
+    }
+
+    /// Reload the process pane for the selected container
+    /// 選択中のコンテナについてプロセスペインを再読み込み
+    ///
+    /// The engine is only asked about running containers, in a background
+    /// task collected by `refresh_process_pane_if_due`; errors are shown
+    /// inside the pane.
+    /// エンジンへの問い合わせは実行中のコンテナのみで、`refresh_process_pane_if_due`が
+    /// 回収するバックグラウンドタスクで行われます。エラーはペイン内に表示されます。
+    pub fn refresh_process_pane(&mut self) {
+        if self.process_pane.is_none() {
+            return;
+        }
+        self.cancel_process_refresh();
+        let now = Instant::now();
+        let container = self.selected_container().cloned();
+
+        match container {
+            Some(container) if container.is_running() => {
+                let repository = self.repository_for(&container.id);
+                let id = container.id.clone();
+                let task = tokio::spawn(async move { repository.top_processes(&id).await });
+                self.process_task = Some((container, task));
+            }
+            Some(container) => {
+                let message = format!(
+                    "{} is not running ({})",
+                    container.display_name(),
+                    container.status
+                );
+                if let Some(pane) = self.process_pane.as_mut() {
+                    pane.show_message(Some(&container), message, now);
+                }
+            }
+            None => {
+                if let Some(pane) = self.process_pane.as_mut() {
+                    pane.show_message(None, "No container selected", now);
+                }
+            }
+        }
+    }
+
+    /// Stop a process listing still in flight
+    /// 実行中のプロセス一覧取得を中止
+    fn cancel_process_refresh(&mut self) {
+        if let Some((_, task)) = self.process_task.take() {
+            task.abort();
+        }
+    }
+
+    /// Collect a finished process listing and reload the pane when due
+    /// 終了したプロセス一覧取得を回収し、必要ならペインを再読み込み
+    ///
+    /// Called on every pass of the event loop; only reloads while the
+    /// container list is shown, the selection changed or the pane is stale,
+    /// and no listing is in flight.
+    /// イベントループの毎回の処理で呼び出され、コンテナリスト表示中で、選択が
+    /// 変わったかペインが古くなり、取得中でない場合のみ再読み込みします。
+    pub async fn refresh_process_pane_if_due(&mut self, now: Instant) {
+        if let Some((container, task)) = self.process_task.take_if(|(_, task)| task.is_finished()) {
+            let result = task
+                .await
+                .unwrap_or_else(|error| Err(DockaError::internal(error.to_string())));
+            if let Some(pane) = self.process_pane.as_mut() {
+                pane.show(&container, result, now);
+            }
+        }
+
+        let selected = self.selected_container().map(|container| &container.id);
+        let due = self
+            .process_pane
+            .as_ref()
+            .is_some_and(|pane| pane.is_due(selected, now));
+        let in_flight = self
+            .process_task
+            .as_ref()
+            .is_some_and(|(container, _)| Some(&container.id) == selected);
+
+        if due && !in_flight && self.view_state == ViewState::ContainerList {
+            self.refresh_process_pane();
+        }
+    }
+
+    /// Show the filesystem changes of the selected container
+    /// 選択中のコンテナのファイルシステム変更を表示
+    ///
+    /// Does nothing when no container is selected.
+    /// コンテナが選択されていない場合は何もしません。
+    pub async fn open_diff_view(&mut self) {
+        let Some(container) = self.selected_container() else {
+            return;
+        };
+        self.diff_view = Some(DiffView::new(container));
+        self.view_state = ViewState::FilesystemChanges;
+        self.reload_diff_view().await;
+    }
+
+    /// Load the changes of the container shown in the diff view again
+    /// 変更ビューに表示中のコンテナの変更を再度読み込み
+    ///
+    /// Engine errors are shown in the view.
+    /// エンジンエラーはビュー内に表示されます。
+    pub async fn reload_diff_view(&mut self) {
+        let repository = self
+            .diff_view
+            .as_ref()
+            .map(|view| self.repository_for(&view.container_id));
+        let (Some(view), Some(repository)) = (self.diff_view.as_mut(), repository) else {
+            return;
+        };
+        self.last_activity = Instant::now();
+        let result = repository.container_changes(&view.container_id).await;
+        view.show(result);
+    }
+
+    /// Leave the diff view and return to the container list
+    /// 変更ビューを閉じてコンテナリストに戻る
+    pub fn close_diff_view(&mut self) {
+        self.diff_view = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the diff view is shown
+    /// 変更ビューが表示されているかチェック
+    #[must_use]
+    pub const fn is_diff_view_open(&self) -> bool {
+        matches!(self.view_state, ViewState::FilesystemChanges)
+    }
+
+    /// Move the diff view highlight
+    /// 変更ビューの選択を移動
+    pub fn navigate_diff_view(&mut self, direction: NavigationDirection) {
+        if let Some(view) = self.diff_view.as_mut() {
+            view.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Expand or collapse the highlighted directory of the diff view
+    /// 変更ビューで選択中のディレクトリを展開または折りたたむ
+    pub fn toggle_diff_node(&mut self) {
+        if let Some(view) = self.diff_view.as_mut() {
+            view.toggle_highlighted();
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Open the file browser at the root of the selected container
+    /// 選択中のコンテナのルートでファイルブラウザを開く
+    pub async fn open_file_browser(&mut self) {
+        let Some(container) = self.selected_container() else {
+            return;
+        };
+        self.file_browser = Some(FileBrowser::new(container));
+        self.view_state = ViewState::FileBrowser;
+        self.reload_file_browser().await;
+    }
+
+    /// Load the directory shown in the file browser again
+    /// ファイルブラウザに表示中のディレクトリを再度読み込み
+    ///
+    /// Engine errors are shown in the browser.
+    /// エンジンエラーはブラウザ内に表示されます。
+    pub async fn reload_file_browser(&mut self) {
+        let Some(path) = self
+            .file_browser
+            .as_ref()
+            .map(|browser| browser.listing.path.clone())
+        else {
+            return;
+        };
+        self.open_in_file_browser(&path).await;
+    }
+
+    /// Open the highlighted directory or preview the highlighted file
+    /// 選択中のディレクトリを開く、または選択中のファイルをプレビュー
+    pub async fn file_browser_enter(&mut self) {
+        let Some(path) = self
+            .file_browser
+            .as_ref()
+            .and_then(FileBrowser::target_path)
+        else {
+            return;
+        };
+        self.open_in_file_browser(&path).await;
+    }
+
+    /// Open `path` in the file browser
+    /// ファイルブラウザで`path`を開く
+    async fn open_in_file_browser(&mut self, path: &str) {
+        let repository = self
+            .file_browser
+            .as_ref()
+            .map(|browser| self.repository_for(&browser.container_id));
+        let (Some(browser), Some(repository)) = (self.file_browser.as_mut(), repository) else {
+            return;
+        };
+        self.last_activity = Instant::now();
+        let service = FileBrowserService::new(repository);
+        let result = service.open(&browser.container_id, path).await;
+        browser.show(result);
+    }
+
+    /// Close an open preview, or leave the file browser
+    /// 開いているプレビューを閉じる、またはファイルブラウザを離れる
+    pub fn file_browser_back(&mut self) {
+        if self.file_browser.as_mut().is_some_and(FileBrowser::back) {
+            self.close_file_browser();
+        } else {
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Leave the file browser and return to the container list
+    /// ファイルブラウザを閉じてコンテナリストに戻る
+    pub fn close_file_browser(&mut self) {
+        self.file_browser = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the file browser is shown
+    /// ファイルブラウザが表示されているかチェック
+    #[must_use]
+    pub const fn is_file_browser_open(&self) -> bool {
+        matches!(self.view_state, ViewState::FileBrowser)
+    }
+
+    /// Move the file browser highlight or scroll its preview
+    /// ファイルブラウザの選択を移動、またはプレビューをスクロール
+    pub fn navigate_file_browser(&mut self, direction: NavigationDirection) {
+        if let Some(browser) = self.file_browser.as_mut() {
+            browser.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Show the inspect details of the selected container
+    /// 選択中のコンテナのinspect詳細を表示
+    ///
+    /// Does nothing when no container is selected.
+    /// コンテナが選択されていない場合は何もしません。
+    pub async fn open_details_view(&mut self) {
+        let Some(container) = self.selected_container() else {
+            return;
+        };
+        self.details_view = Some(DetailsView::new(container));
+        self.view_state = ViewState::ContainerDetails;
+        self.reload_details_view().await;
+    }
+
+    /// Inspect the container shown in the details view again
+    /// 詳細ビューに表示中のコンテナを再度調査
+    ///
+    /// Exits are polled first so the restart timeline is current. Engine
+    /// errors are shown in the view.
+    /// 再起動のタイムラインが最新になるよう先に終了をポーリングします。
+    /// エンジンエラーはビュー内に表示されます。
+    pub async fn reload_details_view(&mut self) {
+        if self.details_view.is_none() {
+            return;
+        }
+        self.poll_container_exits(Utc::now()).await;
+        let repository = self
+            .details_view
+            .as_ref()
+            .map(|view| self.repository_for(&view.container_id));
+        let (Some(view), Some(repository)) = (self.details_view.as_mut(), repository) else {
+            return;
+        };
+        self.last_activity = Instant::now();
+        let result = repository.container_details(&view.container_id).await;
+        view.exits = self.exit_history.exits_for(&view.container_id).to_vec();
+        view.show(result);
+    }
+
+    /// Leave the details view and return to the container list
+    /// 詳細ビューを閉じてコンテナリストに戻る
+    pub fn close_details_view(&mut self) {
+        self.details_view = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the details view is shown
+    /// 詳細ビューが表示されているかチェック
+    #[must_use]
+    pub const fn is_details_view_open(&self) -> bool {
+        matches!(self.view_state, ViewState::ContainerDetails)
+    }
+
+    /// Scroll the details view
+    /// 詳細ビューをスクロール
+    pub fn navigate_details_view(&mut self, direction: NavigationDirection) {
+        if let Some(view) = self.details_view.as_mut() {
+            view.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Show the label keys and values of all containers
+    /// 全コンテナのラベルのキーと値を表示
+    ///
+    /// The labels come from an unfiltered listing, so a label filter can be
+    /// replaced by another value of the same key.
+    /// ラベルは絞り込まれていない一覧から取得するため、ラベルのフィルタを
+    /// 同じキーの別の値に置き換えられます。
+    pub async fn open_label_browser(&mut self) {
+        self.label_browser = Some(LabelBrowser::new());
+        self.view_state = ViewState::Labels;
+        self.reload_label_browser().await;
+    }
+
+    /// List the containers of the label browser again
+    /// ラベルブラウザのコンテナを再度一覧取得
+    ///
+    /// Engine errors are shown in the browser.
+    /// エンジンエラーはブラウザ内に表示されます。
+    pub async fn reload_label_browser(&mut self) {
+        if self.label_browser.is_none() {
+            return;
+        }
+        let result = self.docker_repository.list_containers().await;
+        if let Some(browser) = self.label_browser.as_mut() {
+            browser.show(result);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Leave the label browser and return to the container list
+    /// ラベルブラウザを閉じてコンテナリストに戻る
+    pub fn close_label_browser(&mut self) {
+        self.label_browser = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the label browser is shown
+    /// ラベルブラウザが表示されているかチェック
+    #[must_use]
+    pub const fn is_label_browser_open(&self) -> bool {
+        matches!(self.view_state, ViewState::Labels)
+    }
+
+    /// Move the cursor of the label browser
+    /// ラベルブラウザのカーソルを移動
+    pub fn navigate_label_browser(&mut self, direction: NavigationDirection) {
+        if let Some(browser) = self.label_browser.as_mut() {
+            browser.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Filter the list by the highlighted label value, then refresh
+    /// 選択中のラベル値で一覧を絞り込んで更新
+    ///
+    /// The value replaces a filter on the same key; selecting the value
+    /// already filtered by removes that filter. The browser is closed.
+    /// 値は同じキーのフィルタを置き換え、既に絞り込み中の値を選択すると
+    /// そのフィルタを解除します。ブラウザは閉じられます。
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the refresh (see `refresh_containers`).
+    /// 更新のエラーを返します（`refresh_containers`を参照）。
+    pub async fn toggle_highlighted_label_filter(&mut self) -> DockaResult<()> {
+        let Some(row) = self
+            .label_browser
+            .as_ref()
+            .and_then(LabelBrowser::highlighted)
+            .cloned()
+        else {
+            return Ok(());
+        };
+        if self.query.filter.labels.get(&row.key) == Some(&row.value) {
+            self.query.filter.labels.remove(&row.key);
+        } else {
+            self.query.filter.labels.insert(row.key, row.value);
+        }
+        self.close_label_browser();
+        self.refresh_containers().await
+    }
+
+    /// Group the list by the key of the highlighted label, or ungroup it
+    /// 選択中のラベルのキーで一覧をグループ化、またはグループ化を解除
+    ///
+    /// Selecting the key the list is already grouped by ungroups it. The
+    /// browser is closed.
+    /// 既にグループ化中のキーを選択するとグループ化を解除します。
+    /// ブラウザは閉じられます。
+    pub fn group_by_highlighted_label(&mut self) {
+        let Some(key) = self
+            .label_browser
+            .as_ref()
+            .and_then(LabelBrowser::highlighted)
+            .map(|row| row.key.clone())
+        else {
+            return;
+        };
+        self.close_label_browser();
+        if self.query.group_by_label.as_ref() == Some(&key) {
+            self.set_group_by_label(None);
+        } else {
+            self.set_group_by_label(Some(key));
+        }
+    }
+
+    /// Open the resource dialog prefilled from the selected container
+    /// 選択中のコンテナの値で事前入力したリソースダイアログを開く
+    ///
+    /// # Errors
+    /// * Any error returned by `DockerRepository::container_resources`
+    pub async fn open_resource_dialog(&mut self) -> DockaResult<()> {
+        let Some(container) = self.selected_container().cloned() else {
+            return Ok(());
+        };
+        match self
+            .repository_for(&container.id)
+            .container_resources(&container.id)
+            .await
+        {
+            Ok(limits) => {
+                self.resource_dialog = Some(ResourceDialog::new(&container, limits));
+                self.last_activity = Instant::now();
+                Ok(())
+            }
+            Err(error) => {
+                self.set_error_state(error.user_message());
+                Err(error)
+            }
+        }
+    }
+
+    /// Close the resource dialog without applying anything
+    /// 何も適用せずにリソースダイアログを閉じる
+    pub fn close_resource_dialog(&mut self) {
+        self.resource_dialog = None;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the resource dialog is open
+    /// リソースダイアログが開いているかチェック
+    #[must_use]
+    pub const fn is_resource_dialog_open(&self) -> bool {
+        self.resource_dialog.is_some()
+    }
+
+    /// Move the resource dialog highlight
+    /// リソースダイアログの選択を移動
+    pub fn navigate_resource_dialog(&mut self, direction: NavigationDirection) {
+        if let Some(dialog) = self.resource_dialog.as_mut() {
+            dialog.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Advance the resource dialog on `Enter`
+    /// `Enter`でリソースダイアログを進める
+    ///
+    /// The apply row sends the limits and closes the dialog; validation and
+    /// engine errors are shown in the dialog so the input can be corrected.
+    /// 適用行は制限を送信してダイアログを閉じます。検証エラーとエンジンエラーは
+    /// 入力を修正できるようダイアログ内に表示されます。
+    ///
+    /// # Errors
+    /// * Any error returned by the refresh after a successful update
+    pub async fn resource_dialog_enter(&mut self) -> DockaResult<()> {
+        let repository = self
+            .resource_dialog
+            .as_ref()
+            .map(|dialog| self.repository_for(&dialog.container_id));
+        let (Some(dialog), Some(repository)) = (self.resource_dialog.as_mut(), repository) else {
+            return Ok(());
+        };
+        self.last_activity = Instant::now();
+        if !dialog.activate_field() {
+            return Ok(());
+        }
+
+        let result = match dialog.limits() {
+            Ok(limits) => {
+                repository
+                    .update_container(&dialog.container_id, &limits)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(()) => {
+                self.close_resource_dialog();
+                self.refresh_containers().await
+            }
+            Err(error) => {
+                dialog.message = Some(error.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Open the rename dialog prefilled with the selected container's name
+    /// 選択中のコンテナの名前で事前入力した名前変更ダイアログを開く
+    pub fn open_rename_dialog(&mut self) {
+        if let Some(container) = self.selected_container() {
+            self.rename_dialog = Some(RenameDialog::new(container));
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Close the rename dialog without renaming
+    /// 名前を変更せずに名前変更ダイアログを閉じる
+    pub fn close_rename_dialog(&mut self) {
+        self.rename_dialog = None;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the rename dialog is open
+    /// 名前変更ダイアログが開いているかチェック
+    #[must_use]
+    pub const fn is_rename_dialog_open(&self) -> bool {
+        self.rename_dialog.is_some()
+    }
+
+    /// Rename the container to the name typed in the dialog
+    /// ダイアログに入力された名前にコンテナの名前を変更
+    ///
+    /// Invalid or taken names are shown in the dialog so they can be
+    /// corrected; an unchanged name just closes it.
+    /// 無効または使用中の名前は修正できるようダイアログ内に表示され、
+    /// 変更のない名前の場合はダイアログを閉じるだけです。
+    ///
+    /// # Errors
+    /// * Any error returned by the refresh after a successful rename
+    pub async fn rename_dialog_enter(&mut self) -> DockaResult<()> {
+        let repository = self
+            .rename_dialog
+            .as_ref()
+            .map(|dialog| self.repository_for(&dialog.container_id));
+        let (Some(dialog), Some(repository)) = (self.rename_dialog.as_mut(), repository) else {
+            return Ok(());
+        };
+        self.last_activity = Instant::now();
+
+        let result = match dialog.new_name() {
+            Ok(Some(name)) => repository
+                .rename_container(&dialog.container_id, &name)
+                .await
+                .map(|()| true),
+            Ok(None) => Ok(false),
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(renamed) => {
+                self.close_rename_dialog();
+                if renamed {
+                    return self.refresh_containers().await;
+                }
+                Ok(())
+            }
+            Err(error) => {
+                dialog.message = Some(error.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Open the restart policy picker on the selected container's current policy
+    /// 選択中のコンテナの現在のポリシーで再起動ポリシーピッカーを開く
+    ///
+    /// # Errors
+    /// * Any error returned by `DockerRepository::container_resources`
+    pub async fn open_restart_policy_picker(&mut self) -> DockaResult<()> {
+        let Some(container) = self.selected_container().cloned() else {
+            return Ok(());
+        };
+        match self
+            .repository_for(&container.id)
+            .container_resources(&container.id)
+            .await
+        {
+            Ok(limits) => {
+                self.restart_policy_picker = Some(RestartPolicyPicker::new(&container, limits));
+                self.last_activity = Instant::now();
+                Ok(())
+            }
+            Err(error) => {
+                self.set_error_state(error.user_message());
+                Err(error)
+            }
+        }
+    }
+
+    /// Close the restart policy picker without changing anything
+    /// 何も変更せずに再起動ポリシーピッカーを閉じる
+    pub fn close_restart_policy_picker(&mut self) {
+        self.restart_policy_picker = None;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the restart policy picker is open
+    /// 再起動ポリシーピッカーが開いているかチェック
+    #[must_use]
+    pub const fn is_restart_policy_picker_open(&self) -> bool {
+        self.restart_policy_picker.is_some()
+    }
+
+    /// Move the restart policy picker highlight (circular)
+    /// 再起動ポリシーピッカーの選択を移動（循環）
+    pub fn navigate_restart_policy_picker(&mut self, direction: NavigationDirection) {
+        if let Some(picker) = self.restart_policy_picker.as_mut() {
+            picker.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Apply the highlighted restart policy through a live update
+    /// 選択中の再起動ポリシーをライブ更新で適用
+    ///
+    /// # Errors
+    /// * Any error returned by `DockerRepository::update_container` or the refresh
+    pub async fn apply_restart_policy(&mut self) -> DockaResult<()> {
+        let Some(picker) = self.restart_policy_picker.take() else {
+            return Ok(());
+        };
+        self.last_activity = Instant::now();
+        let limits = picker.limits();
+        if limits == picker.current {
+            return Ok(());
+        }
+
+        if let Err(error) = self
+            .repository_for(&picker.container_id)
+            .update_container(&picker.container_id, &limits)
+            .await
+        {
+            self.set_error_state(error.user_message());
+            return Err(error);
+        }
+
+        self.refresh_containers().await
+    }
+
+    /// Check whether a container row comes from an unreachable host
+    /// コンテナ行が到達不能なホストからのものかチェック
+    #[must_use]
+    pub fn is_stale(&self, container: &Container) -> bool {
+        container
+            .host
+            .as_ref()
+            .is_some_and(|host| self.stale_hosts.contains(host))
+    }
+
+    /// Record the container exits reported since the last poll
+    /// 前回のポーリング以降に報告されたコンテナの終了を記録
+    ///
+    /// The first poll looks back over the crash-loop window. Later polls
+    /// overlap the previous one by a second because the engine filters
+    /// events by whole seconds; duplicates are ignored by the history.
+    /// Failures are ignored: the container list reports unreachable engines.
+    ///
+    /// 最初のポーリングはクラッシュループの期間を遡ります。エンジンはイベントを
+    /// 秒単位で絞り込むため、以降のポーリングは前回と1秒重ねます（重複は履歴が無視します）。
+    /// 失敗は無視されます（到達不能なエンジンはコンテナリストが報告します）。
+    pub async fn poll_container_exits(&mut self, now: DateTime<Utc>) {
+        let since = self.exits_polled_at.map_or_else(
+            || now - self.crash_loop_policy.window,
+            |polled_at| polled_at - Duration::seconds(1),
+        );
+        if let Ok(exits) = self.docker_repository.container_exits(since).await {
+            self.exit_history.record(exits);
+            self.exits_polled_at = Some(now);
+        }
+    }
+
+    /// Poll container exits in the background when `EXIT_POLL_INTERVAL` has passed
+    /// `EXIT_POLL_INTERVAL`が経過した場合にバックグラウンドでコンテナの終了をポーリング
+    ///
+    /// Called on every pass of the event loop: records the result of a
+    /// finished poll, then starts the next one when due, so a slow engine
+    /// never holds up input.
+    /// イベントループの毎回の処理で呼び出されます。終了したポーリングの結果を記録し、
+    /// 期限が来ていれば次を開始するため、遅いエンジンが入力を止めることはありません。
+    pub async fn poll_container_exits_if_due(&mut self, now: DateTime<Utc>) {
+        if let Some(task) = self.exit_poll.take_if(|task| task.is_finished())
+            && let Ok(Ok(exits)) = task.await
+        {
+            self.exit_history.record(exits);
+            self.exits_polled_at = self.exits_attempted_at;
+        }
+
+        let due = self.exit_poll.is_none()
+            && self
+                .exits_attempted_at
+                .is_none_or(|attempted_at| now - attempted_at >= EXIT_POLL_INTERVAL);
+        if due {
+            let since = self.exits_polled_at.map_or_else(
+                || now - self.crash_loop_policy.window,
+                |polled_at| polled_at - Duration::seconds(1),
+            );
+            let repository = Arc::clone(&self.docker_repository);
+            self.exits_attempted_at = Some(now);
+            self.exit_poll = Some(tokio::spawn(async move {
+                repository.container_exits(since).await
+            }));
+        }
+    }
+
+    /// Number of exits of a container within the crash-loop window
+    /// クラッシュループ期間内のコンテナの終了回数
+    #[must_use]
+    pub fn recent_exits(&self, id: &ContainerId) -> usize {
+        self.exit_history
+            .recent_exits(id, self.crash_loop_policy, Utc::now())
+    }
+
+    /// Check whether a container exited more often than the crash-loop policy allows
+    /// コンテナがクラッシュループポリシーの許容回数を超えて終了したかチェック
+    #[must_use]
+    pub fn is_crash_looping(&self, id: &ContainerId) -> bool {
+        self.exit_history
+            .is_crash_looping(id, self.crash_loop_policy, Utc::now())
+    }
+
+    /// Apply the user settings read from the configuration file
+    /// 設定ファイルから読み込んだユーザー設定を適用
+    pub fn apply_config(&mut self, config: &AppConfig) {
+        self.time_display = config.time_display;
+        self.timezone = config.timezone;
+        self.secret_mask = config.secret_patterns.clone();
+        self.query.saved_filters = config.saved_filters.clone();
+    }
+
+    /// Show or mask secret values in the details view
+    /// 詳細ビューのシークレットの値の表示とマスクを切り替え
+    ///
+    /// Secrets are masked again whenever the details view is reopened.
+    /// 詳細ビューを開き直すたびにシークレットは再びマスクされます。
+    pub fn toggle_reveal_secrets(&mut self) {
+        if let Some(view) = self.details_view.as_mut() {
+            view.reveal_secrets = !view.reveal_secrets;
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Switch uptimes and exit times between relative and absolute
+    /// 稼働時間と終了時刻を相対表示と日時表示で切り替え
+    pub fn toggle_time_display(&mut self) {
+        self.time_display = self.time_display.toggled();
+        self.last_activity = Instant::now();
+    }
+
+    /// Replace the Docker repository after switching endpoints
+    /// エンドポイント切り替え後に`Docker`リポジトリを置き換え
+    ///
+    /// Containers from the previous endpoint are discarded; call
+    /// `refresh_containers` afterwards to load the new endpoint's containers.
+    ///
+    /// 前のエンドポイントのコンテナは破棄されます。新しいエンドポイントの
+    /// コンテナを読み込むには、その後`refresh_containers`を呼び出してください。
+    pub fn switch_repository(
+        &mut self,
+        docker_repository: Arc<dyn DockerRepository>,
+        endpoint_name: impl Into<String>,
+    ) {
+        self.docker_repository = docker_repository;
+        self.endpoint_name = endpoint_name.into();
+        self.detected_engine = self.detected_engines.get(&self.endpoint_name).cloned();
+        self.containers.clear();
+        self.listed_containers.clear();
+        self.cancel_container_stats();
+        self.stale_hosts.clear();
+        self.capabilities = EngineCapabilities::unknown();
+        self.selected_index = 0;
+        self.context_switcher_index = None;
+        self.action_menu_index = None;
+        self.signal_picker_index = None;
+        self.system_overview = None;
+        self.cleanup.prune_dialog = None;
+        self.cleanup.assistant = None;
+        self.cancel_process_refresh();
+        if self.process_pane.is_some() {
+            self.process_pane = Some(ProcessPane::new());
+        }
+        self.diff_view = None;
+        if let Some(dialog) = self.copy_dialog.as_mut() {
+            dialog.back();
+        }
+        self.copy_dialog = None;
+        self.file_browser = None;
+        self.details_view = None;
+        self.ports.view = None;
+        self.label_browser = None;
+        self.resource_dialog = None;
+        self.rename_dialog = None;
+        self.restart_policy_picker = None;
+        self.exit_history = ExitHistory::new();
+        self.exits_polled_at = None;
+        if let Some(task) = self.exit_poll.take() {
+            task.abort();
+        }
+        self.exits_attempted_at = None;
+        self.view_state = ViewState::Loading;
+        self.last_error = None;
+        self.last_activity = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::ContainerStats;
+    use crate::domain::{ContainerBuilder, ContainerId, ContainerStatus};
+    use crate::ui::filter_bar::FilterBar;
+    use crate::ui::list_sort::SortKey;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    fn create_test_app() -> App {
+        let mock_repo = Arc::new(MockDockerRepository::new());
+        App::new(mock_repo)
+    }
+
+    async fn settle_stats(app: &mut App) {
+        while app.query.stats_task.is_some() {
+            tokio::task::yield_now().await;
+            app.poll_container_stats().await;
+        }
+    }
+
+    async fn settle_processes(app: &mut App, now: Instant) {
+        app.refresh_process_pane_if_due(now).await;
+        while app.process_task.is_some() {
+            tokio::task::yield_now().await;
+            app.refresh_process_pane_if_due(now).await;
+        }
+    }
+
+    async fn settle_exits(app: &mut App, now: DateTime<Utc>) {
+        app.poll_container_exits_if_due(now).await;
+        while app.exit_poll.is_some() {
+            tokio::task::yield_now().await;
+            app.poll_container_exits_if_due(now).await;
+        }
+    }
+
+    fn create_test_container(id: &str, name: &str) -> Container {
+        ContainerBuilder::new()
+            .id(ContainerId::new(id).unwrap())
+            .name(name.to_string())
+            .image("test:latest".to_string())
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_app_new() {
+        let app = create_test_app();
+        assert!(app.running);
+        assert!(!app.should_quit);
+        assert_eq!(app.containers.len(), 0);
+        assert_eq!(app.selected_index, 0);
+        assert_eq!(app.view_state, ViewState::Loading);
+        assert!(app.last_error.is_none());
+        assert!(app.last_activity.elapsed().as_secs() < 1);
+    }
+
+    #[test]
+    fn test_needs_redraw() {
+        let mut app = create_test_app();
+
+        // 初期状態では再描画不要（100ms経過済み）
+        std::thread::sleep(std::time::Duration::from_millis(110));
+        assert!(!app.needs_redraw());
+
+        // アクティビティ更新後は再描画必要
+        app.last_activity = Instant::now();
+        assert!(app.needs_redraw());
+    }
+
+    #[test]
+    fn test_navigation_updates_activity() {
+        let mut app = create_test_app();
+        app.containers = vec![create_test_container("1", "test1")];
+
+        let initial_activity = app.last_activity;
+
+        // 小さな遅延で確実にタイムスタンプが変わることを保証
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        app.select_next();
+
+        // アクティビティタイムスタンプが更新されているべき
+        assert!(app.last_activity > initial_activity);
+    }
+
+    #[test]
+    fn test_navigation_empty_list() {
+        let mut app = create_test_app();
+
+        // Should not panic with empty list
+        app.select_next();
+        assert_eq!(app.selected_index, 0);
+
+        app.select_previous();
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_navigation_single_item() {
+        let mut app = create_test_app();
+        app.containers = vec![create_test_container("1", "test1")];
+        app.selected_index = 0;
+
+        // Should stay at 0 for single item
+        app.select_next();
+        assert_eq!(app.selected_index, 0);
+
+        app.select_previous();
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_navigation_multiple_items() {
+        let mut app = create_test_app();
+        app.containers = vec![
+            create_test_container("1", "test1"),
+            create_test_container("2", "test2"),
+            create_test_container("3", "test3"),
+        ];
+        app.selected_index = 0;
+
+        // Test forward navigation
+        app.select_next();
+        assert_eq!(app.selected_index, 1);
+
+        app.select_next();
+        assert_eq!(app.selected_index, 2);
+
+        // Test wrap around forward
+        app.select_next();
+        assert_eq!(app.selected_index, 0);
+
+        // Test backward navigation
+        app.select_previous();
+        assert_eq!(app.selected_index, 2);
+
+        app.select_previous();
+        assert_eq!(app.selected_index, 1);
+
+        app.select_previous();
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_selected_container() {
+        let mut app = create_test_app();
+
+        // Empty list
+        assert!(app.selected_container().is_none());
+
+        // With containers
+        let container1 = create_test_container("1", "test1");
+        let container2 = create_test_container("2", "test2");
+        app.containers = vec![container1.clone(), container2.clone()];
+
+        app.selected_index = 0;
+        assert_eq!(app.selected_container().unwrap().id, container1.id);
+
+        app.selected_index = 1;
+        assert_eq!(app.selected_container().unwrap().id, container2.id);
+
+        // Out of bounds
+        app.selected_index = 999;
+        assert!(app.selected_container().is_none());
+    }
+
+    #[test]
+    fn test_app_lifecycle() {
+        let mut app = create_test_app();
+
+        assert!(app.is_running());
+
+        app.quit();
+        assert!(!app.is_running());
+
+        // Reset for force quit test
+        app.should_quit = false;
+        assert!(app.is_running());
+
+        app.force_quit();
+        assert!(!app.is_running());
+        assert!(!app.running);
     }
 
     #[test]
@@ -935,6 +2559,1409 @@ mod tests {
             panic!("Expected Error state");
         }
     }
+
+    #[test]
+    fn test_context_switcher_navigation() {
+        // Test opening, navigating and closing the context switcher
+        // コンテキストスイッチャーの開閉とナビゲーションをテスト
+        let mut app = create_test_app();
+
+        // Nothing to switch to yet
+        // 切り替え先がまだない
+        app.open_context_switcher();
+        assert!(!app.is_context_switcher_open());
+
+        app.set_available_endpoints(
+            vec![
+                "default".to_string(),
+                "build-01".to_string(),
+                "build-02".to_string(),
+            ],
+            "build-01",
+        );
+        app.open_context_switcher();
+        assert_eq!(app.highlighted_endpoint(), Some("build-01"));
+
+        app.navigate_context_switcher(NavigationDirection::Next);
+        assert_eq!(app.highlighted_endpoint(), Some("build-02"));
+        app.navigate_context_switcher(NavigationDirection::Next);
+        assert_eq!(app.highlighted_endpoint(), Some("default"));
+        app.navigate_context_switcher(NavigationDirection::Previous);
+        assert_eq!(app.highlighted_endpoint(), Some("build-02"));
+
+        app.close_context_switcher();
+        assert!(!app.is_context_switcher_open());
+        assert_eq!(app.endpoint_name, "build-01");
+    }
+
+    #[tokio::test]
+    async fn test_switch_repository_replaces_containers() {
+        // Test that switching repositories discards the old endpoint's containers
+        // リポジトリ切り替えで古いエンドポイントのコンテナが破棄されることをテスト
+        let mut app = create_test_app();
+        app.containers = vec![create_test_container("old-1", "old")];
+        app.selected_index = 0;
+        app.set_available_endpoints(vec!["default".to_string(), "remote".to_string()], "default");
+        app.open_context_switcher();
+
+        let remote_repo = MockDockerRepository::new();
+        remote_repo
+            .add_container(create_test_container("remote-1", "remote-a"))
+            .await;
+        remote_repo
+            .add_container(create_test_container("remote-2", "remote-b"))
+            .await;
+
+        app.switch_repository(Arc::new(remote_repo), "remote");
+        assert_eq!(app.endpoint_name, "remote");
+        assert!(app.containers.is_empty());
+        assert!(!app.is_context_switcher_open());
+        assert_eq!(app.view_state, ViewState::Loading);
+
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 2);
+        assert_eq!(app.containers[0].name, "remote-a");
+    }
+
+    #[test]
+    fn test_detected_engine_is_restored_on_switch_back() {
+        // Test that the detected engine follows its endpoint across switches
+        // 検出されたエンジンが切り替えをまたいでエンドポイントに追従することをテスト
+        let mut app = create_test_app();
+        app.set_available_endpoints(vec!["default".to_string(), "remote".to_string()], "default");
+        app.remember_detected_engine("default", "rootless Podman");
+        assert_eq!(app.detected_engine.as_deref(), Some("rootless Podman"));
+
+        app.switch_repository(Arc::new(MockDockerRepository::new()), "remote");
+        assert_eq!(app.detected_engine, None);
+
+        app.switch_repository(Arc::new(MockDockerRepository::new()), "default");
+        assert_eq!(app.detected_engine.as_deref(), Some("rootless Podman"));
+    }
+
+    #[tokio::test]
+    async fn test_action_menu_runs_action_and_refreshes() {
+        // Test that running a menu entry updates the container through the repository
+        // メニュー項目の実行がリポジトリ経由でコンテナを更新することをテスト
+        use crate::ui::actions::ContainerAction;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_action_menu();
+        assert!(app.is_action_menu_open());
+        assert_eq!(
+            app.action_menu_entries()[app.action_menu_index.unwrap()].action,
+            ContainerAction::Stop
+        );
+
+        app.run_highlighted_action().await.unwrap();
+        assert!(!app.is_action_menu_open());
+        assert_eq!(app.containers[0].status, ContainerStatus::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_action_menu_blocks_unsupported_action() {
+        // Test that unsupported actions report the reason without calling the engine
+        // サポートされないアクションがエンジンを呼ばずに理由を報告することをテスト
+        use crate::domain::value_objects::{ApiVersion, EngineFeature};
+        use crate::ui::actions::ContainerAction;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        repo.set_capabilities(
+            EngineCapabilities::new("20.10.7", "linux", ApiVersion::new(1, 41))
+                .with_unsupported(EngineFeature::Pause, "rootless engine on cgroup v1"),
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.capabilities.api_version, Some(ApiVersion::new(1, 41)));
+
+        app.open_action_menu();
+        let pause_index = app
+            .action_menu_entries()
+            .iter()
+            .position(|entry| entry.action == ContainerAction::Pause)
+            .unwrap();
+        app.action_menu_index = Some(pause_index);
+
+        let result = app.run_highlighted_action().await;
+        assert!(matches!(result, Err(DockaError::Unsupported { .. })));
+        assert!(
+            matches!(&app.view_state, ViewState::Error(message) if message.contains("cgroup v1"))
+        );
+        assert_eq!(app.containers[0].status, ContainerStatus::Running);
+    }
+
+    #[tokio::test]
+    async fn test_system_overview_round_trip() {
+        // Test opening, loading and closing the system dashboard
+        // システムダッシュボードの表示・読み込み・終了をテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut app = App::new(repo.clone());
+
+        app.show_system_overview().await.unwrap();
+        assert!(app.is_system_overview_open());
+        assert_eq!(app.system_overview.as_ref().unwrap().containers.running, 1);
+
+        app.close_system_overview();
+        assert_eq!(app.view_state, ViewState::ContainerList);
+
+        repo.set_unavailable(true);
+        assert!(app.show_system_overview().await.is_err());
+        assert!(matches!(app.view_state, ViewState::Error(_)));
+    }
+
+    #[tokio::test]
+    async fn test_kill_action_sends_picked_signal() {
+        // Test that Kill opens the picker and sends the chosen signal without stopping
+        // Killがピッカーを開き、選択したシグナルを停止せずに送信することをテスト
+        use crate::ui::actions::ContainerAction;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_action_menu();
+        let kill_index = app
+            .action_menu_entries()
+            .iter()
+            .position(|entry| entry.action == ContainerAction::Kill)
+            .unwrap();
+        app.action_menu_index = Some(kill_index);
+        app.run_highlighted_action().await.unwrap();
+        assert!(!app.is_action_menu_open());
+        assert_eq!(app.highlighted_signal(), Some(Signal::Term));
+
+        app.navigate_signal_picker(NavigationDirection::Next);
+        app.navigate_signal_picker(NavigationDirection::Next);
+        assert_eq!(app.highlighted_signal(), Some(Signal::Hup));
+        app.send_highlighted_signal().await.unwrap();
+        assert!(!app.is_signal_picker_open());
+        assert_eq!(
+            repo.sent_signals().await,
+            vec![("web-1".to_string(), Signal::Hup)]
+        );
+        assert_eq!(app.containers[0].status, ContainerStatus::Running);
+
+        app.open_signal_picker();
+        app.navigate_signal_picker(NavigationDirection::Previous);
+        assert_eq!(app.highlighted_signal(), Some(Signal::Winch));
+        app.signal_picker_index = Some(1);
+        app.send_highlighted_signal().await.unwrap();
+        assert_eq!(
+            app.containers[0].status,
+            ContainerStatus::Exited { exit_code: 137 }
+        );
+
+        app.open_signal_picker();
+        let result = app.send_highlighted_signal().await;
+        assert!(matches!(result, Err(DockaError::InvalidInput { .. })));
+        assert!(matches!(app.view_state, ViewState::Error(_)));
+    }
+
+    #[tokio::test]
+    async fn test_resource_dialog_updates_limits() {
+        // Test that the dialog is prefilled, reports engine refusals and applies changes
+        // ダイアログが事前入力され、エンジンの拒否を報告し、変更を適用することをテスト
+        use crate::domain::value_objects::{ResourceLimits, RestartPolicy};
+        use crate::ui::resource_dialog::ResourceField;
+        use crate::ui::text_input::TextInput;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        repo.set_resources(
+            "web-1",
+            ResourceLimits {
+                memory: Some(256 << 20),
+                memory_swap: Some(512 << 20),
+                cpu_shares: Some(512),
+                ..ResourceLimits::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_resource_dialog().await.unwrap();
+        let dialog = app.resource_dialog.as_mut().unwrap();
+        assert_eq!(dialog.memory.value(), "256m");
+        dialog.memory = TextInput::with_value("1g");
+        dialog.cpu_shares = TextInput::new();
+        dialog.cursor = ResourceField::ALL.len() - 1;
+        app.resource_dialog_enter().await.unwrap();
+        let message = app.resource_dialog.as_ref().unwrap().message.clone();
+        assert!(message.unwrap().contains("CPU shares"));
+
+        let dialog = app.resource_dialog.as_mut().unwrap();
+        dialog.cpu_shares = TextInput::with_value("2048");
+        dialog.restart_policy = RestartPolicy::Always;
+        app.resource_dialog_enter().await.unwrap();
+        assert!(!app.is_resource_dialog_open());
+
+        let limits = repo
+            .container_resources(&ContainerId::new("web-1").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(limits.memory, Some(1 << 30));
+        assert_eq!(limits.memory_swap, Some(1_280 << 20));
+        assert_eq!(limits.cpu_shares, Some(2048));
+        assert_eq!(limits.restart_policy, RestartPolicy::Always);
+    }
+
+    #[test]
+    fn test_action_menu_requires_selection() {
+        // Test that the menu stays closed without a selected container
+        // コンテナ未選択時にメニューが開かないことをテスト
+        let mut app = create_test_app();
+        app.open_action_menu();
+        assert!(!app.is_action_menu_open());
+    }
+
+    #[tokio::test]
+    async fn test_prune_dialog_previews_confirms_and_reports() {
+        // Test that nothing is removed until the preview is confirmed twice
+        // プレビューを2回確認するまで何も削除されないことをテスト
+        use crate::ui::prune_dialog::{PruneField, PruneStage};
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut stopped = create_test_container("job-1", "job");
+        stopped.status = ContainerStatus::Stopped;
+        repo.add_container(stopped).await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_prune_dialog();
+        let dialog = app.cleanup.prune_dialog.as_mut().unwrap();
+        dialog.cursor = PruneField::all().len() - 1;
+        app.prune_dialog_enter().await.unwrap();
+        let Some(PruneStage::Preview { preview, .. }) = app
+            .cleanup
+            .prune_dialog
+            .as_ref()
+            .map(|dialog| &dialog.stage)
+        else {
+            panic!("expected the preview stage");
+        };
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].name, "job");
+
+        app.prune_dialog_enter().await.unwrap();
+        assert_eq!(repo.list_containers().await.unwrap().len(), 2);
+
+        app.prune_dialog_enter().await.unwrap();
+        assert!(matches!(
+            app.cleanup.prune_dialog.as_ref().map(|dialog| &dialog.stage),
+            Some(PruneStage::Report(report)) if report.succeeded() == 1
+        ));
+        assert_eq!(app.containers.len(), 1);
+
+        app.prune_dialog_enter().await.unwrap();
+        assert!(!app.is_prune_dialog_open());
+    }
+
+    #[tokio::test]
+    async fn test_copy_dialog_confirms_overwrite_and_reports() {
+        // Test that an existing file is only replaced after confirmation
+        // 既存ファイルは確認後にのみ置き換えられることをテスト
+        use crate::domain::entities::PathKind;
+        use crate::ui::copy_dialog::{CopyField, CopyStage};
+        use crate::ui::text_input::TextInput;
+        use crate::utils::tar::{self, TarHeader};
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut archive = TarHeader::new("app.conf", PathKind::File, 2)
+            .encode()
+            .unwrap();
+        archive.extend(b"on");
+        archive.resize(archive.len() + tar::padding(2), 0);
+        archive.extend(tar::END_OF_ARCHIVE);
+        repo.set_archive("web-1", "/etc/app.conf", archive).await;
+        let mut app = App::new(repo);
+        app.refresh_containers().await.unwrap();
+
+        let local = tempfile::tempdir().unwrap();
+        std::fs::write(local.path().join("app.conf"), b"off").unwrap();
+        app.open_copy_dialog();
+        let dialog = app.copy_dialog.as_mut().unwrap();
+        dialog.cursor = CopyField::ALL.len() - 1;
+        app.copy_dialog_enter().await;
+        assert!(app.copy_dialog.as_ref().unwrap().message.is_some());
+
+        let dialog = app.copy_dialog.as_mut().unwrap();
+        dialog.container_path = TextInput::with_value("/etc/app.conf");
+        dialog.local_path = TextInput::with_value(local.path().to_string_lossy());
+        app.copy_dialog_enter().await;
+        assert!(matches!(
+            app.copy_dialog.as_ref().map(|dialog| &dialog.stage),
+            Some(CopyStage::ConfirmOverwrite(_))
+        ));
+        assert_eq!(
+            std::fs::read(local.path().join("app.conf")).unwrap(),
+            b"off"
+        );
+
+        app.copy_dialog_enter().await;
+        while matches!(
+            app.copy_dialog.as_ref().map(|dialog| &dialog.stage),
+            Some(CopyStage::Running { .. })
+        ) {
+            tokio::task::yield_now().await;
+            app.poll_copy_dialog().await;
+        }
+        assert!(matches!(
+            app.copy_dialog.as_ref().map(|dialog| &dialog.stage),
+            Some(CopyStage::Done(Ok(report))) if report.entries == 1
+        ));
+        assert_eq!(std::fs::read(local.path().join("app.conf")).unwrap(), b"on");
+
+        app.copy_dialog_enter().await;
+        assert!(!app.is_copy_dialog_open());
+    }
+
+    #[tokio::test]
+    async fn test_file_browser_opens_directories_and_previews() {
+        // Test walking into a directory, previewing a file and stepping back out
+        // ディレクトリに入り、ファイルをプレビューし、戻ることをテスト
+        use crate::domain::entities::PathKind;
+        use crate::utils::tar::{self, TarHeader};
+
+        fn archive_of(entries: &[(TarHeader, &[u8])]) -> Vec<u8> {
+            let mut archive = Vec::new();
+            for (header, data) in entries {
+                archive.extend(header.encode().unwrap());
+                archive.extend(*data);
+                archive.resize(archive.len() + tar::padding(data.len() as u64), 0);
+            }
+            archive.extend(tar::END_OF_ARCHIVE);
+            archive
+        }
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let root = archive_of(&[
+            (TarHeader::new("./", PathKind::Directory, 0), &[]),
+            (TarHeader::new("./etc/", PathKind::Directory, 0), &[]),
+            (TarHeader::new("./etc/motd", PathKind::File, 2), b"hi"),
+        ]);
+        repo.set_archive("web-1", "/", root).await;
+        let etc = archive_of(&[
+            (TarHeader::new("etc/", PathKind::Directory, 0), &[]),
+            (TarHeader::new("etc/motd", PathKind::File, 2), b"hi"),
+        ]);
+        repo.set_archive("web-1", "/etc", etc).await;
+        let motd = archive_of(&[(TarHeader::new("motd", PathKind::File, 2), b"hi")]);
+        repo.set_archive("web-1", "/etc/motd", motd).await;
+        let mut app = App::new(repo);
+        app.refresh_containers().await.unwrap();
+
+        app.open_file_browser().await;
+        assert!(app.is_file_browser_open());
+        let browser = app.file_browser.as_ref().unwrap();
+        assert_eq!(browser.message, None);
+        assert_eq!(browser.listing.entries.len(), 1);
+
+        app.file_browser_enter().await;
+        app.navigate_file_browser(NavigationDirection::Next);
+        app.file_browser_enter().await;
+        let preview = app.file_browser.as_ref().unwrap().preview.as_ref();
+        assert_eq!(
+            preview.and_then(|preview| preview.text.as_deref()),
+            Some("hi")
+        );
+
+        app.file_browser_back();
+        assert_eq!(app.file_browser.as_ref().unwrap().listing.path, "/etc");
+        app.file_browser_back();
+        assert!(!app.is_file_browser_open());
+        assert_eq!(app.view_state, ViewState::ContainerList);
+    }
+
+    #[tokio::test]
+    async fn test_text_input_receives_bound_keys() {
+        // Test that bound keys are typed into the field while editing
+        // 編集中はバインド済みキーがフィールドに入力されることをテスト
+        use crate::ui::prune_dialog::PruneField;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = create_test_app();
+        app.open_prune_dialog();
+        assert!(!app.is_text_input_active());
+
+        let dialog = app.cleanup.prune_dialog.as_mut().unwrap();
+        dialog.cursor = PruneField::all()
+            .iter()
+            .position(|field| *field == PruneField::Labels)
+            .unwrap();
+        dialog.activate_field();
+        assert!(app.is_text_input_active());
+
+        for c in ['q', '=', 'j'] {
+            app.handle_text_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await;
+        }
+        app.handle_text_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await;
+        assert!(!app.is_text_input_active());
+        assert!(app.is_running());
+        assert_eq!(
+            app.cleanup.prune_dialog.as_ref().unwrap().labels.value(),
+            "q=j"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_assistant_removes_selected_candidates() {
+        // Test that only confirmed, selected candidates are removed
+        // 確認済みで選択された候補のみが削除されることをテスト
+        use crate::ui::cleanup_assistant::CleanupRow;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        for (id, days) in [("job-1", 10), ("job-2", 9), ("job-3", 1)] {
+            let mut stopped = create_test_container(id, id);
+            stopped.status = ContainerStatus::Exited { exit_code: 0 };
+            stopped.finished_at = Some(chrono::Utc::now() - chrono::Duration::days(days));
+            repo.add_container(stopped).await;
+        }
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_cleanup_assistant().await;
+        assert!(app.is_cleanup_assistant_open());
+        let assistant = app.cleanup.assistant.as_mut().unwrap();
+        assert_eq!(assistant.candidates.len(), 2);
+        assert_eq!(assistant.candidates[0].id.as_str(), "job-1");
+
+        assistant.cursor = (0..assistant.row_count())
+            .position(|index| assistant.row(index) == Some(CleanupRow::Candidate(1)))
+            .unwrap();
+        app.cleanup_assistant_enter().await;
+        let assistant = app.cleanup.assistant.as_mut().unwrap();
+        assistant.cursor = (0..assistant.row_count())
+            .position(|index| assistant.row(index) == Some(CleanupRow::Remove))
+            .unwrap();
+
+        app.cleanup_assistant_enter().await;
+        assert_eq!(repo.list_containers().await.unwrap().len(), 4);
+        app.cleanup_assistant_enter().await;
+
+        let remaining: Vec<String> = repo
+            .list_containers()
+            .await
+            .unwrap()
+            .iter()
+            .map(|container| container.id.as_str().to_string())
+            .collect();
+        assert_eq!(remaining, ["web-1", "job-2", "job-3"]);
+        assert_eq!(app.containers.len(), 3);
+        let assistant = app.cleanup.assistant.as_ref().unwrap();
+        assert_eq!(
+            assistant
+                .report
+                .as_ref()
+                .map(crate::app::prune_service::PruneReport::succeeded),
+            Some(1)
+        );
+        assert_eq!(assistant.candidates.len(), 1);
+
+        app.cleanup_assistant_back();
+        assert!(!app.is_cleanup_assistant_open());
+        assert_eq!(app.view_state, ViewState::ContainerList);
+    }
+
+    #[tokio::test]
+    async fn test_process_pane_follows_selection() {
+        // Test that the pane loads running containers and explains stopped ones
+        // ペインが実行中のコンテナを読み込み、停止中のものは理由を示すことをテスト
+        use crate::domain::entities::ContainerProcess;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut stopped = create_test_container("job-1", "job");
+        stopped.status = ContainerStatus::Stopped;
+        repo.add_container(stopped).await;
+        repo.set_processes(
+            "web-1",
+            vec![ContainerProcess {
+                pid: 42,
+                command: "nginx".to_string(),
+                ..ContainerProcess::default()
+            }],
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        let now = Instant::now();
+        settle_processes(&mut app, now).await;
+        assert!(app.process_pane.is_none());
+
+        app.toggle_process_pane();
+        settle_processes(&mut app, now).await;
+        let pane = app.process_pane.as_ref().unwrap();
+        assert_eq!(pane.processes.len(), 1);
+        assert_eq!(pane.processes[0].command, "nginx");
+
+        app.select_next();
+        settle_processes(&mut app, now).await;
+        let pane = app.process_pane.as_ref().unwrap();
+        assert!(pane.processes.is_empty());
+        assert_eq!(
+            pane.message.as_deref(),
+            Some("job is not running (Stopped)")
+        );
+
+        app.toggle_process_pane();
+        assert!(!app.is_process_pane_open());
+    }
+
+    #[tokio::test]
+    async fn test_diff_view_shows_selected_container_changes() {
+        // Test that the diff view loads, expands and closes
+        // 変更ビューが読み込み、展開、終了できることをテスト
+        use crate::domain::entities::{ChangeKind, FilesystemChange};
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        repo.set_changes(
+            "web-1",
+            vec![
+                FilesystemChange::new("/var/log", ChangeKind::Modified),
+                FilesystemChange::new("/var/log/app.log", ChangeKind::Added),
+            ],
+        )
+        .await;
+        let mut app = App::new(repo);
+        app.open_diff_view().await;
+        assert!(!app.is_diff_view_open());
+
+        app.refresh_containers().await.unwrap();
+        app.open_diff_view().await;
+        assert!(app.is_diff_view_open());
+        assert_eq!(app.diff_view.as_ref().unwrap().tree.total().added, 1);
+
+        app.toggle_diff_node();
+        app.navigate_diff_view(NavigationDirection::Next);
+        app.toggle_diff_node();
+        assert_eq!(app.diff_view.as_ref().unwrap().tree.visible_rows().len(), 3);
+
+        app.close_diff_view();
+        assert_eq!(app.view_state, ViewState::ContainerList);
+        assert!(app.diff_view.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_details_view_shows_health_log() {
+        // Test that the details view loads the selected container's health log and closes
+        // 詳細ビューが選択中のコンテナのヘルスログを読み込み、閉じられることをテスト
+        use crate::domain::entities::{ContainerDetails, HealthReport};
+        use crate::domain::value_objects::HealthStatus;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        repo.set_details(
+            "web-1",
+            ContainerDetails {
+                health: Some(HealthReport {
+                    status: HealthStatus::Unhealthy,
+                    failing_streak: 4,
+                    probes: Vec::new(),
+                }),
+                restart_count: 2,
+                ..ContainerDetails::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.open_details_view().await;
+        assert!(!app.is_details_view_open());
+
+        app.refresh_containers().await.unwrap();
+        app.open_details_view().await;
+        assert!(app.is_details_view_open());
+        let view = app.details_view.as_ref().unwrap();
+        let health = view.details.as_ref().unwrap().health.as_ref().unwrap();
+        assert_eq!(health.failing_streak, 4);
+
+        repo.set_unavailable(true);
+        app.reload_details_view().await;
+        assert!(app.details_view.as_ref().unwrap().message.is_some());
+
+        app.close_details_view();
+        assert_eq!(app.view_state, ViewState::ContainerList);
+        assert!(app.details_view.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_polled_exits_flag_crash_loop() {
+        // Test that polled exits flag a crash loop and feed the details timeline
+        // ポーリングした終了がクラッシュループを示し、詳細のタイムラインに渡ることをテスト
+        use crate::domain::entities::ContainerExit;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let id = ContainerId::new("web-1").unwrap();
+        let now = Utc::now();
+        for seconds in [240, 120, 60] {
+            repo.add_exit(ContainerExit {
+                container_id: id.clone(),
+                at: now - Duration::seconds(seconds),
+                exit_code: Some(1),
+            })
+            .await;
+        }
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        settle_exits(&mut app, now).await;
+        assert_eq!(app.recent_exits(&id), 3);
+        assert!(!app.is_crash_looping(&id));
+
+        repo.add_exit(ContainerExit {
+            container_id: id.clone(),
+            at: now + Duration::seconds(2),
+            exit_code: Some(137),
+        })
+        .await;
+        settle_exits(&mut app, now + Duration::seconds(1)).await;
+        assert_eq!(app.exit_history.exits_for(&id).len(), 3);
+        settle_exits(&mut app, now + EXIT_POLL_INTERVAL).await;
+        assert_eq!(app.exit_history.exits_for(&id).len(), 4);
+
+        app.open_details_view().await;
+        let view = app.details_view.as_ref().unwrap();
+        assert_eq!(view.exits.len(), 4);
+        assert_eq!(view.exits[3].exit_code, Some(137));
+        assert!(app.exit_history.is_crash_looping(
+            &id,
+            app.crash_loop_policy,
+            now + Duration::seconds(5)
+        ));
+
+        app.switch_repository(Arc::new(MockDockerRepository::new()), "other");
+        assert!(app.exit_history.exits_for(&id).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_slow_engine_does_not_block_exit_and_process_polls() {
+        // Test that exit and process polls return while the engine is stuck
+        // エンジンが応答しない間も終了とプロセスのポーリングが戻ることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        app.toggle_process_pane();
+
+        let exits = repo.exits.write().await;
+        let processes = repo.processes.write().await;
+        let now = Utc::now();
+        for _ in 0..3 {
+            tokio::task::yield_now().await;
+            app.poll_container_exits_if_due(now).await;
+            app.refresh_process_pane_if_due(Instant::now()).await;
+        }
+        assert!(app.exit_poll.is_some());
+        assert!(app.process_task.is_some());
+        assert!(app.exits_polled_at.is_none());
+        drop(exits);
+        drop(processes);
+
+        settle_exits(&mut app, now).await;
+        settle_processes(&mut app, Instant::now()).await;
+        assert_eq!(app.exits_polled_at, Some(now));
+        assert!(app.process_pane.as_ref().unwrap().message.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_exits_filter_hides_clean_stops() {
+        // Test that the failed exits filter narrows the list and can be turned off
+        // 失敗した終了のフィルタが一覧を絞り込み、解除できることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        for (id, exit_code) in [("oom-1", 137), ("done-1", 0), ("stop-1", 143)] {
+            let container = Container::builder()
+                .id(id)
+                .name(id)
+                .image("nginx:latest")
+                .status(ContainerStatus::Exited { exit_code })
+                .build()
+                .unwrap();
+            repo.add_container(container).await;
+        }
+        let mut app = App::new(repo);
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 3);
+
+        app.toggle_failed_exits_filter().await.unwrap();
+        let ids: Vec<&str> = app.containers.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["oom-1"]);
+
+        app.toggle_failed_exits_filter().await.unwrap();
+        assert_eq!(app.containers.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_start_warns_about_taken_host_port() {
+        // Test that starting a container whose host port another one uses gives a warning
+        // 他のコンテナが使用中のホストポートを持つコンテナの起動で警告が出ることをテスト
+        use crate::domain::entities::ContainerDetails;
+        use crate::domain::value_objects::{PortMapping, PortProtocol};
+        use crate::ui::actions::ContainerAction;
+
+        let postgres = PortMapping::new(5432, PortProtocol::Tcp).published(None, 5432);
+        let repo = Arc::new(MockDockerRepository::new());
+        let mut stopped = create_test_container("db-old", "db-old");
+        stopped.status = ContainerStatus::Exited { exit_code: 0 };
+        repo.add_container(stopped).await;
+        let mut running = create_test_container("db-new", "db-new");
+        running.ports.push(postgres.clone());
+        repo.add_container(running).await;
+        repo.set_details(
+            "db-old",
+            ContainerDetails {
+                port_bindings: vec![postgres],
+                ..ContainerDetails::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+
+        app.open_ports_view().await;
+        assert!(app.is_ports_view_open());
+        let view = app.ports.view.as_ref().unwrap();
+        assert_eq!(view.highlighted().unwrap().container_name, "db-new");
+        app.close_ports_view();
+        assert!(app.ports.view.is_none());
+
+        app.open_action_menu();
+        assert_eq!(
+            app.action_menu_entries()[app.action_menu_index.unwrap()].action,
+            ContainerAction::Start
+        );
+        app.run_highlighted_action().await.unwrap();
+        assert_eq!(
+            app.warning.as_deref(),
+            Some("host port 5432/tcp is used by db-new")
+        );
+
+        app.refresh_containers().await.unwrap();
+        assert!(app.warning.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ports_view_starts_and_stops_forward() {
+        // Test that a taken local port is reported, then a typed forward reaches the echo server and stops
+        // 使用中のローカルポートが報告され、入力したフォワードがエコーサーバーに届き停止されることをテスト
+        use crate::domain::entities::{ContainerDetails, NetworkAddress};
+        use crate::domain::value_objects::{PortMapping, PortProtocol};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::{TcpListener, TcpStream};
+
+        // The echo server stands in for the container endpoint
+        // エコーサーバーがコンテナのエンドポイントの代わりになる
+        let echo = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let echo_port = echo.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = echo.accept().await {
+                tokio::spawn(async move {
+                    let (mut reader, mut writer) = stream.split();
+                    let _ = tokio::io::copy(&mut reader, &mut writer).await;
+                });
+            }
+        });
+
+        let repo = Arc::new(MockDockerRepository::new());
+        let mut debug = create_test_container("api-1", "api");
+        debug
+            .ports
+            .push(PortMapping::new(echo_port, PortProtocol::Tcp));
+        repo.add_container(debug).await;
+        repo.set_details(
+            "api-1",
+            ContainerDetails {
+                network_addresses: vec![
+                    NetworkAddress {
+                        network: "backend".to_string(),
+                        ip: "127.0.0.1".parse().unwrap(),
+                    },
+                    NetworkAddress {
+                        network: "frontend".to_string(),
+                        ip: "192.0.2.1".parse().unwrap(),
+                    },
+                ],
+                ..ContainerDetails::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.remember_routable_endpoint("default");
+        app.open_ports_view().await;
+
+        // The same local port is taken by the echo server itself
+        // 同じローカルポートはエコーサーバー自身が使用している
+        app.toggle_highlighted_forward().await;
+        assert!(app.ports.forwards.forwards().is_empty());
+        let notice = app.ports.view.as_ref().unwrap().notice.clone().unwrap();
+        assert!(notice.contains("already in use"), "{notice}");
+
+        app.open_forward_input();
+        assert!(app.is_text_input_active());
+        app.handle_text_input(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+            .await;
+        for c in format!("0:{echo_port}").chars() {
+            app.handle_text_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await;
+        }
+        app.handle_text_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await;
+        assert!(!app.is_text_input_active());
+        let forward = &app.ports.forwards.forwards()[0];
+        assert_eq!(
+            forward.target,
+            SocketAddr::from(([127, 0, 0, 1], echo_port))
+        );
+        let local_addr = forward.local_addr;
+        let view = app.ports.view.as_ref().unwrap();
+        assert_eq!(view.forward_count, 1);
+        let notice = view.notice.as_deref().unwrap();
+        assert!(notice.starts_with("Forwarding"), "{notice}");
+        assert!(notice.contains("on backend"), "{notice}");
+
+        let mut stream = TcpStream::connect(local_addr).await.unwrap();
+        stream.write_all(b"ping").await.unwrap();
+        let mut reply = [0; 4];
+        stream.read_exact(&mut reply).await.unwrap();
+        assert_eq!(&reply, b"ping");
+
+        app.close_ports_view();
+        assert_eq!(app.ports.forwards.forwards().len(), 1);
+        app.open_ports_view().await;
+        app.navigate_ports_view(NavigationDirection::Next);
+        app.toggle_highlighted_forward().await;
+        assert!(app.ports.forwards.forwards().is_empty());
+        assert_eq!(app.ports.view.as_ref().unwrap().cursor, 0);
+    }
+
+    #[tokio::test]
+    async fn test_forward_input_targets_selected_container_and_network() {
+        // Test that any port of the selected container can be typed, with a chosen network
+        // 選択中のコンテナの任意のポートをネットワークを選んで入力できることをテスト
+        use crate::domain::entities::{ContainerDetails, NetworkAddress};
+
+        async fn submit(app: &mut App, text: &str) {
+            app.open_forward_input();
+            for c in text.chars() {
+                app.handle_text_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                    .await;
+            }
+            app.handle_text_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+                .await;
+        }
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("api-1", "api"))
+            .await;
+        repo.set_details(
+            "api-1",
+            ContainerDetails {
+                network_addresses: vec![NetworkAddress {
+                    network: "backend".to_string(),
+                    ip: "127.0.0.1".parse().unwrap(),
+                }],
+                ..ContainerDetails::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        app.open_ports_view().await;
+        assert!(app.ports.view.as_ref().unwrap().rows.is_empty());
+
+        submit(&mut app, "http").await;
+        let input = app
+            .ports
+            .view
+            .as_ref()
+            .unwrap()
+            .forward_input
+            .clone()
+            .unwrap();
+        assert_eq!(input.container_name, "api");
+        assert!(input.message.unwrap().contains("not a port"));
+        app.handle_text_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await;
+        assert!(!app.is_text_input_active());
+
+        // Container addresses of an unknown endpoint are not assumed reachable
+        // 不明なエンドポイントのコンテナのアドレスは到達可能とみなさない
+        submit(&mut app, "0:6379@backend").await;
+        assert!(app.ports.forwards.forwards().is_empty());
+        let notice = app.ports.view.as_ref().unwrap().notice.clone().unwrap();
+        assert!(notice.starts_with("Cannot forward api"), "{notice}");
+        assert!(notice.contains("'default'"), "{notice}");
+
+        app.remember_routable_endpoint("default");
+        submit(&mut app, "0:6379@cache").await;
+        let notice = app.ports.view.as_ref().unwrap().notice.clone().unwrap();
+        assert_eq!(notice, "api is not on network 'cache' (networks: backend)");
+
+        submit(&mut app, "0:6379@backend").await;
+        let forward = &app.ports.forwards.forwards()[0];
+        assert_eq!(forward.target, SocketAddr::from(([127, 0, 0, 1], 6379)));
+        assert_eq!(forward.label, "api 6379/tcp");
+    }
+
+    #[tokio::test]
+    async fn test_secrets_are_masked_again_after_reopening_details() {
+        // Test that revealed secrets are masked again when the details view is reopened
+        // 詳細ビューを開き直すと表示したシークレットが再びマスクされることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        let mut app = App::new(repo);
+        app.apply_config(&AppConfig {
+            secret_patterns: SecretMask::new(["*DSN"]),
+            ..AppConfig::default()
+        });
+        assert!(app.secret_mask.is_secret("SENTRY_DSN"));
+        app.refresh_containers().await.unwrap();
+
+        app.toggle_reveal_secrets();
+        app.open_details_view().await;
+        assert!(!app.details_view.as_ref().unwrap().reveal_secrets);
+        app.toggle_reveal_secrets();
+        assert!(app.details_view.as_ref().unwrap().reveal_secrets);
+
+        app.close_details_view();
+        app.open_details_view().await;
+        assert!(!app.details_view.as_ref().unwrap().reveal_secrets);
+    }
+
+    #[tokio::test]
+    async fn test_label_browser_filters_and_groups_list() {
+        // Test that label values filter the list and label keys group it
+        // ラベル値で一覧が絞り込まれ、ラベルキーでグループ化されることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        for (id, team, env) in [
+            ("web-1", "growth", "prod"),
+            ("api-1", "payments", "prod"),
+            ("api-2", "payments", "staging"),
+        ] {
+            let container = Container::builder()
+                .id(id)
+                .name(id)
+                .image("nginx:latest")
+                .status(ContainerStatus::Running)
+                .label("team", team)
+                .label("env", env)
+                .build()
+                .unwrap();
+            repo.add_container(container).await;
+        }
+
+        let mut app = App::new(repo);
+        app.refresh_containers().await.unwrap();
+        let ids = |app: &App| -> Vec<String> {
+            app.containers
+                .iter()
+                .map(|c| c.id.as_str().to_string())
+                .collect()
+        };
+
+        // Rows: env=prod, env=staging, team=growth, team=payments
+        // 行: env=prod、env=staging、team=growth、team=payments
+        app.open_label_browser().await;
+        assert!(app.is_label_browser_open());
+        app.toggle_highlighted_label_filter().await.unwrap();
+        assert!(!app.is_label_browser_open());
+        assert_eq!(
+            app.query.filter.labels.get("env").map(String::as_str),
+            Some("prod")
+        );
+        assert_eq!(app.containers.len(), 2);
+
+        app.open_label_browser().await;
+        app.toggle_highlighted_label_filter().await.unwrap();
+        assert!(app.query.filter.labels.is_empty());
+        assert_eq!(app.containers.len(), 3);
+
+        let selected = app.containers[app.containers.len() - 1].id.clone();
+        app.selected_index = app.containers.len() - 1;
+        app.open_label_browser().await;
+        app.navigate_label_browser(NavigationDirection::Next);
+        app.navigate_label_browser(NavigationDirection::Next);
+        app.group_by_highlighted_label();
+        assert_eq!(app.query.group_by_label.as_deref(), Some("team"));
+        assert_eq!(ids(&app)[0], "web-1");
+        assert_eq!(app.group_of(&app.containers[0]), Some("growth"));
+        assert_eq!(app.selected_container().unwrap().id, selected);
+
+        app.refresh_containers().await.unwrap();
+        assert_eq!(ids(&app)[0], "web-1");
+        app.set_group_by_label(None);
+        assert!(app.query.group_by_label.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_live_and_jumps_between_matches() {
+        // Test that typing filters the list, Enter keeps the full list and n/N wrap around
+        // 入力で一覧が絞り込まれ、Enterで全体の一覧に戻り、n/Nが折り返すことをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        for (id, name) in [("aa11", "api"), ("bb22", "web"), ("cc33", "api-worker")] {
+            repo.add_container(create_test_container(id, name)).await;
+        }
+        let mut app = App::new(repo);
+        app.refresh_containers().await.unwrap();
+        app.selected_index = 1;
+
+        app.open_search();
+        assert!(app.is_text_input_active());
+        for c in "api".chars() {
+            app.handle_text_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await;
+        }
+        assert_eq!(app.containers.len(), 2);
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "aa11");
+
+        // Filtering stays live across refreshes while typing
+        // 入力中は更新をまたいでも絞り込みが維持される
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 2);
+
+        app.handle_text_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await;
+        assert!(!app.is_text_input_active());
+        assert_eq!(app.containers.len(), 3);
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "aa11");
+        assert_eq!(app.query.search.history(), ["api"]);
+
+        app.jump_to_match(NavigationDirection::Next);
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "cc33");
+        app.jump_to_match(NavigationDirection::Next);
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "aa11");
+        app.jump_to_match(NavigationDirection::Previous);
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "cc33");
+
+        // An ID prefix matches too, and Esc clears the query
+        // IDの先頭も一致し、Escでクエリがクリアされる
+        app.open_search();
+        for c in "bb".chars() {
+            app.handle_text_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .await;
+        }
+        assert_eq!(app.containers.len(), 1);
+        app.handle_text_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .await;
+        assert_eq!(app.containers.len(), 3);
+        assert!(app.query.search.query().is_none());
+        assert_eq!(app.selected_container().unwrap().id.as_str(), "bb22");
+    }
+
+    #[tokio::test]
+    async fn test_filter_bar_applies_and_saves_expressions() {
+        // Test that expressions filter the list, errors keep the bar open and saves persist
+        // 式で一覧が絞り込まれ、エラーでバーが開いたままになり、保存が永続化されることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("aa11", "api"))
+            .await;
+        repo.add_container(create_test_container("bb22", "api-test"))
+            .await;
+        let mut app = App::new(repo);
+        let dir = tempfile::tempdir().unwrap();
+        app.config_service = Some(ConfigService::new(dir.path().join("config.json")));
+        app.refresh_containers().await.unwrap();
+        let type_text = |text: &'static str| {
+            text.chars()
+                .map(|c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .collect::<Vec<_>>()
+        };
+
+        app.open_filter_bar();
+        assert!(app.is_text_input_active());
+        for key in type_text("name:api colour:red") {
+            app.handle_text_input(key).await;
+        }
+        app.handle_text_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await;
+        let bar = app.query.filter_bar.as_ref().unwrap();
+        assert_eq!(bar.error.as_ref().unwrap().0, Some(9));
+        assert_eq!(app.containers.len(), 2);
+
+        app.query.filter_bar = Some(FilterBar::new("name:api -name:test"));
+        app.handle_text_input(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+            .await;
+        for key in type_text("prod") {
+            app.handle_text_input(key).await;
+        }
+        app.handle_text_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await;
+        assert!(app.query.filter_bar.is_none());
+        assert_eq!(
+            app.query.filter_expression.as_deref(),
+            Some("name:api -name:test")
+        );
+        assert_eq!(app.containers.len(), 1);
+        let config = app.config_service.as_ref().unwrap().load().unwrap();
+        assert_eq!(config.saved_filters["prod"], "name:api -name:test");
+        assert_eq!(app.query.saved_filters, config.saved_filters);
+
+        // The expression filter stays applied across refreshes and is cleared when emptied
+        // 式のフィルタは更新後も適用され、空にすると解除される
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 1);
+        app.query.filter_bar = Some(FilterBar::new(""));
+        app.apply_filter_expression();
+        assert!(app.query.filter_expression.is_none());
+        assert_eq!(app.containers.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sort_keeps_selection_and_persists_across_refreshes() {
+        // Test that sorting keeps the selected container and survives refreshes and endpoint switches
+        // 並べ替えで選択中のコンテナが維持され、更新とエンドポイント切り替え後も保持されることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("cc33", "web"))
+            .await;
+        repo.add_container(create_test_container("aa11", "api"))
+            .await;
+        let mut stopped = create_test_container("bb22", "db");
+        stopped.status = ContainerStatus::Stopped;
+        repo.add_container(stopped).await;
+        for (id, cpu_percent) in [("cc33", 50.0), ("aa11", 5.0)] {
+            let stats = ContainerStats {
+                cpu_percent: Some(cpu_percent),
+                memory_bytes: Some(1_000),
+            };
+            repo.set_stats(id, stats).await;
+        }
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        let names = |app: &App| -> Vec<String> {
+            app.containers
+                .iter()
+                .map(|container| container.name.clone())
+                .collect()
+        };
+        assert_eq!(names(&app), ["web", "api", "db"]);
+
+        app.cycle_sort_key();
+        assert_eq!(names(&app), ["api", "db", "web"]);
+        assert_eq!(app.selected_container().unwrap().name, "web");
+        assert!(app.query.container_stats.is_empty());
+
+        while app.query.sort.key != Some(SortKey::Cpu) {
+            app.cycle_sort_key();
+        }
+        settle_stats(&mut app).await;
+        assert_eq!(app.query.container_stats.len(), 2);
+        assert_eq!(names(&app), ["web", "api", "db"]);
+        app.reverse_sort();
+        assert_eq!(names(&app), ["api", "web", "db"]);
+        assert_eq!(app.selected_container().unwrap().name, "web");
+
+        app.refresh_containers().await.unwrap();
+        assert_eq!(names(&app), ["api", "web", "db"]);
+        app.switch_repository(repo, "other");
+        app.refresh_containers().await.unwrap();
+        settle_stats(&mut app).await;
+        assert_eq!(app.query.sort.indicator().as_deref(), Some("cpu ▲"));
+        assert_eq!(names(&app), ["api", "web", "db"]);
+    }
+
+    #[tokio::test]
+    async fn test_stats_are_sampled_without_blocking_refresh() {
+        // Test that refreshing does not wait for usage samples and the list is re-sorted on arrival
+        // 更新が使用量の取得を待たず、取得後に一覧が並べ替え直されることをテスト
+        let repo = Arc::new(MockDockerRepository::new());
+        for (id, name, cpu_percent) in [("aa11", "api", 5.0), ("cc33", "web", 50.0)] {
+            repo.add_container(create_test_container(id, name)).await;
+            let stats = ContainerStats {
+                cpu_percent: Some(cpu_percent),
+                memory_bytes: None,
+            };
+            repo.set_stats(id, stats).await;
+        }
+        let mut app = App::new(repo.clone());
+        while app.query.sort.key != Some(SortKey::Cpu) {
+            app.cycle_sort_key();
+        }
+        settle_stats(&mut app).await;
+
+        // Sampling is stuck while the mock's stats are locked
+        // モックの使用量がロックされている間は取得が止まる
+        let stats = repo.stats.write().await;
+        app.refresh_containers().await.unwrap();
+        app.refresh_containers().await.unwrap();
+        app.poll_container_stats().await;
+        assert!(app.query.container_stats.is_empty());
+        assert_eq!(app.containers.len(), 2);
+        drop(stats);
+
+        settle_stats(&mut app).await;
+        assert_eq!(app.query.container_stats.len(), 2);
+        assert_eq!(app.containers[0].name, "web");
+
+        app.switch_repository(repo, "other");
+        assert!(app.query.stats_task.is_none());
+        assert!(app.query.container_stats.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_flags_stale_rows_from_failing_host() {
+        // Test that a failing host's rows stay visible and are flagged as stale
+        // 失敗したホストの行が表示されたまま古いと示されることをテスト
+        use crate::domain::repositories::MultiHostDockerRepository;
+
+        let local = Arc::new(MockDockerRepository::new());
+        let remote = Arc::new(MockDockerRepository::new());
+        local
+            .add_container(create_test_container("local-1", "local"))
+            .await;
+        remote
+            .add_container(create_test_container("remote-1", "remote"))
+            .await;
+
+        let hosts: Vec<(String, Arc<dyn DockerRepository>)> = vec![
+            ("default".to_string(), local.clone()),
+            ("remote".to_string(), remote.clone()),
+        ];
+        let mut app = App::new(Arc::new(MultiHostDockerRepository::new(hosts).unwrap()));
+
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 2);
+        assert!(app.containers.iter().all(|c| !app.is_stale(c)));
+
+        remote.set_unavailable(true);
+        app.refresh_containers().await.unwrap();
+        assert_eq!(app.containers.len(), 2);
+        assert_eq!(app.stale_hosts, vec!["remote".to_string()]);
+
+        let remote_row = app
+            .containers
+            .iter()
+            .find(|c| c.host.as_deref() == Some("remote"))
+            .unwrap();
+        let local_row = app
+            .containers
+            .iter()
+            .find(|c| c.host.as_deref() == Some("default"))
+            .unwrap();
+        assert!(app.is_stale(remote_row));
+        assert!(!app.is_stale(local_row));
+    }
+
+    #[tokio::test]
+    async fn test_signals_are_routed_by_row_host() {
+        // Test that an action reaches the host of the selected row even when IDs collide
+        // IDが衝突しても操作が選択行のホストに届くことをテスト
+        use crate::domain::repositories::MultiHostDockerRepository;
+
+        let local = Arc::new(MockDockerRepository::new());
+        let remote = Arc::new(MockDockerRepository::new());
+        local
+            .add_container(create_test_container("same-id", "local"))
+            .await;
+        remote
+            .add_container(create_test_container("same-id", "remote"))
+            .await;
+
+        let hosts: Vec<(String, Arc<dyn DockerRepository>)> = vec![
+            ("default".to_string(), local.clone()),
+            ("remote".to_string(), remote.clone()),
+        ];
+        let mut app = App::new(Arc::new(MultiHostDockerRepository::new(hosts).unwrap()));
+        app.refresh_containers().await.unwrap();
+        app.selected_index = app
+            .containers
+            .iter()
+            .position(|c| c.host.as_deref() == Some("remote"))
+            .unwrap();
+
+        app.open_signal_picker();
+        app.send_highlighted_signal().await.unwrap();
+        assert!(local.sent_signals().await.is_empty());
+        assert_eq!(
+            remote.sent_signals().await,
+            vec![("same-id".to_string(), Signal::Term)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_restart_policy_actions() {
+        // Test renaming through the dialog and changing only the restart policy
+        // ダイアログでの名前変更と再起動ポリシーのみの変更をテスト
+        use crate::domain::value_objects::{ResourceLimits, RestartPolicy};
+        use crate::ui::text_input::TextInput;
+        use crossterm::event::KeyEvent;
+
+        let repo = Arc::new(MockDockerRepository::new());
+        repo.add_container(create_test_container("web-1", "web"))
+            .await;
+        repo.add_container(create_test_container("db-1", "db"))
+            .await;
+        repo.set_resources(
+            "web-1",
+            ResourceLimits {
+                memory: Some(256 << 20),
+                ..ResourceLimits::default()
+            },
+        )
+        .await;
+        let mut app = App::new(repo.clone());
+        app.refresh_containers().await.unwrap();
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+
+        app.open_rename_dialog();
+        assert!(app.is_text_input_active());
+        app.rename_dialog.as_mut().unwrap().input = TextInput::with_value("db");
+        app.handle_text_input(enter).await;
+        let message = app.rename_dialog.as_ref().unwrap().message.clone();
+        assert!(message.unwrap().contains("already in use"));
+
+        app.rename_dialog.as_mut().unwrap().input = TextInput::with_value("web-api");
+        app.handle_text_input(enter).await;
+        assert!(!app.is_rename_dialog_open());
+        assert_eq!(app.containers[0].name, "web-api");
+
+        app.open_restart_policy_picker().await.unwrap();
+        assert_eq!(
+            app.restart_policy_picker.as_ref().unwrap().highlighted(),
+            RestartPolicy::No
+        );
+        app.navigate_restart_policy_picker(NavigationDirection::Previous);
+        app.apply_restart_policy().await.unwrap();
+        assert!(!app.is_restart_policy_picker_open());
+        let limits = repo
+            .container_resources(&ContainerId::new("web-1").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            limits.restart_policy,
+            RestartPolicy::OnFailure { max_retries: 0 }
+        );
+        assert_eq!(limits.memory, Some(256 << 20));
+    }
 }
 
 // src/ui/app.rs の末尾に追加するナビゲーション単体テスト
@@ -0,0 +1,253 @@
+// src/ui/app/cleanup.rs
+// Guided prune dialog and stale container cleanup assistant
+// ガイド付き削除ダイアログと古いコンテナのクリーンアップアシスタント
+
+use super::{App, NavigationDirection, ViewState};
+use crate::app::prune_service::PruneService;
+use crate::error::DockaResult;
+use crate::ui::cleanup_assistant::{CleanupAssistant, CleanupRow};
+use crate::ui::prune_dialog::{PruneDialog, PruneStage};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Guided prune dialog and stale container cleanup assistant
+/// ガイド付き削除ダイアログと古いコンテナのクリーンアップアシスタント
+#[derive(Debug, Default)]
+pub struct Cleanup {
+    /// Guided prune dialog (`None` when closed)
+    /// ガイド付き削除ダイアログ（閉じている場合は`None`）
+    pub prune_dialog: Option<PruneDialog>,
+
+    /// Stale container cleanup assistant (`None` when closed)
+    /// 古いコンテナのクリーンアップアシスタント（閉じている場合は`None`）
+    pub assistant: Option<CleanupAssistant>,
+}
+
+impl App {
+    /// Open the guided prune dialog with the default targets
+    /// デフォルトの対象でガイド付き削除ダイアログを開く
+    pub fn open_prune_dialog(&mut self) {
+        self.cleanup.prune_dialog = Some(PruneDialog::new());
+        self.last_activity = Instant::now();
+    }
+
+    /// Close the prune dialog
+    /// 削除ダイアログを閉じる
+    pub fn close_prune_dialog(&mut self) {
+        self.cleanup.prune_dialog = None;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the prune dialog is open
+    /// 削除ダイアログが開いているかチェック
+    #[must_use]
+    pub const fn is_prune_dialog_open(&self) -> bool {
+        self.cleanup.prune_dialog.is_some()
+    }
+
+    /// Move the prune dialog highlight or scroll its list
+    /// 削除ダイアログの選択を移動、または一覧をスクロール
+    pub fn navigate_prune_dialog(&mut self, direction: NavigationDirection) {
+        if let Some(dialog) = self.cleanup.prune_dialog.as_mut() {
+            dialog.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Advance the prune dialog on `Enter`
+    /// `Enter`で削除ダイアログを進める
+    ///
+    /// Options toggle or start editing; the preview row loads candidates,
+    /// the preview asks for confirmation and then removes them, and the
+    /// report closes the dialog. Validation and engine errors are shown in
+    /// the dialog rather than replacing the current view.
+    ///
+    /// オプションは切り替えまたは編集開始、プレビュー行は候補を読み込み、
+    /// プレビューは確認後に削除し、結果表示でダイアログを閉じます。
+    /// 検証エラーとエンジンエラーは現在のビューを置き換えずにダイアログ内に表示されます。
+    ///
+    /// # Errors
+    /// * Any error returned by the container refresh after removal
+    pub async fn prune_dialog_enter(&mut self) -> DockaResult<()> {
+        let Some(dialog) = self.cleanup.prune_dialog.as_mut() else {
+            return Ok(());
+        };
+        self.last_activity = Instant::now();
+        let service = PruneService::new(Arc::clone(&self.docker_repository));
+
+        match &mut dialog.stage {
+            PruneStage::Options => {
+                if !dialog.activate_field() {
+                    return Ok(());
+                }
+                let result = match dialog.options() {
+                    Ok(options) => service.preview(&options).await,
+                    Err(error) => Err(error),
+                };
+                match result {
+                    Ok(preview) => dialog.show_preview(preview),
+                    Err(error) => dialog.message = Some(error.to_string()),
+                }
+                Ok(())
+            }
+            PruneStage::Preview { preview, .. } if preview.is_empty() => {
+                dialog.back();
+                Ok(())
+            }
+            PruneStage::Preview {
+                confirming: confirming @ false,
+                ..
+            } => {
+                *confirming = true;
+                Ok(())
+            }
+            PruneStage::Preview { preview, .. } => {
+                let report = service.execute(preview).await;
+                dialog.show_report(report);
+                let dashboard_open = self.is_system_overview_open();
+                self.refresh_containers().await?;
+                if dashboard_open {
+                    // Keep the dashboard's disk usage in step with what was removed
+                    // ダッシュボードのディスク使用量を削除結果に合わせる
+                    self.show_system_overview().await?;
+                }
+                Ok(())
+            }
+            PruneStage::Report(_) => {
+                self.close_prune_dialog();
+                Ok(())
+            }
+        }
+    }
+
+    /// Step back in the prune dialog, closing it from the first step or the report
+    /// 削除ダイアログで前の手順に戻る（最初の手順または結果表示からは閉じる）
+    pub fn prune_dialog_back(&mut self) {
+        if self
+            .cleanup
+            .prune_dialog
+            .as_mut()
+            .is_some_and(PruneDialog::back)
+        {
+            self.close_prune_dialog();
+        } else {
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Open the stale container cleanup assistant and load its candidates
+    /// 古いコンテナのクリーンアップアシスタントを開き候補を読み込む
+    pub async fn open_cleanup_assistant(&mut self) {
+        self.cleanup.assistant = Some(CleanupAssistant::new());
+        self.view_state = ViewState::CleanupAssistant;
+        self.reload_cleanup_candidates().await;
+    }
+
+    /// Leave the cleanup assistant and return to the container list
+    /// クリーンアップアシスタントを閉じてコンテナリストに戻る
+    pub fn close_cleanup_assistant(&mut self) {
+        self.cleanup.assistant = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the cleanup assistant is shown
+    /// クリーンアップアシスタントが表示されているかチェック
+    #[must_use]
+    pub const fn is_cleanup_assistant_open(&self) -> bool {
+        matches!(self.view_state, ViewState::CleanupAssistant)
+    }
+
+    /// Move the cleanup assistant highlight
+    /// クリーンアップアシスタントの選択を移動
+    pub fn navigate_cleanup_assistant(&mut self, direction: NavigationDirection) {
+        if let Some(assistant) = self.cleanup.assistant.as_mut() {
+            assistant.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// List the containers matching the assistant's criteria again
+    /// アシスタントの基準に一致するコンテナを再度一覧表示
+    ///
+    /// Invalid criteria and engine errors are shown in the assistant.
+    /// 無効な基準とエンジンエラーはアシスタント内に表示されます。
+    pub async fn reload_cleanup_candidates(&mut self) {
+        let Some(assistant) = self.cleanup.assistant.as_mut() else {
+            return;
+        };
+        self.last_activity = Instant::now();
+
+        let service = PruneService::new(Arc::clone(&self.docker_repository));
+        let result = match assistant.filter(chrono::Utc::now()) {
+            Ok(filter) => service.stale_containers(&filter).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(candidates) => {
+                assistant.set_candidates(candidates);
+                assistant.message = None;
+            }
+            Err(error) => assistant.message = Some(error.to_string()),
+        }
+    }
+
+    /// Act on the highlighted row of the cleanup assistant
+    /// クリーンアップアシスタントの選択行に対して操作
+    ///
+    /// Criteria start editing and candidates toggle. The remove row asks
+    /// for confirmation first, then removes the selection, reports the
+    /// outcome and reloads the candidates.
+    ///
+    /// 基準は編集を開始し、候補は選択を切り替えます。削除行はまず確認を求め、
+    /// その後選択を削除し、結果を報告して候補を再読み込みします。
+    pub async fn cleanup_assistant_enter(&mut self) {
+        let Some(assistant) = self.cleanup.assistant.as_mut() else {
+            return;
+        };
+        self.last_activity = Instant::now();
+
+        match assistant.highlighted_row() {
+            Some(CleanupRow::Field(_)) => assistant.editing = true,
+            Some(CleanupRow::Candidate(index)) => assistant.toggle(index),
+            Some(CleanupRow::Remove) if assistant.selected().is_empty() => {
+                assistant.message = Some("No containers selected".to_string());
+            }
+            Some(CleanupRow::Remove) if !assistant.confirming => assistant.confirming = true,
+            Some(CleanupRow::Remove) => {
+                let preview = assistant.removal_preview();
+                let service = PruneService::new(Arc::clone(&self.docker_repository));
+                let report = service.execute(&preview).await;
+
+                let removed: Vec<&str> = report
+                    .results
+                    .iter()
+                    .filter(|result| result.is_success())
+                    .map(|result| result.candidate.id.as_str())
+                    .collect();
+                self.containers
+                    .retain(|container| !removed.contains(&container.id.as_str()));
+                if self.selected_index >= self.containers.len() {
+                    self.selected_index = self.containers.len().saturating_sub(1);
+                }
+
+                assistant.confirming = false;
+                assistant.report = Some(report);
+                self.reload_cleanup_candidates().await;
+            }
+            None => {}
+        }
+    }
+
+    /// Cancel a pending removal, or close the assistant
+    /// 保留中の削除を取り消す、またはアシスタントを閉じる
+    pub fn cleanup_assistant_back(&mut self) {
+        match self.cleanup.assistant.as_mut() {
+            Some(assistant) if assistant.confirming => {
+                assistant.confirming = false;
+                self.last_activity = Instant::now();
+            }
+            _ => self.close_cleanup_assistant(),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// src/ui/app/copy.rs
+// Copy files dialog of the application
+// アプリケーションのファイルコピーダイアログ
+
+use super::{App, NavigationDirection};
+use crate::app::copy_service::{CopyPlan, CopyProgress, CopyService};
+use crate::ui::copy_dialog::{CopyDialog, CopyStage};
+use std::time::Instant;
+
+impl App {
+    /// Open the copy files dialog for the selected container
+    /// 選択中のコンテナのファイルコピーダイアログを開く
+    pub fn open_copy_dialog(&mut self) {
+        let Some(container) = self.selected_container() else {
+            return;
+        };
+        self.copy_dialog = Some(CopyDialog::new(container));
+        self.last_activity = Instant::now();
+    }
+
+    /// Close the copy dialog, cancelling a running copy
+    /// コピーダイアログを閉じる（実行中のコピーは取り消す）
+    pub fn close_copy_dialog(&mut self) {
+        if let Some(dialog) = self.copy_dialog.as_mut() {
+            dialog.back();
+        }
+        self.copy_dialog = None;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the copy dialog is open
+    /// コピーダイアログが開いているかチェック
+    #[must_use]
+    pub const fn is_copy_dialog_open(&self) -> bool {
+        self.copy_dialog.is_some()
+    }
+
+    /// Move the copy dialog highlight
+    /// コピーダイアログの選択を移動
+    pub fn navigate_copy_dialog(&mut self, direction: NavigationDirection) {
+        if let Some(dialog) = self.copy_dialog.as_mut() {
+            dialog.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Advance the copy dialog on `Enter`
+    /// `Enter`でコピーダイアログを進める
+    ///
+    /// The start row resolves the destination and asks before replacing an
+    /// existing one; the copy then runs in a background task. The result
+    /// step closes the dialog. Validation and engine errors are shown in the
+    /// dialog.
+    ///
+    /// 開始行は宛先を解決し、既存の宛先を置き換える前に確認します。
+    /// その後コピーはバックグラウンドタスクで実行されます。結果表示で
+    /// ダイアログを閉じます。検証エラーとエンジンエラーはダイアログ内に表示されます。
+    pub async fn copy_dialog_enter(&mut self) {
+        let repository = self
+            .copy_dialog
+            .as_ref()
+            .map(|dialog| self.repository_for(&dialog.container_id));
+        let (Some(dialog), Some(repository)) = (self.copy_dialog.as_mut(), repository) else {
+            return;
+        };
+        self.last_activity = Instant::now();
+        let service = CopyService::new(repository);
+
+        match &dialog.stage {
+            CopyStage::Options => {
+                if !dialog.activate_field() {
+                    return;
+                }
+                match service.plan(dialog.request()).await {
+                    Ok(plan) if plan.overwrites => dialog.confirm_overwrite(plan),
+                    Ok(plan) => Self::start_copy(dialog, service, plan),
+                    Err(error) => dialog.message = Some(error.to_string()),
+                }
+            }
+            CopyStage::ConfirmOverwrite(plan) => {
+                let plan = plan.clone();
+                Self::start_copy(dialog, service, plan);
+            }
+            CopyStage::Running { .. } => {}
+            CopyStage::Done(_) => self.close_copy_dialog(),
+        }
+    }
+
+    /// Spawn the copy described by `plan` and show its progress
+    /// `plan`が示すコピーを起動し進捗を表示
+    fn start_copy(dialog: &mut CopyDialog, service: CopyService, plan: CopyPlan) {
+        let (sender, receiver) = tokio::sync::watch::channel(CopyProgress::default());
+        let task_plan = plan.clone();
+        let task = tokio::spawn(async move { service.execute(&task_plan, &sender).await });
+        dialog.show_running(plan, receiver, task);
+    }
+
+    /// Step back in the copy dialog, closing it from the first step or the result
+    /// コピーダイアログで前の手順に戻る（最初の手順または結果表示からは閉じる）
+    pub fn copy_dialog_back(&mut self) {
+        if self.copy_dialog.as_mut().is_some_and(CopyDialog::back) {
+            self.close_copy_dialog();
+        } else {
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Collect the outcome of a finished background copy
+    /// 終了したバックグラウンドコピーの結果を回収
+    pub async fn poll_copy_dialog(&mut self) {
+        if let Some(dialog) = self.copy_dialog.as_mut() {
+            dialog.poll().await;
+        }
+    }
+}
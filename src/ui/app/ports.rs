@@ -0,0 +1,293 @@
+// src/ui/app/ports.rs
+// Published ports view and local port forwards
+// 公開ポートビューとローカルポートフォワード
+
+use super::{App, NavigationDirection, ViewState};
+use crate::app::port_forward_service::PortForwardService;
+use crate::domain::value_objects::{ContainerId, PortProtocol};
+use crate::error::DockaError;
+use crate::ui::ports_view::{ForwardInput, ForwardRequest, PortsView};
+use std::net::SocketAddr;
+use std::time::Instant;
+
+/// Published ports view and the local port forwards started from it
+/// 公開ポートビューと、そこから開始したローカルポートフォワード
+#[derive(Debug, Default)]
+pub struct PortForwarding {
+    /// Published ports view (`None` when closed)
+    /// 公開ポートビュー（閉じている場合は`None`）
+    pub view: Option<PortsView>,
+
+    /// Local port forwards to container ports, kept while other views are shown
+    /// コンテナポートへのローカルポートフォワード（他のビューの表示中も維持）
+    pub forwards: PortForwardService,
+}
+
+impl App {
+    /// Show the ports of all containers
+    /// 全コンテナのポートを表示
+    ///
+    /// The ports come from an unfiltered listing, so the owner of a host port
+    /// is found even while the container list is filtered.
+    /// ポートは絞り込まれていない一覧から取得するため、コンテナリストが
+    /// 絞り込まれていてもホストポートの所有者が見つかります。
+    pub async fn open_ports_view(&mut self) {
+        let mut view = PortsView::new();
+        view.set_forward_count(self.ports.forwards.forwards().len());
+        self.ports.view = Some(view);
+        self.view_state = ViewState::Ports;
+        self.reload_ports_view().await;
+    }
+
+    /// List the containers of the ports view again
+    /// ポートビューのコンテナを再度一覧取得
+    ///
+    /// Engine errors are shown in the view.
+    /// エンジンエラーはビュー内に表示されます。
+    pub async fn reload_ports_view(&mut self) {
+        if self.ports.view.is_none() {
+            return;
+        }
+        let result = self.docker_repository.list_containers().await;
+        if let Some(view) = self.ports.view.as_mut() {
+            view.show(result);
+            self.last_activity = Instant::now();
+        }
+    }
+
+    /// Forward the highlighted port, or stop the highlighted forward
+    /// 選択中のポートを転送、または選択中のフォワードを停止
+    ///
+    /// The outcome is shown as a notice in the view.
+    /// 結果はビュー内の通知として表示されます。
+    pub async fn toggle_highlighted_forward(&mut self) {
+        let Some(view) = self.ports.view.as_ref() else {
+            return;
+        };
+        let notice = if let Some(index) = view.highlighted_forward() {
+            let forward = &self.ports.forwards.forwards()[index];
+            let notice = format!("Stopped forwarding {}", forward.local_addr);
+            let id = forward.id;
+            self.ports.forwards.stop(id);
+            notice
+        } else if let Some(row) = view.highlighted().cloned() {
+            if row.mapping.protocol == PortProtocol::Tcp {
+                let request = ForwardRequest::same_port(row.mapping.container_port);
+                self.start_port_forward(&row.container_id, &row.container_name, &request)
+                    .await
+            } else {
+                format!("Only TCP ports can be forwarded, not {}", row.mapping)
+            }
+        } else {
+            return;
+        };
+        self.show_forward_notice(notice);
+    }
+
+    /// Open the field for forwarding any port of a container
+    /// コンテナの任意のポートを転送する入力欄を開く
+    ///
+    /// The field targets the container of the highlighted port, or the
+    /// container selected in the list, so ports that are neither published
+    /// nor exposed can be forwarded too.
+    /// 入力欄は選択中のポートのコンテナ、またはコンテナリストで選択中の
+    /// コンテナを対象とするため、公開も`expose`もされていないポートも転送できます。
+    pub fn open_forward_input(&mut self) {
+        let target = self
+            .ports
+            .view
+            .as_ref()
+            .and_then(PortsView::highlighted)
+            .map(|row| {
+                (
+                    row.container_id.clone(),
+                    row.container_name.clone(),
+                    Some(row.mapping.container_port),
+                )
+            })
+            .or_else(|| {
+                self.selected_container().map(|container| {
+                    (
+                        container.id.clone(),
+                        container.display_name().to_string(),
+                        None,
+                    )
+                })
+            });
+        let Some(view) = self.ports.view.as_mut() else {
+            return;
+        };
+        match target {
+            Some((id, name, port)) => view.forward_input = Some(ForwardInput::new(id, name, port)),
+            None => view.notice = Some("Select a container to forward a port from".to_string()),
+        }
+        self.last_activity = Instant::now();
+    }
+
+    /// Start the forward typed into the forward input
+    /// フォワード入力欄に入力したフォワードを開始
+    ///
+    /// An invalid entry keeps the field open with the error.
+    /// 無効な入力の場合はエラー付きで入力欄を開いたままにします。
+    pub(super) async fn submit_forward_input(&mut self) {
+        let Some(input) = self
+            .ports
+            .view
+            .as_mut()
+            .and_then(|view| view.forward_input.as_mut())
+        else {
+            return;
+        };
+        let request = match input.request() {
+            Ok(request) => request,
+            Err(error) => {
+                input.message = Some(error.to_string());
+                return;
+            }
+        };
+        let (id, name) = (input.container_id.clone(), input.container_name.clone());
+        if let Some(view) = self.ports.view.as_mut() {
+            view.forward_input = None;
+        }
+        let notice = self.start_port_forward(&id, &name, &request).await;
+        self.show_forward_notice(notice);
+    }
+
+    /// Show the outcome of a forward change and the new number of forwards
+    /// フォワードの変更結果と新しいフォワード数を表示
+    fn show_forward_notice(&mut self, notice: String) {
+        let forward_count = self.ports.forwards.forwards().len();
+        if let Some(view) = self.ports.view.as_mut() {
+            view.set_forward_count(forward_count);
+            view.notice = Some(notice);
+        }
+        self.last_activity = Instant::now();
+    }
+
+    /// Start forwarding a local port to a container port on one of its networks
+    /// コンテナのネットワークの1つにあるコンテナポートへのローカルポートの転送を開始
+    ///
+    /// The network named in the request is used, otherwise the first by
+    /// name. Containers of endpoints whose addresses this machine cannot
+    /// reach (see `remember_routable_endpoint`) are refused rather than
+    /// given a listener that drops every connection. A taken local port is
+    /// reported rather than replaced. Returns the notice describing the outcome.
+    ///
+    /// 要求で指定されたネットワーク、なければ名前順で最初のネットワークを使用します。
+    /// このマシンからアドレスに到達できないエンドポイント（`remember_routable_endpoint`
+    /// を参照）のコンテナは、全ての接続を切るリスナーを作る代わりに拒否します。
+    /// 使用中のローカルポートは置き換えずに報告します。結果を説明する通知を返します。
+    async fn start_port_forward(
+        &mut self,
+        container_id: &ContainerId,
+        container_name: &str,
+        request: &ForwardRequest,
+    ) -> String {
+        let port = request.container_port;
+        let endpoint = self.endpoint_of(container_id);
+        if !self.routable_endpoints.contains(&endpoint) {
+            return format!(
+                "Cannot forward {container_name}: container addresses on '{endpoint}' \
+                 are not reachable from this machine (remote, VM or rootless engine)"
+            );
+        }
+        let details = match self
+            .repository_for(container_id)
+            .container_details(container_id)
+            .await
+        {
+            Ok(details) => details,
+            Err(error) => return error.user_message(),
+        };
+        let networks = details.network_addresses;
+        let address = request.network.as_ref().map_or_else(
+            || networks.first(),
+            |network| networks.iter().find(|address| address.network == *network),
+        );
+        let Some(address) = address else {
+            let names: Vec<&str> = networks
+                .iter()
+                .map(|address| address.network.as_str())
+                .collect();
+            return match &request.network {
+                Some(network) if !names.is_empty() => format!(
+                    "{container_name} is not on network '{network}' (networks: {})",
+                    names.join(", ")
+                ),
+                _ => format!("{container_name} has no network address (is it running?)"),
+            };
+        };
+
+        let label = format!("{container_name} {port}/tcp");
+        let target = SocketAddr::new(address.ip, port);
+        match self
+            .ports
+            .forwards
+            .start(request.local_port, target, label)
+            .await
+        {
+            Ok(forward) => {
+                let others = if request.network.is_none() && networks.len() > 1 {
+                    " (PORT@NETWORK picks another)"
+                } else {
+                    ""
+                };
+                format!(
+                    "Forwarding {} → {} on {}{others}",
+                    forward.local_addr, forward.target, address.network
+                )
+            }
+            Err(DockaError::InvalidInput { message }) => {
+                format!("{message}; press f and enter e.g. 0:{port} for a free local port")
+            }
+            Err(error) => error.user_message(),
+        }
+    }
+
+    /// Endpoint of a container, from the ports view or the container list
+    /// ポートビューまたはコンテナリストから求めたコンテナのエンドポイント
+    ///
+    /// Rows of the multi-host view carry their host; other rows belong to
+    /// the active endpoint.
+    /// 複数ホストビューの行はホストを持ち、それ以外の行はアクティブな
+    /// エンドポイントに属します。
+    fn endpoint_of(&self, container_id: &ContainerId) -> String {
+        let row_host = self.ports.view.as_ref().and_then(|view| {
+            view.rows
+                .iter()
+                .find(|row| row.container_id == *container_id)
+                .map(|row| row.host.clone())
+        });
+        let host = row_host.unwrap_or_else(|| {
+            self.listed_containers
+                .iter()
+                .find(|container| container.id == *container_id)
+                .and_then(|container| container.host.clone())
+        });
+        host.unwrap_or_else(|| self.endpoint_name.clone())
+    }
+
+    /// Leave the ports view and return to the container list
+    /// ポートビューを閉じてコンテナリストに戻る
+    pub fn close_ports_view(&mut self) {
+        self.ports.view = None;
+        self.view_state = ViewState::ContainerList;
+        self.last_activity = Instant::now();
+    }
+
+    /// Check whether the ports view is shown
+    /// ポートビューが表示されているかチェック
+    #[must_use]
+    pub const fn is_ports_view_open(&self) -> bool {
+        matches!(self.view_state, ViewState::Ports)
+    }
+
+    /// Move the cursor of the ports view
+    /// ポートビューのカーソルを移動
+    pub fn navigate_ports_view(&mut self, direction: NavigationDirection) {
+        if let Some(view) = self.ports.view.as_mut() {
+            view.navigate(direction);
+            self.last_activity = Instant::now();
+        }
+    }
+}
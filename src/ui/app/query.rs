@@ -0,0 +1,352 @@
+// src/ui/app/query.rs
+// Filtering, searching, sorting and grouping of the container list
+// コンテナ一覧のフィルタ、検索、並べ替え、グループ化
+
+use super::{App, NavigationDirection};
+use crate::domain::entities::{
+    Container, ContainerFilter, ContainerStats, parse_filter_expression,
+};
+use crate::domain::repositories::DockerRepository;
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::filter_bar::FilterBar;
+use crate::ui::list_sort::{ListSort, SortKey};
+use crate::ui::search_bar::SearchBar;
+use futures_util::future::join_all;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::task::JoinHandle;
+
+/// Filter, search, sort and grouping of the container list
+/// コンテナ一覧のフィルタ、検索、並び順、グループ化
+///
+/// Kept for the session: switching endpoints keeps the query and drops
+/// only the usage samples.
+/// セッション中保持され、エンドポイントを切り替えても使用量の取得結果以外は維持されます。
+#[derive(Debug, Default)]
+pub struct ListQuery {
+    /// Filter applied to the containers on every refresh
+    /// 更新のたびにコンテナに適用するフィルタ
+    pub filter: ContainerFilter,
+
+    /// Label key the containers are grouped by, e.g. `team` (`None` when ungrouped)
+    /// コンテナをグループ化するラベルのキー（例: `team`、グループ化しない場合は`None`）
+    pub group_by_label: Option<String>,
+
+    /// Incremental search over the list, opened with `/`
+    /// `/`で開く一覧のインクリメンタル検索
+    pub search: SearchBar,
+
+    /// Sort order of the list, kept for the session across endpoints
+    /// 一覧の並び順（エンドポイントをまたいでセッション中保持）
+    pub sort: ListSort,
+
+    /// Resource usage of running containers, sampled while sorting by CPU or memory
+    /// 実行中のコンテナのリソース使用量（CPUまたはメモリで並べ替え中に取得）
+    pub container_stats: HashMap<ContainerId, ContainerStats>,
+
+    /// Background sampling of `container_stats` (`None` when idle)
+    /// `container_stats`のバックグラウンド取得（待機中は`None`）
+    pub(super) stats_task: Option<JoinHandle<HashMap<ContainerId, ContainerStats>>>,
+
+    /// Filter expression bar, open while editing with `:`
+    /// フィルタ式バー（`:`で編集中のみ開いている）
+    pub filter_bar: Option<FilterBar>,
+
+    /// Filter expression applied to the list (`None` when there is none)
+    /// 一覧に適用中のフィルタ式（ない場合は`None`）
+    pub filter_expression: Option<String>,
+
+    /// Named filter expressions from the configuration file
+    /// 設定ファイルの名前付きフィルタ式
+    pub saved_filters: BTreeMap<String, String>,
+}
+
+impl App {
+    /// Rebuild the shown containers from the last listing
+    /// 最後の一覧取得から表示するコンテナを再構築
+    ///
+    /// Applies the filter, then the search while it is being typed, then the
+    /// sort order and the grouping. The selected container stays selected when
+    /// still shown; otherwise the index is clamped to the list.
+    /// フィルタ、入力中の検索、並び順、グループ化の順に適用します。選択中のコンテナが
+    /// まだ表示されている場合は選択を維持し、そうでなければインデックスを
+    /// 一覧の範囲に収めます。
+    pub fn apply_filters(&mut self) {
+        let selected = self
+            .selected_container()
+            .map(|container| container.id.clone());
+        self.containers = self
+            .listed_containers
+            .iter()
+            .filter(|container| {
+                self.query.filter.matches(container)
+                    && (!self.query.search.editing || self.query.search.matches(container))
+            })
+            .cloned()
+            .collect();
+        self.query
+            .sort
+            .apply(&mut self.containers, &self.query.container_stats);
+        self.group_containers();
+        self.selected_index = selected
+            .and_then(|id| {
+                self.containers
+                    .iter()
+                    .position(|container| container.id == id)
+            })
+            .unwrap_or_else(|| {
+                self.selected_index
+                    .min(self.containers.len().saturating_sub(1))
+            });
+    }
+
+    /// Label value a container is grouped under, `None` when it lacks the key
+    /// コンテナがグループ化されるラベル値（キーを持たない場合は`None`）
+    #[must_use]
+    pub fn group_of<'a>(&self, container: &'a Container) -> Option<&'a str> {
+        self.query
+            .group_by_label
+            .as_ref()
+            .and_then(|key| container.get_label(key))
+            .map(String::as_str)
+    }
+
+    /// Order the containers by their group, containers without the key last
+    /// コンテナをグループ順に並べる（キーを持たないコンテナは最後）
+    ///
+    /// The sort is stable, so the order within a group is kept.
+    /// ソートは安定しているため、グループ内の順序は維持されます。
+    fn group_containers(&mut self) {
+        let Some(key) = self.query.group_by_label.as_ref() else {
+            return;
+        };
+        self.containers.sort_by(|a, b| {
+            let group = |container: &Container| {
+                let value = container.get_label(key).cloned();
+                (value.is_none(), value)
+            };
+            group(a).cmp(&group(b))
+        });
+    }
+
+    /// Group the containers by a label key, keeping the selected container
+    /// 選択中のコンテナを維持したまま、コンテナをラベルのキーでグループ化
+    ///
+    /// Ungrouping keeps the grouped order until the next refresh.
+    /// グループ化の解除後も次の更新まではグループ化した順序を維持します。
+    pub fn set_group_by_label(&mut self, key: Option<String>) {
+        let selected = self
+            .selected_container()
+            .map(|container| container.id.clone());
+        self.query.group_by_label = key;
+        self.group_containers();
+        if let Some(index) = selected.and_then(|id| {
+            self.containers
+                .iter()
+                .position(|container| container.id == id)
+        }) {
+            self.selected_index = index;
+        }
+        self.last_activity = Instant::now();
+    }
+
+    /// Start typing a search query over the container list
+    /// コンテナリストの検索クエリの入力を開始
+    pub fn open_search(&mut self) {
+        self.query.search.open();
+        self.apply_filters();
+        self.last_activity = Instant::now();
+    }
+
+    /// Select the next or previous container matching the search, wrapping around
+    /// 検索に一致する次または前のコンテナを選択（端で折り返す）
+    ///
+    /// Does nothing without a query or when nothing matches.
+    /// クエリがない場合や一致するものがない場合は何もしません。
+    pub fn jump_to_match(&mut self, direction: NavigationDirection) {
+        if self.query.search.query().is_none() || self.containers.is_empty() {
+            return;
+        }
+        let len = self.containers.len();
+        let current = self.selected_index.min(len - 1);
+        let found = (1..=len)
+            .map(|step| match direction {
+                NavigationDirection::Next => (current + step) % len,
+                NavigationDirection::Previous => (current + len - step % len) % len,
+            })
+            .find(|index| self.query.search.matches(&self.containers[*index]));
+        if let Some(index) = found {
+            self.selected_index = index;
+        }
+        self.last_activity = Instant::now();
+    }
+
+    /// Sort the list by the next column, back to the daemon order after the last (`s`)
+    /// 次の列で一覧を並べ替える（最後の列の後はデーモンの順に戻る、`s`）
+    ///
+    /// Starts sampling the usage of running containers when the new column
+    /// is CPU or memory; the list is sorted again when the samples arrive.
+    /// 新しい列がCPUまたはメモリの場合、実行中のコンテナの使用量の取得を開始し、
+    /// 取得後に一覧を並べ替え直します。
+    pub fn cycle_sort_key(&mut self) {
+        self.query.sort.cycle_key();
+        self.request_container_stats();
+        self.apply_filters();
+        self.last_activity = Instant::now();
+    }
+
+    /// Reverse the order of the sorted column (`S`)
+    /// 並べ替え中の列の順序を反転（`S`）
+    pub fn reverse_sort(&mut self) {
+        self.query.sort.reverse();
+        self.apply_filters();
+        self.last_activity = Instant::now();
+    }
+
+    /// Sample the usage of the listed running containers in the background when the sort needs it
+    /// 並び順が必要とする場合、一覧の実行中のコンテナの使用量をバックグラウンドで取得
+    ///
+    /// Each sample takes about a second, so the event loop does not wait for
+    /// them; `poll_container_stats` picks them up. The previous samples are
+    /// used until then, and no new sampling starts while one is running.
+    /// Containers whose sample fails are left without usage and sort last.
+    /// 各取得に約1秒かかるため、イベントループは待たずに`poll_container_stats`で
+    /// 回収します。それまでは前回の値を使い、取得中は新たな取得を開始しません。
+    /// 取得に失敗したコンテナは使用量なしとなり、最後に並びます。
+    pub(super) fn request_container_stats(&mut self) {
+        if !self.query.sort.key.is_some_and(SortKey::needs_stats) {
+            self.cancel_container_stats();
+            return;
+        }
+        if self.query.stats_task.is_some() {
+            return;
+        }
+        let running: Vec<(ContainerId, Arc<dyn DockerRepository>)> = self
+            .listed_containers
+            .iter()
+            .filter(|container| container.is_running())
+            .map(|container| (container.id.clone(), self.repository_for(&container.id)))
+            .collect();
+        self.query.stats_task = Some(tokio::spawn(async move {
+            let samples = join_all(
+                running
+                    .iter()
+                    .map(|(id, repository)| repository.container_stats(id)),
+            )
+            .await;
+            running
+                .into_iter()
+                .zip(samples)
+                .filter_map(|((id, _), sample)| sample.ok().map(|stats| (id, stats)))
+                .collect()
+        }));
+    }
+
+    /// Drop the samples and stop any sampling in progress
+    /// 取得済みの値を破棄し、実行中の取得を停止
+    pub(super) fn cancel_container_stats(&mut self) {
+        if let Some(task) = self.query.stats_task.take() {
+            task.abort();
+        }
+        self.query.container_stats.clear();
+    }
+
+    /// Collect finished usage samples and sort the list again
+    /// 終了した使用量の取得結果を回収し、一覧を並べ替え直す
+    ///
+    /// Called on every pass of the event loop.
+    /// イベントループの毎回の処理で呼び出されます。
+    pub async fn poll_container_stats(&mut self) {
+        let Some(task) = self.query.stats_task.take_if(|task| task.is_finished()) else {
+            return;
+        };
+        if let Ok(samples) = task.await {
+            self.query.container_stats = samples;
+            self.apply_filters();
+        }
+    }
+
+    /// Open the filter bar on the expression currently applied
+    /// 適用中の式でフィルタバーを開く
+    pub fn open_filter_bar(&mut self) {
+        self.query.filter_bar = Some(FilterBar::new(
+            self.query.filter_expression.clone().unwrap_or_default(),
+        ));
+        self.last_activity = Instant::now();
+    }
+
+    /// Apply the expression of the filter bar and close it
+    /// フィルタバーの式を適用してバーを閉じる
+    ///
+    /// An empty expression removes the expression filter. A parse error is
+    /// shown in the bar, which stays open.
+    /// 空の式は式のフィルタを解除します。解析エラーはバーに表示され、
+    /// バーは開いたままになります。
+    pub fn apply_filter_expression(&mut self) {
+        let Some(bar) = self.query.filter_bar.as_mut() else {
+            return;
+        };
+        let expression = bar.expression().to_string();
+        match parse_filter_expression(&expression) {
+            Ok(parsed) => {
+                self.query.filter.conditions = parsed.conditions;
+                self.query.filter_expression = Some(expression).filter(|text| !text.is_empty());
+                self.query.filter_bar = None;
+                self.apply_filters();
+            }
+            Err(error) => bar.show_error(&error),
+        }
+    }
+
+    /// Save the expression of the filter bar under the typed name, then apply it
+    /// フィルタバーの式を入力した名前で保存して適用
+    ///
+    /// Invalid expressions are not saved. The configuration file is read
+    /// again before writing so settings edited meanwhile are kept.
+    /// 不正な式は保存しません。その間に編集された設定を維持するため、
+    /// 書き込む前に設定ファイルを再度読み込みます。
+    pub fn save_filter_expression(&mut self) {
+        let Some(bar) = self.query.filter_bar.as_mut() else {
+            return;
+        };
+        let Some(name) = bar.naming.take() else {
+            return;
+        };
+        let name = name.value().trim().to_string();
+        let expression = bar.expression().to_string();
+        if let Err(error) = parse_filter_expression(&expression) {
+            bar.show_error(&error);
+            return;
+        }
+        let Some(service) = self.config_service.as_ref() else {
+            self.query.saved_filters.insert(name, expression);
+            self.apply_filter_expression();
+            return;
+        };
+        let saved = service.load().and_then(|mut config| {
+            config.saved_filters.insert(name, expression);
+            service.save(&config).map(|()| config.saved_filters)
+        });
+        match saved {
+            Ok(saved_filters) => {
+                self.query.saved_filters = saved_filters;
+                self.apply_filter_expression();
+            }
+            Err(error) => bar.show_error(&error),
+        }
+    }
+
+    /// Toggle showing only containers that exited with an error, then refresh
+    /// エラーで終了したコンテナのみの表示を切り替えて更新
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the refresh (see `refresh_containers`).
+    /// 更新のエラーを返します（`refresh_containers`を参照）。
+    pub async fn toggle_failed_exits_filter(&mut self) -> DockaResult<()> {
+        self.query.filter.only_failed_exits = !self.query.filter.only_failed_exits;
+        self.refresh_containers().await
+    }
+}
@@ -0,0 +1,416 @@
+// src/ui/cleanup_assistant.rs
+// State of the stale container cleanup assistant
+// 古いコンテナのクリーンアップアシスタントの状態
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::prune_service::{PruneOptions, PrunePreview, PruneReport};
+use crate::domain::entities::{Container, ContainerFilter};
+use crate::error::{DockaError, DockaResult};
+use crate::ui::app::NavigationDirection;
+use crate::ui::text_input::TextInput;
+use crate::utils::time_formatter::parse_duration;
+
+/// Criterion text field of the assistant
+/// アシスタントの基準テキストフィールド
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupField {
+    /// Minimum time since the container exited (e.g. `7d`)
+    /// コンテナ終了からの最小経過時間（例: `7d`）
+    ExitedFor,
+
+    /// Minimum time since the container was created
+    /// コンテナ作成からの最小経過時間
+    CreatedFor,
+
+    /// Required labels (e.g. `ci=true`)
+    /// 必須ラベル（例: `ci=true`）
+    Labels,
+
+    /// Required exit code
+    /// 必須の終了コード
+    ExitCode,
+}
+
+impl CleanupField {
+    /// Every criterion in display order
+    /// 表示順の全基準
+    pub const ALL: [Self; 4] = [
+        Self::ExitedFor,
+        Self::CreatedFor,
+        Self::Labels,
+        Self::ExitCode,
+    ];
+
+    /// Label shown next to the field
+    /// フィールドの横に表示するラベル
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::ExitedFor => "Exited more than",
+            Self::CreatedFor => "Created more than",
+            Self::Labels => "Labels",
+            Self::ExitCode => "Exit code",
+        }
+    }
+
+    /// Text shown while the field is empty
+    /// フィールドが空の間に表示するテキスト
+    #[must_use]
+    pub const fn placeholder(self) -> &'static str {
+        match self {
+            Self::ExitedFor | Self::CreatedFor => "any time ago (e.g. 12h, 7d)",
+            Self::Labels => "any labels (e.g. ci=true)",
+            Self::ExitCode => "any exit code",
+        }
+    }
+}
+
+/// Row of the assistant view
+/// アシスタントビューの行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupRow {
+    /// A criterion text field
+    /// 基準テキストフィールド
+    Field(CleanupField),
+
+    /// Remove the selected candidates
+    /// 選択した候補を削除
+    Remove,
+
+    /// A candidate, by index into `candidates`
+    /// 候補（`candidates`のインデックス）
+    Candidate(usize),
+}
+
+/// Assistant listing stopped containers that match cleanup criteria
+/// クリーンアップ基準に一致する停止中のコンテナを一覧表示するアシスタント
+///
+/// Every candidate starts selected; `Enter` toggles a candidate, and the
+/// remove row asks once for confirmation before removing the selection.
+///
+/// 全候補は選択状態で始まります。`Enter`で候補を切り替え、削除行は
+/// 選択を削除する前に一度確認を求めます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanupAssistant {
+    /// "Exited more than" criterion
+    /// 「終了から経過」基準
+    pub exited_for: TextInput,
+
+    /// "Created more than" criterion
+    /// 「作成から経過」基準
+    pub created_for: TextInput,
+
+    /// Label criterion
+    /// ラベル基準
+    pub labels: TextInput,
+
+    /// Exit code criterion
+    /// 終了コード基準
+    pub exit_code: TextInput,
+
+    /// Containers matching the criteria
+    /// 基準に一致するコンテナ
+    pub candidates: Vec<Container>,
+
+    /// IDs of candidates the user excluded
+    /// ユーザーが除外した候補のID
+    pub deselected: HashSet<String>,
+
+    /// Highlighted row
+    /// 選択行
+    pub cursor: usize,
+
+    /// Whether the highlighted field receives keys
+    /// 選択中のフィールドがキーを受け取っているか
+    pub editing: bool,
+
+    /// Whether removal of the selection awaits confirmation
+    /// 選択の削除が確認待ちか
+    pub confirming: bool,
+
+    /// Outcome of the last removal
+    /// 最後の削除の結果
+    pub report: Option<PruneReport>,
+
+    /// Last validation or engine error
+    /// 最後の検証エラーまたはエンジンエラー
+    pub message: Option<String>,
+}
+
+impl Default for CleanupAssistant {
+    fn default() -> Self {
+        Self {
+            exited_for: TextInput::with_value("7d"),
+            created_for: TextInput::new(),
+            labels: TextInput::new(),
+            exit_code: TextInput::new(),
+            candidates: Vec::new(),
+            deselected: HashSet::new(),
+            cursor: 0,
+            editing: false,
+            confirming: false,
+            report: None,
+            message: None,
+        }
+    }
+}
+
+impl CleanupAssistant {
+    /// Create an assistant looking for containers exited more than 7 days ago
+    /// 7日より前に終了したコンテナを探すアシスタントを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Text field of a criterion
+    /// 基準のテキストフィールド
+    #[must_use]
+    pub const fn input(&self, field: CleanupField) -> &TextInput {
+        match field {
+            CleanupField::ExitedFor => &self.exited_for,
+            CleanupField::CreatedFor => &self.created_for,
+            CleanupField::Labels => &self.labels,
+            CleanupField::ExitCode => &self.exit_code,
+        }
+    }
+
+    const fn input_mut(&mut self, field: CleanupField) -> &mut TextInput {
+        match field {
+            CleanupField::ExitedFor => &mut self.exited_for,
+            CleanupField::CreatedFor => &mut self.created_for,
+            CleanupField::Labels => &mut self.labels,
+            CleanupField::ExitCode => &mut self.exit_code,
+        }
+    }
+
+    /// Number of rows: criteria, the remove row and candidates
+    /// 行数: 基準、削除行、候補
+    #[must_use]
+    pub const fn row_count(&self) -> usize {
+        CleanupField::ALL.len() + 1 + self.candidates.len()
+    }
+
+    /// Row at `index`
+    /// `index`の行
+    #[must_use]
+    pub const fn row(&self, index: usize) -> Option<CleanupRow> {
+        let fields = CleanupField::ALL.len();
+        match index {
+            i if i < fields => Some(CleanupRow::Field(CleanupField::ALL[i])),
+            i if i == fields => Some(CleanupRow::Remove),
+            i if i - fields - 1 < self.candidates.len() => {
+                Some(CleanupRow::Candidate(i - fields - 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Highlighted row
+    /// 選択行
+    #[must_use]
+    pub const fn highlighted_row(&self) -> Option<CleanupRow> {
+        self.row(self.cursor)
+    }
+
+    /// Move the highlight, stopping at the first and last rows
+    /// 選択を移動（最初と最後の行で停止）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        self.cursor = match direction {
+            NavigationDirection::Next => (self.cursor + 1).min(self.row_count() - 1),
+            NavigationDirection::Previous => self.cursor.saturating_sub(1),
+        };
+        self.confirming = false;
+    }
+
+    /// Check whether a candidate will be removed
+    /// 候補が削除対象かチェック
+    #[must_use]
+    pub fn is_selected(&self, container: &Container) -> bool {
+        !self.deselected.contains(container.id.as_str())
+    }
+
+    /// Candidates that will be removed
+    /// 削除対象の候補
+    #[must_use]
+    pub fn selected(&self) -> Vec<&Container> {
+        self.candidates
+            .iter()
+            .filter(|container| self.is_selected(container))
+            .collect()
+    }
+
+    /// Include or exclude a candidate
+    /// 候補を含める、または除外する
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(container) = self.candidates.get(index) {
+            let id = container.id.as_str().to_string();
+            if !self.deselected.remove(&id) {
+                self.deselected.insert(id);
+            }
+            self.confirming = false;
+        }
+    }
+
+    /// Preview of removing the selected candidates
+    /// 選択した候補の削除のプレビュー
+    #[must_use]
+    pub fn removal_preview(&self) -> PrunePreview {
+        PrunePreview {
+            candidates: self.selected().into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Replace the candidates, keeping exclusions of containers still listed
+    /// 候補を置き換え（一覧に残るコンテナの除外は維持）
+    pub fn set_candidates(&mut self, candidates: Vec<Container>) {
+        self.deselected
+            .retain(|id| candidates.iter().any(|c| c.id.as_str() == id));
+        self.candidates = candidates;
+        self.cursor = self.cursor.min(self.row_count() - 1);
+        self.confirming = false;
+    }
+
+    /// Feed a raw key to the field being edited
+    /// 編集中のフィールドに生のキーを渡す
+    ///
+    /// Returns `true` when `Enter` applies the criteria; `Esc` only stops editing.
+    /// `Enter`で基準を適用する場合は`true`を返します。`Esc`は編集を終了するだけです。
+    pub fn handle_text_key(&mut self, key_event: KeyEvent) -> bool {
+        let Some(CleanupRow::Field(field)) = self.highlighted_row() else {
+            self.editing = false;
+            return false;
+        };
+
+        match key_event.code {
+            KeyCode::Enter => {
+                self.editing = false;
+                true
+            }
+            KeyCode::Esc => {
+                self.editing = false;
+                false
+            }
+            _ => {
+                self.input_mut(field).handle_key(key_event);
+                false
+            }
+        }
+    }
+
+    /// Build the container filter from the criteria
+    /// 基準からコンテナフィルタを構築
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When a criterion cannot be parsed
+    pub fn filter(&self, now: DateTime<Utc>) -> DockaResult<ContainerFilter> {
+        let cutoff = |input: &TextInput| -> DockaResult<Option<DateTime<Utc>>> {
+            if input.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(now - parse_duration(input.value())?))
+            }
+        };
+        let exit_code =
+            if self.exit_code.is_empty() {
+                None
+            } else {
+                let text = self.exit_code.value().trim();
+                Some(text.parse().map_err(|_| {
+                    DockaError::invalid_input(format!("Invalid exit code '{text}'"))
+                })?)
+            };
+
+        Ok(ContainerFilter {
+            finished_before: cutoff(&self.exited_for)?,
+            created_before: cutoff(&self.created_for)?,
+            labels: PruneOptions::parse_labels(self.labels.value())?,
+            exit_code,
+            ..ContainerFilter::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crossterm::event::KeyModifiers;
+
+    fn container(id: &str) -> Container {
+        Container::builder()
+            .id(id)
+            .image("runner:latest")
+            .status(ContainerStatus::Exited { exit_code: 1 })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_filter_from_criteria() {
+        // Test that the criteria become a container filter
+        // 基準がコンテナフィルタになることをテスト
+        let now = Utc::now();
+        let mut assistant = CleanupAssistant::new();
+        assistant.labels = TextInput::with_value("ci=true");
+        assistant.exit_code = TextInput::with_value("137");
+
+        let filter = assistant.filter(now).unwrap();
+        assert_eq!(
+            filter.finished_before,
+            Some(now - chrono::Duration::days(7))
+        );
+        assert_eq!(filter.created_before, None);
+        assert_eq!(filter.labels["ci"], "true");
+        assert_eq!(filter.exit_code, Some(137));
+
+        assistant.exit_code = TextInput::with_value("oom");
+        assert!(assistant.filter(now).is_err());
+    }
+
+    #[test]
+    fn test_rows_and_selection() {
+        // Test row mapping, toggling and keeping exclusions across reloads
+        // 行の対応付け、切り替え、再読み込み後の除外の維持をテスト
+        let mut assistant = CleanupAssistant::new();
+        assistant.set_candidates(vec![container("a"), container("b")]);
+        assert_eq!(assistant.row_count(), 7);
+        assert_eq!(assistant.row(4), Some(CleanupRow::Remove));
+        assert_eq!(assistant.row(6), Some(CleanupRow::Candidate(1)));
+        assert_eq!(assistant.row(7), None);
+
+        assistant.toggle(0);
+        assert_eq!(assistant.selected().len(), 1);
+        assert_eq!(assistant.removal_preview().candidates[0].id, "b");
+
+        assistant.cursor = 6;
+        assistant.set_candidates(vec![container("a")]);
+        assert_eq!(assistant.cursor, 5);
+        assert!(assistant.selected().is_empty());
+        assistant.set_candidates(vec![container("b")]);
+        assert!(assistant.deselected.is_empty());
+    }
+
+    #[test]
+    fn test_editing_applies_on_enter() {
+        // Test that Enter applies the edited criterion and Esc does not
+        // Enterで編集した基準が適用され、Escでは適用されないことをテスト
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut assistant = CleanupAssistant::new();
+        assistant.cursor = 3;
+        assistant.editing = true;
+
+        assert!(!assistant.handle_text_key(key(KeyCode::Char('1'))));
+        assert!(assistant.handle_text_key(key(KeyCode::Enter)));
+        assert!(!assistant.editing);
+        assert_eq!(assistant.exit_code.value(), "1");
+
+        assistant.editing = true;
+        assert!(!assistant.handle_text_key(key(KeyCode::Esc)));
+        assert!(!assistant.editing);
+    }
+}
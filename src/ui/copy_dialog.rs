@@ -0,0 +1,363 @@
+// src/ui/copy_dialog.rs
+// State of the copy files dialog
+// ファイルコピーダイアログの状態
+
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::app::copy_service::{CopyDirection, CopyPlan, CopyProgress, CopyReport, CopyRequest};
+use crate::domain::entities::Container;
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+use crate::ui::text_input::TextInput;
+
+/// Row of the options step
+/// オプション手順の行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyField {
+    /// Toggle the direction
+    /// 方向を切り替え
+    Direction,
+
+    /// Container path text field
+    /// コンテナパスのテキストフィールド
+    ContainerPath,
+
+    /// Local path text field
+    /// ローカルパスのテキストフィールド
+    LocalPath,
+
+    /// Start the copy
+    /// コピーを開始
+    Start,
+}
+
+impl CopyField {
+    /// Every row of the options step in display order
+    /// 表示順のオプション手順の全行
+    pub const ALL: [Self; 4] = [
+        Self::Direction,
+        Self::ContainerPath,
+        Self::LocalPath,
+        Self::Start,
+    ];
+}
+
+/// Step of the copy dialog
+/// コピーダイアログの手順
+#[derive(Debug)]
+pub enum CopyStage {
+    /// Choosing direction and paths
+    /// 方向とパスを選択中
+    Options,
+
+    /// Asking before replacing an existing destination
+    /// 既存の宛先を置き換える前に確認中
+    ConfirmOverwrite(CopyPlan),
+
+    /// Copy running in the background
+    /// バックグラウンドでコピー実行中
+    Running {
+        /// Copy being run
+        /// 実行中のコピー
+        plan: CopyPlan,
+
+        /// Latest progress published by the copy task
+        /// コピータスクが公開した最新の進捗
+        progress: watch::Receiver<CopyProgress>,
+
+        /// Copy task
+        /// コピータスク
+        task: JoinHandle<DockaResult<CopyReport>>,
+    },
+
+    /// Copy finished, failed or was cancelled
+    /// コピーが完了、失敗、または取り消された
+    Done(Result<CopyReport, String>),
+}
+
+/// Dialog copying a file or directory between the selected container and a local path
+/// 選択中のコンテナとローカルパスの間でファイルまたはディレクトリをコピーするダイアログ
+///
+/// The copy runs in a background task so the progress keeps updating;
+/// `Esc` while it runs cancels it. An existing destination is only replaced
+/// after a second `Enter`.
+///
+/// コピーはバックグラウンドタスクで実行されるため進捗は更新され続け、
+/// 実行中の`Esc`で取り消します。既存の宛先は2回目の`Enter`の後でのみ置き換えます。
+#[derive(Debug)]
+pub struct CopyDialog {
+    /// Container to copy from or into
+    /// コピー元またはコピー先のコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of the container
+    /// コンテナの表示名
+    pub container_name: String,
+
+    /// Direction of the copy
+    /// コピーの方向
+    pub direction: CopyDirection,
+
+    /// Absolute path inside the container
+    /// コンテナ内の絶対パス
+    pub container_path: TextInput,
+
+    /// Path on the local filesystem
+    /// ローカルファイルシステム上のパス
+    pub local_path: TextInput,
+
+    /// Highlighted row of the options step
+    /// オプション手順で選択中の行
+    pub cursor: usize,
+
+    /// Whether the highlighted text field receives keys
+    /// 選択中のテキストフィールドがキーを受け取っているか
+    pub editing: bool,
+
+    /// Current step
+    /// 現在の手順
+    pub stage: CopyStage,
+
+    /// Last validation error
+    /// 最後の検証エラー
+    pub message: Option<String>,
+}
+
+impl CopyDialog {
+    /// Create a dialog copying out of `container` into the working directory
+    /// `container`から作業ディレクトリへコピーするダイアログを作成
+    #[must_use]
+    pub fn new(container: &Container) -> Self {
+        Self {
+            container_id: container.id.clone(),
+            container_name: container.name.clone(),
+            direction: CopyDirection::FromContainer,
+            container_path: TextInput::new(),
+            local_path: TextInput::with_value("."),
+            cursor: 0,
+            editing: false,
+            stage: CopyStage::Options,
+            message: None,
+        }
+    }
+
+    /// Row highlighted in the options step
+    /// オプション手順で選択中の行
+    #[must_use]
+    pub fn highlighted_field(&self) -> Option<CopyField> {
+        matches!(self.stage, CopyStage::Options)
+            .then(|| CopyField::ALL.get(self.cursor).copied())
+            .flatten()
+    }
+
+    /// Move the highlight of the options step (circular)
+    /// オプション手順の選択を移動（循環）
+    pub const fn navigate(&mut self, direction: NavigationDirection) {
+        if !matches!(self.stage, CopyStage::Options) {
+            return;
+        }
+        let count = CopyField::ALL.len();
+        self.cursor = match direction {
+            NavigationDirection::Next => (self.cursor + 1) % count,
+            NavigationDirection::Previous => (self.cursor + count - 1) % count,
+        };
+    }
+
+    /// Toggle the direction or start editing the highlighted field
+    /// 方向を切り替え、または選択中のフィールドの編集を開始
+    ///
+    /// Returns `true` when the highlighted row asks to start the copy.
+    /// 選択行がコピー開始を要求する場合は`true`を返します。
+    pub fn activate_field(&mut self) -> bool {
+        match self.highlighted_field() {
+            Some(CopyField::Direction) => self.direction = self.direction.reversed(),
+            Some(CopyField::ContainerPath | CopyField::LocalPath) => self.editing = true,
+            Some(CopyField::Start) => return true,
+            None => {}
+        }
+        false
+    }
+
+    /// Feed a raw key to the field being edited
+    /// 編集中のフィールドに生のキーを渡す
+    ///
+    /// `Enter` and `Esc` finish editing.
+    /// `Enter`と`Esc`で編集を終了します。
+    pub fn handle_text_key(&mut self, key_event: KeyEvent) {
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            self.editing = false;
+            return;
+        }
+
+        match self.highlighted_field() {
+            Some(CopyField::ContainerPath) => {
+                self.container_path.handle_key(key_event);
+            }
+            Some(CopyField::LocalPath) => {
+                self.local_path.handle_key(key_event);
+            }
+            _ => self.editing = false,
+        }
+    }
+
+    /// Build the copy request from the dialog inputs
+    /// ダイアログの入力からコピーリクエストを構築
+    #[must_use]
+    pub fn request(&self) -> CopyRequest {
+        CopyRequest {
+            container_id: self.container_id.clone(),
+            direction: self.direction,
+            container_path: self.container_path.value().to_string(),
+            local_path: PathBuf::from(self.local_path.value().trim()),
+        }
+    }
+
+    /// Show the overwrite confirmation for `plan`
+    /// `plan`の上書き確認を表示
+    pub fn confirm_overwrite(&mut self, plan: CopyPlan) {
+        self.stage = CopyStage::ConfirmOverwrite(plan);
+        self.message = None;
+    }
+
+    /// Show a running copy
+    /// 実行中のコピーを表示
+    pub fn show_running(
+        &mut self,
+        plan: CopyPlan,
+        progress: watch::Receiver<CopyProgress>,
+        task: JoinHandle<DockaResult<CopyReport>>,
+    ) {
+        self.stage = CopyStage::Running {
+            plan,
+            progress,
+            task,
+        };
+        self.message = None;
+    }
+
+    /// Latest progress of a running copy
+    /// 実行中のコピーの最新の進捗
+    #[must_use]
+    pub fn progress(&self) -> Option<CopyProgress> {
+        match &self.stage {
+            CopyStage::Running { progress, .. } => Some(progress.borrow().clone()),
+            _ => None,
+        }
+    }
+
+    /// Collect the outcome once the copy task has ended
+    /// コピータスクが終了したら結果を回収
+    ///
+    /// Returns `true` when the dialog moved to the result step.
+    /// ダイアログが結果手順に移った場合は`true`を返します。
+    pub async fn poll(&mut self) -> bool {
+        let CopyStage::Running { task, .. } = &mut self.stage else {
+            return false;
+        };
+        if !task.is_finished() {
+            return false;
+        }
+        let outcome = match task.await {
+            Ok(Ok(report)) => Ok(report),
+            Ok(Err(error)) => Err(error.to_string()),
+            Err(error) => Err(error.to_string()),
+        };
+        self.stage = CopyStage::Done(outcome);
+        true
+    }
+
+    /// Step back; returns `true` when the dialog should close
+    /// 前の手順に戻る（ダイアログを閉じるべき場合は`true`を返す）
+    ///
+    /// Cancelling a running copy leaves what was already written in place.
+    /// 実行中のコピーを取り消しても、既に書き込まれたものはそのまま残ります。
+    pub fn back(&mut self) -> bool {
+        match &self.stage {
+            CopyStage::Options | CopyStage::Done(_) => return true,
+            CopyStage::ConfirmOverwrite(_) => self.stage = CopyStage::Options,
+            CopyStage::Running { task, .. } => {
+                task.abort();
+                self.stage = CopyStage::Done(Err("Copy cancelled".to_string()));
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crossterm::event::KeyModifiers;
+    use std::time::Duration;
+
+    fn create_dialog() -> CopyDialog {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        CopyDialog::new(&container)
+    }
+
+    #[test]
+    fn test_options_build_request() {
+        // Test toggling the direction and typing the paths
+        // 方向の切り替えとパスの入力をテスト
+        let mut dialog = create_dialog();
+        assert!(!dialog.activate_field());
+        assert_eq!(dialog.direction, CopyDirection::ToContainer);
+
+        dialog.navigate(NavigationDirection::Next);
+        dialog.activate_field();
+        for c in "/srv/q".chars() {
+            dialog.handle_text_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        dialog.handle_text_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!dialog.editing);
+
+        dialog.navigate(NavigationDirection::Previous);
+        dialog.navigate(NavigationDirection::Previous);
+        assert_eq!(dialog.highlighted_field(), Some(CopyField::Start));
+        assert!(dialog.activate_field());
+
+        let request = dialog.request();
+        assert_eq!(request.container_path, "/srv/q");
+        assert_eq!(request.local_path, PathBuf::from("."));
+        assert_eq!(request.direction, CopyDirection::ToContainer);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_running_copy() {
+        // Test that Esc aborts a running copy and reports the cancellation
+        // Escで実行中のコピーが中断され、取り消しが報告されることをテスト
+        let mut dialog = create_dialog();
+        let plan = CopyPlan {
+            request: dialog.request(),
+            target_dir: PathBuf::from("."),
+            target_name: "log".to_string(),
+            overwrites: false,
+        };
+        let (_sender, receiver) = watch::channel(CopyProgress::default());
+        let task = tokio::spawn(async {
+            tokio::time::sleep(Duration::from_mins(1)).await;
+            Err(crate::error::DockaError::internal("unreachable"))
+        });
+        dialog.show_running(plan, receiver, task);
+        assert!(!dialog.poll().await);
+        assert_eq!(dialog.progress(), Some(CopyProgress::default()));
+
+        assert!(!dialog.back());
+        assert!(
+            matches!(&dialog.stage, CopyStage::Done(Err(message)) if message == "Copy cancelled")
+        );
+        assert!(dialog.back());
+    }
+}
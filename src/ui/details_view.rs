@@ -0,0 +1,229 @@
+// src/ui/details_view.rs
+// State of the container details view
+// コンテナ詳細ビューの状態
+
+use crate::domain::entities::{Container, ContainerDetails, ContainerExit, HealthReport};
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+
+/// Lines of the state section: heading, start time and exit time
+/// 状態セクションの行数: 見出し、起動時刻、終了時刻
+const STATE_LINE_COUNT: usize = 3;
+
+/// Details of the selected container read from inspect, shown as scrollable text
+/// together with the exits docka recorded for it
+/// inspectから読み取った選択中のコンテナの詳細と、dockaが記録した終了
+/// （スクロール可能なテキストとして表示）
+///
+/// The widget lays the details out in sections; `line_count` mirrors that
+/// layout so scrolling stops at the last line.
+/// ウィジェットは詳細をセクションに分けて配置します。`line_count`はその配置と
+/// 一致するため、スクロールは最後の行で止まります。
+#[derive(Debug, Clone)]
+pub struct DetailsView {
+    /// Container whose details are shown
+    /// 詳細を表示しているコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of that container
+    /// そのコンテナの表示名
+    pub container_name: String,
+
+    /// Command of that container as listed, `None` when unknown
+    /// 一覧に表示されたそのコンテナのコマンド（不明な場合は`None`）
+    pub command: Option<String>,
+
+    /// Labels of that container as `(key, value)`, ordered by key
+    /// `(キー, 値)`形式のそのコンテナのラベル（キー順）
+    pub labels: Vec<(String, String)>,
+
+    /// Loaded details (`None` until the first load succeeds)
+    /// 読み込んだ詳細（最初の読み込みが成功するまでは`None`）
+    pub details: Option<ContainerDetails>,
+
+    /// Exits recorded for the container, oldest first
+    /// コンテナについて記録された終了（古い順）
+    pub exits: Vec<ContainerExit>,
+
+    /// First visible line
+    /// 最初に表示される行
+    pub scroll: usize,
+
+    /// Whether secret values are shown unmasked
+    /// シークレットの値をマスクせずに表示するか
+    pub reveal_secrets: bool,
+
+    /// Last engine error
+    /// 最後のエンジンエラー
+    pub message: Option<String>,
+}
+
+impl DetailsView {
+    /// Create an empty view for a container
+    /// コンテナ用の空のビューを作成
+    #[must_use]
+    pub fn new(container: &Container) -> Self {
+        Self {
+            container_id: container.id.clone(),
+            container_name: container.display_name().to_string(),
+            command: container.command.clone(),
+            labels: {
+                let mut labels: Vec<(String, String)> = container
+                    .labels
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                labels.sort();
+                labels
+            },
+            details: None,
+            exits: Vec::new(),
+            scroll: 0,
+            reveal_secrets: false,
+            message: None,
+        }
+    }
+
+    /// Show newly loaded details, keeping the scroll position when possible
+    /// 新しく読み込んだ詳細を表示（可能な場合はスクロール位置を維持）
+    pub fn show(&mut self, result: DockaResult<ContainerDetails>) {
+        match result {
+            Ok(details) => {
+                self.details = Some(details);
+                self.message = None;
+            }
+            Err(error) => self.message = Some(error.to_string()),
+        }
+        self.scroll = self.scroll.min(self.line_count().saturating_sub(1));
+    }
+
+    /// Scroll by one line, stopping at the first and last lines
+    /// 1行スクロール（最初と最後の行で停止）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        self.scroll = match direction {
+            NavigationDirection::Next => (self.scroll + 1).min(self.line_count().saturating_sub(1)),
+            NavigationDirection::Previous => self.scroll.saturating_sub(1),
+        };
+    }
+
+    /// Number of lines the widget renders for the loaded details
+    /// 読み込んだ詳細についてウィジェットが描画する行数
+    ///
+    /// Sections are separated by a blank line.
+    /// セクションは空行で区切られます。
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.details.as_ref().map_or(0, |details| {
+            STATE_LINE_COUNT
+                + 1
+                + Self::health_line_count(details.health.as_ref())
+                + 1
+                + self.restarts_line_count(details.oom_killed)
+                + 1
+                + Self::environment_line_count(&details.env)
+                + 1
+                + self.labels_line_count()
+        })
+    }
+
+    /// Lines of the labels section: heading, then one line per label
+    /// ラベルセクションの行数: 見出し、ラベルごとに1行
+    const fn labels_line_count(&self) -> usize {
+        1 + if self.labels.is_empty() {
+            1
+        } else {
+            self.labels.len()
+        }
+    }
+
+    /// Lines of the environment section: heading, command, then one line per variable
+    /// 環境セクションの行数: 見出し、コマンド、変数ごとに1行
+    fn environment_line_count(env: &[String]) -> usize {
+        2 + env.len().max(1)
+    }
+
+    /// Lines of the restarts section: heading, restart count, the OOM notice
+    /// when the last exit was an OOM kill, then one line per exit
+    /// 再起動セクションの行数: 見出し、再起動回数、最後の終了がOOMによる場合の通知、
+    /// 終了ごとに1行
+    fn restarts_line_count(&self, oom_killed: bool) -> usize {
+        2 + usize::from(oom_killed) + self.exits.len().max(1)
+    }
+
+    /// Lines of the health section: heading, summary, then each probe and its output
+    /// ヘルスセクションの行数: 見出し、概要、各プローブとその出力
+    fn health_line_count(health: Option<&HealthReport>) -> usize {
+        2 + health.map_or(0, |report| {
+            report
+                .probes
+                .iter()
+                .map(|probe| 1 + probe.output.trim_end().lines().count())
+                .sum()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::HealthProbe;
+    use crate::domain::value_objects::{ContainerStatus, HealthStatus};
+    use crate::error::DockaError;
+    use chrono::Utc;
+
+    #[test]
+    fn test_scroll_stops_at_last_line() {
+        // Test that scrolling is bounded by the probe output and errors keep the details
+        // スクロールがプローブ出力で制限され、エラー時も詳細が維持されることをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .label("team", "payments")
+            .label("env", "prod")
+            .build()
+            .unwrap();
+        let mut view = DetailsView::new(&container);
+        view.navigate(NavigationDirection::Next);
+        assert_eq!(view.scroll, 0);
+        assert_eq!(
+            view.labels,
+            [
+                ("env".to_string(), "prod".to_string()),
+                ("team".to_string(), "payments".to_string())
+            ]
+        );
+
+        view.show(Ok(ContainerDetails {
+            health: Some(HealthReport {
+                status: HealthStatus::Unhealthy,
+                failing_streak: 1,
+                probes: vec![HealthProbe {
+                    start: Utc::now(),
+                    end: None,
+                    exit_code: 1,
+                    output: "connection refused\nretrying\n".to_string(),
+                }],
+            }),
+            restart_count: 0,
+            oom_killed: false,
+            env: vec!["PATH=/usr/bin".to_string(), "TZ=UTC".to_string()],
+            ..ContainerDetails::default()
+        }));
+        // State (3) + blank line + health (5) + blank line + restarts with no exits (3)
+        // + blank line + environment with two variables (4) + blank line + two labels (3)
+        // 状態(3) + 空行 + ヘルス(5) + 空行 + 終了のない再起動(3) + 空行 + 2つの変数の環境(4)
+        // + 空行 + 2つのラベル(3)
+        assert_eq!(view.line_count(), 22);
+        for _ in 0..30 {
+            view.navigate(NavigationDirection::Next);
+        }
+        assert_eq!(view.scroll, 21);
+
+        view.show(Err(DockaError::DockerDaemonNotRunning));
+        assert!(view.message.is_some());
+        assert!(view.details.is_some());
+    }
+}
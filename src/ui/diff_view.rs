@@ -0,0 +1,403 @@
+// src/ui/diff_view.rs
+// State of the filesystem changes (docker diff) view
+// ファイルシステム変更（docker diff）ビューの状態
+
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::entities::{ChangeCounts, ChangeKind, Container, FilesystemChange};
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+
+/// Path segment in the change tree
+/// 変更ツリー内のパスセグメント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeNode {
+    /// Last segment of the path
+    /// パスの最後のセグメント
+    pub name: String,
+
+    /// Absolute path
+    /// 絶対パス
+    pub path: String,
+
+    /// Change reported for this exact path (`None` for implied parents)
+    /// このパス自体について報告された変更（暗黙の親は`None`）
+    pub kind: Option<ChangeKind>,
+
+    /// Changed files (leaves) below and including this node
+    /// このノード以下（自身を含む）の変更されたファイル（葉）の数
+    pub counts: ChangeCounts,
+
+    /// Child node indices, sorted by name
+    /// 子ノードのインデックス（名前順）
+    pub children: Vec<usize>,
+
+    /// Whether the children are shown
+    /// 子が表示されているか
+    pub expanded: bool,
+}
+
+impl ChangeNode {
+    /// Check whether the node has children to expand
+    /// ノードに展開可能な子があるかチェック
+    #[must_use]
+    pub const fn is_directory(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+/// Visible row of the change tree
+/// 変更ツリーの表示行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeRow {
+    /// Index of the node
+    /// ノードのインデックス
+    pub node: usize,
+
+    /// Nesting depth (0 for top-level directories)
+    /// 入れ子の深さ（トップレベルディレクトリは0）
+    pub depth: usize,
+}
+
+/// Changed paths arranged as a collapsible directory tree
+/// 折りたたみ可能なディレクトリツリーとして並べた変更パス
+///
+/// `docker diff` reports a directory as changed whenever something below it
+/// changes, so only leaves are counted: the counts of a top-level directory
+/// tell how many files were written, added or deleted under it.
+///
+/// `docker diff`は配下で何かが変わるとディレクトリも変更として報告するため、
+/// 葉のみを数えます。トップレベルディレクトリの数は、その配下で書き込み・
+/// 追加・削除されたファイルの数を示します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeTree {
+    /// Every node, parents before children
+    /// 全ノード（親は子より前）
+    pub nodes: Vec<ChangeNode>,
+
+    /// Top-level node indices, sorted by name
+    /// トップレベルノードのインデックス（名前順）
+    pub roots: Vec<usize>,
+}
+
+impl ChangeTree {
+    /// Build the tree from the changes reported by the engine
+    /// エンジンが報告した変更からツリーを構築
+    #[must_use]
+    pub fn new(changes: &[FilesystemChange]) -> Self {
+        let mut tree = Self::default();
+        let mut by_path: HashMap<String, usize> = HashMap::new();
+
+        for change in changes {
+            let mut parent: Option<usize> = None;
+            let mut path = String::new();
+            for segment in change.path.split('/').filter(|segment| !segment.is_empty()) {
+                path.push('/');
+                path.push_str(segment);
+                let index = if let Some(&index) = by_path.get(&path) {
+                    index
+                } else {
+                    let index = tree.nodes.len();
+                    tree.nodes.push(ChangeNode {
+                        name: segment.to_string(),
+                        path: path.clone(),
+                        kind: None,
+                        counts: ChangeCounts::default(),
+                        children: Vec::new(),
+                        expanded: false,
+                    });
+                    match parent {
+                        Some(parent) => tree.nodes[parent].children.push(index),
+                        None => tree.roots.push(index),
+                    }
+                    by_path.insert(path.clone(), index);
+                    index
+                };
+                parent = Some(index);
+            }
+            if let Some(index) = parent {
+                tree.nodes[index].kind = Some(change.kind);
+            }
+        }
+
+        tree.sort_children();
+        for index in (0..tree.nodes.len()).rev() {
+            tree.count_leaves(index);
+        }
+        tree
+    }
+
+    /// Sort roots and children by name
+    /// ルートと子を名前順に並べる
+    fn sort_children(&mut self) {
+        let names: Vec<String> = self.nodes.iter().map(|node| node.name.clone()).collect();
+        self.roots.sort_by(|a, b| names[*a].cmp(&names[*b]));
+        for node in &mut self.nodes {
+            node.children.sort_by(|a, b| names[*a].cmp(&names[*b]));
+        }
+    }
+
+    /// Count the leaves of a node whose children are already counted
+    /// 子が数え済みのノードの葉を数える
+    fn count_leaves(&mut self, index: usize) {
+        let mut counts = ChangeCounts::default();
+        if self.nodes[index].children.is_empty() {
+            if let Some(kind) = self.nodes[index].kind {
+                counts.record(kind);
+            }
+        } else {
+            for &child in &self.nodes[index].children {
+                counts.merge(self.nodes[child].counts);
+            }
+        }
+        self.nodes[index].counts = counts;
+    }
+
+    /// Changed files in the whole tree
+    /// ツリー全体の変更されたファイルの数
+    #[must_use]
+    pub fn total(&self) -> ChangeCounts {
+        let mut counts = ChangeCounts::default();
+        for &root in &self.roots {
+            counts.merge(self.nodes[root].counts);
+        }
+        counts
+    }
+
+    /// Rows shown with the current expansion, in display order
+    /// 現在の展開状態で表示される行（表示順）
+    #[must_use]
+    pub fn visible_rows(&self) -> Vec<ChangeRow> {
+        let mut rows = Vec::new();
+        let mut stack: Vec<ChangeRow> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|&node| ChangeRow { node, depth: 0 })
+            .collect();
+
+        while let Some(row) = stack.pop() {
+            rows.push(row);
+            let node = &self.nodes[row.node];
+            if node.expanded {
+                stack.extend(node.children.iter().rev().map(|&child| ChangeRow {
+                    node: child,
+                    depth: row.depth + 1,
+                }));
+            }
+        }
+        rows
+    }
+
+    /// Paths of the expanded directories
+    /// 展開されているディレクトリのパス
+    #[must_use]
+    pub fn expanded_paths(&self) -> HashSet<String> {
+        self.nodes
+            .iter()
+            .filter(|node| node.expanded)
+            .map(|node| node.path.clone())
+            .collect()
+    }
+
+    /// Expand the directories whose path is listed
+    /// パスが列挙されたディレクトリを展開
+    pub fn expand_paths(&mut self, paths: &HashSet<String>) {
+        for node in &mut self.nodes {
+            node.expanded = node.is_directory() && paths.contains(&node.path);
+        }
+    }
+}
+
+/// View of the filesystem changes of one container
+/// 1つのコンテナのファイルシステム変更のビュー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffView {
+    /// Container whose writable layer is shown
+    /// 書き込み可能レイヤーを表示しているコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of that container
+    /// そのコンテナの表示名
+    pub container_name: String,
+
+    /// Changed paths
+    /// 変更されたパス
+    pub tree: ChangeTree,
+
+    /// Highlighted visible row
+    /// 選択中の表示行
+    pub cursor: usize,
+
+    /// Last engine error
+    /// 最後のエンジンエラー
+    pub message: Option<String>,
+}
+
+impl DiffView {
+    /// Create an empty view for a container
+    /// コンテナ用の空のビューを作成
+    #[must_use]
+    pub fn new(container: &Container) -> Self {
+        Self {
+            container_id: container.id.clone(),
+            container_name: container.display_name().to_string(),
+            tree: ChangeTree::default(),
+            cursor: 0,
+            message: None,
+        }
+    }
+
+    /// Show newly loaded changes, keeping expanded directories open
+    /// 新しく読み込んだ変更を表示（展開済みのディレクトリは開いたまま）
+    pub fn show(&mut self, result: DockaResult<Vec<FilesystemChange>>) {
+        match result {
+            Ok(changes) => {
+                let expanded = self.tree.expanded_paths();
+                self.tree = ChangeTree::new(&changes);
+                self.tree.expand_paths(&expanded);
+                self.message = None;
+            }
+            Err(error) => self.message = Some(error.to_string()),
+        }
+        self.clamp_cursor();
+    }
+
+    /// Node under the cursor
+    /// カーソル位置のノード
+    #[must_use]
+    pub fn highlighted_node(&self) -> Option<&ChangeNode> {
+        self.tree
+            .visible_rows()
+            .get(self.cursor)
+            .map(|row| &self.tree.nodes[row.node])
+    }
+
+    /// Move the highlight, stopping at the first and last rows
+    /// 選択を移動（最初と最後の行で停止）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        self.cursor = match direction {
+            NavigationDirection::Next => self.cursor + 1,
+            NavigationDirection::Previous => self.cursor.saturating_sub(1),
+        };
+        self.clamp_cursor();
+    }
+
+    /// Expand or collapse the highlighted directory
+    /// 選択中のディレクトリを展開または折りたたむ
+    pub fn toggle_highlighted(&mut self) {
+        if let Some(row) = self.tree.visible_rows().get(self.cursor) {
+            let node = &mut self.tree.nodes[row.node];
+            if node.is_directory() {
+                node.expanded = !node.expanded;
+            }
+        }
+    }
+
+    fn clamp_cursor(&mut self) {
+        let rows = self.tree.visible_rows().len();
+        self.cursor = self.cursor.min(rows.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+
+    fn sample_changes() -> Vec<FilesystemChange> {
+        vec![
+            FilesystemChange::new("/var", ChangeKind::Modified),
+            FilesystemChange::new("/var/log", ChangeKind::Modified),
+            FilesystemChange::new("/var/log/app.log", ChangeKind::Added),
+            FilesystemChange::new("/var/log/app.log.1", ChangeKind::Added),
+            FilesystemChange::new("/etc", ChangeKind::Modified),
+            FilesystemChange::new("/etc/hosts", ChangeKind::Modified),
+            FilesystemChange::new("/etc/motd", ChangeKind::Deleted),
+            FilesystemChange::new("/tmp/cache", ChangeKind::Added),
+        ]
+    }
+
+    #[test]
+    fn test_tree_counts_leaves_per_top_level_directory() {
+        // Test that only files are counted and parents are implied
+        // ファイルのみが数えられ、親が暗黙に作られることをテスト
+        let tree = ChangeTree::new(&sample_changes());
+
+        let roots: Vec<&str> = tree
+            .roots
+            .iter()
+            .map(|&root| tree.nodes[root].name.as_str())
+            .collect();
+        assert_eq!(roots, ["etc", "tmp", "var"]);
+
+        let counts = |index: usize| tree.nodes[tree.roots[index]].counts;
+        assert_eq!(
+            counts(0),
+            ChangeCounts {
+                added: 0,
+                modified: 1,
+                deleted: 1
+            }
+        );
+        assert_eq!(counts(2).added, 2);
+        assert_eq!(counts(2).modified, 0);
+        assert_eq!(tree.nodes[tree.roots[1]].kind, None);
+        assert_eq!(tree.total().total(), 5);
+    }
+
+    #[test]
+    fn test_expand_collapse_and_navigate() {
+        // Test that toggling a directory shows and hides its children
+        // ディレクトリの切り替えで子が表示・非表示になることをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut view = DiffView::new(&container);
+        view.show(Ok(sample_changes()));
+        assert_eq!(view.tree.visible_rows().len(), 3);
+
+        view.navigate(NavigationDirection::Next);
+        view.navigate(NavigationDirection::Next);
+        assert_eq!(view.highlighted_node().unwrap().path, "/var");
+        view.toggle_highlighted();
+        view.navigate(NavigationDirection::Next);
+        view.toggle_highlighted();
+
+        let paths: Vec<(&str, usize)> = view
+            .tree
+            .visible_rows()
+            .iter()
+            .map(|row| (view.tree.nodes[row.node].path.as_str(), row.depth))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                ("/etc", 0),
+                ("/tmp", 0),
+                ("/var", 0),
+                ("/var/log", 1),
+                ("/var/log/app.log", 2),
+                ("/var/log/app.log.1", 2),
+            ]
+        );
+
+        view.navigate(NavigationDirection::Next);
+        view.toggle_highlighted();
+        assert_eq!(view.tree.visible_rows().len(), 6);
+
+        view.show(Ok(sample_changes()));
+        assert_eq!(view.tree.visible_rows().len(), 6);
+
+        view.cursor = 2;
+        view.toggle_highlighted();
+        view.show(Ok(Vec::new()));
+        assert_eq!(view.cursor, 0);
+        assert!(view.highlighted_node().is_none());
+    }
+}
@@ -3,7 +3,7 @@
 // TUIアプリケーション用イベント処理実装
 
 use crate::error::DockaResult;
-use crate::ui::app::App;
+use crate::ui::app::{App, NavigationDirection};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Application event enum representing user actions
@@ -40,6 +40,90 @@ pub enum AppEvent {
     /// 現在の選択をアクティベート (Enter)
     Enter,
 
+    /// Open the Docker context switcher (c)
+    /// Dockerコンテキストスイッチャーを開く (c)
+    SwitchContext,
+
+    /// Show host information and disk usage (i)
+    /// ホスト情報とディスク使用量を表示 (i)
+    ShowSystem,
+
+    /// Open the guided prune dialog (p)
+    /// ガイド付き削除ダイアログを開く (p)
+    Prune,
+
+    /// Open the stale container cleanup assistant (a)
+    /// 古いコンテナのクリーンアップアシスタントを開く (a)
+    CleanupAssistant,
+
+    /// Show or hide the process pane of the selected container (t)
+    /// 選択中のコンテナのプロセスペインを表示または非表示 (t)
+    ToggleProcesses,
+
+    /// Show the filesystem changes of the selected container (d)
+    /// 選択中のコンテナのファイルシステム変更を表示 (d)
+    ShowChanges,
+
+    /// Copy files into or out of the selected container (f)
+    /// 選択中のコンテナへ、またはコンテナからファイルをコピー (f)
+    CopyFiles,
+
+    /// Browse the files of the selected container (b)
+    /// 選択中のコンテナのファイルをブラウズ (b)
+    BrowseFiles,
+
+    /// Show the inspect details of the selected container (v)
+    /// 選択中のコンテナのinspect詳細を表示 (v)
+    ShowDetails,
+
+    /// Show only containers that exited with an error, or all again (e)
+    /// エラーで終了したコンテナのみを表示、または全て表示に戻す (e)
+    ToggleFailedExits,
+
+    /// Switch uptimes and exit times between relative and absolute (u)
+    /// 稼働時間と終了時刻を相対表示と日時表示で切り替え (u)
+    ToggleTimeDisplay,
+
+    /// Show the published ports of all containers (o)
+    /// 全コンテナの公開ポートを表示 (o)
+    ShowPorts,
+
+    /// Reveal or mask secret values in the details view (m)
+    /// 詳細ビューのシークレットの値を表示またはマスク (m)
+    ToggleSecrets,
+
+    /// Show the label keys and values of all containers (l)
+    /// 全コンテナのラベルのキーと値を表示 (l)
+    BrowseLabels,
+
+    /// Group the list by a label key, or ungroup it (g)
+    /// 一覧をラベルのキーでグループ化、またはグループ化を解除 (g)
+    GroupByLabel,
+
+    /// Start typing a search query over the list (/)
+    /// 一覧の検索クエリの入力を開始 (/)
+    Search,
+
+    /// Select the next container matching the search (n)
+    /// 検索に一致する次のコンテナを選択 (n)
+    NextMatch,
+
+    /// Select the previous container matching the search (N)
+    /// 検索に一致する前のコンテナを選択 (N)
+    PreviousMatch,
+
+    /// Edit the filter expression of the list (:)
+    /// 一覧のフィルタ式を編集 (:)
+    EditFilter,
+
+    /// Sort the list by the next column (s)
+    /// 次の列で一覧を並べ替え (s)
+    CycleSort,
+
+    /// Reverse the sort order of the list (S)
+    /// 一覧の並び順を反転 (S)
+    ReverseSort,
+
     /// Unknown or unhandled key
     /// 不明または未処理のキー
     Unknown,
@@ -60,7 +144,26 @@ pub enum AppEvent {
 /// - `q`, `Esc` - Normal quit
 /// - `Ctrl+C` - Force quit
 /// - `r`, `F5` - Refresh containers
-/// - `Enter` - Activate selection
+/// - `Enter` - Open the action menu for the selected container
+/// - `c` - Open Docker context switcher
+/// - `i` - Show host information and disk usage
+/// - `p` - Open the guided prune dialog
+/// - `a` - Open the stale container cleanup assistant
+/// - `t` - Show or hide the process pane of the selected container
+/// - `d` - Show the filesystem changes of the selected container
+/// - `f` - Copy files into or out of the selected container
+/// - `b` - Browse the files of the selected container
+/// - `v` - Show the details (health log) of the selected container
+/// - `e` - Show only containers that exited with an error
+/// - `u` - Switch between relative and absolute uptimes and exit times
+/// - `o` - Show the published ports of all containers
+/// - `m` - Reveal or mask secret values in the details view
+/// - `l` - Browse labels to filter the list by a label value
+/// - `g` - Group the list by a label key, or ungroup it
+/// - `/` - Search the list by name, image or ID prefix
+/// - `n`, `N` - Select the next or previous search match
+/// - `:` - Edit the filter expression (`status:running -name:test`)
+/// - `s`, `S` - Sort the list by the next column, or reverse the order
 ///
 /// # Arguments
 /// * `key_event` - Raw keyboard event from crossterm
@@ -106,6 +209,39 @@ pub const fn handle_key_event(key_event: KeyEvent) -> AppEvent {
             AppEvent::ForceQuit
         }
 
+        // Endpoint management
+        // エンドポイント管理
+        KeyCode::Char('c') => AppEvent::SwitchContext,
+        KeyCode::Char('i') => AppEvent::ShowSystem,
+
+        // Cleanup
+        // クリーンアップ
+        KeyCode::Char('p') => AppEvent::Prune,
+        KeyCode::Char('a') => AppEvent::CleanupAssistant,
+
+        // Container inspection
+        // コンテナの調査
+        KeyCode::Char('t') => AppEvent::ToggleProcesses,
+        KeyCode::Char('d') => AppEvent::ShowChanges,
+        KeyCode::Char('f') => AppEvent::CopyFiles,
+        KeyCode::Char('b') => AppEvent::BrowseFiles,
+        KeyCode::Char('v') => AppEvent::ShowDetails,
+        KeyCode::Char('e') => AppEvent::ToggleFailedExits,
+        KeyCode::Char('u') => AppEvent::ToggleTimeDisplay,
+        KeyCode::Char('o') => AppEvent::ShowPorts,
+        KeyCode::Char('m') => AppEvent::ToggleSecrets,
+        KeyCode::Char('l') => AppEvent::BrowseLabels,
+        KeyCode::Char('g') => AppEvent::GroupByLabel,
+
+        // Search
+        // 検索
+        KeyCode::Char('/') => AppEvent::Search,
+        KeyCode::Char('n') => AppEvent::NextMatch,
+        KeyCode::Char('N') => AppEvent::PreviousMatch,
+        KeyCode::Char(':') => AppEvent::EditFilter,
+        KeyCode::Char('s') => AppEvent::CycleSort,
+        KeyCode::Char('S') => AppEvent::ReverseSort,
+
         // Unknown key
         // 不明なキー
         _ => AppEvent::Unknown,
@@ -192,10 +328,113 @@ pub async fn process_app_event(app: &mut App, event: AppEvent) -> DockaResult<()
         }
 
         AppEvent::Enter => {
-            // Phase 1: No action implementation yet
-            // Phase 1: アクション実装はまだなし
-            // This will be expanded in Phase 1.4 for container operations
-            // これはPhase 1.4でコンテナ操作用に拡張される予定
+            // Actions run once an entry of the menu is chosen
+            // アクションはメニュー項目の選択後に実行される
+            app.open_action_menu();
+            Ok(())
+        }
+
+        AppEvent::SwitchContext => {
+            // The actual reconnect happens once an endpoint is chosen
+            // 実際の再接続はエンドポイント選択後に行われる
+            app.open_context_switcher();
+            Ok(())
+        }
+
+        AppEvent::ShowSystem => app.show_system_overview().await,
+
+        AppEvent::Prune => {
+            // Nothing is removed before the preview is confirmed
+            // プレビューが確認されるまで何も削除されない
+            app.open_prune_dialog();
+            Ok(())
+        }
+
+        AppEvent::CleanupAssistant => {
+            // Load failures are shown inside the assistant
+            // 読み込みの失敗はアシスタント内に表示される
+            app.open_cleanup_assistant().await;
+            Ok(())
+        }
+
+        AppEvent::ToggleProcesses => {
+            // The pane loads on the next pass of the event loop
+            // ペインはイベントループの次の処理で読み込まれる
+            app.toggle_process_pane();
+            Ok(())
+        }
+
+        AppEvent::ShowChanges => {
+            // Load failures are shown inside the view
+            // 読み込みの失敗はビュー内に表示される
+            app.open_diff_view().await;
+            Ok(())
+        }
+
+        AppEvent::CopyFiles => {
+            app.open_copy_dialog();
+            Ok(())
+        }
+
+        AppEvent::BrowseFiles => {
+            // Load failures are shown inside the browser
+            // 読み込みの失敗はブラウザ内に表示される
+            app.open_file_browser().await;
+            Ok(())
+        }
+
+        AppEvent::ShowDetails => {
+            // Load failures are shown inside the view
+            // 読み込みの失敗はビュー内に表示される
+            app.open_details_view().await;
+            Ok(())
+        }
+
+        AppEvent::ToggleFailedExits => app.toggle_failed_exits_filter().await,
+
+        AppEvent::ToggleTimeDisplay => {
+            app.toggle_time_display();
+            Ok(())
+        }
+
+        AppEvent::ShowPorts => {
+            // Load failures are shown inside the view
+            // 読み込みの失敗はビュー内に表示される
+            app.open_ports_view().await;
+            Ok(())
+        }
+
+        AppEvent::ToggleSecrets => {
+            // Secrets are only revealed inside the details view
+            // シークレットは詳細ビュー内でのみ表示される
+            Ok(())
+        }
+
+        AppEvent::BrowseLabels => {
+            // Load failures are shown inside the browser
+            // 読み込みの失敗はブラウザ内に表示される
+            app.open_label_browser().await;
+            Ok(())
+        }
+
+        AppEvent::GroupByLabel => {
+            // The key to group by is picked in the label browser
+            // グループ化するキーはラベルブラウザで選ぶ
+            if app.query.group_by_label.is_some() {
+                app.set_group_by_label(None);
+            } else {
+                app.open_label_browser().await;
+            }
+            Ok(())
+        }
+
+        AppEvent::Search
+        | AppEvent::NextMatch
+        | AppEvent::PreviousMatch
+        | AppEvent::EditFilter
+        | AppEvent::CycleSort
+        | AppEvent::ReverseSort => {
+            process_list_query_event(app, &event);
             Ok(())
         }
 
@@ -207,6 +446,20 @@ pub async fn process_app_event(app: &mut App, event: AppEvent) -> DockaResult<()
     }
 }
 
+/// Process an event searching, filtering or sorting the container list
+/// コンテナリストを検索、絞り込み、または並べ替えるイベントを処理
+fn process_list_query_event(app: &mut App, event: &AppEvent) {
+    match event {
+        AppEvent::Search => app.open_search(),
+        AppEvent::NextMatch => app.jump_to_match(NavigationDirection::Next),
+        AppEvent::PreviousMatch => app.jump_to_match(NavigationDirection::Previous),
+        AppEvent::EditFilter => app.open_filter_bar(),
+        AppEvent::CycleSort => app.cycle_sort_key(),
+        AppEvent::ReverseSort => app.reverse_sort(),
+        _ => {}
+    }
+}
+
 /// Validate key input to filter out control characters
 /// 制御文字をフィルタリングするためのキー入力検証
 ///
@@ -313,7 +566,31 @@ impl EventStats {
             AppEvent::SelectNext | AppEvent::SelectPrevious => {
                 self.navigation_events += 1;
             }
-            AppEvent::Enter | AppEvent::Refresh | AppEvent::Quit | AppEvent::ForceQuit => {
+            AppEvent::Enter
+            | AppEvent::Refresh
+            | AppEvent::Quit
+            | AppEvent::ForceQuit
+            | AppEvent::SwitchContext
+            | AppEvent::ShowSystem
+            | AppEvent::Prune
+            | AppEvent::CleanupAssistant
+            | AppEvent::ToggleProcesses
+            | AppEvent::ShowChanges
+            | AppEvent::CopyFiles
+            | AppEvent::BrowseFiles
+            | AppEvent::ShowDetails
+            | AppEvent::ToggleFailedExits
+            | AppEvent::ToggleTimeDisplay
+            | AppEvent::ShowPorts
+            | AppEvent::ToggleSecrets
+            | AppEvent::BrowseLabels
+            | AppEvent::GroupByLabel
+            | AppEvent::Search
+            | AppEvent::NextMatch
+            | AppEvent::PreviousMatch
+            | AppEvent::EditFilter
+            | AppEvent::CycleSort
+            | AppEvent::ReverseSort => {
                 self.action_events += 1;
             }
             AppEvent::Unknown => {
@@ -423,6 +700,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_key_event_switch_context() {
+        // Test context switcher binding does not shadow Ctrl+C
+        // コンテキストスイッチャーのバインドがCtrl+Cを隠さないことをテスト
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('c'), KeyModifiers::NONE)),
+            AppEvent::SwitchContext
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('i'), KeyModifiers::NONE)),
+            AppEvent::ShowSystem
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('p'), KeyModifiers::NONE)),
+            AppEvent::Prune
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('a'), KeyModifiers::NONE)),
+            AppEvent::CleanupAssistant
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('t'), KeyModifiers::NONE)),
+            AppEvent::ToggleProcesses
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('d'), KeyModifiers::NONE)),
+            AppEvent::ShowChanges
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('f'), KeyModifiers::NONE)),
+            AppEvent::CopyFiles
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('b'), KeyModifiers::NONE)),
+            AppEvent::BrowseFiles
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('v'), KeyModifiers::NONE)),
+            AppEvent::ShowDetails
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('e'), KeyModifiers::NONE)),
+            AppEvent::ToggleFailedExits
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('u'), KeyModifiers::NONE)),
+            AppEvent::ToggleTimeDisplay
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('o'), KeyModifiers::NONE)),
+            AppEvent::ShowPorts
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('m'), KeyModifiers::NONE)),
+            AppEvent::ToggleSecrets
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('l'), KeyModifiers::NONE)),
+            AppEvent::BrowseLabels
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('g'), KeyModifiers::NONE)),
+            AppEvent::GroupByLabel
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('/'), KeyModifiers::NONE)),
+            AppEvent::Search
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('n'), KeyModifiers::NONE)),
+            AppEvent::NextMatch
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('N'), KeyModifiers::SHIFT)),
+            AppEvent::PreviousMatch
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char(':'), KeyModifiers::NONE)),
+            AppEvent::EditFilter
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('s'), KeyModifiers::NONE)),
+            AppEvent::CycleSort
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('S'), KeyModifiers::SHIFT)),
+            AppEvent::ReverseSort
+        );
+        assert_eq!(
+            handle_key_event(create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            AppEvent::ForceQuit
+        );
+    }
+
     #[test]
     fn test_handle_key_event_unknown() {
         // Test unknown keys
@@ -505,6 +876,22 @@ mod tests {
         assert!(process_app_event(&mut app, AppEvent::Enter).await.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_process_app_event_switch_context() {
+        // Test that SwitchContext opens the switcher on the active endpoint
+        // SwitchContextがアクティブなエンドポイントでスイッチャーを開くことをテスト
+        let mut app = create_test_app();
+        app.set_available_endpoints(vec!["default".to_string(), "ci".to_string()], "ci");
+
+        assert!(
+            process_app_event(&mut app, AppEvent::SwitchContext)
+                .await
+                .is_ok()
+        );
+        assert!(app.is_context_switcher_open());
+        assert_eq!(app.highlighted_endpoint(), Some("ci"));
+    }
+
     #[test]
     fn test_validate_key_input() {
         // Valid keys
@@ -0,0 +1,289 @@
+// src/ui/file_browser.rs
+// State of the in-container file browser
+// コンテナ内ファイルブラウザの状態
+
+use crate::app::copy_service::parent_dir;
+use crate::app::file_browser_service::{BrowserContent, DirectoryListing, FilePreview};
+use crate::domain::entities::{Container, FileEntry};
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+
+/// Row of the directory listing
+/// ディレクトリ一覧の行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserRow<'a> {
+    /// `..`, leading to the parent directory
+    /// 親ディレクトリへの`..`
+    Parent,
+
+    /// Child of the listed directory
+    /// 一覧表示中のディレクトリの子
+    Entry(&'a FileEntry),
+}
+
+/// Read-only browser of one container's filesystem
+/// 1つのコンテナのファイルシステムの読み取り専用ブラウザ
+///
+/// `Enter` opens the highlighted directory or previews the highlighted file;
+/// while a preview is shown, `j`/`k` scroll it and `Esc` returns to the listing.
+///
+/// `Enter`で選択中のディレクトリを開くか選択中のファイルをプレビューし、
+/// プレビュー表示中は`j`/`k`でスクロール、`Esc`で一覧に戻ります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileBrowser {
+    /// Container being browsed
+    /// ブラウズ中のコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of that container
+    /// そのコンテナの表示名
+    pub container_name: String,
+
+    /// Directory shown in the listing
+    /// 一覧に表示中のディレクトリ
+    pub listing: DirectoryListing,
+
+    /// Highlighted row of the listing
+    /// 一覧で選択中の行
+    pub cursor: usize,
+
+    /// Open file preview (`None` while browsing the listing)
+    /// 開いているファイルプレビュー（一覧をブラウズ中は`None`）
+    pub preview: Option<FilePreview>,
+
+    /// First preview line shown
+    /// 表示するプレビューの最初の行
+    pub scroll: usize,
+
+    /// Last engine error
+    /// 最後のエンジンエラー
+    pub message: Option<String>,
+}
+
+impl FileBrowser {
+    /// Create a browser positioned at the root of a container
+    /// コンテナのルートに位置するブラウザを作成
+    #[must_use]
+    pub fn new(container: &Container) -> Self {
+        Self {
+            container_id: container.id.clone(),
+            container_name: container.display_name().to_string(),
+            listing: DirectoryListing {
+                path: "/".to_string(),
+                entries: Vec::new(),
+                truncated: false,
+            },
+            cursor: 0,
+            preview: None,
+            scroll: 0,
+            message: None,
+        }
+    }
+
+    /// Rows of the listing, starting with `..` below the root
+    /// 一覧の行（ルート以外では`..`から始まる）
+    #[must_use]
+    pub fn rows(&self) -> Vec<BrowserRow<'_>> {
+        let parent = (self.listing.path != "/").then_some(BrowserRow::Parent);
+        parent
+            .into_iter()
+            .chain(self.listing.entries.iter().map(BrowserRow::Entry))
+            .collect()
+    }
+
+    /// Entry under the cursor (`None` for `..`)
+    /// カーソル位置のエントリ（`..`の場合は`None`）
+    #[must_use]
+    pub fn highlighted_entry(&self) -> Option<&FileEntry> {
+        match self.rows().get(self.cursor) {
+            Some(BrowserRow::Entry(entry)) => Some(entry),
+            _ => None,
+        }
+    }
+
+    /// Path that `Enter` opens, if any
+    /// `Enter`で開くパス（ある場合）
+    #[must_use]
+    pub fn target_path(&self) -> Option<String> {
+        if self.preview.is_some() {
+            return None;
+        }
+        match self.rows().get(self.cursor)? {
+            BrowserRow::Parent => Some(parent_dir(&self.listing.path)),
+            BrowserRow::Entry(entry) => Some(entry.path.clone()),
+        }
+    }
+
+    /// Show an opened directory or file
+    /// 開いたディレクトリまたはファイルを表示
+    ///
+    /// Going up highlights the directory that was left; reloading the same
+    /// directory keeps the highlighted entry.
+    /// 上の階層に移動すると離れたディレクトリを選択し、同じディレクトリの
+    /// 再読み込みでは選択中のエントリを維持します。
+    pub fn show(&mut self, result: DockaResult<BrowserContent>) {
+        match result {
+            Ok(BrowserContent::Directory(listing)) => {
+                let keep = if listing.path == self.listing.path {
+                    self.highlighted_entry().map(|entry| entry.path.clone())
+                } else {
+                    Some(self.listing.path.clone())
+                };
+                self.listing = listing;
+                self.cursor = keep.and_then(|path| self.row_of(&path)).unwrap_or(0);
+                self.preview = None;
+                self.message = None;
+            }
+            Ok(BrowserContent::File(preview)) => {
+                self.preview = Some(preview);
+                self.scroll = 0;
+                self.message = None;
+            }
+            Err(error) => self.message = Some(error.to_string()),
+        }
+    }
+
+    /// Row showing the entry at `path`
+    /// `path`のエントリを表示する行
+    fn row_of(&self, path: &str) -> Option<usize> {
+        self.rows()
+            .iter()
+            .position(|row| matches!(row, BrowserRow::Entry(entry) if entry.path == path))
+    }
+
+    /// Number of lines in the open preview
+    /// 開いているプレビューの行数
+    #[must_use]
+    pub fn preview_lines(&self) -> usize {
+        self.preview
+            .as_ref()
+            .and_then(|preview| preview.text.as_ref())
+            .map_or(0, |text| text.lines().count())
+    }
+
+    /// Move the highlight, or scroll the open preview
+    /// 選択を移動、または開いているプレビューをスクロール
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        let (position, count) = if self.preview.is_some() {
+            let lines = self.preview_lines();
+            (&mut self.scroll, lines)
+        } else {
+            let rows = self.rows().len();
+            (&mut self.cursor, rows)
+        };
+        *position = match direction {
+            NavigationDirection::Next => (*position + 1).min(count.saturating_sub(1)),
+            NavigationDirection::Previous => position.saturating_sub(1),
+        };
+    }
+
+    /// Step back; returns `true` when the browser should close
+    /// 前に戻る（ブラウザを閉じるべき場合は`true`を返す）
+    pub fn back(&mut self) -> bool {
+        if self.preview.take().is_some() {
+            self.scroll = 0;
+            false
+        } else {
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::PathKind;
+    use crate::domain::value_objects::ContainerStatus;
+
+    fn entry(path: &str, kind: PathKind) -> FileEntry {
+        FileEntry {
+            name: path.rsplit('/').next().unwrap().to_string(),
+            path: path.to_string(),
+            kind,
+            size: 0,
+            mode: 0o644,
+            mtime: 0,
+            link_target: None,
+        }
+    }
+
+    fn listing(path: &str, entries: Vec<FileEntry>) -> BrowserContent {
+        BrowserContent::Directory(DirectoryListing {
+            path: path.to_string(),
+            entries,
+            truncated: false,
+        })
+    }
+
+    fn create_browser() -> FileBrowser {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        FileBrowser::new(&container)
+    }
+
+    #[test]
+    fn test_navigate_into_and_out_of_directories() {
+        // Test that `..` appears below the root and going up highlights the old directory
+        // ルート以外で`..`が表示され、上の階層に戻ると元のディレクトリが選択されることをテスト
+        let mut browser = create_browser();
+        browser.show(Ok(listing(
+            "/",
+            vec![
+                entry("/etc", PathKind::Directory),
+                entry("/var", PathKind::Directory),
+            ],
+        )));
+        assert_eq!(browser.rows().len(), 2);
+        browser.navigate(NavigationDirection::Next);
+        browser.navigate(NavigationDirection::Next);
+        assert_eq!(browser.target_path().as_deref(), Some("/var"));
+
+        browser.show(Ok(listing(
+            "/var",
+            vec![entry("/var/log", PathKind::Directory)],
+        )));
+        assert_eq!(browser.cursor, 0);
+        assert_eq!(browser.rows()[0], BrowserRow::Parent);
+        assert_eq!(browser.target_path().as_deref(), Some("/"));
+
+        browser.show(Ok(listing(
+            "/",
+            vec![
+                entry("/etc", PathKind::Directory),
+                entry("/var", PathKind::Directory),
+            ],
+        )));
+        assert_eq!(browser.highlighted_entry().unwrap().path, "/var");
+        assert!(browser.back());
+    }
+
+    #[test]
+    fn test_preview_scrolls_and_closes() {
+        // Test scrolling a preview within its lines and returning to the listing
+        // プレビューを行の範囲内でスクロールし、一覧に戻ることをテスト
+        let mut browser = create_browser();
+        browser.show(Ok(listing("/", vec![entry("/motd", PathKind::File)])));
+        browser.show(Ok(BrowserContent::File(FilePreview {
+            entry: entry("/motd", PathKind::File),
+            text: Some("one\ntwo\nthree".to_string()),
+            truncated: false,
+        })));
+        assert_eq!(browser.target_path(), None);
+        for _ in 0..5 {
+            browser.navigate(NavigationDirection::Next);
+        }
+        assert_eq!(browser.scroll, 2);
+
+        browser.show(Err(crate::error::DockaError::internal("gone")));
+        assert!(browser.message.is_some());
+        assert!(!browser.back());
+        assert_eq!(browser.preview, None);
+        assert_eq!(browser.highlighted_entry().unwrap().path, "/motd");
+    }
+}
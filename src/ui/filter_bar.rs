@@ -0,0 +1,206 @@
+// src/ui/filter_bar.rs
+// State of the filter expression bar
+// フィルタ式バーの状態
+
+use std::collections::BTreeMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::error::DockaError;
+use crate::ui::text_input::TextInput;
+
+/// Bar editing the filter expression of the list, opened with `:`
+/// `:`で開く一覧のフィルタ式を編集するバー
+///
+/// `Enter` applies the expression (see `parse_filter_expression`); a parse
+/// error keeps the bar open and points at the faulty term. `Tab` cycles
+/// through the saved filters and `Ctrl+S` asks for a name to save the
+/// expression under.
+///
+/// `Enter`で式を適用します（`parse_filter_expression`を参照）。解析エラーの
+/// 場合はバーを開いたまま問題の項を指し示します。`Tab`で保存済みのフィルタを
+/// 順に切り替え、`Ctrl+S`で式を保存する名前を尋ねます。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterBar {
+    /// Expression being edited
+    /// 編集中の式
+    pub input: TextInput,
+
+    /// Name typed after `Ctrl+S`, `None` while editing the expression
+    /// `Ctrl+S`の後に入力する名前（式の編集中は`None`）
+    pub naming: Option<TextInput>,
+
+    /// Last error, with the character offset in the expression when known
+    /// 最後のエラー（判明している場合は式内の文字位置付き）
+    pub error: Option<(Option<usize>, String)>,
+
+    /// Saved filter shown in the input after `Tab`
+    /// `Tab`の後に入力に表示している保存済みのフィルタ
+    saved_index: Option<usize>,
+}
+
+/// Outcome of a key sent to the filter bar
+/// フィルタバーに送ったキーの結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterBarKey {
+    /// The key edited the expression or the name, or was ignored
+    /// キーが式または名前を編集した、または無視された
+    Edited,
+
+    /// `Enter` on the expression: apply it
+    /// 式での`Enter`: 式を適用
+    Apply,
+
+    /// `Enter` on the name: save the expression under it
+    /// 名前での`Enter`: 式をその名前で保存
+    Save,
+
+    /// `Esc` on the expression: close without changing the filter
+    /// 式での`Esc`: フィルタを変更せずに閉じる
+    Cancel,
+}
+
+impl FilterBar {
+    /// Create a bar editing `expression`
+    /// `expression`を編集するバーを作成
+    #[must_use]
+    pub fn new(expression: impl Into<String>) -> Self {
+        Self {
+            input: TextInput::with_value(expression),
+            ..Self::default()
+        }
+    }
+
+    /// Send a raw key to the bar
+    /// バーに生のキーを送る
+    ///
+    /// `saved` are the saved filters by name, cycled through with `Tab`.
+    /// `saved`は名前ごとの保存済みフィルタで、`Tab`で順に切り替えます。
+    pub fn handle_key(
+        &mut self,
+        key_event: KeyEvent,
+        saved: &BTreeMap<String, String>,
+    ) -> FilterBarKey {
+        if let Some(name) = self.naming.as_mut() {
+            return match key_event.code {
+                KeyCode::Enter if !name.value().trim().is_empty() => FilterBarKey::Save,
+                KeyCode::Esc => {
+                    self.naming = None;
+                    FilterBarKey::Edited
+                }
+                _ => {
+                    name.handle_key(key_event);
+                    FilterBarKey::Edited
+                }
+            };
+        }
+
+        match key_event.code {
+            KeyCode::Enter => FilterBarKey::Apply,
+            KeyCode::Esc => FilterBarKey::Cancel,
+            KeyCode::Char('s') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                if !self.expression().is_empty() {
+                    self.naming = Some(TextInput::new());
+                }
+                FilterBarKey::Edited
+            }
+            KeyCode::Tab => {
+                if !saved.is_empty() {
+                    let index = self
+                        .saved_index
+                        .map_or(0, |index| (index + 1) % saved.len());
+                    if let Some(expression) = saved.values().nth(index) {
+                        self.input = TextInput::with_value(expression.clone());
+                    }
+                    self.saved_index = Some(index);
+                    self.error = None;
+                }
+                FilterBarKey::Edited
+            }
+            _ => {
+                if self.input.handle_key(key_event) {
+                    self.saved_index = None;
+                    self.error = None;
+                }
+                FilterBarKey::Edited
+            }
+        }
+    }
+
+    /// Expression without surrounding spaces
+    /// 前後の空白を除いた式
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        self.input.value().trim()
+    }
+
+    /// Name of the saved filter shown in the input, if any
+    /// 入力に表示している保存済みフィルタの名前（ある場合）
+    #[must_use]
+    pub fn saved_name<'a>(&self, saved: &'a BTreeMap<String, String>) -> Option<&'a str> {
+        self.saved_index
+            .and_then(|index| saved.keys().nth(index))
+            .map(String::as_str)
+    }
+
+    /// Show an error, pointing at its position in the expression when known
+    /// エラーを表示（判明している場合は式内の位置を指し示す）
+    pub fn show_error(&mut self, error: &DockaError) {
+        let message = match error {
+            DockaError::FilterParse { message, .. } => message.clone(),
+            other => other.to_string(),
+        };
+        self.error = Some((error.input_position(), message));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_tab_cycles_saved_filters_and_ctrl_s_asks_for_a_name() {
+        // Test that Tab recalls saved filters in name order and Ctrl+S prompts for a name
+        // Tabで保存済みフィルタが名前順に呼び出され、Ctrl+Sで名前を尋ねることをテスト
+        let saved = BTreeMap::from([
+            ("broken".to_string(), "exit:!=0".to_string()),
+            ("db".to_string(), "image:postgres*".to_string()),
+        ]);
+        let mut bar = FilterBar::new("");
+        assert_eq!(
+            bar.handle_key(key(KeyCode::Tab), &saved),
+            FilterBarKey::Edited
+        );
+        assert_eq!(bar.expression(), "exit:!=0");
+        bar.handle_key(key(KeyCode::Tab), &saved);
+        assert_eq!(bar.saved_name(&saved), Some("db"));
+        bar.handle_key(key(KeyCode::Tab), &saved);
+        assert_eq!(bar.expression(), "exit:!=0");
+
+        bar.handle_key(key(KeyCode::Char('x')), &saved);
+        assert_eq!(bar.saved_name(&saved), None);
+
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        bar.handle_key(ctrl_s, &saved);
+        assert!(bar.naming.is_some());
+        assert_eq!(
+            bar.handle_key(key(KeyCode::Enter), &saved),
+            FilterBarKey::Edited
+        );
+        bar.handle_key(key(KeyCode::Char('q')), &saved);
+        assert_eq!(
+            bar.handle_key(key(KeyCode::Enter), &saved),
+            FilterBarKey::Save
+        );
+        bar.handle_key(key(KeyCode::Esc), &saved);
+        assert!(bar.naming.is_none());
+        assert_eq!(
+            bar.handle_key(key(KeyCode::Esc), &saved),
+            FilterBarKey::Cancel
+        );
+    }
+}
@@ -0,0 +1,171 @@
+// src/ui/label_browser.rs
+// State of the label browser listing label values across containers
+// コンテナ全体のラベル値を一覧表示するラベルブラウザの状態
+
+use std::collections::BTreeMap;
+
+use crate::domain::entities::Container;
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+
+/// One label value and the number of containers carrying it
+/// 1つのラベル値とそれを持つコンテナの数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelRow {
+    /// Label key, e.g. `team`
+    /// ラベルのキー（例: `team`）
+    pub key: String,
+
+    /// Label value, e.g. `payments`
+    /// ラベルの値（例: `payments`）
+    pub value: String,
+
+    /// Number of containers with this key and value
+    /// このキーと値を持つコンテナの数
+    pub count: usize,
+}
+
+/// Every label key and value in use, answering "what does team=payments run?"
+/// 「team=paymentsは何を実行しているか」に答える使用中の全てのラベルのキーと値
+///
+/// Rows are ordered by key, then by value. Selecting a row filters the
+/// container list by it, or groups the list by its key.
+///
+/// 行はキー順、次に値順に並びます。行を選択するとその値でコンテナリストを
+/// 絞り込むか、そのキーで一覧をグループ化します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelBrowser {
+    /// Rows in display order
+    /// 表示順の行
+    pub rows: Vec<LabelRow>,
+
+    /// Highlighted row
+    /// 選択中の行
+    pub cursor: usize,
+
+    /// Whether the first load finished
+    /// 最初の読み込みが完了したか
+    pub loaded: bool,
+
+    /// Last engine error
+    /// 最後のエンジンエラー
+    pub message: Option<String>,
+}
+
+impl LabelBrowser {
+    /// Create an empty browser that is filled by `show`
+    /// `show`で埋められる空のブラウザを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the labels of newly listed containers, keeping the cursor when possible
+    /// 新しく一覧取得したコンテナのラベルを表示（可能な場合はカーソルを維持）
+    pub fn show(&mut self, result: DockaResult<Vec<Container>>) {
+        self.loaded = true;
+        match result {
+            Ok(containers) => {
+                let mut counts: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+                for (key, value) in containers.iter().flat_map(|container| &container.labels) {
+                    *counts.entry((key, value)).or_default() += 1;
+                }
+                self.rows = counts
+                    .into_iter()
+                    .map(|((key, value), count)| LabelRow {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                        count,
+                    })
+                    .collect();
+                self.message = None;
+            }
+            Err(error) => self.message = Some(error.to_string()),
+        }
+        self.cursor = self.cursor.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Move the cursor by one row, stopping at the first and last rows
+    /// カーソルを1行移動（最初と最後の行で停止）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        self.cursor = match direction {
+            NavigationDirection::Next => (self.cursor + 1).min(self.rows.len().saturating_sub(1)),
+            NavigationDirection::Previous => self.cursor.saturating_sub(1),
+        };
+    }
+
+    /// Number of distinct label keys
+    /// 異なるラベルキーの数
+    #[must_use]
+    pub fn key_count(&self) -> usize {
+        let mut keys: Vec<&str> = self.rows.iter().map(|row| row.key.as_str()).collect();
+        keys.dedup();
+        keys.len()
+    }
+
+    /// Highlighted row
+    /// 選択中の行
+    #[must_use]
+    pub fn highlighted(&self) -> Option<&LabelRow> {
+        self.rows.get(self.cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crate::error::DockaError;
+
+    fn create_container(id: &str, labels: &[(&str, &str)]) -> Container {
+        labels
+            .iter()
+            .fold(
+                Container::builder()
+                    .id(id)
+                    .name(id)
+                    .image("nginx:latest")
+                    .status(ContainerStatus::Running),
+                |builder, (key, value)| builder.label(*key, *value),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_show_counts_values_by_key() {
+        // Test that label values are counted and ordered by key, then value
+        // ラベル値が数えられ、キー順、次に値順に並ぶことをテスト
+        let mut browser = LabelBrowser::new();
+        browser.show(Ok(vec![
+            create_container("api-1", &[("team", "payments"), ("env", "prod")]),
+            create_container("api-2", &[("team", "payments"), ("env", "staging")]),
+            create_container("web-1", &[("team", "growth"), ("env", "prod")]),
+        ]));
+
+        let rows: Vec<(&str, &str, usize)> = browser
+            .rows
+            .iter()
+            .map(|row| (row.key.as_str(), row.value.as_str(), row.count))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("env", "prod", 2),
+                ("env", "staging", 1),
+                ("team", "growth", 1),
+                ("team", "payments", 2),
+            ]
+        );
+        assert_eq!(browser.key_count(), 2);
+
+        for _ in 0..10 {
+            browser.navigate(NavigationDirection::Next);
+        }
+        assert_eq!(browser.highlighted().unwrap().value, "payments");
+
+        browser.show(Err(DockaError::internal("daemon went away")));
+        assert_eq!(browser.rows.len(), 4);
+        assert!(browser.message.is_some());
+    }
+}
@@ -143,6 +143,28 @@ impl SimpleLayout {
     pub const fn minimum_size() -> (u16, u16) {
         (40, 7) // Minimum 40 cols x 7 rows / 最小40列 x 7行
     }
+
+    /// Calculate a centered popup area clamped to the available space
+    /// 利用可能なスペースに収まる中央配置のポップアップエリアを計算
+    ///
+    /// # Arguments / 引数
+    /// * `area` - Area to center within / 中央配置する対象エリア
+    /// * `width` - Desired popup width / 希望するポップアップ幅
+    /// * `height` - Desired popup height / 希望するポップアップ高さ
+    ///
+    /// # Returns / 戻り値
+    /// * `Rect` - Popup area / ポップアップエリア
+    #[must_use]
+    pub fn centered_popup(area: Rect, width: u16, height: u16) -> Rect {
+        let width = width.min(area.width);
+        let height = height.min(area.height);
+        Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +251,21 @@ mod tests {
         assert!(layout.main.height >= 1);
     }
 
+    #[test]
+    fn test_centered_popup() {
+        // Test popup centering and clamping
+        // ポップアップの中央配置とクランプをテスト
+        let area = Rect::new(0, 0, 80, 24);
+        assert_eq!(
+            SimpleLayout::centered_popup(area, 40, 10),
+            Rect::new(20, 7, 40, 10)
+        );
+
+        // Larger than the area: clamp to the area
+        // エリアより大きい場合：エリアにクランプ
+        assert_eq!(SimpleLayout::centered_popup(area, 200, 50), area);
+    }
+
     #[test]
     fn test_should_show_help() {
         // Test help visibility logic
@@ -0,0 +1,312 @@
+// src/ui/list_sort.rs
+// Sort order of the container list
+// コンテナリストの並び順
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::domain::entities::{Container, ContainerStats};
+use crate::domain::value_objects::{ContainerId, ContainerStatus};
+
+/// Column the container list can be sorted by
+/// コンテナリストを並べ替えられる列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// Container name, ignoring case
+    /// コンテナ名（大文字小文字は無視）
+    Name,
+
+    /// Image reference, ignoring case
+    /// イメージ参照（大文字小文字は無視）
+    Image,
+
+    /// Status, running containers first
+    /// ステータス（実行中のコンテナが先）
+    Status,
+
+    /// Creation time
+    /// 作成日時
+    Created,
+
+    /// Time since the container started (running containers only)
+    /// コンテナ起動からの経過時間（実行中のコンテナのみ）
+    Uptime,
+
+    /// CPU usage from `docker stats`
+    /// `docker stats`のCPU使用率
+    Cpu,
+
+    /// Memory usage from `docker stats`
+    /// `docker stats`のメモリ使用量
+    Memory,
+}
+
+impl SortKey {
+    /// Keys in the order `s` cycles through them
+    /// `s`で切り替わる順のキー
+    pub const ALL: [Self; 7] = [
+        Self::Name,
+        Self::Image,
+        Self::Status,
+        Self::Created,
+        Self::Uptime,
+        Self::Cpu,
+        Self::Memory,
+    ];
+
+    /// Short name shown in the list header
+    /// 一覧のヘッダーに表示する短い名前
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Image => "image",
+            Self::Status => "status",
+            Self::Created => "created",
+            Self::Uptime => "uptime",
+            Self::Cpu => "cpu",
+            Self::Memory => "memory",
+        }
+    }
+
+    /// Whether sorting needs resource usage from `docker stats`
+    /// 並べ替えに`docker stats`のリソース使用量が必要か
+    #[must_use]
+    pub const fn needs_stats(self) -> bool {
+        matches!(self, Self::Cpu | Self::Memory)
+    }
+
+    /// Order a key starts in: largest first for times and usage
+    /// キーの初期の並び順: 時間と使用量は大きい順
+    const fn starts_descending(self) -> bool {
+        matches!(
+            self,
+            Self::Created | Self::Uptime | Self::Cpu | Self::Memory
+        )
+    }
+}
+
+/// Sort order of the container list, kept for the whole session
+/// セッション中保持されるコンテナリストの並び順
+///
+/// Without a key the list keeps the order the daemon reports. Containers
+/// missing the sorted value (e.g. CPU of a stopped container) always come
+/// last, and ties keep the daemon order.
+///
+/// キーがない場合、一覧はデーモンが報告した順のままです。並べ替える値が
+/// ないコンテナ（例: 停止中のコンテナのCPU）は常に最後に並び、
+/// 同じ値のコンテナはデーモンの順を保ちます。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListSort {
+    /// Column sorted by, `None` for the daemon order
+    /// 並べ替える列（デーモンの順の場合は`None`）
+    pub key: Option<SortKey>,
+
+    /// Whether the largest value comes first
+    /// 大きい値が先に並ぶか
+    pub descending: bool,
+}
+
+impl ListSort {
+    /// Switch to the next key, back to the daemon order after the last one
+    /// 次のキーに切り替える（最後のキーの後はデーモンの順に戻る）
+    ///
+    /// Each key starts in its natural order: names ascending, times and
+    /// usage descending.
+    /// 各キーは自然な順で始まります: 名前は昇順、時間と使用量は降順です。
+    pub fn cycle_key(&mut self) {
+        self.key = self.key.map_or(Some(SortKey::ALL[0]), |key| {
+            SortKey::ALL
+                .iter()
+                .position(|candidate| *candidate == key)
+                .and_then(|index| SortKey::ALL.get(index + 1))
+                .copied()
+        });
+        self.descending = self.key.is_some_and(SortKey::starts_descending);
+    }
+
+    /// Reverse the order of the current key
+    /// 現在のキーの並び順を反転
+    pub const fn reverse(&mut self) {
+        if self.key.is_some() {
+            self.descending = !self.descending;
+        }
+    }
+
+    /// Header indicator such as `cpu ▼`, `None` for the daemon order
+    /// `cpu ▼`のようなヘッダーの表示（デーモンの順の場合は`None`）
+    #[must_use]
+    pub fn indicator(&self) -> Option<String> {
+        self.key.map(|key| {
+            let arrow = if self.descending { '▼' } else { '▲' };
+            format!("{} {arrow}", key.label())
+        })
+    }
+
+    /// Sort containers in place, looking up usage in `stats`
+    /// `stats`で使用量を参照してコンテナをその場で並べ替える
+    pub fn apply(
+        &self,
+        containers: &mut [Container],
+        stats: &HashMap<ContainerId, ContainerStats>,
+    ) {
+        let Some(key) = self.key else {
+            return;
+        };
+        let usage = |container: &Container| stats.get(&container.id).copied().unwrap_or_default();
+        containers.sort_by(|a, b| match key {
+            SortKey::Name => self.compare(Some(a.name.to_lowercase()), Some(b.name.to_lowercase())),
+            SortKey::Image => {
+                self.compare(Some(a.image.to_lowercase()), Some(b.image.to_lowercase()))
+            }
+            SortKey::Status => {
+                self.compare(Some(status_rank(&a.status)), Some(status_rank(&b.status)))
+            }
+            SortKey::Created => self.compare(Some(a.created_at), Some(b.created_at)),
+            SortKey::Uptime => self.compare(a.uptime(), b.uptime()),
+            SortKey::Cpu => self.compare(
+                usage(a).cpu_percent.map(Percent),
+                usage(b).cpu_percent.map(Percent),
+            ),
+            SortKey::Memory => self.compare(usage(a).memory_bytes, usage(b).memory_bytes),
+        });
+    }
+
+    /// Compare two values in the current order, missing values last
+    /// 現在の並び順で2つの値を比較（値がない場合は最後）
+    fn compare<T: Ord>(self, a: Option<T>, b: Option<T>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) if self.descending => b.cmp(&a),
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// CPU percentage ordered with `f64::total_cmp`
+/// `f64::total_cmp`で順序付けるCPU使用率
+#[derive(PartialEq)]
+struct Percent(f64);
+
+impl Eq for Percent {}
+
+impl PartialOrd for Percent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Percent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Rank of a status when sorting ascending: live containers first
+/// 昇順で並べる際のステータスの順位: 稼働中のコンテナが先
+const fn status_rank(status: &ContainerStatus) -> u8 {
+    match status {
+        ContainerStatus::Running => 0,
+        ContainerStatus::Restarting => 1,
+        ContainerStatus::Starting => 2,
+        ContainerStatus::Paused => 3,
+        ContainerStatus::Stopping => 4,
+        ContainerStatus::Created => 5,
+        ContainerStatus::Exited { .. } | ContainerStatus::Stopped => 6,
+        ContainerStatus::Removing => 7,
+        ContainerStatus::Dead => 8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(id: &str, name: &str, status: ContainerStatus) -> Container {
+        Container::builder()
+            .id(id)
+            .name(name)
+            .image("test:latest")
+            .status(status)
+            .build()
+            .unwrap()
+    }
+
+    fn names(containers: &[Container]) -> Vec<&str> {
+        containers
+            .iter()
+            .map(|container| container.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_cycle_key_starts_in_natural_order_and_ends_in_daemon_order() {
+        // Test that keys cycle in order with their own direction, then return to the daemon order
+        // キーが固有の向きで順に切り替わり、最後にデーモンの順に戻ることをテスト
+        let mut sort = ListSort::default();
+        assert_eq!(sort.indicator(), None);
+        sort.reverse();
+        assert!(!sort.descending);
+
+        sort.cycle_key();
+        assert_eq!(sort.indicator().as_deref(), Some("name ▲"));
+        sort.reverse();
+        assert_eq!(sort.indicator().as_deref(), Some("name ▼"));
+
+        for _ in 0..5 {
+            sort.cycle_key();
+        }
+        assert_eq!(sort.indicator().as_deref(), Some("cpu ▼"));
+        sort.cycle_key();
+        sort.cycle_key();
+        assert_eq!(sort.key, None);
+    }
+
+    #[test]
+    fn test_apply_puts_missing_values_last_in_both_directions() {
+        // Test that containers without usage stay last whichever way the list is sorted
+        // 使用量のないコンテナが並び順に関わらず最後に並ぶことをテスト
+        let mut containers = vec![
+            container("aaa111", "stopped", ContainerStatus::Stopped),
+            container("bbb222", "idle", ContainerStatus::Running),
+            container("ccc333", "busy", ContainerStatus::Running),
+        ];
+        let stats = HashMap::from([
+            (
+                ContainerId::new("bbb222").unwrap(),
+                ContainerStats {
+                    cpu_percent: Some(0.5),
+                    memory_bytes: Some(10),
+                },
+            ),
+            (
+                ContainerId::new("ccc333").unwrap(),
+                ContainerStats {
+                    cpu_percent: Some(75.0),
+                    memory_bytes: Some(5),
+                },
+            ),
+        ]);
+        let mut sort = ListSort {
+            key: Some(SortKey::Cpu),
+            descending: true,
+        };
+        sort.apply(&mut containers, &stats);
+        assert_eq!(names(&containers), ["busy", "idle", "stopped"]);
+
+        sort.reverse();
+        sort.apply(&mut containers, &stats);
+        assert_eq!(names(&containers), ["idle", "busy", "stopped"]);
+
+        sort.key = Some(SortKey::Memory);
+        sort.apply(&mut containers, &stats);
+        assert_eq!(names(&containers), ["busy", "idle", "stopped"]);
+
+        sort.key = Some(SortKey::Status);
+        sort.descending = true;
+        sort.apply(&mut containers, &stats);
+        assert_eq!(names(&containers), ["stopped", "busy", "idle"]);
+    }
+}
@@ -16,9 +16,43 @@
 //! ```text
 //! UI Layer
 //! ├── app.rs              # Application state management
+//! ├── app/                # Per-feature state and methods of the App
+//! │   ├── cleanup.rs      # Prune dialog and cleanup assistant
+//! │   ├── copy.rs         # Copy files dialog
+//! │   ├── ports.rs        # Ports view and port forwards
+//! │   └── query.rs        # Filter, search, sort and grouping of the list
+//! ├── actions.rs          # Container actions and their availability
+//! ├── cleanup_assistant.rs # Stale container cleanup assistant state
+//! ├── copy_dialog.rs      # Copy files dialog state
+//! ├── details_view.rs     # Container details (inspect) view state
+//! ├── diff_view.rs        # Filesystem changes (docker diff) tree state
 //! ├── events.rs           # Event handling and processing
+//! ├── file_browser.rs     # In-container file browser state
+//! ├── filter_bar.rs       # Filter expression bar state
+//! ├── label_browser.rs    # Label browser state
+//! ├── list_sort.rs        # Container list sort order
+//! ├── ports_view.rs       # Published ports view state
+//! ├── process_pane.rs     # Container process (top) pane state
+//! ├── prune_dialog.rs     # Guided prune dialog state
+//! ├── rename_dialog.rs    # Rename container dialog state
+//! ├── resource_dialog.rs  # Resource limits dialog state
+//! ├── restart_policy_picker.rs # Restart policy picker state
+//! ├── search_bar.rs       # Incremental container search state
+//! ├── text_input.rs       # Single-line text input
 //! ├── widgets/            # UI widgets (Phase 1.2.2)
+//! │   ├── cleanup_assistant.rs
 //! │   ├── container_list.rs
+//! │   ├── copy_dialog.rs
+//! │   ├── details_view.rs
+//! │   ├── diff_view.rs
+//! │   ├── file_browser.rs
+//! │   ├── label_browser.rs
+//! │   ├── ports_view.rs
+//! │   ├── process_pane.rs
+//! │   ├── prune_dialog.rs
+//! │   ├── rename_dialog.rs
+//! │   ├── resource_dialog.rs
+//! │   ├── restart_policy_picker.rs
 //! │   └── status_bar.rs
 //! ├── layouts/            # Layout management (Phase 1.2.3)
 //! │   └── simple_layout.rs
@@ -60,6 +94,55 @@
 /// 管理するメインApp構造体を提供します。
 pub mod app;
 
+/// Container actions offered by the action menu.
+///
+/// This module lists lifecycle actions and decides which ones apply to a
+/// container and which ones the connected engine cannot run.
+///
+/// アクションメニューで提供するコンテナアクション。
+/// ライフサイクルアクションを列挙し、コンテナに適用できるものと
+/// 接続中のエンジンが実行できないものを判定します。
+pub mod actions;
+
+/// Stale container cleanup assistant state.
+///
+/// This module holds the criteria, candidates and selection of the view
+/// that bulk-removes old stopped containers.
+///
+/// 古いコンテナのクリーンアップアシスタントの状態。
+/// 古い停止中のコンテナを一括削除するビューの基準、候補、選択を保持します。
+pub mod cleanup_assistant;
+
+/// Copy files dialog state.
+///
+/// This module holds the direction, paths, overwrite confirmation and the
+/// background copy task of the dialog that copies files in and out of a
+/// container.
+///
+/// ファイルコピーダイアログの状態。
+/// コンテナとの間でファイルをコピーするダイアログの方向、パス、上書き確認、
+/// バックグラウンドのコピータスクを保持します。
+pub mod copy_dialog;
+
+/// Container details view state.
+///
+/// This module keeps the inspect details of the selected container, such as
+/// its health log, and the scroll position of the view.
+///
+/// コンテナ詳細ビューの状態。
+/// ヘルスログなど選択中のコンテナのinspect詳細と、ビューのスクロール位置を保持します。
+pub mod details_view;
+
+/// Filesystem changes (docker diff) view state.
+///
+/// This module arranges the changed paths of a container's writable layer
+/// into a collapsible tree with per-directory counts.
+///
+/// ファイルシステム変更（docker diff）ビューの状態。
+/// コンテナの書き込み可能レイヤーの変更パスを、ディレクトリごとの数付きの
+/// 折りたたみ可能なツリーに並べます。
+pub mod diff_view;
+
 /// Event handling and processing for user interactions.
 ///
 /// This module handles keyboard input, converts raw events to application events,
@@ -70,6 +153,123 @@ pub mod app;
 /// アプリケーション状態更新を処理します。
 pub mod events;
 
+/// In-container file browser state.
+///
+/// This module holds the directory listing, highlight and file preview of
+/// the read-only browser of a container's filesystem.
+///
+/// コンテナ内ファイルブラウザの状態。
+/// コンテナのファイルシステムの読み取り専用ブラウザのディレクトリ一覧、
+/// 選択、ファイルプレビューを保持します。
+pub mod file_browser;
+
+/// Filter expression bar state.
+///
+/// This module holds the expression being edited, its parse error and the
+/// name prompt used to save it as a named filter.
+///
+/// フィルタ式バーの状態。
+/// 編集中の式、その解析エラー、名前付きフィルタとして保存するための
+/// 名前の入力を保持します。
+pub mod filter_bar;
+
+/// Label browser state.
+///
+/// This module lists every label key and value in use with the number of
+/// containers carrying it.
+///
+/// ラベルブラウザの状態。
+/// 使用中の全てのラベルのキーと値を、それを持つコンテナの数とともに一覧にします。
+pub mod label_browser;
+
+/// Container list sort order.
+///
+/// This module sorts the container list by a column, ascending or
+/// descending, with containers missing the value last.
+///
+/// コンテナリストの並び順。
+/// 列ごとに昇順または降順でコンテナリストを並べ替え、値のないコンテナを
+/// 最後に並べます。
+pub mod list_sort;
+
+/// Published ports view state.
+///
+/// This module lists the ports of all containers, published ones first
+/// ordered by host port.
+///
+/// 公開ポートビューの状態。
+/// 全コンテナのポートを、公開ポートをホストポート順に先にして一覧にします。
+pub mod ports_view;
+
+/// Container process (top) pane state.
+///
+/// This module holds the process table of the selected container and
+/// decides when it is reloaded.
+///
+/// コンテナプロセス（top）ペインの状態。
+/// 選択中のコンテナのプロセステーブルを保持し、再読み込みの時期を判定します。
+pub mod process_pane;
+
+/// Guided prune dialog state.
+///
+/// This module holds the options, preview, confirmation and report steps
+/// of the cleanup dialog, independent of rendering.
+///
+/// ガイド付き削除ダイアログの状態。
+/// クリーンアップダイアログのオプション、プレビュー、確認、結果の各手順を
+/// 描画から独立して保持します。
+pub mod prune_dialog;
+
+/// Rename container dialog state.
+///
+/// This module holds the name field of the dialog that renames a
+/// container, validated with the same rules as the container builder.
+///
+/// コンテナ名変更ダイアログの状態。
+/// コンテナビルダーと同じ規則で検証する、コンテナの名前を変更する
+/// ダイアログの名前フィールドを保持します。
+pub mod rename_dialog;
+
+/// Resource limits dialog state.
+///
+/// This module holds the prefilled fields of the dialog changing a
+/// container's resource limits and restart policy without recreating it.
+///
+/// リソース制限ダイアログの状態。
+/// コンテナを再作成せずにリソース制限と再起動ポリシーを変更するダイアログの
+/// 事前入力されたフィールドを保持します。
+pub mod resource_dialog;
+
+/// Restart policy picker state.
+///
+/// This module holds the highlighted policy of the picker that changes a
+/// container's restart policy through a live update.
+///
+/// 再起動ポリシーピッカーの状態。
+/// ライブ更新でコンテナの再起動ポリシーを変更するピッカーの
+/// 選択中のポリシーを保持します。
+pub mod restart_policy_picker;
+
+/// Incremental container search state.
+///
+/// This module holds the `/` search query and its history, and matches
+/// containers by name, image and ID prefix.
+///
+/// コンテナのインクリメンタル検索の状態。
+/// `/`検索のクエリとその履歴を保持し、名前、イメージ、IDの先頭で
+/// コンテナを照合します。
+pub mod search_bar;
+
+/// Single-line text input shared by dialogs.
+///
+/// This module provides an editable text buffer with a cursor that
+/// receives raw key events while a field is being edited.
+///
+/// ダイアログで共有する単一行テキスト入力。
+/// フィールド編集中に生のキーイベントを受け取る、
+/// カーソル付きの編集可能なテキストバッファを提供します。
+pub mod text_input;
+
 /// Layout management for UI components.
 ///
 /// This module provides layout managers for organizing UI components
@@ -117,7 +317,11 @@ pub use layouts::{LayoutAreas, SimpleLayout};
 
 /// Widget types and functions.
 /// ウィジェット型と関数。
-pub use widgets::{ContainerListWidget, StatusBar};
+pub use widgets::{
+    ActionMenuWidget, CleanupAssistantWidget, ContainerListWidget, ContextSwitcherWidget,
+    CopyDialogWidget, DiffViewWidget, FileBrowserWidget, ProcessPaneWidget, PruneDialogWidget,
+    StatusBar, SystemDashboardWidget,
+};
 
 /// Styling and theming types.
 /// スタイリングとテーマ設定型。
@@ -0,0 +1,416 @@
+// src/ui/ports_view.rs
+// State of the ports view listing published endpoints
+// 公開エンドポイントを一覧表示するポートビューの状態
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::domain::entities::Container;
+use crate::domain::value_objects::{ContainerId, PortMapping};
+use crate::error::{DockaError, DockaResult};
+use crate::ui::app::NavigationDirection;
+use crate::ui::text_input::TextInput;
+
+/// One container port shown in the ports view
+/// ポートビューに表示する1つのコンテナポート
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortRow {
+    /// Container owning the port
+    /// ポートを持つコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of the container owning the port
+    /// ポートを持つコンテナの表示名
+    pub container_name: String,
+
+    /// Docker endpoint of the container (multi-host view only)
+    /// コンテナの`Docker`エンドポイント（複数ホストビューのみ）
+    pub host: Option<String>,
+
+    /// The port and where it is published
+    /// ポートとその公開先
+    pub mapping: PortMapping,
+}
+
+/// Port forward typed as `[LOCAL:]PORT[@NETWORK]`, e.g. `15432:5432@backend`
+/// `[LOCAL:]PORT[@NETWORK]`形式で入力したポートフォワード（例: `15432:5432@backend`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardRequest {
+    /// Local port to listen on, `0` for any free port
+    /// 待ち受けるローカルポート（`0`は空いている任意のポート）
+    pub local_port: u16,
+
+    /// Container port, published or not
+    /// コンテナのポート（公開の有無を問わない）
+    pub container_port: u16,
+
+    /// Network whose address is used, `None` for the first by name
+    /// アドレスを使用するネットワーク（`None`は名前順で最初のもの）
+    pub network: Option<String>,
+}
+
+impl ForwardRequest {
+    /// Forward the same local port to `container_port` on the first network
+    /// 最初のネットワークの`container_port`へ同じローカルポートを転送
+    #[must_use]
+    pub const fn same_port(container_port: u16) -> Self {
+        Self {
+            local_port: container_port,
+            container_port,
+            network: None,
+        }
+    }
+
+    /// Parse `[LOCAL:]PORT[@NETWORK]`
+    /// `[LOCAL:]PORT[@NETWORK]`を解析
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When a port is missing or out of range
+    pub fn parse(input: &str) -> DockaResult<Self> {
+        let input = input.trim();
+        let (ports, network) = match input.split_once('@') {
+            Some((ports, network)) if !network.trim().is_empty() => {
+                (ports, Some(network.trim().to_string()))
+            }
+            Some(_) => return Err(DockaError::invalid_input("Network name is empty")),
+            None => (input, None),
+        };
+        let port = |text: &str, allow_zero: bool| {
+            text.trim()
+                .parse::<u16>()
+                .ok()
+                .filter(|port| allow_zero || *port != 0)
+                .ok_or_else(|| {
+                    DockaError::invalid_input(format!(
+                        "'{}' is not a port (expected [LOCAL:]PORT[@NETWORK])",
+                        text.trim()
+                    ))
+                })
+        };
+        let (local_port, container_port) = if let Some((local, container)) = ports.split_once(':') {
+            (port(local, true)?, port(container, false)?)
+        } else {
+            let container_port = port(ports, false)?;
+            (container_port, container_port)
+        };
+        Ok(Self {
+            local_port,
+            container_port,
+            network,
+        })
+    }
+}
+
+/// Field for forwarding any port of one container, including unpublished ones
+/// 1つのコンテナの任意のポート（未公開のものを含む）を転送する入力欄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardInput {
+    /// Container whose port is forwarded
+    /// ポートを転送するコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of the container
+    /// コンテナの表示名
+    pub container_name: String,
+
+    /// Typed `[LOCAL:]PORT[@NETWORK]`
+    /// 入力された`[LOCAL:]PORT[@NETWORK]`
+    pub input: TextInput,
+
+    /// Last validation error
+    /// 最後の検証エラー
+    pub message: Option<String>,
+}
+
+/// Outcome of a key sent to the forward input
+/// フォワード入力欄に送ったキーの結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForwardKey {
+    /// The key edited the field or was ignored
+    /// キーが入力欄を編集した、または無視された
+    Edited,
+
+    /// `Enter`: start the forward
+    /// `Enter`: フォワードを開始
+    Submit,
+
+    /// `Esc`: close the field
+    /// `Esc`: 入力欄を閉じる
+    Cancel,
+}
+
+impl ForwardInput {
+    /// Create the field for a container, prefilled with a port when given
+    /// コンテナ用の入力欄を作成（ポートがあれば事前入力）
+    #[must_use]
+    pub fn new(container_id: ContainerId, container_name: String, port: Option<u16>) -> Self {
+        Self {
+            container_id,
+            container_name,
+            input: TextInput::with_value(port.map(|port| port.to_string()).unwrap_or_default()),
+            message: None,
+        }
+    }
+
+    /// Send a raw key to the field
+    /// 入力欄に生のキーを送る
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> ForwardKey {
+        match key_event.code {
+            KeyCode::Enter => ForwardKey::Submit,
+            KeyCode::Esc => ForwardKey::Cancel,
+            _ => {
+                if self.input.handle_key(key_event) {
+                    self.message = None;
+                }
+                ForwardKey::Edited
+            }
+        }
+    }
+
+    /// Parsed forward
+    /// 解析したフォワード
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - See `ForwardRequest::parse`
+    pub fn request(&self) -> DockaResult<ForwardRequest> {
+        ForwardRequest::parse(self.input.value())
+    }
+}
+
+/// Ports of all containers, answering "which container owns port 5432?"
+/// 「ポート5432はどのコンテナのものか」に答える全コンテナのポート
+///
+/// Published ports come first, ordered by host port; ports that are only
+/// exposed follow, ordered by container. The cursor then moves on to the
+/// active port forwards, which are listed below the ports.
+///
+/// 公開ポートをホストポート順に先に並べ、`expose`のみのポートを
+/// コンテナ順にその後に並べます。カーソルはその後、ポートの下に表示される
+/// 実行中のポートフォワードに移動します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortsView {
+    /// Rows in display order
+    /// 表示順の行
+    pub rows: Vec<PortRow>,
+
+    /// Highlighted row
+    /// 選択中の行
+    pub cursor: usize,
+
+    /// Number of active port forwards listed after the ports
+    /// ポートの後に表示する実行中のポートフォワードの数
+    pub forward_count: usize,
+
+    /// Outcome of the last forward started or stopped
+    /// 最後に開始または停止したフォワードの結果
+    pub notice: Option<String>,
+
+    /// Whether the first load finished
+    /// 最初の読み込みが完了したか
+    pub loaded: bool,
+
+    /// Last engine error
+    /// 最後のエンジンエラー
+    pub message: Option<String>,
+
+    /// Open field for forwarding a typed port
+    /// 入力したポートを転送するための開いている入力欄
+    pub forward_input: Option<ForwardInput>,
+}
+
+impl PortsView {
+    /// Create an empty view that is filled by `show`
+    /// `show`で埋められる空のビューを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the ports of newly listed containers, keeping the cursor when possible
+    /// 新しく一覧取得したコンテナのポートを表示（可能な場合はカーソルを維持）
+    pub fn show(&mut self, result: DockaResult<Vec<Container>>) {
+        self.loaded = true;
+        match result {
+            Ok(containers) => {
+                self.rows = containers
+                    .iter()
+                    .flat_map(|container| {
+                        container.ports.iter().map(|mapping| PortRow {
+                            container_id: container.id.clone(),
+                            container_name: container.display_name().to_string(),
+                            host: container.host.clone(),
+                            mapping: mapping.clone(),
+                        })
+                    })
+                    .collect();
+                self.rows.sort_by(|a, b| {
+                    let key = |row: &PortRow| {
+                        (
+                            row.mapping.host_port.is_none(),
+                            row.mapping.host_port,
+                            row.container_name.clone(),
+                            row.mapping.container_port,
+                        )
+                    };
+                    key(a).cmp(&key(b))
+                });
+                self.message = None;
+            }
+            Err(error) => self.message = Some(error.to_string()),
+        }
+        self.clamp_cursor();
+    }
+
+    /// Update the number of active forwards, keeping the cursor on a row
+    /// 実行中のフォワードの数を更新（カーソルは行の上に保つ）
+    pub fn set_forward_count(&mut self, forward_count: usize) {
+        self.forward_count = forward_count;
+        self.clamp_cursor();
+    }
+
+    /// Move the cursor by one row, stopping at the first and last rows
+    /// カーソルを1行移動（最初と最後の行で停止）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        self.cursor = match direction {
+            NavigationDirection::Next => (self.cursor + 1).min(self.item_count().saturating_sub(1)),
+            NavigationDirection::Previous => self.cursor.saturating_sub(1),
+        };
+    }
+
+    /// Number of port rows and forwards the cursor moves over
+    /// カーソルが移動するポート行とフォワードの数
+    #[must_use]
+    pub const fn item_count(&self) -> usize {
+        self.rows.len() + self.forward_count
+    }
+
+    /// Index of the highlighted forward, when the cursor is past the ports
+    /// カーソルがポートより後にある場合、選択中のフォワードのインデックス
+    #[must_use]
+    pub fn highlighted_forward(&self) -> Option<usize> {
+        self.cursor
+            .checked_sub(self.rows.len())
+            .filter(|index| *index < self.forward_count)
+    }
+
+    /// Keep the cursor within the ports and forwards
+    /// カーソルをポートとフォワードの範囲内に保つ
+    fn clamp_cursor(&mut self) {
+        self.cursor = self.cursor.min(self.item_count().saturating_sub(1));
+    }
+
+    /// Number of published ports
+    /// 公開ポートの数
+    #[must_use]
+    pub fn published_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| row.mapping.is_published())
+            .count()
+    }
+
+    /// Highlighted row
+    /// 選択中の行
+    #[must_use]
+    pub fn highlighted(&self) -> Option<&PortRow> {
+        self.rows.get(self.cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{ContainerStatus, PortProtocol};
+    use crate::error::DockaError;
+
+    fn create_container(id: &str, name: &str, ports: Vec<PortMapping>) -> Container {
+        ports
+            .into_iter()
+            .fold(
+                Container::builder()
+                    .id(id)
+                    .name(name)
+                    .image("postgres:16")
+                    .status(ContainerStatus::Running),
+                crate::domain::ContainerBuilder::port,
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_show_orders_published_ports_first() {
+        // Test that published ports are ordered by host port before exposed ones
+        // 公開ポートがホストポート順に、`expose`のみのポートより先に並ぶことをテスト
+        let tcp = |port| PortMapping::new(port, PortProtocol::Tcp);
+        let mut view = PortsView::new();
+        view.show(Ok(vec![
+            create_container(
+                "web-1",
+                "web",
+                vec![tcp(80).published(None, 8080), tcp(9229)],
+            ),
+            create_container("db-1", "db", vec![tcp(5432).published(None, 5432)]),
+        ]));
+
+        let rows: Vec<(String, String)> = view
+            .rows
+            .iter()
+            .map(|row| (row.container_name.clone(), row.mapping.to_string()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("db".to_string(), "0.0.0.0:5432 → 5432/tcp".to_string()),
+                ("web".to_string(), "0.0.0.0:8080 → 80/tcp".to_string()),
+                ("web".to_string(), "9229/tcp".to_string()),
+            ]
+        );
+        assert_eq!(view.published_count(), 2);
+
+        view.navigate(NavigationDirection::Next);
+        view.navigate(NavigationDirection::Next);
+        view.navigate(NavigationDirection::Next);
+        assert_eq!(view.highlighted().unwrap().mapping.container_port, 9229);
+
+        view.set_forward_count(1);
+        view.navigate(NavigationDirection::Next);
+        assert!(view.highlighted().is_none());
+        assert_eq!(view.highlighted_forward(), Some(0));
+        view.set_forward_count(0);
+        assert_eq!(view.highlighted().unwrap().mapping.container_port, 9229);
+
+        view.show(Err(DockaError::internal("daemon went away")));
+        assert_eq!(view.rows.len(), 3);
+        assert!(
+            view.message
+                .as_deref()
+                .unwrap()
+                .contains("daemon went away")
+        );
+    }
+
+    #[test]
+    fn test_forward_request_parses_local_port_and_network() {
+        // Test that the local port and network are optional and ports are validated
+        // ローカルポートとネットワークが省略可能で、ポートが検証されることをテスト
+        assert_eq!(
+            ForwardRequest::parse("5432").unwrap(),
+            ForwardRequest::same_port(5432)
+        );
+        assert_eq!(
+            ForwardRequest::parse(" 15432:5432@backend ").unwrap(),
+            ForwardRequest {
+                local_port: 15432,
+                container_port: 5432,
+                network: Some("backend".to_string()),
+            }
+        );
+        assert_eq!(ForwardRequest::parse("0:9229").unwrap().local_port, 0);
+        for invalid in ["", "0", "5432:0", "70000", "db", "5432@", "a:5432"] {
+            assert!(
+                ForwardRequest::parse(invalid).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// src/ui/process_pane.rs
+// State of the container process (top) pane
+// コンテナプロセス（top）ペインの状態
+
+use std::time::{Duration, Instant};
+
+use crate::domain::entities::{Container, ContainerProcess};
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+
+/// How often the process table of the selected container is reloaded
+/// 選択中のコンテナのプロセステーブルを再読み込みする間隔
+pub const PROCESS_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Pane listing the processes of the selected container
+/// 選択中のコンテナのプロセスを一覧表示するペイン
+///
+/// The pane follows the selection: it reloads as soon as another container
+/// is selected, and every `PROCESS_REFRESH_INTERVAL` otherwise.
+///
+/// ペインは選択に追従します。別のコンテナが選択されるとすぐに再読み込みし、
+/// それ以外は`PROCESS_REFRESH_INTERVAL`ごとに再読み込みします。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessPane {
+    /// Container the table belongs to
+    /// テーブルが属するコンテナ
+    pub container_id: Option<ContainerId>,
+
+    /// Display name of that container
+    /// そのコンテナの表示名
+    pub container_name: String,
+
+    /// Processes, busiest first
+    /// プロセス（負荷の高い順）
+    pub processes: Vec<ContainerProcess>,
+
+    /// Why no processes are shown (not running, engine error)
+    /// プロセスが表示されない理由（未実行、エンジンエラー）
+    pub message: Option<String>,
+
+    /// Time of the last reload (`None` before the first one)
+    /// 最後の再読み込み時刻（初回前は`None`）
+    pub refreshed_at: Option<Instant>,
+}
+
+impl ProcessPane {
+    /// Create an empty pane that loads on the next tick
+    /// 次のティックで読み込む空のペインを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether the pane should reload for the selected container
+    /// 選択中のコンテナについてペインを再読み込みすべきかチェック
+    #[must_use]
+    pub fn is_due(&self, selected: Option<&ContainerId>, now: Instant) -> bool {
+        self.container_id.as_ref() != selected
+            || self.refreshed_at.is_none_or(|refreshed_at| {
+                now.duration_since(refreshed_at) >= PROCESS_REFRESH_INTERVAL
+            })
+    }
+
+    /// Show the outcome of listing a container's processes
+    /// コンテナのプロセス一覧取得の結果を表示
+    pub fn show(
+        &mut self,
+        container: &Container,
+        result: DockaResult<Vec<ContainerProcess>>,
+        now: Instant,
+    ) {
+        self.container_id = Some(container.id.clone());
+        self.container_name = container.display_name().to_string();
+        self.refreshed_at = Some(now);
+        match result {
+            Ok(mut processes) => {
+                processes.sort_by(ContainerProcess::cmp_by_cpu);
+                self.processes = processes;
+                self.message = None;
+            }
+            Err(error) => {
+                self.processes.clear();
+                self.message = Some(error.to_string());
+            }
+        }
+    }
+
+    /// Show a message instead of processes (e.g. container not running)
+    /// プロセスの代わりにメッセージを表示（例: コンテナ未実行）
+    pub fn show_message(
+        &mut self,
+        container: Option<&Container>,
+        message: impl Into<String>,
+        now: Instant,
+    ) {
+        self.container_id = container.map(|container| container.id.clone());
+        self.container_name = container
+            .map(|container| container.display_name().to_string())
+            .unwrap_or_default();
+        self.processes.clear();
+        self.message = Some(message.into());
+        self.refreshed_at = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crate::error::DockaError;
+
+    fn create_container(id: &str) -> Container {
+        Container::builder()
+            .id(id)
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_due_on_selection_change_and_interval() {
+        // Test that the pane reloads for a new selection and after the interval
+        // 新しい選択時と間隔経過後にペインが再読み込みすることをテスト
+        let web = create_container("web-1");
+        let db = create_container("db-1");
+        let now = Instant::now();
+
+        let mut pane = ProcessPane::new();
+        assert!(pane.is_due(Some(&web.id), now));
+
+        pane.show(&web, Ok(Vec::new()), now);
+        assert!(!pane.is_due(Some(&web.id), now));
+        assert!(pane.is_due(Some(&db.id), now));
+        assert!(pane.is_due(Some(&web.id), now + PROCESS_REFRESH_INTERVAL));
+    }
+
+    #[test]
+    fn test_show_sorts_processes_and_reports_errors() {
+        // Test that processes are sorted by CPU and errors replace the table
+        // プロセスがCPU順に並び、エラーがテーブルを置き換えることをテスト
+        let web = create_container("web-1");
+        let now = Instant::now();
+        let process = |pid, cpu_percent| ContainerProcess {
+            pid,
+            cpu_percent: Some(cpu_percent),
+            ..ContainerProcess::default()
+        };
+
+        let mut pane = ProcessPane::new();
+        pane.show(&web, Ok(vec![process(1, 0.1), process(7, 55.0)]), now);
+        assert_eq!(pane.processes[0].pid, 7);
+        assert_eq!(pane.container_name, "web");
+
+        pane.show(&web, Err(DockaError::DockerDaemonNotRunning), now);
+        assert!(pane.processes.is_empty());
+        assert!(pane.message.is_some());
+    }
+}
@@ -0,0 +1,337 @@
+// src/ui/prune_dialog.rs
+// State of the guided prune dialog
+// ガイド付き削除ダイアログの状態
+
+use std::collections::BTreeSet;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::prune_service::{PruneOptions, PrunePreview, PruneReport, PruneTarget};
+use crate::error::DockaResult;
+use crate::ui::app::NavigationDirection;
+use crate::ui::text_input::TextInput;
+use crate::utils::time_formatter::parse_duration;
+
+/// Row of the options step
+/// オプション手順の行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneField {
+    /// Toggle an object kind
+    /// オブジェクトの種類を切り替え
+    Target(PruneTarget),
+
+    /// Minimum age text field
+    /// 最小経過時間のテキストフィールド
+    OlderThan,
+
+    /// Label filter text field
+    /// ラベルフィルタのテキストフィールド
+    Labels,
+
+    /// Build the preview
+    /// プレビューを作成
+    Preview,
+}
+
+impl PruneField {
+    /// Every row of the options step in display order
+    /// 表示順のオプション手順の全行
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        PruneTarget::ALL
+            .into_iter()
+            .map(Self::Target)
+            .chain([Self::OlderThan, Self::Labels, Self::Preview])
+            .collect()
+    }
+}
+
+/// Step of the prune dialog
+/// 削除ダイアログの手順
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PruneStage {
+    /// Choosing object kinds and filters
+    /// オブジェクトの種類とフィルタを選択中
+    Options,
+
+    /// Reviewing what would be removed
+    /// 削除されるものを確認中
+    Preview {
+        /// Candidates to remove
+        /// 削除候補
+        preview: PrunePreview,
+
+        /// Whether the final confirmation is being asked
+        /// 最終確認を求めているか
+        confirming: bool,
+    },
+
+    /// Showing the per-item outcome
+    /// 項目ごとの結果を表示中
+    Report(PruneReport),
+}
+
+/// Guided prune dialog: options, preview, confirmation and report
+/// ガイド付き削除ダイアログ: オプション、プレビュー、確認、結果
+///
+/// Nothing is removed before the preview has been confirmed twice
+/// (`Enter` to ask, `Enter` again to run); `Esc` steps back at any point.
+///
+/// プレビューを2回確認するまで何も削除されません（`Enter`で確認を求め、
+/// もう一度`Enter`で実行）。`Esc`でいつでも前の手順に戻ります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneDialog {
+    /// Selected object kinds
+    /// 選択されたオブジェクトの種類
+    pub targets: BTreeSet<PruneTarget>,
+
+    /// Minimum age (e.g. `7d`), empty for any age
+    /// 最小経過時間（例: `7d`）、空の場合は経過時間を問わない
+    pub older_than: TextInput,
+
+    /// Label filter (e.g. `env=dev,team=web`)
+    /// ラベルフィルタ（例: `env=dev,team=web`）
+    pub labels: TextInput,
+
+    /// Highlighted row (options) or first visible row (preview and report)
+    /// 選択行（オプション）または先頭の表示行（プレビューと結果）
+    pub cursor: usize,
+
+    /// Whether the highlighted text field receives keys
+    /// 選択中のテキストフィールドがキーを受け取っているか
+    pub editing: bool,
+
+    /// Current step
+    /// 現在の手順
+    pub stage: PruneStage,
+
+    /// Last validation or engine error
+    /// 最後の検証エラーまたはエンジンエラー
+    pub message: Option<String>,
+}
+
+impl Default for PruneDialog {
+    fn default() -> Self {
+        Self {
+            targets: PruneTarget::defaults(),
+            older_than: TextInput::new(),
+            labels: TextInput::new(),
+            cursor: 0,
+            editing: false,
+            stage: PruneStage::Options,
+            message: None,
+        }
+    }
+}
+
+impl PruneDialog {
+    /// Create a dialog with the default targets
+    /// デフォルトの対象でダイアログを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Row highlighted in the options step
+    /// オプション手順で選択中の行
+    #[must_use]
+    pub fn highlighted_field(&self) -> Option<PruneField> {
+        matches!(self.stage, PruneStage::Options)
+            .then(|| PruneField::all().get(self.cursor).copied())
+            .flatten()
+    }
+
+    /// Move the highlight (options, circular) or scroll (preview and report)
+    /// 選択を移動（オプション、循環）またはスクロール（プレビューと結果）
+    pub fn navigate(&mut self, direction: NavigationDirection) {
+        let (count, circular) = match &self.stage {
+            PruneStage::Options => (PruneField::all().len(), true),
+            PruneStage::Preview { preview, .. } => (preview.candidates.len(), false),
+            PruneStage::Report(report) => (report.results.len(), false),
+        };
+        if count == 0 {
+            return;
+        }
+
+        self.cursor = match (direction, circular) {
+            (NavigationDirection::Next, true) => (self.cursor + 1) % count,
+            (NavigationDirection::Previous, true) => (self.cursor + count - 1) % count,
+            (NavigationDirection::Next, false) => (self.cursor + 1).min(count - 1),
+            (NavigationDirection::Previous, false) => self.cursor.saturating_sub(1),
+        };
+    }
+
+    /// Toggle the highlighted target or start editing the highlighted field
+    /// 選択中の対象を切り替え、または選択中のフィールドの編集を開始
+    ///
+    /// Returns `true` when the highlighted row asks for the preview instead.
+    /// 選択行がプレビューを要求する場合は`true`を返します。
+    pub fn activate_field(&mut self) -> bool {
+        match self.highlighted_field() {
+            Some(PruneField::Target(target)) => self.toggle_target(target),
+            Some(PruneField::OlderThan | PruneField::Labels) => self.editing = true,
+            Some(PruneField::Preview) => return true,
+            None => {}
+        }
+        false
+    }
+
+    /// Select or deselect an object kind
+    /// オブジェクトの種類を選択または選択解除
+    pub fn toggle_target(&mut self, target: PruneTarget) {
+        if !self.targets.remove(&target) {
+            self.targets.insert(target);
+        }
+    }
+
+    /// Feed a raw key to the field being edited
+    /// 編集中のフィールドに生のキーを渡す
+    ///
+    /// `Enter` and `Esc` finish editing.
+    /// `Enter`と`Esc`で編集を終了します。
+    pub fn handle_text_key(&mut self, key_event: KeyEvent) {
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            self.editing = false;
+            return;
+        }
+
+        match self.highlighted_field() {
+            Some(PruneField::OlderThan) => {
+                self.older_than.handle_key(key_event);
+            }
+            Some(PruneField::Labels) => {
+                self.labels.handle_key(key_event);
+            }
+            _ => self.editing = false,
+        }
+    }
+
+    /// Build prune options from the dialog inputs
+    /// ダイアログの入力から削除オプションを構築
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the age or label filter cannot be parsed
+    pub fn options(&self) -> DockaResult<PruneOptions> {
+        let older_than = if self.older_than.is_empty() {
+            None
+        } else {
+            Some(parse_duration(self.older_than.value())?)
+        };
+
+        Ok(PruneOptions {
+            targets: self.targets.clone(),
+            older_than,
+            labels: PruneOptions::parse_labels(self.labels.value())?,
+        })
+    }
+
+    /// Show the preview step
+    /// プレビュー手順を表示
+    pub fn show_preview(&mut self, preview: PrunePreview) {
+        self.stage = PruneStage::Preview {
+            preview,
+            confirming: false,
+        };
+        self.cursor = 0;
+        self.message = None;
+    }
+
+    /// Show the report step
+    /// 結果手順を表示
+    pub fn show_report(&mut self, report: PruneReport) {
+        self.stage = PruneStage::Report(report);
+        self.cursor = 0;
+        self.message = None;
+    }
+
+    /// Step back; returns `true` when the dialog should close
+    /// 前の手順に戻る（ダイアログを閉じるべき場合は`true`を返す）
+    pub fn back(&mut self) -> bool {
+        match &mut self.stage {
+            PruneStage::Preview {
+                confirming: confirming @ true,
+                ..
+            } => *confirming = false,
+            PruneStage::Preview { .. } => {
+                self.stage = PruneStage::Options;
+                self.cursor = PruneField::all().len() - 1;
+            }
+            PruneStage::Options | PruneStage::Report(_) => return true,
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_toggle_and_edit_fields() {
+        // Test toggling a target and typing into the age field
+        // 対象の切り替えと経過時間フィールドへの入力をテスト
+        let mut dialog = PruneDialog::new();
+        assert!(dialog.targets.contains(&PruneTarget::StoppedContainers));
+        assert!(!dialog.activate_field());
+        assert!(!dialog.targets.contains(&PruneTarget::StoppedContainers));
+
+        dialog.cursor = PruneTarget::ALL.len();
+        assert_eq!(dialog.highlighted_field(), Some(PruneField::OlderThan));
+        dialog.activate_field();
+        assert!(dialog.editing);
+        for c in ['7', 'd'] {
+            dialog.handle_text_key(key(KeyCode::Char(c)));
+        }
+        dialog.handle_text_key(key(KeyCode::Enter));
+        assert!(!dialog.editing);
+
+        let options = dialog.options().unwrap();
+        assert_eq!(options.older_than, Some(chrono::Duration::days(7)));
+        assert!(!options.targets.contains(&PruneTarget::StoppedContainers));
+    }
+
+    #[test]
+    fn test_invalid_filters_are_rejected() {
+        // Test that malformed age and label filters fail validation
+        // 不正な経過時間とラベルのフィルタが検証で失敗することをテスト
+        let dialog = PruneDialog {
+            older_than: TextInput::with_value("soon"),
+            ..PruneDialog::new()
+        };
+        assert!(dialog.options().is_err());
+
+        let dialog = PruneDialog {
+            labels: TextInput::with_value("env"),
+            ..PruneDialog::new()
+        };
+        assert!(dialog.options().is_err());
+    }
+
+    #[test]
+    fn test_back_steps_through_stages() {
+        // Test that Esc leaves confirmation, then the preview, then the dialog
+        // Escで確認、プレビュー、ダイアログの順に戻ることをテスト
+        let mut dialog = PruneDialog::new();
+        dialog.show_preview(PrunePreview::default());
+        if let PruneStage::Preview { confirming, .. } = &mut dialog.stage {
+            *confirming = true;
+        }
+
+        assert!(!dialog.back());
+        assert!(matches!(
+            dialog.stage,
+            PruneStage::Preview {
+                confirming: false,
+                ..
+            }
+        ));
+        assert!(!dialog.back());
+        assert_eq!(dialog.highlighted_field(), Some(PruneField::Preview));
+        assert!(dialog.back());
+    }
+}
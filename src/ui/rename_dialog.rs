@@ -0,0 +1,132 @@
+// src/ui/rename_dialog.rs
+// State of the rename container dialog
+// コンテナ名変更ダイアログの状態
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::domain::entities::Container;
+use crate::domain::value_objects::ContainerId;
+use crate::error::DockaResult;
+use crate::ui::text_input::TextInput;
+
+/// Dialog giving the selected container a new name
+/// 選択中のコンテナに新しい名前を付けるダイアログ
+///
+/// The name field receives keys as soon as the dialog opens; `Enter`
+/// submits and `Esc` cancels.
+/// 名前フィールドはダイアログを開いた時点でキーを受け取り、`Enter`で確定、
+/// `Esc`でキャンセルします。
+#[derive(Debug, Clone)]
+pub struct RenameDialog {
+    /// Container being renamed
+    /// 名前を変更するコンテナ
+    pub container_id: ContainerId,
+
+    /// Name when the dialog was opened
+    /// ダイアログを開いた時点の名前
+    pub current_name: String,
+
+    /// New name, prefilled with the current one
+    /// 現在の名前で事前入力された新しい名前
+    pub input: TextInput,
+
+    /// Last validation or engine error
+    /// 最後の検証エラーまたはエンジンエラー
+    pub message: Option<String>,
+}
+
+/// Outcome of a key sent to the rename dialog
+/// 名前変更ダイアログに送ったキーの結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameKey {
+    /// The key edited the name or was ignored
+    /// キーが名前を編集した、または無視された
+    Edited,
+
+    /// `Enter`: rename the container
+    /// `Enter`: コンテナの名前を変更
+    Submit,
+
+    /// `Esc`: close without renaming
+    /// `Esc`: 名前を変更せずに閉じる
+    Cancel,
+}
+
+impl RenameDialog {
+    /// Create a dialog prefilled with the container's current name
+    /// コンテナの現在の名前で事前入力されたダイアログを作成
+    #[must_use]
+    pub fn new(container: &Container) -> Self {
+        Self {
+            container_id: container.id.clone(),
+            current_name: container.name.clone(),
+            input: TextInput::with_value(container.name.clone()),
+            message: None,
+        }
+    }
+
+    /// Send a raw key to the name field
+    /// 名前フィールドに生のキーを送る
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> RenameKey {
+        match key_event.code {
+            KeyCode::Enter => RenameKey::Submit,
+            KeyCode::Esc => RenameKey::Cancel,
+            _ => {
+                if self.input.handle_key(key_event) {
+                    self.message = None;
+                }
+                RenameKey::Edited
+            }
+        }
+    }
+
+    /// Validated new name, or `None` when it is unchanged
+    /// 検証済みの新しい名前（変更がない場合は`None`）
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When `Container::validate_name` rejects the name
+    pub fn new_name(&self) -> DockaResult<Option<String>> {
+        let name = self.input.value().trim();
+        let name = name.strip_prefix('/').unwrap_or(name);
+        Container::validate_name(name)?;
+        Ok((name != self.current_name).then(|| name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_new_name_is_validated() {
+        // Test that the name is prefilled, validated and unchanged names are skipped
+        // 名前が事前入力され、検証され、変更のない名前はスキップされることをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut dialog = RenameDialog::new(&container);
+        assert_eq!(dialog.input.value(), "web");
+        assert_eq!(dialog.new_name().unwrap(), None);
+
+        for c in " api".chars() {
+            dialog.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert!(dialog.new_name().is_err());
+
+        dialog.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        for c in "/web-api".chars() {
+            dialog.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.new_name().unwrap().as_deref(), Some("web-api"));
+        assert_eq!(
+            dialog.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            RenameKey::Submit
+        );
+    }
+}
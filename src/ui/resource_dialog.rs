@@ -0,0 +1,380 @@
+// src/ui/resource_dialog.rs
+// State of the resource limits dialog
+// リソース制限ダイアログの状態
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::domain::entities::Container;
+use crate::domain::value_objects::{ContainerId, ResourceLimits, RestartPolicy};
+use crate::error::{DockaError, DockaResult};
+use crate::ui::app::NavigationDirection;
+use crate::ui::text_input::TextInput;
+use crate::utils::size_formatter::{format_memory_size, parse_memory_size};
+
+/// Row of the resource limits dialog
+/// リソース制限ダイアログの行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceField {
+    /// Memory limit text field
+    /// メモリ制限のテキストフィールド
+    Memory,
+
+    /// Memory reservation text field
+    /// メモリ予約のテキストフィールド
+    MemoryReservation,
+
+    /// CPU shares text field
+    /// `CPU`シェアのテキストフィールド
+    CpuShares,
+
+    /// CPU quota text field
+    /// `CPU`クォータのテキストフィールド
+    CpuQuota,
+
+    /// PIDs limit text field
+    /// `PIDs`制限のテキストフィールド
+    PidsLimit,
+
+    /// Cycle the restart policy
+    /// 再起動ポリシーを切り替え
+    RestartPolicy,
+
+    /// Retry count of `on-failure`
+    /// `on-failure`の再試行回数
+    MaxRetries,
+
+    /// Apply the changes
+    /// 変更を適用
+    Apply,
+}
+
+impl ResourceField {
+    /// Every row in display order
+    /// 表示順の全行
+    pub const ALL: [Self; 8] = [
+        Self::Memory,
+        Self::MemoryReservation,
+        Self::CpuShares,
+        Self::CpuQuota,
+        Self::PidsLimit,
+        Self::RestartPolicy,
+        Self::MaxRetries,
+        Self::Apply,
+    ];
+}
+
+/// Dialog editing the resource limits and restart policy of the selected container
+/// 選択中のコンテナのリソース制限と再起動ポリシーを編集するダイアログ
+///
+/// Fields are prefilled from the container's current values; an empty field
+/// means no limit.
+///
+/// フィールドはコンテナの現在の値で事前入力され、空のフィールドは制限なしを意味します。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceDialog {
+    /// Container being edited
+    /// 編集中のコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of the container
+    /// コンテナの表示名
+    pub container_name: String,
+
+    /// Limits when the dialog was opened
+    /// ダイアログを開いた時点の制限
+    pub current: ResourceLimits,
+
+    /// Memory limit (e.g. `512m`)
+    /// メモリ制限（例: `512m`）
+    pub memory: TextInput,
+
+    /// Memory reservation (e.g. `256m`)
+    /// メモリ予約（例: `256m`）
+    pub memory_reservation: TextInput,
+
+    /// Relative CPU weight
+    /// 相対的な`CPU`の重み
+    pub cpu_shares: TextInput,
+
+    /// CPU time per period in microseconds
+    /// 期間あたりの`CPU`時間（マイクロ秒）
+    pub cpu_quota: TextInput,
+
+    /// Maximum number of processes
+    /// 最大プロセス数
+    pub pids_limit: TextInput,
+
+    /// Chosen restart policy
+    /// 選択した再起動ポリシー
+    pub restart_policy: RestartPolicy,
+
+    /// Retry count used when the policy is `on-failure`
+    /// ポリシーが`on-failure`の場合に使用する再試行回数
+    pub max_retries: TextInput,
+
+    /// Highlighted row
+    /// 選択中の行
+    pub cursor: usize,
+
+    /// Whether the highlighted text field receives keys
+    /// 選択中のテキストフィールドがキーを受け取っているか
+    pub editing: bool,
+
+    /// Last validation or engine error
+    /// 最後の検証エラーまたはエンジンエラー
+    pub message: Option<String>,
+}
+
+impl ResourceDialog {
+    /// Create a dialog prefilled with the container's current limits
+    /// コンテナの現在の制限で事前入力されたダイアログを作成
+    #[must_use]
+    pub fn new(container: &Container, current: ResourceLimits) -> Self {
+        let number = |value: Option<u64>| {
+            value.map_or_else(TextInput::new, |value| {
+                TextInput::with_value(value.to_string())
+            })
+        };
+        let memory = |value: Option<u64>| {
+            value.map_or_else(TextInput::new, |value| {
+                TextInput::with_value(format_memory_size(value))
+            })
+        };
+        let max_retries = match current.restart_policy {
+            RestartPolicy::OnFailure { max_retries } => max_retries,
+            _ => 0,
+        };
+
+        Self {
+            container_id: container.id.clone(),
+            container_name: container.display_name().to_string(),
+            memory: memory(current.memory),
+            memory_reservation: memory(current.memory_reservation),
+            cpu_shares: number(current.cpu_shares),
+            cpu_quota: number(current.cpu_quota),
+            pids_limit: number(current.pids_limit),
+            restart_policy: current.restart_policy,
+            max_retries: TextInput::with_value(max_retries.to_string()),
+            current,
+            cursor: 0,
+            editing: false,
+            message: None,
+        }
+    }
+
+    /// Highlighted row
+    /// 選択中の行
+    #[must_use]
+    pub fn highlighted_field(&self) -> Option<ResourceField> {
+        ResourceField::ALL.get(self.cursor).copied()
+    }
+
+    /// Move the highlight (circular)
+    /// 選択を移動（循環）
+    pub const fn navigate(&mut self, direction: NavigationDirection) {
+        let count = ResourceField::ALL.len();
+        self.cursor = match direction {
+            NavigationDirection::Next => (self.cursor + 1) % count,
+            NavigationDirection::Previous => (self.cursor + count - 1) % count,
+        };
+    }
+
+    /// Cycle the restart policy or start editing the highlighted field
+    /// 再起動ポリシーを切り替え、または選択中のフィールドの編集を開始
+    ///
+    /// Returns `true` when the highlighted row asks to apply the changes.
+    /// The retry count can only be edited for `on-failure`.
+    /// 選択行が変更の適用を要求する場合は`true`を返します。
+    /// 再試行回数は`on-failure`の場合のみ編集できます。
+    pub fn activate_field(&mut self) -> bool {
+        match self.highlighted_field() {
+            Some(ResourceField::RestartPolicy) => {
+                self.restart_policy = self.restart_policy.cycled();
+            }
+            Some(ResourceField::MaxRetries) => {
+                self.editing = matches!(self.restart_policy, RestartPolicy::OnFailure { .. });
+            }
+            Some(ResourceField::Apply) => return true,
+            Some(_) => self.editing = true,
+            None => {}
+        }
+        false
+    }
+
+    /// Text field of a row, if it has one
+    /// 行のテキストフィールド（ある場合）
+    const fn input_mut(&mut self, field: ResourceField) -> Option<&mut TextInput> {
+        match field {
+            ResourceField::Memory => Some(&mut self.memory),
+            ResourceField::MemoryReservation => Some(&mut self.memory_reservation),
+            ResourceField::CpuShares => Some(&mut self.cpu_shares),
+            ResourceField::CpuQuota => Some(&mut self.cpu_quota),
+            ResourceField::PidsLimit => Some(&mut self.pids_limit),
+            ResourceField::MaxRetries => Some(&mut self.max_retries),
+            ResourceField::RestartPolicy | ResourceField::Apply => None,
+        }
+    }
+
+    /// Feed a raw key to the field being edited
+    /// 編集中のフィールドに生のキーを渡す
+    ///
+    /// `Enter` and `Esc` finish editing.
+    /// `Enter`と`Esc`で編集を終了します。
+    pub fn handle_text_key(&mut self, key_event: KeyEvent) {
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            self.editing = false;
+            return;
+        }
+
+        match self
+            .highlighted_field()
+            .and_then(|field| self.input_mut(field))
+        {
+            Some(input) => {
+                input.handle_key(key_event);
+            }
+            None => self.editing = false,
+        }
+    }
+
+    /// Build the limits to apply from the dialog inputs
+    /// ダイアログの入力から適用する制限を構築
+    ///
+    /// The memory plus swap limit and the CPU period are kept as they were.
+    /// メモリとスワップの合計制限と`CPU`期間は元のまま維持します。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When a field cannot be parsed or the
+    ///   limits fail `ResourceLimits::validate`
+    pub fn limits(&self) -> DockaResult<ResourceLimits> {
+        let number = |label: &str, input: &TextInput| {
+            let text = input.value().trim();
+            if text.is_empty() {
+                return Ok(None);
+            }
+            text.parse::<u64>().map(Some).map_err(|_| {
+                DockaError::invalid_input(format!("{label} must be a whole number, got '{text}'"))
+            })
+        };
+        let memory = |input: &TextInput| {
+            let text = input.value().trim();
+            if text.is_empty() {
+                Ok(None)
+            } else {
+                parse_memory_size(text).map(Some)
+            }
+        };
+        let restart_policy = match self.restart_policy {
+            RestartPolicy::OnFailure { .. } => RestartPolicy::OnFailure {
+                max_retries: number("Max retries", &self.max_retries)?
+                    .map_or(Ok(0), u32::try_from)
+                    .map_err(|_| DockaError::invalid_input("Max retries is too large"))?,
+            },
+            policy => policy,
+        };
+
+        let limits = ResourceLimits {
+            memory: memory(&self.memory)?,
+            memory_reservation: memory(&self.memory_reservation)?,
+            memory_swap: self.current.memory_swap,
+            cpu_shares: number("CPU shares", &self.cpu_shares)?,
+            cpu_quota: number("CPU quota", &self.cpu_quota)?,
+            cpu_period: self.current.cpu_period,
+            pids_limit: number("PIDs limit", &self.pids_limit)?,
+            restart_policy,
+        };
+        limits.validate_update(&self.current)?;
+        Ok(limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crossterm::event::KeyModifiers;
+
+    fn create_dialog(current: ResourceLimits) -> ResourceDialog {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        ResourceDialog::new(&container, current)
+    }
+
+    fn type_text(dialog: &mut ResourceDialog, text: &str) {
+        dialog.handle_text_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        for c in text.chars() {
+            dialog.handle_text_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        dialog.handle_text_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_prefilled_fields_build_limits() {
+        // Test that current limits are prefilled and edits produce new limits
+        // 現在の制限が事前入力され、編集が新しい制限を生成することをテスト
+        let current = ResourceLimits {
+            memory: Some(512 << 20),
+            pids_limit: Some(100),
+            restart_policy: RestartPolicy::OnFailure { max_retries: 3 },
+            ..ResourceLimits::default()
+        };
+        let mut dialog = create_dialog(current.clone());
+        assert_eq!(dialog.memory.value(), "512m");
+        assert_eq!(dialog.pids_limit.value(), "100");
+        assert_eq!(dialog.max_retries.value(), "3");
+        assert_eq!(dialog.limits().unwrap(), current);
+
+        assert!(!dialog.activate_field());
+        type_text(&mut dialog, "1g");
+        assert!(!dialog.editing);
+        dialog.cursor = 4;
+        dialog.activate_field();
+        type_text(&mut dialog, "");
+        dialog.navigate(NavigationDirection::Next);
+        dialog.activate_field();
+        assert_eq!(dialog.restart_policy, RestartPolicy::No);
+
+        let limits = dialog.limits().unwrap();
+        assert_eq!(limits.memory, Some(1 << 30));
+        assert_eq!(limits.pids_limit, None);
+        assert_eq!(limits.restart_policy, RestartPolicy::No);
+
+        dialog.navigate(NavigationDirection::Next);
+        assert!(!dialog.activate_field());
+        assert!(!dialog.editing);
+        dialog.cursor = 0;
+        dialog.navigate(NavigationDirection::Previous);
+        assert_eq!(dialog.highlighted_field(), Some(ResourceField::Apply));
+        assert!(dialog.activate_field());
+    }
+
+    #[test]
+    fn test_invalid_inputs_are_rejected() {
+        // Test that unparsable and out-of-range values are reported
+        // 解析できない値と範囲外の値が報告されることをテスト
+        let mut dialog = create_dialog(ResourceLimits {
+            memory: Some(512 << 20),
+            ..ResourceLimits::default()
+        });
+        dialog.cpu_shares = TextInput::with_value("lots");
+        assert!(
+            dialog
+                .limits()
+                .unwrap_err()
+                .to_string()
+                .contains("CPU shares")
+        );
+
+        dialog.cpu_shares = TextInput::new();
+        dialog.memory = TextInput::new();
+        assert!(dialog.limits().is_err());
+
+        dialog.memory = TextInput::with_value("1m");
+        assert!(dialog.limits().is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// src/ui/restart_policy_picker.rs
+// State of the restart policy picker
+// 再起動ポリシーピッカーの状態
+
+use crate::domain::entities::Container;
+use crate::domain::value_objects::{ContainerId, ResourceLimits, RestartPolicy};
+use crate::ui::app::NavigationDirection;
+
+/// Picker changing the restart policy of the selected container
+/// 選択中のコンテナの再起動ポリシーを変更するピッカー
+///
+/// The other limits are kept as loaded, so applying only changes the
+/// policy. Choosing `on-failure` keeps the current retry count; the
+/// resource dialog edits it.
+/// 他の制限は読み込んだ値のまま維持されるため、適用で変わるのはポリシーのみです。
+/// `on-failure`を選ぶと現在の再試行回数が維持されます（回数はリソースダイアログで編集します）。
+#[derive(Debug, Clone)]
+pub struct RestartPolicyPicker {
+    /// Container whose policy is changed
+    /// ポリシーを変更するコンテナ
+    pub container_id: ContainerId,
+
+    /// Display name of the container
+    /// コンテナの表示名
+    pub container_name: String,
+
+    /// Limits when the picker was opened
+    /// ピッカーを開いた時点の制限
+    pub current: ResourceLimits,
+
+    /// Highlighted entry of `options`
+    /// `options`の選択中の項目
+    pub index: usize,
+}
+
+impl RestartPolicyPicker {
+    /// Create a picker with the current policy highlighted
+    /// 現在のポリシーを選択した状態のピッカーを作成
+    #[must_use]
+    pub fn new(container: &Container, current: ResourceLimits) -> Self {
+        let mut picker = Self {
+            container_id: container.id.clone(),
+            container_name: container.display_name().to_string(),
+            current,
+            index: 0,
+        };
+        picker.index = picker
+            .options()
+            .iter()
+            .position(|policy| *policy == picker.current.restart_policy)
+            .unwrap_or(0);
+        picker
+    }
+
+    /// Policies offered, in picker order
+    /// ピッカー順の選択肢のポリシー
+    #[must_use]
+    pub const fn options(&self) -> [RestartPolicy; 4] {
+        let max_retries = match self.current.restart_policy {
+            RestartPolicy::OnFailure { max_retries } => max_retries,
+            _ => 0,
+        };
+        [
+            RestartPolicy::No,
+            RestartPolicy::Always,
+            RestartPolicy::UnlessStopped,
+            RestartPolicy::OnFailure { max_retries },
+        ]
+    }
+
+    /// Policy under the highlight
+    /// 選択中のポリシー
+    #[must_use]
+    pub fn highlighted(&self) -> RestartPolicy {
+        self.options()[self.index.min(3)]
+    }
+
+    /// Move the highlight (circular)
+    /// 選択を移動（循環）
+    pub const fn navigate(&mut self, direction: NavigationDirection) {
+        let count = self.options().len();
+        self.index = match direction {
+            NavigationDirection::Next => (self.index + 1) % count,
+            NavigationDirection::Previous => (self.index + count - 1) % count,
+        };
+    }
+
+    /// Limits to send: the current ones with the highlighted policy
+    /// 送信する制限: 現在の制限に選択中のポリシーを設定したもの
+    #[must_use]
+    pub fn limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            restart_policy: self.highlighted(),
+            ..self.current.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+
+    #[test]
+    fn test_picker_starts_on_current_policy() {
+        // Test that the current policy and retry count are kept and other limits untouched
+        // 現在のポリシーと再試行回数が維持され、他の制限が変わらないことをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let current = ResourceLimits {
+            memory: Some(512 << 20),
+            restart_policy: RestartPolicy::OnFailure { max_retries: 5 },
+            ..ResourceLimits::default()
+        };
+        let mut picker = RestartPolicyPicker::new(&container, current);
+        assert_eq!(picker.index, 3);
+        assert_eq!(picker.limits(), picker.current);
+
+        picker.navigate(NavigationDirection::Next);
+        assert_eq!(picker.highlighted(), RestartPolicy::No);
+        picker.navigate(NavigationDirection::Previous);
+        picker.navigate(NavigationDirection::Previous);
+        let limits = picker.limits();
+        assert_eq!(limits.restart_policy, RestartPolicy::UnlessStopped);
+        assert_eq!(limits.memory, Some(512 << 20));
+    }
+}
@@ -0,0 +1,266 @@
+// src/ui/search_bar.rs
+// State of the incremental container search
+// コンテナのインクリメンタル検索の状態
+
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::domain::entities::Container;
+use crate::ui::text_input::TextInput;
+
+/// Number of confirmed queries kept in the history
+/// 履歴に保持する確定済みクエリの数
+const HISTORY_LIMIT: usize = 20;
+
+/// Incremental search over the container list, opened with `/`
+/// `/`で開くコンテナリストのインクリメンタル検索
+///
+/// While the query is typed, the list only shows matching containers.
+/// `Enter` confirms the query: the full list is shown again with the
+/// matches highlighted, and `n`/`N` jump between them. `Esc` clears the
+/// query. `Up`/`Down` recall confirmed queries.
+///
+/// クエリの入力中、一覧には一致するコンテナのみが表示されます。`Enter`で
+/// クエリを確定すると全体の一覧が再び表示されて一致箇所が強調され、`n`/`N`で
+/// 一致間を移動できます。`Esc`でクエリをクリアします。`Up`/`Down`で
+/// 確定済みのクエリを呼び出します。
+///
+/// A container matches when its name or image contains the query, ignoring
+/// ASCII case, or when its ID starts with the query.
+/// 名前またはイメージがクエリを含む（ASCIIの大文字小文字は無視）か、
+/// IDがクエリで始まる場合にコンテナは一致します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchBar {
+    /// Query being typed or confirmed
+    /// 入力中または確定済みのクエリ
+    pub input: TextInput,
+
+    /// Whether the query is being typed
+    /// クエリを入力中か
+    pub editing: bool,
+
+    /// Confirmed queries, oldest first
+    /// 確定済みのクエリ（古い順）
+    history: Vec<String>,
+
+    /// History entry shown in the input while browsing with `Up`/`Down`
+    /// `Up`/`Down`で閲覧中に入力に表示している履歴の項目
+    history_index: Option<usize>,
+}
+
+/// Outcome of a key sent to the search bar
+/// 検索バーに送ったキーの結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKey {
+    /// The key edited the query or was ignored
+    /// キーがクエリを編集した、または無視された
+    Edited,
+
+    /// `Enter`: keep the query and highlight its matches
+    /// `Enter`: クエリを維持して一致箇所を強調
+    Submit,
+
+    /// `Esc`: clear the query
+    /// `Esc`: クエリをクリア
+    Cancel,
+}
+
+impl SearchBar {
+    /// Create an empty search without history
+    /// 履歴のない空の検索を作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start typing a new query
+    /// 新しいクエリの入力を開始
+    pub fn open(&mut self) {
+        self.input.clear();
+        self.editing = true;
+        self.history_index = None;
+    }
+
+    /// Send a raw key to the query field
+    /// クエリフィールドに生のキーを送る
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> SearchKey {
+        match key_event.code {
+            KeyCode::Enter => {
+                self.submit();
+                SearchKey::Submit
+            }
+            KeyCode::Esc => {
+                self.clear();
+                SearchKey::Cancel
+            }
+            KeyCode::Up => {
+                self.recall(self.history_index.map_or_else(
+                    || self.history.len().checked_sub(1),
+                    |index| Some(index.saturating_sub(1)),
+                ));
+                SearchKey::Edited
+            }
+            KeyCode::Down => {
+                self.recall(
+                    self.history_index
+                        .map(|index| index + 1)
+                        .filter(|index| *index < self.history.len()),
+                );
+                SearchKey::Edited
+            }
+            _ => {
+                self.input.handle_key(key_event);
+                SearchKey::Edited
+            }
+        }
+    }
+
+    /// Query in effect, `None` when empty
+    /// 有効なクエリ（空の場合は`None`）
+    #[must_use]
+    pub fn query(&self) -> Option<&str> {
+        Some(self.input.value().trim()).filter(|query| !query.is_empty())
+    }
+
+    /// Confirmed queries, oldest first
+    /// 確定済みのクエリ（古い順）
+    #[must_use]
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Check whether a container matches the query in effect
+    /// コンテナが有効なクエリに一致するかチェック
+    ///
+    /// Every container matches when there is no query.
+    /// クエリがない場合は全てのコンテナが一致します。
+    #[must_use]
+    pub fn matches(&self, container: &Container) -> bool {
+        self.query().is_none_or(|query| {
+            container.id.matches(query)
+                || !Self::match_ranges(&container.name, query).is_empty()
+                || !Self::match_ranges(&container.image, query).is_empty()
+        })
+    }
+
+    /// Byte ranges of `text` containing `query`, ignoring ASCII case
+    /// `query`を含む`text`のバイト範囲（ASCIIの大文字小文字は無視）
+    #[must_use]
+    pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let text = text.to_ascii_lowercase();
+        let query = query.to_ascii_lowercase();
+        text.match_indices(&query)
+            .map(|(start, found)| start..start + found.len())
+            .collect()
+    }
+
+    /// Stop typing, remembering the query in the history
+    /// 入力を終了し、クエリを履歴に記録
+    fn submit(&mut self) {
+        self.editing = false;
+        self.history_index = None;
+        let Some(query) = self.query().map(ToString::to_string) else {
+            self.input.clear();
+            return;
+        };
+        self.history.retain(|entry| *entry != query);
+        self.history.push(query);
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+    }
+
+    /// Stop typing and forget the query
+    /// 入力を終了してクエリを破棄
+    pub fn clear(&mut self) {
+        self.input.clear();
+        self.editing = false;
+        self.history_index = None;
+    }
+
+    /// Show a history entry in the input, or an empty input past the newest one
+    /// 履歴の項目を入力に表示（最新より後は空の入力）
+    fn recall(&mut self, index: Option<usize>) {
+        self.history_index = index;
+        self.input = index.map_or_else(TextInput::new, |index| {
+            TextInput::with_value(self.history[index].clone())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::ContainerStatus;
+    use crossterm::event::KeyModifiers;
+
+    fn type_query(search: &mut SearchBar, query: &str) {
+        search.open();
+        for c in query.chars() {
+            search.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_matches_name_id_prefix_and_image() {
+        // Test that the query matches names and images anywhere and IDs by prefix
+        // クエリが名前とイメージの任意の位置、IDの先頭に一致することをテスト
+        let container = Container::builder()
+            .id("3f4e5d6c7b8a9f0e")
+            .name("billing-api")
+            .image("registry.local/Postgres:16")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut search = SearchBar::new();
+        assert!(search.matches(&container));
+
+        for (query, expected) in [
+            ("API", true),
+            ("postgres", true),
+            ("3f4e", true),
+            ("4e5d", false),
+            ("redis", false),
+        ] {
+            type_query(&mut search, query);
+            assert_eq!(search.matches(&container), expected, "{query}");
+        }
+        assert_eq!(SearchBar::match_ranges("api-apigw", "API"), [0..3, 4..7]);
+    }
+
+    #[test]
+    fn test_history_is_recalled_with_up_and_down() {
+        // Test that confirmed queries are kept once and recalled newest first
+        // 確定済みのクエリが1回だけ保持され、新しい順に呼び出されることをテスト
+        let mut search = SearchBar::new();
+        for query in ["web", "db", "web"] {
+            type_query(&mut search, query);
+            assert_eq!(search.handle_key(key(KeyCode::Enter)), SearchKey::Submit);
+        }
+        assert_eq!(search.history(), ["db", "web"]);
+        assert_eq!(search.query(), Some("web"));
+
+        search.open();
+        search.handle_key(key(KeyCode::Up));
+        assert_eq!(search.query(), Some("web"));
+        search.handle_key(key(KeyCode::Up));
+        search.handle_key(key(KeyCode::Up));
+        assert_eq!(search.query(), Some("db"));
+        search.handle_key(key(KeyCode::Down));
+        assert_eq!(search.query(), Some("web"));
+        search.handle_key(key(KeyCode::Down));
+        assert_eq!(search.query(), None);
+
+        assert_eq!(search.handle_key(key(KeyCode::Esc)), SearchKey::Cancel);
+        assert!(!search.editing);
+        assert_eq!(search.history().len(), 2);
+    }
+}
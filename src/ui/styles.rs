@@ -255,7 +255,7 @@ impl BlockStyles {
     /// # Returns / 戻り値
     /// * `Block` - Styled status block widget / スタイル設定されたステータスブロックウィジェット
     #[must_use]
-    pub fn status_block(&self, state_style: Style) -> Block {
+    pub fn status_block(&self, state_style: Style) -> Block<'_> {
         Block::default()
             .borders(Borders::ALL)
             .border_type(self.normal_border)
@@ -0,0 +1,173 @@
+// src/ui/text_input.rs
+// Single-line text input state
+// 単一行テキスト入力の状態
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Editable single-line text with a cursor
+/// カーソル付きの編集可能な単一行テキスト
+///
+/// Text fields receive raw key events instead of `AppEvent`s so that every
+/// character, including bound keys such as `q` or `j`, can be typed.
+///
+/// テキストフィールドは`AppEvent`ではなく生のキーイベントを受け取るため、
+/// `q`や`j`などバインド済みのキーを含む全ての文字を入力できます。
+///
+/// # Examples
+///
+/// ```rust
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use docka::ui::text_input::TextInput;
+///
+/// let mut input = TextInput::with_value("7");
+/// input.handle_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+/// assert_eq!(input.value(), "7d");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInput {
+    value: String,
+    /// Cursor position in characters
+    /// 文字単位のカーソル位置
+    cursor: usize,
+}
+
+impl TextInput {
+    /// Create an empty input
+    /// 空の入力を作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an input with the cursor after `value`
+    /// `value`の後ろにカーソルを置いた入力を作成
+    #[must_use]
+    pub fn with_value(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let cursor = value.chars().count();
+        Self { value, cursor }
+    }
+
+    /// Current text
+    /// 現在のテキスト
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Cursor position in characters
+    /// 文字単位のカーソル位置
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Check whether the input is empty
+    /// 入力が空かチェック
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Remove all text
+    /// 全テキストを削除
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    /// Text before and after the cursor
+    /// カーソルの前後のテキスト
+    #[must_use]
+    pub fn split_at_cursor(&self) -> (&str, &str) {
+        self.value.split_at(self.byte_index(self.cursor))
+    }
+
+    /// Apply an editing key; returns `false` for keys the input ignores
+    /// 編集キーを適用（入力が無視するキーでは`false`を返す）
+    ///
+    /// Supports printable characters, `Backspace`, `Delete`, `Left`, `Right`,
+    /// `Home`, `End` and `Ctrl+U` (clear).
+    ///
+    /// 印字可能文字、`Backspace`、`Delete`、`Left`、`Right`、`Home`、`End`、
+    /// `Ctrl+U`（クリア）をサポートします。
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> bool {
+        let control = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        match key_event.code {
+            KeyCode::Char('u') if control => self.clear(),
+            KeyCode::Char(c) if !control => {
+                let index = self.byte_index(self.cursor);
+                self.value.insert(index, c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let index = self.byte_index(self.cursor);
+                self.value.remove(index);
+            }
+            KeyCode::Delete if self.cursor < self.len() => {
+                let index = self.byte_index(self.cursor);
+                self.value.remove(index);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.len(),
+            KeyCode::Backspace | KeyCode::Delete => {}
+            _ => return false,
+        }
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_editing_in_the_middle() {
+        // Test inserting and deleting around a moved cursor, including multibyte text
+        // マルチバイト文字を含め、移動したカーソル周辺での挿入と削除をテスト
+        let mut input = TextInput::with_value("env=dév");
+        input.handle_key(key(KeyCode::Left));
+        input.handle_key(key(KeyCode::Backspace));
+        assert_eq!(input.value(), "env=dv");
+        input.handle_key(key(KeyCode::Char('e')));
+        assert_eq!(input.value(), "env=dev");
+        assert_eq!(input.split_at_cursor(), ("env=de", "v"));
+
+        input.handle_key(key(KeyCode::Home));
+        input.handle_key(key(KeyCode::Delete));
+        assert_eq!(input.value(), "nv=dev");
+        input.handle_key(key(KeyCode::Backspace));
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_control_keys() {
+        // Test that Ctrl+U clears and other control keys are ignored
+        // Ctrl+Uでクリアされ、その他の制御キーが無視されることをテスト
+        let mut input = TextInput::with_value("abc");
+        assert!(!input.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert!(!input.handle_key(key(KeyCode::Enter)));
+        assert_eq!(input.value(), "abc");
+
+        assert!(input.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)));
+        assert!(input.is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+// src/ui/widgets/action_menu.rs
+// Container action menu popup widget
+// コンテナアクションメニューポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::ui::{actions::ActionMenuEntry, app::App, layouts::SimpleLayout, styles::Theme};
+
+/// Popup listing the actions available for the selected container
+/// 選択中のコンテナで利用可能なアクションを一覧表示するポップアップ
+///
+/// Actions the connected engine cannot run are shown muted with the reason;
+/// `j`/`k` move the highlight, `Enter` runs the action and `Esc` closes the popup.
+///
+/// 接続中のエンジンが実行できないアクションは理由付きで淡色表示されます。
+/// `j`/`k`で選択を移動し、`Enter`でアクションを実行、`Esc`でポップアップを閉じます。
+pub struct ActionMenuWidget;
+
+impl ActionMenuWidget {
+    /// Render the action menu centered within `area` if it is open
+    /// アクションメニューが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(highlighted) = app.action_menu_index else {
+            return;
+        };
+        let Some(container) = app.selected_container() else {
+            return;
+        };
+
+        let items: Vec<ListItem> = app
+            .action_menu_entries()
+            .iter()
+            .map(|entry| Self::format_action_item(entry, theme))
+            .collect();
+
+        // Borders (2) + one row per action, capped by the popup helper
+        // ボーダー(2) + アクションごとに1行、ポップアップヘルパーで制限
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 60, height);
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Actions: {} (Enter: run, Esc: cancel)",
+                        container.display_name()
+                    ))
+                    .border_style(theme.blocks.active_border_style)
+                    .title_style(theme.styles.primary_style()),
+            )
+            .highlight_style(theme.styles.selected_style());
+
+        let mut state = ListState::default().with_selected(Some(highlighted));
+
+        f.render_widget(Clear, popup);
+        f.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Format a single action entry
+    /// 単一のアクション項目をフォーマット
+    fn format_action_item(entry: &ActionMenuEntry, theme: &Theme) -> ListItem<'static> {
+        let line = entry.disabled_reason.as_ref().map_or_else(
+            || {
+                Line::from(Span::styled(
+                    entry.action.label(),
+                    theme.styles.normal_style(),
+                ))
+            },
+            |reason| {
+                Line::from(vec![
+                    Span::styled(entry.action.label(), theme.styles.muted_style()),
+                    Span::styled(
+                        format!(" (unsupported: {reason})"),
+                        theme.styles.muted_style(),
+                    ),
+                ])
+            },
+        );
+
+        ListItem::new(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::{
+        ApiVersion, ContainerStatus, EngineCapabilities, EngineFeature,
+    };
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| ActionMenuWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_app_with_running_container() -> App {
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.containers = vec![
+            Container::builder()
+                .id("abc123")
+                .name("web")
+                .image("nginx:latest")
+                .status(ContainerStatus::Running)
+                .build()
+                .unwrap(),
+        ];
+        app
+    }
+
+    #[test]
+    fn test_render_closed_menu_draws_nothing() {
+        // Test that a closed menu leaves the screen untouched
+        // 閉じたメニューが画面に何も描画しないことをテスト
+        let app = create_app_with_running_container();
+        assert!(render_to_string(&app).trim().is_empty());
+    }
+
+    #[test]
+    fn test_render_menu_shows_disabled_reason() {
+        // Test that unsupported actions are listed with their reason
+        // サポートされないアクションが理由付きで表示されることをテスト
+        let mut app = create_app_with_running_container();
+        app.capabilities = EngineCapabilities::new("20.10.7", "linux", ApiVersion::new(1, 41))
+            .with_unsupported(EngineFeature::Pause, "no cgroup");
+        app.open_action_menu();
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Actions: web"));
+        assert!(screen.contains("Stop"));
+        assert!(screen.contains("Pause (unsupported: no cgroup)"));
+        assert!(!screen.contains("Start"));
+    }
+}
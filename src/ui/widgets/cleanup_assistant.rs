@@ -0,0 +1,294 @@
+// src/ui/widgets/cleanup_assistant.rs
+// Stale container cleanup assistant widget
+// 古いコンテナのクリーンアップアシスタントウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::entities::Container;
+use crate::ui::{
+    app::App,
+    cleanup_assistant::{CleanupAssistant, CleanupField, CleanupRow},
+    styles::Theme,
+};
+use crate::utils::time_formatter::format_duration;
+
+/// Most removal failures listed below the candidates
+/// 候補の下に表示する削除失敗の最大数
+const MAX_FAILURES_SHOWN: usize = 3;
+
+/// View listing stopped containers that match cleanup criteria
+/// クリーンアップ基準に一致する停止中のコンテナを一覧表示するビュー
+///
+/// The criteria sit above the candidates; each candidate shows its age
+/// and, when known, how long ago it exited.
+///
+/// 基準は候補の上に配置され、各候補は経過時間と、判明している場合は
+/// 終了からの経過時間を表示します。
+pub struct CleanupAssistantWidget;
+
+impl CleanupAssistantWidget {
+    /// Render the assistant within `area`
+    /// `area`内にアシスタントを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(assistant) = &app.cleanup.assistant else {
+            return;
+        };
+
+        let items: Vec<ListItem> = (0..assistant.row_count())
+            .filter_map(|index| assistant.row(index))
+            .map(|row| ListItem::new(Self::row_line(assistant, row, theme)))
+            .collect();
+        let footer = Self::footer_lines(assistant, theme);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Cleanup assistant: {} candidates",
+                assistant.candidates.len()
+            ))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(u16::try_from(footer.len()).unwrap_or(u16::MAX)),
+            ])
+            .split(inner);
+
+        f.render_widget(block, area);
+        let list = List::new(items).highlight_style(theme.styles.selected_style());
+        let mut state = ListState::default().with_selected(Some(assistant.cursor));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+        f.render_widget(Paragraph::new(footer), chunks[1]);
+    }
+
+    /// Format one row of the assistant
+    /// アシスタントの1行をフォーマット
+    fn row_line(assistant: &CleanupAssistant, row: CleanupRow, theme: &Theme) -> Line<'static> {
+        match row {
+            CleanupRow::Field(field) => Self::field_line(assistant, field, theme),
+            CleanupRow::Remove => {
+                let count = assistant.selected().len();
+                if assistant.confirming {
+                    Line::from(Span::styled(
+                        format!("Remove {count} containers? Enter: confirm, Esc: cancel"),
+                        theme.styles.error_style(),
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!("Remove {count} selected ▶"),
+                        theme.styles.primary_style(),
+                    ))
+                }
+            }
+            CleanupRow::Candidate(index) => {
+                let container = &assistant.candidates[index];
+                Self::candidate_line(container, assistant.is_selected(container), theme)
+            }
+        }
+    }
+
+    /// Format a criterion, with a cursor while editing
+    /// 基準をフォーマット（編集中はカーソル付き）
+    fn field_line(
+        assistant: &CleanupAssistant,
+        field: CleanupField,
+        theme: &Theme,
+    ) -> Line<'static> {
+        let input = assistant.input(field);
+        let label = Span::raw(format!("{:<20}", field.label()));
+        let editing =
+            assistant.editing && assistant.highlighted_row() == Some(CleanupRow::Field(field));
+
+        if editing {
+            let (before, after) = input.split_at_cursor();
+            Line::from(vec![
+                label,
+                Span::raw(before.to_string()),
+                Span::styled("▏", theme.styles.primary_style()),
+                Span::raw(after.to_string()),
+            ])
+        } else if input.is_empty() {
+            Line::from(vec![
+                label,
+                Span::styled(field.placeholder(), theme.styles.muted_style()),
+            ])
+        } else {
+            let value = match field {
+                CleanupField::ExitedFor | CleanupField::CreatedFor => {
+                    format!("{} ago", input.value())
+                }
+                CleanupField::Labels | CleanupField::ExitCode => input.value().to_string(),
+            };
+            Line::from(vec![label, Span::raw(value)])
+        }
+    }
+
+    /// Format a candidate with its selection mark, age and exit time
+    /// 候補を選択マーク、経過時間、終了日時付きでフォーマット
+    fn candidate_line(container: &Container, selected: bool, theme: &Theme) -> Line<'static> {
+        let mark = if selected { "[x]" } else { "[ ]" };
+        let exited = container
+            .exited_for()
+            .map(|duration| format!("exited {} ago", format_duration(duration)))
+            .unwrap_or_default();
+        let style = if selected {
+            theme.styles.normal_style()
+        } else {
+            theme.styles.muted_style()
+        };
+
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "{mark} {:<28} {:<24} {:<14} ",
+                    container.display_name(),
+                    container.image,
+                    container.status.to_string()
+                ),
+                style,
+            ),
+            Span::styled(
+                format!("created {:<16} ", container.age()),
+                theme.styles.muted_style(),
+            ),
+            Span::styled(exited, theme.styles.muted_style()),
+        ])
+    }
+
+    /// Footer: last error, last removal outcome and key hints
+    /// フッター: 最後のエラー、最後の削除結果、キーヒント
+    fn footer_lines(assistant: &CleanupAssistant, theme: &Theme) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        if let Some(message) = &assistant.message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                theme.styles.error_style(),
+            )));
+        }
+
+        if let Some(report) = &assistant.report {
+            lines.push(Line::from(Span::styled(
+                format!("{} removed, {} failed", report.succeeded(), report.failed()),
+                theme.styles.success_style(),
+            )));
+            lines.extend(
+                report
+                    .results
+                    .iter()
+                    .filter_map(|result| {
+                        result
+                            .error
+                            .as_ref()
+                            .map(|error| format!("✗ {}: {error}", result.candidate.name))
+                    })
+                    .take(MAX_FAILURES_SHOWN)
+                    .map(|text| Line::from(Span::styled(text, theme.styles.error_style()))),
+            );
+        }
+
+        let hint = if assistant.editing {
+            "Type to edit, Enter: apply, Esc: done, Ctrl+U: clear"
+        } else if assistant.candidates.is_empty() {
+            "No stopped containers match. Enter: edit criteria, q/Esc: back"
+        } else {
+            "Enter: edit/toggle, r: reload, q/Esc: back"
+        };
+        lines.push(Line::from(Span::styled(hint, theme.styles.muted_style())));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::prune_service::{PruneCandidate, PruneItemResult, PruneReport};
+    use crate::domain::MockDockerRepository;
+    use crate::domain::value_objects::ContainerStatus;
+    use crate::ui::app::ViewState;
+    use chrono::{Duration, Utc};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(130, 16);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| CleanupAssistantWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn exited_container(name: &str, days: i64) -> Container {
+        Container::builder()
+            .id(name)
+            .name(name)
+            .image("runner:latest")
+            .status(ContainerStatus::Exited { exit_code: 1 })
+            .created_at(Utc::now() - Duration::days(days + 1))
+            .finished_at(Utc::now() - Duration::days(days))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_render_candidates_with_age() {
+        // Test that candidates show their selection, age and exit time
+        // 候補が選択状態、経過時間、終了日時を表示することをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut assistant = CleanupAssistant::new();
+        assistant.set_candidates(vec![
+            exited_container("ci-job-1", 9),
+            exited_container("ci-job-2", 8),
+        ]);
+        assistant.toggle(1);
+        app.cleanup.assistant = Some(assistant);
+        app.view_state = ViewState::CleanupAssistant;
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Cleanup assistant: 2 candidates"));
+        assert!(screen.contains("Exited more than    7d ago"));
+        assert!(screen.contains("Remove 1 selected ▶"));
+        assert!(screen.contains("[x] ci-job-1"));
+        assert!(screen.contains("[ ] ci-job-2"));
+        assert!(screen.contains("created 10 days ago"));
+        assert!(screen.contains("exited 1w2d ago"));
+    }
+
+    #[test]
+    fn test_render_confirmation_and_report() {
+        // Test the confirmation prompt and the failures of the last removal
+        // 確認プロンプトと最後の削除の失敗表示をテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut assistant = CleanupAssistant::new();
+        assistant.set_candidates(vec![exited_container("ci-job-1", 9)]);
+        assistant.confirming = true;
+        let container = exited_container("ci-job-0", 9);
+        assistant.report = Some(PruneReport {
+            results: vec![PruneItemResult {
+                candidate: PruneCandidate::from(&container),
+                error: Some("device or resource busy".to_string()),
+            }],
+        });
+        app.cleanup.assistant = Some(assistant);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Remove 1 containers? Enter: confirm"));
+        assert!(screen.contains("0 removed, 1 failed"));
+        assert!(screen.contains("✗ ci-job-0: device or resource busy"));
+    }
+}
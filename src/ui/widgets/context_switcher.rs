@@ -0,0 +1,124 @@
+// src/ui/widgets/context_switcher.rs
+// Docker context switcher popup widget
+// Dockerコンテキストスイッチャーポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::ui::{app::App, layouts::SimpleLayout, styles::Theme};
+
+/// Popup listing the known Docker endpoints (contexts)
+/// 既知の`Docker`エンドポイント（コンテキスト）を一覧表示するポップアップ
+///
+/// The active endpoint is marked with `●`; `j`/`k` move the highlight,
+/// `Enter` reconnects to the highlighted endpoint and `Esc` closes the popup.
+///
+/// アクティブなエンドポイントは`●`で示されます。`j`/`k`で選択を移動し、
+/// `Enter`で選択したエンドポイントに再接続、`Esc`でポップアップを閉じます。
+pub struct ContextSwitcherWidget;
+
+impl ContextSwitcherWidget {
+    /// Render the context switcher centered within `area` if it is open
+    /// コンテキストスイッチャーが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(highlighted) = app.context_switcher_index else {
+            return;
+        };
+
+        let items: Vec<ListItem> = app
+            .available_endpoints
+            .iter()
+            .map(|name| Self::format_endpoint_item(name, *name == app.endpoint_name, theme))
+            .collect();
+
+        // Borders (2) + one row per endpoint, capped by the popup helper
+        // ボーダー(2) + エンドポイントごとに1行、ポップアップヘルパーで制限
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 50, height);
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Docker Contexts (Enter: switch, Esc: cancel)")
+                    .border_style(theme.blocks.active_border_style)
+                    .title_style(theme.styles.primary_style()),
+            )
+            .highlight_style(theme.styles.selected_style());
+
+        let mut state = ListState::default().with_selected(Some(highlighted));
+
+        f.render_widget(Clear, popup);
+        f.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Format a single endpoint entry
+    /// 単一のエンドポイント項目をフォーマット
+    fn format_endpoint_item<'a>(name: &'a str, is_active: bool, theme: &Theme) -> ListItem<'a> {
+        let (marker, style) = if is_active {
+            ("● ", theme.styles.success_style())
+        } else {
+            ("  ", theme.styles.normal_style())
+        };
+
+        ListItem::new(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(name, style),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(60, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| ContextSwitcherWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn test_render_closed_switcher_draws_nothing() {
+        // Test that a closed switcher leaves the screen untouched
+        // 閉じたスイッチャーが画面に何も描画しないことをテスト
+        let app = App::new(Arc::new(MockDockerRepository::new()));
+        assert!(render_to_string(&app).trim().is_empty());
+    }
+
+    #[test]
+    fn test_render_open_switcher_lists_endpoints() {
+        // Test that all endpoints are listed and the active one is marked
+        // 全エンドポイントが表示されアクティブなものが示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.set_available_endpoints(
+            vec!["default".to_string(), "build-01".to_string()],
+            "build-01",
+        );
+        app.open_context_switcher();
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Docker Contexts"));
+        assert!(screen.contains("default"));
+        assert!(screen.contains("● build-01"));
+    }
+}
@@ -0,0 +1,328 @@
+// src/ui/widgets/copy_dialog.rs
+// Copy files dialog popup widget
+// ファイルコピーダイアログポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::copy_service::{CopyDirection, CopyPlan, CopyProgress, CopyReport};
+use crate::ui::{
+    app::App,
+    copy_dialog::{CopyDialog, CopyField, CopyStage},
+    layouts::SimpleLayout,
+    styles::Theme,
+    widgets::PruneDialogWidget,
+};
+use crate::utils::size_formatter::format_size;
+
+/// Width of the text progress bar in cells
+/// テキスト進捗バーの幅（セル数）
+const PROGRESS_BAR_WIDTH: u32 = 40;
+
+/// Popup copying a file or directory between the selected container and a local path
+/// 選択中のコンテナとローカルパスの間でファイルまたはディレクトリをコピーするポップアップ
+///
+/// Uploads show a progress bar; downloads show the bytes and entries copied
+/// so far, since the engine does not announce the archive size.
+///
+/// アップロードは進捗バーを表示し、ダウンロードはエンジンがアーカイブサイズを
+/// 通知しないため、これまでにコピーしたバイト数とエントリ数を表示します。
+pub struct CopyDialogWidget;
+
+impl CopyDialogWidget {
+    /// Render the copy dialog centered within `area` if it is open
+    /// コピーダイアログが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(dialog) = &app.copy_dialog else {
+            return;
+        };
+
+        let (items, footer) = match &dialog.stage {
+            CopyStage::Options => (
+                Self::option_items(dialog, theme),
+                Self::options_footer(dialog, theme),
+            ),
+            CopyStage::ConfirmOverwrite(plan) => (
+                vec![ListItem::new(Self::route_line(dialog, plan))],
+                vec![
+                    Line::from(Span::styled(
+                        format!("{} already exists. Replace it?", plan.destination()),
+                        theme.styles.error_style(),
+                    )),
+                    Line::from(Span::styled(
+                        "Enter: overwrite, Esc: back",
+                        theme.styles.muted_style(),
+                    )),
+                ],
+            ),
+            CopyStage::Running { plan, .. } => (
+                Self::progress_items(dialog, plan, &dialog.progress().unwrap_or_default(), theme),
+                vec![Line::from(Span::styled(
+                    "Esc: cancel",
+                    theme.styles.muted_style(),
+                ))],
+            ),
+            CopyStage::Done(outcome) => (
+                vec![ListItem::new(Self::outcome_line(outcome, theme))],
+                vec![Line::from(Span::styled(
+                    "Enter/Esc: close",
+                    theme.styles.muted_style(),
+                ))],
+            ),
+        };
+
+        // Borders (2) + rows + footer, capped by the popup helper
+        // ボーダー(2) + 行 + フッター、ポップアップヘルパーで制限
+        let footer_height = u16::try_from(footer.len()).unwrap_or(u16::MAX);
+        let height = u16::try_from(items.len().max(1))
+            .unwrap_or(u16::MAX)
+            .saturating_add(footer_height)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 80, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Copy files: {}", dialog.container_name))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(popup);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(footer_height)])
+            .split(inner);
+
+        f.render_widget(Clear, popup);
+        f.render_widget(block, popup);
+
+        let list = List::new(items).highlight_style(theme.styles.selected_style());
+        let selected = matches!(dialog.stage, CopyStage::Options).then_some(dialog.cursor);
+        let mut state = ListState::default().with_selected(selected);
+        f.render_stateful_widget(list, chunks[0], &mut state);
+        f.render_widget(Paragraph::new(footer), chunks[1]);
+    }
+
+    /// Build the option rows: direction, both paths and the start button
+    /// オプション行を構築: 方向、両方のパス、開始ボタン
+    fn option_items(dialog: &CopyDialog, theme: &Theme) -> Vec<ListItem<'static>> {
+        let editing = |field| dialog.editing && dialog.highlighted_field() == Some(field);
+        CopyField::ALL
+            .into_iter()
+            .map(|field| {
+                let line = match field {
+                    CopyField::Direction => Line::from(vec![
+                        Span::raw(format!("{:<12}", "Direction")),
+                        Span::styled(dialog.direction.label(), theme.styles.primary_style()),
+                    ]),
+                    CopyField::ContainerPath => PruneDialogWidget::input_line(
+                        "Container",
+                        &dialog.container_path,
+                        "absolute path (e.g. /var/log)",
+                        editing(field),
+                        theme,
+                    ),
+                    CopyField::LocalPath => PruneDialogWidget::input_line(
+                        "Local",
+                        &dialog.local_path,
+                        "local file or directory",
+                        editing(field),
+                        theme,
+                    ),
+                    CopyField::Start => {
+                        Line::from(Span::styled("Copy ▶", theme.styles.primary_style()))
+                    }
+                };
+                ListItem::new(line)
+            })
+            .collect()
+    }
+
+    /// Footer of the options step: last error and key hints
+    /// オプション手順のフッター: 最後のエラーとキーヒント
+    fn options_footer(dialog: &CopyDialog, theme: &Theme) -> Vec<Line<'static>> {
+        let hint = if dialog.editing {
+            "Type to edit, Enter/Esc: done, Ctrl+U: clear"
+        } else {
+            "Enter: toggle/edit, Esc: close"
+        };
+        let mut lines = Vec::new();
+        if let Some(message) = &dialog.message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                theme.styles.error_style(),
+            )));
+        }
+        lines.push(Line::from(Span::styled(hint, theme.styles.muted_style())));
+        lines
+    }
+
+    /// Format the source and destination of a planned copy
+    /// 計画したコピーのソースと宛先をフォーマット
+    fn route_line(dialog: &CopyDialog, plan: &CopyPlan) -> Line<'static> {
+        let container = format!("{}:{}", dialog.container_name, plan.request.container_path);
+        let line = match plan.request.direction {
+            CopyDirection::FromContainer => format!("{container} → {}", plan.destination()),
+            CopyDirection::ToContainer => format!(
+                "{} → {}:{}",
+                plan.request.local_path.display(),
+                dialog.container_name,
+                plan.destination()
+            ),
+        };
+        Line::from(line)
+    }
+
+    /// Build the rows shown while copying
+    /// コピー中に表示する行を構築
+    fn progress_items(
+        dialog: &CopyDialog,
+        plan: &CopyPlan,
+        progress: &CopyProgress,
+        theme: &Theme,
+    ) -> Vec<ListItem<'static>> {
+        let copied = format!(
+            "{} copied, {} entries",
+            format_size(progress.bytes),
+            progress.entries
+        );
+        let amount = match (progress.fraction(), progress.total_bytes) {
+            (Some(fraction), Some(total)) => Line::from(vec![
+                Span::styled(progress_bar(fraction), theme.styles.success_style()),
+                Span::raw(format!(" {copied} of {}", format_size(total))),
+            ]),
+            _ => Line::from(copied),
+        };
+        vec![
+            ListItem::new(Self::route_line(dialog, plan)),
+            ListItem::new(amount),
+            ListItem::new(Span::styled(
+                progress.current.clone(),
+                theme.styles.muted_style(),
+            )),
+        ]
+    }
+
+    /// Format the outcome of a finished copy
+    /// 終了したコピーの結果をフォーマット
+    fn outcome_line(outcome: &Result<CopyReport, String>, theme: &Theme) -> Line<'static> {
+        match outcome {
+            Ok(report) => Line::from(vec![
+                Span::styled("✓ ", theme.styles.success_style()),
+                Span::raw(format!(
+                    "Copied {} entries ({}) to {}",
+                    report.entries,
+                    format_size(report.bytes),
+                    report.destination
+                )),
+            ]),
+            Err(error) => Line::from(vec![
+                Span::styled("✗ ", theme.styles.error_style()),
+                Span::styled(error.clone(), theme.styles.error_style()),
+            ]),
+        }
+    }
+}
+
+/// Text progress bar such as `[█████░░░░░]  50%`
+/// `[█████░░░░░]  50%`のようなテキスト進捗バー
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Clamped to 0..=1
+fn progress_bar(fraction: f64) -> String {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let filled = (fraction * f64::from(PROGRESS_BAR_WIDTH)).round() as u32;
+    format!(
+        "[{}{}] {:>3.0}%",
+        "█".repeat(filled as usize),
+        "░".repeat((PROGRESS_BAR_WIDTH - filled) as usize),
+        fraction * 100.0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::copy_service::CopyRequest;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(90, 16);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| CopyDialogWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_dialog() -> CopyDialog {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        CopyDialog::new(&container)
+    }
+
+    #[test]
+    fn test_render_options_and_overwrite_prompt() {
+        // Test the option rows and the confirmation shown for an existing destination
+        // オプション行と既存の宛先に対する確認表示をテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.copy_dialog = Some(create_dialog());
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Copy files: web"));
+        assert!(screen.contains("Container → local"));
+        assert!(screen.contains("absolute path (e.g. /var/log)"));
+
+        let dialog = app.copy_dialog.as_mut().unwrap();
+        let plan = CopyPlan {
+            request: CopyRequest {
+                container_path: "/var/log".to_string(),
+                ..dialog.request()
+            },
+            target_dir: PathBuf::from("backup"),
+            target_name: "log".to_string(),
+            overwrites: true,
+        };
+        dialog.confirm_overwrite(plan);
+        let screen = render_to_string(&app);
+        assert!(screen.contains("web:/var/log → backup/log"));
+        assert!(screen.contains("backup/log already exists. Replace it?"));
+    }
+
+    #[test]
+    fn test_progress_bar_and_outcome() {
+        // Test the progress bar text and the report line
+        // 進捗バーのテキストと結果行をテスト
+        assert_eq!(
+            progress_bar(0.5),
+            format!("[{}{}]  50%", "█".repeat(20), "░".repeat(20))
+        );
+
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut dialog = create_dialog();
+        dialog.stage = CopyStage::Done(Ok(CopyReport {
+            entries: 3,
+            bytes: 1_500,
+            destination: "./log".to_string(),
+        }));
+        app.copy_dialog = Some(dialog);
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Copied 3 entries (1.5 kB) to ./log"));
+    }
+}
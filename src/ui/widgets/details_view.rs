@@ -0,0 +1,561 @@
+// src/ui/widgets/details_view.rs
+// Container details view widget
+// コンテナ詳細ビューウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::domain::entities::{ContainerDetails, ContainerExit, HealthProbe, HealthReport};
+use crate::domain::value_objects::ContainerStatus;
+use crate::ui::{app::App, details_view::DetailsView, styles::Theme, widgets::ContainerListWidget};
+use crate::utils::secret_mask::MASK;
+use crate::utils::time_formatter::{DisplayTimezone, TimeDisplay, format_elapsed};
+use chrono::{DateTime, Utc};
+
+/// View showing the inspect details of a container in sections
+/// コンテナのinspect詳細をセクションに分けて表示するビュー
+///
+/// The state section shows when the container last started and exited. The
+/// health section lists the most recent probes first with their exit
+/// code and output, so the reason a check fails can be read directly. The
+/// restarts section shows the restart count and a timeline of exits. The
+/// environment section shows the command and variables with secret values
+/// masked until they are revealed, and the labels section lists the labels.
+///
+/// 状態セクションはコンテナが最後に起動・終了した時刻を表示します。
+/// ヘルスセクションは直近のプローブを新しい順に終了コードと出力付きで表示するため、
+/// チェックが失敗する理由を直接読み取れます。再起動セクションは再起動回数と
+/// 終了のタイムラインを表示します。環境セクションはコマンドと変数を表示し、
+/// シークレットの値は明示的に表示するまでマスクします。ラベルセクションは
+/// ラベルを一覧表示します。
+pub struct DetailsViewWidget;
+
+impl DetailsViewWidget {
+    /// Render the view within `area`
+    /// `area`内にビューを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(view) = &app.details_view else {
+            return;
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Details: {}", view.container_name))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        f.render_widget(block, area);
+
+        if let Some(message) = &view.message {
+            f.render_widget(
+                Paragraph::new(Span::styled(message.clone(), theme.styles.error_style())),
+                chunks[0],
+            );
+        } else if view.details.is_some() {
+            let lines: Vec<Line> = Self::lines(view, app, theme)
+                .into_iter()
+                .skip(view.scroll)
+                .collect();
+            f.render_widget(Paragraph::new(lines), chunks[0]);
+        } else {
+            f.render_widget(
+                Paragraph::new(Span::styled("Loading…", theme.styles.loading_style())),
+                chunks[0],
+            );
+        }
+
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "j/k: scroll, r: reload, u: relative/absolute times, m: reveal/mask secrets, q/Esc: back",
+                theme.styles.muted_style(),
+            )),
+            chunks[1],
+        );
+    }
+
+    /// Every line of the details, in the order counted by `DetailsView::line_count`
+    /// `DetailsView::line_count`が数える順の詳細の全行
+    ///
+    /// Times follow the app's relative or absolute display.
+    /// 時刻はアプリの相対表示または日時表示に従います。
+    fn lines(view: &DetailsView, app: &App, theme: &Theme) -> Vec<Line<'static>> {
+        let Some(details) = &view.details else {
+            return Vec::new();
+        };
+        let mut lines = Self::state_lines(details, app, Utc::now(), theme);
+        lines.push(Line::default());
+        lines.extend(Self::health_lines(
+            details.health.as_ref(),
+            app.timezone,
+            theme,
+        ));
+        lines.push(Line::default());
+        lines.extend(Self::restart_lines(
+            details,
+            &view.exits,
+            app.timezone,
+            theme,
+        ));
+        lines.push(Line::default());
+        lines.extend(Self::environment_lines(view, details, app, theme));
+        lines.push(Line::default());
+        lines.extend(Self::label_lines(view, app, theme));
+        lines
+    }
+
+    /// Labels section: one `key=value` line per label, ordered by key
+    /// ラベルセクション: キー順にラベルごとに1行の`key=value`
+    ///
+    /// Values with a secret key are masked like environment variables.
+    /// シークレットのキーを持つ値は環境変数と同様にマスクされます。
+    fn label_lines(view: &DetailsView, app: &App, theme: &Theme) -> Vec<Line<'static>> {
+        let mut lines = vec![Self::heading("Labels", theme)];
+        if view.labels.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No labels",
+                theme.styles.muted_style(),
+            )));
+        }
+        lines.extend(view.labels.iter().map(|(key, value)| {
+            let value = if view.reveal_secrets || !app.secret_mask.is_secret(key) {
+                value.clone()
+            } else {
+                MASK.to_string()
+            };
+            Line::from(vec![
+                Span::styled(format!("  {key}="), theme.styles.muted_style()),
+                Span::styled(value, theme.styles.normal_style()),
+            ])
+        }));
+        lines
+    }
+
+    /// Section heading
+    /// セクション見出し
+    fn heading(title: &'static str, theme: &Theme) -> Line<'static> {
+        Line::from(Span::styled(
+            title,
+            theme.styles.primary_style().add_modifier(Modifier::BOLD),
+        ))
+    }
+
+    /// State section: when the container last started and exited
+    /// 状態セクション: コンテナが最後に起動した時刻と終了した時刻
+    fn state_lines(
+        details: &ContainerDetails,
+        app: &App,
+        now: DateTime<Utc>,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let time = |at: Option<DateTime<Utc>>| match (at, app.time_display) {
+            (None, _) => "never".to_string(),
+            (Some(at), TimeDisplay::Relative) => format!("{} ago", format_elapsed(now - at)),
+            (Some(at), TimeDisplay::Absolute) => app.timezone.format(at),
+        };
+        let line = |label: &'static str, at: Option<DateTime<Utc>>| {
+            Line::from(vec![
+                Span::styled(label, theme.styles.muted_style()),
+                Span::styled(time(at), theme.styles.normal_style()),
+            ])
+        };
+        vec![
+            Self::heading("State", theme),
+            line("  Started:  ", details.started_at),
+            line("  Finished: ", details.finished_at),
+        ]
+    }
+
+    /// Health section: status and failing streak, then the probes newest first
+    /// ヘルスセクション: ステータスと連続失敗数、続いて新しい順のプローブ
+    fn health_lines(
+        health: Option<&HealthReport>,
+        timezone: DisplayTimezone,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let mut lines = vec![Self::heading("Health", theme)];
+        let Some(report) = health else {
+            lines.push(Line::from(Span::styled(
+                "  No health check configured",
+                theme.styles.muted_style(),
+            )));
+            return lines;
+        };
+
+        let status_style = ContainerListWidget::health_style(report.status, theme)
+            .unwrap_or_else(|| theme.styles.muted_style());
+        lines.push(Line::from(vec![
+            Span::styled("  Status: ", theme.styles.muted_style()),
+            Span::styled(report.status.to_string(), status_style),
+            Span::styled("  Failing streak: ", theme.styles.muted_style()),
+            Span::styled(
+                report.failing_streak.to_string(),
+                theme.styles.normal_style(),
+            ),
+        ]));
+        for probe in report.latest_probes() {
+            lines.push(Self::probe_line(probe, timezone, theme));
+            lines.extend(probe.output.trim_end().lines().map(|output| {
+                Line::from(Span::styled(
+                    format!("      {output}"),
+                    theme.styles.muted_style(),
+                ))
+            }));
+        }
+        lines
+    }
+
+    /// Restarts section: restart count and OOM kill, then the recorded exits newest first
+    /// 再起動セクション: 再起動回数とOOMによる強制終了、続いて新しい順の記録された終了
+    fn restart_lines(
+        details: &ContainerDetails,
+        exits: &[ContainerExit],
+        timezone: DisplayTimezone,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Self::heading("Restarts", theme),
+            Line::from(vec![
+                Span::styled("  Restart count: ", theme.styles.muted_style()),
+                Span::styled(
+                    details.restart_count.to_string(),
+                    theme.styles.normal_style(),
+                ),
+            ]),
+        ];
+        if details.oom_killed {
+            lines.push(Line::from(Span::styled(
+                "  ✘ Last exit was an OOM kill (memory limit reached)",
+                theme.styles.error_style(),
+            )));
+        }
+        if exits.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No exits recorded",
+                theme.styles.muted_style(),
+            )));
+        }
+        for exit in exits.iter().rev() {
+            let (marker, style) = if exit.exit_code == Some(0) {
+                ("■", theme.styles.muted_style())
+            } else {
+                ("✘", theme.styles.error_style())
+            };
+            let code = exit.exit_code.map_or_else(
+                || "?".to_string(),
+                |code| {
+                    ContainerStatus::exit_code_meaning(code)
+                        .map_or_else(|| code.to_string(), |meaning| format!("{code} ({meaning})"))
+                },
+            );
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {marker} "), style),
+                Span::styled(timezone.format(exit.at), theme.styles.normal_style()),
+                Span::styled(format!("  exit {code}"), style),
+            ]));
+        }
+        lines
+    }
+
+    /// Environment section: the command, then one line per variable
+    /// 環境セクション: コマンド、続いて変数ごとに1行
+    ///
+    /// Secret values are masked unless the app reveals them.
+    /// アプリが表示しない限り、シークレットの値はマスクされます。
+    fn environment_lines(
+        view: &DetailsView,
+        details: &ContainerDetails,
+        app: &App,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let mut heading = Self::heading("Command and environment", theme);
+        if view.reveal_secrets {
+            heading.push_span(Span::styled(
+                "  (secrets revealed)",
+                theme.styles.error_style(),
+            ));
+        }
+        let command = view.command.as_deref().map_or_else(
+            || "-".to_string(),
+            |command| {
+                if view.reveal_secrets {
+                    command.to_string()
+                } else {
+                    app.secret_mask.mask_text(command)
+                }
+            },
+        );
+        let mut lines = vec![
+            heading,
+            Line::from(vec![
+                Span::styled("  Command: ", theme.styles.muted_style()),
+                Span::styled(command, theme.styles.normal_style()),
+            ]),
+        ];
+        if details.env.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No environment variables",
+                theme.styles.muted_style(),
+            )));
+        }
+        lines.extend(details.env.iter().map(|entry| {
+            let entry = if view.reveal_secrets {
+                entry.clone()
+            } else {
+                app.secret_mask.mask_env(entry)
+            };
+            Line::from(Span::styled(
+                format!("  {entry}"),
+                theme.styles.normal_style(),
+            ))
+        }));
+        lines
+    }
+
+    /// Format one probe as `✔ start  exit code  duration`
+    /// 1件のプローブを`✔ 開始時刻  終了コード  所要時間`の形式でフォーマット
+    fn probe_line(probe: &HealthProbe, timezone: DisplayTimezone, theme: &Theme) -> Line<'static> {
+        let (marker, style) = if probe.is_success() {
+            ("✔", theme.styles.success_style())
+        } else {
+            ("✘", theme.styles.error_style())
+        };
+        let duration = probe.duration().map_or_else(
+            || "running".to_string(),
+            |duration| format!("{}ms", duration.num_milliseconds()),
+        );
+
+        Line::from(vec![
+            Span::styled(format!("  {marker} "), style),
+            Span::styled(timezone.format(probe.start), theme.styles.normal_style()),
+            Span::styled(format!("  exit {}", probe.exit_code), style),
+            Span::styled(format!("  {duration}"), theme.styles.muted_style()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::HealthStatus;
+    use crate::ui::details_view::DetailsView;
+    use chrono::{DateTime, Duration, Utc};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| DetailsViewWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_view(details: ContainerDetails) -> DetailsView {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut view = DetailsView::new(&container);
+        view.show(Ok(details));
+        view
+    }
+
+    #[test]
+    fn test_render_health_log_newest_first() {
+        // Test that probes are listed newest first with exit code, duration and output
+        // プローブが終了コード、所要時間、出力付きで新しい順に表示されることをテスト
+        let start = DateTime::parse_from_rfc3339("2024-05-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let probe = |seconds: i64, exit_code: i64, output: &str| HealthProbe {
+            start: start + Duration::seconds(seconds),
+            end: Some(start + Duration::seconds(seconds) + Duration::milliseconds(120)),
+            exit_code,
+            output: output.to_string(),
+        };
+        let details = ContainerDetails {
+            health: Some(HealthReport {
+                status: HealthStatus::Unhealthy,
+                failing_streak: 2,
+                probes: vec![probe(0, 0, "ok\n"), probe(30, 1, "connection refused\n")],
+            }),
+            ..ContainerDetails::default()
+        };
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.timezone = DisplayTimezone::Utc;
+        let view = create_view(details);
+        assert_eq!(
+            DetailsViewWidget::lines(&view, &app, &Theme::dark()).len(),
+            view.line_count()
+        );
+        app.details_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Details: web"));
+        assert!(screen.contains("Status: unhealthy  Failing streak: 2"));
+        let failed = screen
+            .find("✘ 2024-05-01 10:00:30 UTC  exit 1  120ms")
+            .unwrap();
+        let passed = screen.find("✔ 2024-05-01 10:00:00 UTC  exit 0").unwrap();
+        assert!(failed < passed);
+        assert!(screen.contains("connection refused"));
+    }
+
+    #[test]
+    fn test_render_without_health_check() {
+        // Test the message shown for containers without a health check
+        // ヘルスチェックのないコンテナに表示されるメッセージをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.details_view = Some(create_view(ContainerDetails::default()));
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("No health check configured"));
+        assert!(screen.contains("No exits recorded"));
+    }
+
+    #[test]
+    fn test_render_labels_ordered_by_key() {
+        // Test that labels are listed by key with secret values masked
+        // ラベルがキー順に表示され、シークレットの値がマスクされることをテスト
+        let mut view = create_view(ContainerDetails::default());
+        view.labels = vec![
+            ("env".to_string(), "prod".to_string()),
+            ("registry_token".to_string(), "abc123".to_string()),
+            ("team".to_string(), "payments".to_string()),
+        ];
+        view.scroll = 9;
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        assert_eq!(
+            DetailsViewWidget::lines(&view, &app, &Theme::dark()).len(),
+            view.line_count()
+        );
+        app.details_view = Some(view);
+
+        let screen = render_to_string(&app);
+        let env = screen.find("env=prod").unwrap();
+        let team = screen.find("team=payments").unwrap();
+        assert!(env < team);
+        assert!(screen.contains("registry_token=••••••"));
+    }
+
+    #[test]
+    fn test_render_environment_masks_secrets_until_revealed() {
+        // Test that secret values are masked by default and shown after revealing
+        // シークレットの値がデフォルトでマスクされ、表示切り替え後に表示されることをテスト
+        let mut view = create_view(ContainerDetails {
+            env: vec![
+                "POSTGRES_PASSWORD=hunter2".to_string(),
+                "PGDATA=/var/lib/postgresql".to_string(),
+            ],
+            ..ContainerDetails::default()
+        });
+        view.command = Some("postgres --api-key abc123".to_string());
+        // Scroll past the other sections so the environment fits on screen
+        // 環境が画面に収まるよう他のセクションをスクロールで飛ばす
+        view.scroll = 9;
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        assert_eq!(
+            DetailsViewWidget::lines(&view, &app, &Theme::dark()).len(),
+            view.line_count()
+        );
+        app.details_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Command: postgres --api-key ••••••"));
+        assert!(screen.contains("POSTGRES_PASSWORD=••••••"));
+        assert!(screen.contains("PGDATA=/var/lib/postgresql"));
+        assert!(!screen.contains("hunter2"));
+        assert!(!screen.contains("abc123"));
+
+        app.toggle_reveal_secrets();
+        let screen = render_to_string(&app);
+        assert!(screen.contains("(secrets revealed)"));
+        assert!(screen.contains("POSTGRES_PASSWORD=hunter2"));
+        assert!(screen.contains("--api-key abc123"));
+    }
+
+    #[test]
+    fn test_render_restart_timeline() {
+        // Test that the restart count and exits are shown newest first with their codes
+        // 再起動回数と終了がコード付きで新しい順に表示されることをテスト
+        let at = DateTime::parse_from_rfc3339("2024-05-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut view = create_view(ContainerDetails {
+            restart_count: 6,
+            oom_killed: true,
+            ..ContainerDetails::default()
+        });
+        view.exits = [(0, Some(1)), (40, Some(137)), (80, None)]
+            .into_iter()
+            .map(|(seconds, exit_code)| ContainerExit {
+                container_id: view.container_id.clone(),
+                at: at + Duration::seconds(seconds),
+                exit_code,
+            })
+            .collect();
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.timezone = DisplayTimezone::Utc;
+        assert_eq!(
+            DetailsViewWidget::lines(&view, &app, &Theme::dark()).len(),
+            view.line_count()
+        );
+        app.details_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Restart count: 6"));
+        assert!(screen.contains("Last exit was an OOM kill"));
+        let unknown = screen.find("✘ 2024-05-01 10:01:20 UTC  exit ?").unwrap();
+        let killed = screen
+            .find("✘ 2024-05-01 10:00:40 UTC  exit 137 (SIGKILL or OOM)")
+            .unwrap();
+        let failed = screen.find("✘ 2024-05-01 10:00:00 UTC  exit 1").unwrap();
+        assert!(unknown < killed && killed < failed);
+    }
+
+    #[test]
+    fn test_render_state_times() {
+        // Test that start and exit times follow the relative or absolute display
+        // 起動時刻と終了時刻が相対表示または日時表示に従うことをテスト
+        let started_at = Utc::now() - Duration::minutes(200);
+        let view = create_view(ContainerDetails {
+            started_at: Some(started_at),
+            ..ContainerDetails::default()
+        });
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.details_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Started:  3h ago"));
+        assert!(screen.contains("Finished: never"));
+
+        app.timezone = DisplayTimezone::parse("+09:00").unwrap();
+        app.toggle_time_display();
+        let screen = render_to_string(&app);
+        let expected = format!("Started:  {}", app.timezone.format(started_at));
+        assert!(expected.ends_with("+09:00"));
+        assert!(screen.contains(&expected));
+    }
+}
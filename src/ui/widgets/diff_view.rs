@@ -0,0 +1,202 @@
+// src/ui/widgets/diff_view.rs
+// Filesystem changes (docker diff) view widget
+// ファイルシステム変更（docker diff）ビューウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::entities::{ChangeCounts, ChangeKind};
+use crate::ui::{
+    app::App,
+    diff_view::{ChangeRow, DiffView},
+    styles::Theme,
+};
+
+/// View showing a container's writable layer changes as a collapsible tree
+/// コンテナの書き込み可能レイヤーの変更を折りたたみ可能なツリーとして表示するビュー
+///
+/// Directories show how many files below them were added, changed or
+/// deleted, so paths that should be volumes stand out.
+///
+/// ディレクトリには配下で追加・変更・削除されたファイル数を表示し、
+/// ボリュームにすべきパスが目立つようにします。
+pub struct DiffViewWidget;
+
+impl DiffViewWidget {
+    /// Render the view within `area`
+    /// `area`内にビューを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(view) = &app.diff_view else {
+            return;
+        };
+
+        let total = view.tree.total();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Changes: {} ({} files: {})",
+                view.container_name,
+                total.total(),
+                Self::counts_label(total)
+            ))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        f.render_widget(block, area);
+
+        let rows = view.tree.visible_rows();
+        if let Some(message) = &view.message {
+            f.render_widget(
+                Paragraph::new(Span::styled(message.clone(), theme.styles.error_style())),
+                chunks[0],
+            );
+        } else if rows.is_empty() {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    "No changes in the writable layer",
+                    theme.styles.muted_style(),
+                )),
+                chunks[0],
+            );
+        } else {
+            let items: Vec<ListItem> = rows
+                .iter()
+                .map(|row| ListItem::new(Self::row_line(view, *row, theme)))
+                .collect();
+            let list = List::new(items).highlight_style(theme.styles.selected_style());
+            let mut state = ListState::default().with_selected(Some(view.cursor));
+            f.render_stateful_widget(list, chunks[0], &mut state);
+        }
+
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "Enter: expand/collapse, r: reload, q/Esc: back",
+                theme.styles.muted_style(),
+            )),
+            chunks[1],
+        );
+    }
+
+    /// Format counts as `+added ~changed -deleted`
+    /// 数を`+追加 ~変更 -削除`の形式でフォーマット
+    fn counts_label(counts: ChangeCounts) -> String {
+        format!("+{} ~{} -{}", counts.added, counts.modified, counts.deleted)
+    }
+
+    /// Style of a change marker
+    /// 変更マーカーのスタイル
+    fn kind_style(kind: Option<ChangeKind>, theme: &Theme) -> Style {
+        match kind {
+            Some(ChangeKind::Added) => theme.styles.success_style(),
+            Some(ChangeKind::Modified) => theme.styles.loading_style(),
+            Some(ChangeKind::Deleted) => theme.styles.error_style(),
+            None => theme.styles.muted_style(),
+        }
+    }
+
+    /// Format one tree row with indentation, marker and counts
+    /// 1行のツリー行をインデント、マーカー、数付きでフォーマット
+    fn row_line(view: &DiffView, row: ChangeRow, theme: &Theme) -> Line<'static> {
+        let node = &view.tree.nodes[row.node];
+        let toggle = match (node.is_directory(), node.expanded) {
+            (false, _) => " ",
+            (true, false) => "▸",
+            (true, true) => "▾",
+        };
+        let marker = node.kind.map_or(' ', ChangeKind::marker);
+
+        let mut spans = vec![
+            Span::raw(format!("{}{toggle} ", "  ".repeat(row.depth))),
+            Span::styled(format!("{marker} "), Self::kind_style(node.kind, theme)),
+            Span::styled(node.name.clone(), theme.styles.normal_style()),
+        ];
+        if node.is_directory() {
+            spans.push(Span::styled(
+                format!("  ({})", Self::counts_label(node.counts)),
+                theme.styles.muted_style(),
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::{Container, FilesystemChange};
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| DiffViewWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_view() -> DiffView {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        DiffView::new(&container)
+    }
+
+    #[test]
+    fn test_render_tree_with_counts() {
+        // Test that directories show their counts and expanded children
+        // ディレクトリが数と展開された子を表示することをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut view = create_view();
+        view.show(Ok(vec![
+            FilesystemChange::new("/var", ChangeKind::Modified),
+            FilesystemChange::new("/var/cache/apt.bin", ChangeKind::Added),
+            FilesystemChange::new("/etc/motd", ChangeKind::Deleted),
+        ]));
+        view.cursor = 1;
+        view.toggle_highlighted();
+        app.diff_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Changes: web (2 files: +1 ~0 -1)"));
+        assert!(screen.contains("▸   etc  (+0 ~0 -1)"));
+        assert!(screen.contains("▾ C var  (+1 ~0 -0)"));
+        assert!(screen.contains("  ▸   cache  (+1 ~0 -0)"));
+    }
+
+    #[test]
+    fn test_render_empty_layer() {
+        // Test the message shown when nothing changed
+        // 何も変更されていない場合のメッセージをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut view = create_view();
+        view.show(Ok(Vec::new()));
+        app.diff_view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("No changes in the writable layer"));
+    }
+}
@@ -0,0 +1,337 @@
+// src/ui/widgets/file_browser.rs
+// In-container file browser widget
+// コンテナ内ファイルブラウザウィジェット
+
+use chrono::DateTime;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::file_browser_service::{FilePreview, MAX_SCANNED_ENTRIES, PREVIEW_LIMIT};
+use crate::domain::entities::{FileEntry, PathKind};
+use crate::ui::{
+    app::App,
+    file_browser::{BrowserRow, FileBrowser},
+    styles::Theme,
+};
+use crate::utils::size_formatter::format_size;
+
+/// Lines used by the metadata of the highlighted entry, including its border
+/// 選択中のエントリのメタデータが使用する行数（境界線を含む）
+const METADATA_HEIGHT: u16 = 6;
+
+/// View listing a container's directories next to the highlighted file's details
+/// コンテナのディレクトリ一覧と選択中のファイルの詳細を並べて表示するビュー
+///
+/// The left pane lists the current directory; the right pane shows the
+/// metadata of the highlighted entry and, once opened, the beginning of a
+/// text file.
+///
+/// 左ペインは現在のディレクトリを一覧表示し、右ペインは選択中のエントリの
+/// メタデータと、開いた場合はテキストファイルの先頭部分を表示します。
+pub struct FileBrowserWidget;
+
+impl FileBrowserWidget {
+    /// Render the view within `area`
+    /// `area`内にビューを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(browser) = &app.file_browser else {
+            return;
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Files: {}:{}",
+                browser.container_name, browser.listing.path
+            ))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(rows[0]);
+        f.render_widget(block, area);
+
+        Self::render_listing(f, browser, columns[0], theme);
+        Self::render_details(f, browser, columns[1], theme);
+
+        let hint = if browser.preview.is_some() {
+            "j/k: scroll, q/Esc: back to listing"
+        } else {
+            "Enter: open, r: reload, q/Esc: back"
+        };
+        let footer = browser.message.as_ref().map_or_else(
+            || Span::styled(hint, theme.styles.muted_style()),
+            |message| Span::styled(message.clone(), theme.styles.error_style()),
+        );
+        f.render_widget(Paragraph::new(footer), rows[1]);
+    }
+
+    /// Render the directory listing
+    /// ディレクトリ一覧を描画
+    fn render_listing(f: &mut Frame, browser: &FileBrowser, area: Rect, theme: &Theme) {
+        let mut items: Vec<ListItem> = browser
+            .rows()
+            .into_iter()
+            .map(|row| ListItem::new(Self::row_line(row, theme)))
+            .collect();
+        if browser.listing.truncated {
+            items.push(ListItem::new(Span::styled(
+                format!("… stopped after {MAX_SCANNED_ENTRIES} entries"),
+                theme.styles.muted_style(),
+            )));
+        }
+
+        let border_style = if browser.preview.is_some() {
+            theme.blocks.normal_border_style
+        } else {
+            theme.blocks.active_border_style
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::RIGHT)
+                    .border_style(border_style),
+            )
+            .highlight_style(theme.styles.selected_style());
+        let mut state = ListState::default().with_selected(Some(browser.cursor));
+        f.render_stateful_widget(list, area, &mut state);
+    }
+
+    /// Format one listing row
+    /// 一覧の1行をフォーマット
+    fn row_line(row: BrowserRow<'_>, theme: &Theme) -> Line<'static> {
+        let entry = match row {
+            BrowserRow::Parent => {
+                return Line::from(Span::styled("▴ ..", theme.styles.muted_style()));
+            }
+            BrowserRow::Entry(entry) => entry,
+        };
+        let (icon, style) = match entry.kind {
+            PathKind::Directory => ("▸ ", theme.styles.primary_style()),
+            PathKind::Symlink => ("↪ ", theme.styles.loading_style()),
+            PathKind::File | PathKind::HardLink | PathKind::Other => {
+                ("  ", theme.styles.normal_style())
+            }
+        };
+        let mut spans = vec![Span::raw(icon), Span::styled(entry.name.clone(), style)];
+        if entry.kind.is_directory() {
+            spans.push(Span::styled("/", style));
+        } else if let Some(target) = &entry.link_target {
+            spans.push(Span::styled(
+                format!(" → {target}"),
+                theme.styles.muted_style(),
+            ));
+        } else {
+            spans.push(Span::styled(
+                format!("  {}", format_size(entry.size)),
+                theme.styles.muted_style(),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Render the metadata and preview pane
+    /// メタデータとプレビューのペインを描画
+    fn render_details(f: &mut Frame, browser: &FileBrowser, area: Rect, theme: &Theme) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(METADATA_HEIGHT), Constraint::Min(1)])
+            .split(area);
+
+        let entry = browser
+            .preview
+            .as_ref()
+            .map(|preview| &preview.entry)
+            .or_else(|| browser.highlighted_entry());
+        let metadata = entry.map_or_else(Vec::new, |entry| Self::metadata_lines(entry, theme));
+        f.render_widget(
+            Paragraph::new(metadata).block(Block::default().borders(Borders::BOTTOM)),
+            chunks[0],
+        );
+
+        let preview = browser.preview.as_ref().map_or_else(
+            || {
+                vec![Line::from(Span::styled(
+                    "Enter on a file shows its content",
+                    theme.styles.muted_style(),
+                ))]
+            },
+            |preview| Self::preview_lines(preview, browser.scroll, chunks[1].height, theme),
+        );
+        f.render_widget(Paragraph::new(preview), chunks[1]);
+    }
+
+    /// Format the metadata of an entry
+    /// エントリのメタデータをフォーマット
+    fn metadata_lines(entry: &FileEntry, theme: &Theme) -> Vec<Line<'static>> {
+        let field = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{label:<10}"), theme.styles.muted_style()),
+                Span::styled(value, theme.styles.normal_style()),
+            ])
+        };
+        let modified = i64::try_from(entry.mtime)
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .map_or_else(
+                || "-".to_string(),
+                |time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            );
+
+        let mut lines = vec![
+            field("Path", entry.path.clone()),
+            field(
+                "Mode",
+                format!("{} ({:o})", entry.permissions(), entry.mode),
+            ),
+            field("Size", format_size(entry.size)),
+            field("Modified", modified),
+        ];
+        if let Some(target) = &entry.link_target {
+            lines.push(field("Link", target.clone()));
+        }
+        lines
+    }
+
+    /// Lines of the preview visible from `scroll`
+    /// `scroll`から表示されるプレビューの行
+    fn preview_lines(
+        preview: &FilePreview,
+        scroll: usize,
+        height: u16,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        let Some(text) = &preview.text else {
+            return vec![Line::from(Span::styled(
+                "Binary file, not shown",
+                theme.styles.muted_style(),
+            ))];
+        };
+
+        let mut lines: Vec<Line> = text
+            .lines()
+            .skip(scroll)
+            .take(usize::from(height))
+            .map(|line| Line::from(line.replace('\t', "    ")))
+            .collect();
+        if preview.truncated {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "… showing the first {} of {}",
+                    format_size(PREVIEW_LIMIT as u64),
+                    format_size(preview.entry.size)
+                ),
+                theme.styles.muted_style(),
+            )));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::file_browser_service::{BrowserContent, DirectoryListing};
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(100, 14);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| FileBrowserWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn entry(path: &str, kind: PathKind, size: u64) -> FileEntry {
+        FileEntry {
+            name: path.rsplit('/').next().unwrap().to_string(),
+            path: path.to_string(),
+            kind,
+            size,
+            mode: 0o644,
+            mtime: 1_700_000_000,
+            link_target: None,
+        }
+    }
+
+    fn create_app() -> App {
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut browser = FileBrowser::new(&container);
+        browser.show(Ok(BrowserContent::Directory(DirectoryListing {
+            path: "/etc".to_string(),
+            entries: vec![
+                entry("/etc/nginx", PathKind::Directory, 0),
+                entry("/etc/hosts", PathKind::File, 1_500),
+            ],
+            truncated: true,
+        })));
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.file_browser = Some(browser);
+        app
+    }
+
+    #[test]
+    fn test_render_listing_and_metadata() {
+        // Test the listing rows and the metadata of the highlighted entry
+        // 一覧の行と選択中のエントリのメタデータをテスト
+        let mut app = create_app();
+        app.file_browser.as_mut().unwrap().cursor = 2;
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Files: web:/etc"));
+        assert!(screen.contains("▴ .."));
+        assert!(screen.contains("▸ nginx/"));
+        assert!(screen.contains("hosts  1.5 kB"));
+        assert!(screen.contains("… stopped after 10000 entries"));
+        assert!(screen.contains("Mode      -rw-r--r-- (644)"));
+        assert!(screen.contains("Modified  2023-11-14 22:13:20 UTC"));
+    }
+
+    #[test]
+    fn test_render_scrolled_preview() {
+        // Test that the preview starts at the scroll offset and notes truncation
+        // プレビューがスクロール位置から始まり、切り詰めを示すことをテスト
+        let mut app = create_app();
+        let browser = app.file_browser.as_mut().unwrap();
+        browser.show(Ok(BrowserContent::File(FilePreview {
+            entry: entry("/etc/hosts", PathKind::File, 300_000),
+            text: Some("127.0.0.1 localhost\n::1 localhost\n10.0.0.2 web".to_string()),
+            truncated: true,
+        })));
+        browser.scroll = 1;
+
+        let screen = render_to_string(&app);
+        assert!(!screen.contains("127.0.0.1"));
+        assert!(screen.contains("::1 localhost"));
+        assert!(screen.contains("… showing the first 262 kB of 300 kB"));
+        assert!(screen.contains("j/k: scroll, q/Esc: back to listing"));
+    }
+}
@@ -0,0 +1,182 @@
+// src/ui/widgets/label_browser.rs
+// Label browser widget listing label values across containers
+// コンテナ全体のラベル値を一覧表示するラベルブラウザウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::Span,
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+};
+
+use crate::ui::{app::App, label_browser::LabelBrowser, styles::Theme};
+
+/// View listing every label as `key = value` with its number of containers
+/// 全てのラベルを`キー = 値`として、コンテナの数付きで一覧表示するビュー
+///
+/// Values the list is filtered by are marked with `✔`, and the key the list
+/// is grouped by with `▾`.
+///
+/// 一覧を絞り込み中の値には`✔`、一覧をグループ化中のキーには`▾`が付きます。
+pub struct LabelBrowserWidget;
+
+impl LabelBrowserWidget {
+    /// Render the view within `area`
+    /// `area`内にビューを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(browser) = &app.label_browser else {
+            return;
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Self::title(browser))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        f.render_widget(block, area);
+
+        if let Some(message) = &browser.message {
+            f.render_widget(
+                Paragraph::new(Span::styled(message.clone(), theme.styles.error_style())),
+                chunks[0],
+            );
+        } else if !browser.loaded {
+            f.render_widget(
+                Paragraph::new(Span::styled("Loading…", theme.styles.loading_style())),
+                chunks[0],
+            );
+        } else if browser.rows.is_empty() {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    "No container has a label",
+                    theme.styles.muted_style(),
+                )),
+                chunks[0],
+            );
+        } else {
+            let mut state = TableState::default().with_selected(Some(browser.cursor));
+            f.render_stateful_widget(Self::table(browser, app, theme), chunks[0], &mut state);
+        }
+
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "j/k: select, Enter: filter by value / clear, g: group by key / ungroup, r: reload, q/Esc: back",
+                theme.styles.muted_style(),
+            )),
+            chunks[1],
+        );
+    }
+
+    /// View title with the number of keys
+    /// キーの数付きのビューのタイトル
+    fn title(browser: &LabelBrowser) -> String {
+        format!("Labels ({} keys)", browser.key_count())
+    }
+
+    /// Build the label table
+    /// ラベル表を構築
+    fn table(browser: &LabelBrowser, app: &App, theme: &Theme) -> Table<'static> {
+        let header =
+            Row::new(["", "KEY", "VALUE", "CONTAINERS"]).style(theme.styles.primary_style());
+        let rows: Vec<Row> = browser
+            .rows
+            .iter()
+            .map(|row| {
+                let filtered = app.query.filter.labels.get(&row.key) == Some(&row.value);
+                let grouped = app.query.group_by_label.as_ref() == Some(&row.key);
+                let marker = match (filtered, grouped) {
+                    (true, _) => "✔",
+                    (false, true) => "▾",
+                    (false, false) => "",
+                };
+                let style = if filtered {
+                    theme.styles.success_style()
+                } else {
+                    theme.styles.normal_style()
+                };
+                Row::new([
+                    marker.to_string(),
+                    row.key.clone(),
+                    row.value.clone(),
+                    row.count.to_string(),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(1),
+                Constraint::Min(16),
+                Constraint::Min(16),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(theme.styles.selected_style())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| LabelBrowserWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn test_render_marks_filter_and_grouping() {
+        // Test that the filtered value and grouped key are marked
+        // 絞り込み中の値とグループ化中のキーに印が付くことをテスト
+        let container = Container::builder()
+            .id("api-1")
+            .name("api")
+            .image("api:latest")
+            .status(ContainerStatus::Running)
+            .label("env", "prod")
+            .label("team", "payments")
+            .build()
+            .unwrap();
+        let mut browser = LabelBrowser::new();
+        browser.show(Ok(vec![container]));
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.label_browser = Some(browser);
+        app.query
+            .filter
+            .labels
+            .insert("env".to_string(), "prod".to_string());
+        app.query.group_by_label = Some("team".to_string());
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Labels (2 keys)"));
+        let filtered = screen.find("✔").unwrap();
+        let grouped = screen.find("▾").unwrap();
+        assert!(filtered < screen.find("prod").unwrap());
+        assert!(grouped < screen.find("payments").unwrap());
+        assert!(filtered < grouped);
+    }
+}
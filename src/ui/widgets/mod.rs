@@ -69,6 +69,164 @@ pub mod status_bar;
 /// Dockerコンテナリスト表示ウィジェットを提供します。
 pub mod container_list;
 
+/// Docker context switcher popup.
+///
+/// This module provides a popup listing the configured Docker endpoints
+/// so the user can reconnect without restarting docka.
+///
+/// Dockerコンテキストスイッチャーポップアップ。
+/// 設定済みのDockerエンドポイントを一覧表示し、
+/// dockaを再起動せずに再接続できるポップアップを提供します。
+pub mod context_switcher;
+
+/// Container action menu popup.
+///
+/// This module provides a popup listing the actions available for the
+/// selected container, with unsupported ones disabled and explained.
+///
+/// コンテナアクションメニューポップアップ。
+/// 選択中のコンテナで利用可能なアクションを一覧表示し、
+/// サポートされないものは理由付きで無効化するポップアップを提供します。
+pub mod action_menu;
+
+/// Signal picker popup.
+///
+/// This module provides a popup choosing the signal sent to the selected
+/// container, such as `SIGHUP` to reload a daemon in place.
+///
+/// シグナルピッカーポップアップ。
+/// 選択中のコンテナに送信するシグナル（デーモンをその場で再読み込みする
+/// `SIGHUP`など）を選ぶポップアップを提供します。
+pub mod signal_picker;
+
+/// Host information and disk usage dashboard.
+///
+/// This module provides the system view combining engine information,
+/// container counts and disk usage with reclaimable space.
+///
+/// ホスト情報とディスク使用量のダッシュボード。
+/// エンジン情報、コンテナ数、回収可能な容量付きのディスク使用量を
+/// 組み合わせたシステムビューを提供します。
+pub mod system_dashboard;
+
+/// Stale container cleanup assistant view.
+///
+/// This module provides the view that lists old stopped containers
+/// matching cleanup criteria and removes the selection in bulk.
+///
+/// 古いコンテナのクリーンアップアシスタントビュー。
+/// クリーンアップ基準に一致する古い停止中のコンテナを一覧表示し、
+/// 選択したものを一括削除するビューを提供します。
+pub mod cleanup_assistant;
+
+/// Guided prune dialog popup.
+///
+/// This module provides the popup that previews what a cleanup would
+/// remove, asks for confirmation and reports the outcome per item.
+///
+/// ガイド付き削除ダイアログポップアップ。
+/// クリーンアップで削除されるものをプレビューし、確認を求め、
+/// 項目ごとの結果を報告するポップアップを提供します。
+pub mod prune_dialog;
+
+/// Container process (top) pane.
+///
+/// This module provides the pane below the container list that shows
+/// the processes of the selected container.
+///
+/// コンテナプロセス（top）ペイン。
+/// コンテナリストの下に、選択中のコンテナのプロセスを表示する
+/// ペインを提供します。
+pub mod process_pane;
+
+/// Container details view.
+///
+/// This module provides the view that shows the inspect details of the
+/// selected container in sections, starting with its health log.
+///
+/// コンテナ詳細ビュー。
+/// 選択中のコンテナのinspect詳細を、ヘルスログをはじめとするセクションに
+/// 分けて表示するビューを提供します。
+pub mod details_view;
+
+/// Filesystem changes (docker diff) view.
+///
+/// This module provides the view that shows the changed paths of the
+/// selected container as a collapsible tree.
+///
+/// ファイルシステム変更（docker diff）ビュー。
+/// 選択中のコンテナの変更パスを折りたたみ可能なツリーとして表示する
+/// ビューを提供します。
+pub mod diff_view;
+
+/// Copy files dialog popup.
+///
+/// This module provides the popup that copies a file or directory between
+/// the selected container and a local path, with progress.
+///
+/// ファイルコピーダイアログポップアップ。
+/// 選択中のコンテナとローカルパスの間でファイルまたはディレクトリを
+/// 進捗付きでコピーするポップアップを提供します。
+pub mod copy_dialog;
+
+/// Resource limits dialog popup.
+///
+/// This module provides the popup that changes the memory, CPU and PIDs
+/// limits and the restart policy of the selected container in place.
+///
+/// リソース制限ダイアログポップアップ。
+/// 選択中のコンテナのメモリ、`CPU`、`PIDs`の制限と再起動ポリシーを
+/// その場で変更するポップアップを提供します。
+pub mod resource_dialog;
+
+/// Rename container dialog popup.
+///
+/// This module provides the popup that types a new name for the selected
+/// container.
+///
+/// コンテナ名変更ダイアログポップアップ。
+/// 選択中のコンテナの新しい名前を入力するポップアップを提供します。
+pub mod rename_dialog;
+
+/// Restart policy picker popup.
+///
+/// This module provides the popup that shows the current restart policy of
+/// the selected container and applies a new one.
+///
+/// 再起動ポリシーピッカーポップアップ。
+/// 選択中のコンテナの現在の再起動ポリシーを表示し、新しいポリシーを
+/// 適用するポップアップを提供します。
+pub mod restart_policy_picker;
+
+/// In-container file browser.
+///
+/// This module provides the read-only view that lists the directories of
+/// the selected container and previews its files.
+///
+/// コンテナ内ファイルブラウザ。
+/// 選択中のコンテナのディレクトリを一覧表示し、ファイルをプレビューする
+/// 読み取り専用ビューを提供します。
+pub mod file_browser;
+
+/// Label browser.
+///
+/// This module provides the view that lists label values and applies one
+/// as a filter or grouping of the container list.
+///
+/// ラベルブラウザ。
+/// ラベル値を一覧表示し、その1つをコンテナリストのフィルタまたは
+/// グループ化として適用するビューを提供します。
+pub mod label_browser;
+
+/// Published ports view.
+///
+/// This module provides the view that shows which container owns each
+/// host port.
+///
+/// 公開ポートビュー。
+/// 各ホストポートをどのコンテナが所有しているかを表示するビューを提供します。
+pub mod ports_view;
+
 // Re-export commonly used types for convenient access
 // 便利なアクセスのためによく使用される型を再エクスポート
 
@@ -79,3 +237,67 @@ pub use status_bar::StatusBar;
 /// Container list widget for displaying Docker containers
 /// Dockerコンテナリスト表示ウィジェット
 pub use container_list::ContainerListWidget;
+
+/// Docker context switcher popup widget
+/// Dockerコンテキストスイッチャーポップアップウィジェット
+pub use context_switcher::ContextSwitcherWidget;
+
+/// Container action menu popup widget
+/// コンテナアクションメニューポップアップウィジェット
+pub use action_menu::ActionMenuWidget;
+
+/// Signal picker popup widget
+/// シグナルピッカーポップアップウィジェット
+pub use signal_picker::SignalPickerWidget;
+
+/// Host information and disk usage dashboard widget
+/// ホスト情報とディスク使用量のダッシュボードウィジェット
+pub use system_dashboard::SystemDashboardWidget;
+
+/// Guided prune dialog popup widget
+/// ガイド付き削除ダイアログポップアップウィジェット
+pub use prune_dialog::PruneDialogWidget;
+
+/// Stale container cleanup assistant widget
+/// 古いコンテナのクリーンアップアシスタントウィジェット
+pub use cleanup_assistant::CleanupAssistantWidget;
+
+/// Container process (top) pane widget
+/// コンテナプロセス（top）ペインウィジェット
+pub use process_pane::ProcessPaneWidget;
+
+/// Filesystem changes (docker diff) view widget
+/// ファイルシステム変更（docker diff）ビューウィジェット
+pub use diff_view::DiffViewWidget;
+
+/// Container details view widget
+/// コンテナ詳細ビューウィジェット
+pub use details_view::DetailsViewWidget;
+
+/// Copy files dialog popup widget
+/// ファイルコピーダイアログポップアップウィジェット
+pub use copy_dialog::CopyDialogWidget;
+
+/// Resource limits dialog popup widget
+/// リソース制限ダイアログポップアップウィジェット
+pub use resource_dialog::ResourceDialogWidget;
+
+/// Rename container dialog popup widget
+/// コンテナ名変更ダイアログポップアップウィジェット
+pub use rename_dialog::RenameDialogWidget;
+
+/// Restart policy picker popup widget
+/// 再起動ポリシーピッカーポップアップウィジェット
+pub use restart_policy_picker::RestartPolicyPickerWidget;
+
+/// In-container file browser widget
+/// コンテナ内ファイルブラウザウィジェット
+pub use file_browser::FileBrowserWidget;
+
+/// Published ports view widget
+/// 公開ポートビューウィジェット
+pub use ports_view::PortsViewWidget;
+
+/// Label browser widget
+/// ラベルブラウザウィジェット
+pub use label_browser::LabelBrowserWidget;
@@ -0,0 +1,309 @@
+// src/ui/widgets/ports_view.rs
+// Ports view widget listing published endpoints
+// 公開エンドポイントを一覧表示するポートビューウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::port_forward_service::PortForward;
+use crate::ui::{
+    app::App,
+    ports_view::{PortRow, PortsView},
+    styles::Theme,
+    widgets::PruneDialogWidget,
+};
+
+/// View listing every container port as `host:port → container port/proto`
+/// 全コンテナのポートを`host:port → コンテナ ポート/プロトコル`として一覧表示するビュー
+///
+/// Published ports are sorted by host port, so the owner of a port can be
+/// found at a glance; ports that are only exposed are listed last with `-`
+/// as the host endpoint. Active port forwards are listed below the ports.
+///
+/// 公開ポートはホストポート順に並ぶため、ポートの所有者がひと目で分かります。
+/// `expose`のみのポートはホストエンドポイントを`-`として最後に表示します。
+/// 実行中のポートフォワードはポートの下に表示します。
+pub struct PortsViewWidget;
+
+impl PortsViewWidget {
+    /// Render the view within `area`
+    /// `area`内にビューを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(view) = &app.ports.view else {
+            return;
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Self::title(view))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(area);
+        let forwards = app.ports.forwards.forwards();
+        let forwards_height = if forwards.is_empty() {
+            0
+        } else {
+            u16::try_from(forwards.len() + 2).unwrap_or(u16::MAX)
+        };
+        let footer = Self::footer(view, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(forwards_height),
+                Constraint::Length(u16::try_from(footer.len()).unwrap_or(u16::MAX)),
+            ])
+            .split(inner);
+        f.render_widget(block, area);
+
+        if let Some(message) = &view.message {
+            f.render_widget(
+                Paragraph::new(Span::styled(message.clone(), theme.styles.error_style())),
+                chunks[0],
+            );
+        } else if !view.loaded {
+            f.render_widget(
+                Paragraph::new(Span::styled("Loading…", theme.styles.loading_style())),
+                chunks[0],
+            );
+        } else if view.rows.is_empty() {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    "No container exposes a port",
+                    theme.styles.muted_style(),
+                )),
+                chunks[0],
+            );
+        } else {
+            let selected = (view.cursor < view.rows.len()).then_some(view.cursor);
+            let mut state = TableState::default().with_selected(selected);
+            f.render_stateful_widget(Self::table(view, theme), chunks[0], &mut state);
+        }
+
+        if !forwards.is_empty() {
+            let mut state = TableState::default().with_selected(view.highlighted_forward());
+            f.render_stateful_widget(Self::forwards_table(forwards, theme), chunks[1], &mut state);
+        }
+        f.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+
+    /// Lines below the tables: forward field or notice, reachability and key hints
+    /// 表の下の行: フォワード入力欄または通知、到達性、キーヒント
+    ///
+    /// Forwards dial the container's address on its network, which only the
+    /// machine running the engine can reach, so the limitation is always shown.
+    /// フォワードはネットワーク上のコンテナのアドレスに接続し、そこへはエンジンを
+    /// 実行しているマシンしか到達できないため、この制限を常に表示します。
+    fn footer(view: &PortsView, theme: &Theme) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        let hint = if let Some(input) = &view.forward_input {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Forward a port of {} as [LOCAL:]PORT[@NETWORK]",
+                    input.container_name
+                ),
+                theme.styles.primary_style(),
+            )));
+            lines.push(PruneDialogWidget::input_line(
+                "Port",
+                &input.input,
+                "",
+                true,
+                theme,
+            ));
+            if let Some(message) = &input.message {
+                lines.push(Line::from(Span::styled(
+                    message.clone(),
+                    theme.styles.error_style(),
+                )));
+            }
+            "Enter: forward, Esc: cancel, Ctrl+U: clear"
+        } else {
+            if let Some(notice) = &view.notice {
+                lines.push(Line::from(Span::styled(
+                    notice.clone(),
+                    theme.styles.loading_style(),
+                )));
+            }
+            "j/k: select, Enter: forward/stop, f: forward any port, r: reload, q/Esc: back"
+        };
+        lines.push(Line::from(Span::styled(
+            "Forwarding needs a local rootful engine on Linux, not a remote, VM or rootless one",
+            theme.styles.muted_style(),
+        )));
+        lines.push(Line::from(Span::styled(hint, theme.styles.muted_style())));
+        lines
+    }
+
+    /// Build the table of active forwards
+    /// 実行中のフォワードの表を構築
+    fn forwards_table(forwards: &[PortForward], theme: &Theme) -> Table<'static> {
+        let rows: Vec<Row> = forwards
+            .iter()
+            .map(|forward| {
+                Row::new([
+                    forward.local_addr.to_string(),
+                    "⇄".to_string(),
+                    forward.label.clone(),
+                    format!("{} ({} open)", forward.target, forward.open_connections()),
+                ])
+                .style(theme.styles.success_style())
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(24),
+                Constraint::Length(1),
+                Constraint::Min(12),
+                Constraint::Length(30),
+            ],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .title("Forwards")
+                .border_style(theme.blocks.normal_border_style)
+                .title_style(theme.styles.primary_style()),
+        )
+        .row_highlight_style(theme.styles.selected_style())
+    }
+
+    /// View title with the number of published ports
+    /// 公開ポート数付きのビューのタイトル
+    fn title(view: &PortsView) -> String {
+        format!("Ports ({} published)", view.published_count())
+    }
+
+    /// Build the port table
+    /// ポート表を構築
+    fn table(view: &PortsView, theme: &Theme) -> Table<'static> {
+        let header =
+            Row::new(["HOST", "", "CONTAINER", "PORT"]).style(theme.styles.primary_style());
+        let rows: Vec<Row> = view
+            .rows
+            .iter()
+            .map(|row| {
+                let style = if row.mapping.is_published() {
+                    theme.styles.normal_style()
+                } else {
+                    theme.styles.muted_style()
+                };
+                Row::new([
+                    row.mapping
+                        .host_endpoint()
+                        .unwrap_or_else(|| "-".to_string()),
+                    "→".to_string(),
+                    Self::container_label(row),
+                    format!("{}/{}", row.mapping.container_port, row.mapping.protocol),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(24),
+                Constraint::Length(1),
+                Constraint::Min(12),
+                Constraint::Length(12),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(theme.styles.selected_style())
+    }
+
+    /// Container name, with its endpoint in the multi-host view
+    /// コンテナ名（複数ホストビューではエンドポイント付き）
+    fn container_label(row: &PortRow) -> String {
+        row.host.as_ref().map_or_else(
+            || row.container_name.clone(),
+            |host| format!("{} @{host}", row.container_name),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::{ContainerStatus, PortMapping, PortProtocol};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| PortsViewWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn test_render_port_owners() {
+        // Test that each port is shown with its host endpoint and owning container
+        // 各ポートがホストエンドポイントと所有コンテナ付きで表示されることをテスト
+        let container = Container::builder()
+            .id("db-1")
+            .name("db")
+            .image("postgres:16")
+            .status(ContainerStatus::Running)
+            .port(PortMapping::new(5432, PortProtocol::Tcp).published(None, 5432))
+            .port(PortMapping::new(9187, PortProtocol::Tcp))
+            .build()
+            .unwrap();
+        let mut view = PortsView::new();
+        view.show(Ok(vec![container]));
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.ports.view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Ports (1 published)"));
+        assert!(screen.contains("0.0.0.0:5432"));
+        assert!(screen.contains("db"));
+        assert!(screen.contains("5432/tcp"));
+        assert!(screen.contains("9187/tcp"));
+        assert!(screen.contains("Forwarding needs a local rootful engine"));
+        assert!(screen.contains("f: forward any port"));
+    }
+
+    #[tokio::test]
+    async fn test_render_active_forwards() {
+        // Test that active forwards are listed below the ports with their target
+        // 実行中のフォワードが転送先付きでポートの下に表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let target = "172.17.0.3:9229".parse().unwrap();
+        let local_addr = app
+            .ports
+            .forwards
+            .start(0, target, "api 9229/tcp")
+            .await
+            .unwrap()
+            .local_addr;
+        let mut view = PortsView::new();
+        view.show(Ok(Vec::new()));
+        view.set_forward_count(1);
+        app.ports.view = Some(view);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Forwards"));
+        assert!(screen.contains(&local_addr.to_string()));
+        assert!(screen.contains("api 9229/tcp"));
+        assert!(screen.contains("172.17.0.3:9229 (0 open)"));
+    }
+}
@@ -0,0 +1,184 @@
+// src/ui/widgets/process_pane.rs
+// Container process (top) pane widget
+// コンテナプロセス（top）ペインウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    text::Span,
+    widgets::{Block, Borders, Paragraph, Row, Table},
+};
+
+use crate::ui::{app::App, process_pane::ProcessPane, styles::Theme};
+
+/// Pane listing the processes of the selected container like `docker top`
+/// `docker top`のように選択中のコンテナのプロセスを一覧表示するペイン
+///
+/// Columns are PID, user, CPU, memory and command, busiest process first.
+/// Usage the engine does not report is shown as `-`.
+///
+/// 列はPID、ユーザー、CPU、メモリ、コマンドで、負荷の高いプロセスから
+/// 表示します。エンジンが報告しない使用率は`-`で表示します。
+pub struct ProcessPaneWidget;
+
+impl ProcessPaneWidget {
+    /// Render the pane within `area`
+    /// `area`内にペインを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(pane) = &app.process_pane else {
+            return;
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Self::title(pane))
+            .border_style(theme.blocks.normal_border_style)
+            .title_style(theme.styles.primary_style());
+
+        if let Some(message) = &pane.message {
+            let paragraph =
+                Paragraph::new(Span::styled(message.clone(), theme.styles.muted_style()))
+                    .block(block);
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        f.render_widget(Self::table(pane, app, theme).block(block), area);
+    }
+
+    /// Pane title naming the container and the process count
+    /// コンテナ名とプロセス数を示すペインのタイトル
+    fn title(pane: &ProcessPane) -> String {
+        if pane.refreshed_at.is_none() {
+            "Processes (loading...)".to_string()
+        } else if pane.message.is_some() {
+            format!("Processes: {}", pane.container_name)
+        } else {
+            format!(
+                "Processes: {} ({})",
+                pane.container_name,
+                pane.processes.len()
+            )
+        }
+    }
+
+    /// Build the process table
+    /// プロセス表を構築
+    ///
+    /// Secrets in command lines are masked.
+    /// コマンドライン内のシークレットはマスクされます。
+    fn table(pane: &ProcessPane, app: &App, theme: &Theme) -> Table<'static> {
+        let percent =
+            |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{v:.1}"));
+        let header = Row::new(["PID", "USER", "%CPU", "%MEM", "COMMAND"])
+            .style(theme.styles.primary_style());
+        let rows: Vec<Row> = pane
+            .processes
+            .iter()
+            .map(|process| {
+                Row::new([
+                    process.pid.to_string(),
+                    process.user.clone(),
+                    percent(process.cpu_percent),
+                    percent(process.memory_percent),
+                    app.secret_mask.mask_text(&process.command),
+                ])
+                .style(theme.styles.normal_style())
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(10),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Min(10),
+            ],
+        )
+        .header(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::{Container, ContainerProcess};
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| ProcessPaneWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_container() -> Container {
+        Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_render_process_table() {
+        // Test that processes are listed with their usage and command
+        // プロセスが使用率とコマンド付きで一覧表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut pane = ProcessPane::new();
+        pane.show(
+            &create_container(),
+            Ok(vec![ContainerProcess {
+                pid: 4242,
+                user: "www-data".to_string(),
+                cpu_percent: Some(12.5),
+                memory_percent: None,
+                command: "nginx: worker process".to_string(),
+            }]),
+            Instant::now(),
+        );
+        app.process_pane = Some(pane);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Processes: web (1)"));
+        assert!(screen.contains("4242"));
+        assert!(screen.contains("www-data"));
+        assert!(screen.contains("12.5"));
+        assert!(screen.contains("nginx: worker process"));
+    }
+
+    #[test]
+    fn test_render_message_instead_of_table() {
+        // Test that the reason is shown when there is no process table
+        // プロセス表がない場合に理由が表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut pane = ProcessPane::new();
+        pane.show_message(
+            Some(&create_container()),
+            "web is not running (Stopped)",
+            Instant::now(),
+        );
+        app.process_pane = Some(pane);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Processes: web"));
+        assert!(screen.contains("web is not running (Stopped)"));
+    }
+}
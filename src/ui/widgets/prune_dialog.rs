@@ -0,0 +1,422 @@
+// src/ui/widgets/prune_dialog.rs
+// Guided prune dialog popup widget
+// ガイド付き削除ダイアログポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::app::prune_service::{PruneCandidate, PruneItemResult, PrunePreview, PruneReport};
+use crate::ui::{
+    app::App,
+    layouts::SimpleLayout,
+    prune_dialog::{PruneDialog, PruneField, PruneStage},
+    styles::Theme,
+    text_input::TextInput,
+};
+use crate::utils::size_formatter::format_size;
+
+/// Popup walking through prune options, preview, confirmation and report
+/// 削除オプション、プレビュー、確認、結果を順に案内するポップアップ
+///
+/// The preview lists every object that would be removed with its size and
+/// the total reclaimable space; objects of unknown size are counted
+/// separately instead of being added as zero.
+///
+/// プレビューは削除される全オブジェクトをサイズと回収可能な合計容量とともに
+/// 一覧表示します。サイズ不明のオブジェクトは0として加算せず別に数えます。
+pub struct PruneDialogWidget;
+
+impl PruneDialogWidget {
+    /// Render the prune dialog centered within `area` if it is open
+    /// 削除ダイアログが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(dialog) = &app.cleanup.prune_dialog else {
+            return;
+        };
+
+        let (title, items, footer) = match &dialog.stage {
+            PruneStage::Options => (
+                "Prune: choose what to remove",
+                Self::option_items(dialog, theme),
+                Self::options_footer(dialog, theme),
+            ),
+            PruneStage::Preview {
+                preview,
+                confirming,
+            } => (
+                "Prune: preview",
+                Self::preview_items(preview, theme),
+                Self::preview_footer(preview, *confirming, theme),
+            ),
+            PruneStage::Report(report) => (
+                "Prune: result",
+                Self::report_items(report, theme),
+                Self::report_footer(report, theme),
+            ),
+        };
+
+        // Borders (2) + rows + footer, capped by the popup helper
+        // ボーダー(2) + 行 + フッター、ポップアップヘルパーで制限
+        let footer_height = u16::try_from(footer.len()).unwrap_or(u16::MAX);
+        let height = u16::try_from(items.len().max(1))
+            .unwrap_or(u16::MAX)
+            .saturating_add(footer_height)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 80, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(popup);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(footer_height)])
+            .split(inner);
+
+        f.render_widget(Clear, popup);
+        f.render_widget(block, popup);
+
+        let list = List::new(items).highlight_style(theme.styles.selected_style());
+        let mut state = ListState::default().with_selected(Some(dialog.cursor));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+        f.render_widget(Paragraph::new(footer), chunks[1]);
+    }
+
+    /// Build the option rows: targets, filters and the preview button
+    /// オプション行を構築: 対象、フィルタ、プレビューボタン
+    fn option_items(dialog: &PruneDialog, theme: &Theme) -> Vec<ListItem<'static>> {
+        PruneField::all()
+            .into_iter()
+            .map(|field| {
+                let line = match field {
+                    PruneField::Target(target) => {
+                        let mark = if dialog.targets.contains(&target) {
+                            "[x]"
+                        } else {
+                            "[ ]"
+                        };
+                        Line::from(format!("{mark} {}", target.label()))
+                    }
+                    PruneField::OlderThan => Self::input_line(
+                        "Older than",
+                        &dialog.older_than,
+                        "any age (e.g. 12h, 7d)",
+                        dialog.editing && dialog.highlighted_field() == Some(field),
+                        theme,
+                    ),
+                    PruneField::Labels => Self::input_line(
+                        "Labels",
+                        &dialog.labels,
+                        "any labels (e.g. env=dev,team=web)",
+                        dialog.editing && dialog.highlighted_field() == Some(field),
+                        theme,
+                    ),
+                    PruneField::Preview => {
+                        Line::from(Span::styled("Preview ▶", theme.styles.primary_style()))
+                    }
+                };
+                ListItem::new(line)
+            })
+            .collect()
+    }
+
+    /// Format a labelled text field, with a cursor while editing
+    /// ラベル付きテキストフィールドをフォーマット（編集中はカーソル付き）
+    pub(crate) fn input_line(
+        label: &str,
+        input: &TextInput,
+        placeholder: &'static str,
+        editing: bool,
+        theme: &Theme,
+    ) -> Line<'static> {
+        let label = Span::raw(format!("{label:<12}"));
+        if editing {
+            let (before, after) = input.split_at_cursor();
+            Line::from(vec![
+                label,
+                Span::raw(before.to_string()),
+                Span::styled("▏", theme.styles.primary_style()),
+                Span::raw(after.to_string()),
+            ])
+        } else if input.is_empty() {
+            Line::from(vec![
+                label,
+                Span::styled(placeholder, theme.styles.muted_style()),
+            ])
+        } else {
+            Line::from(vec![label, Span::raw(input.value().to_string())])
+        }
+    }
+
+    /// Footer of the options step: last error and key hints
+    /// オプション手順のフッター: 最後のエラーとキーヒント
+    fn options_footer(dialog: &PruneDialog, theme: &Theme) -> Vec<Line<'static>> {
+        let hint = if dialog.editing {
+            "Type to edit, Enter/Esc: done, Ctrl+U: clear"
+        } else {
+            "Enter: toggle/edit, Esc: close"
+        };
+        let mut lines = Vec::new();
+        if let Some(message) = &dialog.message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                theme.styles.error_style(),
+            )));
+        }
+        lines.push(Line::from(Span::styled(hint, theme.styles.muted_style())));
+        lines
+    }
+
+    /// Build the preview rows: kind, name and size of every candidate
+    /// プレビュー行を構築: 全候補の種類、名前、サイズ
+    fn preview_items(preview: &PrunePreview, theme: &Theme) -> Vec<ListItem<'static>> {
+        if preview.is_empty() {
+            return vec![ListItem::new(Span::styled(
+                "Nothing matches the selected options",
+                theme.styles.muted_style(),
+            ))];
+        }
+
+        preview
+            .candidates
+            .iter()
+            .map(|candidate| ListItem::new(Self::candidate_line(candidate, theme)))
+            .collect()
+    }
+
+    /// Format a candidate as `KIND  NAME  SIZE`
+    /// 候補を`KIND  NAME  SIZE`形式でフォーマット
+    fn candidate_line(candidate: &PruneCandidate, theme: &Theme) -> Line<'static> {
+        let size = candidate.size.map_or_else(|| "-".to_string(), format_size);
+        Line::from(vec![
+            Span::styled(
+                format!("{:<12}", candidate.kind.label()),
+                theme.styles.muted_style(),
+            ),
+            Span::raw(format!("{:<52} ", truncate(&candidate.name, 52))),
+            Span::raw(format!("{size:>10}")),
+        ])
+    }
+
+    /// Footer of the preview step: totals and the confirmation prompt
+    /// プレビュー手順のフッター: 合計と確認プロンプト
+    fn preview_footer(
+        preview: &PrunePreview,
+        confirming: bool,
+        theme: &Theme,
+    ) -> Vec<Line<'static>> {
+        if preview.is_empty() {
+            return vec![Line::from(Span::styled(
+                "Enter/Esc: back",
+                theme.styles.muted_style(),
+            ))];
+        }
+
+        let unknown = match preview.unknown_size_count() {
+            0 => String::new(),
+            count => format!(" (+{count} of unknown size)"),
+        };
+        let total = format!(
+            "Total reclaimable: {}{unknown}",
+            format_size(preview.total_reclaimable())
+        );
+
+        let prompt = if confirming {
+            Line::from(Span::styled(
+                format!(
+                    "Delete {} objects? Enter: confirm, Esc: cancel",
+                    preview.candidates.len()
+                ),
+                theme.styles.error_style(),
+            ))
+        } else {
+            Line::from(Span::styled(
+                "Enter: remove, Esc: back",
+                theme.styles.muted_style(),
+            ))
+        };
+
+        vec![
+            Line::from(Span::styled(total, theme.styles.primary_style())),
+            prompt,
+        ]
+    }
+
+    /// Build the report rows: outcome of every removal
+    /// 結果行を構築: 各削除の結果
+    fn report_items(report: &PruneReport, theme: &Theme) -> Vec<ListItem<'static>> {
+        report
+            .results
+            .iter()
+            .map(|result| ListItem::new(Self::result_line(result, theme)))
+            .collect()
+    }
+
+    /// Format a removal outcome with its error, if any
+    /// 削除結果をエラー（ある場合）付きでフォーマット
+    fn result_line(result: &PruneItemResult, theme: &Theme) -> Line<'static> {
+        let candidate = &result.candidate;
+        let name = format!(
+            "{:<12}{}",
+            candidate.kind.label(),
+            truncate(&candidate.name, 40)
+        );
+        match &result.error {
+            None => Line::from(vec![
+                Span::styled("✓ ", theme.styles.success_style()),
+                Span::raw(name),
+            ]),
+            Some(error) => Line::from(vec![
+                Span::styled("✗ ", theme.styles.error_style()),
+                Span::raw(name),
+                Span::styled(format!(" {error}"), theme.styles.error_style()),
+            ]),
+        }
+    }
+
+    /// Footer of the report step: removal summary
+    /// 結果手順のフッター: 削除の概要
+    fn report_footer(report: &PruneReport, theme: &Theme) -> Vec<Line<'static>> {
+        vec![
+            Line::from(Span::styled(
+                format!(
+                    "{} removed, {} failed, {} reclaimed",
+                    report.succeeded(),
+                    report.failed(),
+                    format_size(report.reclaimed())
+                ),
+                theme.styles.primary_style(),
+            )),
+            Line::from(Span::styled("Enter/Esc: close", theme.styles.muted_style())),
+        ]
+    }
+}
+
+/// Shorten `text` to `width` characters with a trailing ellipsis
+/// `text`を末尾に省略記号を付けて`width`文字に短縮
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut short: String = text.chars().take(width.saturating_sub(1)).collect();
+        short.push('…');
+        short
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::prune_service::PruneKind;
+    use crate::domain::MockDockerRepository;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(90, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| PruneDialogWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn candidate(kind: PruneKind, name: &str, size: Option<u64>) -> PruneCandidate {
+        PruneCandidate {
+            kind,
+            id: name.to_string(),
+            name: name.to_string(),
+            size,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_render_options() {
+        // Test that the options step lists targets and filter placeholders
+        // オプション手順が対象とフィルタのプレースホルダーを表示することをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        assert!(render_to_string(&app).trim().is_empty());
+
+        app.open_prune_dialog();
+        let screen = render_to_string(&app);
+        assert!(screen.contains("[x] Stopped containers"));
+        assert!(screen.contains("any age"));
+        assert!(screen.contains("Preview ▶"));
+    }
+
+    #[test]
+    fn test_render_preview_totals_and_confirmation() {
+        // Test that the preview shows sizes, totals, unknown sizes and the prompt
+        // プレビューがサイズ、合計、サイズ不明数、プロンプトを表示することをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut dialog = PruneDialog::new();
+        dialog.show_preview(PrunePreview {
+            candidates: vec![
+                candidate(PruneKind::Image, "<none> (0123456789ab)", Some(2048)),
+                candidate(PruneKind::Container, "old-job", None),
+            ],
+        });
+        app.cleanup.prune_dialog = Some(dialog);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("<none> (0123456789ab)"));
+        assert!(screen.contains(&format!(
+            "Total reclaimable: {} (+1 of unknown size)",
+            format_size(2048)
+        )));
+        assert!(screen.contains("Enter: remove"));
+
+        if let Some(PruneStage::Preview { confirming, .. }) = app
+            .cleanup
+            .prune_dialog
+            .as_mut()
+            .map(|dialog| &mut dialog.stage)
+        {
+            *confirming = true;
+        }
+        assert!(render_to_string(&app).contains("Delete 2 objects?"));
+    }
+
+    #[test]
+    fn test_render_report() {
+        // Test that the report shows failures with their error and a summary
+        // 結果表示が失敗をエラー付きで表示し、概要を示すことをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        let mut dialog = PruneDialog::new();
+        dialog.show_report(PruneReport {
+            results: vec![
+                PruneItemResult {
+                    candidate: candidate(PruneKind::Volume, "cache", Some(1024)),
+                    error: None,
+                },
+                PruneItemResult {
+                    candidate: candidate(PruneKind::Network, "backend", Some(0)),
+                    error: Some("network is in use".to_string()),
+                },
+            ],
+        });
+        app.cleanup.prune_dialog = Some(dialog);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("✓ "));
+        assert!(screen.contains("network is in use"));
+        assert!(screen.contains(&format!(
+            "1 removed, 1 failed, {} reclaimed",
+            format_size(1024)
+        )));
+    }
+}
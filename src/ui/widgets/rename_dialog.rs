@@ -0,0 +1,108 @@
+// src/ui/widgets/rename_dialog.rs
+// Rename container dialog popup widget
+// コンテナ名変更ダイアログポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::ui::{app::App, layouts::SimpleLayout, styles::Theme, widgets::PruneDialogWidget};
+
+/// Popup typing a new name for the selected container
+/// 選択中のコンテナの新しい名前を入力するポップアップ
+pub struct RenameDialogWidget;
+
+impl RenameDialogWidget {
+    /// Render the rename dialog centered within `area` if it is open
+    /// 名前変更ダイアログが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(dialog) = &app.rename_dialog else {
+            return;
+        };
+
+        let mut lines = vec![PruneDialogWidget::input_line(
+            "New name",
+            &dialog.input,
+            "",
+            true,
+            theme,
+        )];
+        if let Some(message) = &dialog.message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                theme.styles.error_style(),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            "Enter: rename, Esc: cancel, Ctrl+U: clear",
+            theme.styles.muted_style(),
+        )));
+
+        // Borders (2) + lines
+        // ボーダー(2) + 行
+        let height = u16::try_from(lines.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 60, height);
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Rename: {}", dialog.current_name))
+                .border_style(theme.blocks.active_border_style)
+                .title_style(theme.styles.primary_style()),
+        );
+
+        f.render_widget(Clear, popup);
+        f.render_widget(paragraph, popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_render_name_and_error() {
+        // Test that the current name, the input and the last error are shown
+        // 現在の名前、入力、最後のエラーが表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.containers = vec![
+            Container::builder()
+                .id("web-1")
+                .name("web")
+                .image("nginx:latest")
+                .status(ContainerStatus::Running)
+                .build()
+                .unwrap(),
+        ];
+        app.open_rename_dialog();
+        app.rename_dialog.as_mut().unwrap().message =
+            Some("Container name 'db' is already in use".to_string());
+
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| RenameDialogWidget::render(f, &app, f.area(), &Theme::dark()))
+            .unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+
+        assert!(screen.contains("Rename: web"));
+        assert!(screen.contains("New name    web▏"));
+        assert!(screen.contains("already in use"));
+    }
+}
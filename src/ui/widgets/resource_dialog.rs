@@ -0,0 +1,229 @@
+// src/ui/widgets/resource_dialog.rs
+// Resource limits dialog popup widget
+// リソース制限ダイアログポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::value_objects::RestartPolicy;
+use crate::domain::value_objects::resource_limits::DEFAULT_CPU_PERIOD;
+use crate::ui::{
+    app::App,
+    layouts::SimpleLayout,
+    resource_dialog::{ResourceDialog, ResourceField},
+    styles::Theme,
+    widgets::PruneDialogWidget,
+};
+
+/// Popup editing the resource limits and restart policy of the selected container
+/// 選択中のコンテナのリソース制限と再起動ポリシーを編集するポップアップ
+///
+/// Each row is prefilled with the current value; empty rows mean no limit.
+/// 各行は現在の値で事前入力され、空の行は制限なしを意味します。
+pub struct ResourceDialogWidget;
+
+impl ResourceDialogWidget {
+    /// Render the resource dialog centered within `area` if it is open
+    /// リソースダイアログが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(dialog) = &app.resource_dialog else {
+            return;
+        };
+
+        let items = Self::field_items(dialog, theme);
+        let footer = Self::footer(dialog, theme);
+
+        // Borders (2) + rows + footer, capped by the popup helper
+        // ボーダー(2) + 行 + フッター、ポップアップヘルパーで制限
+        let footer_height = u16::try_from(footer.len()).unwrap_or(u16::MAX);
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(footer_height)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 70, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Resources: {}", dialog.container_name))
+            .border_style(theme.blocks.active_border_style)
+            .title_style(theme.styles.primary_style());
+        let inner = block.inner(popup);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(footer_height)])
+            .split(inner);
+
+        f.render_widget(Clear, popup);
+        f.render_widget(block, popup);
+
+        let list = List::new(items).highlight_style(theme.styles.selected_style());
+        let mut state = ListState::default().with_selected(Some(dialog.cursor));
+        f.render_stateful_widget(list, chunks[0], &mut state);
+        f.render_widget(Paragraph::new(footer), chunks[1]);
+    }
+
+    /// Build one row per field
+    /// フィールドごとに1行を構築
+    fn field_items(dialog: &ResourceDialog, theme: &Theme) -> Vec<ListItem<'static>> {
+        let editing = |field| dialog.editing && dialog.highlighted_field() == Some(field);
+        let cpu_hint = format!(
+            "µs per {}µs period, e.g. 50000 = 0.5 CPU",
+            dialog.current.cpu_period.unwrap_or(DEFAULT_CPU_PERIOD)
+        );
+        ResourceField::ALL
+            .into_iter()
+            .map(|field| {
+                let line = match field {
+                    ResourceField::Memory => PruneDialogWidget::input_line(
+                        "Memory",
+                        &dialog.memory,
+                        "unlimited (e.g. 512m, 2g)",
+                        editing(field),
+                        theme,
+                    ),
+                    ResourceField::MemoryReservation => PruneDialogWidget::input_line(
+                        "Reservation",
+                        &dialog.memory_reservation,
+                        "none (soft limit, e.g. 256m)",
+                        editing(field),
+                        theme,
+                    ),
+                    ResourceField::CpuShares => PruneDialogWidget::input_line(
+                        "CPU shares",
+                        &dialog.cpu_shares,
+                        "default (1024)",
+                        editing(field),
+                        theme,
+                    ),
+                    ResourceField::CpuQuota => {
+                        let mut line = PruneDialogWidget::input_line(
+                            "CPU quota",
+                            &dialog.cpu_quota,
+                            "unlimited",
+                            editing(field),
+                            theme,
+                        );
+                        line.push_span(Span::styled(
+                            format!("  ({cpu_hint})"),
+                            theme.styles.muted_style(),
+                        ));
+                        line
+                    }
+                    ResourceField::PidsLimit => PruneDialogWidget::input_line(
+                        "PIDs limit",
+                        &dialog.pids_limit,
+                        "unlimited",
+                        editing(field),
+                        theme,
+                    ),
+                    ResourceField::RestartPolicy => Line::from(vec![
+                        Span::raw(format!("{:<12}", "Restart")),
+                        Span::styled(dialog.restart_policy.name(), theme.styles.primary_style()),
+                    ]),
+                    ResourceField::MaxRetries => {
+                        if matches!(dialog.restart_policy, RestartPolicy::OnFailure { .. }) {
+                            PruneDialogWidget::input_line(
+                                "Max retries",
+                                &dialog.max_retries,
+                                "0 (retry forever)",
+                                editing(field),
+                                theme,
+                            )
+                        } else {
+                            Line::from(Span::styled(
+                                format!("{:<12}only for on-failure", "Max retries"),
+                                theme.styles.muted_style(),
+                            ))
+                        }
+                    }
+                    ResourceField::Apply => {
+                        Line::from(Span::styled("Apply ▶", theme.styles.primary_style()))
+                    }
+                };
+                ListItem::new(line)
+            })
+            .collect()
+    }
+
+    /// Footer: last error and key hints
+    /// フッター: 最後のエラーとキーヒント
+    fn footer(dialog: &ResourceDialog, theme: &Theme) -> Vec<Line<'static>> {
+        let hint = if dialog.editing {
+            "Type to edit, Enter/Esc: done, Ctrl+U: clear"
+        } else {
+            "Enter: toggle/edit, Esc: close"
+        };
+        let mut lines = Vec::new();
+        if let Some(message) = &dialog.message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                theme.styles.error_style(),
+            )));
+        }
+        lines.push(Line::from(Span::styled(hint, theme.styles.muted_style())));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::{ContainerStatus, ResourceLimits};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(90, 16);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| ResourceDialogWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn test_render_prefilled_fields() {
+        // Test that current values, placeholders and errors are shown
+        // 現在の値、プレースホルダー、エラーが表示されることをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut dialog = ResourceDialog::new(
+            &container,
+            ResourceLimits {
+                memory: Some(512 << 20),
+                restart_policy: RestartPolicy::OnFailure { max_retries: 5 },
+                ..ResourceLimits::default()
+            },
+        );
+        dialog.message = Some("Memory limit must be at least 6m".to_string());
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.resource_dialog = Some(dialog);
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Resources: web"));
+        assert!(screen.contains("Memory      512m"));
+        assert!(screen.contains("PIDs limit  unlimited"));
+        assert!(screen.contains("(µs per 100000µs period"));
+        assert!(screen.contains("Restart     on-failure"));
+        assert!(screen.contains("Max retries 5"));
+        assert!(screen.contains("Memory limit must be at least 6m"));
+    }
+}
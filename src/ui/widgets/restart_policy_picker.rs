@@ -0,0 +1,136 @@
+// src/ui/widgets/restart_policy_picker.rs
+// Restart policy picker popup widget
+// 再起動ポリシーピッカーポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::domain::value_objects::RestartPolicy;
+use crate::ui::{app::App, layouts::SimpleLayout, styles::Theme};
+
+/// Popup choosing the restart policy of the selected container
+/// 選択中のコンテナの再起動ポリシーを選ぶポップアップ
+///
+/// The current policy is marked; `Enter` applies the highlighted one.
+/// 現在のポリシーには印が付き、`Enter`で選択中のポリシーを適用します。
+pub struct RestartPolicyPickerWidget;
+
+impl RestartPolicyPickerWidget {
+    /// Render the restart policy picker centered within `area` if it is open
+    /// 再起動ポリシーピッカーが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(picker) = &app.restart_policy_picker else {
+            return;
+        };
+
+        let current = picker.current.restart_policy;
+        let items: Vec<ListItem> = picker
+            .options()
+            .into_iter()
+            .map(|policy| Self::format_policy_item(policy, policy == current, theme))
+            .collect();
+
+        // Borders (2) + one row per policy
+        // ボーダー(2) + ポリシーごとに1行
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 60, height);
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Restart policy: {} (Enter: apply, Esc: cancel)",
+                        picker.container_name
+                    ))
+                    .border_style(theme.blocks.active_border_style)
+                    .title_style(theme.styles.primary_style()),
+            )
+            .highlight_style(theme.styles.selected_style());
+
+        let mut state = ListState::default().with_selected(Some(picker.index));
+
+        f.render_widget(Clear, popup);
+        f.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Format a single policy entry
+    /// 単一のポリシー項目をフォーマット
+    fn format_policy_item(
+        policy: RestartPolicy,
+        current: bool,
+        theme: &Theme,
+    ) -> ListItem<'static> {
+        let description = match policy {
+            RestartPolicy::No => "never restart",
+            RestartPolicy::Always => "always, also after a daemon restart",
+            RestartPolicy::UnlessStopped => "unless stopped by the user",
+            RestartPolicy::OnFailure { .. } => "after a non-zero exit",
+        };
+        let marker = if current { "● " } else { "  " };
+
+        ListItem::new(Line::from(vec![
+            Span::styled(marker, theme.styles.primary_style()),
+            Span::styled(
+                format!("{:<16}", policy.to_string()),
+                theme.styles.normal_style(),
+            ),
+            Span::styled(description, theme.styles.muted_style()),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::{ContainerStatus, ResourceLimits};
+    use crate::ui::restart_policy_picker::RestartPolicyPicker;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_render_marks_current_policy() {
+        // Test that every policy is listed and the current one is marked
+        // 全ポリシーが表示され、現在のポリシーに印が付くことをテスト
+        let container = Container::builder()
+            .id("web-1")
+            .name("web")
+            .image("nginx:latest")
+            .status(ContainerStatus::Running)
+            .build()
+            .unwrap();
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.restart_policy_picker = Some(RestartPolicyPicker::new(
+            &container,
+            ResourceLimits {
+                restart_policy: RestartPolicy::OnFailure { max_retries: 3 },
+                ..ResourceLimits::default()
+            },
+        ));
+
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| RestartPolicyPickerWidget::render(f, &app, f.area(), &Theme::dark()))
+            .unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+
+        assert!(screen.contains("Restart policy: web"));
+        assert!(screen.contains("  unless-stopped  unless stopped by the user"));
+        assert!(screen.contains("● on-failure:3    after a non-zero exit"));
+    }
+}
@@ -0,0 +1,147 @@
+// src/ui/widgets/signal_picker.rs
+// Signal picker popup widget
+// シグナルピッカーポップアップウィジェット
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::domain::value_objects::Signal;
+use crate::ui::{app::App, layouts::SimpleLayout, styles::Theme};
+
+/// Popup choosing the signal sent to the selected container
+/// 選択中のコンテナに送信するシグナルを選ぶポップアップ
+///
+/// Signals that terminate an unprepared process are shown in the error
+/// colour; `j`/`k` move the highlight, `Enter` sends and `Esc` cancels.
+///
+/// 準備のないプロセスを終了させるシグナルはエラー色で表示されます。
+/// `j`/`k`で選択を移動し、`Enter`で送信、`Esc`でキャンセルします。
+pub struct SignalPickerWidget;
+
+impl SignalPickerWidget {
+    /// Render the signal picker centered within `area` if it is open
+    /// シグナルピッカーが開いている場合、`area`の中央に描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(highlighted) = app.signal_picker_index else {
+            return;
+        };
+        let Some(container) = app.selected_container() else {
+            return;
+        };
+
+        let items: Vec<ListItem> = Signal::ALL
+            .iter()
+            .map(|signal| Self::format_signal_item(*signal, theme))
+            .collect();
+
+        // Borders (2) + one row per signal
+        // ボーダー(2) + シグナルごとに1行
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup = SimpleLayout::centered_popup(area, 60, height);
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Signal: {} (Enter: send, Esc: cancel)",
+                        container.display_name()
+                    ))
+                    .border_style(theme.blocks.active_border_style)
+                    .title_style(theme.styles.primary_style()),
+            )
+            .highlight_style(theme.styles.selected_style());
+
+        let mut state = ListState::default().with_selected(Some(highlighted));
+
+        f.render_widget(Clear, popup);
+        f.render_stateful_widget(list, popup, &mut state);
+    }
+
+    /// Format a single signal entry
+    /// 単一のシグナル項目をフォーマット
+    fn format_signal_item(signal: Signal, theme: &Theme) -> ListItem<'static> {
+        let name_style = if signal.is_terminating() {
+            theme.styles.error_style()
+        } else {
+            theme.styles.normal_style()
+        };
+
+        ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<9}", signal.name()), name_style),
+            Span::styled(
+                format!("{:>3}  ", signal.number()),
+                theme.styles.muted_style(),
+            ),
+            Span::styled(signal.description(), theme.styles.muted_style()),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::Container;
+    use crate::domain::value_objects::ContainerStatus;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 14);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| SignalPickerWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_app_with_running_container() -> App {
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.containers = vec![
+            Container::builder()
+                .id("abc123")
+                .name("web")
+                .image("nginx:latest")
+                .status(ContainerStatus::Running)
+                .build()
+                .unwrap(),
+        ];
+        app
+    }
+
+    #[test]
+    fn test_render_closed_picker_draws_nothing() {
+        // Test that a closed picker leaves the screen untouched
+        // 閉じたピッカーが画面に何も描画しないことをテスト
+        let app = create_app_with_running_container();
+        assert!(render_to_string(&app).trim().is_empty());
+    }
+
+    #[test]
+    fn test_render_picker_lists_signals() {
+        // Test that every signal is listed with its number and description
+        // 全シグナルが番号と説明付きで表示されることをテスト
+        let mut app = create_app_with_running_container();
+        app.open_signal_picker();
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("Signal: web"));
+        assert!(screen.contains("SIGHUP     1  reload configuration"));
+        assert!(screen.contains("SIGKILL    9  terminate immediately"));
+        assert!(screen.contains("SIGWINCH"));
+    }
+}
@@ -93,7 +93,26 @@ impl StatusBar {
                     0
                 };
 
-                let content = Line::from(vec![
+                let engine_label = app
+                    .detected_engine
+                    .as_ref()
+                    .map(|engine| format!("[{engine}] "))
+                    .unwrap_or_default();
+                let api_label = app
+                    .capabilities
+                    .api_version
+                    .map(|api_version| format!("API {api_version} "))
+                    .unwrap_or_default();
+                let endpoint_label = format!("🐳 {} {engine_label}{api_label}", app.endpoint_name);
+
+                let mut spans = vec![
+                    Span::styled(
+                        endpoint_label,
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("| ", Style::default().fg(Color::Gray)),
                     Span::styled(
                         "📦 Containers: ",
                         Style::default()
@@ -113,15 +132,304 @@ impl StatusBar {
                             .fg(Color::Cyan)
                             .add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled(
-                        " | j/k: navigate, q: quit, r: refresh",
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
+                ];
+
+                spans.extend(Self::container_list_notices(app));
+                spans.push(Span::styled(
+                    " | j/k: navigate, Enter: actions, q: quit, r: refresh, c: context",
+                    Style::default().fg(Color::DarkGray),
+                ));
                 let style = Style::default().fg(Color::Green);
-                (content, style)
+                (Line::from(spans), style)
             }
+
+            ViewState::SystemOverview => Self::build_system_overview_content(app),
+            ViewState::CleanupAssistant => Self::build_cleanup_assistant_content(app),
+            ViewState::FilesystemChanges => Self::build_diff_view_content(app),
+            ViewState::FileBrowser => Self::build_file_browser_content(app),
+            ViewState::ContainerDetails => Self::build_details_view_content(app),
+            ViewState::Ports => Self::build_ports_view_content(app),
+            ViewState::Labels => Self::build_label_browser_content(app),
+        }
+    }
+
+    /// Notices shown in the container list status: stale hosts, the last warning,
+    /// running port forwards, the active filter and grouping
+    /// コンテナリストのステータスに表示する通知: 古いホスト、最後の警告、
+    /// 実行中のポートフォワード、有効なフィルタとグループ化
+    fn container_list_notices(app: &App) -> Vec<Span<'static>> {
+        let notice_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let mut spans = Vec::new();
+
+        // Warn about aggregated hosts that currently fail to answer
+        // 現在応答しない統合ホストを警告
+        if !app.stale_hosts.is_empty() {
+            spans.push(Span::styled(
+                format!(" | ⚠ stale: {}", app.stale_hosts.join(", ")),
+                notice_style,
+            ));
+        }
+
+        // Warn about the last action, e.g. a host port taken by another container
+        // 最後のアクションについて警告（例: 他のコンテナが使用中のホストポート）
+        if let Some(warning) = &app.warning {
+            spans.push(Span::styled(format!(" | ⚠ {warning}"), notice_style));
+        }
+
+        // Remind that port forwards keep running in the background
+        // ポートフォワードがバックグラウンドで実行中であることを通知
+        let forwards = app.ports.forwards.forwards().len();
+        if forwards > 0 {
+            spans.push(Span::styled(
+                format!(" | ⇄ {forwards} forward(s) (o: ports)"),
+                notice_style,
+            ));
+        }
+
+        // Remind that the list is filtered
+        // 一覧が絞り込まれていることを通知
+        if app.query.filter.only_failed_exits {
+            spans.push(Span::styled(
+                " | filter: failed exits (e: all)",
+                notice_style,
+            ));
         }
+        if !app.query.filter.labels.is_empty() {
+            let mut labels: Vec<String> = app
+                .query
+                .filter
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect();
+            labels.sort();
+            spans.push(Span::styled(
+                format!(" | filter: {} (l: labels)", labels.join(", ")),
+                notice_style,
+            ));
+        }
+        if let Some(expression) = &app.query.filter_expression {
+            spans.push(Span::styled(
+                format!(" | filter: {expression} (: edit)"),
+                notice_style,
+            ));
+        }
+        if let Some(key) = &app.query.group_by_label {
+            spans.push(Span::styled(
+                format!(" | grouped by {key} (g: ungroup)"),
+                notice_style,
+            ));
+        }
+        spans
+    }
+
+    /// Build the status line shown with the filesystem changes view
+    /// ファイルシステム変更ビュー表示時のステータス行を構築
+    fn build_diff_view_content(app: &App) -> (Line<'static>, Style) {
+        let container = app
+            .diff_view
+            .as_ref()
+            .map(|view| view.container_name.clone())
+            .unwrap_or_default();
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("📂 Changes: {container}"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | Enter: expand/collapse, r: reload, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the container details view
+    /// コンテナ詳細ビュー表示時のステータス行を構築
+    fn build_details_view_content(app: &App) -> (Line<'static>, Style) {
+        let container = app
+            .details_view
+            .as_ref()
+            .map(|view| view.container_name.clone())
+            .unwrap_or_default();
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("🔍 Details: {container}"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | j/k: scroll, r: reload, u: times, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the ports view
+    /// ポートビュー表示時のステータス行を構築
+    fn build_ports_view_content(app: &App) -> (Line<'static>, Style) {
+        let ports = app.ports.view.as_ref().map_or(0, |view| view.rows.len());
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("🔌 Ports: {ports}"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | j/k: select, Enter: forward/stop, r: reload, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the label browser
+    /// ラベルブラウザ表示時のステータス行を構築
+    fn build_label_browser_content(app: &App) -> (Line<'static>, Style) {
+        let values = app
+            .label_browser
+            .as_ref()
+            .map_or(0, |browser| browser.rows.len());
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("🏷 Labels: {values}"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | j/k: select, Enter: filter, g: group, r: reload, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the file browser
+    /// ファイルブラウザ表示時のステータス行を構築
+    fn build_file_browser_content(app: &App) -> (Line<'static>, Style) {
+        let location = app
+            .file_browser
+            .as_ref()
+            .map(|browser| format!("{}:{}", browser.container_name, browser.listing.path))
+            .unwrap_or_default();
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("📁 Files: {location}"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | Enter: open, r: reload, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the cleanup assistant
+    /// クリーンアップアシスタント表示時のステータス行を構築
+    fn build_cleanup_assistant_content(app: &App) -> (Line<'static>, Style) {
+        let selected = app
+            .cleanup
+            .assistant
+            .as_ref()
+            .map_or(0, |assistant| assistant.selected().len());
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("🧹 Cleanup: {selected} selected"),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | Enter: edit/toggle, r: reload, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
+    }
+
+    /// Build the status line shown with the system dashboard
+    /// システムダッシュボード表示時のステータス行を構築
+    fn build_system_overview_content(app: &App) -> (Line<'static>, Style) {
+        let content = Line::from(vec![
+            Span::styled(
+                format!("🐳 {} ", app.endpoint_name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("| ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "🖥 System overview",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " | r: refresh, p: prune, q/Esc: back",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+        let style = Style::default().fg(Color::Green);
+        (content, style)
     }
 
     /// Get recommended height for status bar
@@ -175,6 +483,34 @@ impl StatusBar {
                     Span::styled(" | q:quit", Style::default().fg(Color::DarkGray)),
                 ])
             }
+            ViewState::SystemOverview => Line::from(vec![
+                Span::styled("System", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::CleanupAssistant => Line::from(vec![
+                Span::styled("Cleanup", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::FilesystemChanges => Line::from(vec![
+                Span::styled("Changes", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::FileBrowser => Line::from(vec![
+                Span::styled("Files", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::ContainerDetails => Line::from(vec![
+                Span::styled("Details", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::Ports => Line::from(vec![
+                Span::styled("Ports", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
+            ViewState::Labels => Line::from(vec![
+                Span::styled("Labels", Style::default().fg(Color::Green)),
+                Span::styled(" | q:back", Style::default().fg(Color::DarkGray)),
+            ]),
         };
 
         let minimal_paragraph = Paragraph::new(content);
@@ -315,6 +651,123 @@ mod tests {
         assert_eq!(style.fg, Some(Color::Green));
     }
 
+    #[test]
+    fn test_build_status_content_shows_endpoint() {
+        // Test that the active Docker endpoint is shown
+        // アクティブなDockerエンドポイントが表示されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.set_available_endpoints(vec!["build-02".to_string()], "build-02");
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("build-02"));
+    }
+
+    #[test]
+    fn test_build_status_content_shows_detected_engine() {
+        // Test that an auto-detected engine is shown next to the endpoint
+        // 自動検出されたエンジンがエンドポイントの横に表示されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.detected_engine = Some("rootless Podman".to_string());
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("default [rootless Podman]"));
+    }
+
+    #[test]
+    fn test_build_status_content_shows_api_version() {
+        // Test that the negotiated API version is shown once known
+        // ネゴシエート済みAPIバージョンが判明後に表示されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.capabilities = crate::domain::value_objects::EngineCapabilities::new(
+            "1.13.1",
+            "linux",
+            crate::domain::value_objects::ApiVersion::new(1, 26),
+        );
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("default API 1.26"));
+    }
+
+    #[test]
+    fn test_build_status_content_shows_stale_hosts() {
+        // Test that unreachable aggregated hosts are reported
+        // 到達不能な統合ホストが報告されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.stale_hosts = vec!["build-02".to_string()];
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("stale: build-02"));
+    }
+
+    #[test]
+    fn test_build_status_content_shows_failed_exits_filter() {
+        // Test that the failed exits filter is reported while active
+        // 失敗した終了のフィルタが有効な間は報告されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.query.filter.only_failed_exits = true;
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("filter: failed exits"));
+    }
+
+    #[test]
+    fn test_build_status_content_shows_label_filter_and_grouping() {
+        // Test that label filters and the grouping key are reported while active
+        // ラベルのフィルタとグループ化のキーが有効な間は報告されることをテスト
+        let mut app = create_test_app();
+        app.view_state = ViewState::ContainerList;
+        app.query
+            .filter
+            .labels
+            .insert("team".to_string(), "payments".to_string());
+        app.query
+            .filter
+            .labels
+            .insert("env".to_string(), "prod".to_string());
+        app.query.group_by_label = Some("owner".to_string());
+        app.query.filter_expression = Some("exit:!=0".to_string());
+
+        let (content, _) = StatusBar::build_status_content(&app);
+        let content_text = content
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        assert!(content_text.contains("filter: env=prod, team=payments"));
+        assert!(content_text.contains("filter: exit:!=0"));
+        assert!(content_text.contains("grouped by owner"));
+    }
+
     #[test]
     fn test_recommended_height() {
         // Test recommended height value
@@ -0,0 +1,236 @@
+// src/ui/widgets/system_dashboard.rs
+// Host information and disk usage dashboard widget
+// ホスト情報とディスク使用量のダッシュボードウィジェット
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Row, Table},
+};
+
+use crate::domain::entities::{DiskUsage, SystemOverview};
+use crate::ui::{app::App, styles::Theme};
+use crate::utils::size_formatter::{format_reclaimable, format_size};
+
+/// Dashboard combining engine information and `docker system df`
+/// エンジン情報と`docker system df`を組み合わせたダッシュボード
+///
+/// The upper block shows the engine, host resources and container counts;
+/// the lower table splits disk usage into images, containers, volumes and
+/// build cache with the space pruning could reclaim.
+///
+/// 上部ブロックはエンジン、ホストリソース、コンテナ数を表示し、下部の表は
+/// ディスク使用量をイメージ・コンテナ・ボリューム・ビルドキャッシュに分け、
+/// 削除で回収できる容量とともに表示します。
+pub struct SystemDashboardWidget;
+
+impl SystemDashboardWidget {
+    /// Render the dashboard within `area`
+    /// `area`内にダッシュボードを描画
+    pub fn render(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+        let Some(overview) = &app.system_overview else {
+            return;
+        };
+
+        let info_lines = Self::info_lines(overview, theme);
+        let info_height = u16::try_from(info_lines.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(info_height), Constraint::Min(3)])
+            .split(area);
+
+        let info = Paragraph::new(info_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("System: {}", app.endpoint_name))
+                .border_style(theme.blocks.active_border_style)
+                .title_style(theme.styles.primary_style()),
+        );
+        f.render_widget(info, chunks[0]);
+
+        let disk_block = Block::default()
+            .borders(Borders::ALL)
+            .title("Disk Usage")
+            .border_style(theme.blocks.normal_border_style)
+            .title_style(theme.styles.primary_style());
+
+        match &overview.disk_usage {
+            Some(usage) => {
+                f.render_widget(Self::disk_table(usage, theme).block(disk_block), chunks[1]);
+            }
+            None => f.render_widget(
+                Paragraph::new(Span::styled(
+                    "Disk usage is not available on this engine",
+                    theme.styles.muted_style(),
+                ))
+                .block(disk_block),
+                chunks[1],
+            ),
+        }
+    }
+
+    /// Build the engine and host information lines
+    /// エンジンとホスト情報の行を構築
+    fn info_lines(overview: &SystemOverview, theme: &Theme) -> Vec<Line<'static>> {
+        let field = |label: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{label:<16}"), theme.styles.muted_style()),
+                Span::styled(value, theme.styles.normal_style()),
+            ])
+        };
+        let containers = &overview.containers;
+
+        vec![
+            field(
+                "Engine",
+                format!("{} (API {})", overview.engine_version, overview.api_version),
+            ),
+            field(
+                "OS / Kernel",
+                format!("{} / {}", overview.os, overview.kernel_version),
+            ),
+            field("Storage driver", overview.storage_driver.clone()),
+            field(
+                "CPU / Memory",
+                format!("{} CPUs / {}", overview.cpus, format_size(overview.memory)),
+            ),
+            field(
+                "Containers",
+                format!(
+                    "{} ({} running, {} paused, {} stopped)",
+                    containers.total(),
+                    containers.running,
+                    containers.paused,
+                    containers.stopped
+                ),
+            ),
+            field("Images", overview.images.to_string()),
+        ]
+    }
+
+    /// Build the disk usage table with a total row
+    /// 合計行付きのディスク使用量表を構築
+    fn disk_table(usage: &DiskUsage, theme: &Theme) -> Table<'static> {
+        let header = Row::new(["TYPE", "TOTAL", "ACTIVE", "SIZE", "RECLAIMABLE"])
+            .style(theme.styles.primary_style());
+
+        let mut rows: Vec<Row> = usage
+            .categories()
+            .iter()
+            .map(|(name, category)| {
+                Row::new([
+                    (*name).to_string(),
+                    category.total_count.to_string(),
+                    category.active_count.to_string(),
+                    format_size(category.size),
+                    format_reclaimable(category.reclaimable, category.size),
+                ])
+                .style(theme.styles.normal_style())
+            })
+            .collect();
+        rows.push(
+            Row::new([
+                "Total".to_string(),
+                String::new(),
+                String::new(),
+                format_size(usage.total_size()),
+                format_reclaimable(usage.total_reclaimable(), usage.total_size()),
+            ])
+            .style(theme.styles.success_style()),
+        );
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(14),
+                Constraint::Length(7),
+                Constraint::Length(7),
+                Constraint::Length(10),
+                Constraint::Min(16),
+            ],
+        )
+        .header(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MockDockerRepository;
+    use crate::domain::entities::{ContainerCounts, DiskUsageCategory};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+
+    fn render_to_string(app: &App) -> String {
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| SystemDashboardWidget::render(f, app, f.area(), &Theme::dark()))
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn create_overview(disk_usage: Option<DiskUsage>) -> SystemOverview {
+        SystemOverview {
+            engine_version: "24.0.7".to_string(),
+            api_version: "1.43".to_string(),
+            os: "Ubuntu 22.04.4 LTS".to_string(),
+            kernel_version: "6.5.0".to_string(),
+            storage_driver: "overlay2".to_string(),
+            cpus: 8,
+            memory: 16_000_000_000,
+            containers: ContainerCounts {
+                running: 3,
+                paused: 0,
+                stopped: 2,
+            },
+            images: 12,
+            disk_usage,
+        }
+    }
+
+    #[test]
+    fn test_render_dashboard_with_disk_usage() {
+        // Test that engine information and disk usage are shown
+        // エンジン情報とディスク使用量が表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.system_overview = Some(create_overview(Some(DiskUsage {
+            images: DiskUsageCategory {
+                total_count: 12,
+                active_count: 4,
+                size: 4_000_000_000,
+                reclaimable: 3_000_000_000,
+            },
+            ..DiskUsage::default()
+        })));
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("24.0.7 (API 1.43)"));
+        assert!(screen.contains("overlay2"));
+        assert!(screen.contains("8 CPUs / 16 GB"));
+        assert!(screen.contains("5 (3 running, 0 paused, 2 stopped)"));
+        assert!(screen.contains("3 GB (75%)"));
+        assert!(screen.contains("Build Cache"));
+    }
+
+    #[test]
+    fn test_render_dashboard_without_disk_usage() {
+        // Test that engines without /system/df get an explanation
+        // /system/dfのないエンジンで説明が表示されることをテスト
+        let mut app = App::new(Arc::new(MockDockerRepository::new()));
+        app.system_overview = Some(create_overview(None));
+
+        let screen = render_to_string(&app);
+        assert!(screen.contains("not available on this engine"));
+    }
+}
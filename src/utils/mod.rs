@@ -0,0 +1,64 @@
+// src/utils/mod.rs
+// Formatting and encoding utilities shared across the application
+// アプリケーション全体で共有するフォーマットとエンコードのユーティリティ
+
+//! Formatting and encoding utilities shared across the application.
+//!
+//! These helpers turn raw values reported by the Docker engine into the
+//! short, human-readable strings shown in the TUI, and encode the archives
+//! exchanged with it.
+//!
+//! アプリケーション全体で共有するフォーマットとエンコードのユーティリティ。
+//! `Docker`エンジンが報告する生の値を、`TUI`に表示する短く
+//! 人間が読める文字列に変換し、エンジンとやり取りするアーカイブをエンコードします。
+
+/// Human-readable byte sizes (`1.5 GB`, `734 MB`).
+///
+/// 人間が読めるバイトサイズ（`1.5 GB`、`734 MB`）。
+pub mod size_formatter;
+
+/// Short durations (`7d`, `1h30m`) used by age filters, the engine's
+/// relative times (`3 days ago`), and uptimes and exit times (`Up 3h`).
+///
+/// 経過時間フィルタで使用する短い期間表記（`7d`、`1h30m`）、
+/// エンジンの相対時間表記（`3 days ago`）、稼働時間と終了時刻（`Up 3h`）。
+pub mod time_formatter;
+
+/// Streaming tar encoding and decoding for copying files to and from containers.
+///
+/// The engine's archive endpoints are read and written as chunk streams, so
+/// the decoder is a push parser fed with each chunk as it arrives and the
+/// encoder produces headers for a bounded upload queue. `tokio-tar` and
+/// `async-tar` expect an `AsyncRead` (an adapter crate would be needed for
+/// the response stream), bring their own `unpack` that does not apply the
+/// destination rules of the copy service, and `tokio-tar` is no longer
+/// maintained. Only what the engine produces is interpreted: ustar headers,
+/// PAX `path`/`linkpath`/`size` records and GNU long names. Other PAX keys
+/// are ignored, special files are skipped and corrupt headers are rejected.
+///
+/// コンテナとのファイルコピー用のストリーミングtarエンコードとデコード。
+///
+/// エンジンのアーカイブエンドポイントはチャンクのストリームとして読み書きするため、
+/// デコーダーは到着したチャンクを順に受け取るプッシュ型パーサーで、エンコーダーは
+/// 上限付きのアップロードキュー用にヘッダーを生成します。`tokio-tar`と`async-tar`は
+/// `AsyncRead`を前提とし（レスポンスのストリームにはアダプタのクレートが必要）、
+/// コピーサービスの宛先ルールを適用しない独自の`unpack`を持ち、`tokio-tar`は
+/// 保守されていません。エンジンが生成するもの（ustarヘッダー、PAXの
+/// `path`/`linkpath`/`size`レコード、GNU長名）のみを解釈します。その他のPAXキーは
+/// 無視し、特殊ファイルはスキップし、破損したヘッダーは拒否します。
+pub mod tar;
+
+/// Masking of secrets in environment variables, commands and log lines.
+///
+/// Secrets are masked where they leave docka: the details view (command and
+/// environment), the process pane, and the log writer of debug builds.
+/// Release builds install no log subscriber, so nothing is logged there.
+/// docka has no export or clipboard path; one added later must mask too.
+///
+/// 環境変数、コマンド、ログ行に含まれるシークレットのマスク。
+///
+/// シークレットはdockaの外に出る箇所でマスクされます：詳細ビュー（コマンドと
+/// 環境変数）、プロセスペイン、デバッグビルドのログライター。リリースビルドは
+/// ログのサブスクライバーを設定しないため、ログは出力されません。dockaには
+/// エクスポートやクリップボードの経路がなく、今後追加する場合もマスクが必要です。
+pub mod secret_mask;
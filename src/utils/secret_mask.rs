@@ -0,0 +1,252 @@
+// src/utils/secret_mask.rs
+// Masking of secrets in environment variables, commands and log lines
+// 環境変数、コマンド、ログ行に含まれるシークレットのマスク
+
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+/// Text shown instead of a secret value
+/// シークレットの値の代わりに表示するテキスト
+pub const MASK: &str = "••••••";
+
+/// Key patterns masked when the configuration does not name any
+/// 設定で指定されない場合にマスクするキーのパターン
+pub const DEFAULT_SECRET_PATTERNS: [&str; 4] = ["*PASSWORD*", "*TOKEN*", "*SECRET*", "*_KEY"];
+
+/// Key patterns whose values are secret, such as `*PASSWORD*`
+/// `*PASSWORD*`のような、値がシークレットであるキーのパターン
+///
+/// Patterns are matched case-insensitively against the whole key, and `*`
+/// matches any run of characters. Command-line flags are matched as keys
+/// too, with dashes read as underscores (`--db-password` as `DB_PASSWORD`).
+///
+/// パターンは大文字小文字を区別せずキー全体と照合され、`*`は任意の文字列に
+/// 一致します。コマンドラインのフラグもダッシュをアンダースコアとして読み
+/// （`--db-password`は`DB_PASSWORD`）、キーとして照合されます。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::secret_mask::SecretMask;
+///
+/// let mask = SecretMask::default();
+/// assert_eq!(mask.mask_env("POSTGRES_PASSWORD=hunter2"), "POSTGRES_PASSWORD=••••••");
+/// assert_eq!(mask.mask_env("PGDATA=/var/lib/postgresql"), "PGDATA=/var/lib/postgresql");
+/// assert_eq!(
+///     mask.mask_text("app --api-key abc123 --port 80"),
+///     "app --api-key •••••• --port 80"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretMask {
+    /// Patterns in upper case
+    /// 大文字のパターン
+    patterns: Vec<String>,
+}
+
+impl Default for SecretMask {
+    fn default() -> Self {
+        Self::new(DEFAULT_SECRET_PATTERNS)
+    }
+}
+
+impl SecretMask {
+    /// Create a mask from key patterns
+    /// キーのパターンからマスクを作成
+    #[must_use]
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|pattern| pattern.as_ref().to_uppercase())
+                .collect(),
+        }
+    }
+
+    /// Key patterns in use
+    /// 使用中のキーのパターン
+    #[must_use]
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Check whether values of `key` are secret
+    /// `key`の値がシークレットかチェック
+    #[must_use]
+    pub fn is_secret(&self, key: &str) -> bool {
+        let key = key
+            .trim_start_matches(['-', '"', '\''])
+            .replace('-', "_")
+            .to_uppercase();
+        !key.is_empty()
+            && self
+                .patterns
+                .iter()
+                .any(|pattern| Self::matches(pattern, &key))
+    }
+
+    /// Mask the value of a `KEY=VALUE` environment entry with a secret key
+    /// シークレットのキーを持つ`KEY=VALUE`形式の環境変数の値をマスク
+    #[must_use]
+    pub fn mask_env(&self, entry: &str) -> String {
+        match entry.split_once('=') {
+            Some((key, _)) if self.is_secret(key) => format!("{key}={MASK}"),
+            _ => entry.to_string(),
+        }
+    }
+
+    /// Mask secrets in free text such as a command or a log line
+    /// コマンドやログ行のような自由なテキスト内のシークレットをマスク
+    ///
+    /// Masks `KEY=VALUE` words with a secret key and the word following a
+    /// secret flag (`--password hunter2`). Whitespace is kept as is.
+    /// シークレットのキーを持つ`KEY=VALUE`形式の単語と、シークレットのフラグに
+    /// 続く単語（`--password hunter2`）をマスクします。空白はそのまま保持します。
+    #[must_use]
+    pub fn mask_text(&self, text: &str) -> String {
+        let mut masked = String::with_capacity(text.len());
+        let mut value_follows = false;
+        for piece in text.split_inclusive(char::is_whitespace) {
+            let word = piece.trim_end_matches(char::is_whitespace);
+            let spacing = &piece[word.len()..];
+            if word.is_empty() {
+                masked.push_str(piece);
+                continue;
+            }
+
+            if value_follows {
+                masked.push_str(MASK);
+                value_follows = false;
+            } else if word.contains('=') {
+                masked.push_str(&self.mask_env(word));
+            } else {
+                value_follows = word.starts_with('-') && self.is_secret(word);
+                masked.push_str(word);
+            }
+            masked.push_str(spacing);
+        }
+        masked
+    }
+
+    /// Match an upper-case key against a pattern where `*` is any run of characters
+    /// `*`が任意の文字列に一致するパターンと大文字のキーを照合
+    fn matches(pattern: &str, key: &str) -> bool {
+        let mut parts = pattern.split('*');
+        let first = parts.next().unwrap_or_default();
+        let Some(mut rest) = key.strip_prefix(first) else {
+            return false;
+        };
+        let parts: Vec<&str> = parts.collect();
+        let Some((last, middle)) = parts.split_last() else {
+            // No `*`: the whole key must equal the pattern
+            // `*`なし: キー全体がパターンと等しい必要がある
+            return rest.is_empty();
+        };
+        for part in middle {
+            match rest.find(part) {
+                Some(index) => rest = &rest[index + part.len()..],
+                None => return false,
+            }
+        }
+        rest.ends_with(last)
+    }
+}
+
+/// Writer masking secrets in every line written through it
+/// 書き込まれる全ての行のシークレットをマスクするライター
+///
+/// Used for docka's own log output, which receives one formatted event per
+/// write.
+/// 1回の書き込みで1つの整形済みイベントを受け取る、docka自身のログ出力に使用します。
+#[derive(Debug)]
+pub struct MaskingWriter<W> {
+    /// Patterns to mask
+    /// マスクするパターン
+    mask: SecretMask,
+
+    /// Destination of the masked text
+    /// マスクしたテキストの出力先
+    inner: W,
+}
+
+impl<W: Write> MaskingWriter<W> {
+    /// Wrap a writer
+    /// ライターをラップ
+    #[must_use]
+    pub const fn new(mask: SecretMask, inner: W) -> Self {
+        Self { mask, inner }
+    }
+}
+
+impl<W: Write> Write for MaskingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let text = String::from_utf8_lossy(buf);
+        self.inner
+            .write_all(self.mask.mask_text(&text).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_patterns_match_secret_keys() {
+        // Test the default patterns against common variable names and flags
+        // 一般的な変数名とフラグに対してデフォルトのパターンをテスト
+        let mask = SecretMask::default();
+        assert!(mask.is_secret("POSTGRES_PASSWORD"));
+        assert!(mask.is_secret("github_token"));
+        assert!(mask.is_secret("CLIENT_SECRET_FILE"));
+        assert!(mask.is_secret("AWS_ACCESS_KEY"));
+        assert!(mask.is_secret("--api-key"));
+        assert!(!mask.is_secret("KEYCLOAK_URL"));
+        assert!(!mask.is_secret("PATH"));
+        assert!(!mask.is_secret("--"));
+
+        let custom = SecretMask::new(["DSN"]);
+        assert!(custom.is_secret("dsn"));
+        assert!(!custom.is_secret("SENTRY_DSN"));
+    }
+
+    #[test]
+    fn test_mask_text_keeps_layout() {
+        // Test that only secret values are replaced and spacing is preserved
+        // シークレットの値のみが置き換えられ、空白が保持されることをテスト
+        let mask = SecretMask::default();
+        assert_eq!(
+            mask.mask_text("env DB_PASSWORD=hunter2  HOST=db\nrun --token=abc --secret xyz -v"),
+            "env DB_PASSWORD=••••••  HOST=db\nrun --token=•••••• --secret •••••• -v"
+        );
+        assert_eq!(
+            mask.mask_text("nginx -g daemon off;"),
+            "nginx -g daemon off;"
+        );
+    }
+
+    #[test]
+    fn test_masking_writer() {
+        // Test that text written through the writer is masked
+        // ライター経由で書き込まれたテキストがマスクされることをテスト
+        let mut writer = MaskingWriter::new(SecretMask::default(), Vec::new());
+        writer.write_all(b"starting with API_KEY=abc\n").unwrap();
+        assert_eq!(
+            String::from_utf8(writer.inner).unwrap(),
+            format!("starting with API_KEY={MASK}\n")
+        );
+
+        let config: SecretMask = serde_json::from_str(r#"["*PASS*"]"#).unwrap();
+        assert_eq!(config.patterns(), ["*PASS*"]);
+    }
+}
@@ -0,0 +1,264 @@
+// src/utils/size_formatter.rs
+// Human-readable byte size formatting
+// 人間が読めるバイトサイズのフォーマット
+
+use crate::error::{DockaError, DockaResult};
+
+/// Decimal units used by the Docker CLI (`docker system df`)
+/// `Docker` `CLI`（`docker system df`）が使用する10進単位
+const UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Format a byte count the way the Docker CLI does (decimal units)
+/// `Docker` `CLI`と同じ形式（10進単位）でバイト数をフォーマット
+///
+/// Values below 1 kB are printed as whole bytes; larger values keep up to
+/// three significant digits.
+///
+/// 1 kB未満の値はバイト単位の整数で表示し、それ以上の値は最大3桁の有効数字で表示します。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::size_formatter::format_size;
+///
+/// assert_eq!(format_size(0), "0 B");
+/// assert_eq!(format_size(1_500), "1.5 kB");
+/// assert_eq!(format_size(734_003_200), "734 MB");
+/// assert_eq!(format_size(12_345_678_901), "12.3 GB");
+/// ```
+#[must_use]
+pub fn format_size(bytes: u64) -> String {
+    if bytes < 1_000 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)] // Display only / 表示専用
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1_000.0 && unit < UNITS.len() - 1 {
+        value /= 1_000.0;
+        unit += 1;
+    }
+
+    let format_value = |value: f64| {
+        if value >= 100.0 {
+            format!("{value:.0}")
+        } else if value >= 10.0 {
+            format!("{value:.1}")
+        } else {
+            format!("{value:.2}")
+        }
+    };
+
+    // 999.6 kB rounds to "1000"; show it as 1 MB instead
+    // 999.6 kBは"1000"に丸められるため、代わりに1 MBとして表示
+    let mut formatted = format_value(value);
+    if formatted == "1000" && unit < UNITS.len() - 1 {
+        value /= 1_000.0;
+        unit += 1;
+        formatted = format_value(value);
+    }
+
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted.as_str()
+    };
+
+    format!("{trimmed} {}", UNITS[unit])
+}
+
+/// Format a signed byte count reported by the Engine API
+/// `Engine` `API`が報告する符号付きバイト数をフォーマット
+///
+/// The API uses `-1` for sizes that were not computed; those are shown as `-`.
+/// `API`は計算されていないサイズに`-1`を使用するため、それらは`-`として表示されます。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::size_formatter::format_api_size;
+///
+/// assert_eq!(format_api_size(2_048), "2.05 kB");
+/// assert_eq!(format_api_size(-1), "-");
+/// ```
+#[must_use]
+pub fn format_api_size(bytes: i64) -> String {
+    u64::try_from(bytes).map_or_else(|_| "-".to_string(), format_size)
+}
+
+/// Format reclaimable space with its share of the total (e.g. `1.2 GB (45%)`)
+/// 回収可能な容量を合計に対する割合付きでフォーマット（例: `1.2 GB (45%)`）
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::size_formatter::format_reclaimable;
+///
+/// assert_eq!(format_reclaimable(500_000_000, 2_000_000_000), "500 MB (25%)");
+/// assert_eq!(format_reclaimable(0, 0), "0 B (0%)");
+/// ```
+#[must_use]
+pub fn format_reclaimable(reclaimable: u64, total: u64) -> String {
+    format!(
+        "{} ({}%)",
+        format_size(reclaimable),
+        percentage(reclaimable, total)
+    )
+}
+
+/// Integer percentage of `part` in `total`, capped at 100
+/// `total`に対する`part`の整数パーセンテージ（上限100）
+#[must_use]
+pub fn percentage(part: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+
+    let percent = u128::from(part) * 100 / u128::from(total);
+    u64::try_from(percent.min(100)).unwrap_or(100)
+}
+
+/// Binary suffixes of memory sizes in `docker run --memory` notation
+/// `docker run --memory`表記のメモリサイズの2進接尾辞
+const MEMORY_SUFFIXES: [(char, u32); 3] = [('g', 30), ('m', 20), ('k', 10)];
+
+/// Parse a memory size like `docker run --memory` does (`512m`, `1.5g`)
+/// `docker run --memory`と同様にメモリサイズを解析（`512m`、`1.5g`）
+///
+/// Suffixes `b`, `k`, `m` and `g` are binary and case-insensitive; a bare
+/// number means bytes.
+///
+/// 接尾辞`b`、`k`、`m`、`g`は2進単位で大文字小文字を区別しません。
+/// 接尾辞のない数値はバイトとして扱います。
+///
+/// # Errors
+/// * `DockaError::InvalidInput` - When the text is empty, has an unknown suffix or overflows
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::size_formatter::parse_memory_size;
+///
+/// assert_eq!(parse_memory_size("512m").unwrap(), 512 * 1024 * 1024);
+/// assert_eq!(parse_memory_size("1.5G").unwrap(), 1_610_612_736);
+/// assert_eq!(parse_memory_size("4096").unwrap(), 4096);
+/// assert!(parse_memory_size("12x").is_err());
+/// ```
+pub fn parse_memory_size(text: &str) -> DockaResult<u64> {
+    let text = text.trim().to_ascii_lowercase();
+    let invalid = || {
+        DockaError::invalid_input(format!(
+            "Invalid size '{text}' (use e.g. 512m, 1.5g or a byte count)"
+        ))
+    };
+
+    let number = text.strip_suffix('b').unwrap_or(&text);
+    let (number, shift) = MEMORY_SUFFIXES
+        .iter()
+        .find_map(|(suffix, shift)| number.strip_suffix(*suffix).map(|rest| (rest, *shift)))
+        .unwrap_or((number, 0));
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(invalid());
+    }
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    let unit = 1_u64 << shift;
+    let mut bytes = whole.checked_mul(unit).ok_or_else(invalid)?;
+    if !fraction.is_empty() {
+        // Scale the fraction in integers to avoid float rounding
+        // 浮動小数点の丸めを避けるため、小数部を整数でスケーリング
+        let digits = u32::try_from(fraction.len()).map_err(|_| invalid())?;
+        let denominator = 10_u128.checked_pow(digits).ok_or_else(invalid)?;
+        let numerator: u128 = fraction.parse().map_err(|_| invalid())?;
+        let part =
+            u64::try_from(numerator * u128::from(unit) / denominator).map_err(|_| invalid())?;
+        bytes = bytes.checked_add(part).ok_or_else(invalid)?;
+    }
+    Ok(bytes)
+}
+
+/// Format a memory size in `docker run --memory` notation (`512m`, `1g`)
+/// メモリサイズを`docker run --memory`表記でフォーマット（`512m`、`1g`）
+///
+/// The largest binary suffix that divides the size exactly is used, so the
+/// result parses back to the same value.
+/// サイズを割り切れる最大の2進接尾辞を使用するため、結果は同じ値に解析し直せます。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::utils::size_formatter::format_memory_size;
+///
+/// assert_eq!(format_memory_size(512 * 1024 * 1024), "512m");
+/// assert_eq!(format_memory_size(1 << 30), "1g");
+/// assert_eq!(format_memory_size(1_000), "1000");
+/// ```
+#[must_use]
+pub fn format_memory_size(bytes: u64) -> String {
+    MEMORY_SUFFIXES
+        .iter()
+        .find(|(_, shift)| bytes != 0 && bytes.trailing_zeros() >= *shift)
+        .map_or_else(
+            || bytes.to_string(),
+            |(suffix, shift)| format!("{}{suffix}", bytes >> shift),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size_units() {
+        // Test that sizes are scaled to decimal units like the Docker CLI
+        // Docker CLIと同様に10進単位にスケーリングされることをテスト
+        assert_eq!(format_size(999), "999 B");
+        assert_eq!(format_size(1_000), "1 kB");
+        assert_eq!(format_size(1_234), "1.23 kB");
+        assert_eq!(format_size(45_600_000), "45.6 MB");
+        assert_eq!(format_size(2_000_000_000_000), "2 TB");
+        assert_eq!(format_size(u64::MAX), "18.4 EB");
+    }
+
+    #[test]
+    fn test_format_size_rounds_up_to_next_unit() {
+        // Test that values rounding to 1000 move to the next unit
+        // 1000に丸められる値が次の単位に移ることをテスト
+        assert_eq!(format_size(999_999), "1 MB");
+        assert_eq!(format_size(999_499), "999 kB");
+    }
+
+    #[test]
+    fn test_percentage_edge_cases() {
+        // Test percentage with zero totals and overflowing parts
+        // 合計ゼロや超過した部分でのパーセンテージをテスト
+        assert_eq!(percentage(1, 0), 0);
+        assert_eq!(percentage(1, 3), 33);
+        assert_eq!(percentage(5, 4), 100);
+        assert_eq!(percentage(u64::MAX, u64::MAX), 100);
+    }
+
+    #[test]
+    fn test_memory_size_round_trip() {
+        // Test that formatted memory sizes parse back to the same value
+        // フォーマットしたメモリサイズが同じ値に解析し直せることをテスト
+        for bytes in [1, 6 << 20, 1_536 << 10, 3 << 30, 1_000_000] {
+            assert_eq!(
+                parse_memory_size(&format_memory_size(bytes)).unwrap(),
+                bytes
+            );
+        }
+        assert_eq!(parse_memory_size(" 256MB ").unwrap(), 256 << 20);
+        assert_eq!(parse_memory_size(".5k").unwrap(), 512);
+        assert!(parse_memory_size("").is_err());
+        assert!(parse_memory_size("m").is_err());
+        assert!(parse_memory_size("1.2.3g").is_err());
+        assert!(parse_memory_size("99999999999999g").is_err());
+    }
+}
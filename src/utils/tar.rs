@@ -0,0 +1,720 @@
+// src/utils/tar.rs
+// Streaming tar encoding and decoding
+// ストリーミングtarのエンコードとデコード
+
+use crate::domain::entities::PathKind;
+use crate::error::{DockaError, DockaResult};
+
+/// Size of a tar block; headers and padded data are multiples of it
+/// tarブロックのサイズ（ヘッダーとパディングされたデータはその倍数）
+pub const BLOCK_SIZE: usize = 512;
+
+/// Two zero blocks that terminate an archive
+/// アーカイブを終端する2つのゼロブロック
+pub const END_OF_ARCHIVE: [u8; 2 * BLOCK_SIZE] = [0; 2 * BLOCK_SIZE];
+
+/// Largest PAX or GNU long name record kept in memory
+/// メモリ上に保持するPAXまたはGNU長名レコードの最大サイズ
+const MAX_EXTENSION_SIZE: u64 = 1024 * 1024;
+
+/// Largest size that fits the 11 octal digits of a ustar header
+/// ustarヘッダーの8進数11桁に収まる最大サイズ
+const MAX_OCTAL_SIZE: u64 = 0o777_7777_7777;
+
+/// Metadata of one archive entry
+/// アーカイブエントリ1件のメタデータ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TarHeader {
+    /// Relative path inside the archive, without a trailing `/`
+    /// アーカイブ内の相対パス（末尾の`/`なし）
+    pub path: String,
+
+    /// Kind of entry
+    /// エントリの種類
+    pub kind: PathKind,
+
+    /// Target of a symbolic or hard link
+    /// シンボリックリンクまたはハードリンクのリンク先
+    pub link_target: Option<String>,
+
+    /// Size of the entry's data in bytes (zero for everything but files)
+    /// エントリのデータサイズ（バイト、ファイル以外は0）
+    pub size: u64,
+
+    /// Permission bits
+    /// パーミッションビット
+    pub mode: u32,
+
+    /// Modification time in seconds since the Unix epoch
+    /// `Unix`エポックからの秒数での更新日時
+    pub mtime: u64,
+}
+
+impl TarHeader {
+    /// Create a header with the usual permissions for `kind`
+    /// `kind`の一般的なパーミッションでヘッダーを作成
+    #[must_use]
+    pub fn new(path: impl Into<String>, kind: PathKind, size: u64) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+            link_target: None,
+            size,
+            mode: if kind.is_directory() { 0o755 } else { 0o644 },
+            mtime: 0,
+        }
+    }
+
+    /// Encode the header as ustar blocks, preceded by a PAX header when needed
+    /// ヘッダーをustarブロックとしてエンコード（必要な場合はPAXヘッダーを前に付加）
+    ///
+    /// Paths and link targets that do not fit the ustar fields and sizes of
+    /// 8 GiB or more are stored in a PAX extended header.
+    ///
+    /// ustarのフィールドに収まらないパスとリンク先、8GiB以上のサイズは
+    /// PAX拡張ヘッダーに格納されます。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - Special files cannot be archived
+    pub fn encode(&self) -> DockaResult<Vec<u8>> {
+        let typeflag = match self.kind {
+            PathKind::File => b'0',
+            PathKind::HardLink => b'1',
+            PathKind::Symlink => b'2',
+            PathKind::Directory => b'5',
+            PathKind::Other => {
+                return Err(DockaError::invalid_input(format!(
+                    "Cannot archive special file '{}'",
+                    self.path
+                )));
+            }
+        };
+
+        let mut path = self.path.trim_end_matches('/').to_string();
+        if self.kind.is_directory() {
+            path.push('/');
+        }
+
+        let mut records = Vec::new();
+        let split = split_ustar_path(&path);
+        if split.is_none() {
+            records.extend(pax_record("path", &path));
+        }
+        let link_target = self.link_target.as_deref().unwrap_or_default();
+        if link_target.len() > 100 {
+            records.extend(pax_record("linkpath", link_target));
+        }
+        if self.size > MAX_OCTAL_SIZE {
+            records.extend(pax_record("size", &self.size.to_string()));
+        }
+
+        let mut blocks = Vec::with_capacity(BLOCK_SIZE);
+        if !records.is_empty() {
+            let name = format!("PaxHeaders/{}", tail(&path, 89));
+            let size = records.len() as u64;
+            blocks.extend(ustar_block(("", &name), b'x', "", size, 0o644, self.mtime));
+            let padding = padding(size);
+            blocks.extend(records);
+            blocks.resize(blocks.len() + padding, 0);
+        }
+
+        let (prefix, name) = split.unwrap_or_else(|| ("", tail(&path, 100)));
+        let size = if self.size > MAX_OCTAL_SIZE {
+            0
+        } else {
+            self.size
+        };
+        blocks.extend(ustar_block(
+            (prefix, name),
+            typeflag,
+            tail(link_target, 100),
+            size,
+            self.mode,
+            self.mtime,
+        ));
+        Ok(blocks)
+    }
+}
+
+/// Number of zero bytes that pad `size` bytes of data to a whole block
+/// `size`バイトのデータをブロック境界まで埋めるゼロバイト数
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // The remainder is below BLOCK_SIZE
+pub const fn padding(size: u64) -> usize {
+    let rest = (size % BLOCK_SIZE as u64) as usize;
+    if rest == 0 { 0 } else { BLOCK_SIZE - rest }
+}
+
+/// Piece of an archive produced by `TarReader`
+/// `TarReader`が生成するアーカイブの断片
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TarEvent<'a> {
+    /// Start of an entry
+    /// エントリの開始
+    Entry(TarHeader),
+
+    /// Next part of the current entry's data
+    /// 現在のエントリのデータの続き
+    Data(&'a [u8]),
+
+    /// All data of the current entry has been read
+    /// 現在のエントリの全データを読み終えた
+    EntryEnd,
+}
+
+/// Step of `TarReader`
+/// `TarReader`の手順
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum ReadState {
+    /// Collecting a header block
+    /// ヘッダーブロックを収集中
+    #[default]
+    Header,
+
+    /// Handing out entry data
+    /// エントリデータを渡している
+    Data { remaining: u64, padding: usize },
+
+    /// Entry data is complete; `EntryEnd` is still to be reported
+    /// エントリデータ完了、`EntryEnd`をまだ報告していない
+    EntryEnd { padding: usize },
+
+    /// Skipping the zero bytes after data
+    /// データ後のゼロバイトをスキップ中
+    Padding(usize),
+
+    /// Collecting a PAX or GNU long name record
+    /// PAXまたはGNU長名レコードを収集中
+    Extension {
+        typeflag: u8,
+        remaining: u64,
+        padding: usize,
+        data: Vec<u8>,
+    },
+
+    /// End-of-archive marker seen
+    /// アーカイブ終端マーカーを検出済み
+    Finished,
+}
+
+/// Values from extension records that override the next header
+/// 次のヘッダーを上書きする拡張レコードの値
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Overrides {
+    path: Option<String>,
+    link_target: Option<String>,
+    size: Option<u64>,
+}
+
+/// Incremental tar decoder fed with chunks as they arrive
+/// 到着したチャンクを順に渡すインクリメンタルtarデコーダー
+///
+/// Only a partial header block and extension records are buffered; entry
+/// data is returned as slices of the input, so memory use does not depend
+/// on the archive size. ustar, PAX (`path`, `linkpath`, `size`) and GNU
+/// long names are understood.
+///
+/// 部分的なヘッダーブロックと拡張レコードのみをバッファし、エントリデータは
+/// 入力のスライスとして返すため、メモリ使用量はアーカイブサイズに依存しません。
+/// ustar、PAX（`path`、`linkpath`、`size`）、GNU長名を解釈します。
+///
+/// # Examples
+///
+/// ```rust
+/// use docka::domain::entities::PathKind;
+/// use docka::utils::tar::{TarEvent, TarHeader, TarReader, END_OF_ARCHIVE};
+///
+/// let mut archive = TarHeader::new("etc", PathKind::Directory, 0).encode().unwrap();
+/// archive.extend(END_OF_ARCHIVE);
+///
+/// let mut reader = TarReader::new();
+/// let mut input = archive.as_slice();
+/// let Some(TarEvent::Entry(header)) = reader.next_event(&mut input).unwrap() else {
+///     panic!("expected an entry");
+/// };
+/// assert_eq!(header.path, "etc");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TarReader {
+    block: Vec<u8>,
+    state: ReadState,
+    overrides: Overrides,
+}
+
+impl TarReader {
+    /// Create a reader at the start of an archive
+    /// アーカイブの先頭にあるリーダーを作成
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether the end-of-archive marker has been read
+    /// アーカイブ終端マーカーを読んだかチェック
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        matches!(self.state, ReadState::Finished)
+    }
+
+    /// Consume bytes from `input` until the next event
+    /// 次のイベントまで`input`からバイトを消費
+    ///
+    /// Returns `Ok(None)` once `input` is exhausted (feed the next chunk) or
+    /// the archive has ended.
+    /// `input`を使い切った場合（次のチャンクを渡す）またはアーカイブが
+    /// 終了した場合は`Ok(None)`を返します。
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - A header is corrupt or an extension record is too large
+    pub fn next_event<'a>(&mut self, input: &mut &'a [u8]) -> DockaResult<Option<TarEvent<'a>>> {
+        loop {
+            match &mut self.state {
+                ReadState::Finished => return Ok(None),
+                ReadState::Padding(remaining) => {
+                    let skipped = (*remaining).min(input.len());
+                    *input = &input[skipped..];
+                    *remaining -= skipped;
+                    if *remaining > 0 {
+                        return Ok(None);
+                    }
+                    self.state = ReadState::Header;
+                }
+                ReadState::EntryEnd { padding } => {
+                    self.state = ReadState::Padding(*padding);
+                    return Ok(Some(TarEvent::EntryEnd));
+                }
+                ReadState::Data { remaining, padding } => {
+                    if *remaining == 0 {
+                        self.state = ReadState::EntryEnd { padding: *padding };
+                        continue;
+                    }
+                    if input.is_empty() {
+                        return Ok(None);
+                    }
+                    let taken = usize::try_from(*remaining)
+                        .map_or(input.len(), |remaining| remaining.min(input.len()));
+                    let (data, rest) = input.split_at(taken);
+                    *input = rest;
+                    *remaining -= taken as u64;
+                    return Ok(Some(TarEvent::Data(data)));
+                }
+                ReadState::Extension {
+                    typeflag,
+                    remaining,
+                    padding,
+                    data,
+                } => {
+                    let taken = usize::try_from(*remaining)
+                        .map_or(input.len(), |remaining| remaining.min(input.len()));
+                    data.extend_from_slice(&input[..taken]);
+                    *input = &input[taken..];
+                    *remaining -= taken as u64;
+                    if *remaining > 0 {
+                        return Ok(None);
+                    }
+                    let (typeflag, padding, data) = (*typeflag, *padding, std::mem::take(data));
+                    self.apply_extension(typeflag, &data)?;
+                    self.state = ReadState::Padding(padding);
+                }
+                ReadState::Header => {
+                    let wanted = (BLOCK_SIZE - self.block.len()).min(input.len());
+                    self.block.extend_from_slice(&input[..wanted]);
+                    *input = &input[wanted..];
+                    if self.block.len() < BLOCK_SIZE {
+                        return Ok(None);
+                    }
+                    let block = std::mem::take(&mut self.block);
+                    if let Some(header) = self.read_header(&block)? {
+                        return Ok(Some(TarEvent::Entry(header)));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Decode a header block and move to the state that follows it
+    /// ヘッダーブロックをデコードし、続く状態に移行
+    ///
+    /// Returns `None` for extension headers and the end-of-archive marker.
+    /// 拡張ヘッダーとアーカイブ終端マーカーでは`None`を返します。
+    fn read_header(&mut self, block: &[u8]) -> DockaResult<Option<TarHeader>> {
+        if block.iter().all(|&byte| byte == 0) {
+            self.state = ReadState::Finished;
+            return Ok(None);
+        }
+        verify_checksum(block)?;
+
+        let typeflag = block[156];
+        let size = self
+            .overrides
+            .size
+            .take()
+            .map_or_else(|| parse_size(&block[124..136]), Ok)?;
+
+        if matches!(typeflag, b'x' | b'g' | b'L' | b'K') {
+            if size > MAX_EXTENSION_SIZE {
+                return Err(DockaError::invalid_input(
+                    "Tar extension record is too large",
+                ));
+            }
+            self.state = ReadState::Extension {
+                typeflag,
+                remaining: size,
+                padding: padding(size),
+                data: Vec::new(),
+            };
+            return Ok(None);
+        }
+
+        let kind = match typeflag {
+            0 | b'0' | b'7' => PathKind::File,
+            b'1' => PathKind::HardLink,
+            b'2' => PathKind::Symlink,
+            b'5' => PathKind::Directory,
+            _ => PathKind::Other,
+        };
+        let path = self.overrides.path.take().unwrap_or_else(|| {
+            let name = field_str(&block[0..100]);
+            let prefix = field_str(&block[345..500]);
+            if block[257..262] == *b"ustar" && !prefix.is_empty() {
+                format!("{prefix}/{name}")
+            } else {
+                name
+            }
+        });
+        let link_target = self
+            .overrides
+            .link_target
+            .take()
+            .or_else(|| Some(field_str(&block[157..257])).filter(|target| !target.is_empty()));
+
+        self.state = ReadState::Data {
+            remaining: size,
+            padding: padding(size),
+        };
+        Ok(Some(TarHeader {
+            path: trim_path(&path),
+            kind,
+            link_target,
+            size,
+            mode: u32::try_from(parse_octal(&block[100..108])?).unwrap_or(0o644),
+            mtime: parse_octal(&block[136..148])?,
+        }))
+    }
+
+    /// Record the values of a completed extension record
+    /// 完了した拡張レコードの値を記録
+    fn apply_extension(&mut self, typeflag: u8, data: &[u8]) -> DockaResult<()> {
+        match typeflag {
+            b'L' => self.overrides.path = Some(field_str(data)),
+            b'K' => self.overrides.link_target = Some(field_str(data)),
+            b'x' => {
+                for (key, value) in parse_pax_records(data)? {
+                    match key.as_str() {
+                        "path" => self.overrides.path = Some(value),
+                        "linkpath" => self.overrides.link_target = Some(value),
+                        "size" => {
+                            self.overrides.size = Some(value.parse().map_err(|_| {
+                                DockaError::invalid_input(format!("Invalid PAX size '{value}'"))
+                            })?);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            // Global headers only carry defaults this reader does not use
+            // グローバルヘッダーはこのリーダーが使わない既定値のみを持つ
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Split a path into the ustar `prefix` and `name` fields
+/// パスをustarの`prefix`と`name`フィールドに分割
+fn split_ustar_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    // A trailing `/` of a directory must stay in the name field
+    // ディレクトリ末尾の`/`は名前フィールドに残す必要がある
+    let search = &path[..path.len() - 1];
+    search
+        .match_indices('/')
+        .map(|(index, _)| (&path[..index], &path[index + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100)
+}
+
+/// Last `max` bytes of `text`, cut at a character boundary
+/// `text`の末尾`max`バイト（文字境界で切る）
+fn tail(text: &str, max: usize) -> &str {
+    let mut start = text.len().saturating_sub(max);
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    &text[start..]
+}
+
+/// Encode one PAX record (`<length> <key>=<value>\n`)
+/// PAXレコードを1件エンコード（`<長さ> <キー>=<値>\n`）
+fn pax_record(key: &str, value: &str) -> Vec<u8> {
+    // The length prefix counts its own digits
+    // 長さの接頭辞は自身の桁数も数える
+    let body = key.len() + value.len() + 3;
+    let mut length = body + body.to_string().len();
+    if length.to_string().len() > body.to_string().len() {
+        length += 1;
+    }
+    format!("{length} {key}={value}\n").into_bytes()
+}
+
+/// Parse PAX records into key/value pairs
+/// PAXレコードをキーと値の組に解析
+fn parse_pax_records(mut data: &[u8]) -> DockaResult<Vec<(String, String)>> {
+    let invalid = || DockaError::invalid_input("Corrupt PAX extended header");
+    let mut records = Vec::new();
+    while !data.is_empty() && data[0] != 0 {
+        let space = data
+            .iter()
+            .position(|&byte| byte == b' ')
+            .ok_or_else(invalid)?;
+        let length: usize = std::str::from_utf8(&data[..space])
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .filter(|&length| length > space + 1 && length <= data.len())
+            .ok_or_else(invalid)?;
+        let record = String::from_utf8_lossy(&data[space + 1..length - 1]);
+        let (key, value) = record.split_once('=').ok_or_else(invalid)?;
+        records.push((key.to_string(), value.to_string()));
+        data = &data[length..];
+    }
+    Ok(records)
+}
+
+/// Build a single ustar header block
+/// ustarヘッダーブロックを1つ構築
+fn ustar_block(
+    (prefix, name): (&str, &str),
+    typeflag: u8,
+    link_target: &str,
+    size: u64,
+    mode: u32,
+    mtime: u64,
+) -> [u8; BLOCK_SIZE] {
+    let mut block = [0u8; BLOCK_SIZE];
+    write_bytes(&mut block[0..100], name.as_bytes());
+    write_octal(&mut block[100..108], u64::from(mode & 0o7777));
+    write_octal(&mut block[108..116], 0);
+    write_octal(&mut block[116..124], 0);
+    write_octal(&mut block[124..136], size);
+    write_octal(&mut block[136..148], mtime.min(MAX_OCTAL_SIZE));
+    block[156] = typeflag;
+    write_bytes(&mut block[157..257], link_target.as_bytes());
+    block[257..263].copy_from_slice(b"ustar\0");
+    block[263..265].copy_from_slice(b"00");
+    write_bytes(&mut block[345..500], prefix.as_bytes());
+
+    block[148..156].fill(b' ');
+    let checksum: u32 = block.iter().map(|&byte| u32::from(byte)).sum();
+    write_bytes(
+        &mut block[148..156],
+        format!("{checksum:06o}\0 ").as_bytes(),
+    );
+    block
+}
+
+/// Copy `bytes` into `field`, truncating if needed
+/// `bytes`を`field`にコピー（必要に応じて切り詰め）
+fn write_bytes(field: &mut [u8], bytes: &[u8]) {
+    let length = bytes.len().min(field.len());
+    field[..length].copy_from_slice(&bytes[..length]);
+}
+
+/// Write a zero-padded, NUL-terminated octal number
+/// ゼロ埋めしNUL終端した8進数を書き込む
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = field.len() - 1;
+    write_bytes(field, format!("{value:0digits$o}").as_bytes());
+    field[digits] = 0;
+}
+
+/// Read a NUL-terminated text field
+/// NUL終端のテキストフィールドを読む
+fn field_str(field: &[u8]) -> String {
+    let end = field
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+/// Strip `./` and trailing `/` from an archive path
+/// アーカイブパスから`./`と末尾の`/`を除去
+fn trim_path(path: &str) -> String {
+    let path = path.strip_prefix("./").unwrap_or(path);
+    path.trim_end_matches('/').to_string()
+}
+
+/// Parse an octal field padded with spaces or NULs
+/// スペースまたはNULでパディングされた8進数フィールドを解析
+fn parse_octal(field: &[u8]) -> DockaResult<u64> {
+    let text = field_str(field);
+    let digits = text.trim_matches(|c: char| c == ' ' || c == '\0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(digits, 8)
+        .map_err(|_| DockaError::invalid_input(format!("Invalid tar number '{digits}'")))
+}
+
+/// Parse the size field, which is base-256 when its high bit is set
+/// サイズフィールドを解析（最上位ビットが立っている場合は256進数）
+fn parse_size(field: &[u8]) -> DockaResult<u64> {
+    if field[0] & 0x80 == 0 {
+        return parse_octal(field);
+    }
+    field[1..]
+        .iter()
+        .try_fold(u64::from(field[0] & 0x7f), |size, &byte| {
+            size.checked_mul(256).map(|size| size | u64::from(byte))
+        })
+        .ok_or_else(|| DockaError::invalid_input("Tar entry size overflows"))
+}
+
+/// Check the header checksum, computed with the checksum field as spaces
+/// チェックサムフィールドをスペースとして計算したヘッダーチェックサムを確認
+fn verify_checksum(block: &[u8]) -> DockaResult<()> {
+    let expected = parse_octal(&block[148..156])?;
+    let actual: u64 = block
+        .iter()
+        .enumerate()
+        .map(|(index, &byte)| {
+            if (148..156).contains(&index) {
+                u64::from(b' ')
+            } else {
+                u64::from(byte)
+            }
+        })
+        .sum();
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(DockaError::invalid_input(
+            "Corrupt tar header (bad checksum)",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode `archive` fed in chunks of `chunk_size`, collecting entries and their data
+    /// `archive`を`chunk_size`ずつ渡してデコードし、エントリとデータを収集
+    fn decode(archive: &[u8], chunk_size: usize) -> Vec<(TarHeader, Vec<u8>)> {
+        let mut reader = TarReader::new();
+        let mut entries: Vec<(TarHeader, Vec<u8>)> = Vec::new();
+        let mut ended = 0;
+        for chunk in archive.chunks(chunk_size) {
+            let mut input = chunk;
+            while let Some(event) = reader.next_event(&mut input).unwrap() {
+                match event {
+                    TarEvent::Entry(header) => entries.push((header, Vec::new())),
+                    TarEvent::Data(data) => entries.last_mut().unwrap().1.extend(data),
+                    TarEvent::EntryEnd => ended += 1,
+                }
+            }
+        }
+        assert!(reader.is_finished());
+        assert_eq!(ended, entries.len());
+        entries
+    }
+
+    fn archive_of(entries: &[(TarHeader, &[u8])]) -> Vec<u8> {
+        let mut archive = Vec::new();
+        for (header, data) in entries {
+            archive.extend(header.encode().unwrap());
+            archive.extend(*data);
+            archive.resize(archive.len() + padding(data.len() as u64), 0);
+        }
+        archive.extend(END_OF_ARCHIVE);
+        archive
+    }
+
+    #[test]
+    fn test_round_trip_in_small_chunks() {
+        // Test that entries survive encoding and chunked decoding, including long paths
+        // 長いパスを含め、エントリがエンコードと分割デコードで保たれることをテスト
+        let long_dir = format!("app/{}", "nested/".repeat(30));
+        let long_file = format!("{long_dir}config.json");
+        let mut link = TarHeader::new("app/current", PathKind::Symlink, 0);
+        link.link_target = Some(format!("/{}", "x".repeat(120)));
+        let mut file = TarHeader::new(long_file.clone(), PathKind::File, 600);
+        file.mode = 0o600;
+        file.mtime = 1_700_000_000;
+        let content = vec![b'z'; 600];
+
+        let archive = archive_of(&[
+            (TarHeader::new("app", PathKind::Directory, 0), &[]),
+            (
+                TarHeader::new(long_dir.clone(), PathKind::Directory, 0),
+                &[],
+            ),
+            (file.clone(), &content),
+            (link.clone(), &[]),
+        ]);
+
+        for chunk_size in [1, 7, 512, archive.len()] {
+            let entries = decode(&archive, chunk_size);
+            let paths: Vec<&str> = entries.iter().map(|(h, _)| h.path.as_str()).collect();
+            assert_eq!(
+                paths,
+                [
+                    "app",
+                    long_dir.trim_end_matches('/'),
+                    &long_file,
+                    "app/current"
+                ]
+            );
+            assert_eq!(entries[1].0.kind, PathKind::Directory);
+            assert_eq!(entries[2].0, file);
+            assert_eq!(entries[2].1, content);
+            assert_eq!(entries[3].0.link_target, link.link_target);
+        }
+    }
+
+    #[test]
+    fn test_reads_base256_size_and_rejects_bad_checksum() {
+        // Test the binary size encoding used for huge files and checksum validation
+        // 巨大ファイルに使われるバイナリサイズ表現とチェックサム検証をテスト
+        let mut field = [0u8; 12];
+        field[0] = 0x80;
+        field[7] = 0x02;
+        assert_eq!(parse_size(&field).unwrap(), 2 << 32);
+
+        let mut block = TarHeader::new("a.txt", PathKind::File, 3).encode().unwrap();
+        block[0] = b'b';
+        let mut input = block.as_slice();
+        assert!(matches!(
+            TarReader::new().next_event(&mut input),
+            Err(DockaError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn test_huge_size_uses_pax_record() {
+        // Test that sizes beyond the octal field are written as a PAX record
+        // 8進数フィールドを超えるサイズがPAXレコードとして書かれることをテスト
+        let header = TarHeader::new("disk.img", PathKind::File, MAX_OCTAL_SIZE + 1);
+        let encoded = header.encode().unwrap();
+        assert_eq!(encoded.len(), 3 * BLOCK_SIZE);
+
+        let mut reader = TarReader::new();
+        let mut input = encoded.as_slice();
+        let Some(TarEvent::Entry(decoded)) = reader.next_event(&mut input).unwrap() else {
+            panic!("expected an entry");
+        };
+        assert_eq!(decoded.size, MAX_OCTAL_SIZE + 1);
+        assert_eq!(pax_record("path", "a"), b"9 path=a\n");
+    }
+}
@@ -0,0 +1,418 @@
+// src/utils/time_formatter.rs
+// Duration and timestamp parsing and formatting
+// 期間とタイムスタンプの解析とフォーマット
+
+use std::fmt::{self, Display};
+
+use crate::error::{DockaError, DockaResult};
+use chrono::{DateTime, Duration, FixedOffset, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Units accepted by `parse_duration`, largest first, with their length in seconds
+/// `parse_duration`が受け付ける単位（大きい順）とその秒数
+const UNITS: [(char, i64); 5] = [
+    ('w', 7 * 24 * 60 * 60),
+    ('d', 24 * 60 * 60),
+    ('h', 60 * 60),
+    ('m', 60),
+    ('s', 1),
+];
+
+/// Parse a short duration such as `30m`, `12h`, `7d` or `1d12h`
+/// `30m`、`12h`、`7d`、`1d12h`のような短い期間表記を解析
+///
+/// Units are `w`, `d`, `h`, `m` and `s`; a bare number means hours, like
+/// the `until` filter of the Docker CLI.
+///
+/// 単位は`w`、`d`、`h`、`m`、`s`です。単位のない数値は`Docker` `CLI`の
+/// `until`フィルタと同様に時間として扱います。
+///
+/// # Errors
+/// * `DockaError::InvalidInput` - When the text is empty, has an unknown unit or overflows
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Duration;
+/// use docka::utils::time_formatter::parse_duration;
+///
+/// assert_eq!(parse_duration("7d").unwrap(), Duration::days(7));
+/// assert_eq!(parse_duration("1h30m").unwrap(), Duration::minutes(90));
+/// assert_eq!(parse_duration("24").unwrap(), Duration::hours(24));
+/// assert!(parse_duration("3x").is_err());
+/// ```
+pub fn parse_duration(text: &str) -> DockaResult<Duration> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(DockaError::invalid_input("Duration cannot be empty"));
+    }
+
+    let invalid = || {
+        DockaError::invalid_input(format!(
+            "Invalid duration '{text}' (use e.g. 30m, 12h, 7d or 2w)"
+        ))
+    };
+
+    if let Ok(hours) = text.parse::<u32>() {
+        return Ok(Duration::hours(i64::from(hours)));
+    }
+
+    let mut total: i64 = 0;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let (_, seconds) = UNITS
+            .iter()
+            .find(|(unit, _)| *unit == c.to_ascii_lowercase())
+            .ok_or_else(invalid)?;
+        let value: i64 = number.parse().map_err(|_| invalid())?;
+        total = value
+            .checked_mul(*seconds)
+            .and_then(|part| total.checked_add(part))
+            .ok_or_else(invalid)?;
+        number.clear();
+    }
+
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    Duration::try_seconds(total).ok_or_else(invalid)
+}
+
+/// Format a duration with its two largest units (e.g. `7d`, `1h30m`)
+/// 期間を大きい方から2つの単位でフォーマット（例: `7d`、`1h30m`）
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Duration;
+/// use docka::utils::time_formatter::format_duration;
+///
+/// assert_eq!(format_duration(Duration::days(7)), "1w");
+/// assert_eq!(format_duration(Duration::minutes(90)), "1h30m");
+/// assert_eq!(format_duration(Duration::zero()), "0s");
+/// ```
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let mut remaining = duration.num_seconds().max(0);
+    let mut parts = Vec::new();
+    for (unit, seconds) in UNITS {
+        if parts.len() == 2 {
+            break;
+        }
+        let value = remaining / seconds;
+        if value > 0 {
+            parts.push(format!("{value}{unit}"));
+            remaining -= value * seconds;
+        } else if !parts.is_empty() {
+            // Keep the two units adjacent (1d0h is shown as 1d)
+            // 2つの単位を隣接させる（1d0hは1dとして表示）
+            break;
+        }
+    }
+
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.concat()
+    }
+}
+
+/// Format a duration with its largest unit only (e.g. `3h`, `5m`)
+/// 期間を最も大きい単位のみでフォーマット（例: `3h`、`5m`）
+///
+/// Like the Docker CLI, the value is rounded down.
+/// `Docker` `CLI`と同様に値は切り捨てられます。
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Duration;
+/// use docka::utils::time_formatter::format_elapsed;
+///
+/// assert_eq!(format_elapsed(Duration::minutes(200)), "3h");
+/// assert_eq!(format_elapsed(Duration::seconds(59)), "59s");
+/// ```
+#[must_use]
+pub fn format_elapsed(duration: Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    UNITS
+        .iter()
+        .find(|(_, unit_seconds)| seconds >= *unit_seconds)
+        .map_or_else(
+            || "0s".to_string(),
+            |(unit, unit_seconds)| format!("{}{unit}", seconds / unit_seconds),
+        )
+}
+
+/// Whether timestamps are shown relative to now or as date and time
+/// タイムスタンプを現在からの相対で表示するか日時で表示するか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeDisplay {
+    /// Time since the event, e.g. `Up 3h`, `Exited 5m ago`
+    /// イベントからの経過時間（例: `Up 3h`、`Exited 5m ago`）
+    #[default]
+    Relative,
+
+    /// Date and time of the event, e.g. `Up since 2024-05-01 19:00:00 +09:00`
+    /// イベントの日時（例: `Up since 2024-05-01 19:00:00 +09:00`）
+    Absolute,
+}
+
+impl TimeDisplay {
+    /// The other display
+    /// もう一方の表示
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Relative => Self::Absolute,
+            Self::Absolute => Self::Relative,
+        }
+    }
+}
+
+/// Timezone of absolute timestamps
+/// 日時表示のタイムゾーン
+///
+/// Written in the config as `local`, `UTC` or an offset such as `+09:00`.
+/// 設定には`local`、`UTC`、または`+09:00`のようなオフセットとして記述します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum DisplayTimezone {
+    /// Timezone of the machine running docka
+    /// dockaを実行しているマシンのタイムゾーン
+    #[default]
+    Local,
+
+    /// Coordinated Universal Time
+    /// 協定世界時
+    Utc,
+
+    /// Fixed offset from UTC
+    /// `UTC`からの固定オフセット
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse `local`, `UTC` or an offset such as `+09:00` or `-0530`
+    /// `local`、`UTC`、または`+09:00`や`-0530`のようなオフセットを解析
+    ///
+    /// # Errors
+    /// * `DockaError::InvalidInput` - When the text is none of these
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docka::utils::time_formatter::DisplayTimezone;
+    ///
+    /// assert_eq!(DisplayTimezone::parse("utc").unwrap(), DisplayTimezone::Utc);
+    /// assert_eq!(DisplayTimezone::parse("+09:00").unwrap().to_string(), "+09:00");
+    /// assert!(DisplayTimezone::parse("Asia/Tokyo").is_err());
+    /// ```
+    pub fn parse(text: &str) -> DockaResult<Self> {
+        let text = text.trim();
+        match text.to_lowercase().as_str() {
+            "local" => return Ok(Self::Local),
+            "utc" | "z" => return Ok(Self::Utc),
+            _ => {}
+        }
+
+        let invalid = || {
+            DockaError::invalid_input(format!(
+                "Invalid timezone '{text}' (use local, UTC or an offset such as +09:00)"
+            ))
+        };
+        let (sign, digits) = match text.as_bytes().first() {
+            Some(b'+') => (1, &text[1..]),
+            Some(b'-') => (-1, &text[1..]),
+            _ => return Err(invalid()),
+        };
+        let digits = digits.replace(':', "");
+        if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let hours: i32 = digits[..2].parse().map_err(|_| invalid())?;
+        let minutes: i32 = digits[2..].parse().map_err(|_| invalid())?;
+        if minutes >= 60 {
+            return Err(invalid());
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self::Fixed)
+            .ok_or_else(invalid)
+    }
+
+    /// Format a timestamp as `2024-05-01 19:00:00 +09:00` (or `… UTC`)
+    /// タイムスタンプを`2024-05-01 19:00:00 +09:00`（または`… UTC`）の形式でフォーマット
+    #[must_use]
+    pub fn format(self, at: DateTime<Utc>) -> String {
+        const FORMAT: &str = "%Y-%m-%d %H:%M:%S %:z";
+        match self {
+            Self::Local => at.with_timezone(&Local).format(FORMAT).to_string(),
+            Self::Utc => at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            Self::Fixed(offset) => at.with_timezone(&offset).format(FORMAT).to_string(),
+        }
+    }
+}
+
+impl Display for DisplayTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => f.write_str("local"),
+            Self::Utc => f.write_str("UTC"),
+            Self::Fixed(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+impl TryFrom<String> for DisplayTimezone {
+    type Error = DockaError;
+
+    fn try_from(text: String) -> DockaResult<Self> {
+        Self::parse(&text)
+    }
+}
+
+impl From<DisplayTimezone> for String {
+    fn from(timezone: DisplayTimezone) -> Self {
+        timezone.to_string()
+    }
+}
+
+/// Format how long a container has been up (`Up 3h` or `Up since …`)
+/// コンテナの稼働時間をフォーマット（`Up 3h`または`Up since …`）
+#[must_use]
+pub fn format_uptime(
+    started_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    display: TimeDisplay,
+    timezone: DisplayTimezone,
+) -> String {
+    match display {
+        TimeDisplay::Relative => format!("Up {}", format_elapsed(now - started_at)),
+        TimeDisplay::Absolute => format!("Up since {}", timezone.format(started_at)),
+    }
+}
+
+/// Format when a container exited (`Exited 5m ago` or `Exited at …`)
+/// コンテナが終了した時刻をフォーマット（`Exited 5m ago`または`Exited at …`）
+#[must_use]
+pub fn format_exited(
+    finished_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    display: TimeDisplay,
+    timezone: DisplayTimezone,
+) -> String {
+    match display {
+        TimeDisplay::Relative => format!("Exited {} ago", format_elapsed(now - finished_at)),
+        TimeDisplay::Absolute => format!("Exited at {}", timezone.format(finished_at)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_units() {
+        // Test every unit, combined units and case-insensitivity
+        // 全単位、単位の組み合わせ、大文字小文字の区別なしをテスト
+        assert_eq!(parse_duration("45s").unwrap(), Duration::seconds(45));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::days(14));
+        assert_eq!(parse_duration("1d12h").unwrap(), Duration::hours(36));
+        assert_eq!(parse_duration(" 10M ").unwrap(), Duration::minutes(10));
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid_text() {
+        // Test that malformed or overflowing input is rejected
+        // 不正な入力やオーバーフローする入力が拒否されることをテスト
+        for text in ["", "d", "7d3", "-1", "-1d", "1.5h", "99999999999999999w"] {
+            assert!(
+                matches!(parse_duration(text), Err(DockaError::InvalidInput { .. })),
+                "{text} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_duration_round_trip() {
+        // Test that formatted durations parse back to the same value
+        // フォーマットした期間が同じ値に解析されることをテスト
+        for text in ["30m", "12h", "3d", "1h30m", "2w"] {
+            let duration = parse_duration(text).unwrap();
+            assert_eq!(format_duration(duration), text);
+        }
+        assert_eq!(format_duration(Duration::seconds(90_061)), "1d1h");
+    }
+
+    #[test]
+    fn test_format_uptime_and_exit() {
+        // Test the relative and absolute forms of uptimes and exit times
+        // 稼働時間と終了時刻の相対表示と日時表示をテスト
+        let now = DateTime::parse_from_rfc3339("2024-05-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let tokyo = DisplayTimezone::parse("+09:00").unwrap();
+        let started_at = now - Duration::minutes(200);
+        assert_eq!(
+            format_uptime(started_at, now, TimeDisplay::Relative, tokyo),
+            "Up 3h"
+        );
+        assert_eq!(
+            format_uptime(started_at, now, TimeDisplay::Absolute, tokyo),
+            "Up since 2024-05-01 15:40:00 +09:00"
+        );
+        assert_eq!(
+            format_exited(
+                now - Duration::seconds(330),
+                now,
+                TimeDisplay::Relative,
+                tokyo
+            ),
+            "Exited 5m ago"
+        );
+        assert_eq!(
+            format_exited(now, now, TimeDisplay::Absolute, DisplayTimezone::Utc),
+            "Exited at 2024-05-01 10:00:00 UTC"
+        );
+        assert_eq!(format_elapsed(Duration::days(-1)), "0s");
+        assert_eq!(TimeDisplay::Relative.toggled(), TimeDisplay::Absolute);
+    }
+
+    #[test]
+    fn test_display_timezone_parse() {
+        // Test accepted timezone spellings and their round trip through serde
+        // 受け付けるタイムゾーン表記とserdeでの往復をテスト
+        assert_eq!(
+            DisplayTimezone::parse("Local").unwrap(),
+            DisplayTimezone::Local
+        );
+        assert_eq!(DisplayTimezone::parse(" Z ").unwrap(), DisplayTimezone::Utc);
+        assert_eq!(
+            DisplayTimezone::parse("-0530").unwrap().to_string(),
+            "-05:30"
+        );
+        for text in ["", "JST", "+9", "+09:60", "+25:00", "09:00"] {
+            assert!(
+                matches!(
+                    DisplayTimezone::parse(text),
+                    Err(DockaError::InvalidInput { .. })
+                ),
+                "{text} should be rejected"
+            );
+        }
+
+        let timezone = DisplayTimezone::parse("+09:00").unwrap();
+        let json = serde_json::to_string(&timezone).unwrap();
+        assert_eq!(json, "\"+09:00\"");
+        assert_eq!(
+            serde_json::from_str::<DisplayTimezone>(&json).unwrap(),
+            timezone
+        );
+        assert!(serde_json::from_str::<DisplayTimezone>("\"Mars\"").is_err());
+    }
+}
@@ -32,6 +32,10 @@
 //! cargo test --lib
 //! ```
 
+// Lints the tests below predate; they are kept as written
+// 以下のテストが以前から抱えるリント。テストは書かれたまま維持する
+#![allow(clippy::double_must_use)]
+
 use docka::domain::repositories::DockerRepository;
 use docka::domain::value_objects::{ContainerId, ContainerStatus};
 use docka::infrastructure::BollardDockerRepository;
@@ -583,6 +587,46 @@ async fn test_invalid_operations_integration() {
     helper.cleanup().await;
 }
 
+#[tokio::test]
+async fn test_list_reads_exact_start_and_exit_times_integration() {
+    // Test that listed containers carry the start and exit times from inspect
+    // 一覧のコンテナがinspectから得た起動・終了日時を持つことをテスト
+    if !check_docker_available().await {
+        return;
+    }
+
+    let mut helper = IntegrationTestHelper::new().await.unwrap();
+    let container_id = helper.create_test_container("times-test").await.unwrap();
+    let before = chrono::Utc::now() - chrono::Duration::seconds(1);
+
+    helper.repo.start_container(&container_id).await.unwrap();
+    helper
+        .wait_for_running(&container_id, TEST_TIMEOUT)
+        .await
+        .unwrap();
+    let running = helper.repo.get_container(&container_id).await.unwrap();
+    let started_at = running
+        .started_at
+        .expect("running container has a start time");
+    assert!(started_at >= before, "{started_at} is before {before}");
+    assert!(running.finished_at.is_none());
+
+    helper.repo.stop_container(&container_id).await.unwrap();
+    helper
+        .wait_for_container_stopped(&container_id, TEST_TIMEOUT)
+        .await
+        .unwrap();
+    let stopped = helper.repo.get_container(&container_id).await.unwrap();
+    if matches!(stopped.status, ContainerStatus::Exited { .. }) {
+        let finished_at = stopped
+            .finished_at
+            .expect("exited container has an exit time");
+        assert!(finished_at >= started_at);
+    }
+
+    helper.cleanup().await;
+}
+
 // Unit tests for helper functions and conversion logic
 // ヘルパー関数と変換ロジックの単体テスト
 
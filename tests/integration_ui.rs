@@ -8,6 +8,10 @@
 //! このモジュールはウィジェット、レイアウト、イベント処理を含む
 //! 全UIコンポーネントの統合テストを含みます。
 
+// Lints the tests below predate; they are kept as written
+// 以下のテストが以前から抱えるリント。テストは書かれたまま維持する
+#![allow(clippy::clone_on_copy, clippy::module_inception)]
+
 pub mod ui;
 
 // Re-export for convenient access
@@ -9,6 +9,7 @@
 
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use ratatui::{Terminal, backend::TestBackend};
 
 use docka::{
@@ -21,12 +22,23 @@ use docka::{
     DockaError,
     DockaResult,
     DockerRepository,
+    domain::{
+        entities::{
+            ArchiveStream, BuildCacheEntry, ContainerDetails, ContainerExit, ContainerProcess,
+            ContainerStats, FilesystemChange, Image, Network, PathKind, SystemOverview, Volume,
+        },
+        value_objects::{EngineCapabilities, ResourceLimits, Signal},
+    },
     ui::{
         app::{App, NavigationDirection, ViewState},
         events::{AppEvent, EventStats, handle_key_event},
         layouts::SimpleLayout,
         styles::Theme,
-        widgets::{ContainerListWidget, StatusBar},
+        widgets::{
+            CleanupAssistantWidget, ContainerListWidget, DetailsViewWidget, DiffViewWidget,
+            FileBrowserWidget, LabelBrowserWidget, PortsViewWidget, StatusBar,
+            SystemDashboardWidget,
+        },
     },
 };
 
@@ -89,6 +101,10 @@ impl DockerRepository for TestDockerRepository {
         Ok(())
     }
 
+    async fn kill_container(&self, _id: &ContainerId, _signal: Signal) -> DockaResult<()> {
+        Ok(())
+    }
+
     async fn restart_container(&self, _id: &ContainerId) -> DockaResult<()> {
         Ok(())
     }
@@ -104,6 +120,103 @@ impl DockerRepository for TestDockerRepository {
     async fn unpause_container(&self, _id: &ContainerId) -> DockaResult<()> {
         Ok(())
     }
+
+    async fn container_resources(&self, _id: &ContainerId) -> DockaResult<ResourceLimits> {
+        Ok(ResourceLimits::default())
+    }
+
+    async fn update_container(
+        &self,
+        _id: &ContainerId,
+        _limits: &ResourceLimits,
+    ) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn rename_container(&self, _id: &ContainerId, _new_name: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities::unknown()
+    }
+
+    async fn system_overview(&self) -> DockaResult<SystemOverview> {
+        Ok(SystemOverview::default())
+    }
+
+    async fn list_images(&self) -> DockaResult<Vec<Image>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_image(&self, _id: &str, _force: bool) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_volume(&self, _name: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn list_networks(&self) -> DockaResult<Vec<Network>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_network(&self, _id: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn top_processes(&self, _id: &ContainerId) -> DockaResult<Vec<ContainerProcess>> {
+        Ok(Vec::new())
+    }
+
+    async fn container_stats(&self, _id: &ContainerId) -> DockaResult<ContainerStats> {
+        Ok(ContainerStats::default())
+    }
+
+    async fn container_changes(&self, _id: &ContainerId) -> DockaResult<Vec<FilesystemChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn container_details(&self, _id: &ContainerId) -> DockaResult<ContainerDetails> {
+        Ok(ContainerDetails::default())
+    }
+
+    async fn container_exits(&self, _since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>> {
+        Ok(Vec::new())
+    }
+
+    async fn download_archive(&self, _id: &ContainerId, _path: &str) -> DockaResult<ArchiveStream> {
+        Ok(Box::pin(futures_util::stream::empty()))
+    }
+
+    async fn upload_archive(
+        &self,
+        _id: &ContainerId,
+        _path: &str,
+        _archive: ArchiveStream,
+    ) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn container_path_kind(
+        &self,
+        _id: &ContainerId,
+        _path: &str,
+    ) -> DockaResult<Option<PathKind>> {
+        Ok(None)
+    }
+
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_build_cache(&self, _id: &str) -> DockaResult<()> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -193,6 +306,27 @@ mod tests {
                             &self.theme,
                         );
                     }
+                    ViewState::SystemOverview => {
+                        SystemDashboardWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::CleanupAssistant => {
+                        CleanupAssistantWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::FilesystemChanges => {
+                        DiffViewWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::FileBrowser => {
+                        FileBrowserWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::ContainerDetails => {
+                        DetailsViewWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::Ports => {
+                        PortsViewWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
+                    ViewState::Labels => {
+                        LabelBrowserWidget::render(frame, &self.app, areas.main, &self.theme);
+                    }
                 }
 
                 // Render status bar using the correct signature
@@ -8,14 +8,21 @@
 //! このモジュールはContainerListWidgetの統合テストを含み、
 //! アプリケーション状態とレンダリングパイプラインとの統合に焦点を当てています。
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 
 // Import from the main crate
 // メインクレートからインポート
 use docka::{
     ContainerFilter, DockaError, DockaResult,
-    domain::{Container, ContainerBuilder, ContainerId, ContainerStatus, DockerRepository},
+    domain::{
+        Container, ContainerBuilder, ContainerId, ContainerStatus, DockerRepository,
+        entities::{
+            ArchiveStream, BuildCacheEntry, ContainerDetails, ContainerExit, ContainerProcess,
+            ContainerStats, FilesystemChange, Image, Network, PathKind, SystemOverview, Volume,
+        },
+        value_objects::{EngineCapabilities, ResourceLimits, Signal},
+    },
     ui::{App, ContainerListWidget, Theme, ViewState},
 };
 use ratatui::{
@@ -84,6 +91,10 @@ impl DockerRepository for TestDockerRepository {
         Ok(())
     }
 
+    async fn kill_container(&self, _id: &ContainerId, _signal: Signal) -> DockaResult<()> {
+        Ok(())
+    }
+
     async fn restart_container(&self, _id: &ContainerId) -> DockaResult<()> {
         Ok(())
     }
@@ -99,6 +110,103 @@ impl DockerRepository for TestDockerRepository {
     async fn unpause_container(&self, _id: &ContainerId) -> DockaResult<()> {
         Ok(())
     }
+
+    async fn container_resources(&self, _id: &ContainerId) -> DockaResult<ResourceLimits> {
+        Ok(ResourceLimits::default())
+    }
+
+    async fn update_container(
+        &self,
+        _id: &ContainerId,
+        _limits: &ResourceLimits,
+    ) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn rename_container(&self, _id: &ContainerId, _new_name: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities::unknown()
+    }
+
+    async fn system_overview(&self) -> DockaResult<SystemOverview> {
+        Ok(SystemOverview::default())
+    }
+
+    async fn list_images(&self) -> DockaResult<Vec<Image>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_image(&self, _id: &str, _force: bool) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn list_volumes(&self) -> DockaResult<Vec<Volume>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_volume(&self, _name: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn list_networks(&self) -> DockaResult<Vec<Network>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_network(&self, _id: &str) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn top_processes(&self, _id: &ContainerId) -> DockaResult<Vec<ContainerProcess>> {
+        Ok(Vec::new())
+    }
+
+    async fn container_stats(&self, _id: &ContainerId) -> DockaResult<ContainerStats> {
+        Ok(ContainerStats::default())
+    }
+
+    async fn container_changes(&self, _id: &ContainerId) -> DockaResult<Vec<FilesystemChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn container_details(&self, _id: &ContainerId) -> DockaResult<ContainerDetails> {
+        Ok(ContainerDetails::default())
+    }
+
+    async fn container_exits(&self, _since: DateTime<Utc>) -> DockaResult<Vec<ContainerExit>> {
+        Ok(Vec::new())
+    }
+
+    async fn download_archive(&self, _id: &ContainerId, _path: &str) -> DockaResult<ArchiveStream> {
+        Ok(Box::pin(futures_util::stream::empty()))
+    }
+
+    async fn upload_archive(
+        &self,
+        _id: &ContainerId,
+        _path: &str,
+        _archive: ArchiveStream,
+    ) -> DockaResult<()> {
+        Ok(())
+    }
+
+    async fn container_path_kind(
+        &self,
+        _id: &ContainerId,
+        _path: &str,
+    ) -> DockaResult<Option<PathKind>> {
+        Ok(None)
+    }
+
+    async fn list_build_cache(&self) -> DockaResult<Vec<BuildCacheEntry>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_build_cache(&self, _id: &str) -> DockaResult<()> {
+        Ok(())
+    }
 }
 
 /// Test utilities for ContainerListWidget integration tests